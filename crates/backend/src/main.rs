@@ -1,13 +1,18 @@
+use std::net::SocketAddr;
 use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::Context;
 use axum::Router;
-use common::config::Config;
+use clap::Parser;
+use common::audit::{AuditSinkKind, LogAuditSink, RedisAuditSink};
+use common::config::{CliArgs, Config};
 use common::state::AppState;
 use redis::Client;
 use tower_http::cors::CorsLayer;
 use tower_http::trace::TraceLayer;
-use tracing::info;
+use tracing::{error, info};
 
 pub mod assets;
 pub mod route;
@@ -16,7 +21,10 @@ pub mod stream_sender;
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     dotenvy::dotenv().ok();
-    let config = Config::load().context("설정 로딩 실패")?;
+    let cli_args = CliArgs::parse();
+    let rotate_signing_key = cli_args.rotate_signing_key;
+    let grant_admin = cli_args.grant_admin.clone();
+    let config = Config::load_from(cli_args).context("설정 로딩 실패")?;
     config.validate().context("설정 검증 실패")?;
     init_tracing(&config);
 
@@ -25,9 +33,40 @@ async fn main() -> anyhow::Result<()> {
     let redis_client = Client::open(config.redis_url.clone())
         .with_context(|| format!("Redis에 연결할 수 없습니다: {}", config.redis_url))?;
 
-    let state = AppState::new(redis_client.clone());
+    let keys = config.keys();
+    let mut state = AppState::new(
+        redis_client.clone(),
+        keys.clone(),
+        config.argon2_params()?,
+        config.token_policy(),
+    )
+    .with_rate_limit_policy(config.rate_limit_policy())
+    .with_cookie_policy(config.cookie_policy());
+    state = match config.audit_sink {
+        AuditSinkKind::Redis => state.with_audit_sink(Arc::new(
+            RedisAuditSink::new(redis_client.clone(), &keys).with_max_len(config.audit_log_max_len),
+        )),
+        AuditSinkKind::Log => state.with_audit_sink(Arc::new(LogAuditSink)),
+    };
+    if let Some(oidc_config) = config.oidc_config() {
+        info!("SSO 로그인 사용: {}", oidc_config.issuer);
+        let provider = common::oidc::OidcProvider::new(oidc_config)
+            .map_err(|e| anyhow::anyhow!("OIDC 클라이언트 생성 실패: {:?}", e))?;
+        state = state.with_oidc(provider);
+    }
+    if !config.trusted_issuers.is_empty() {
+        for issuer in &config.trusted_issuers {
+            info!("외부 발급자 토큰 허용: {}", issuer.issuer);
+        }
+        let trusted_issuers =
+            common::trusted_issuer::TrustedIssuers::new(config.trusted_issuers.clone())
+                .map_err(|e| anyhow::anyhow!("외부 발급자 설정 실패: {:?}", e))?;
+        state = state.with_trusted_issuers(trusted_issuers);
+    }
 
-    if let Err(e) = common::share::initialize_redis(&redis_client).await {
+    if let Err(e) =
+        common::share::initialize_redis(&redis_client, &keys, config.signing_algorithm).await
+    {
         return Err(anyhow::anyhow!(
             "Failed to initialize JWK in Redis: {:?}",
             e
@@ -35,6 +74,42 @@ async fn main() -> anyhow::Result<()> {
     }
     info!("JWK initialized successfully");
 
+    if rotate_signing_key {
+        let kid = state
+            .rotate_signing_key()
+            .await
+            .map_err(|e| anyhow::anyhow!("서명 키 교체 실패: {:?}", e))?;
+        info!("서명 키를 교체했습니다: {}", kid);
+        return Ok(());
+    }
+
+    // 첫 관리자는 API로 지정할 수 없으므로 여기서 지정합니다.
+    if let Some(user_id) = grant_admin {
+        state
+            .update_account_status(&user_id, Some(true), None)
+            .await
+            .map_err(|e| anyhow::anyhow!("관리자 지정 실패: {:?}", e))?;
+        info!("{}에게 관리자 역할을 주었습니다", user_id);
+        return Ok(());
+    }
+
+    // 설정된 알고리즘과 다른 키로 서명하고 있었다면 지금 교체합니다.
+    match state.rotate_signing_key_if_due(None).await {
+        Ok(Some(kid)) => info!(
+            "서명 알고리즘을 {}(으)로 바꿨습니다: {}",
+            config.signing_algorithm, kid
+        ),
+        Ok(None) => {}
+        Err(e) => return Err(anyhow::anyhow!("서명 키 교체 실패: {:?}", e)),
+    }
+
+    if config.signing_key_rotation_seconds > 0 {
+        tokio::spawn(rotate_signing_key_periodically(
+            state.clone(),
+            config.signing_key_rotation_seconds,
+        ));
+    }
+
     let app = Router::<AppState>::new()
         .merge(route::router())
         .with_state(state)
@@ -43,10 +118,29 @@ async fn main() -> anyhow::Result<()> {
 
     let bind_addr = format!("{}:{}", config.host, config.port);
     info!("서버 시작: http://{}", bind_addr);
-    axum::serve(tokio::net::TcpListener::bind(&bind_addr).await?, app).await?;
+    axum::serve(
+        tokio::net::TcpListener::bind(&bind_addr).await?,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await?;
     Ok(())
 }
 
+/// 활성 서명 키가 교체 주기보다 오래되었는지 주기적으로 확인해 교체합니다.
+///
+/// 모든 인스턴스가 실행하지만 실제 교체는 잠금을 잡은 한 곳에서만 일어납니다.
+async fn rotate_signing_key_periodically(state: AppState, rotation_seconds: u64) {
+    let mut ticker = tokio::time::interval(Duration::from_secs(rotation_seconds.min(60 * 60)));
+    loop {
+        ticker.tick().await;
+        match state.rotate_signing_key_if_due(Some(rotation_seconds)).await {
+            Ok(Some(kid)) => info!("서명 키를 교체했습니다: {}", kid),
+            Ok(None) => {}
+            Err(e) => error!("서명 키 교체 실패: {:?}", e),
+        }
+    }
+}
+
 fn init_tracing(config: &Config) {
     if tracing::subscriber::set_global_default(
         tracing_subscriber::fmt()
@@ -0,0 +1,47 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use common::{
+    audit::{AuditAction, AuditContext},
+    auth::AdminUser,
+    directory::SessionRef,
+    errors::ApiError,
+    keys,
+    state::AppState,
+};
+use serde_json::json;
+
+/// 세션을 강제로 삭제하고 열려 있는 스트림을 끝냅니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    AdminUser(admin_id): AdminUser,
+    Path((org_id, session_id)): Path<(String, String)>,
+    audit: AuditContext,
+) -> Result<StatusCode, ApiError> {
+    keys::validate_org_id(&org_id)?;
+    keys::validate_session_id(&session_id)?;
+
+    let session_ref = SessionRef::org(&org_id, &session_id);
+    if !state.force_delete_session(&session_ref).await? {
+        return Err(ApiError::SessionNotFound(session_id));
+    }
+
+    tracing::warn!(
+        "Force-deleted org session: org={}, session_id={}, by={}",
+        org_id,
+        session_id,
+        admin_id
+    );
+    state
+        .audit(
+            audit
+                .event(AuditAction::AdminAction)
+                .with_actor(&admin_id)
+                .on_session(&session_ref)
+                .with_details(json!({ "operation": "delete_session" })),
+        )
+        .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,20 @@
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use common::{
+    audit::{AuditEvent, AuditQuery},
+    auth::AdminUser,
+    errors::ApiError,
+    state::AppState,
+    ListResponse,
+};
+
+/// 감사 로그를 최근 기록부터 조회합니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    AdminUser(_): AdminUser,
+    Query(query): Query<AuditQuery>,
+) -> Result<Json<ListResponse<AuditEvent>>, ApiError> {
+    Ok(Json(state.query_audit_log(&query).await?))
+}
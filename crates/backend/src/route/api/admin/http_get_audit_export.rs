@@ -0,0 +1,56 @@
+use axum::{
+    extract::{Query, State},
+    http::header,
+    response::{IntoResponse, Response},
+};
+use common::{
+    audit::{AuditEvent, AuditQuery},
+    auth::AdminUser,
+    errors::ApiError,
+    session_list::MAX_LIMIT,
+    state::AppState,
+};
+
+/// 조건에 맞는 감사 기록을 모두 JSON Lines로 내보냅니다.
+///
+/// `GET /api/admin/audit`과 같은 필터를 쓰며, 오래된 기록부터 한 줄에 하나씩 씁니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    AdminUser(admin_id): AdminUser,
+    Query(mut query): Query<AuditQuery>,
+) -> Result<Response, ApiError> {
+    query.limit = Some(MAX_LIMIT);
+    let mut events: Vec<AuditEvent> = Vec::new();
+    loop {
+        let page = state.query_audit_log(&query).await?;
+        events.extend(page.items);
+        match page.next_cursor {
+            Some(cursor) => query.cursor = Some(cursor),
+            None => break,
+        }
+    }
+
+    let mut body = String::new();
+    for event in events.iter().rev() {
+        body.push_str(&serde_json::to_string(event)?);
+        body.push('\n');
+    }
+
+    tracing::info!(
+        "Exported audit log: events={}, by={}",
+        events.len(),
+        admin_id
+    );
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/x-ndjson"),
+            (
+                header::CONTENT_DISPOSITION,
+                "attachment; filename=\"audit.jsonl\"",
+            ),
+        ],
+        body,
+    )
+        .into_response())
+}
@@ -0,0 +1,29 @@
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use common::{
+    admin::{AdminSessionInfo, AdminSessionQuery},
+    auth::AdminUser,
+    errors::ApiError,
+    state::AppState,
+    ListResponse,
+};
+
+/// 공용, 사용자, 조직 세션을 모두 검색합니다. 비공개 세션도 포함됩니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    AdminUser(_): AdminUser,
+    Query(query): Query<AdminSessionQuery>,
+) -> Result<Json<ListResponse<AdminSessionInfo>>, ApiError> {
+    let sessions = state
+        .list_all_session_entries()
+        .await?
+        .into_iter()
+        .map(AdminSessionInfo::from)
+        .collect();
+
+    let (items, next_cursor) = query.apply(sessions)?;
+
+    Ok(Json(ListResponse { items, next_cursor }))
+}
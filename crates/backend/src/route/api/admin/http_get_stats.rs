@@ -0,0 +1,17 @@
+use axum::{extract::State, Json};
+use common::{admin::SystemStats, auth::AdminUser, errors::ApiError, state::AppState};
+
+/// 계정, 조직, 세션 수와 현재 뷰어 수, 로그인 거절 횟수를 집계합니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    AdminUser(_): AdminUser,
+) -> Result<Json<SystemStats>, ApiError> {
+    let accounts = state.list_accounts().await?;
+    let sessions = state.list_all_session_entries().await?;
+    let orgs = state.count_orgs().await?;
+
+    Ok(Json(SystemStats {
+        login_rejections: state.login_rejection_stats().await?,
+        ..SystemStats::collect(&accounts, &sessions, orgs)
+    }))
+}
@@ -0,0 +1,29 @@
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use common::{
+    admin::{AdminUserInfo, AdminUserQuery},
+    auth::AdminUser,
+    errors::ApiError,
+    state::AppState,
+    ListResponse,
+};
+
+/// 모든 계정을 검색합니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    AdminUser(_): AdminUser,
+    Query(query): Query<AdminUserQuery>,
+) -> Result<Json<ListResponse<AdminUserInfo>>, ApiError> {
+    let users = state
+        .list_accounts()
+        .await?
+        .into_iter()
+        .map(AdminUserInfo::from)
+        .collect();
+
+    let (items, next_cursor) = query.apply(users)?;
+
+    Ok(Json(ListResponse { items, next_cursor }))
+}
@@ -0,0 +1,66 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use common::{
+    admin::{AdminUserInfo, AdminUserUpdate},
+    audit::{AuditAction, AuditContext},
+    auth::AdminUser,
+    errors::ApiError,
+    keys::validate_user_id,
+    state::AppState,
+};
+use serde_json::json;
+
+/// 계정의 관리자 역할과 비활성화 여부를 바꿉니다.
+///
+/// 관리자가 모두 사라지지 않도록 자기 계정은 바꿀 수 없습니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    AdminUser(admin_id): AdminUser,
+    Path(user_id): Path<String>,
+    audit: AuditContext,
+    Json(req): Json<AdminUserUpdate>,
+) -> Result<Json<AdminUserInfo>, ApiError> {
+    validate_user_id(&user_id)?;
+    if admin_id == user_id {
+        return Err(ApiError::InvalidAdminAction(
+            "자기 계정의 관리자 역할이나 활성 상태는 바꿀 수 없습니다".to_string(),
+        ));
+    }
+
+    let user_data = state
+        .update_account_status(&user_id, req.admin, req.disabled)
+        .await?;
+    let sessions = state.list_user_sessions(&user_id).await?.len() as u64;
+
+    tracing::info!(
+        "Updated account status: user={}, admin={}, disabled={}, by={}",
+        user_id,
+        user_data.admin,
+        user_data.is_disabled(),
+        admin_id
+    );
+    state
+        .audit(
+            audit
+                .event(AuditAction::AdminAction)
+                .with_actor(&admin_id)
+                .on_user(&user_id)
+                .with_details(json!({
+                    "operation": "update_account",
+                    "admin": user_data.admin,
+                    "disabled": user_data.is_disabled(),
+                })),
+        )
+        .await;
+
+    Ok(Json(AdminUserInfo {
+        user_id,
+        admin: user_data.admin,
+        disabled_at: user_data.disabled_at,
+        created_at: user_data.created_at,
+        last_login_at: user_data.last_login_at,
+        sessions,
+    }))
+}
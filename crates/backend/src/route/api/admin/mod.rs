@@ -0,0 +1,37 @@
+use axum::{
+    routing::{delete, get, put},
+    Router,
+};
+use common::state::AppState;
+
+mod http_delete_org_session;
+mod http_delete_public_session;
+mod http_delete_user_session;
+mod http_get_audit;
+mod http_get_audit_export;
+mod http_get_sessions;
+mod http_get_stats;
+mod http_get_users;
+mod http_put_user;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/stats", get(http_get_stats::handler))
+        .route("/audit", get(http_get_audit::handler))
+        .route("/audit/export", get(http_get_audit_export::handler))
+        .route("/user", get(http_get_users::handler))
+        .route("/user/{user_id}", put(http_put_user::handler))
+        .route("/session", get(http_get_sessions::handler))
+        .route(
+            "/session/public/{session_id}",
+            delete(http_delete_public_session::handler),
+        )
+        .route(
+            "/session/user/{user_id}/{session_id}",
+            delete(http_delete_user_session::handler),
+        )
+        .route(
+            "/session/org/{org_id}/{session_id}",
+            delete(http_delete_org_session::handler),
+        )
+}
@@ -0,0 +1,33 @@
+use axum::{extract::State, http::HeaderMap, http::StatusCode};
+use common::{
+    auth::AuthenticatedClaims,
+    auth_cookie::{self, SetCookies},
+    errors::ApiError,
+    state::AppState,
+};
+
+/// 쿠키 로그인을 끝내고 쿠키를 지웁니다.
+///
+/// access 토큰이 이미 만료되었어도 refresh 토큰 쿠키를 폐기하므로 브라우저에 로그인이 남지 않습니다.
+/// 모든 기기에서 로그아웃하려면 먼저 `POST /api/auth/logout`에 `all: true`를 보냅니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    claims: Result<AuthenticatedClaims, ApiError>,
+) -> Result<(SetCookies, StatusCode), ApiError> {
+    auth_cookie::verify_csrf(&headers)?;
+
+    if let Ok(AuthenticatedClaims(claims)) = &claims {
+        state.revoke_token(claims).await?;
+    }
+    if let Some(refresh_token) = auth_cookie::cookie_value(&headers, auth_cookie::REFRESH_COOKIE) {
+        if let Some(user_id) = state.revoke_refresh_token_family(refresh_token).await? {
+            tracing::info!("Logged out cookie login of user {}", user_id);
+        }
+    }
+
+    Ok((
+        state.share().cookie_policy().clear(),
+        StatusCode::NO_CONTENT,
+    ))
+}
@@ -0,0 +1,14 @@
+use axum::Json;
+use common::{auth::AuthenticatedClaims, auth_cookie::CookieSession};
+
+/// 현재 로그인 정보를 돌려줍니다.
+///
+/// 스크립트는 HttpOnly 쿠키의 토큰을 읽을 수 없으므로, 페이지를 새로 열 때 로그인 상태를 이것으로 확인합니다.
+pub async fn handler(AuthenticatedClaims(claims): AuthenticatedClaims) -> Json<CookieSession> {
+    let remaining = claims.exp as i64 - chrono::Utc::now().timestamp();
+    Json(CookieSession {
+        expires_in: remaining.max(0) as u64,
+        user_id: claims.sub,
+        admin: claims.admin,
+    })
+}
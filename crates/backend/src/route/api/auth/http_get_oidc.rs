@@ -0,0 +1,15 @@
+use axum::{extract::State, Json};
+use common::state::AppState;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct OidcStatusResponse {
+    /// SSO 로그인 버튼을 보여 줄지 여부
+    enabled: bool,
+}
+
+pub async fn handler(State(state): State<AppState>) -> Json<OidcStatusResponse> {
+    Json(OidcStatusResponse {
+        enabled: state.oidc().is_some(),
+    })
+}
@@ -0,0 +1,161 @@
+use axum::{
+    extract::{Query, State},
+    http::HeaderMap,
+    response::Redirect,
+};
+use common::{
+    audit::{AuditAction, AuditContext},
+    auth_cookie::{self, SetCookies},
+    errors::ApiError,
+    jwt, oidc,
+    state::AppState,
+};
+use serde::Deserialize;
+use serde_json::json;
+
+#[derive(Debug, Deserialize)]
+pub struct CallbackQuery {
+    #[serde(default)]
+    code: Option<String>,
+    #[serde(default)]
+    state: Option<String>,
+    /// 사용자가 거절하는 등 제공자가 로그인을 끝내지 못한 경우
+    #[serde(default)]
+    error: Option<String>,
+}
+
+/// 제공자에서 돌아온 브라우저를 로그인시키고 프론트엔드 콜백 페이지로 보냅니다.
+///
+/// 토큰은 `/auth/callback#token=...&refresh_token=...&expires_in=...`처럼 fragment로
+/// 전달되어 서버 로그나 Referer에 남지 않습니다. 실패하면 `#error={code}`로 보냅니다.
+/// `GET /api/auth/oidc/login?cookie=true`로 시작한 로그인은 토큰을 HttpOnly 쿠키로 내려 주고
+/// `#cookie=1&expires_in=...`로 보냅니다.
+///
+/// 로그인을 시작한 브라우저의 `state` 쿠키가 콜백의 `state`와 다르면 거절하고,
+/// 결과와 관계없이 `state` 쿠키는 지웁니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    audit: AuditContext,
+    headers: HeaderMap,
+    Query(query): Query<CallbackQuery>,
+) -> (SetCookies, Redirect) {
+    let (cookies, redirect) = finish(&state, &audit, &headers, query).await;
+    let cleared = state.share().cookie_policy().clear_oidc_state();
+    (cookies.merge(cleared), redirect)
+}
+
+async fn finish(
+    state: &AppState,
+    audit: &AuditContext,
+    headers: &HeaderMap,
+    query: CallbackQuery,
+) -> (SetCookies, Redirect) {
+    let (code, login_state) = match (query.code, query.state, query.error) {
+        (_, _, Some(error)) => {
+            tracing::warn!("SSO login returned error: {}", error);
+            return (
+                SetCookies::default(),
+                Redirect::to(&oidc::frontend_redirect(&[("error", &error)])),
+            );
+        }
+        (Some(code), Some(login_state), None) => (code, login_state),
+        _ => {
+            return (
+                SetCookies::default(),
+                Redirect::to(&oidc::frontend_redirect(&[("error", "invalid_request")])),
+            )
+        }
+    };
+
+    match login(state, audit, headers, &code, &login_state).await {
+        Ok(login) if login.cookie => {
+            let cookies = state.share().cookie_policy().login(
+                &login.token,
+                login.expires_in,
+                &login.refresh_token,
+                state.share().token_policy().refresh_ttl_seconds,
+            );
+            let redirect = oidc::frontend_redirect(&[
+                ("cookie", "1"),
+                ("expires_in", &login.expires_in.to_string()),
+            ]);
+            (cookies, Redirect::to(&redirect))
+        }
+        Ok(login) => (
+            SetCookies::default(),
+            Redirect::to(&oidc::frontend_redirect(&[
+                ("token", &login.token),
+                ("refresh_token", &login.refresh_token),
+                ("expires_in", &login.expires_in.to_string()),
+            ])),
+        ),
+        Err(e) => {
+            tracing::warn!("SSO login failed: {:?}", e);
+            let error = match e {
+                ApiError::UserExists(_) => "account_exists",
+                ApiError::AccountDisabled(_) => "account_disabled",
+                ApiError::InvalidUsername(_) | ApiError::InvalidUserId => "invalid_user_id",
+                _ => "login_failed",
+            };
+            state
+                .audit(
+                    audit
+                        .event(AuditAction::LoginFailed)
+                        .with_details(json!({ "method": "oidc", "reason": error })),
+                )
+                .await;
+            (
+                SetCookies::default(),
+                Redirect::to(&oidc::frontend_redirect(&[("error", error)])),
+            )
+        }
+    }
+}
+
+/// SSO 로그인으로 발급한 토큰.
+struct IssuedTokens {
+    token: String,
+    refresh_token: String,
+    expires_in: u64,
+    /// 쿠키로 내려 줄지 여부
+    cookie: bool,
+}
+
+async fn login(
+    state: &AppState,
+    audit: &AuditContext,
+    headers: &HeaderMap,
+    code: &str,
+    login_state: &str,
+) -> Result<IssuedTokens, ApiError> {
+    auth_cookie::verify_oidc_state(headers, login_state)?;
+    let login = state.complete_oidc_login(code, login_state).await?;
+    let (user_id, user_data) = (login.user_id, login.user_data);
+
+    let ttl = state.share().token_policy().access_ttl(None);
+    let account = state.account_claims(&user_id, &user_data).await?;
+    let signing_key = state.share().get_signing_key(state.redis_client()).await?;
+    let token = jwt::create_token(
+        &user_id,
+        user_data.token_generation,
+        account,
+        &signing_key,
+        ttl,
+    )?;
+    let refresh_token = state.issue_refresh_token(&user_id, &user_data).await?;
+    state
+        .audit(
+            audit
+                .event(AuditAction::TokenIssued)
+                .with_actor(&user_id)
+                .on_user(&user_id)
+                .with_details(json!({ "method": "oidc", "cookie": login.cookie })),
+        )
+        .await;
+    Ok(IssuedTokens {
+        token,
+        refresh_token,
+        expires_in: ttl,
+        cookie: login.cookie,
+    })
+}
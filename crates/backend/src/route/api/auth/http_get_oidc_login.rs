@@ -0,0 +1,25 @@
+use axum::{
+    extract::{Query, State},
+    response::Redirect,
+};
+use common::{auth_cookie::SetCookies, errors::ApiError, state::AppState};
+use serde::Deserialize;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct LoginQuery {
+    /// `true`면 로그인을 마친 뒤 토큰을 fragment 대신 HttpOnly 쿠키로 받습니다.
+    #[serde(default)]
+    cookie: bool,
+}
+
+/// SSO 로그인을 시작합니다. 브라우저를 제공자의 인가 페이지로 보냅니다.
+///
+/// 콜백이 이 브라우저로 돌아오는지 확인할 수 있도록 `state`를 HttpOnly 쿠키로 남깁니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    Query(query): Query<LoginQuery>,
+) -> Result<(SetCookies, Redirect), ApiError> {
+    let login = state.begin_oidc_login(query.cookie).await?;
+    let cookies = state.share().cookie_policy().oidc_state(&login.state);
+    Ok((cookies, Redirect::to(&login.url)))
+}
@@ -0,0 +1,61 @@
+use axum::{extract::State, Json};
+use common::{
+    audit::{AuditAction, AuditContext},
+    auth_cookie::{CookieSession, SetCookies},
+    errors::ApiError,
+    jwt,
+    state::AppState,
+};
+use serde_json::json;
+
+use super::http_post_token::{verify_password_login, TokenRequest};
+
+/// 비밀번호로 로그인하고 토큰을 HttpOnly 쿠키로 내려 줍니다.
+///
+/// 요청은 `POST /api/auth/token`과 같고, 응답 본문에는 토큰 대신 사용자 정보만 담습니다.
+/// 이후 상태 변경 요청에는 CSRF 쿠키 값을 `X-CSRF-Token` 헤더로 함께 보내야 합니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    audit: AuditContext,
+    Json(req): Json<TokenRequest>,
+) -> Result<(SetCookies, Json<CookieSession>), ApiError> {
+    let user_data = verify_password_login(&state, &audit, &req.user_id, &req.password).await?;
+    let policy = state.share().token_policy();
+    let ttl = policy.access_ttl(req.ttl_seconds);
+
+    let account = state.account_claims(&req.user_id, &user_data).await?;
+    let admin = account.admin;
+    let signing_key = state.share().get_signing_key(state.redis_client()).await?;
+    let token = jwt::create_token(
+        &req.user_id,
+        user_data.token_generation,
+        account,
+        &signing_key,
+        ttl,
+    )?;
+    let refresh_token = state.issue_refresh_token(&req.user_id, &user_data).await?;
+    state
+        .audit(
+            audit
+                .event(AuditAction::TokenIssued)
+                .with_actor(&req.user_id)
+                .on_user(&req.user_id)
+                .with_details(json!({ "method": "password", "cookie": true })),
+        )
+        .await;
+
+    let cookies = state.share().cookie_policy().login(
+        &token,
+        ttl,
+        &refresh_token,
+        policy.refresh_ttl_seconds,
+    );
+    Ok((
+        cookies,
+        Json(CookieSession {
+            user_id: req.user_id,
+            admin,
+            expires_in: ttl,
+        }),
+    ))
+}
@@ -0,0 +1,63 @@
+use axum::{extract::State, http::HeaderMap, Json};
+use common::{
+    audit::{AuditAction, AuditContext},
+    auth_cookie::{self, CookieSession, SetCookies},
+    errors::ApiError,
+    jwt,
+    state::AppState,
+};
+use serde_json::json;
+
+/// refresh 토큰 쿠키로 새 access 토큰을 발급하고 세 쿠키를 모두 교체합니다.
+///
+/// 쿠키만으로 동작하므로 CSRF 토큰이 필요합니다. 사용한 refresh 토큰은 `POST /api/auth/refresh`와
+/// 같이 교체되어 더 이상 쓸 수 없습니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    audit: AuditContext,
+    headers: HeaderMap,
+) -> Result<(SetCookies, Json<CookieSession>), ApiError> {
+    auth_cookie::verify_csrf(&headers)?;
+    let refresh_token = auth_cookie::cookie_value(&headers, auth_cookie::REFRESH_COOKIE)
+        .ok_or_else(|| ApiError::Unauthorized("Missing refresh token cookie".to_string()))?;
+    let rotated = state.rotate_refresh_token(refresh_token).await?;
+
+    let policy = state.share().token_policy();
+    let ttl = policy.access_ttl(None);
+    let account = state
+        .account_claims(&rotated.user_id, &rotated.user_data)
+        .await?;
+    let admin = account.admin;
+    let signing_key = state.share().get_signing_key(state.redis_client()).await?;
+    let token = jwt::create_token(
+        &rotated.user_id,
+        rotated.user_data.token_generation,
+        account,
+        &signing_key,
+        ttl,
+    )?;
+    state
+        .audit(
+            audit
+                .event(AuditAction::TokenIssued)
+                .with_actor(&rotated.user_id)
+                .on_user(&rotated.user_id)
+                .with_details(json!({ "method": "refresh", "cookie": true })),
+        )
+        .await;
+
+    let cookies = state.share().cookie_policy().login(
+        &token,
+        ttl,
+        &rotated.refresh_token,
+        policy.refresh_ttl_seconds,
+    );
+    Ok((
+        cookies,
+        Json(CookieSession {
+            user_id: rotated.user_id,
+            admin,
+            expires_in: ttl,
+        }),
+    ))
+}
@@ -0,0 +1,38 @@
+use axum::{extract::State, http::StatusCode, Json};
+use common::{auth::AuthenticatedClaims, errors::ApiError, state::AppState};
+use serde::Deserialize;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct LogoutRequest {
+    /// 함께 폐기할 refresh 토큰
+    #[serde(default)]
+    refresh_token: Option<String>,
+    /// `true`면 이 사용자의 모든 토큰을 폐기합니다 (모든 기기에서 로그아웃).
+    #[serde(default)]
+    all: bool,
+}
+
+/// 요청에 쓰인 access 토큰을 폐기합니다. 본문은 선택입니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    AuthenticatedClaims(claims): AuthenticatedClaims,
+    req: Option<Json<LogoutRequest>>,
+) -> Result<StatusCode, ApiError> {
+    let Json(req) = req.unwrap_or_default();
+
+    if req.all {
+        state.revoke_user_tokens(&claims.sub).await?;
+        tracing::info!("Logged out user {} everywhere", claims.sub);
+        return Ok(StatusCode::NO_CONTENT);
+    }
+
+    state.revoke_token(&claims).await?;
+    if let Some(refresh_token) = &req.refresh_token {
+        state
+            .revoke_refresh_token(&claims.sub, refresh_token)
+            .await?;
+    }
+    tracing::info!("Logged out user {}", claims.sub);
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,103 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    Json,
+};
+use common::{
+    audit::{AuditAction, AuditContext},
+    auth::AuthenticatedUser,
+    auth_cookie::{self, SetCookies},
+    errors::ApiError,
+    jwt,
+    state::AppState,
+    user_data::validate_password,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+pub struct PasswordChangeRequest {
+    old_password: String,
+    new_password: String,
+    #[serde(default)]
+    ttl_seconds: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PasswordChangeResponse {
+    token: String,
+    refresh_token: String,
+    expires_in: u64,
+}
+
+/// 비밀번호를 바꾸면 기존 토큰과 refresh 토큰은 모두 무효가 되므로 새 토큰을 함께 돌려줍니다.
+///
+/// 로그인 쿠키로 인증한 요청이면 새 토큰으로 쿠키도 교체합니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    AuthenticatedUser(user_id): AuthenticatedUser,
+    audit: AuditContext,
+    headers: HeaderMap,
+    Json(req): Json<PasswordChangeRequest>,
+) -> Result<(StatusCode, SetCookies, Json<PasswordChangeResponse>), ApiError> {
+    let mut user_data = state
+        .get_user_data(&user_id)
+        .await?
+        .ok_or_else(|| ApiError::Unauthorized("Account no longer exists".to_string()))?;
+
+    if !state
+        .verify_user_password(&user_data, &req.old_password)
+        .await?
+    {
+        tracing::warn!("Invalid current password for user {}", user_id);
+        return Err(ApiError::Forbidden(
+            "현재 비밀번호가 올바르지 않습니다".to_string(),
+        ));
+    }
+
+    validate_password(&user_id, &req.new_password)?;
+
+    state
+        .change_user_password(&user_id, &mut user_data, &req.new_password)
+        .await?;
+    tracing::info!("Changed password for user {}", user_id);
+    state
+        .audit(
+            audit
+                .event(AuditAction::PasswordChanged)
+                .with_actor(&user_id)
+                .on_user(&user_id),
+        )
+        .await;
+
+    let ttl = state.share().token_policy().access_ttl(req.ttl_seconds);
+    let account = state.account_claims(&user_id, &user_data).await?;
+    let signing_key = state.share().get_signing_key(state.redis_client()).await?;
+    let token = jwt::create_token(
+        &user_id,
+        user_data.token_generation,
+        account,
+        &signing_key,
+        ttl,
+    )?;
+    let refresh_token = state.issue_refresh_token(&user_id, &user_data).await?;
+    let cookies = if auth_cookie::uses_login_cookie(&headers) {
+        state.share().cookie_policy().login(
+            &token,
+            ttl,
+            &refresh_token,
+            state.share().token_policy().refresh_ttl_seconds,
+        )
+    } else {
+        SetCookies::default()
+    };
+
+    Ok((
+        StatusCode::OK,
+        cookies,
+        Json(PasswordChangeResponse {
+            token,
+            refresh_token,
+            expires_in: ttl,
+        }),
+    ))
+}
@@ -0,0 +1,68 @@
+use axum::{extract::State, http::StatusCode, Json};
+use common::{
+    audit::{AuditAction, AuditContext},
+    errors::ApiError,
+    jwt,
+    state::AppState,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Debug, Deserialize)]
+pub struct RefreshRequest {
+    refresh_token: String,
+    #[serde(default)]
+    ttl_seconds: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RefreshResponse {
+    token: String,
+    refresh_token: String,
+    expires_in: u64,
+}
+
+/// refresh 토큰으로 새 access 토큰을 발급합니다.
+///
+/// 사용한 refresh 토큰은 교체되어 더 이상 쓸 수 없고, 응답의 `refresh_token`을
+/// 다음 갱신에 사용해야 합니다.
+/// 새 토큰의 `orgs`, `admin` claim은 현재 조직 멤버십과 관리자 역할로 다시 채워지므로,
+/// 역할이 바뀌면 갱신해서 반영합니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    audit: AuditContext,
+    Json(req): Json<RefreshRequest>,
+) -> Result<(StatusCode, Json<RefreshResponse>), ApiError> {
+    let rotated = state.rotate_refresh_token(&req.refresh_token).await?;
+
+    let ttl = state.share().token_policy().access_ttl(req.ttl_seconds);
+    let account = state
+        .account_claims(&rotated.user_id, &rotated.user_data)
+        .await?;
+    let signing_key = state.share().get_signing_key(state.redis_client()).await?;
+    let token = jwt::create_token(
+        &rotated.user_id,
+        rotated.user_data.token_generation,
+        account,
+        &signing_key,
+        ttl,
+    )?;
+    state
+        .audit(
+            audit
+                .event(AuditAction::TokenIssued)
+                .with_actor(&rotated.user_id)
+                .on_user(&rotated.user_id)
+                .with_details(json!({ "method": "refresh" })),
+        )
+        .await;
+
+    Ok((
+        StatusCode::OK,
+        Json(RefreshResponse {
+            token,
+            refresh_token: rotated.refresh_token,
+            expires_in: ttl,
+        }),
+    ))
+}
@@ -0,0 +1,81 @@
+use axum::{extract::State, http::StatusCode, Json};
+use chrono::{DateTime, Utc};
+use common::{
+    audit::{AuditAction, AuditContext},
+    errors::ApiError,
+    keys,
+    state::AppState,
+    user_data::{validate_password, validate_username, UserData},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Debug, Deserialize)]
+pub struct RegisterRequest {
+    user_id: String,
+    password: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RegisterResponse {
+    user_id: String,
+    created_at: Option<DateTime<Utc>>,
+}
+
+/// 가입은 로그인과 같은 IP별 시도 제한을 받고, 성공과 실패 모두 감사 로그에 남습니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    audit: AuditContext,
+    Json(req): Json<RegisterRequest>,
+) -> Result<(StatusCode, Json<RegisterResponse>), ApiError> {
+    let user_data = match register(&state, &audit, &req).await {
+        Ok(user_data) => user_data,
+        Err(e) => {
+            let reason = match e {
+                ApiError::RateLimited(_) => "rate_limited",
+                ApiError::UserExists(_) => "user_exists",
+                ApiError::InvalidUsername(_) | ApiError::InvalidUserId => "invalid_user_id",
+                ApiError::WeakPassword(_) => "weak_password",
+                _ => "error",
+            };
+            let mut event = audit
+                .event(AuditAction::RegistrationFailed)
+                .with_details(json!({ "reason": reason }));
+            if keys::validate_user_id(&req.user_id).is_ok() {
+                event = event.on_user(&req.user_id);
+            }
+            state.audit(event).await;
+            return Err(e);
+        }
+    };
+    tracing::info!("Registered user {}", req.user_id);
+    state
+        .audit(
+            audit
+                .event(AuditAction::AccountRegistered)
+                .with_actor(&req.user_id)
+                .on_user(&req.user_id),
+        )
+        .await;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(RegisterResponse {
+            user_id: req.user_id,
+            created_at: user_data.created_at,
+        }),
+    ))
+}
+
+async fn register(
+    state: &AppState,
+    audit: &AuditContext,
+    req: &RegisterRequest,
+) -> Result<UserData, ApiError> {
+    validate_username(&req.user_id)?;
+    validate_password(&req.user_id, &req.password)?;
+
+    // Argon2 해시를 만들기 전에 확인해야 가입 요청으로 CPU를 쓰지 못합니다.
+    state.check_registration_attempt(audit.ip).await?;
+    state.register_user(&req.user_id, &req.password).await
+}
@@ -1,49 +1,124 @@
 use axum::{extract::State, http::StatusCode, Json};
-use common::{errors::ApiError, jwt, state::AppState};
+use common::{
+    audit::{AuditAction, AuditContext},
+    errors::ApiError,
+    jwt, keys,
+    state::AppState,
+    user_data::UserData,
+};
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 
 #[derive(Debug, Deserialize)]
 pub struct TokenRequest {
-    user_id: String,
-    password: String,
+    pub(super) user_id: String,
+    pub(super) password: String,
     #[serde(default)]
-    ttl_seconds: Option<u64>,
+    pub(super) ttl_seconds: Option<u64>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct TokenResponse {
     token: String,
+    refresh_token: String,
+    /// access 토큰 수명 (초)
+    expires_in: u64,
 }
 
 pub async fn handler(
     State(state): State<AppState>,
+    audit: AuditContext,
     Json(req): Json<TokenRequest>,
 ) -> Result<(StatusCode, Json<TokenResponse>), ApiError> {
-    if req.user_id.is_empty() {
-        return Err(ApiError::Unauthorized(
-            "user_id cannot be empty".to_string(),
-        ));
-    }
-    let ttl = req.ttl_seconds.unwrap_or(3600);
-    let user_data = state
-        .create_user_data(&req.user_id, &req.password, ttl)
-        .await?;
-
-    if !state
-        .verify_user_password(&user_data, &req.password)
-        .await?
-    {
-        return Err(ApiError::Unauthorized(
-            "Invalid credentials, 동일한 사용자명이 다른 사용자에 의해 점유되었을 수 있습니다."
-                .to_string(),
-        ));
-    }
+    let user_data = verify_password_login(&state, &audit, &req.user_id, &req.password).await?;
+    let ttl = state.share().token_policy().access_ttl(req.ttl_seconds);
 
+    let account = state.account_claims(&req.user_id, &user_data).await?;
+    let signing_key = state.share().get_signing_key(state.redis_client()).await?;
     let token = jwt::create_token(
         &req.user_id,
-        state.share().get_encoding_key(state.redis_client()).await?,
+        user_data.token_generation,
+        account,
+        &signing_key,
         ttl,
     )?;
+    let refresh_token = state.issue_refresh_token(&req.user_id, &user_data).await?;
+    state
+        .audit(
+            audit
+                .event(AuditAction::TokenIssued)
+                .with_actor(&req.user_id)
+                .on_user(&req.user_id)
+                .with_details(json!({ "method": "password" })),
+        )
+        .await;
+
+    Ok((
+        StatusCode::OK,
+        Json(TokenResponse {
+            token,
+            refresh_token,
+            expires_in: ttl,
+        }),
+    ))
+}
+
+/// 시도 횟수 제한과 잠금을 확인한 뒤 비밀번호를 검증하고 로그인을 기록합니다.
+///
+/// 실패하면 감사 기록을 남깁니다. 토큰 로그인과 쿠키 로그인이 함께 씁니다.
+pub(super) async fn verify_password_login(
+    state: &AppState,
+    audit: &AuditContext,
+    user_id: &str,
+    password: &str,
+) -> Result<UserData, ApiError> {
+    keys::validate_user_id(user_id)?;
+    let login_failed = |reason: &str| {
+        audit
+            .event(AuditAction::LoginFailed)
+            .on_user(user_id)
+            .with_details(json!({ "reason": reason }))
+    };
+
+    // Argon2 검증 전에 확인해야 대입 공격이 CPU를 쓰지 못합니다.
+    let checked = state.check_login_attempt(user_id, audit.ip).await;
+    if let Err(ApiError::RateLimited(_)) = checked {
+        state.audit(login_failed("rate_limited")).await;
+    }
+    checked?;
+
+    // 존재하지 않는 사용자와 비밀번호 불일치는 같은 응답을 돌려줍니다.
+    let invalid_credentials =
+        || ApiError::Unauthorized("사용자 ID 또는 비밀번호가 올바르지 않습니다".to_string());
+
+    // 없는 사용자도 같은 Argon2 검증을 거치고 실패를 세어, 응답 시간이나 잠금 여부로
+    // 계정 존재를 알 수 없게 합니다.
+    let Some(mut user_data) = state.get_user_data(user_id).await? else {
+        state.verify_dummy_password(password);
+        state.record_login_failure(user_id).await?;
+        state.audit(login_failed("invalid_credentials")).await;
+        return Err(invalid_credentials());
+    };
 
-    Ok((StatusCode::OK, Json(TokenResponse { token })))
+    if !state.verify_user_password(&user_data, password).await? {
+        tracing::warn!("Invalid password for user {}", user_id);
+        state.record_login_failure(user_id).await?;
+        state.audit(login_failed("invalid_credentials")).await;
+        return Err(invalid_credentials());
+    }
+    state.record_login_success(user_id).await?;
+    if user_data.is_disabled() {
+        tracing::warn!("Login attempt to disabled account {}", user_id);
+        state.audit(login_failed("account_disabled")).await;
+        return Err(ApiError::AccountDisabled(user_id.to_string()));
+    }
+
+    if state
+        .rehash_password_if_needed(user_id, &mut user_data, password)
+        .await?
+    {
+        tracing::info!("Upgraded password hash for user {}", user_id);
+    }
+    state.record_user_login(user_id, &mut user_data).await?;
+    Ok(user_data)
 }
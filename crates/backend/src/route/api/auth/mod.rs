@@ -1,8 +1,37 @@
-use axum::{routing::post, Router};
+use axum::{
+    routing::{get, post},
+    Router,
+};
 use common::state::AppState;
 
+mod http_delete_cookie;
+mod http_get_cookie;
+mod http_get_oidc;
+mod http_get_oidc_callback;
+mod http_get_oidc_login;
+mod http_post_cookie;
+mod http_post_cookie_refresh;
+mod http_post_logout;
+mod http_post_password;
+mod http_post_refresh;
+mod http_post_register;
 mod http_post_token;
 
 pub fn router() -> Router<AppState> {
-    Router::new().route("/token", post(http_post_token::handler))
+    Router::new()
+        .route(
+            "/cookie",
+            get(http_get_cookie::handler)
+                .post(http_post_cookie::handler)
+                .delete(http_delete_cookie::handler),
+        )
+        .route("/cookie/refresh", post(http_post_cookie_refresh::handler))
+        .route("/logout", post(http_post_logout::handler))
+        .route("/oidc", get(http_get_oidc::handler))
+        .route("/oidc/callback", get(http_get_oidc_callback::handler))
+        .route("/oidc/login", get(http_get_oidc_login::handler))
+        .route("/password", post(http_post_password::handler))
+        .route("/refresh", post(http_post_refresh::handler))
+        .route("/register", post(http_post_register::handler))
+        .route("/token", post(http_post_token::handler))
 }
@@ -0,0 +1,48 @@
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use common::{
+    directory::{DirectoryEntry, DirectoryQuery, MAX_SEARCHED_SESSIONS},
+    errors::ApiError,
+    state::{AppState, DirectorySessionEntry, IndexPage},
+    ListResponse,
+};
+
+/// 공개 디렉터리에 등록된 세션을 검색합니다 (인증 불필요).
+pub async fn handler(
+    State(state): State<AppState>,
+    Query(query): Query<DirectoryQuery>,
+) -> Result<Json<ListResponse<DirectoryEntry>>, ApiError> {
+    let to_item = |entry: DirectorySessionEntry| DirectoryEntry {
+        page_url: entry.session_ref.page_path(),
+        stream_url: entry.session_ref.stream_path(),
+        thumbnail_url: entry.session_ref.snapshot_path(),
+        user_id: entry.session_ref.user_id,
+        org_id: entry.session_ref.org_id,
+        session_id: entry.session_ref.session_id,
+        title: entry.session.title,
+        description: entry.session.description,
+        tags: entry.session.tags,
+        created_at: entry.session.created_at,
+        viewers: entry.viewers,
+    };
+
+    let (items, next_cursor) = if query.uses_index() {
+        let cursor = query.cursor()?;
+        let page = IndexPage {
+            after: cursor.as_ref(),
+            descending: true,
+            count: query.limit() + 1,
+        };
+        let items = state
+            .page_directory_entries(page, |entry| Some(to_item(entry)))
+            .await?;
+        query.page_from_index(items)
+    } else {
+        let entries = state.list_directory_entries(MAX_SEARCHED_SESSIONS).await?;
+        query.apply(entries.into_iter().map(to_item).collect())?
+    };
+
+    Ok(Json(ListResponse { items, next_cursor }))
+}
@@ -0,0 +1,8 @@
+use axum::routing::get;
+use common::state::AppState;
+
+pub mod http_get;
+
+pub fn router() -> axum::Router<AppState> {
+    axum::Router::<AppState>::new().route("/", get(http_get::handler))
+}
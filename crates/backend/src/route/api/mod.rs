@@ -1,13 +1,19 @@
 use axum::Router;
 use common::state::AppState;
 
+pub mod admin;
 pub mod auth;
+pub mod directory;
+pub mod org;
 pub mod session;
 pub mod user;
 
 pub fn router() -> Router<AppState> {
     axum::Router::new()
+        .nest("/admin", admin::router())
         .nest("/auth", auth::router())
+        .nest("/directory", directory::router())
+        .nest("/org", org::router())
         .nest("/session", session::router())
         .nest("/user", user::router())
 }
@@ -0,0 +1,43 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use common::{
+    audit::{AuditAction, AuditContext},
+    auth::AuthenticatedUser,
+    errors::ApiError,
+    state::AppState,
+};
+use serde_json::json;
+
+use super::load_org_as_owner;
+
+/// 조직과 조직 세션을 모두 삭제합니다. 소유자만 할 수 있습니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    AuthenticatedUser(user_id): AuthenticatedUser,
+    Path(org_id): Path<String>,
+    audit: AuditContext,
+) -> Result<StatusCode, ApiError> {
+    load_org_as_owner(&state, &user_id, &org_id).await?;
+
+    let deleted_sessions = state.delete_org(&org_id).await?;
+
+    tracing::info!(
+        "Deleted org: org_id={}, by={}, sessions={}",
+        org_id,
+        user_id,
+        deleted_sessions
+    );
+    state
+        .audit(
+            audit
+                .event(AuditAction::PermissionChanged)
+                .with_actor(&user_id)
+                .on_org(&org_id)
+                .with_details(json!({ "change": "org_deleted", "sessions": deleted_sessions })),
+        )
+        .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
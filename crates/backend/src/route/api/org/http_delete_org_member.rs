@@ -0,0 +1,58 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use common::{
+    audit::{AuditAction, AuditContext},
+    auth::AuthenticatedUser,
+    errors::ApiError,
+    org::OrgRole,
+    state::AppState,
+};
+use serde_json::json;
+
+use super::load_org_as;
+
+/// 조직 멤버를 제거합니다. 소유자는 누구든 제거할 수 있고, 멤버는 스스로 나갈 수 있습니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    AuthenticatedUser(user_id): AuthenticatedUser,
+    Path((org_id, member_id)): Path<(String, String)>,
+    audit: AuditContext,
+) -> Result<StatusCode, ApiError> {
+    let (_, role) = load_org_as(&state, &user_id, &org_id).await?;
+    if role != OrgRole::Owner && member_id != user_id {
+        tracing::warn!(
+            "User {} attempted to remove {} from org {}",
+            user_id,
+            member_id,
+            org_id
+        );
+        return Err(ApiError::Forbidden(format!(
+            "Only owners can remove other members of org {}",
+            org_id
+        )));
+    }
+
+    if !state.remove_org_member(&org_id, &member_id).await? {
+        return Err(ApiError::OrgMemberNotFound(member_id));
+    }
+
+    tracing::info!(
+        "Removed org member: org_id={}, member={}, by={}",
+        org_id,
+        member_id,
+        user_id
+    );
+    state
+        .audit(
+            audit
+                .event(AuditAction::PermissionChanged)
+                .with_actor(&user_id)
+                .on_org(&org_id)
+                .with_details(json!({ "change": "org_member_removed", "member": member_id })),
+        )
+        .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
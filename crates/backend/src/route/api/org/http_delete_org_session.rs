@@ -0,0 +1,42 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use common::{
+    audit::{AuditAction, AuditContext},
+    auth::ScopedUser,
+    directory::SessionRef,
+    errors::ApiError,
+    scope::SessionAccess,
+    state::AppState,
+};
+
+pub async fn handler(
+    State(state): State<AppState>,
+    user: ScopedUser,
+    Path((org_id, session_id)): Path<(String, String)>,
+    audit: AuditContext,
+) -> Result<StatusCode, ApiError> {
+    user.authorize_org(&org_id, SessionAccess::Write)?;
+
+    if !state.delete_org_session(&org_id, &session_id).await? {
+        return Err(ApiError::SessionNotFound(session_id));
+    }
+
+    tracing::info!(
+        "Deleted org session: org={}, session_id={}, by={}",
+        org_id,
+        session_id,
+        user.user_id
+    );
+    state
+        .audit(
+            audit
+                .event(AuditAction::SessionDeleted)
+                .with_actor(&user.user_id)
+                .on_session(&SessionRef::org(&org_id, &session_id)),
+        )
+        .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
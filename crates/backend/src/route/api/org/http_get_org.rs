@@ -0,0 +1,30 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use common::{
+    auth::AuthenticatedUser, errors::ApiError, state::AppState, OrgDetailInfo, OrgMemberInfo,
+};
+
+use super::load_org_as;
+
+/// 조직 정보와 멤버 목록. 멤버만 볼 수 있습니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    AuthenticatedUser(user_id): AuthenticatedUser,
+    Path(org_id): Path<String>,
+) -> Result<Json<OrgDetailInfo>, ApiError> {
+    let (org, role) = load_org_as(&state, &user_id, &org_id).await?;
+
+    Ok(Json(OrgDetailInfo {
+        org_id,
+        name: org.name,
+        role,
+        created_at: org.created_at,
+        members: org
+            .members
+            .into_iter()
+            .map(|(user_id, role)| OrgMemberInfo { user_id, role })
+            .collect(),
+    }))
+}
@@ -0,0 +1,35 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+use common::{
+    auth::ScopedUser, errors::ApiError, scope::SessionAccess, state::AppState, SessionDetailInfo,
+};
+
+pub async fn handler(
+    Path((org_id, session_id)): Path<(String, String)>,
+    user: ScopedUser,
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse, ApiError> {
+    let org_role = user.authorize_org(&org_id, SessionAccess::Read)?;
+
+    let session_data = state
+        .get_org_session(&org_id, &session_id)
+        .await?
+        .ok_or(ApiError::SessionNotFound(session_id.clone()))?;
+
+    Ok(Json(SessionDetailInfo {
+        session_id,
+        template: session_data.template,
+        args: session_data.args,
+        title: session_data.title,
+        description: session_data.description,
+        tags: session_data.tags,
+        listed: session_data.listed,
+        visibility: session_data.visibility,
+        role: None,
+        org_role: Some(org_role),
+        pending_transfer: None,
+    }))
+}
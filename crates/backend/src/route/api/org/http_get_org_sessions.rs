@@ -0,0 +1,50 @@
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use common::{
+    auth::ScopedUser,
+    errors::ApiError,
+    scope::SessionAccess,
+    session_list::SessionListQuery,
+    state::{AppState, IndexPage},
+    ListResponse, SessionSummary,
+};
+
+/// 생성 시각 정렬(기본값)은 세션 인덱스에서 한 페이지만 읽고, 다른 정렬은 모든 세션을 읽어 정렬하므로
+/// 세션이 [`MAX_SORTED_SESSIONS`](common::session_list::MAX_SORTED_SESSIONS)개를 넘으면 400입니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    user: ScopedUser,
+    Path(org_id): Path<String>,
+    Query(query): Query<SessionListQuery>,
+) -> Result<Json<ListResponse<SessionSummary>>, ApiError> {
+    user.authorize_org(&org_id, SessionAccess::Read)?;
+
+    let (items, next_cursor) = if query.uses_index() {
+        let cursor = query.cursor()?;
+        let matches = query.matcher()?;
+        let page = IndexPage {
+            after: cursor.as_ref(),
+            descending: query.descending(),
+            count: query.limit() + 1,
+        };
+        let items = state
+            .page_org_session_entries(&org_id, page, |entry| {
+                Some(SessionSummary::from_org_entry(&org_id, entry))
+                    .filter(|summary| matches(summary))
+            })
+            .await?;
+        query.page_from_index(items)
+    } else {
+        query.check_sortable(state.count_org_sessions(&org_id).await?)?;
+        let entries = state.list_org_session_entries(&org_id).await?;
+        let summaries = entries
+            .into_iter()
+            .map(|entry| SessionSummary::from_org_entry(&org_id, entry))
+            .collect();
+        query.apply(summaries)?
+    };
+
+    Ok(Json(ListResponse { items, next_cursor }))
+}
@@ -0,0 +1,53 @@
+use axum::{extract::State, http::StatusCode, Json};
+use common::{
+    audit::{AuditAction, AuditContext},
+    auth::AuthenticatedUser,
+    errors::ApiError,
+    keys,
+    org::OrgData,
+    state::AppState,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateOrgRequest {
+    org_id: String,
+    name: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateOrgResponse {
+    org_id: String,
+}
+
+/// 조직을 만들고 요청한 사용자를 소유자로 등록합니다.
+///
+/// 지금 쓰는 토큰에는 새 조직이 들어 있지 않으므로, 조직 세션을 쓰려면 토큰을 갱신해야 합니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    AuthenticatedUser(user_id): AuthenticatedUser,
+    audit: AuditContext,
+    Json(req): Json<CreateOrgRequest>,
+) -> Result<(StatusCode, Json<CreateOrgResponse>), ApiError> {
+    keys::validate_org_id(&req.org_id)?;
+    let org = OrgData::new(&req.name, user_id.clone())?;
+
+    state.create_org(&req.org_id, &org).await?;
+
+    tracing::info!("Created org: org_id={}, owner={}", req.org_id, user_id);
+    state
+        .audit(
+            audit
+                .event(AuditAction::PermissionChanged)
+                .with_actor(&user_id)
+                .on_org(&req.org_id)
+                .with_details(json!({ "change": "org_created" })),
+        )
+        .await;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(CreateOrgResponse { org_id: req.org_id }),
+    ))
+}
@@ -0,0 +1,86 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use common::{
+    audit::{AuditAction, AuditContext},
+    auth::ScopedUser,
+    directory::SessionRef,
+    errors::ApiError,
+    keys,
+    scope::SessionAccess,
+    session_data::{SessionData, SessionMetadataPatch},
+    state::AppState,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+pub struct CreateSessionRequest {
+    session_id: String,
+    template: String,
+    args: std::collections::HashMap<String, serde_json::Value>,
+    #[serde(default = "default_ttl")]
+    ttl_seconds: u64,
+    #[serde(flatten)]
+    metadata: SessionMetadataPatch,
+}
+
+fn default_ttl() -> u64 {
+    3600
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateSessionResponse {
+    org_id: String,
+    session_id: String,
+}
+
+/// 조직 세션을 만듭니다. 조직 세션은 특정 사용자가 소유하지 않으므로 공개로만 둘 수 있습니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    user: ScopedUser,
+    Path(org_id): Path<String>,
+    audit: AuditContext,
+    Json(req): Json<CreateSessionRequest>,
+) -> Result<(StatusCode, Json<CreateSessionResponse>), ApiError> {
+    user.authorize_org(&org_id, SessionAccess::Write)?;
+
+    keys::validate_session_id(&req.session_id)?;
+    // 토큰이 발급된 뒤 조직이 삭제되었을 수 있습니다.
+    if state.get_org(&org_id).await?.is_none() {
+        return Err(ApiError::OrgNotFound(org_id));
+    }
+    let session_ref = SessionRef::org(&org_id, &req.session_id);
+    state.check_session_quota(&session_ref).await?;
+
+    let mut session = SessionData::new(req.template, req.args);
+    session.apply_metadata(req.metadata)?;
+
+    state
+        .create_org_session(&org_id, &req.session_id, &session, req.ttl_seconds)
+        .await?;
+
+    tracing::info!(
+        "Created org session: org={}, session_id={}, by={}",
+        org_id,
+        req.session_id,
+        user.user_id
+    );
+    state
+        .audit(
+            audit
+                .event(AuditAction::SessionCreated)
+                .with_actor(&user.user_id)
+                .on_session(&session_ref),
+        )
+        .await;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(CreateSessionResponse {
+            org_id,
+            session_id: req.session_id,
+        }),
+    ))
+}
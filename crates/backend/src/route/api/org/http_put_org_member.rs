@@ -0,0 +1,61 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use common::{
+    audit::{AuditAction, AuditContext},
+    auth::AuthenticatedUser,
+    errors::ApiError,
+    keys,
+    org::OrgRole,
+    state::AppState,
+};
+use serde::Deserialize;
+use serde_json::json;
+
+use super::load_org_as_owner;
+
+#[derive(Debug, Deserialize)]
+pub struct SetMemberRequest {
+    role: OrgRole,
+}
+
+/// 조직 멤버를 추가하거나 역할을 바꿉니다. 소유자만 할 수 있습니다.
+///
+/// 바뀐 역할은 그 멤버가 토큰을 새로 받은 뒤부터 조직 세션 요청에 반영됩니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    AuthenticatedUser(user_id): AuthenticatedUser,
+    Path((org_id, member_id)): Path<(String, String)>,
+    audit: AuditContext,
+    Json(req): Json<SetMemberRequest>,
+) -> Result<StatusCode, ApiError> {
+    load_org_as_owner(&state, &user_id, &org_id).await?;
+    keys::validate_user_id(&member_id)?;
+
+    state.set_org_member(&org_id, &member_id, req.role).await?;
+
+    tracing::info!(
+        "Set org member: org_id={}, member={}, role={}, by={}",
+        org_id,
+        member_id,
+        req.role.as_str(),
+        user_id
+    );
+    state
+        .audit(
+            audit
+                .event(AuditAction::PermissionChanged)
+                .with_actor(&user_id)
+                .on_org(&org_id)
+                .with_details(json!({
+                    "change": "org_member_set",
+                    "member": member_id,
+                    "role": req.role,
+                })),
+        )
+        .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
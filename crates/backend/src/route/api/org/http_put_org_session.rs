@@ -0,0 +1,67 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use common::{
+    audit::{AuditAction, AuditContext},
+    auth::ScopedUser,
+    directory::SessionRef,
+    errors::ApiError,
+    scope::SessionAccess,
+    session_data::SessionMetadataPatch,
+    state::AppState,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateSessionRequest {
+    #[serde(default)]
+    args: Option<std::collections::HashMap<String, serde_json::Value>>,
+    #[serde(flatten)]
+    metadata: SessionMetadataPatch,
+}
+
+pub async fn handler(
+    State(state): State<AppState>,
+    user: ScopedUser,
+    Path((org_id, session_id)): Path<(String, String)>,
+    audit: AuditContext,
+    axum::Json(req): axum::Json<UpdateSessionRequest>,
+) -> Result<StatusCode, ApiError> {
+    user.authorize_org(&org_id, SessionAccess::Write)?;
+
+    state
+        .get_org_session(&org_id, &session_id)
+        .await?
+        .ok_or_else(|| ApiError::SessionNotFound(session_id.clone()))?;
+    let session_ref = SessionRef::org(&org_id, &session_id);
+    state.check_session_update_rate(&session_ref).await?;
+    let event = audit
+        .event(AuditAction::SessionUpdated)
+        .with_actor(&user.user_id)
+        .on_session(&session_ref)
+        .with_session_changes(req.args.is_some(), &req.metadata);
+
+    let ttl_seconds = 3600;
+    state
+        .update_org_session(&org_id, &session_id, ttl_seconds, |session| {
+            if let Some(args) = &req.args {
+                session.replace_args(args.clone());
+            }
+            session.apply_metadata(req.metadata.clone())?;
+            session.touch();
+            Ok(())
+        })
+        .await?
+        .ok_or_else(|| ApiError::SessionNotFound(session_id.clone()))?;
+
+    tracing::info!(
+        "Updated org session: org={}, session_id={}, by={}",
+        org_id,
+        session_id,
+        user.user_id
+    );
+    state.audit(event).await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,82 @@
+use axum::{
+    routing::{get, post, put},
+    Router,
+};
+use common::{
+    errors::ApiError,
+    org::{OrgData, OrgRole},
+    state::AppState,
+};
+
+mod http_delete_org;
+mod http_delete_org_member;
+mod http_delete_org_session;
+mod http_get_org;
+mod http_get_org_session_id;
+mod http_get_org_sessions;
+mod http_post_org;
+mod http_post_org_session;
+mod http_put_org_member;
+mod http_put_org_session;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", post(http_post_org::handler))
+        .route(
+            "/{org_id}",
+            get(http_get_org::handler).delete(http_delete_org::handler),
+        )
+        .route(
+            "/{org_id}/member/{user_id}",
+            put(http_put_org_member::handler).delete(http_delete_org_member::handler),
+        )
+        .route(
+            "/{org_id}/session",
+            get(http_get_org_sessions::handler).post(http_post_org_session::handler),
+        )
+        .route(
+            "/{org_id}/session/{session_id}",
+            get(http_get_org_session_id::handler)
+                .put(http_put_org_session::handler)
+                .delete(http_delete_org_session::handler),
+        )
+}
+
+/// 조직 관리 요청을 위해 저장된 멤버 목록으로 `user_id`의 역할을 확인합니다.
+///
+/// 세션 요청과 달리 토큰의 `orgs` claim을 쓰지 않으므로 역할 변경이 바로 반영됩니다.
+/// 멤버가 아니거나 조직이 없으면 조직 ID가 드러나지 않도록 모두 403입니다.
+async fn load_org_as(
+    state: &AppState,
+    user_id: &str,
+    org_id: &str,
+) -> Result<(OrgData, OrgRole), ApiError> {
+    let org = state.get_org(org_id).await?;
+    match org.and_then(|org| Some((org.role_of(user_id)?, org))) {
+        Some((role, org)) => Ok((org, role)),
+        None => {
+            tracing::warn!("User {} is not a member of org {}", user_id, org_id);
+            Err(ApiError::Forbidden(format!(
+                "User {} is not a member of org {}",
+                user_id, org_id
+            )))
+        }
+    }
+}
+
+/// [`load_org_as`]에 더해 소유자인지 확인합니다.
+async fn load_org_as_owner(
+    state: &AppState,
+    user_id: &str,
+    org_id: &str,
+) -> Result<OrgData, ApiError> {
+    let (org, role) = load_org_as(state, user_id, org_id).await?;
+    if role != OrgRole::Owner {
+        tracing::warn!("User {} attempted to manage org {}", user_id, org_id);
+        return Err(ApiError::Forbidden(format!(
+            "Only owners can manage org {}",
+            org_id
+        )));
+    }
+    Ok(org)
+}
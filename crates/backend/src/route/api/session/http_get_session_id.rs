@@ -18,5 +18,13 @@ pub async fn handler(
         session_id,
         template: session_data.template,
         args: session_data.args,
+        title: session_data.title,
+        description: session_data.description,
+        tags: session_data.tags,
+        listed: session_data.listed,
+        visibility: session_data.visibility,
+        role: None,
+        org_role: None,
+        pending_transfer: None,
     }))
 }
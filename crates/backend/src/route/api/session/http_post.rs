@@ -1,8 +1,15 @@
 use std::collections::HashMap;
 
 use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
-use common::{errors::ApiError, session_data::SessionData, state::AppState, SessionInfo};
-use redis::AsyncTypedCommands;
+use common::{
+    audit::{AuditAction, AuditContext},
+    directory::SessionRef,
+    errors::ApiError,
+    keys,
+    session_data::{SessionData, SessionMetadataPatch},
+    state::AppState,
+    SessionInfo,
+};
 use serde::{Deserialize, Serialize};
 
 /// 세션 생성 요청 페이로드.
@@ -12,29 +19,37 @@ pub struct Body {
     pub template: String,
     pub args: Option<HashMap<String, serde_json::Value>>,
     pub expire: Option<String>,
+    #[serde(flatten)]
+    pub metadata: SessionMetadataPatch,
 }
 
 pub async fn handler(
     State(state): State<AppState>,
+    audit: AuditContext,
     Json(body): Json<Body>,
 ) -> Result<impl IntoResponse, ApiError> {
-    let session_id = body.session_id.trim();
-    if session_id.is_empty() {
-        return Err(ApiError::InvalidSessionId);
-    }
-    let session_id = session_id.to_string();
+    let session_id = body.session_id.trim().to_string();
+    keys::validate_session_id(&session_id)?;
     let expire = body.expire.clone().unwrap_or("1d".to_string());
     let ttl = humantime::parse_duration(&expire)?;
 
-    let mut conn = state.connection_redis().await?;
-    if conn.exists(&session_id).await? {
+    if state.session_exists(&session_id).await? {
         return Err(ApiError::SessionExists(session_id));
     }
-    let session = SessionData::new(body.template.clone(), body.args.clone().unwrap_or_default());
+    let mut session =
+        SessionData::new(body.template.clone(), body.args.clone().unwrap_or_default());
+    session.apply_metadata(body.metadata.clone())?;
 
     state
         .set_session(&session_id, &session, ttl.as_secs())
         .await?;
+    state
+        .audit(
+            audit
+                .event(AuditAction::SessionCreated)
+                .on_session(&SessionRef::public(&session_id)),
+        )
+        .await;
 
     Ok((
         StatusCode::CREATED,
@@ -5,26 +5,52 @@ use axum::{
     response::IntoResponse,
     Json,
 };
-use common::{errors::ApiError, state::AppState, SessionInfo};
+use common::{
+    audit::{AuditAction, AuditContext},
+    directory::SessionRef,
+    errors::ApiError,
+    session_data::SessionMetadataPatch,
+    state::AppState,
+    SessionInfo,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 pub struct Body {
-    pub args: HashMap<String, serde_json::Value>,
+    #[serde(default)]
+    pub args: Option<HashMap<String, serde_json::Value>>,
+    #[serde(flatten)]
+    pub metadata: SessionMetadataPatch,
 }
 
 pub async fn handler(
     Path(session_id): Path<String>,
     State(state): State<AppState>,
+    audit: AuditContext,
     Json(body): Json<Body>,
 ) -> Result<impl IntoResponse, ApiError> {
-    let mut session_data = state
+    state
         .get_session(&session_id)
         .await?
         .ok_or(ApiError::SessionNotFound(session_id.clone()))?;
-    session_data.replace_args(body.args);
-
-    state.set_session(&session_id, &session_data, 3600).await?;
+    let session_ref = SessionRef::public(&session_id);
+    state.check_session_update_rate(&session_ref).await?;
+    let event = audit
+        .event(AuditAction::SessionUpdated)
+        .on_session(&session_ref)
+        .with_session_changes(body.args.is_some(), &body.metadata);
+    state
+        .update_public_session(&session_id, 3600, |session_data| {
+            if let Some(args) = &body.args {
+                session_data.replace_args(args.clone());
+            }
+            session_data.apply_metadata(body.metadata.clone())?;
+            session_data.touch();
+            Ok(())
+        })
+        .await?
+        .ok_or(ApiError::SessionNotFound(session_id.clone()))?;
+    state.audit(event).await;
 
     Ok(Json(SessionInfo { session_id }))
 }
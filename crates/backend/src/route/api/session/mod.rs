@@ -2,6 +2,7 @@ use axum::routing::{get, post, put};
 use common::state::AppState;
 
 pub mod http_get_session_id;
+pub mod http_get_session_id_snapshot;
 pub mod http_post;
 pub mod http_put_session_id;
 
@@ -10,4 +11,8 @@ pub fn router() -> axum::Router<AppState> {
         .route("/", post(http_post::handler))
         .route("/{session_id}", get(http_get_session_id::handler))
         .route("/{session_id}", put(http_put_session_id::handler))
+        .route(
+            "/{session_id}/snapshot",
+            get(http_get_session_id_snapshot::handler),
+        )
 }
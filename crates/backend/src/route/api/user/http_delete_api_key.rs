@@ -0,0 +1,47 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use common::{
+    audit::{AuditAction, AuditContext},
+    auth::AuthenticatedClaims,
+    errors::ApiError,
+    state::AppState,
+};
+use serde_json::json;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    AuthenticatedClaims(claims): AuthenticatedClaims,
+    Path((user_id, key_id)): Path<(String, String)>,
+    audit: AuditContext,
+) -> Result<StatusCode, ApiError> {
+    if claims.sub != user_id {
+        tracing::warn!(
+            "User {} attempted to revoke API key of user {}",
+            claims.sub,
+            user_id
+        );
+        return Err(ApiError::Forbidden(format!(
+            "User {} cannot revoke API keys of user {}",
+            claims.sub, user_id
+        )));
+    }
+
+    if !state.revoke_api_key(&user_id, &key_id).await? {
+        return Err(ApiError::ApiKeyNotFound(key_id));
+    }
+
+    tracing::info!("Revoked API key: user={}, key_id={}", user_id, key_id);
+    state
+        .audit(
+            audit
+                .event(AuditAction::PermissionChanged)
+                .with_actor(&user_id)
+                .on_user(&user_id)
+                .with_details(json!({ "change": "api_key_revoked", "key_id": key_id })),
+        )
+        .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,59 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use common::{
+    audit::{AuditAction, AuditContext},
+    auth::AuthenticatedUser,
+    directory::SessionRef,
+    errors::ApiError,
+    state::AppState,
+};
+use serde_json::json;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    AuthenticatedUser(user_id_from_token): AuthenticatedUser,
+    Path((user_id, session_id, collaborator_id)): Path<(String, String, String)>,
+    audit: AuditContext,
+) -> Result<StatusCode, ApiError> {
+    if user_id_from_token != user_id {
+        tracing::warn!(
+            "User {} attempted to remove collaborator of user {}",
+            user_id_from_token,
+            user_id
+        );
+        return Err(ApiError::Forbidden(format!(
+            "User {} cannot remove collaborators of user {}",
+            user_id_from_token, user_id
+        )));
+    }
+
+    if !state
+        .remove_session_collaborator(&user_id, &session_id, &collaborator_id)
+        .await?
+    {
+        return Err(ApiError::CollaboratorNotFound(collaborator_id));
+    }
+
+    tracing::info!(
+        "Removed collaborator: user={}, session_id={}, collaborator={}",
+        user_id,
+        session_id,
+        collaborator_id
+    );
+    state
+        .audit(
+            audit
+                .event(AuditAction::PermissionChanged)
+                .with_actor(&user_id)
+                .on_session(&SessionRef::user(&user_id, &session_id))
+                .with_details(json!({
+                    "change": "collaborator_removed",
+                    "collaborator": collaborator_id,
+                })),
+        )
+        .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,43 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use common::{auth::AuthenticatedUser, errors::ApiError, state::AppState};
+
+/// 받은 소유권 이전 요청을 거절합니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    AuthenticatedUser(user_id_from_token): AuthenticatedUser,
+    Path((user_id, owner, session_id)): Path<(String, String, String)>,
+) -> Result<StatusCode, ApiError> {
+    if user_id_from_token != user_id {
+        tracing::warn!(
+            "User {} attempted to decline session transfer for user {}",
+            user_id_from_token,
+            user_id
+        );
+        return Err(ApiError::Forbidden(format!(
+            "User {} cannot decline session transfers for user {}",
+            user_id_from_token, user_id
+        )));
+    }
+
+    if !state
+        .cancel_session_transfer(&owner, &session_id, Some(&user_id))
+        .await?
+    {
+        return Err(ApiError::TransferNotFound(format!(
+            "{}/{}",
+            owner, session_id
+        )));
+    }
+
+    tracing::info!(
+        "Declined session transfer: from={}, to={}, session_id={}",
+        owner,
+        user_id,
+        session_id
+    );
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,42 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use common::{
+    audit::{AuditAction, AuditContext},
+    auth::ScopedUser,
+    directory::SessionRef,
+    errors::ApiError,
+    scope::SessionAccess,
+    state::AppState,
+};
+
+pub async fn handler(
+    State(state): State<AppState>,
+    user: ScopedUser,
+    Path((user_id, session_id)): Path<(String, String)>,
+    audit: AuditContext,
+) -> Result<StatusCode, ApiError> {
+    user.authorize_session(&user_id, Some(&session_id), SessionAccess::Write)?;
+    user.require_unscoped()?;
+
+    if !state.delete_user_session(&user_id, &session_id).await? {
+        return Err(ApiError::SessionNotFound(session_id));
+    }
+
+    tracing::info!(
+        "Deleted user session: user={}, session_id={}",
+        user_id,
+        session_id
+    );
+    state
+        .audit(
+            audit
+                .event(AuditAction::SessionDeleted)
+                .with_actor(&user.user_id)
+                .on_session(&SessionRef::user(&user_id, &session_id)),
+        )
+        .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,56 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use common::{
+    audit::{AuditAction, AuditContext},
+    auth::AuthenticatedUser,
+    directory::SessionRef,
+    errors::ApiError,
+    state::AppState,
+};
+use serde_json::json;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    AuthenticatedUser(user_id_from_token): AuthenticatedUser,
+    Path((user_id, session_id, link_id)): Path<(String, String, String)>,
+    audit: AuditContext,
+) -> Result<StatusCode, ApiError> {
+    if user_id_from_token != user_id {
+        tracing::warn!(
+            "User {} attempted to revoke share link of user {}",
+            user_id_from_token,
+            user_id
+        );
+        return Err(ApiError::Forbidden(format!(
+            "User {} cannot revoke share links of user {}",
+            user_id_from_token, user_id
+        )));
+    }
+
+    if !state
+        .revoke_share_link(&user_id, &session_id, &link_id)
+        .await?
+    {
+        return Err(ApiError::ShareLinkNotFound(link_id));
+    }
+
+    tracing::info!(
+        "Revoked share link: user={}, session_id={}, link_id={}",
+        user_id,
+        session_id,
+        link_id
+    );
+    state
+        .audit(
+            audit
+                .event(AuditAction::PermissionChanged)
+                .with_actor(&user_id)
+                .on_session(&SessionRef::user(&user_id, &session_id))
+                .with_details(json!({ "change": "share_link_revoked", "link_id": link_id })),
+        )
+        .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,42 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use common::{auth::AuthenticatedUser, errors::ApiError, state::AppState};
+
+/// 보낸 소유권 이전 요청을 취소합니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    AuthenticatedUser(user_id_from_token): AuthenticatedUser,
+    Path((user_id, session_id)): Path<(String, String)>,
+) -> Result<StatusCode, ApiError> {
+    if user_id_from_token != user_id {
+        tracing::warn!(
+            "User {} attempted to cancel session transfer of user {}",
+            user_id_from_token,
+            user_id
+        );
+        return Err(ApiError::Forbidden(format!(
+            "User {} cannot cancel session transfers of user {}",
+            user_id_from_token, user_id
+        )));
+    }
+
+    if !state
+        .cancel_session_transfer(&user_id, &session_id, None)
+        .await?
+    {
+        return Err(ApiError::TransferNotFound(format!(
+            "{}/{}",
+            user_id, session_id
+        )));
+    }
+
+    tracing::info!(
+        "Cancelled session transfer: user={}, session_id={}",
+        user_id,
+        session_id
+    );
+
+    Ok(StatusCode::NO_CONTENT)
+}
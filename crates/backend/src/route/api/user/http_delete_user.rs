@@ -0,0 +1,49 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use common::{
+    audit::{AuditAction, AuditContext},
+    auth::AuthenticatedUser,
+    errors::ApiError,
+    state::AppState,
+};
+use serde_json::json;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    AuthenticatedUser(user_id_from_token): AuthenticatedUser,
+    Path(user_id): Path<String>,
+    audit: AuditContext,
+) -> Result<StatusCode, ApiError> {
+    if user_id_from_token != user_id {
+        tracing::warn!(
+            "User {} attempted to delete account of user {}",
+            user_id_from_token,
+            user_id
+        );
+        return Err(ApiError::Forbidden(format!(
+            "User {} cannot delete account of user {}",
+            user_id_from_token, user_id
+        )));
+    }
+
+    let deleted_sessions = state.delete_user(&user_id).await?;
+
+    tracing::info!(
+        "Deleted account: user={}, sessions={}",
+        user_id,
+        deleted_sessions
+    );
+    state
+        .audit(
+            audit
+                .event(AuditAction::AccountDeleted)
+                .with_actor(&user_id)
+                .on_user(&user_id)
+                .with_details(json!({ "sessions": deleted_sessions })),
+        )
+        .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
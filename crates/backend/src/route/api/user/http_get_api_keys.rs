@@ -0,0 +1,37 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use common::{
+    api_key::ApiKeyInfo, auth::AuthenticatedClaims, errors::ApiError, state::AppState, ListResponse,
+};
+
+pub async fn handler(
+    State(state): State<AppState>,
+    AuthenticatedClaims(claims): AuthenticatedClaims,
+    Path(user_id): Path<String>,
+) -> Result<Json<ListResponse<ApiKeyInfo>>, ApiError> {
+    if claims.sub != user_id {
+        tracing::warn!(
+            "User {} attempted to list API keys of user {}",
+            claims.sub,
+            user_id
+        );
+        return Err(ApiError::Forbidden(format!(
+            "User {} cannot list API keys of user {}",
+            claims.sub, user_id
+        )));
+    }
+
+    let items = state
+        .list_api_keys(&user_id)
+        .await?
+        .into_iter()
+        .map(ApiKeyInfo::from)
+        .collect();
+
+    Ok(Json(ListResponse {
+        items,
+        next_cursor: None,
+    }))
+}
@@ -0,0 +1,41 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use common::{
+    auth::AuthenticatedUser, errors::ApiError, state::AppState, CollaboratorInfo, ListResponse,
+};
+
+pub async fn handler(
+    State(state): State<AppState>,
+    AuthenticatedUser(user_id_from_token): AuthenticatedUser,
+    Path((user_id, session_id)): Path<(String, String)>,
+) -> Result<Json<ListResponse<CollaboratorInfo>>, ApiError> {
+    if user_id_from_token != user_id {
+        tracing::warn!(
+            "User {} attempted to list collaborators of user {}",
+            user_id_from_token,
+            user_id
+        );
+        return Err(ApiError::Forbidden(format!(
+            "User {} cannot list collaborators of user {}",
+            user_id_from_token, user_id
+        )));
+    }
+
+    let session = state
+        .get_user_session(&user_id, &session_id)
+        .await?
+        .ok_or(ApiError::SessionNotFound(session_id))?;
+
+    let items = session
+        .collaborators
+        .into_iter()
+        .map(|(user_id, role)| CollaboratorInfo { user_id, role })
+        .collect();
+
+    Ok(Json(ListResponse {
+        items,
+        next_cursor: None,
+    }))
+}
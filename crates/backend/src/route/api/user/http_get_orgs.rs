@@ -0,0 +1,43 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use common::{
+    auth::AuthenticatedUser, errors::ApiError, state::AppState, ListResponse, OrgSummary,
+};
+
+/// 사용자가 속한 조직 목록. 자신의 목록만 볼 수 있습니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    AuthenticatedUser(user_id_from_token): AuthenticatedUser,
+    Path(user_id): Path<String>,
+) -> Result<Json<ListResponse<OrgSummary>>, ApiError> {
+    if user_id_from_token != user_id {
+        tracing::warn!(
+            "User {} attempted to list orgs of user {}",
+            user_id_from_token,
+            user_id
+        );
+        return Err(ApiError::Forbidden(format!(
+            "User {} cannot list orgs of user {}",
+            user_id_from_token, user_id
+        )));
+    }
+
+    let items = state
+        .list_user_orgs(&user_id)
+        .await?
+        .into_iter()
+        .map(|entry| OrgSummary {
+            org_id: entry.org_id,
+            name: entry.org.name,
+            role: entry.role,
+            created_at: entry.org.created_at,
+        })
+        .collect();
+
+    Ok(Json(ListResponse {
+        items,
+        next_cursor: None,
+    }))
+}
@@ -3,32 +3,40 @@ use axum::{
     response::IntoResponse,
     Json,
 };
-use common::{auth::AuthenticatedUser, errors::ApiError, state::AppState, SessionDetailInfo};
+use common::{
+    auth::ScopedUser, errors::ApiError, scope::SessionAccess, state::AppState, SessionDetailInfo,
+};
 
 pub async fn handler(
     Path((user_id, session_id)): Path<(String, String)>,
-    AuthenticatedUser(user_id_from_token): AuthenticatedUser,
+    user: ScopedUser,
     State(state): State<AppState>,
 ) -> Result<impl IntoResponse, ApiError> {
-    if user_id_from_token != user_id {
-        tracing::warn!(
-            "User {} attempted to access session of user {}",
-            user_id_from_token,
-            user_id
-        );
-        return Err(ApiError::Forbidden(format!(
-            "User {} cannot access sessions of user {}",
-            user_id_from_token, user_id
-        )));
-    }
-    let session_data = state
-        .get_user_session(&user_id, &session_id)
-        .await?
-        .ok_or(ApiError::SessionNotFound(session_id.clone()))?;
+    let session_data = state.get_user_session(&user_id, &session_id).await?;
+    let role = user.authorize_shared_session(
+        &user_id,
+        &session_id,
+        session_data.as_ref(),
+        SessionAccess::Read,
+    )?;
+    let session_data = session_data.ok_or(ApiError::SessionNotFound(session_id.clone()))?;
+    // 이전 요청은 소유자에게만 보여 줍니다.
+    let now = chrono::Utc::now();
+    let pending_transfer = session_data
+        .pending_transfer
+        .filter(|transfer| role.is_none() && transfer.expires_at > now);
 
     Ok(Json(SessionDetailInfo {
         session_id,
         template: session_data.template,
         args: session_data.args,
+        title: session_data.title,
+        description: session_data.description,
+        tags: session_data.tags,
+        listed: session_data.listed,
+        visibility: session_data.visibility,
+        role,
+        org_role: None,
+        pending_transfer,
     }))
 }
@@ -1,34 +1,49 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     Json,
 };
 use common::{
-    auth::AuthenticatedUser, errors::ApiError, state::AppState, ListResponse, SessionInfo,
+    auth::ScopedUser,
+    errors::ApiError,
+    scope::SessionAccess,
+    session_list::SessionListQuery,
+    state::{AppState, IndexPage, UserSessionEntry},
+    ListResponse, SessionSummary,
 };
 
+/// 소유자만 볼 수 있습니다. 협업자로 추가된 세션은 `GET /api/user/{user_id}/shared`에서 봅니다.
+///
+/// 생성 시각 정렬(기본값)은 세션 인덱스에서 한 페이지만 읽고, 다른 정렬은 모든 세션을 읽어 정렬하므로
+/// 세션이 [`MAX_SORTED_SESSIONS`](common::session_list::MAX_SORTED_SESSIONS)개를 넘으면 400입니다.
 pub async fn handler(
     State(state): State<AppState>,
-    AuthenticatedUser(user_id_from_token): AuthenticatedUser,
+    user: ScopedUser,
     Path(user_id): Path<String>,
-) -> Result<Json<ListResponse<SessionInfo>>, ApiError> {
-    if user_id_from_token != user_id {
-        tracing::warn!(
-            "User {} attempted to list sessions of user {}",
-            user_id_from_token,
-            user_id
-        );
-        return Err(ApiError::Forbidden(format!(
-            "User {} cannot list sessions of user {}",
-            user_id_from_token, user_id
-        )));
-    }
+    Query(query): Query<SessionListQuery>,
+) -> Result<Json<ListResponse<SessionSummary>>, ApiError> {
+    user.authorize_session(&user_id, None, SessionAccess::Read)?;
 
-    let session_ids = state.list_user_sessions(&user_id).await?;
+    let summarize = |entry: UserSessionEntry| SessionSummary::from_entry(&user_id, entry);
 
-    let sessions = session_ids
-        .into_iter()
-        .map(|session_id| SessionInfo { session_id })
-        .collect();
+    let (items, next_cursor) = if query.uses_index() {
+        let cursor = query.cursor()?;
+        let matches = query.matcher()?;
+        let page = IndexPage {
+            after: cursor.as_ref(),
+            descending: query.descending(),
+            count: query.limit() + 1,
+        };
+        let items = state
+            .page_user_session_entries(&user_id, page, |entry| {
+                Some(summarize(entry)).filter(|summary| matches(summary))
+            })
+            .await?;
+        query.page_from_index(items)
+    } else {
+        query.check_sortable(state.count_user_sessions(&user_id).await?)?;
+        let entries = state.list_user_session_entries(&user_id).await?;
+        query.apply(entries.into_iter().map(summarize).collect())?
+    };
 
-    Ok(Json(ListResponse { items: sessions }))
+    Ok(Json(ListResponse { items, next_cursor }))
 }
@@ -0,0 +1,38 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use common::{
+    auth::AuthenticatedUser, errors::ApiError, share_link::ShareLinkInfo, state::AppState,
+    ListResponse,
+};
+
+pub async fn handler(
+    State(state): State<AppState>,
+    AuthenticatedUser(user_id_from_token): AuthenticatedUser,
+    Path((user_id, session_id)): Path<(String, String)>,
+) -> Result<Json<ListResponse<ShareLinkInfo>>, ApiError> {
+    if user_id_from_token != user_id {
+        tracing::warn!(
+            "User {} attempted to list share links of user {}",
+            user_id_from_token,
+            user_id
+        );
+        return Err(ApiError::Forbidden(format!(
+            "User {} cannot list share links of user {}",
+            user_id_from_token, user_id
+        )));
+    }
+
+    let items = state
+        .list_share_links(&user_id, &session_id)
+        .await?
+        .into_iter()
+        .map(ShareLinkInfo::from)
+        .collect();
+
+    Ok(Json(ListResponse {
+        items,
+        next_cursor: None,
+    }))
+}
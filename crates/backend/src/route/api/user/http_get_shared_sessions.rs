@@ -0,0 +1,43 @@
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use common::{
+    auth::AuthenticatedUser, errors::ApiError, session_list::SessionListQuery, state::AppState,
+    ListResponse, SessionSummary,
+};
+
+/// 다른 사용자가 협업자로 추가해 준 세션 목록.
+pub async fn handler(
+    State(state): State<AppState>,
+    AuthenticatedUser(user_id_from_token): AuthenticatedUser,
+    Path(user_id): Path<String>,
+    Query(query): Query<SessionListQuery>,
+) -> Result<Json<ListResponse<SessionSummary>>, ApiError> {
+    if user_id_from_token != user_id {
+        tracing::warn!(
+            "User {} attempted to list shared sessions of user {}",
+            user_id_from_token,
+            user_id
+        );
+        return Err(ApiError::Forbidden(format!(
+            "User {} cannot list shared sessions of user {}",
+            user_id_from_token, user_id
+        )));
+    }
+
+    let summaries = state
+        .list_shared_session_entries(&user_id)
+        .await?
+        .into_iter()
+        .map(|shared| SessionSummary {
+            owner: Some(shared.owner.clone()),
+            role: Some(shared.role),
+            ..SessionSummary::from_entry(&shared.owner, shared.entry)
+        })
+        .collect::<Vec<_>>();
+
+    let (items, next_cursor) = query.apply(summaries)?;
+
+    Ok(Json(ListResponse { items, next_cursor }))
+}
@@ -0,0 +1,45 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use common::{
+    auth::AuthenticatedUser, errors::ApiError, state::AppState, ListResponse, SessionTransferInfo,
+};
+
+/// 사용자에게 온 소유권 이전 요청 목록. 만료가 가까운 순입니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    AuthenticatedUser(user_id_from_token): AuthenticatedUser,
+    Path(user_id): Path<String>,
+) -> Result<Json<ListResponse<SessionTransferInfo>>, ApiError> {
+    if user_id_from_token != user_id {
+        tracing::warn!(
+            "User {} attempted to list session transfers of user {}",
+            user_id_from_token,
+            user_id
+        );
+        return Err(ApiError::Forbidden(format!(
+            "User {} cannot list session transfers of user {}",
+            user_id_from_token, user_id
+        )));
+    }
+
+    let items = state
+        .list_incoming_transfers(&user_id)
+        .await?
+        .into_iter()
+        .map(|entry| {
+            SessionTransferInfo::new(
+                &entry.owner,
+                &entry.session_id,
+                &entry.session,
+                &entry.transfer,
+            )
+        })
+        .collect();
+
+    Ok(Json(ListResponse {
+        items,
+        next_cursor: None,
+    }))
+}
@@ -0,0 +1,26 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use common::{auth::AuthenticatedUser, errors::ApiError, rate_limit::UserUsage, state::AppState};
+
+/// 세션 수, 세션 크기와 할당량. 자신의 사용량만 볼 수 있습니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    AuthenticatedUser(user_id_from_token): AuthenticatedUser,
+    Path(user_id): Path<String>,
+) -> Result<Json<UserUsage>, ApiError> {
+    if user_id_from_token != user_id {
+        tracing::warn!(
+            "User {} attempted to view usage of user {}",
+            user_id_from_token,
+            user_id
+        );
+        return Err(ApiError::Forbidden(format!(
+            "User {} cannot view usage of user {}",
+            user_id_from_token, user_id
+        )));
+    }
+
+    Ok(Json(state.user_usage(&user_id).await?))
+}
@@ -0,0 +1,88 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use common::{
+    audit::{AuditAction, AuditContext},
+    auth::AuthenticatedClaims,
+    errors::ApiError,
+    state::AppState,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Debug, Deserialize)]
+pub struct ApiKeyCreateRequest {
+    name: String,
+    /// 생략하면 만료되지 않습니다.
+    #[serde(default)]
+    expires_in_seconds: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ApiKeyCreateResponse {
+    key_id: String,
+    name: String,
+    /// 키 원문. 이 응답에서만 볼 수 있습니다.
+    api_key: String,
+    created_at: DateTime<Utc>,
+    expires_at: Option<DateTime<Utc>>,
+}
+
+/// API 키로는 새 API 키를 만들 수 없도록 로그인 토큰만 받습니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    AuthenticatedClaims(claims): AuthenticatedClaims,
+    Path(user_id): Path<String>,
+    audit: AuditContext,
+    Json(req): Json<ApiKeyCreateRequest>,
+) -> Result<(StatusCode, Json<ApiKeyCreateResponse>), ApiError> {
+    if claims.sub != user_id {
+        tracing::warn!(
+            "User {} attempted to create API key for user {}",
+            claims.sub,
+            user_id
+        );
+        return Err(ApiError::Forbidden(format!(
+            "User {} cannot create API keys for user {}",
+            claims.sub, user_id
+        )));
+    }
+
+    let (api_key, record) = state
+        .create_api_key(&user_id, &req.name, req.expires_in_seconds)
+        .await?;
+
+    tracing::info!(
+        "Created API key: user={}, key_id={}, expires_at={:?}",
+        user_id,
+        record.key_id,
+        record.expires_at
+    );
+    state
+        .audit(
+            audit
+                .event(AuditAction::PermissionChanged)
+                .with_actor(&user_id)
+                .on_user(&user_id)
+                .with_details(json!({
+                    "change": "api_key_created",
+                    "key_id": record.key_id,
+                    "expires_at": record.expires_at,
+                })),
+        )
+        .await;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiKeyCreateResponse {
+            key_id: record.key_id,
+            name: record.name,
+            api_key,
+            created_at: record.created_at,
+            expires_at: record.expires_at,
+        }),
+    ))
+}
@@ -4,7 +4,14 @@ use axum::{
     Json,
 };
 use common::{
-    auth::AuthenticatedUser, errors::ApiError, session_data::SessionData, state::AppState,
+    audit::{AuditAction, AuditContext},
+    auth::ScopedUser,
+    directory::SessionRef,
+    errors::ApiError,
+    keys,
+    scope::SessionAccess,
+    session_data::{SessionData, SessionMetadataPatch},
+    state::AppState,
 };
 use serde::{Deserialize, Serialize};
 
@@ -15,6 +22,8 @@ pub struct CreateSessionRequest {
     args: std::collections::HashMap<String, serde_json::Value>,
     #[serde(default = "default_ttl")]
     ttl_seconds: u64,
+    #[serde(flatten)]
+    metadata: SessionMetadataPatch,
 }
 
 fn default_ttl() -> u64 {
@@ -29,30 +38,23 @@ pub struct CreateSessionResponse {
 
 pub async fn handler(
     State(state): State<AppState>,
-    AuthenticatedUser(user_id_from_token): AuthenticatedUser,
+    user: ScopedUser,
     Path(user_id): Path<String>,
+    audit: AuditContext,
     Json(req): Json<CreateSessionRequest>,
 ) -> Result<(StatusCode, Json<CreateSessionResponse>), ApiError> {
-    if user_id_from_token != user_id {
-        tracing::warn!(
-            "User {} attempted to create session for user {}",
-            user_id_from_token,
-            user_id
-        );
-        return Err(ApiError::Forbidden(format!(
-            "User {} cannot create sessions for user {}",
-            user_id_from_token, user_id
-        )));
-    }
+    user.authorize_session(&user_id, Some(&req.session_id), SessionAccess::Write)?;
+    user.require_unscoped()?;
 
-    if req.session_id.is_empty() {
-        return Err(ApiError::InvalidSessionId);
-    }
+    keys::validate_session_id(&req.session_id)?;
+    let session_ref = SessionRef::user(&user_id, &req.session_id);
+    state.check_session_quota(&session_ref).await?;
 
-    let session = SessionData::new_with_owner(req.template, req.args, user_id.clone());
+    let mut session = SessionData::new_with_owner(req.template, req.args, user_id.clone());
+    session.apply_metadata(req.metadata)?;
 
     state
-        .set_user_session(&user_id, &req.session_id, &session, req.ttl_seconds)
+        .create_user_session(&user_id, &req.session_id, &session, req.ttl_seconds)
         .await?;
 
     tracing::info!(
@@ -61,6 +63,14 @@ pub async fn handler(
         req.session_id,
         user_id
     );
+    state
+        .audit(
+            audit
+                .event(AuditAction::SessionCreated)
+                .with_actor(&user.user_id)
+                .on_session(&session_ref),
+        )
+        .await;
 
     Ok((
         StatusCode::CREATED,
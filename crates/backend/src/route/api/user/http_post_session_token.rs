@@ -0,0 +1,128 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use common::{
+    audit::{AuditAction, AuditContext},
+    auth::AuthenticatedUser,
+    directory::SessionRef,
+    errors::ApiError,
+    jwt,
+    scope::{Scope, SessionAccess},
+    state::AppState,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Debug, Deserialize)]
+pub struct SessionTokenRequest {
+    /// 생략하면 쓰기 권한만 줍니다.
+    #[serde(default = "default_access")]
+    access: Vec<SessionAccess>,
+    #[serde(default)]
+    ttl_seconds: Option<u64>,
+}
+
+fn default_access() -> Vec<SessionAccess> {
+    vec![SessionAccess::Write]
+}
+
+#[derive(Debug, Serialize)]
+pub struct SessionTokenResponse {
+    token: String,
+    /// 토큰 수명 (초)
+    expires_in: u64,
+    scopes: Vec<Scope>,
+}
+
+/// 세션 하나만 다룰 수 있는 토큰을 발급합니다.
+///
+/// 범위가 제한된 토큰으로는 다른 토큰을 발급할 수 없도록 `AuthenticatedUser`를 씁니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    AuthenticatedUser(user_id_from_token): AuthenticatedUser,
+    Path((user_id, session_id)): Path<(String, String)>,
+    audit: AuditContext,
+    Json(req): Json<SessionTokenRequest>,
+) -> Result<(StatusCode, Json<SessionTokenResponse>), ApiError> {
+    if user_id_from_token != user_id {
+        tracing::warn!(
+            "User {} attempted to issue session token for user {}",
+            user_id_from_token,
+            user_id
+        );
+        return Err(ApiError::Forbidden(format!(
+            "User {} cannot issue session tokens for user {}",
+            user_id_from_token, user_id
+        )));
+    }
+
+    let mut access = req.access;
+    access.sort_by_key(|access| access.as_str());
+    access.dedup();
+    if access.contains(&SessionAccess::View) {
+        return Err(ApiError::InvalidScope(
+            "view 권한은 공유 링크로 발급합니다".to_string(),
+        ));
+    }
+    if access.is_empty() {
+        return Err(ApiError::InvalidScope(
+            "access에는 read 또는 write가 하나 이상 있어야 합니다".to_string(),
+        ));
+    }
+
+    if state
+        .get_user_session(&user_id, &session_id)
+        .await?
+        .is_none()
+    {
+        return Err(ApiError::SessionNotFound(session_id));
+    }
+
+    let user_data = state
+        .get_user_data(&user_id)
+        .await?
+        .ok_or_else(|| ApiError::Unauthorized("Account no longer exists".to_string()))?;
+
+    let scopes: Vec<Scope> = access
+        .into_iter()
+        .map(|access| Scope::session(&user_id, &session_id, access))
+        .collect();
+    let ttl = state.share().token_policy().scoped_ttl(req.ttl_seconds);
+    let signing_key = state.share().get_signing_key(state.redis_client()).await?;
+    let token = jwt::create_scoped_token(
+        &user_id,
+        user_data.token_generation,
+        scopes.clone(),
+        &signing_key,
+        ttl,
+    )?;
+
+    tracing::info!(
+        "Issued session token: user={}, session_id={}, ttl={}",
+        user_id,
+        session_id,
+        ttl
+    );
+    state
+        .audit(
+            audit
+                .event(AuditAction::TokenIssued)
+                .with_actor(&user_id)
+                .on_session(&SessionRef::user(&user_id, &session_id))
+                .with_details(
+                    json!({ "method": "session_token", "scopes": scopes, "ttl_seconds": ttl }),
+                ),
+        )
+        .await;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(SessionTokenResponse {
+            token,
+            expires_in: ttl,
+            scopes,
+        }),
+    ))
+}
@@ -0,0 +1,103 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use common::{
+    audit::{AuditAction, AuditContext},
+    auth::AuthenticatedUser,
+    directory::SessionRef,
+    errors::ApiError,
+    share_link,
+    state::AppState,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Debug, Deserialize)]
+pub struct ShareLinkCreateRequest {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    ttl_seconds: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ShareLinkCreateResponse {
+    link_id: String,
+    name: Option<String>,
+    /// 뷰어 토큰. 이 응답에서만 볼 수 있습니다.
+    token: String,
+    /// 토큰이 붙은 스트림 URL
+    url: String,
+    created_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+}
+
+/// 범위가 제한된 토큰으로는 공유 링크를 만들 수 없도록 `AuthenticatedUser`를 씁니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    AuthenticatedUser(user_id_from_token): AuthenticatedUser,
+    Path((user_id, session_id)): Path<(String, String)>,
+    audit: AuditContext,
+    Json(req): Json<ShareLinkCreateRequest>,
+) -> Result<(StatusCode, Json<ShareLinkCreateResponse>), ApiError> {
+    if user_id_from_token != user_id {
+        tracing::warn!(
+            "User {} attempted to create share link for user {}",
+            user_id_from_token,
+            user_id
+        );
+        return Err(ApiError::Forbidden(format!(
+            "User {} cannot create share links for user {}",
+            user_id_from_token, user_id
+        )));
+    }
+
+    if state
+        .get_user_session(&user_id, &session_id)
+        .await?
+        .is_none()
+    {
+        return Err(ApiError::SessionNotFound(session_id));
+    }
+
+    let ttl = state.share().token_policy().scoped_ttl(req.ttl_seconds);
+    let (token, record) = state
+        .create_share_link(&user_id, &session_id, req.name.as_deref(), ttl)
+        .await?;
+
+    tracing::info!(
+        "Created share link: user={}, session_id={}, link_id={}, expires_at={}",
+        user_id,
+        session_id,
+        record.link_id,
+        record.expires_at
+    );
+    state
+        .audit(
+            audit
+                .event(AuditAction::PermissionChanged)
+                .with_actor(&user_id)
+                .on_session(&SessionRef::user(&user_id, &session_id))
+                .with_details(json!({
+                    "change": "share_link_created",
+                    "link_id": record.link_id,
+                    "expires_at": record.expires_at,
+                })),
+        )
+        .await;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ShareLinkCreateResponse {
+            url: share_link::stream_url(&user_id, &session_id, &token),
+            link_id: record.link_id,
+            name: record.name,
+            token,
+            created_at: record.created_at,
+            expires_at: record.expires_at,
+        }),
+    ))
+}
@@ -0,0 +1,58 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use common::{
+    auth::AuthenticatedUser, errors::ApiError, keys::validate_user_id, state::AppState,
+    SessionTransferInfo,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct TransferRequest {
+    to: String,
+}
+
+/// 세션 소유권 이전을 요청합니다. 받는 사용자가 수락해야 이전됩니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    AuthenticatedUser(user_id_from_token): AuthenticatedUser,
+    Path((user_id, session_id)): Path<(String, String)>,
+    Json(req): Json<TransferRequest>,
+) -> Result<(StatusCode, Json<SessionTransferInfo>), ApiError> {
+    if user_id_from_token != user_id {
+        tracing::warn!(
+            "User {} attempted to transfer session of user {}",
+            user_id_from_token,
+            user_id
+        );
+        return Err(ApiError::Forbidden(format!(
+            "User {} cannot transfer sessions of user {}",
+            user_id_from_token, user_id
+        )));
+    }
+    validate_user_id(&req.to)?;
+
+    let (session, transfer) = state
+        .request_session_transfer(&user_id, &session_id, &req.to)
+        .await?;
+
+    tracing::info!(
+        "Requested session transfer: user={}, session_id={}, to={}, expires_at={}",
+        user_id,
+        session_id,
+        transfer.to,
+        transfer.expires_at
+    );
+
+    Ok((
+        StatusCode::CREATED,
+        Json(SessionTransferInfo::new(
+            &user_id,
+            &session_id,
+            &session,
+            &transfer,
+        )),
+    ))
+}
@@ -0,0 +1,66 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use common::{
+    audit::{AuditAction, AuditContext},
+    auth::AuthenticatedUser,
+    directory::SessionRef,
+    errors::ApiError,
+    state::AppState,
+};
+use serde::Serialize;
+use serde_json::json;
+
+#[derive(Debug, Serialize)]
+pub struct TransferAcceptResponse {
+    user_id: String,
+    session_id: String,
+}
+
+/// 소유권 이전을 수락합니다. 세션은 같은 ID로 수락한 사용자의 세션이 됩니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    AuthenticatedUser(user_id_from_token): AuthenticatedUser,
+    Path((user_id, owner, session_id)): Path<(String, String, String)>,
+    audit: AuditContext,
+) -> Result<Json<TransferAcceptResponse>, ApiError> {
+    if user_id_from_token != user_id {
+        tracing::warn!(
+            "User {} attempted to accept session transfer for user {}",
+            user_id_from_token,
+            user_id
+        );
+        return Err(ApiError::Forbidden(format!(
+            "User {} cannot accept session transfers for user {}",
+            user_id_from_token, user_id
+        )));
+    }
+
+    let session_ref = SessionRef::user(&user_id, &session_id);
+    state.check_session_quota(&session_ref).await?;
+    state
+        .accept_session_transfer(&user_id, &owner, &session_id)
+        .await?;
+
+    tracing::info!(
+        "Accepted session transfer: from={}, to={}, session_id={}",
+        owner,
+        user_id,
+        session_id
+    );
+    state
+        .audit(
+            audit
+                .event(AuditAction::PermissionChanged)
+                .with_actor(&user_id)
+                .on_session(&session_ref)
+                .with_details(json!({ "change": "ownership_transferred", "from": owner })),
+        )
+        .await;
+
+    Ok(Json(TransferAcceptResponse {
+        user_id,
+        session_id,
+    }))
+}
@@ -0,0 +1,70 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use common::{
+    audit::{AuditAction, AuditContext},
+    auth::AuthenticatedUser,
+    directory::SessionRef,
+    errors::ApiError,
+    keys::validate_user_id,
+    session_data::SessionRole,
+    state::AppState,
+};
+use serde::Deserialize;
+use serde_json::json;
+
+#[derive(Debug, Deserialize)]
+pub struct CollaboratorRequest {
+    role: SessionRole,
+}
+
+/// 협업자를 추가하거나 역할을 바꿉니다.
+pub async fn handler(
+    State(state): State<AppState>,
+    AuthenticatedUser(user_id_from_token): AuthenticatedUser,
+    Path((user_id, session_id, collaborator_id)): Path<(String, String, String)>,
+    audit: AuditContext,
+    Json(req): Json<CollaboratorRequest>,
+) -> Result<StatusCode, ApiError> {
+    if user_id_from_token != user_id {
+        tracing::warn!(
+            "User {} attempted to share session of user {}",
+            user_id_from_token,
+            user_id
+        );
+        return Err(ApiError::Forbidden(format!(
+            "User {} cannot share sessions of user {}",
+            user_id_from_token, user_id
+        )));
+    }
+    validate_user_id(&collaborator_id)?;
+
+    state
+        .set_session_collaborator(&user_id, &session_id, &collaborator_id, req.role)
+        .await?;
+
+    tracing::info!(
+        "Set collaborator: user={}, session_id={}, collaborator={}, role={}",
+        user_id,
+        session_id,
+        collaborator_id,
+        req.role.as_str()
+    );
+    state
+        .audit(
+            audit
+                .event(AuditAction::PermissionChanged)
+                .with_actor(&user_id)
+                .on_session(&SessionRef::user(&user_id, &session_id))
+                .with_details(json!({
+                    "change": "collaborator_set",
+                    "collaborator": collaborator_id,
+                    "role": req.role,
+                })),
+        )
+        .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -2,49 +2,92 @@ use axum::{
     extract::{Path, State},
     http::StatusCode,
 };
-use common::{auth::AuthenticatedUser, errors::ApiError, state::AppState};
+use common::{
+    audit::{AuditAction, AuditContext},
+    auth::ScopedUser,
+    directory::SessionRef,
+    errors::ApiError,
+    scope::SessionAccess,
+    session_data::SessionMetadataPatch,
+    state::AppState,
+};
 use serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
 pub struct UpdateSessionRequest {
-    args: std::collections::HashMap<String, serde_json::Value>,
+    #[serde(default)]
+    args: Option<std::collections::HashMap<String, serde_json::Value>>,
+    #[serde(flatten)]
+    metadata: SessionMetadataPatch,
 }
 
 pub async fn handler(
     State(state): State<AppState>,
-    AuthenticatedUser(user_id_from_token): AuthenticatedUser,
+    user: ScopedUser,
     Path((user_id, session_id)): Path<(String, String)>,
+    audit: AuditContext,
     axum::Json(req): axum::Json<UpdateSessionRequest>,
 ) -> Result<StatusCode, ApiError> {
-    if user_id_from_token != user_id {
-        tracing::warn!(
-            "User {} attempted to update session of user {}",
-            user_id_from_token,
-            user_id
-        );
-        return Err(ApiError::Forbidden(format!(
-            "User {} cannot modify sessions of user {}",
-            user_id_from_token, user_id
-        )));
+    let session = state.get_user_session(&user_id, &session_id).await?;
+    user.authorize_shared_session(
+        &user_id,
+        &session_id,
+        session.as_ref(),
+        SessionAccess::Write,
+    )?;
+    if session.is_none() {
+        return Err(ApiError::SessionNotFound(session_id));
+    }
+    if !req.metadata.is_empty() {
+        user.require_unscoped()?;
     }
 
-    let mut session = state
-        .get_user_session(&user_id, &session_id)
-        .await?
-        .ok_or_else(|| ApiError::SessionNotFound(session_id.clone()))?;
-
-    session.replace_args(req.args);
+    let session_ref = SessionRef::user(&user_id, &session_id);
+    state.check_session_update_rate(&session_ref).await?;
+    let event = audit
+        .event(AuditAction::SessionUpdated)
+        .with_actor(&user.user_id)
+        .on_session(&session_ref)
+        .with_session_changes(req.args.is_some(), &req.metadata);
 
+    // 읽은 뒤로 협업자가 바뀌었을 수 있으므로 저장할 세션을 기준으로 권한을 다시 확인합니다.
     let ttl_seconds = 3600;
     state
-        .set_user_session(&user_id, &session_id, &session, ttl_seconds)
-        .await?;
+        .update_user_session(&user_id, &session_id, ttl_seconds, |session| {
+            let role = user.authorize_shared_session(
+                &user_id,
+                &session_id,
+                Some(session),
+                SessionAccess::Write,
+            )?;
+            if role.is_some() && req.metadata.changes_sharing(session) {
+                tracing::warn!(
+                    "Collaborator {} attempted to change sharing of session {}/{}",
+                    user.user_id,
+                    user_id,
+                    session_id
+                );
+                return Err(ApiError::Forbidden(
+                    "공개 범위와 디렉터리 등록은 소유자만 바꿀 수 있습니다".to_string(),
+                ));
+            }
+            if let Some(args) = &req.args {
+                session.replace_args(args.clone());
+            }
+            session.apply_metadata(req.metadata.clone())?;
+            session.touch();
+            Ok(())
+        })
+        .await?
+        .ok_or_else(|| ApiError::SessionNotFound(session_id.clone()))?;
 
     tracing::info!(
-        "Updated user session: user={}, session_id={}",
+        "Updated user session: user={}, session_id={}, by={}",
         user_id,
-        session_id
+        session_id,
+        user.user_id
     );
+    state.audit(event).await;
 
     Ok(StatusCode::NO_CONTENT)
 }
@@ -1,24 +1,89 @@
 use axum::{
-    routing::{get, post, put},
+    routing::{delete, get, post, put},
     Router,
 };
 use common::state::AppState;
 
+mod http_delete_api_key;
+mod http_delete_collaborator;
+mod http_delete_incoming_transfer;
+mod http_delete_session;
+mod http_delete_share_link;
+mod http_delete_transfer;
+mod http_delete_user;
+mod http_get_api_keys;
+mod http_get_collaborators;
+mod http_get_orgs;
 mod http_get_session_id;
 mod http_get_sessions;
+mod http_get_share_links;
+mod http_get_shared_sessions;
+mod http_get_transfers;
+mod http_get_usage;
+mod http_post_api_key;
 mod http_post_session;
+mod http_post_session_token;
+mod http_post_share_link;
+mod http_post_transfer;
+mod http_post_transfer_accept;
+mod http_put_collaborator;
 mod http_put_session;
 
 pub fn router() -> Router<AppState> {
     Router::new()
+        .route("/{user_id}", delete(http_delete_user::handler))
+        .route(
+            "/{user_id}/api-key",
+            get(http_get_api_keys::handler).post(http_post_api_key::handler),
+        )
+        .route(
+            "/{user_id}/api-key/{key_id}",
+            delete(http_delete_api_key::handler),
+        )
         .route("/{user_id}/session", post(http_post_session::handler))
         .route(
             "/{user_id}/session/{session_id}",
-            put(http_put_session::handler),
+            put(http_put_session::handler).delete(http_delete_session::handler),
         )
         .route("/{user_id}/session", get(http_get_sessions::handler))
         .route(
             "/{user_id}/session/{session_id}",
             get(http_get_session_id::handler),
         )
+        .route(
+            "/{user_id}/session/{session_id}/token",
+            post(http_post_session_token::handler),
+        )
+        .route(
+            "/{user_id}/session/{session_id}/share-link",
+            get(http_get_share_links::handler).post(http_post_share_link::handler),
+        )
+        .route(
+            "/{user_id}/session/{session_id}/share-link/{link_id}",
+            delete(http_delete_share_link::handler),
+        )
+        .route(
+            "/{user_id}/session/{session_id}/collaborator",
+            get(http_get_collaborators::handler),
+        )
+        .route(
+            "/{user_id}/session/{session_id}/collaborator/{collaborator_id}",
+            put(http_put_collaborator::handler).delete(http_delete_collaborator::handler),
+        )
+        .route(
+            "/{user_id}/session/{session_id}/transfer",
+            post(http_post_transfer::handler).delete(http_delete_transfer::handler),
+        )
+        .route("/{user_id}/shared", get(http_get_shared_sessions::handler))
+        .route("/{user_id}/transfer", get(http_get_transfers::handler))
+        .route(
+            "/{user_id}/transfer/{owner}/{session_id}",
+            delete(http_delete_incoming_transfer::handler),
+        )
+        .route(
+            "/{user_id}/transfer/{owner}/{session_id}/accept",
+            post(http_post_transfer_accept::handler),
+        )
+        .route("/{user_id}/org", get(http_get_orgs::handler))
+        .route("/{user_id}/usage", get(http_get_usage::handler))
 }
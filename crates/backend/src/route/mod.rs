@@ -1,5 +1,6 @@
-use axum::{routing::get, Router};
-use common::state::AppState;
+use axum::{http::HeaderName, routing::get, Router};
+use common::{audit::REQUEST_ID_HEADER, state::AppState};
+use tower_http::request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer};
 
 pub mod api;
 pub mod fallback;
@@ -17,4 +18,12 @@ pub fn router() -> Router<AppState> {
         .route("/session/{session_id}", get(index::handler))
         .route("/", get(index::handler))
         .fallback(fallback::handler)
+        // 감사 기록과 로그를 요청 단위로 묶을 수 있게, 요청 ID가 없으면 만들어 응답에도 붙입니다.
+        .layer(PropagateRequestIdLayer::new(HeaderName::from_static(
+            REQUEST_ID_HEADER,
+        )))
+        .layer(SetRequestIdLayer::new(
+            HeaderName::from_static(REQUEST_ID_HEADER),
+            MakeRequestUuid,
+        ))
 }
@@ -3,7 +3,7 @@ use axum::{
     http::HeaderMap,
     response::IntoResponse,
 };
-use common::{errors::ApiError, state::AppState, whoami::ExtractWhoAmI};
+use common::{client_ip::ClientIp, errors::ApiError, state::AppState, whoami::ExtractWhoAmI};
 use tracing::info;
 
 use crate::stream_sender::{StreamSender, StreamSenderConfigParams, StreamSenderRequest};
@@ -13,6 +13,7 @@ pub async fn handler(
     ExtractWhoAmI(whoami): ExtractWhoAmI,
     State(state): State<AppState>,
     headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
     Query(query): Query<StreamSenderConfigParams>,
 ) -> Result<impl IntoResponse, ApiError> {
     info!(session_id = %session_id, whoami = ?whoami, "HTTP GET 스트림 요청을 처리합니다");
@@ -32,7 +33,9 @@ pub async fn handler(
             session_log_id: session_id.clone(),
             redirect_path: format!("/session/{}", session_id),
             initial_frame,
-            pubsub_channel: session_id,
+            pubsub_channel: state.keys().public_session(&session_id),
+            client_ip,
+            share_link_id: None,
         },
     )
     .await
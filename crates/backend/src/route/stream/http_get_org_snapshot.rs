@@ -0,0 +1,25 @@
+use axum::{
+    extract::{Path, State},
+    http::header,
+    response::IntoResponse,
+};
+use common::{errors::ApiError, state::AppState};
+
+/// 조직 세션의 현재 프레임을 단일 SVG 이미지로 반환합니다 (목록 썸네일 용도).
+pub async fn handler(
+    Path((org_id, session_id)): Path<(String, String)>,
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse, ApiError> {
+    let session = state
+        .get_org_session(&org_id, &session_id)
+        .await?
+        .ok_or(ApiError::SessionNotFound(session_id.clone()))?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "image/svg+xml"),
+            (header::CACHE_CONTROL, "no-cache"),
+        ],
+        session.current_frame().content,
+    ))
+}
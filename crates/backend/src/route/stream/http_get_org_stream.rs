@@ -0,0 +1,46 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::HeaderMap,
+    response::IntoResponse,
+};
+use common::{client_ip::ClientIp, errors::ApiError, state::AppState, whoami::ExtractWhoAmI};
+use tracing::info;
+
+use crate::stream_sender::{StreamSender, StreamSenderConfigParams, StreamSenderRequest};
+
+/// 조직 세션 스트림. 조직 세션은 공개 세션만 있으므로 인증 없이 볼 수 있습니다.
+pub async fn handler(
+    Path((org_id, session_id)): Path<(String, String)>,
+    ExtractWhoAmI(whoami): ExtractWhoAmI,
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+    Query(query): Query<StreamSenderConfigParams>,
+) -> Result<impl IntoResponse, ApiError> {
+    info!(
+        "Org stream access: org_id={}, session_id={}",
+        org_id, session_id
+    );
+
+    let session = state
+        .get_org_session(&org_id, &session_id)
+        .await?
+        .ok_or(ApiError::SessionNotFound(session_id.clone()))?;
+    let initial_frame = session.current_frame();
+
+    StreamSender::from_params(
+        &state,
+        query,
+        whoami,
+        &headers,
+        StreamSenderRequest {
+            session_log_id: format!("org:{}:{}", org_id, session_id),
+            redirect_path: format!("/org/{}/session/{}", org_id, session_id),
+            initial_frame,
+            pubsub_channel: state.keys().org_session(&org_id, &session_id),
+            client_ip,
+            share_link_id: None,
+        },
+    )
+    .await
+}
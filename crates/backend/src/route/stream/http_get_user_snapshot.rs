@@ -0,0 +1,34 @@
+use axum::{
+    extract::{Path, Query, RawQuery, State},
+    http::header,
+    response::{IntoResponse, Response},
+};
+use common::{errors::ApiError, state::AppState};
+
+use super::{moved_session_redirect, ViewerTokenQuery};
+
+/// 세션의 현재 프레임을 단일 SVG 이미지로 반환합니다 (목록 썸네일 용도).
+///
+/// 비공개 세션은 스트림과 같은 뷰어 토큰이 필요합니다.
+pub async fn handler(
+    Path((user_id, session_id)): Path<(String, String)>,
+    State(state): State<AppState>,
+    Query(viewer): Query<ViewerTokenQuery>,
+    RawQuery(raw_query): RawQuery,
+) -> Result<Response, ApiError> {
+    let Some(session) = state.get_user_session(&user_id, &session_id).await? else {
+        return moved_session_redirect(&state, &user_id, &session_id, "/snapshot", raw_query).await;
+    };
+    state
+        .authorize_viewer(&user_id, &session_id, &session, viewer.token.as_deref())
+        .await?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "image/svg+xml"),
+            (header::CACHE_CONTROL, "no-cache"),
+        ],
+        session.current_frame().content,
+    )
+        .into_response())
+}
@@ -1,29 +1,36 @@
 use axum::{
-    extract::{Path, Query, State},
+    extract::{Path, Query, RawQuery, State},
     http::HeaderMap,
-    response::IntoResponse,
+    response::{IntoResponse, Response},
 };
-use common::{errors::ApiError, state::AppState, whoami::ExtractWhoAmI};
+use common::{client_ip::ClientIp, errors::ApiError, state::AppState, whoami::ExtractWhoAmI};
 use tracing::info;
 
+use super::{moved_session_redirect, ViewerTokenQuery};
 use crate::stream_sender::{StreamSender, StreamSenderConfigParams, StreamSenderRequest};
 
+#[allow(clippy::too_many_arguments)]
 pub async fn handler(
     Path((user_id, session_id)): Path<(String, String)>,
     ExtractWhoAmI(whoami): ExtractWhoAmI,
     State(state): State<AppState>,
     headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
     Query(query): Query<StreamSenderConfigParams>,
-) -> Result<impl IntoResponse, ApiError> {
+    Query(viewer): Query<ViewerTokenQuery>,
+    RawQuery(raw_query): RawQuery,
+) -> Result<Response, ApiError> {
     info!(
         "User stream access: user_id={}, session_id={}",
         user_id, session_id
     );
 
-    let session = state
-        .get_user_session(&user_id, &session_id)
-        .await?
-        .ok_or(ApiError::SessionNotFound(session_id.clone()))?;
+    let Some(session) = state.get_user_session(&user_id, &session_id).await? else {
+        return moved_session_redirect(&state, &user_id, &session_id, "", raw_query).await;
+    };
+    let share_link_id = state
+        .authorize_viewer(&user_id, &session_id, &session, viewer.token.as_deref())
+        .await?;
     let initial_frame = session.current_frame();
 
     StreamSender::from_params(
@@ -35,8 +42,11 @@ pub async fn handler(
             session_log_id: format!("{}:{}", user_id, session_id),
             redirect_path: format!("/session/{}", session_id),
             initial_frame,
-            pubsub_channel: format!("user:{}:session:{}", user_id, session_id),
+            pubsub_channel: state.keys().user_session(&user_id, &session_id),
+            client_ip,
+            share_link_id,
         },
     )
     .await
+    .map(IntoResponse::into_response)
 }
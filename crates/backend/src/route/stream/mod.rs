@@ -1,9 +1,47 @@
-use axum::{routing::get, Router};
-use common::state::AppState;
+use axum::{
+    response::{IntoResponse, Redirect, Response},
+    routing::get,
+    Router,
+};
+use common::{errors::ApiError, state::AppState};
+use serde::Deserialize;
 
 pub mod http_get;
+pub mod http_get_org_snapshot;
+pub mod http_get_org_stream;
+pub mod http_get_user_snapshot;
 pub mod http_get_user_stream;
 
+/// 비공개 세션을 볼 때 쓰는 공유 링크의 뷰어 토큰.
+///
+/// `<img>` 태그는 헤더를 보낼 수 없으므로 `?token=` 쿼리로 받습니다.
+#[derive(Debug, Default, Deserialize)]
+pub struct ViewerTokenQuery {
+    pub token: Option<String>,
+}
+
+/// 소유권이 이전된 세션이면 예전 URL을 새 소유자의 URL로 보냅니다. 아니면 `SessionNotFound`입니다.
+///
+/// `suffix`는 세션 경로 뒤에 붙는 부분(`""` 또는 `"/snapshot"`)이며 쿼리 문자열은 그대로 넘깁니다.
+async fn moved_session_redirect(
+    state: &AppState,
+    user_id: &str,
+    session_id: &str,
+    suffix: &str,
+    query: Option<String>,
+) -> Result<Response, ApiError> {
+    let new_owner = state
+        .moved_session_owner(user_id, session_id)
+        .await?
+        .ok_or_else(|| ApiError::SessionNotFound(session_id.to_string()))?;
+    let query = query.map(|query| format!("?{}", query)).unwrap_or_default();
+    Ok(Redirect::temporary(&format!(
+        "/stream/{}/{}{}{}",
+        new_owner, session_id, suffix, query
+    ))
+    .into_response())
+}
+
 pub fn router() -> Router<AppState> {
     Router::<AppState>::new()
         .route("/{session_id}", get(http_get::handler))
@@ -11,4 +49,17 @@ pub fn router() -> Router<AppState> {
             "/{user_id}/{session_id}",
             get(http_get_user_stream::handler),
         )
+        .route(
+            "/{user_id}/{session_id}/snapshot",
+            get(http_get_user_snapshot::handler),
+        )
+        // `org`는 가입할 수 없는 사용자 이름이라 사용자 스트림 경로와 겹치지 않습니다.
+        .route(
+            "/org/{org_id}/{session_id}",
+            get(http_get_org_stream::handler),
+        )
+        .route(
+            "/org/{org_id}/{session_id}/snapshot",
+            get(http_get_org_snapshot::handler),
+        )
 }
@@ -1,4 +1,5 @@
 use std::convert::Infallible;
+use std::net::IpAddr;
 
 use axum::{
     body::Body,
@@ -6,7 +7,16 @@ use axum::{
     response::{IntoResponse, Redirect},
 };
 use bytes::Bytes;
-use common::{browser_engine::WellKnownBrowserEngine, errors::ApiError, state::AppState, whoami::WhoAmI, SvgFrame};
+use common::{
+    browser_engine::WellKnownBrowserEngine,
+    errors::ApiError,
+    parse_stream_moved_message,
+    rate_limit::{StreamSlot, STREAM_SLOT_RENEW_INTERVAL},
+    share_link_close_message,
+    state::AppState,
+    whoami::WhoAmI,
+    SvgFrame, STREAM_CLOSE_ANONYMOUS_MESSAGE, STREAM_CLOSE_MESSAGE,
+};
 use redis::aio::PubSub;
 use tokio::sync::mpsc;
 use tokio::time::{interval, Duration};
@@ -27,6 +37,12 @@ pub struct StreamSenderConfig {
     pub should_double: bool,
     pub keep_alive_ms: u64,
     pub delayed_start_ms: u64,
+    /// 공유 링크로 연 스트림이면 그 링크 ID. 링크가 폐기되면 스트림을 닫습니다.
+    pub share_link_id: Option<String>,
+    /// 구독 중인 채널. 세션 소유권이 이전되면 새 채널로 바꿉니다.
+    pub pubsub_channel: String,
+    /// IP별 동시 스트림 제한의 자리. 스트림이 끝나면 반납됩니다.
+    pub stream_slot: Option<StreamSlot>,
 }
 
 pub struct StreamSenderRequest {
@@ -34,6 +50,8 @@ pub struct StreamSenderRequest {
     pub redirect_path: String,
     pub initial_frame: SvgFrame,
     pub pubsub_channel: String,
+    pub client_ip: Option<IpAddr>,
+    pub share_link_id: Option<String>,
 }
 
 pub struct StreamSender {
@@ -56,6 +74,7 @@ impl StreamSender {
             return Ok(Redirect::temporary(&request.redirect_path).into_response());
         }
 
+        let stream_slot = state.acquire_stream_slot(request.client_ip).await?;
         let mut pubsub = state.pubsub().await?;
         pubsub.subscribe(&request.pubsub_channel).await?;
 
@@ -73,16 +92,16 @@ impl StreamSender {
             should_double,
             keep_alive_ms: keep_alive_interval,
             delayed_start_ms: delayed_start,
+            share_link_id: request.share_link_id,
+            pubsub_channel: request.pubsub_channel,
+            stream_slot,
         });
 
         let response = sender.start_and_build_response(pubsub).await;
         Ok(response)
     }
 
-    pub async fn start_and_build_response(
-        self,
-        mut pubsub: PubSub,
-    ) -> Response<Body> {
+    pub async fn start_and_build_response(self, pubsub: PubSub) -> Response<Body> {
         let (tx, rx) = mpsc::channel::<SvgFrame>(16);
         let tx_clone = tx.clone();
 
@@ -90,6 +109,12 @@ impl StreamSender {
         let initial_frame = self.config.initial_frame.clone();
         let keep_alive_interval = self.config.keep_alive_ms;
         let delayed_start = self.config.delayed_start_ms;
+        let mut channel = self.config.pubsub_channel.clone();
+        let stream_slot = self.config.stream_slot;
+        let viewer_close_message = match &self.config.share_link_id {
+            Some(link_id) => share_link_close_message(link_id),
+            None => STREAM_CLOSE_ANONYMOUS_MESSAGE.to_string(),
+        };
 
         let start_boundary = once(Ok::<Bytes, Infallible>(Bytes::from_static(b"--frame\r\n")));
         let initial_part = once(Ok::<Bytes, Infallible>(Bytes::from(encode_stream_frame(
@@ -116,9 +141,10 @@ impl StreamSender {
             .insert(header::CACHE_CONTROL, HeaderValue::from_static("no-cache"));
 
         tokio::spawn(async move {
-            let mut pubsub_stream = pubsub.on_message();
+            let (mut pubsub_sink, mut pubsub_stream) = pubsub.split();
             let mut last_frame: SvgFrame = initial_frame;
             let mut keep_alive_timer = interval(Duration::from_millis(keep_alive_interval));
+            let mut slot_renew_timer = interval(STREAM_SLOT_RENEW_INTERVAL);
 
             tokio::time::sleep(Duration::from_millis(delayed_start)).await;
 
@@ -135,6 +161,28 @@ impl StreamSender {
                                     }
                                 };
 
+                                if payload == STREAM_CLOSE_MESSAGE {
+                                    debug!(session = %session_log_id, "세션이 삭제되어 스트림을 종료합니다");
+                                    break;
+                                }
+                                if payload == viewer_close_message {
+                                    debug!(session = %session_log_id, "볼 권한이 없어져 스트림을 종료합니다");
+                                    break;
+                                }
+                                if let Some(moved_to) = parse_stream_moved_message(&payload) {
+                                    debug!(session = %session_log_id, moved_to, "세션 소유권이 이전되어 새 채널을 구독합니다");
+                                    // 새 채널을 먼저 구독해야 옮기는 사이의 프레임을 놓치지 않습니다.
+                                    if let Err(err) = pubsub_sink.subscribe(moved_to).await {
+                                        error!(session = %session_log_id, %err, "이전된 세션의 채널을 구독하지 못했습니다");
+                                        break;
+                                    }
+                                    if let Err(err) = pubsub_sink.unsubscribe(&channel).await {
+                                        error!(session = %session_log_id, %err, "이전 채널의 구독을 해제하지 못했습니다");
+                                    }
+                                    channel = moved_to.to_string();
+                                    continue;
+                                }
+
                                 match serde_json::from_str::<SvgFrame>(&payload) {
                                     Ok(frame) => {
                                         last_frame = frame.clone();
@@ -156,6 +204,13 @@ impl StreamSender {
                             break;
                         }
                     }
+                    _ = slot_renew_timer.tick(), if stream_slot.is_some() => {
+                        if let Some(slot) = &stream_slot {
+                            if let Err(err) = slot.renew().await {
+                                error!(session = %session_log_id, ?err, "스트림 자리의 임대를 연장하지 못했습니다");
+                            }
+                        }
+                    }
                 }
             }
         });
@@ -9,6 +9,7 @@ fn test_default_config() {
     assert_eq!(config.host, "127.0.0.1");
     assert_eq!(config.port, 3000);
     assert_eq!(config.log_level, "info");
+    assert_eq!(config.key_prefix, "");
 }
 
 #[test]
@@ -186,3 +187,179 @@ fn test_validation_error_messages() {
     let error = result.unwrap_err();
     assert!(error.to_string().contains("호스트"));
 }
+
+#[test]
+fn test_validation_key_prefix() {
+    let config = Config {
+        key_prefix: "tenant-a".into(),
+        ..Default::default()
+    };
+    assert!(config.validate().is_ok());
+    assert_eq!(config.keys().public_session("x"), "tenant-a:session:x");
+
+    for prefix in [
+        "tenant a",
+        "tenant*",
+        "a:user:bob",
+        "tenant-a:",
+        "한글",
+        "session",
+        "user",
+        ".realtime-svg",
+    ] {
+        let config = Config {
+            key_prefix: prefix.into(),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err(), "{prefix}");
+    }
+}
+
+#[test]
+fn test_validation_argon2_params() {
+    let mut config = Config::default();
+    assert!(config.argon2_params().is_ok());
+
+    config.argon2_iterations = 0;
+    assert!(config.validate().is_err());
+
+    config.argon2_iterations = 3;
+    config.argon2_memory_kib = 64 * 1024;
+    let params = config.argon2_params().unwrap();
+    assert_eq!(params.m_cost(), 64 * 1024);
+    assert_eq!(params.t_cost(), 3);
+}
+
+#[test]
+fn test_validation_token_ttl() {
+    let mut config = Config::default();
+    assert!(config.validate().is_ok());
+
+    config.access_token_ttl_seconds = 0;
+    assert!(config.validate().is_err());
+
+    config.access_token_ttl_seconds = 7200;
+    config.max_access_token_ttl_seconds = 3600;
+    assert!(config.validate().is_err());
+
+    config.max_access_token_ttl_seconds = 7200;
+    let policy = config.token_policy();
+    assert_eq!(policy.access_ttl(None), 7200);
+    assert_eq!(policy.access_ttl(Some(10_000)), 7200);
+}
+
+#[test]
+fn test_validation_trusted_issuers() {
+    use common::trusted_issuer::TrustedIssuerConfig;
+
+    let issuer = TrustedIssuerConfig {
+        issuer: "https://idp.example.com".to_string(),
+        audience: "realtime-svg".to_string(),
+        jwks_url: Some("https://idp.example.com/jwks".to_string()),
+        jwks_file: None,
+        user_claim: "sub".to_string(),
+    };
+    let mut config = Config {
+        trusted_issuers: vec![issuer.clone()],
+        ..Config::default()
+    };
+    assert!(config.validate().is_ok());
+
+    config.trusted_issuers.push(issuer.clone());
+    assert!(config.validate().is_err());
+
+    config.trusted_issuers = vec![TrustedIssuerConfig {
+        jwks_url: Some("not a url".to_string()),
+        ..issuer.clone()
+    }];
+    assert!(config.validate().is_err());
+
+    config.trusted_issuers = vec![TrustedIssuerConfig {
+        audience: String::new(),
+        ..issuer
+    }];
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_validation_login_rate_limit() {
+    let config = Config {
+        login_window_seconds: 0,
+        ..Config::default()
+    };
+    assert!(config.validate().is_err());
+
+    let config = Config {
+        login_lockout_base_seconds: 7200,
+        login_lockout_max_seconds: 3600,
+        ..Config::default()
+    };
+    assert!(config.validate().is_err());
+
+    // 잠금을 쓰지 않으면 잠금 시간은 검사하지 않습니다.
+    let config = Config {
+        login_lockout_threshold: 0,
+        login_lockout_base_seconds: 0,
+        ..Config::default()
+    };
+    assert!(config.validate().is_ok());
+
+    let policy = Config {
+        login_user_limit: 7,
+        trust_forwarded_for: true,
+        ..Config::default()
+    }
+    .rate_limit_policy();
+    assert_eq!(policy.login_user_limit, 7);
+    assert!(policy.trust_forwarded_for);
+}
+
+#[test]
+fn test_config_quotas() {
+    let policy = Config {
+        max_sessions_per_user: 5,
+        max_template_bytes: 1024,
+        max_streams_per_ip: 0,
+        ..Config::default()
+    }
+    .rate_limit_policy();
+    assert_eq!(policy.max_sessions_per_user, 5);
+    assert_eq!(policy.max_template_bytes, 1024);
+    assert_eq!(policy.max_streams_per_ip, 0);
+    assert_eq!(
+        policy.session_updates_per_minute,
+        Config::default().session_updates_per_minute
+    );
+}
+
+#[test]
+fn test_config_audit_sink() {
+    use clap::Parser;
+    use common::audit::{AuditSinkKind, DEFAULT_MAX_LEN};
+    use common::config::CliArgs;
+
+    let config = Config::default();
+    assert_eq!(config.audit_sink, AuditSinkKind::Redis);
+    assert_eq!(config.audit_log_max_len, DEFAULT_MAX_LEN);
+
+    let args = CliArgs::try_parse_from(["server", "--audit-sink", "log"]).unwrap();
+    assert_eq!(args.audit_sink, Some(AuditSinkKind::Log));
+    assert!(CliArgs::try_parse_from(["server", "--audit-sink", "file"]).is_err());
+}
+
+#[test]
+fn test_config_cookie_policy() {
+    use clap::Parser;
+    use common::config::CliArgs;
+
+    assert!(Config::default().cookie_policy().secure);
+    let policy = Config {
+        cookie_secure: false,
+        ..Config::default()
+    }
+    .cookie_policy();
+    assert!(!policy.secure);
+
+    let args = CliArgs::try_parse_from(["server", "--cookie-secure", "false"]).unwrap();
+    assert_eq!(args.cookie_secure, Some(false));
+}
@@ -1,8 +1,14 @@
-use axum::{body::Body, http::Request, Router};
-use common::state::AppState;
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    response::Response,
+    Router,
+};
+use common::{keys::Keys, state::AppState};
 use redis::Client;
-use serde_json::json;
+use serde_json::{json, Value};
 use std::sync::Once;
+use tower::ServiceExt;
 
 static INIT_LOGGER: Once = Once::new();
 
@@ -18,25 +24,263 @@ fn init_logger() {
     });
 }
 
+#[allow(dead_code)]
 pub async fn create_test_app() -> Router {
     init_logger();
 
     let redis_url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1/".to_string());
     let redis_client = Client::open(redis_url).unwrap();
 
-    common::share::initialize_redis(&redis_client)
+    create_test_app_with_keys(redis_client, Keys::default()).await
+}
+
+/// `key_prefix`가 설정된 배포를 흉내 냅니다.
+#[allow(dead_code)]
+pub async fn create_test_app_with_keys(redis_client: Client, keys: Keys) -> Router {
+    create_test_app_with(redis_client, keys, Default::default()).await
+}
+
+#[allow(dead_code)]
+pub async fn create_test_app_with(
+    redis_client: Client,
+    keys: Keys,
+    argon2_params: argon2::Params,
+) -> Router {
+    init_logger();
+
+    common::share::initialize_redis(&redis_client, &keys, Default::default())
         .await
         .unwrap();
 
-    let state = AppState::new(redis_client);
+    let state = AppState::new(redis_client, keys, argon2_params, Default::default());
     backend::route::router().with_state(state)
 }
 
+/// SSO 로그인 제공자가 설정된 앱.
+#[allow(dead_code)]
+pub async fn create_test_app_with_oidc(config: common::oidc::OidcConfig) -> Router {
+    init_logger();
+
+    let redis_url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1/".to_string());
+    let redis_client = Client::open(redis_url).unwrap();
+    common::share::initialize_redis(&redis_client, &Keys::default(), Default::default())
+        .await
+        .unwrap();
+
+    let provider = common::oidc::OidcProvider::new(config).unwrap();
+    let state = AppState::new(
+        redis_client,
+        Keys::default(),
+        Default::default(),
+        Default::default(),
+    )
+    .with_oidc(provider);
+    backend::route::router().with_state(state)
+}
+
+/// 외부 발급자의 토큰도 받아들이는 앱.
+#[allow(dead_code)]
+pub async fn create_test_app_with_trusted_issuers(
+    issuers: Vec<common::trusted_issuer::TrustedIssuerConfig>,
+) -> Router {
+    init_logger();
+
+    let redis_url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1/".to_string());
+    let redis_client = Client::open(redis_url).unwrap();
+    common::share::initialize_redis(&redis_client, &Keys::default(), Default::default())
+        .await
+        .unwrap();
+
+    let trusted_issuers = common::trusted_issuer::TrustedIssuers::new(issuers).unwrap();
+    let state = AppState::new(
+        redis_client,
+        Keys::default(),
+        Default::default(),
+        Default::default(),
+    )
+    .with_trusted_issuers(trusted_issuers);
+    backend::route::router().with_state(state)
+}
+
+/// 로그인 시도 제한을 `policy`로 바꾼 앱.
+#[allow(dead_code)]
+pub async fn create_test_app_with_rate_limit(
+    policy: common::rate_limit::RateLimitPolicy,
+) -> Router {
+    init_logger();
+
+    let redis_url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1/".to_string());
+    let redis_client = Client::open(redis_url).unwrap();
+    common::share::initialize_redis(&redis_client, &Keys::default(), Default::default())
+        .await
+        .unwrap();
+
+    let state = AppState::new(
+        redis_client,
+        Keys::default(),
+        Default::default(),
+        Default::default(),
+    )
+    .with_rate_limit_policy(policy);
+    backend::route::router().with_state(state)
+}
+
+pub const TEST_PASSWORD: &str = "test-password";
+
+/// [`send_raw`]가 모든 요청에 붙이는 `User-Agent`.
+#[allow(dead_code)]
+pub const TEST_USER_AGENT: &str = "realtime-svg-test/1.0";
+
+/// 테스트 요청의 인증 방법. 토큰 문자열은 Bearer 토큰으로 변환됩니다.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy)]
+pub enum Auth<'a> {
+    Anonymous,
+    /// `Authorization: Bearer {token}`
+    Bearer(&'a str),
+    /// 그대로 붙일 헤더 (`X-API-Key`, `Authorization: ApiKey ...` 등)
+    Header(&'static str, &'a str),
+}
+
+impl<'a, T: AsRef<str> + ?Sized> From<&'a T> for Auth<'a> {
+    fn from(token: &'a T) -> Self {
+        Auth::Bearer(token.as_ref())
+    }
+}
+
+impl<'a, T: AsRef<str> + ?Sized> From<Option<&'a T>> for Auth<'a> {
+    fn from(token: Option<&'a T>) -> Self {
+        token.map_or(Auth::Anonymous, |token| Auth::Bearer(token.as_ref()))
+    }
+}
+
+/// JSON 본문(있으면)과 인증 정보를 붙여 요청을 보냅니다.
+#[allow(dead_code)]
+pub async fn send_raw<'a>(
+    app: Router,
+    method: &str,
+    uri: &str,
+    auth: impl Into<Auth<'a>>,
+    body: Option<Value>,
+) -> Response {
+    let mut request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("user-agent", TEST_USER_AGENT);
+    match auth.into() {
+        Auth::Anonymous => {}
+        Auth::Bearer(token) => {
+            request = request.header("authorization", format!("Bearer {}", token));
+        }
+        Auth::Header(name, value) => request = request.header(name, value),
+    }
+    let request = match body {
+        Some(body) => request
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string())),
+        None => request.body(Body::empty()),
+    }
+    .unwrap();
+    app.oneshot(request).await.unwrap()
+}
+
+/// [`send_raw`]로 보내고 상태 코드와 JSON 본문을 돌려줍니다. 본문이 JSON이 아니면 `Null`.
+#[allow(dead_code)]
+pub async fn send<'a>(
+    app: Router,
+    method: &str,
+    uri: &str,
+    auth: impl Into<Auth<'a>>,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let response = send_raw(app, method, uri, auth, body).await;
+    let status = response.status();
+    (status, json_body(response).await)
+}
+
+/// 응답 본문을 JSON으로 읽습니다. JSON이 아니면 `Null`.
+#[allow(dead_code)]
+pub async fn json_body(response: Response) -> Value {
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    serde_json::from_slice(&body).unwrap_or(Value::Null)
+}
+
+/// 로그인한 테스트 사용자.
+#[allow(dead_code)]
+pub struct User {
+    pub id: String,
+    pub token: String,
+}
+
+#[allow(dead_code)]
+impl User {
+    /// 조직 멤버십이나 권한이 바뀐 뒤 새 claim을 받기 위해 다시 로그인합니다.
+    pub async fn relogin(&mut self, app: Router) {
+        self.token = issue_token(app, &self.id, None).await;
+    }
+
+    /// 이 사용자의 세션을 만듭니다.
+    pub async fn create_session(&self, app: Router, session_id: &str, template: &str) {
+        create_user_session(app, &self.token, &self.id, session_id, template).await;
+    }
+}
+
+/// `prefix`로 시작하는 새 계정을 만들고 로그인합니다.
+#[allow(dead_code)]
+pub async fn user(app: Router, prefix: &str) -> User {
+    let id = unique_user_id(prefix);
+    let token = issue_token(app, &id, None).await;
+    User { id, token }
+}
+
+/// `--grant-admin`과 같은 경로로 관리자 권한을 준 새 계정으로 로그인합니다.
+#[allow(dead_code)]
+pub async fn admin(app: Router, prefix: &str) -> User {
+    let id = unique_user_id(prefix);
+    register_user(app.clone(), &id).await;
+
+    let redis_url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1/".to_string());
+    let state = AppState::new(
+        Client::open(redis_url).unwrap(),
+        Keys::default(),
+        Default::default(),
+        Default::default(),
+    );
+    state
+        .update_account_status(&id, Some(true), None)
+        .await
+        .unwrap();
+
+    let token = issue_token(app, &id, None).await;
+    User { id, token }
+}
+
+/// 계정을 만듭니다. 이미 있으면 그대로 둡니다.
+#[allow(dead_code)]
+pub async fn register_user(app: Router, user_id: &str) {
+    let request = Request::builder()
+        .method("POST")
+        .uri("/api/auth/register")
+        .header("content-type", "application/json")
+        .body(Body::from(
+            json!({"user_id": user_id, "password": TEST_PASSWORD}).to_string(),
+        ))
+        .unwrap();
+
+    let status = app.oneshot(request).await.unwrap().status();
+    assert!(
+        status == StatusCode::CREATED || status == StatusCode::CONFLICT,
+        "register {user_id}: {status}"
+    );
+}
+
 #[allow(dead_code)]
 pub async fn issue_token(app: Router, user_id: &str, ttl_seconds: Option<i64>) -> String {
-    use tower::ServiceExt;
+    register_user(app.clone(), user_id).await;
 
-    let mut payload = json!({"user_id": user_id});
+    let mut payload = json!({"user_id": user_id, "password": TEST_PASSWORD});
     if let Some(ttl) = ttl_seconds {
         payload["ttl_seconds"] = json!(ttl);
     }
@@ -56,10 +300,38 @@ pub async fn issue_token(app: Router, user_id: &str, ttl_seconds: Option<i64>) -
     json["token"].as_str().unwrap().to_string()
 }
 
+#[allow(dead_code)]
+pub async fn create_user_session(
+    app: Router,
+    token: &str,
+    user_id: &str,
+    session_id: &str,
+    template: &str,
+) {
+    let request = Request::builder()
+        .method("POST")
+        .uri(format!("/api/user/{}/session", user_id))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(
+            json!({
+                "session_id": session_id,
+                "template": template,
+                "args": {"msg": "hello"}
+            })
+            .to_string(),
+        ))
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+}
+
 pub fn unique_user_id(prefix: &str) -> String {
     format!("{}_{}", prefix, uuid::Uuid::new_v4())
 }
 
+#[allow(dead_code)]
 pub fn unique_session_id(prefix: &str) -> String {
     format!("{}_{}", prefix, uuid::Uuid::new_v4())
 }
@@ -67,10 +339,10 @@ pub fn unique_session_id(prefix: &str) -> String {
 #[allow(dead_code)]
 pub async fn create_expired_jwt(redis_client: &redis::Client, user_id: &str) -> String {
     use chrono::{Duration, Utc};
-    use jsonwebtoken::{encode, Algorithm, Header};
+    use jsonwebtoken::{encode, Header};
 
-    let cache = common::share::ShareState::new();
-    let encoding_key = cache.get_encoding_key(redis_client).await.unwrap();
+    let cache = common::share::ShareState::default();
+    let signing_key = cache.get_signing_key(redis_client).await.unwrap();
 
     let now = Utc::now();
     let exp = now - Duration::hours(2);
@@ -80,8 +352,14 @@ pub async fn create_expired_jwt(redis_client: &redis::Client, user_id: &str) ->
         exp: exp.timestamp() as usize,
         iat: now.timestamp() as usize,
         iss: "realtime-svg".to_string(),
+        generation: 0,
+        jti: None,
+        scopes: None,
+        orgs: Default::default(),
+        admin: false,
     };
 
-    let header = Header::new(Algorithm::RS256);
-    encode(&header, &claims, encoding_key).unwrap()
+    let mut header = Header::new(signing_key.algorithm);
+    header.kid = Some(signing_key.kid.clone());
+    encode(&header, &claims, &signing_key.encoding_key).unwrap()
 }
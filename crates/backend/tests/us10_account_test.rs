@@ -0,0 +1,328 @@
+mod helpers;
+
+use std::time::Duration;
+
+use axum::{http::StatusCode, Router};
+use redis::AsyncTypedCommands;
+use serde_json::json;
+
+async fn change_password(
+    app: Router,
+    token: &str,
+    old_password: &str,
+    new_password: &str,
+) -> axum::response::Response {
+    helpers::send_raw(
+        app,
+        "POST",
+        "/api/auth/password",
+        token,
+        Some(json!({"old_password": old_password, "new_password": new_password})),
+    )
+    .await
+}
+
+async fn login(app: Router, user_id: &str, password: &str) -> StatusCode {
+    helpers::send_raw(
+        app,
+        "POST",
+        "/api/auth/token",
+        helpers::Auth::Anonymous,
+        Some(json!({"user_id": user_id, "password": password})),
+    )
+    .await
+    .status()
+}
+
+#[tokio::test]
+async fn test_password_change_revokes_old_tokens() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("passwd");
+    let old_token = helpers::issue_token(app.clone(), &user_id, None).await;
+
+    let response =
+        change_password(app.clone(), &old_token, "wrong-password", "new-password-1").await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = change_password(app.clone(), &old_token, helpers::TEST_PASSWORD, "short").await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = change_password(
+        app.clone(),
+        &old_token,
+        helpers::TEST_PASSWORD,
+        "new-password-1",
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let new_token = json["token"].as_str().unwrap().to_string();
+
+    let list_uri = format!("/api/user/{}/session", user_id);
+    let response = helpers::send_raw(app.clone(), "GET", &list_uri, &old_token, None).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let response = helpers::send_raw(app.clone(), "GET", &list_uri, &new_token, None).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    assert_eq!(
+        login(app.clone(), &user_id, helpers::TEST_PASSWORD).await,
+        StatusCode::UNAUTHORIZED
+    );
+    assert_eq!(login(app, &user_id, "new-password-1").await, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_password_change_keeps_concurrent_token_revocation() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("passwd");
+    helpers::register_user(app, &user_id).await;
+
+    let redis_url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1/".to_string());
+    let state = common::state::AppState::new(
+        redis::Client::open(redis_url).unwrap(),
+        Default::default(),
+        Default::default(),
+        Default::default(),
+    );
+
+    // 현재 비밀번호를 검증하는 동안 로그아웃(`all: true`)이 토큰 세대를 올린 상황입니다.
+    let mut verified = state.get_user_data(&user_id).await.unwrap().unwrap();
+    let mut other = verified.clone();
+    let generation = verified.token_generation;
+    state.revoke_user_tokens(&user_id).await.unwrap();
+    state
+        .change_user_password(&user_id, &mut verified, "new-password-1")
+        .await
+        .unwrap();
+    assert_eq!(verified.token_generation, generation + 2);
+
+    // 다른 요청이 먼저 바꾼 비밀번호를 예전 비밀번호로 검증한 요청이 덮어쓰지 않습니다.
+    let result = state
+        .change_user_password(&user_id, &mut other, "new-password-2")
+        .await;
+    assert!(result.is_err());
+    assert_eq!(
+        state.get_user_data(&user_id).await.unwrap().unwrap(),
+        verified
+    );
+}
+
+#[tokio::test]
+async fn test_delete_account_removes_sessions_and_tokens() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("delete_me");
+    let other_id = helpers::unique_user_id("other");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+    let other_token = helpers::issue_token(app.clone(), &other_id, None).await;
+
+    let listed = helpers::unique_session_id("listed");
+    let hidden = helpers::unique_session_id("hidden");
+    helpers::create_user_session(app.clone(), &token, &user_id, &hidden, "<svg></svg>").await;
+    let response = helpers::send_raw(
+        app.clone(),
+        "POST",
+        &format!("/api/user/{}/session", user_id),
+        &token,
+        Some(json!({
+            "session_id": listed,
+            "template": "<svg></svg>",
+            "args": {},
+            "title": "Listed",
+            "listed": true
+        })),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let account_uri = format!("/api/user/{}", user_id);
+    let response = helpers::send_raw(app.clone(), "DELETE", &account_uri, &other_token, None).await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = helpers::send_raw(app.clone(), "DELETE", &account_uri, &token, None).await;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    for session_id in [&listed, &hidden] {
+        let uri = format!("/stream/{}/{}/snapshot", user_id, session_id);
+        let response =
+            helpers::send_raw(app.clone(), "GET", &uri, helpers::Auth::Anonymous, None).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    let response = helpers::send_raw(
+        app.clone(),
+        "GET",
+        &format!("/api/user/{}/session", user_id),
+        &token,
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(
+        login(app.clone(), &user_id, helpers::TEST_PASSWORD).await,
+        StatusCode::UNAUTHORIZED
+    );
+
+    let response = helpers::send_raw(
+        app.clone(),
+        "GET",
+        "/api/directory?limit=100&sort=recent",
+        helpers::Auth::Anonymous,
+        None,
+    )
+    .await;
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert!(json["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .all(|item| item["session_id"] != listed.as_str()));
+
+    let redis_url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1/".to_string());
+    let mut conn = redis::Client::open(redis_url)
+        .unwrap()
+        .get_multiplexed_async_connection()
+        .await
+        .unwrap();
+    assert!(!conn.exists(format!("user:{}:data", user_id)).await.unwrap());
+    assert!(!conn
+        .exists(format!("user:{}:sessions", user_id))
+        .await
+        .unwrap());
+}
+
+#[tokio::test]
+async fn test_deleted_account_tokens_do_not_pass_to_new_account() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("reregister");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+    let sensor = helpers::unique_session_id("sensor");
+    helpers::create_user_session(app.clone(), &token, &user_id, &sensor, "<svg></svg>").await;
+    let response = helpers::send_raw(
+        app.clone(),
+        "POST",
+        &format!("/api/user/{}/session/{}/token", user_id, sensor),
+        &token,
+        Some(json!({})),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let scoped = json["token"].as_str().unwrap().to_string();
+
+    let response = helpers::send_raw(
+        app.clone(),
+        "DELETE",
+        &format!("/api/user/{}", user_id),
+        &token,
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    // 같은 ID로 다시 가입하고 같은 세션을 만들어도 예전 토큰은 통하지 않습니다.
+    let new_token = helpers::issue_token(app.clone(), &user_id, None).await;
+    helpers::create_user_session(app.clone(), &new_token, &user_id, &sensor, "<svg></svg>").await;
+
+    let list_uri = format!("/api/user/{}/session", user_id);
+    let response = helpers::send_raw(app.clone(), "GET", &list_uri, &token, None).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let response = helpers::send_raw(
+        app.clone(),
+        "PUT",
+        &format!("/api/user/{}/session/{}", user_id, sensor),
+        &scoped,
+        Some(json!({"args": {"v": 1}})),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = helpers::send_raw(app, "GET", &list_uri, &new_token, None).await;
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_delete_account_removes_unindexed_sessions() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("legacy_owner");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+
+    // 인덱스가 생기기 전에 만들어진 세션처럼 키만 있는 세션.
+    let legacy_key = format!("user:{}:session:legacy", user_id);
+    let session = common::session_data::SessionData::new_with_owner(
+        "<svg></svg>",
+        Default::default(),
+        user_id.clone(),
+    );
+    let redis_url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1/".to_string());
+    let mut conn = redis::Client::open(redis_url)
+        .unwrap()
+        .get_multiplexed_async_connection()
+        .await
+        .unwrap();
+    conn.set_ex(
+        &legacy_key,
+        serde_json::to_string(&session).unwrap().as_str(),
+        600,
+    )
+    .await
+    .unwrap();
+
+    let response = helpers::send_raw(
+        app,
+        "DELETE",
+        &format!("/api/user/{}", user_id),
+        &token,
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert!(!conn.exists(&legacy_key).await.unwrap());
+}
+
+#[tokio::test]
+async fn test_delete_account_ends_open_streams() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("streamer");
+    let session_id = helpers::unique_session_id("live");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+    helpers::create_user_session(app.clone(), &token, &user_id, &session_id, "<svg></svg>").await;
+
+    let response = helpers::send_raw(
+        app.clone(),
+        "GET",
+        &format!("/stream/{}/{}?as_bot=true", user_id, session_id),
+        helpers::Auth::Anonymous,
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body();
+
+    let response = helpers::send_raw(
+        app,
+        "DELETE",
+        &format!("/api/user/{}", user_id),
+        &token,
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let body = tokio::time::timeout(
+        Duration::from_secs(5),
+        axum::body::to_bytes(body, usize::MAX),
+    )
+    .await
+    .expect("stream should end after the account is deleted")
+    .unwrap();
+    assert!(body.starts_with(b"--frame\r\n"));
+}
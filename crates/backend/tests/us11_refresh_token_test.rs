@@ -0,0 +1,194 @@
+mod helpers;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+async fn post_json(
+    app: Router,
+    uri: &str,
+    token: Option<&str>,
+    body: Value,
+) -> (StatusCode, Value) {
+    let mut request = Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("content-type", "application/json");
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Bearer {}", token));
+    }
+    let response = app
+        .oneshot(request.body(Body::from(body.to_string())).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+}
+
+async fn login(app: Router, user_id: &str, ttl_seconds: Option<u64>) -> Value {
+    helpers::register_user(app.clone(), user_id).await;
+    let mut payload = json!({"user_id": user_id, "password": helpers::TEST_PASSWORD});
+    if let Some(ttl) = ttl_seconds {
+        payload["ttl_seconds"] = json!(ttl);
+    }
+    let (status, body) = post_json(app, "/api/auth/token", None, payload).await;
+    assert_eq!(status, StatusCode::OK);
+    body
+}
+
+async fn refresh(app: Router, refresh_token: &str) -> (StatusCode, Value) {
+    post_json(
+        app,
+        "/api/auth/refresh",
+        None,
+        json!({"refresh_token": refresh_token}),
+    )
+    .await
+}
+
+async fn list_sessions_status(app: Router, user_id: &str, token: &str) -> StatusCode {
+    let request = Request::builder()
+        .uri(format!("/api/user/{}/session", user_id))
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::empty())
+        .unwrap();
+    app.oneshot(request).await.unwrap().status()
+}
+
+/// 서명 검증 없이 `exp - iat`를 읽습니다.
+fn token_lifetime(token: &str) -> u64 {
+    let payload = token.split('.').nth(1).unwrap();
+    let claims: Value = serde_json::from_slice(&URL_SAFE_NO_PAD.decode(payload).unwrap()).unwrap();
+    claims["exp"].as_u64().unwrap() - claims["iat"].as_u64().unwrap()
+}
+
+#[tokio::test]
+async fn test_login_returns_short_lived_token_and_refresh_token() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("refresh");
+
+    let body = login(app.clone(), &user_id, None).await;
+    assert!(body["refresh_token"]
+        .as_str()
+        .is_some_and(|t| !t.is_empty()));
+    assert_eq!(body["expires_in"], 900);
+    let token = body["token"].as_str().unwrap();
+    assert_eq!(token_lifetime(token), 900);
+
+    // 서버 상한(기본 1시간)보다 긴 요청은 잘립니다.
+    let body = login(app, &user_id, Some(365 * 24 * 60 * 60)).await;
+    assert_eq!(body["expires_in"], 3600);
+    assert_eq!(token_lifetime(body["token"].as_str().unwrap()), 3600);
+}
+
+#[tokio::test]
+async fn test_refresh_rotates_token() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("refresh");
+    let body = login(app.clone(), &user_id, None).await;
+    let first = body["refresh_token"].as_str().unwrap().to_string();
+
+    let (status, body) = refresh(app.clone(), &first).await;
+    assert_eq!(status, StatusCode::OK);
+    let second = body["refresh_token"].as_str().unwrap().to_string();
+    assert_ne!(first, second);
+
+    let token = body["token"].as_str().unwrap();
+    assert_eq!(
+        list_sessions_status(app.clone(), &user_id, token).await,
+        StatusCode::OK
+    );
+
+    let (status, _) = refresh(app.clone(), &second).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_refresh_token_reuse_revokes_family() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("reuse");
+    let body = login(app.clone(), &user_id, None).await;
+    let first = body["refresh_token"].as_str().unwrap().to_string();
+
+    let (status, body) = refresh(app.clone(), &first).await;
+    assert_eq!(status, StatusCode::OK);
+    let second = body["refresh_token"].as_str().unwrap().to_string();
+
+    // 이미 교체된 토큰을 다시 쓰면 거부되고, 같은 family의 최신 토큰도 폐기됩니다.
+    let (status, _) = refresh(app.clone(), &first).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let (status, _) = refresh(app.clone(), &second).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    // 다른 로그인(family)은 영향을 받지 않습니다.
+    let body = login(app.clone(), &user_id, None).await;
+    let other = body["refresh_token"].as_str().unwrap();
+    let (status, _) = refresh(app, other).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_refresh_rejects_unknown_token() {
+    let app = helpers::create_test_app().await;
+
+    let (status, _) = refresh(app, "not-a-refresh-token").await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_password_change_revokes_refresh_tokens() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("refresh-pw");
+    let body = login(app.clone(), &user_id, None).await;
+    let token = body["token"].as_str().unwrap();
+    let old_refresh = body["refresh_token"].as_str().unwrap();
+
+    let (status, body) = post_json(
+        app.clone(),
+        "/api/auth/password",
+        Some(token),
+        json!({"old_password": helpers::TEST_PASSWORD, "new_password": "new-password-1"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let new_refresh = body["refresh_token"].as_str().unwrap();
+
+    let (status, _) = refresh(app.clone(), old_refresh).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let (status, _) = refresh(app, new_refresh).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_account_deletion_revokes_refresh_tokens() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("refresh-del");
+    let body = login(app.clone(), &user_id, None).await;
+    let token = body["token"].as_str().unwrap();
+    let old_refresh = body["refresh_token"].as_str().unwrap();
+
+    let request = Request::builder()
+        .method("DELETE")
+        .uri(format!("/api/user/{}", user_id))
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    // 같은 ID로 다시 가입해도 삭제된 계정의 refresh 토큰으로는 갱신할 수 없습니다.
+    let body = login(app.clone(), &user_id, None).await;
+    let new_refresh = body["refresh_token"].as_str().unwrap();
+    let (status, _) = refresh(app.clone(), old_refresh).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let (status, _) = refresh(app, new_refresh).await;
+    assert_eq!(status, StatusCode::OK);
+}
@@ -0,0 +1,163 @@
+mod helpers;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+async fn post_json(
+    app: Router,
+    uri: &str,
+    token: Option<&str>,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let mut request = Request::builder().method("POST").uri(uri);
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Bearer {}", token));
+    }
+    let request = match body {
+        Some(body) => request
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string())),
+        None => request.body(Body::empty()),
+    }
+    .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+}
+
+/// 로그인해서 (access 토큰, refresh 토큰)을 받습니다.
+async fn login(app: Router, user_id: &str) -> (String, String) {
+    helpers::register_user(app.clone(), user_id).await;
+    let (status, body) = post_json(
+        app,
+        "/api/auth/token",
+        None,
+        Some(json!({"user_id": user_id, "password": helpers::TEST_PASSWORD})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    (
+        body["token"].as_str().unwrap().to_string(),
+        body["refresh_token"].as_str().unwrap().to_string(),
+    )
+}
+
+async fn refresh_status(app: Router, refresh_token: &str) -> StatusCode {
+    post_json(
+        app,
+        "/api/auth/refresh",
+        None,
+        Some(json!({"refresh_token": refresh_token})),
+    )
+    .await
+    .0
+}
+
+async fn list_sessions_status(app: Router, user_id: &str, token: &str) -> StatusCode {
+    let request = Request::builder()
+        .uri(format!("/api/user/{}/session", user_id))
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::empty())
+        .unwrap();
+    app.oneshot(request).await.unwrap().status()
+}
+
+#[tokio::test]
+async fn test_logout_revokes_only_current_token() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("logout");
+    let (token, refresh_token) = login(app.clone(), &user_id).await;
+    let (other_token, other_refresh) = login(app.clone(), &user_id).await;
+
+    let (status, _) = post_json(
+        app.clone(),
+        "/api/auth/logout",
+        Some(&token),
+        Some(json!({"refresh_token": refresh_token})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    assert_eq!(
+        list_sessions_status(app.clone(), &user_id, &token).await,
+        StatusCode::UNAUTHORIZED
+    );
+    assert_eq!(
+        refresh_status(app.clone(), &refresh_token).await,
+        StatusCode::UNAUTHORIZED
+    );
+
+    // 다른 기기의 로그인은 그대로입니다.
+    assert_eq!(
+        list_sessions_status(app.clone(), &user_id, &other_token).await,
+        StatusCode::OK
+    );
+    assert_eq!(refresh_status(app, &other_refresh).await, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_logout_without_body() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("logout");
+    let (token, _) = login(app.clone(), &user_id).await;
+
+    let (status, _) = post_json(app.clone(), "/api/auth/logout", Some(&token), None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    // 폐기된 토큰으로는 다시 로그아웃할 수도 없습니다.
+    let (status, _) = post_json(app, "/api/auth/logout", Some(&token), None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_logout_everywhere_revokes_all_tokens() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("logout-all");
+    let (token, refresh_token) = login(app.clone(), &user_id).await;
+    let (other_token, other_refresh) = login(app.clone(), &user_id).await;
+
+    let (status, _) = post_json(
+        app.clone(),
+        "/api/auth/logout",
+        Some(&token),
+        Some(json!({"all": true})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    for token in [&token, &other_token] {
+        assert_eq!(
+            list_sessions_status(app.clone(), &user_id, token).await,
+            StatusCode::UNAUTHORIZED
+        );
+    }
+    for refresh_token in [&refresh_token, &other_refresh] {
+        assert_eq!(
+            refresh_status(app.clone(), refresh_token).await,
+            StatusCode::UNAUTHORIZED
+        );
+    }
+
+    // 다시 로그인하면 새 토큰을 쓸 수 있습니다.
+    let (token, _) = login(app.clone(), &user_id).await;
+    assert_eq!(
+        list_sessions_status(app, &user_id, &token).await,
+        StatusCode::OK
+    );
+}
+
+#[tokio::test]
+async fn test_logout_requires_authentication() {
+    let app = helpers::create_test_app().await;
+
+    let (status, _) = post_json(app, "/api/auth/logout", None, None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
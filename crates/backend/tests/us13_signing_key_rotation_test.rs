@@ -0,0 +1,336 @@
+mod helpers;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use common::{
+    jwt::TokenPolicy,
+    jwt::{self, SigningKey},
+    keys::Keys,
+    signing_key::{self, SigningAlgorithm},
+    state::AppState,
+};
+use jsonwebtoken::{EncodingKey, Header};
+use redis::AsyncTypedCommands;
+use serde_json::Value;
+use tower::ServiceExt;
+
+/// 다른 테스트의 키와 섞이지 않도록 prefix마다 따로 키를 만듭니다.
+async fn setup() -> (AppState, Router) {
+    setup_with(
+        Keys::new(helpers::unique_user_id("jwk")),
+        SigningAlgorithm::RS256,
+    )
+    .await
+}
+
+/// `algorithm`으로 설정된 인스턴스를 시작합니다.
+async fn setup_with(keys: Keys, algorithm: SigningAlgorithm) -> (AppState, Router) {
+    let redis_url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1/".to_string());
+    let redis_client = redis::Client::open(redis_url).unwrap();
+
+    common::share::initialize_redis(&redis_client, &keys, algorithm)
+        .await
+        .unwrap();
+    let token_policy = TokenPolicy {
+        signing_algorithm: algorithm,
+        ..Default::default()
+    };
+    let state = AppState::new(redis_client, keys, Default::default(), token_policy);
+    let app = backend::route::router().with_state(state.clone());
+    (state, app)
+}
+
+async fn jwks(app: Router) -> Vec<Value> {
+    let request = Request::builder()
+        .uri("/.well-known/jwks.json")
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let jwks: Value = serde_json::from_slice(&body).unwrap();
+    jwks["keys"].as_array().unwrap().clone()
+}
+
+fn token_kid(token: &str) -> Option<String> {
+    jsonwebtoken::decode_header(token).unwrap().kid
+}
+
+async fn jwks_kids(app: Router) -> Vec<String> {
+    jwks(app)
+        .await
+        .iter()
+        .map(|key| key["kid"].as_str().unwrap().to_string())
+        .collect()
+}
+
+async fn list_sessions_status(app: Router, user_id: &str, token: &str) -> StatusCode {
+    let request = Request::builder()
+        .uri(format!("/api/user/{}/session", user_id))
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::empty())
+        .unwrap();
+    app.oneshot(request).await.unwrap().status()
+}
+
+fn signing_key_from(stored: &signing_key::StoredSigningKey, kid: &str) -> SigningKey {
+    SigningKey {
+        kid: kid.to_string(),
+        ..stored.signing_key().unwrap()
+    }
+}
+
+#[tokio::test]
+async fn test_issued_token_kid_is_published_in_jwks() {
+    let (_, app) = setup().await;
+    let user_id = helpers::unique_user_id("kid");
+
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+    let kid = token_kid(&token).expect("token header must carry a kid");
+
+    assert_eq!(jwks_kids(app).await, vec![kid]);
+}
+
+#[tokio::test]
+async fn test_rotation_keeps_old_tokens_valid() {
+    let (state, app) = setup().await;
+    let user_id = helpers::unique_user_id("rotate");
+
+    let old_token = helpers::issue_token(app.clone(), &user_id, None).await;
+    let old_kid = token_kid(&old_token).unwrap();
+
+    let new_kid = state.rotate_signing_key().await.unwrap();
+    assert_ne!(old_kid, new_kid);
+
+    // 새 토큰은 새 키로 서명되고, 이전 토큰도 만료 전까지 계속 쓸 수 있습니다.
+    let new_token = helpers::issue_token(app.clone(), &user_id, None).await;
+    assert_eq!(token_kid(&new_token), Some(new_kid.clone()));
+    for token in [&old_token, &new_token] {
+        assert_eq!(
+            list_sessions_status(app.clone(), &user_id, token).await,
+            StatusCode::OK
+        );
+    }
+
+    assert_eq!(jwks_kids(app).await, vec![new_kid.clone(), old_kid.clone()]);
+
+    // 이전 키는 가장 긴 토큰이 만료될 즈음 사라지고, 활성 키는 만료되지 않습니다.
+    let mut conn = state.connection_redis().await.unwrap();
+    let retire_after = signing_key::retire_after_seconds(state.share().token_policy());
+    let old_ttl = conn
+        .ttl(state.keys().signing_key(&old_kid))
+        .await
+        .unwrap()
+        .raw();
+    assert!(old_ttl > 0 && old_ttl as u64 <= retire_after, "{old_ttl}");
+    let new_ttl = conn
+        .ttl(state.keys().signing_key(&new_kid))
+        .await
+        .unwrap()
+        .raw();
+    assert_eq!(new_ttl, -1);
+}
+
+#[tokio::test]
+async fn test_rotation_is_seen_by_other_instances() {
+    let (state, app) = setup().await;
+    let other = AppState::new(
+        state.redis_client().clone(),
+        state.keys().clone(),
+        Default::default(),
+        Default::default(),
+    );
+    let other_app = backend::route::router().with_state(other.clone());
+    let user_id = helpers::unique_user_id("rotate");
+
+    // 다른 인스턴스가 키를 캐시한 뒤에 교체합니다.
+    let token = helpers::issue_token(other_app.clone(), &user_id, None).await;
+    assert_eq!(
+        list_sessions_status(other_app.clone(), &user_id, &token).await,
+        StatusCode::OK
+    );
+    state.rotate_signing_key().await.unwrap();
+
+    // 새 키로 서명된 토큰은 캐시에 없는 kid라도 다시 읽어서 검증합니다.
+    // 모르는 kid로 인한 재조회는 5초에 한 번으로 제한되므로 그만큼 기다립니다.
+    tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+    let token = helpers::issue_token(app, &user_id, None).await;
+    assert_eq!(
+        list_sessions_status(other_app, &user_id, &token).await,
+        StatusCode::OK
+    );
+}
+
+#[tokio::test]
+async fn test_unknown_or_forged_kid_is_rejected() {
+    let (state, app) = setup().await;
+    let user_id = helpers::unique_user_id("kid");
+    helpers::register_user(app.clone(), &user_id).await;
+
+    let active_kid = state
+        .share()
+        .get_signing_key(state.redis_client())
+        .await
+        .unwrap()
+        .kid
+        .clone();
+    let foreign = signing_key::generate(Default::default()).unwrap();
+
+    for kid in ["20200101-deadbeef", active_kid.as_str()] {
+        let token = jwt::create_token(
+            &user_id,
+            0,
+            Default::default(),
+            &signing_key_from(&foreign, kid),
+            3600,
+        )
+        .unwrap();
+        assert_eq!(
+            list_sessions_status(app.clone(), &user_id, &token).await,
+            StatusCode::UNAUTHORIZED,
+            "{kid}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_rotate_if_due() {
+    let (state, _) = setup().await;
+
+    assert_eq!(
+        state
+            .rotate_signing_key_if_due(Some(24 * 60 * 60))
+            .await
+            .unwrap(),
+        None
+    );
+    let kid = state
+        .rotate_signing_key_if_due(Some(0))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        state
+            .share()
+            .get_signing_key(state.redis_client())
+            .await
+            .unwrap()
+            .kid,
+        kid
+    );
+}
+
+#[tokio::test]
+async fn test_legacy_key_is_imported_and_tokens_without_kid_still_verify() {
+    let redis_url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1/".to_string());
+    let redis_client = redis::Client::open(redis_url).unwrap();
+    let keys = Keys::new(helpers::unique_user_id("jwk-legacy"));
+
+    // kid 도입 이전처럼 단일 키만 있는 저장소.
+    let legacy = signing_key::generate(SigningAlgorithm::RS256).unwrap();
+    let mut conn = redis_client
+        .get_multiplexed_async_connection()
+        .await
+        .unwrap();
+    conn.set(keys.rsa_private_pem(), legacy.private_pem.as_str())
+        .await
+        .unwrap();
+    conn.set(keys.rsa_public_pem(), legacy.public_pem.as_str())
+        .await
+        .unwrap();
+
+    let app = helpers::create_test_app_with_keys(redis_client, keys).await;
+    let user_id = helpers::unique_user_id("legacy");
+    helpers::register_user(app.clone(), &user_id).await;
+
+    let now = chrono::Utc::now().timestamp() as usize;
+    let claims = jwt::Claims {
+        sub: user_id.clone(),
+        exp: now + 600,
+        iat: now,
+        iss: "realtime-svg".to_string(),
+        generation: 0,
+        jti: None,
+        scopes: None,
+        orgs: Default::default(),
+        admin: false,
+    };
+    let token = jsonwebtoken::encode(
+        &Header::new(jsonwebtoken::Algorithm::RS256),
+        &claims,
+        &EncodingKey::from_rsa_pem(legacy.private_pem.as_bytes()).unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        list_sessions_status(app.clone(), &user_id, &token).await,
+        StatusCode::OK
+    );
+    assert_eq!(jwks_kids(app).await.len(), 1);
+}
+
+#[tokio::test]
+async fn test_compact_algorithms_issue_verifiable_tokens() {
+    for (algorithm, kty) in [
+        (SigningAlgorithm::ES256, "EC"),
+        (SigningAlgorithm::EdDSA, "OKP"),
+    ] {
+        let (_, app) = setup_with(Keys::new(helpers::unique_user_id("jwk")), algorithm).await;
+        let user_id = helpers::unique_user_id("alg");
+
+        let token = helpers::issue_token(app.clone(), &user_id, None).await;
+        let header = jsonwebtoken::decode_header(&token).unwrap();
+        assert_eq!(header.alg, algorithm.jwt_algorithm());
+        assert_eq!(
+            list_sessions_status(app.clone(), &user_id, &token).await,
+            StatusCode::OK
+        );
+
+        let keys = jwks(app).await;
+        assert_eq!(keys.len(), 1);
+        assert_eq!(keys[0]["kty"], kty);
+        assert_eq!(keys[0]["alg"], algorithm.as_str());
+        assert_eq!(keys[0]["kid"].as_str(), header.kid.as_deref());
+    }
+}
+
+#[tokio::test]
+async fn test_changing_algorithm_rotates_and_keeps_old_tokens_valid() {
+    let keys = Keys::new(helpers::unique_user_id("jwk"));
+    let (_, app) = setup_with(keys.clone(), SigningAlgorithm::RS256).await;
+    let user_id = helpers::unique_user_id("alg");
+    let old_token = helpers::issue_token(app, &user_id, None).await;
+
+    // ES256로 설정을 바꿔 다시 시작합니다.
+    let (state, app) = setup_with(keys, SigningAlgorithm::ES256).await;
+    assert!(state
+        .rotate_signing_key_if_due(None)
+        .await
+        .unwrap()
+        .is_some());
+    assert_eq!(state.rotate_signing_key_if_due(None).await.unwrap(), None);
+
+    let new_token = helpers::issue_token(app.clone(), &user_id, None).await;
+    assert_eq!(
+        jsonwebtoken::decode_header(&new_token).unwrap().alg,
+        jsonwebtoken::Algorithm::ES256
+    );
+    for token in [&old_token, &new_token] {
+        assert_eq!(
+            list_sessions_status(app.clone(), &user_id, token).await,
+            StatusCode::OK
+        );
+    }
+
+    let algs: Vec<_> = jwks(app)
+        .await
+        .iter()
+        .map(|key| key["alg"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(algs, vec!["ES256", "RS256"]);
+}
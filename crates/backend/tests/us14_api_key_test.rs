@@ -0,0 +1,259 @@
+mod helpers;
+
+use std::time::Duration;
+
+use axum::{http::StatusCode, Router};
+use helpers::Auth;
+use serde_json::{json, Value};
+
+fn x_api_key(key: &str) -> Auth<'_> {
+    Auth::Header("x-api-key", key)
+}
+
+async fn create_api_key(
+    app: Router,
+    user_id: &str,
+    token: &str,
+    body: Value,
+) -> (StatusCode, Value) {
+    helpers::send(
+        app,
+        "POST",
+        &format!("/api/user/{}/api-key", user_id),
+        token,
+        Some(body),
+    )
+    .await
+}
+
+async fn list_api_keys(app: Router, user_id: &str, token: &str) -> Vec<Value> {
+    let (status, body) = helpers::send(
+        app,
+        "GET",
+        &format!("/api/user/{}/api-key", user_id),
+        token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    body["items"].as_array().unwrap().clone()
+}
+
+async fn list_sessions_status(app: Router, user_id: &str, auth: Auth<'_>) -> StatusCode {
+    helpers::send(
+        app,
+        "GET",
+        &format!("/api/user/{}/session", user_id),
+        auth,
+        None,
+    )
+    .await
+    .0
+}
+
+#[tokio::test]
+async fn test_api_key_is_shown_once_and_authenticates() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("apikey");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+
+    let (status, created) = create_api_key(
+        app.clone(),
+        &user_id,
+        &token,
+        json!({"name": "  CI deploy "}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let api_key = created["api_key"].as_str().unwrap().to_string();
+    assert!(api_key.starts_with("rsvg_"));
+    assert_eq!(created["name"], "CI deploy");
+    assert_eq!(created["expires_at"], Value::Null);
+
+    // 목록에는 키 원문이 없습니다.
+    let keys = list_api_keys(app.clone(), &user_id, &token).await;
+    assert_eq!(keys.len(), 1);
+    assert_eq!(keys[0]["key_id"], created["key_id"]);
+    assert_eq!(keys[0]["last_used_at"], Value::Null);
+    assert!(!keys[0].to_string().contains(&api_key));
+
+    let authorization = format!("ApiKey {}", api_key);
+    for auth in [
+        x_api_key(&api_key),
+        Auth::Header("authorization", &authorization),
+    ] {
+        assert_eq!(
+            list_sessions_status(app.clone(), &user_id, auth).await,
+            StatusCode::OK
+        );
+    }
+
+    // 세션 생성과 인자 갱신도 API 키로 할 수 있습니다.
+    let session_id = helpers::unique_session_id("iot");
+    let (status, _) = helpers::send(
+        app.clone(),
+        "POST",
+        &format!("/api/user/{}/session", user_id),
+        x_api_key(&api_key),
+        Some(json!({"session_id": session_id, "template": "<svg>{{ v }}</svg>", "args": {"v": 1}})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, _) = helpers::send(
+        app.clone(),
+        "PUT",
+        &format!("/api/user/{}/session/{}", user_id, session_id),
+        x_api_key(&api_key),
+        Some(json!({"args": {"v": 2}})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let keys = list_api_keys(app, &user_id, &token).await;
+    assert!(keys[0]["last_used_at"].is_string());
+}
+
+#[tokio::test]
+async fn test_revoked_or_unknown_api_key_is_rejected() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("apikey");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+
+    let (_, created) =
+        create_api_key(app.clone(), &user_id, &token, json!({"name": "device"})).await;
+    let api_key = created["api_key"].as_str().unwrap().to_string();
+    let key_id = created["key_id"].as_str().unwrap();
+
+    let uri = format!("/api/user/{}/api-key/{}", user_id, key_id);
+    let (status, _) = helpers::send(app.clone(), "DELETE", &uri, &token, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = helpers::send(app.clone(), "DELETE", &uri, &token, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    assert!(list_api_keys(app.clone(), &user_id, &token)
+        .await
+        .is_empty());
+    for key in [api_key.as_str(), "rsvg_0123456789abcdef_nope", "garbage"] {
+        assert_eq!(
+            list_sessions_status(app.clone(), &user_id, x_api_key(key)).await,
+            StatusCode::UNAUTHORIZED,
+            "{key}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_api_key_expires() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("apikey");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+
+    let (status, created) = create_api_key(
+        app.clone(),
+        &user_id,
+        &token,
+        json!({"name": "short", "expires_in_seconds": 1}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert!(created["expires_at"].is_string());
+    let api_key = created["api_key"].as_str().unwrap().to_string();
+
+    assert_eq!(
+        list_sessions_status(app.clone(), &user_id, x_api_key(&api_key)).await,
+        StatusCode::OK
+    );
+    tokio::time::sleep(Duration::from_millis(1500)).await;
+    assert_eq!(
+        list_sessions_status(app.clone(), &user_id, x_api_key(&api_key)).await,
+        StatusCode::UNAUTHORIZED
+    );
+    assert!(list_api_keys(app, &user_id, &token).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_invalid_create_requests() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("apikey");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+
+    for body in [
+        json!({"name": "   "}),
+        json!({"name": "x".repeat(65)}),
+        json!({"name": "ci", "expires_in_seconds": 0}),
+    ] {
+        let (status, _) = create_api_key(app.clone(), &user_id, &token, body.clone()).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{body}");
+    }
+
+    let other_id = helpers::unique_user_id("apikey");
+    let (status, _) = create_api_key(app, &other_id, &token, json!({"name": "ci"})).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_api_key_cannot_manage_api_keys() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("apikey");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+    let (_, created) = create_api_key(app.clone(), &user_id, &token, json!({"name": "ci"})).await;
+    let api_key = created["api_key"].as_str().unwrap().to_string();
+
+    let uri = format!("/api/user/{}/api-key", user_id);
+    let (status, _) = helpers::send(
+        app.clone(),
+        "POST",
+        &uri,
+        x_api_key(&api_key),
+        Some(json!({"name": "escalated"})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let (status, _) = helpers::send(app, "GET", &uri, x_api_key(&api_key), None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_api_keys_survive_logout_but_not_account_deletion() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("apikey");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+    let (_, created) = create_api_key(app.clone(), &user_id, &token, json!({"name": "ci"})).await;
+    let api_key = created["api_key"].as_str().unwrap().to_string();
+
+    let (status, _) = helpers::send(
+        app.clone(),
+        "POST",
+        "/api/auth/logout",
+        &token,
+        Some(json!({"all": true})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    assert_eq!(
+        list_sessions_status(app.clone(), &user_id, x_api_key(&api_key)).await,
+        StatusCode::OK
+    );
+
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+    let (status, _) = helpers::send(
+        app.clone(),
+        "DELETE",
+        &format!("/api/user/{}", user_id),
+        &token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    assert_eq!(
+        list_sessions_status(app.clone(), &user_id, x_api_key(&api_key)).await,
+        StatusCode::UNAUTHORIZED
+    );
+
+    // 같은 이름으로 다시 가입해도 이전 계정의 키는 되살아나지 않습니다.
+    helpers::register_user(app.clone(), &user_id).await;
+    assert_eq!(
+        list_sessions_status(app, &user_id, x_api_key(&api_key)).await,
+        StatusCode::UNAUTHORIZED
+    );
+}
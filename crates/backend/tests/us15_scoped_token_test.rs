@@ -0,0 +1,271 @@
+mod helpers;
+
+use axum::{http::StatusCode, Router};
+use serde_json::{json, Value};
+
+async fn mint(
+    app: Router,
+    user_id: &str,
+    session_id: &str,
+    token: &str,
+    body: Value,
+) -> (StatusCode, Value) {
+    helpers::send(
+        app,
+        "POST",
+        &format!("/api/user/{}/session/{}/token", user_id, session_id),
+        token,
+        Some(body),
+    )
+    .await
+}
+
+/// 사용자, 로그인 토큰, 세션 두 개를 만듭니다.
+async fn setup(app: Router) -> (String, String, String, String) {
+    let user_id = helpers::unique_user_id("scoped");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+    let sensor = helpers::unique_session_id("sensor");
+    let other = helpers::unique_session_id("other");
+    for session_id in [&sensor, &other] {
+        helpers::create_user_session(
+            app.clone(),
+            &token,
+            &user_id,
+            session_id,
+            "<svg>{{ v }}</svg>",
+        )
+        .await;
+    }
+    (user_id, token, sensor, other)
+}
+
+fn put_args() -> Option<Value> {
+    Some(json!({"args": {"v": 2}}))
+}
+
+#[tokio::test]
+async fn test_write_token_only_updates_its_session() {
+    let app = helpers::create_test_app().await;
+    let (user_id, token, sensor, other) = setup(app.clone()).await;
+
+    let (status, minted) = mint(app.clone(), &user_id, &sensor, &token, json!({})).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(
+        minted["scopes"],
+        json!([format!("session:{}/{}:write", user_id, sensor)])
+    );
+    let scoped = minted["token"].as_str().unwrap().to_string();
+
+    let session_uri = |session_id: &str| format!("/api/user/{}/session/{}", user_id, session_id);
+    let (status, _) = helpers::send(
+        app.clone(),
+        "PUT",
+        &session_uri(&sensor),
+        &scoped,
+        put_args(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    // 다른 세션, 읽기, 목록
+    let (status, _) = helpers::send(
+        app.clone(),
+        "PUT",
+        &session_uri(&other),
+        &scoped,
+        put_args(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = helpers::send(app.clone(), "GET", &session_uri(&sensor), &scoped, None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("/api/user/{}/session", user_id),
+        &scoped,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    // 계정 작업과 새 토큰 발급
+    let (status, _) = mint(app.clone(), &user_id, &sensor, &scoped, json!({})).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = helpers::send(
+        app.clone(),
+        "POST",
+        &format!("/api/user/{}/api-key", user_id),
+        &scoped,
+        Some(json!({"name": "escalated"})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = helpers::send(
+        app.clone(),
+        "DELETE",
+        &format!("/api/user/{}", user_id),
+        &scoped,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    // 로그인 토큰은 그대로 모든 세션을 다룹니다.
+    let (status, _) = helpers::send(app, "PUT", &session_uri(&other), &token, put_args()).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn test_read_and_write_token() {
+    let app = helpers::create_test_app().await;
+    let (user_id, token, sensor, _) = setup(app.clone()).await;
+
+    let (status, minted) = mint(
+        app.clone(),
+        &user_id,
+        &sensor,
+        &token,
+        json!({"access": ["write", "read", "read"]}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(minted["scopes"].as_array().unwrap().len(), 2);
+    let scoped = minted["token"].as_str().unwrap().to_string();
+
+    let uri = format!("/api/user/{}/session/{}", user_id, sensor);
+    let (status, _) = helpers::send(app.clone(), "GET", &uri, &scoped, None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    // 세션을 지우거나 같은 ID로 다시 만들어 템플릿을 바꿀 수는 없습니다.
+    let (status, _) = helpers::send(app.clone(), "DELETE", &uri, &scoped, None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = helpers::send(
+        app.clone(),
+        "POST",
+        &format!("/api/user/{}/session", user_id),
+        &scoped,
+        Some(json!({"session_id": sensor, "template": "<svg/>", "args": {}})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, session) = helpers::send(app, "GET", &uri, &scoped, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(session["template"], "<svg>{{ v }}</svg>");
+}
+
+#[tokio::test]
+async fn test_write_token_cannot_change_metadata() {
+    let app = helpers::create_test_app().await;
+    let (user_id, token, sensor, _) = setup(app.clone()).await;
+    let (_, minted) = mint(app.clone(), &user_id, &sensor, &token, json!({})).await;
+    let scoped = minted["token"].as_str().unwrap().to_string();
+    let uri = format!("/api/user/{}/session/{}", user_id, sensor);
+    let (status, _) = helpers::send(
+        app.clone(),
+        "PUT",
+        &uri,
+        &token,
+        Some(json!({"visibility": "private"})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    for metadata in [
+        json!({"title": "Renamed"}),
+        json!({"description": "changed"}),
+        json!({"tags": ["hacked"]}),
+        json!({"visibility": "public"}),
+        json!({"listed": true}),
+        json!({"args": {"v": 3}, "title": "Renamed"}),
+    ] {
+        let (status, _) =
+            helpers::send(app.clone(), "PUT", &uri, &scoped, Some(metadata.clone())).await;
+        assert_eq!(status, StatusCode::FORBIDDEN, "{metadata}");
+    }
+
+    let (status, session) = helpers::send(app, "GET", &uri, &token, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(session["args"], json!({"msg": "hello"}));
+    assert!(session["title"].is_null());
+    assert_eq!(session["tags"], json!([]));
+    assert_eq!(session["visibility"], "private");
+    assert_eq!(session["listed"], false);
+}
+
+#[tokio::test]
+async fn test_mint_validation_and_ttl() {
+    let app = helpers::create_test_app().await;
+    let (user_id, token, sensor, _) = setup(app.clone()).await;
+
+    let (status, minted) = mint(app.clone(), &user_id, &sensor, &token, json!({})).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(minted["expires_in"], 24 * 60 * 60);
+
+    let (status, minted) = mint(
+        app.clone(),
+        &user_id,
+        &sensor,
+        &token,
+        json!({"ttl_seconds": 10 * 365 * 24 * 60 * 60}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(minted["expires_in"], 30 * 24 * 60 * 60);
+
+    let (status, _) = mint(
+        app.clone(),
+        &user_id,
+        &sensor,
+        &token,
+        json!({"access": []}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let (status, _) = mint(
+        app.clone(),
+        &user_id,
+        &sensor,
+        &token,
+        json!({"access": ["admin"]}),
+    )
+    .await;
+    assert!(status.is_client_error(), "{status}");
+
+    let (status, _) = mint(app.clone(), &user_id, "missing-session", &token, json!({})).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let other_user = helpers::unique_user_id("scoped");
+    let (status, _) = mint(app, &other_user, &sensor, &token, json!({})).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_logout_all_revokes_scoped_tokens() {
+    let app = helpers::create_test_app().await;
+    let (user_id, token, sensor, _) = setup(app.clone()).await;
+
+    let (_, minted) = mint(app.clone(), &user_id, &sensor, &token, json!({})).await;
+    let scoped = minted["token"].as_str().unwrap().to_string();
+
+    let (status, _) = helpers::send(
+        app.clone(),
+        "POST",
+        "/api/auth/logout",
+        &token,
+        Some(json!({"all": true})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, _) = helpers::send(
+        app,
+        "PUT",
+        &format!("/api/user/{}/session/{}", user_id, sensor),
+        &scoped,
+        put_args(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
@@ -0,0 +1,335 @@
+mod helpers;
+
+use std::time::Duration;
+
+use axum::{http::StatusCode, response::Response, Router};
+use serde_json::{json, Value};
+
+async fn set_visibility(app: Router, token: &str, user_id: &str, session_id: &str, value: &str) {
+    let (status, _) = helpers::send(
+        app,
+        "PUT",
+        &format!("/api/user/{}/session/{}", user_id, session_id),
+        token,
+        Some(json!({"visibility": value})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+}
+
+async fn create_share_link(
+    app: Router,
+    token: &str,
+    user_id: &str,
+    session_id: &str,
+    body: Value,
+) -> (StatusCode, Value) {
+    helpers::send(
+        app,
+        "POST",
+        &format!("/api/user/{}/session/{}/share-link", user_id, session_id),
+        token,
+        Some(body),
+    )
+    .await
+}
+
+fn snapshot_uri(user_id: &str, session_id: &str, viewer_token: Option<&str>) -> String {
+    match viewer_token {
+        Some(viewer_token) => format!(
+            "/stream/{}/{}/snapshot?token={}",
+            user_id, session_id, viewer_token
+        ),
+        None => format!("/stream/{}/{}/snapshot", user_id, session_id),
+    }
+}
+
+async fn snapshot_status(app: Router, uri: &str) -> StatusCode {
+    helpers::send_raw(app, "GET", uri, helpers::Auth::Anonymous, None)
+        .await
+        .status()
+}
+
+/// 스트림이 `timeout` 안에 끝나면 `true`를 반환합니다.
+async fn stream_ends_within(response: Response, timeout: Duration) -> bool {
+    tokio::time::timeout(
+        timeout,
+        axum::body::to_bytes(response.into_body(), usize::MAX),
+    )
+    .await
+    .is_ok()
+}
+
+/// 사용자, 로그인 토큰, 비공개 세션을 만듭니다.
+async fn setup(app: Router) -> (String, String, String) {
+    let user_id = helpers::unique_user_id("private");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+    let session_id = helpers::unique_session_id("private");
+    helpers::create_user_session(app.clone(), &token, &user_id, &session_id, "<svg></svg>").await;
+    set_visibility(app, &token, &user_id, &session_id, "private").await;
+    (user_id, token, session_id)
+}
+
+#[tokio::test]
+async fn test_private_session_requires_viewer_token() {
+    let app = helpers::create_test_app().await;
+    let (user_id, token, session_id) = setup(app.clone()).await;
+
+    let (status, detail) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("/api/user/{}/session/{}", user_id, session_id),
+        &token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(detail["visibility"], "private");
+
+    let stream_uri = format!("/stream/{}/{}?as_bot=true", user_id, session_id);
+    assert_eq!(
+        helpers::send_raw(
+            app.clone(),
+            "GET",
+            &stream_uri,
+            helpers::Auth::Anonymous,
+            None
+        )
+        .await
+        .status(),
+        StatusCode::UNAUTHORIZED
+    );
+    assert_eq!(
+        snapshot_status(app.clone(), &snapshot_uri(&user_id, &session_id, None)).await,
+        StatusCode::UNAUTHORIZED
+    );
+
+    let (status, link) = create_share_link(
+        app.clone(),
+        &token,
+        &user_id,
+        &session_id,
+        json!({"name": " 고객 A "}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(link["name"], "고객 A");
+    let viewer_token = link["token"].as_str().unwrap().to_string();
+    assert_eq!(
+        link["url"],
+        format!("/stream/{}/{}?token={}", user_id, session_id, viewer_token)
+    );
+
+    let response = helpers::send_raw(
+        app.clone(),
+        "GET",
+        &format!("{}&as_bot=true", link["url"].as_str().unwrap()),
+        helpers::Auth::Anonymous,
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        snapshot_status(
+            app.clone(),
+            &snapshot_uri(&user_id, &session_id, Some(&viewer_token))
+        )
+        .await,
+        StatusCode::OK
+    );
+
+    // 로그인 토큰, 다른 세션의 링크, 형식이 틀린 값은 뷰어 토큰이 아닙니다.
+    let other_session = helpers::unique_session_id("private");
+    helpers::create_user_session(app.clone(), &token, &user_id, &other_session, "<svg/>").await;
+    set_visibility(app.clone(), &token, &user_id, &other_session, "private").await;
+    for candidate in [token.as_str(), viewer_token.as_str(), "garbage"] {
+        assert_eq!(
+            snapshot_status(
+                app.clone(),
+                &snapshot_uri(&user_id, &other_session, Some(candidate))
+            )
+            .await,
+            StatusCode::UNAUTHORIZED,
+            "{candidate}"
+        );
+    }
+
+    // 미등록·공개 세션은 토큰 없이 볼 수 있습니다.
+    for visibility in ["unlisted", "public"] {
+        set_visibility(app.clone(), &token, &user_id, &session_id, visibility).await;
+        assert_eq!(
+            snapshot_status(app.clone(), &snapshot_uri(&user_id, &session_id, None)).await,
+            StatusCode::OK,
+            "{visibility}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_revoking_share_link_ends_its_streams() {
+    let app = helpers::create_test_app().await;
+    let (user_id, token, session_id) = setup(app.clone()).await;
+
+    let (_, link) = create_share_link(app.clone(), &token, &user_id, &session_id, json!({})).await;
+    let link_id = link["link_id"].as_str().unwrap().to_string();
+    let viewer_token = link["token"].as_str().unwrap().to_string();
+    let (_, other) = create_share_link(app.clone(), &token, &user_id, &session_id, json!({})).await;
+
+    let links_uri = format!("/api/user/{}/session/{}/share-link", user_id, session_id);
+    let (status, list) = helpers::send(app.clone(), "GET", &links_uri, &token, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(list["items"].as_array().unwrap().len(), 2);
+    assert!(!list.to_string().contains(&viewer_token));
+
+    let stream_uri = |viewer_token: &str| {
+        format!(
+            "/stream/{}/{}?token={}&as_bot=true",
+            user_id, session_id, viewer_token
+        )
+    };
+    let revoked_stream = helpers::send_raw(
+        app.clone(),
+        "GET",
+        &stream_uri(&viewer_token),
+        helpers::Auth::Anonymous,
+        None,
+    )
+    .await;
+    assert_eq!(revoked_stream.status(), StatusCode::OK);
+    let other_stream = helpers::send_raw(
+        app.clone(),
+        "GET",
+        &stream_uri(other["token"].as_str().unwrap()),
+        helpers::Auth::Anonymous,
+        None,
+    )
+    .await;
+    assert_eq!(other_stream.status(), StatusCode::OK);
+
+    let link_uri = format!("{}/{}", links_uri, link_id);
+    let (status, _) = helpers::send(app.clone(), "DELETE", &link_uri, &token, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = helpers::send(app.clone(), "DELETE", &link_uri, &token, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    assert!(stream_ends_within(revoked_stream, Duration::from_secs(5)).await);
+    assert!(!stream_ends_within(other_stream, Duration::from_millis(500)).await);
+    assert_eq!(
+        snapshot_status(
+            app.clone(),
+            &snapshot_uri(&user_id, &session_id, Some(&viewer_token))
+        )
+        .await,
+        StatusCode::UNAUTHORIZED
+    );
+    let (_, list) = helpers::send(app, "GET", &links_uri, &token, None).await;
+    assert_eq!(list["items"].as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_going_private_ends_anonymous_streams() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("private");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+    let session_id = helpers::unique_session_id("live");
+    helpers::create_user_session(app.clone(), &token, &user_id, &session_id, "<svg></svg>").await;
+
+    let stream = helpers::send_raw(
+        app.clone(),
+        "GET",
+        &format!("/stream/{}/{}?as_bot=true", user_id, session_id),
+        helpers::Auth::Anonymous,
+        None,
+    )
+    .await;
+    assert_eq!(stream.status(), StatusCode::OK);
+
+    set_visibility(app, &token, &user_id, &session_id, "private").await;
+    assert!(stream_ends_within(stream, Duration::from_secs(5)).await);
+}
+
+#[tokio::test]
+async fn test_visibility_and_share_link_rules() {
+    let app = helpers::create_test_app().await;
+    let (user_id, token, session_id) = setup(app.clone()).await;
+    let session_uri = format!("/api/user/{}/session/{}", user_id, session_id);
+
+    // 비공개 세션은 디렉터리에 등록할 수 없습니다.
+    let (status, _) = helpers::send(
+        app.clone(),
+        "PUT",
+        &session_uri,
+        &token,
+        Some(json!({"title": "t", "listed": true})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, link) = create_share_link(
+        app.clone(),
+        &token,
+        &user_id,
+        &session_id,
+        json!({"ttl_seconds": 10 * 365 * 24 * 60 * 60}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let lifetime = chrono::DateTime::parse_from_rfc3339(link["expires_at"].as_str().unwrap())
+        .unwrap()
+        - chrono::DateTime::parse_from_rfc3339(link["created_at"].as_str().unwrap()).unwrap();
+    assert_eq!(lifetime.num_seconds(), 30 * 24 * 60 * 60);
+
+    let (status, _) = create_share_link(
+        app.clone(),
+        &token,
+        &user_id,
+        &session_id,
+        json!({"name": "x".repeat(65)}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let (status, _) = create_share_link(app.clone(), &token, &user_id, "missing", json!({})).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let other_user = helpers::unique_user_id("private");
+    let (status, _) =
+        create_share_link(app.clone(), &token, &other_user, &session_id, json!({})).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    // 세션 전용 토큰으로는 공유 링크를 만들 수 없습니다.
+    let (_, minted) = helpers::send(
+        app.clone(),
+        "POST",
+        &format!("{}/token", session_uri),
+        &token,
+        Some(json!({})),
+    )
+    .await;
+    let scoped = minted["token"].as_str().unwrap().to_string();
+    let (status, _) =
+        create_share_link(app.clone(), &scoped, &user_id, &session_id, json!({})).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    // 세션을 지우면 링크도 사라져, 같은 ID로 다시 만든 세션에는 통하지 않습니다.
+    let viewer_token = link["token"].as_str().unwrap().to_string();
+    let (status, _) = helpers::send(app.clone(), "DELETE", &session_uri, &token, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    helpers::create_user_session(app.clone(), &token, &user_id, &session_id, "<svg/>").await;
+    set_visibility(app.clone(), &token, &user_id, &session_id, "private").await;
+    assert_eq!(
+        snapshot_status(
+            app.clone(),
+            &snapshot_uri(&user_id, &session_id, Some(&viewer_token))
+        )
+        .await,
+        StatusCode::UNAUTHORIZED
+    );
+    let (_, list) = helpers::send(
+        app,
+        "GET",
+        &format!("{}/share-link", session_uri),
+        &token,
+        None,
+    )
+    .await;
+    assert!(list["items"].as_array().unwrap().is_empty());
+}
@@ -0,0 +1,490 @@
+mod helpers;
+
+use axum::{http::StatusCode, Router};
+use helpers::User;
+use redis::AsyncTypedCommands;
+use serde_json::json;
+
+async fn share(
+    app: Router,
+    token: &str,
+    owner: &str,
+    session_id: &str,
+    collaborator: &str,
+    role: &str,
+) -> StatusCode {
+    helpers::send(
+        app,
+        "PUT",
+        &format!(
+            "/api/user/{}/session/{}/collaborator/{}",
+            owner, session_id, collaborator
+        ),
+        token,
+        Some(json!({"role": role})),
+    )
+    .await
+    .0
+}
+
+/// alice의 세션 하나를 만들고 bob은 편집자, carol은 뷰어로 추가합니다.
+async fn setup(app: Router) -> (User, User, User, String) {
+    let alice = helpers::user(app.clone(), "alice").await;
+    let bob = helpers::user(app.clone(), "bob").await;
+    let carol = helpers::user(app.clone(), "carol").await;
+    let session_id = helpers::unique_session_id("shared");
+    alice
+        .create_session(app.clone(), &session_id, "<svg>{{ v }}</svg>")
+        .await;
+
+    for (collaborator, role) in [(&bob, "editor"), (&carol, "viewer")] {
+        let status = share(
+            app.clone(),
+            &alice.token,
+            &alice.id,
+            &session_id,
+            &collaborator.id,
+            role,
+        )
+        .await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+    }
+    (alice, bob, carol, session_id)
+}
+
+#[tokio::test]
+async fn test_roles_control_read_and_write() {
+    let app = helpers::create_test_app().await;
+    let (alice, bob, carol, session_id) = setup(app.clone()).await;
+    let uri = format!("/api/user/{}/session/{}", alice.id, session_id);
+
+    let (status, detail) = helpers::send(app.clone(), "GET", &uri, &carol.token, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(detail["role"], "viewer");
+    let (status, _) = helpers::send(
+        app.clone(),
+        "PUT",
+        &uri,
+        &carol.token,
+        Some(json!({"args": {"v": 1}})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, _) = helpers::send(
+        app.clone(),
+        "PUT",
+        &uri,
+        &bob.token,
+        Some(json!({"args": {"v": 2}, "title": "편집자", "listed": false, "visibility": "public"})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (_, detail) = helpers::send(app.clone(), "GET", &uri, &bob.token, None).await;
+    assert_eq!(detail["role"], "editor");
+    assert_eq!(detail["args"]["v"], 2);
+    assert_eq!(detail["title"], "편집자");
+
+    // 공개 범위와 디렉터리 등록은 소유자만 바꿉니다.
+    let (status, _) = helpers::send(
+        app.clone(),
+        "PUT",
+        &uri,
+        &bob.token,
+        Some(json!({"visibility": "private"})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    // 삭제와 공유는 소유자만 할 수 있습니다.
+    let (status, _) = helpers::send(app.clone(), "DELETE", &uri, &bob.token, None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let status = share(
+        app.clone(),
+        &bob.token,
+        &alice.id,
+        &session_id,
+        &carol.id,
+        "editor",
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    // 협업자가 아니면 세션이 있는지도 알 수 없습니다.
+    let dave = helpers::user(app.clone(), "dave").await;
+    let (status, _) = helpers::send(app.clone(), "GET", &uri, &dave.token, None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("/api/user/{}/session/missing", alice.id),
+        &bob.token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, collaborators) = helpers::send(
+        app,
+        "GET",
+        &format!("{}/collaborator", uri),
+        &alice.token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(collaborators["items"].as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_shared_with_me_listing() {
+    let app = helpers::create_test_app().await;
+    let (alice, bob, _, session_id) = setup(app.clone()).await;
+    let private_session = helpers::unique_session_id("not_shared");
+    alice
+        .create_session(app.clone(), &private_session, "<svg/>")
+        .await;
+
+    let shared_uri = format!("/api/user/{}/shared", bob.id);
+    let (status, shared) = helpers::send(app.clone(), "GET", &shared_uri, &bob.token, None).await;
+    assert_eq!(status, StatusCode::OK);
+    let items = shared["items"].as_array().unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["owner"], alice.id.as_str());
+    assert_eq!(items[0]["session_id"], session_id.as_str());
+    assert_eq!(items[0]["role"], "editor");
+    assert_eq!(
+        items[0]["thumbnail_url"],
+        format!("/stream/{}/{}/snapshot", alice.id, session_id)
+    );
+
+    // 소유자의 세션 목록은 협업자에게도 보이지 않습니다.
+    let (status, _) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("/api/user/{}/session?sort=name", alice.id),
+        &bob.token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (_, listed) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("/api/user/{}/session", alice.id),
+        &alice.token,
+        None,
+    )
+    .await;
+    assert_eq!(listed["items"].as_array().unwrap().len(), 2);
+    assert!(listed["items"][0].get("role").is_none());
+
+    let (status, _) = helpers::send(app.clone(), "GET", &shared_uri, &alice.token, None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let remove_uri = format!(
+        "/api/user/{}/session/{}/collaborator/{}",
+        alice.id, session_id, bob.id
+    );
+    let (status, _) = helpers::send(app.clone(), "DELETE", &remove_uri, &alice.token, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = helpers::send(app.clone(), "DELETE", &remove_uri, &alice.token, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (_, shared) = helpers::send(app.clone(), "GET", &shared_uri, &bob.token, None).await;
+    assert!(shared["items"].as_array().unwrap().is_empty());
+    let (status, _) = helpers::send(
+        app,
+        "GET",
+        &format!("/api/user/{}/session/{}", alice.id, session_id),
+        &bob.token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_repost_does_not_overwrite_shared_session() {
+    let app = helpers::create_test_app().await;
+    let (alice, bob, _, session_id) = setup(app.clone()).await;
+    let uri = format!("/api/user/{}/session/{}", alice.id, session_id);
+
+    let (status, _) = helpers::send(
+        app.clone(),
+        "POST",
+        &format!("/api/user/{}/session", alice.id),
+        &alice.token,
+        Some(json!({"session_id": session_id, "template": "<svg/>", "args": {}})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    // 협업자와 공유받은 세션 인덱스가 그대로 남습니다.
+    let (status, collaborators) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("{}/collaborator", uri),
+        &alice.token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(collaborators["items"].as_array().unwrap().len(), 2);
+    let (_, shared) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("/api/user/{}/shared", bob.id),
+        &bob.token,
+        None,
+    )
+    .await;
+    assert_eq!(shared["items"].as_array().unwrap().len(), 1);
+    let (status, detail) = helpers::send(app, "GET", &uri, &bob.token, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(detail["role"], "editor");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_revoke_during_update_is_not_overwritten() {
+    let app = helpers::create_test_app().await;
+    let (alice, bob, _, session_id) = setup(app.clone()).await;
+    let uri = format!("/api/user/{}/session/{}", alice.id, session_id);
+
+    let redis_url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1/".to_string());
+    let state = common::state::AppState::new(
+        redis::Client::open(redis_url).unwrap(),
+        Default::default(),
+        Default::default(),
+        Default::default(),
+    );
+
+    // bob의 수정이 세션을 읽은 뒤 저장하기 전에 alice가 bob을 협업자에서 뺀 상황입니다.
+    let mut attempts = 0;
+    let result = state
+        .update_user_session(&alice.id, &session_id, 3600, |session| {
+            attempts += 1;
+            if attempts == 1 {
+                let removed =
+                    tokio::task::block_in_place(|| {
+                        tokio::runtime::Handle::current().block_on(
+                            state.remove_session_collaborator(&alice.id, &session_id, &bob.id),
+                        )
+                    });
+                assert!(removed.unwrap());
+            }
+            if session.role_of(&bob.id).is_none() {
+                return Err(common::errors::ApiError::Forbidden("revoked".to_string()));
+            }
+            session.touch();
+            Ok(())
+        })
+        .await;
+    assert_eq!(attempts, 2);
+    assert!(matches!(
+        result,
+        Err(common::errors::ApiError::Forbidden(_))
+    ));
+
+    // 예전 협업자 목록으로 덮어쓰지 않았으므로 bob의 권한이 되살아나지 않습니다.
+    let (status, _) = helpers::send(app.clone(), "GET", &uri, &bob.token, None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (_, collaborators) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("{}/collaborator", uri),
+        &alice.token,
+        None,
+    )
+    .await;
+    assert_eq!(collaborators["items"].as_array().unwrap().len(), 1);
+    let (status, _) = helpers::send(
+        app,
+        "PUT",
+        &uri,
+        &bob.token,
+        Some(json!({"args": {"v": "stale"}})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_collaborator_validation() {
+    let app = helpers::create_test_app().await;
+    let (alice, bob, _, session_id) = setup(app.clone()).await;
+
+    let unknown = helpers::unique_user_id("nobody");
+    let status = share(
+        app.clone(),
+        &alice.token,
+        &alice.id,
+        &session_id,
+        &unknown,
+        "viewer",
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let status = share(
+        app.clone(),
+        &alice.token,
+        &alice.id,
+        &session_id,
+        &alice.id,
+        "editor",
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let status = share(
+        app.clone(),
+        &alice.token,
+        &alice.id,
+        &session_id,
+        &bob.id,
+        "owner",
+    )
+    .await;
+    assert!(status.is_client_error(), "{status}");
+    let status = share(
+        app.clone(),
+        &alice.token,
+        &alice.id,
+        "missing-session",
+        &bob.id,
+        "viewer",
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    // 범위가 제한된 토큰은 공유받은 세션에 쓸 수 없습니다.
+    let bob_session = helpers::unique_session_id("bob");
+    bob.create_session(app.clone(), &bob_session, "<svg/>")
+        .await;
+    let (status, minted) = helpers::send(
+        app.clone(),
+        "POST",
+        &format!("/api/user/{}/session/{}/token", bob.id, bob_session),
+        &bob.token,
+        Some(json!({"access": ["read", "write"]})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let scoped = minted["token"].as_str().unwrap();
+    let (status, _) = helpers::send(
+        app,
+        "GET",
+        &format!("/api/user/{}/session/{}", alice.id, session_id),
+        scoped,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_deleted_session_and_account_lose_access() {
+    let app = helpers::create_test_app().await;
+    let (alice, bob, carol, session_id) = setup(app.clone()).await;
+    let other_session = helpers::unique_session_id("other");
+    alice
+        .create_session(app.clone(), &other_session, "<svg/>")
+        .await;
+    let status = share(
+        app.clone(),
+        &alice.token,
+        &alice.id,
+        &other_session,
+        &bob.id,
+        "viewer",
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, _) = helpers::send(
+        app.clone(),
+        "DELETE",
+        &format!("/api/user/{}/session/{}", alice.id, session_id),
+        &alice.token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (_, shared) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("/api/user/{}/shared", carol.id),
+        &carol.token,
+        None,
+    )
+    .await;
+    assert!(shared["items"].as_array().unwrap().is_empty());
+
+    // 계정을 지우고 같은 ID로 다시 가입해도 권한은 남지 않습니다.
+    let (status, _) = helpers::send(
+        app.clone(),
+        "DELETE",
+        &format!("/api/user/{}", bob.id),
+        &bob.token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let new_token = helpers::issue_token(app.clone(), &bob.id, None).await;
+    let (status, _) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("/api/user/{}/session/{}", alice.id, other_session),
+        &new_token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (_, collaborators) = helpers::send(
+        app,
+        "GET",
+        &format!(
+            "/api/user/{}/session/{}/collaborator",
+            alice.id, other_session
+        ),
+        &alice.token,
+        None,
+    )
+    .await;
+    assert!(collaborators["items"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_deleted_owner_is_removed_from_shared_indexes() {
+    let app = helpers::create_test_app().await;
+    let (alice, bob, carol, session_id) = setup(app.clone()).await;
+
+    let (status, _) = helpers::send(
+        app.clone(),
+        "DELETE",
+        &format!("/api/user/{}", alice.id),
+        &alice.token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    // 목록은 사라진 세션을 걸러내므로, 인덱스 자체가 비었는지 직접 확인합니다.
+    let redis_url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1/".to_string());
+    let mut conn = redis::Client::open(redis_url)
+        .unwrap()
+        .get_multiplexed_async_connection()
+        .await
+        .unwrap();
+    let keys = common::keys::Keys::default();
+    for collaborator in [&bob, &carol] {
+        let fields = conn
+            .hkeys(keys.user_shared_sessions(&collaborator.id))
+            .await
+            .unwrap();
+        assert!(
+            !fields.contains(&format!("{}/{}", alice.id, session_id)),
+            "{}",
+            collaborator.id
+        );
+    }
+}
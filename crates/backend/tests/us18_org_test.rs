@@ -0,0 +1,454 @@
+mod helpers;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use helpers::User;
+use serde_json::json;
+use tower::ServiceExt;
+
+async fn set_member(
+    app: Router,
+    owner: &User,
+    org_id: &str,
+    member: &str,
+    role: &str,
+) -> StatusCode {
+    helpers::send(
+        app,
+        "PUT",
+        &format!("/api/org/{}/member/{}", org_id, member),
+        &owner.token,
+        Some(json!({"role": role})),
+    )
+    .await
+    .0
+}
+
+async fn create_org_session(
+    app: Router,
+    user: &User,
+    org_id: &str,
+    session_id: &str,
+) -> StatusCode {
+    helpers::send(
+        app,
+        "POST",
+        &format!("/api/org/{}/session", org_id),
+        &user.token,
+        Some(json!({"session_id": session_id, "template": "<svg>{{ v }}</svg>", "args": {"v": 0}})),
+    )
+    .await
+    .0
+}
+
+/// alice가 소유자인 조직을 만들고 bob은 멤버, carol은 뷰어로 추가합니다.
+async fn setup(app: Router) -> (User, User, User, String) {
+    let mut alice = helpers::user(app.clone(), "alice").await;
+    let mut bob = helpers::user(app.clone(), "bob").await;
+    let mut carol = helpers::user(app.clone(), "carol").await;
+    let org_id = helpers::unique_session_id("team");
+
+    let (status, created) = helpers::send(
+        app.clone(),
+        "POST",
+        "/api/org",
+        &alice.token,
+        Some(json!({"org_id": org_id, "name": "Design team"})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(created["org_id"], org_id.as_str());
+
+    for (member, role) in [(&bob, "member"), (&carol, "viewer")] {
+        let status = set_member(app.clone(), &alice, &org_id, &member.id, role).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+    }
+    for member in [&mut alice, &mut bob, &mut carol] {
+        member.relogin(app.clone()).await;
+    }
+    (alice, bob, carol, org_id)
+}
+
+#[tokio::test]
+async fn test_org_roles_control_sessions() {
+    let app = helpers::create_test_app().await;
+    let (alice, bob, carol, org_id) = setup(app.clone()).await;
+    let session_id = helpers::unique_session_id("board");
+    let uri = format!("/api/org/{}/session/{}", org_id, session_id);
+
+    assert_eq!(
+        create_org_session(app.clone(), &bob, &org_id, &session_id).await,
+        StatusCode::CREATED
+    );
+    assert_eq!(
+        create_org_session(app.clone(), &carol, &org_id, "viewer-session").await,
+        StatusCode::FORBIDDEN
+    );
+
+    let (status, detail) = helpers::send(app.clone(), "GET", &uri, &carol.token, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(detail["org_role"], "viewer");
+    let (status, _) = helpers::send(
+        app.clone(),
+        "PUT",
+        &uri,
+        &carol.token,
+        Some(json!({"args": {"v": 1}})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, _) = helpers::send(
+        app.clone(),
+        "PUT",
+        &uri,
+        &alice.token,
+        Some(json!({"args": {"v": 2}, "title": "Team board", "listed": true})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    // 조직 세션은 특정 사용자가 소유하지 않으므로 공개로만 둘 수 있습니다.
+    let (status, _) = helpers::send(
+        app.clone(),
+        "PUT",
+        &uri,
+        &alice.token,
+        Some(json!({"visibility": "private"})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, listed) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("/api/org/{}/session", org_id),
+        &carol.token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let items = listed["items"].as_array().unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(
+        items[0]["thumbnail_url"],
+        format!("/stream/org/{}/{}/snapshot", org_id, session_id)
+    );
+
+    let (status, directory) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("/api/directory?q={}", session_id),
+        &carol.token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let entry = directory["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|entry| entry["org_id"] == org_id.as_str())
+        .expect("listed org session");
+    assert_eq!(
+        entry["page_url"],
+        format!("/org/{}/session/{}", org_id, session_id)
+    );
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!("/stream/org/{}/{}/snapshot", org_id, session_id))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    assert_eq!(&body[..], b"<svg>2</svg>");
+
+    // 멤버가 아니면 조직이 있는지도 알 수 없습니다.
+    let dave = helpers::user(app.clone(), "dave").await;
+    let (status, _) = helpers::send(app.clone(), "GET", &uri, &dave.token, None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("/api/org/{}", org_id),
+        &dave.token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, _) = helpers::send(app.clone(), "DELETE", &uri, &bob.token, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = helpers::send(app, "GET", &uri, &bob.token, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_memberships_follow_tokens_and_api_keys() {
+    let app = helpers::create_test_app().await;
+    let mut alice = helpers::user(app.clone(), "alice").await;
+    let org_id = helpers::unique_session_id("team");
+    let (status, _) = helpers::send(
+        app.clone(),
+        "POST",
+        "/api/org",
+        &alice.token,
+        Some(json!({"org_id": org_id, "name": "Ops"})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    // 조직을 만들기 전에 받은 토큰에는 멤버십이 없습니다.
+    assert_eq!(
+        create_org_session(app.clone(), &alice, &org_id, "early").await,
+        StatusCode::FORBIDDEN
+    );
+    alice.relogin(app.clone()).await;
+    assert_eq!(
+        create_org_session(app.clone(), &alice, &org_id, "early").await,
+        StatusCode::CREATED
+    );
+
+    // API 키는 요청마다 멤버십을 조회합니다.
+    let (status, created) = helpers::send(
+        app.clone(),
+        "POST",
+        &format!("/api/user/{}/api-key", alice.id),
+        &alice.token,
+        Some(json!({"name": "ci"})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let api_key = format!("ApiKey {}", created["api_key"].as_str().unwrap());
+    let (status, _) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("/api/org/{}/session/early", org_id),
+        &api_key,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // 범위가 제한된 토큰은 조직 세션에 쓸 수 없습니다.
+    let own_session = helpers::unique_session_id("own");
+    alice
+        .create_session(app.clone(), &own_session, "<svg/>")
+        .await;
+    let (_, minted) = helpers::send(
+        app.clone(),
+        "POST",
+        &format!("/api/user/{}/session/{}/token", alice.id, own_session),
+        &alice.token,
+        Some(json!({"access": ["read"]})),
+    )
+    .await;
+    let (status, _) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("/api/org/{}/session", org_id),
+        minted["token"].as_str().unwrap(),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, orgs) = helpers::send(
+        app,
+        "GET",
+        &format!("/api/user/{}/org", alice.id),
+        &alice.token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(orgs["items"][0]["org_id"], org_id.as_str());
+    assert_eq!(orgs["items"][0]["role"], "owner");
+}
+
+#[tokio::test]
+async fn test_member_management() {
+    let app = helpers::create_test_app().await;
+    let (alice, mut bob, carol, org_id) = setup(app.clone()).await;
+
+    // 소유자만 멤버를 관리합니다.
+    assert_eq!(
+        set_member(app.clone(), &bob, &org_id, &carol.id, "owner").await,
+        StatusCode::FORBIDDEN
+    );
+    assert_eq!(
+        set_member(app.clone(), &alice, &org_id, &alice.id, "member").await,
+        StatusCode::BAD_REQUEST
+    );
+    let unknown = helpers::unique_user_id("nobody");
+    assert_eq!(
+        set_member(app.clone(), &alice, &org_id, &unknown, "viewer").await,
+        StatusCode::NOT_FOUND
+    );
+    assert!(set_member(app.clone(), &alice, &org_id, &bob.id, "admin")
+        .await
+        .is_client_error());
+
+    let (status, detail) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("/api/org/{}", org_id),
+        &carol.token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(detail["name"], "Design team");
+    assert_eq!(detail["role"], "viewer");
+    assert_eq!(detail["members"].as_array().unwrap().len(), 3);
+
+    // 멤버는 다른 멤버를 내보낼 수 없지만 스스로 나갈 수는 있습니다.
+    let carol_uri = format!("/api/org/{}/member/{}", org_id, carol.id);
+    let (status, _) = helpers::send(app.clone(), "DELETE", &carol_uri, &bob.token, None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = helpers::send(app.clone(), "DELETE", &carol_uri, &carol.token, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = helpers::send(app.clone(), "DELETE", &carol_uri, &alice.token, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    // 마지막 소유자는 나갈 수 없습니다.
+    let alice_uri = format!("/api/org/{}/member/{}", org_id, alice.id);
+    let (status, _) = helpers::send(app.clone(), "DELETE", &alice_uri, &alice.token, None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // 내보낸 멤버는 새 토큰부터 조직 세션에 접근할 수 없습니다.
+    let bob_uri = format!("/api/org/{}/member/{}", org_id, bob.id);
+    let (status, _) = helpers::send(app.clone(), "DELETE", &bob_uri, &alice.token, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    bob.relogin(app.clone()).await;
+    let (status, _) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("/api/org/{}/session", org_id),
+        &bob.token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (_, orgs) = helpers::send(
+        app,
+        "GET",
+        &format!("/api/user/{}/org", bob.id),
+        &bob.token,
+        None,
+    )
+    .await;
+    assert!(orgs["items"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_sessions_survive_members_leaving() {
+    let app = helpers::create_test_app().await;
+    let (mut alice, bob, carol, org_id) = setup(app.clone()).await;
+    let session_id = helpers::unique_session_id("kept");
+    assert_eq!(
+        create_org_session(app.clone(), &bob, &org_id, &session_id).await,
+        StatusCode::CREATED
+    );
+
+    // 세션을 만든 멤버가 계정을 지워도 조직 세션은 남습니다.
+    let (status, _) = helpers::send(
+        app.clone(),
+        "DELETE",
+        &format!("/api/user/{}", bob.id),
+        &bob.token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let session_uri = format!("/api/org/{}/session/{}", org_id, session_id);
+    let (status, _) = helpers::send(app.clone(), "GET", &session_uri, &alice.token, None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    // 다른 멤버가 남은 조직의 마지막 소유자는 계정을 지울 수 없습니다.
+    let alice_account = format!("/api/user/{}", alice.id);
+    let (status, _) =
+        helpers::send(app.clone(), "DELETE", &alice_account, &alice.token, None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(
+        set_member(app.clone(), &alice, &org_id, &carol.id, "owner").await,
+        StatusCode::NO_CONTENT
+    );
+    let (status, _) =
+        helpers::send(app.clone(), "DELETE", &alice_account, &alice.token, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, detail) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("/api/org/{}", org_id),
+        &carol.token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(detail["members"].as_array().unwrap().len(), 1);
+
+    // 조직을 지우면 조직 세션도 함께 사라집니다.
+    let (status, _) = helpers::send(
+        app.clone(),
+        "DELETE",
+        &format!("/api/org/{}", org_id),
+        &carol.token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!("/stream/org/{}/{}/snapshot", org_id, session_id))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    // 같은 ID로 다시 가입한 사용자는 예전 조직을 물려받지 않습니다.
+    alice.relogin(app.clone()).await;
+    let (_, orgs) = helpers::send(
+        app,
+        "GET",
+        &format!("/api/user/{}/org", alice.id),
+        &alice.token,
+        None,
+    )
+    .await;
+    assert!(orgs["items"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_org_is_a_reserved_username() {
+    let app = helpers::create_test_app().await;
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/auth/register")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({"user_id": "org", "password": helpers::TEST_PASSWORD}).to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,575 @@
+mod helpers;
+
+use std::time::Duration;
+
+use axum::{
+    http::{header, StatusCode},
+    Router,
+};
+use helpers::User;
+use serde_json::{json, Value};
+use tokio_stream::StreamExt;
+
+fn session_uri(owner: &str, session_id: &str) -> String {
+    format!("/api/user/{}/session/{}", owner, session_id)
+}
+
+async fn request_transfer(
+    app: Router,
+    owner: &User,
+    session_id: &str,
+    to: &str,
+) -> (StatusCode, Value) {
+    helpers::send(
+        app,
+        "POST",
+        &format!("{}/transfer", session_uri(&owner.id, session_id)),
+        &owner.token,
+        Some(json!({"to": to})),
+    )
+    .await
+}
+
+async fn accept_transfer(
+    app: Router,
+    recipient: &User,
+    owner: &str,
+    session_id: &str,
+) -> StatusCode {
+    helpers::send(
+        app,
+        "POST",
+        &format!(
+            "/api/user/{}/transfer/{}/{}/accept",
+            recipient.id, owner, session_id
+        ),
+        &recipient.token,
+        None,
+    )
+    .await
+    .0
+}
+
+async fn incoming(app: Router, recipient: &User) -> Vec<Value> {
+    let (status, body) = helpers::send(
+        app,
+        "GET",
+        &format!("/api/user/{}/transfer", recipient.id),
+        &recipient.token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    body["items"].as_array().unwrap().clone()
+}
+
+fn ttl_of(sessions: &Value, session_id: &str) -> i64 {
+    sessions["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|item| item["session_id"] == session_id)
+        .expect("session in list")["ttl_seconds"]
+        .as_i64()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_transfer_moves_session_to_recipient() {
+    let app = helpers::create_test_app().await;
+    let alice = helpers::user(app.clone(), "transfer_from").await;
+    let bob = helpers::user(app.clone(), "transfer_to").await;
+    let session_id = helpers::unique_session_id("moving");
+    alice
+        .create_session(app.clone(), &session_id, "<svg/>")
+        .await;
+    let (_, before) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("/api/user/{}/session", alice.id),
+        &alice.token,
+        None,
+    )
+    .await;
+    let ttl_before = ttl_of(&before, &session_id);
+
+    let (status, transfer) = request_transfer(app.clone(), &alice, &session_id, &bob.id).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(transfer["owner"], alice.id.as_str());
+    assert_eq!(transfer["to"], bob.id.as_str());
+
+    // 요청은 소유자의 세션 상세와 받는 사람의 목록에 보입니다.
+    let (_, detail) = helpers::send(
+        app.clone(),
+        "GET",
+        &session_uri(&alice.id, &session_id),
+        &alice.token,
+        None,
+    )
+    .await;
+    assert_eq!(detail["pending_transfer"]["to"], bob.id.as_str());
+    let items = incoming(app.clone(), &bob).await;
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["session_id"], session_id.as_str());
+
+    // 다른 사람은 대신 수락할 수 없습니다.
+    assert_eq!(
+        accept_transfer(app.clone(), &alice, &alice.id, &session_id).await,
+        StatusCode::NOT_FOUND
+    );
+    assert_eq!(
+        helpers::send(
+            app.clone(),
+            "POST",
+            &format!(
+                "/api/user/{}/transfer/{}/{}/accept",
+                bob.id, alice.id, session_id
+            ),
+            &alice.token,
+            None,
+        )
+        .await
+        .0,
+        StatusCode::FORBIDDEN
+    );
+
+    assert_eq!(
+        accept_transfer(app.clone(), &bob, &alice.id, &session_id).await,
+        StatusCode::OK
+    );
+
+    let (status, detail) = helpers::send(
+        app.clone(),
+        "GET",
+        &session_uri(&bob.id, &session_id),
+        &bob.token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(detail.get("pending_transfer").is_none());
+    let (status, _) = helpers::send(
+        app.clone(),
+        "GET",
+        &session_uri(&alice.id, &session_id),
+        &alice.token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(incoming(app.clone(), &bob).await.is_empty());
+
+    // 남은 TTL은 그대로 넘어갑니다.
+    let (_, after) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("/api/user/{}/session", bob.id),
+        &bob.token,
+        None,
+    )
+    .await;
+    let ttl_after = ttl_of(&after, &session_id);
+    assert!(ttl_after <= ttl_before && ttl_after > ttl_before - 60);
+
+    // 예전 스트림 URL은 새 소유자의 URL로 보냅니다.
+    for (old, new) in [
+        (
+            format!("/stream/{}/{}?as_bot=true", alice.id, session_id),
+            format!("/stream/{}/{}?as_bot=true", bob.id, session_id),
+        ),
+        (
+            format!("/stream/{}/{}/snapshot", alice.id, session_id),
+            format!("/stream/{}/{}/snapshot", bob.id, session_id),
+        ),
+    ] {
+        let response =
+            helpers::send_raw(app.clone(), "GET", &old, helpers::Auth::Anonymous, None).await;
+        assert_eq!(response.status(), StatusCode::TEMPORARY_REDIRECT);
+        assert_eq!(response.headers()[header::LOCATION], new.as_str());
+    }
+    let response = helpers::send_raw(
+        app.clone(),
+        "GET",
+        &format!("/stream/{}/{}/snapshot", alice.id, "never_existed"),
+        helpers::Auth::Anonymous,
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_open_stream_follows_transferred_session() {
+    let app = helpers::create_test_app().await;
+    let alice = helpers::user(app.clone(), "stream_from").await;
+    let bob = helpers::user(app.clone(), "stream_to").await;
+    let session_id = helpers::unique_session_id("streaming");
+    alice
+        .create_session(app.clone(), &session_id, "<svg>{{ msg }}</svg>")
+        .await;
+
+    let stream = helpers::send_raw(
+        app.clone(),
+        "GET",
+        &format!("/stream/{}/{}?as_bot=true", alice.id, session_id),
+        helpers::Auth::Anonymous,
+        None,
+    )
+    .await;
+    assert_eq!(stream.status(), StatusCode::OK);
+    let mut frames = stream.into_body().into_data_stream();
+
+    request_transfer(app.clone(), &alice, &session_id, &bob.id).await;
+    assert_eq!(
+        accept_transfer(app.clone(), &bob, &alice.id, &session_id).await,
+        StatusCode::OK
+    );
+    // 스트림이 새 채널을 구독할 시간을 줍니다.
+    tokio::time::sleep(Duration::from_millis(300)).await;
+
+    let (status, _) = helpers::send(
+        app.clone(),
+        "PUT",
+        &session_uri(&bob.id, &session_id),
+        &bob.token,
+        Some(json!({"args": {"msg": "after-transfer"}})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let received = tokio::time::timeout(Duration::from_secs(5), async {
+        let mut seen = Vec::new();
+        while let Some(Ok(chunk)) = frames.next().await {
+            seen.extend_from_slice(&chunk);
+            if String::from_utf8_lossy(&seen).contains("after-transfer") {
+                return true;
+            }
+        }
+        false
+    })
+    .await;
+    assert_eq!(received, Ok(true));
+}
+
+#[tokio::test]
+async fn test_transfer_revokes_collaborators_and_share_links() {
+    let app = helpers::create_test_app().await;
+    let alice = helpers::user(app.clone(), "revoke_from").await;
+    let bob = helpers::user(app.clone(), "revoke_to").await;
+    let carol = helpers::user(app.clone(), "revoke_collab").await;
+    let session_id = helpers::unique_session_id("revoked");
+    alice
+        .create_session(app.clone(), &session_id, "<svg/>")
+        .await;
+    let old_uri = session_uri(&alice.id, &session_id);
+
+    let (status, _) = helpers::send(
+        app.clone(),
+        "PUT",
+        &format!("{}/collaborator/{}", old_uri, carol.id),
+        &alice.token,
+        Some(json!({"role": "editor"})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = helpers::send(
+        app.clone(),
+        "PUT",
+        &old_uri,
+        &alice.token,
+        Some(json!({"title": "Moving board", "listed": true})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, link) = helpers::send(
+        app.clone(),
+        "POST",
+        &format!("{}/share-link", old_uri),
+        &alice.token,
+        Some(json!({})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let viewer_token = link["token"].as_str().unwrap().to_string();
+
+    request_transfer(app.clone(), &alice, &session_id, &bob.id).await;
+    assert_eq!(
+        accept_transfer(app.clone(), &bob, &alice.id, &session_id).await,
+        StatusCode::OK
+    );
+    let new_uri = session_uri(&bob.id, &session_id);
+
+    let (_, shared) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("/api/user/{}/shared", carol.id),
+        &carol.token,
+        None,
+    )
+    .await;
+    assert!(shared["items"].as_array().unwrap().is_empty());
+    let (status, _) = helpers::send(app.clone(), "GET", &new_uri, &carol.token, None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (_, collaborators) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("{}/collaborator", new_uri),
+        &bob.token,
+        None,
+    )
+    .await;
+    assert!(collaborators["items"].as_array().unwrap().is_empty());
+
+    let (_, links) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("{}/share-link", new_uri),
+        &bob.token,
+        None,
+    )
+    .await;
+    assert!(links["items"].as_array().unwrap().is_empty());
+
+    // 디렉터리 항목은 새 소유자를 가리킵니다.
+    let (_, directory) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("/api/directory?q={}", session_id),
+        &bob.token,
+        None,
+    )
+    .await;
+    let owners: Vec<&Value> = directory["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|entry| entry["session_id"] == session_id.as_str())
+        .map(|entry| &entry["user_id"])
+        .collect();
+    assert_eq!(owners, vec![&json!(bob.id)]);
+
+    // 비공개로 바꿔도 예전 링크의 토큰은 통하지 않습니다.
+    let (status, _) = helpers::send(
+        app.clone(),
+        "PUT",
+        &new_uri,
+        &bob.token,
+        Some(json!({"listed": false, "visibility": "private"})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let snapshot = helpers::send_raw(
+        app,
+        "GET",
+        &format!(
+            "/stream/{}/{}/snapshot?token={}",
+            bob.id, session_id, viewer_token
+        ),
+        helpers::Auth::Anonymous,
+        None,
+    )
+    .await;
+    assert_eq!(snapshot.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_transfer_conflicts_and_validation() {
+    let app = helpers::create_test_app().await;
+    let alice = helpers::user(app.clone(), "conflict_from").await;
+    let bob = helpers::user(app.clone(), "conflict_to").await;
+    let session_id = helpers::unique_session_id("taken");
+    alice
+        .create_session(app.clone(), &session_id, "<svg/>")
+        .await;
+
+    let (status, _) = request_transfer(app.clone(), &alice, &session_id, &alice.id).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let (status, _) = request_transfer(
+        app.clone(),
+        &alice,
+        &session_id,
+        &helpers::unique_user_id("ghost"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _) = request_transfer(app.clone(), &alice, "missing_session", &bob.id).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _) = helpers::send(
+        app.clone(),
+        "POST",
+        &format!("{}/transfer", session_uri(&alice.id, &session_id)),
+        &bob.token,
+        Some(json!({"to": bob.id})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    // 받는 사람에게 같은 ID의 세션이 있으면 수락할 수 없고, 원래 세션은 그대로 남습니다.
+    bob.create_session(app.clone(), &session_id, "<svg/>").await;
+    let (status, _) = request_transfer(app.clone(), &alice, &session_id, &bob.id).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(
+        accept_transfer(app.clone(), &bob, &alice.id, &session_id).await,
+        StatusCode::CONFLICT
+    );
+    let (status, detail) = helpers::send(
+        app.clone(),
+        "GET",
+        &session_uri(&alice.id, &session_id),
+        &alice.token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(detail["pending_transfer"]["to"], bob.id.as_str());
+}
+
+#[tokio::test]
+async fn test_cancel_and_decline_transfer() {
+    let app = helpers::create_test_app().await;
+    let alice = helpers::user(app.clone(), "cancel_from").await;
+    let bob = helpers::user(app.clone(), "cancel_to").await;
+    let carol = helpers::user(app.clone(), "cancel_other").await;
+    let session_id = helpers::unique_session_id("undecided");
+    alice
+        .create_session(app.clone(), &session_id, "<svg/>")
+        .await;
+    let transfer_uri = format!("{}/transfer", session_uri(&alice.id, &session_id));
+
+    // 다른 사람에게 다시 요청하면 앞선 요청을 대체합니다.
+    request_transfer(app.clone(), &alice, &session_id, &bob.id).await;
+    request_transfer(app.clone(), &alice, &session_id, &carol.id).await;
+    assert!(incoming(app.clone(), &bob).await.is_empty());
+    assert_eq!(incoming(app.clone(), &carol).await.len(), 1);
+    assert_eq!(
+        accept_transfer(app.clone(), &bob, &alice.id, &session_id).await,
+        StatusCode::NOT_FOUND
+    );
+
+    // 소유자가 취소
+    let (status, _) = helpers::send(app.clone(), "DELETE", &transfer_uri, &alice.token, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = helpers::send(app.clone(), "DELETE", &transfer_uri, &alice.token, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(incoming(app.clone(), &carol).await.is_empty());
+
+    // 받는 사람이 거절
+    request_transfer(app.clone(), &alice, &session_id, &bob.id).await;
+    let decline_uri = format!("/api/user/{}/transfer/{}/{}", bob.id, alice.id, session_id);
+    let (status, _) = helpers::send(app.clone(), "DELETE", &decline_uri, &carol.token, None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = helpers::send(app.clone(), "DELETE", &decline_uri, &bob.token, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    assert!(incoming(app.clone(), &bob).await.is_empty());
+    let (_, detail) = helpers::send(
+        app.clone(),
+        "GET",
+        &session_uri(&alice.id, &session_id),
+        &alice.token,
+        None,
+    )
+    .await;
+    assert!(detail.get("pending_transfer").is_none());
+    assert_eq!(
+        accept_transfer(app.clone(), &bob, &alice.id, &session_id).await,
+        StatusCode::NOT_FOUND
+    );
+}
+
+fn test_state() -> common::state::AppState {
+    let redis_url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1/".to_string());
+    common::state::AppState::new(
+        redis::Client::open(redis_url).unwrap(),
+        Default::default(),
+        Default::default(),
+        Default::default(),
+    )
+}
+
+/// 진행 중인 비동기 작업을 세션 수정 콜백 안에서 끝까지 실행합니다.
+fn run_now<T>(future: impl std::future::Future<Output = T>) -> T {
+    tokio::task::block_in_place(|| tokio::runtime::Handle::current().block_on(future))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_update_during_accept_does_not_recreate_old_session() {
+    let app = helpers::create_test_app().await;
+    let alice = helpers::user(app.clone(), "race_from").await;
+    let bob = helpers::user(app.clone(), "race_to").await;
+    let session_id = helpers::unique_session_id("racing");
+    alice
+        .create_session(app.clone(), &session_id, "<svg/>")
+        .await;
+    request_transfer(app.clone(), &alice, &session_id, &bob.id).await;
+    let state = test_state();
+
+    // alice의 수정이 세션을 읽은 뒤 저장하기 전에 bob이 이전을 수락한 상황입니다.
+    let mut attempts = 0;
+    let updated = state
+        .update_user_session(&alice.id, &session_id, 3600, |session| {
+            attempts += 1;
+            if attempts == 1 {
+                run_now(state.accept_session_transfer(&bob.id, &alice.id, &session_id)).unwrap();
+            }
+            session.touch();
+            Ok(())
+        })
+        .await
+        .unwrap();
+    assert!(updated.is_none());
+
+    // 세션은 bob에게만 있고, 이전 요청이 남은 예전 사본이 되살아나지 않습니다.
+    let old = state
+        .get_user_session(&alice.id, &session_id)
+        .await
+        .unwrap();
+    assert!(old.is_none());
+    let moved = state
+        .get_user_session(&bob.id, &session_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(moved.owner.as_deref(), Some(bob.id.as_str()));
+    assert!(moved.pending_transfer.is_none());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_update_during_cancel_keeps_cancellation() {
+    let app = helpers::create_test_app().await;
+    let alice = helpers::user(app.clone(), "cancel_race_from").await;
+    let bob = helpers::user(app.clone(), "cancel_race_to").await;
+    let session_id = helpers::unique_session_id("cancelling");
+    alice
+        .create_session(app.clone(), &session_id, "<svg/>")
+        .await;
+    request_transfer(app.clone(), &alice, &session_id, &bob.id).await;
+    let state = test_state();
+
+    let mut attempts = 0;
+    state
+        .update_user_session(&alice.id, &session_id, 3600, |session| {
+            attempts += 1;
+            if attempts == 1 {
+                let cancelled =
+                    run_now(state.cancel_session_transfer(&alice.id, &session_id, None));
+                assert!(cancelled.unwrap());
+            }
+            session.touch();
+            Ok(())
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(attempts, 2);
+
+    let session = state
+        .get_user_session(&alice.id, &session_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(session.pending_transfer.is_none());
+    assert_eq!(
+        accept_transfer(app, &bob, &alice.id, &session_id).await,
+        StatusCode::NOT_FOUND
+    );
+}
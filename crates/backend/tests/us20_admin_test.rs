@@ -0,0 +1,270 @@
+mod helpers;
+
+use std::time::Duration;
+
+use axum::http::StatusCode;
+use serde_json::json;
+use tokio_stream::StreamExt;
+
+#[tokio::test]
+async fn test_admin_routes_require_admin_role() {
+    let app = helpers::create_test_app().await;
+    let alice = helpers::user(app.clone(), "not_admin").await;
+
+    for uri in ["/api/admin/stats", "/api/admin/user", "/api/admin/session"] {
+        let (status, _) = helpers::send(app.clone(), "GET", uri, &alice.token, None).await;
+        assert_eq!(status, StatusCode::FORBIDDEN, "{uri}");
+
+        let response =
+            helpers::send_raw(app.clone(), "GET", uri, helpers::Auth::Anonymous, None).await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED, "{uri}");
+    }
+}
+
+#[tokio::test]
+async fn test_admin_lists_and_searches_users() {
+    let app = helpers::create_test_app().await;
+    let root = helpers::admin(app.clone(), "admin_list").await;
+    let alice = helpers::user(app.clone(), "admin_listed").await;
+    let session_id = helpers::unique_session_id("counted");
+    alice
+        .create_session(app.clone(), &session_id, "<svg>{{ msg }}</svg>")
+        .await;
+
+    let (status, body) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("/api/admin/user?q={}", alice.id),
+        &root.token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let items = body["items"].as_array().unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["user_id"], alice.id);
+    assert_eq!(items[0]["admin"], false);
+    assert_eq!(items[0]["sessions"], 1);
+
+    let (status, body) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("/api/admin/user?q={}&admin=true", root.id),
+        &root.token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["items"][0]["user_id"], root.id);
+    assert_eq!(body["items"][0]["admin"], true);
+}
+
+#[tokio::test]
+async fn test_disabling_account_revokes_tokens_and_blocks_login() {
+    let app = helpers::create_test_app().await;
+    let root = helpers::admin(app.clone(), "admin_disable").await;
+    let alice = helpers::user(app.clone(), "disabled").await;
+    let sessions_uri = format!("/api/user/{}/session", alice.id);
+
+    let (status, body) = helpers::send(
+        app.clone(),
+        "PUT",
+        &format!("/api/admin/user/{}", alice.id),
+        &root.token,
+        Some(json!({"disabled": true})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["disabled_at"].is_string());
+
+    let (status, _) = helpers::send(app.clone(), "GET", &sessions_uri, &alice.token, None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let response = helpers::send_raw(
+        app.clone(),
+        "POST",
+        "/api/auth/token",
+        helpers::Auth::Anonymous,
+        Some(json!({"user_id": alice.id, "password": helpers::TEST_PASSWORD})),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let (status, body) = helpers::send(
+        app.clone(),
+        "PUT",
+        &format!("/api/admin/user/{}", alice.id),
+        &root.token,
+        Some(json!({"disabled": false})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["disabled_at"].is_null());
+
+    let token = helpers::issue_token(app.clone(), &alice.id, None).await;
+    let (status, _) = helpers::send(app.clone(), "GET", &sessions_uri, &token, None).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_disabling_account_survives_concurrent_login_record() {
+    let app = helpers::create_test_app().await;
+    let alice = helpers::user(app, "disabled_race").await;
+
+    let redis_url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1/".to_string());
+    let state = common::state::AppState::new(
+        redis::Client::open(redis_url).unwrap(),
+        Default::default(),
+        Default::default(),
+        Default::default(),
+    );
+
+    // 로그인이 비밀번호를 검증하는 동안 관리자가 계정을 비활성화한 상황입니다.
+    let mut stale = state.get_user_data(&alice.id).await.unwrap().unwrap();
+    let disabled = state
+        .update_account_status(&alice.id, None, Some(true))
+        .await
+        .unwrap();
+    assert_eq!(disabled.token_generation, stale.token_generation + 1);
+    state
+        .record_user_login(&alice.id, &mut stale)
+        .await
+        .unwrap();
+
+    let stored = state.get_user_data(&alice.id).await.unwrap().unwrap();
+    assert_eq!(stored.disabled_at, disabled.disabled_at);
+    assert_eq!(stored.token_generation, disabled.token_generation);
+
+    // 이미 비활성화된 계정을 다시 비활성화해도 시각과 토큰 세대는 그대로입니다.
+    let again = state
+        .update_account_status(&alice.id, Some(false), Some(true))
+        .await
+        .unwrap();
+    assert_eq!(again.disabled_at, disabled.disabled_at);
+    assert_eq!(again.token_generation, disabled.token_generation);
+}
+
+#[tokio::test]
+async fn test_admin_cannot_change_own_account() {
+    let app = helpers::create_test_app().await;
+    let root = helpers::admin(app.clone(), "admin_self").await;
+
+    let (status, _) = helpers::send(
+        app.clone(),
+        "PUT",
+        &format!("/api/admin/user/{}", root.id),
+        &root.token,
+        Some(json!({"admin": false})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, _) = helpers::send(
+        app.clone(),
+        "PUT",
+        "/api/admin/user/never_registered_admin_target",
+        &root.token,
+        Some(json!({"disabled": true})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_demoted_admin_loses_access_immediately() {
+    let app = helpers::create_test_app().await;
+    let root = helpers::admin(app.clone(), "admin_demoter").await;
+    let other = helpers::admin(app.clone(), "admin_demoted").await;
+
+    let (status, _) =
+        helpers::send(app.clone(), "GET", "/api/admin/stats", &other.token, None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = helpers::send(
+        app.clone(),
+        "PUT",
+        &format!("/api/admin/user/{}", other.id),
+        &root.token,
+        Some(json!({"admin": false})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) =
+        helpers::send(app.clone(), "GET", "/api/admin/stats", &other.token, None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_force_delete_closes_stream() {
+    let app = helpers::create_test_app().await;
+    let root = helpers::admin(app.clone(), "admin_delete").await;
+    let alice = helpers::user(app.clone(), "deleted_owner").await;
+    let session_id = helpers::unique_session_id("doomed");
+    alice
+        .create_session(app.clone(), &session_id, "<svg>{{ msg }}</svg>")
+        .await;
+
+    let (status, body) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("/api/admin/session?user_id={}", alice.id),
+        &root.token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["items"][0]["session_id"], session_id);
+
+    let stream = helpers::send_raw(
+        app.clone(),
+        "GET",
+        &format!("/stream/{}/{}?as_bot=true", alice.id, session_id),
+        helpers::Auth::Anonymous,
+        None,
+    )
+    .await;
+    assert_eq!(stream.status(), StatusCode::OK);
+    let mut frames = stream.into_body().into_data_stream();
+    // 스트림이 채널을 구독할 시간을 줍니다.
+    tokio::time::sleep(Duration::from_millis(300)).await;
+
+    let (status, _) = helpers::send(
+        app.clone(),
+        "DELETE",
+        &format!("/api/admin/session/user/{}/{}", alice.id, session_id),
+        &root.token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let closed = tokio::time::timeout(Duration::from_secs(5), async {
+        while let Some(Ok(_)) = frames.next().await {}
+    })
+    .await;
+    assert!(closed.is_ok(), "stream should end after force delete");
+
+    let (status, _) = helpers::send(
+        app.clone(),
+        "DELETE",
+        &format!("/api/admin/session/user/{}/{}", alice.id, session_id),
+        &root.token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_stats_reports_counts() {
+    let app = helpers::create_test_app().await;
+    let root = helpers::admin(app.clone(), "admin_stats").await;
+
+    let (status, body) =
+        helpers::send(app.clone(), "GET", "/api/admin/stats", &root.token, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["users"].as_u64().unwrap() >= 1);
+    assert!(body["admins"].as_u64().unwrap() >= 1);
+    assert!(body["viewers"].is_u64());
+}
@@ -0,0 +1,379 @@
+mod helpers;
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use axum::{
+    body::Body,
+    extract::{Form, State},
+    http::{header, Request, StatusCode},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use common::oidc::OidcConfig;
+use common::signing_key::{self, SigningAlgorithm, StoredSigningKey};
+use jsonwebtoken::jwk::JwkSet;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use tower::ServiceExt;
+
+const CLIENT_ID: &str = "realtime-svg-test";
+const REDIRECT_URL: &str = "http://localhost:3000/api/auth/oidc/callback";
+
+/// 인가 요청에서 받아 두었다가 code 교환 때 확인하는 값.
+struct Grant {
+    code_challenge: String,
+    nonce: String,
+    sub: String,
+    username: String,
+}
+
+/// discovery, JWKS, 토큰 엔드포인트만 있는 테스트용 OIDC 제공자.
+#[derive(Clone)]
+struct MockIssuer {
+    issuer: String,
+    key: Arc<StoredSigningKey>,
+    grants: Arc<Mutex<HashMap<String, Grant>>>,
+}
+
+impl MockIssuer {
+    async fn start() -> Self {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let issuer = MockIssuer {
+            issuer: format!("http://{}", listener.local_addr().unwrap()),
+            key: Arc::new(signing_key::generate(SigningAlgorithm::ES256).unwrap()),
+            grants: Arc::default(),
+        };
+
+        let app = Router::new()
+            .route("/.well-known/openid-configuration", get(discovery))
+            .route("/jwks", get(jwks))
+            .route("/token", post(token))
+            .with_state(issuer.clone());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        issuer
+    }
+
+    fn config(&self) -> OidcConfig {
+        OidcConfig {
+            issuer: self.issuer.clone(),
+            client_id: CLIENT_ID.to_string(),
+            client_secret: None,
+            redirect_url: REDIRECT_URL.to_string(),
+            scopes: "openid profile".to_string(),
+            user_claim: "preferred_username".to_string(),
+        }
+    }
+
+    /// 사용자가 제공자에서 로그인을 마친 것처럼 code를 만들고 콜백 경로를 돌려줍니다.
+    fn authorize(&self, authorization_url: &str, sub: &str, username: &str) -> String {
+        let url = url::Url::parse(authorization_url).unwrap();
+        assert!(authorization_url.starts_with(&format!("{}/authorize?", self.issuer)));
+        let params: HashMap<String, String> = url.query_pairs().into_owned().collect();
+        assert_eq!(params["response_type"], "code");
+        assert_eq!(params["client_id"], CLIENT_ID);
+        assert_eq!(params["redirect_uri"], REDIRECT_URL);
+        assert_eq!(params["code_challenge_method"], "S256");
+        assert!(params["scope"].split(' ').any(|scope| scope == "openid"));
+
+        let code = helpers::unique_session_id("code");
+        self.grants.lock().unwrap().insert(
+            code.clone(),
+            Grant {
+                code_challenge: params["code_challenge"].clone(),
+                nonce: params["nonce"].clone(),
+                sub: sub.to_string(),
+                username: username.to_string(),
+            },
+        );
+        format!(
+            "/api/auth/oidc/callback?code={}&state={}",
+            code, params["state"]
+        )
+    }
+}
+
+async fn discovery(State(issuer): State<MockIssuer>) -> Json<Value> {
+    Json(json!({
+        "issuer": issuer.issuer,
+        "authorization_endpoint": format!("{}/authorize", issuer.issuer),
+        "token_endpoint": format!("{}/token", issuer.issuer),
+        "jwks_uri": format!("{}/jwks", issuer.issuer),
+    }))
+}
+
+async fn jwks(State(issuer): State<MockIssuer>) -> Json<JwkSet> {
+    Json(JwkSet {
+        keys: vec![issuer.key.public_key().to_jwk().unwrap()],
+    })
+}
+
+async fn token(
+    State(issuer): State<MockIssuer>,
+    Form(form): Form<HashMap<String, String>>,
+) -> Response {
+    let Some(grant) = issuer.grants.lock().unwrap().remove(&form["code"]) else {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(json!({"error": "invalid_grant"})),
+        )
+            .into_response();
+    };
+    let challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(form["code_verifier"].as_bytes()));
+    if challenge != grant.code_challenge || form["redirect_uri"] != REDIRECT_URL {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(json!({"error": "invalid_grant"})),
+        )
+            .into_response();
+    }
+
+    let signing_key = issuer.key.signing_key().unwrap();
+    let mut header = jsonwebtoken::Header::new(signing_key.algorithm);
+    header.kid = Some(signing_key.kid.clone());
+    let id_token = jsonwebtoken::encode(
+        &header,
+        &json!({
+            "iss": issuer.issuer,
+            "aud": CLIENT_ID,
+            "sub": grant.sub,
+            "exp": chrono::Utc::now().timestamp() + 300,
+            "iat": chrono::Utc::now().timestamp(),
+            "nonce": grant.nonce,
+            "preferred_username": grant.username,
+        }),
+        &signing_key.encoding_key,
+    )
+    .unwrap();
+    Json(json!({"access_token": "opaque", "token_type": "Bearer", "id_token": id_token}))
+        .into_response()
+}
+
+async fn get_raw(app: Router, uri: &str, token: Option<&str>) -> Response {
+    let mut request = Request::builder().uri(uri);
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Bearer {}", token));
+    }
+    app.oneshot(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+}
+
+/// `cookie` 헤더를 붙여 콜백을 호출합니다.
+async fn callback(app: Router, uri: &str, cookie: Option<&str>) -> Response {
+    let mut request = Request::builder().uri(uri);
+    if let Some(cookie) = cookie {
+        request = request.header("cookie", cookie);
+    }
+    app.oneshot(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+}
+
+/// 로그인 시작 응답이 남긴 `state` 쿠키 (`rsvg_oidc_state=...`).
+fn state_cookie(response: &Response) -> String {
+    let set_cookie = response
+        .headers()
+        .get_all(header::SET_COOKIE)
+        .iter()
+        .map(|value| value.to_str().unwrap())
+        .find(|cookie| cookie.starts_with("rsvg_oidc_state="))
+        .unwrap();
+    assert!(set_cookie.contains("HttpOnly"));
+    assert!(set_cookie.contains("SameSite=Lax"));
+    set_cookie.split(';').next().unwrap().to_string()
+}
+
+/// 콜백 응답이 `state` 쿠키를 지우는지 확인합니다.
+fn assert_state_cookie_cleared(response: &Response) {
+    assert!(response
+        .headers()
+        .get_all(header::SET_COOKIE)
+        .iter()
+        .map(|value| value.to_str().unwrap())
+        .any(|cookie| cookie.starts_with("rsvg_oidc_state=;") && cookie.contains("Max-Age=0")));
+}
+
+fn location(response: &Response) -> String {
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+    response.headers()[header::LOCATION]
+        .to_str()
+        .unwrap()
+        .to_string()
+}
+
+/// 프론트엔드 콜백 URL의 fragment 값.
+fn fragment(location: &str) -> HashMap<String, String> {
+    let (path, fragment) = location.split_once('#').unwrap();
+    assert_eq!(path, "/auth/callback");
+    url::form_urlencoded::parse(fragment.as_bytes())
+        .into_owned()
+        .collect()
+}
+
+/// 로그인을 시작하고 제공자에서 `sub`/`username`으로 로그인한 뒤의 콜백 결과.
+async fn sso_login(
+    app: Router,
+    issuer: &MockIssuer,
+    sub: &str,
+    username: &str,
+) -> HashMap<String, String> {
+    let start = get_raw(app.clone(), "/api/auth/oidc/login", None).await;
+    let cookie = state_cookie(&start);
+    let uri = issuer.authorize(&location(&start), sub, username);
+    let response = callback(app, &uri, Some(&cookie)).await;
+    assert_state_cookie_cleared(&response);
+    fragment(&location(&response))
+}
+
+#[tokio::test]
+async fn test_sso_disabled_without_provider() {
+    let app = helpers::create_test_app().await;
+
+    let response = get_raw(app.clone(), "/api/auth/oidc", None).await;
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body["enabled"], false);
+
+    let response = get_raw(app, "/api/auth/oidc/login", None).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_sso_login_creates_account_and_issues_tokens() {
+    let issuer = MockIssuer::start().await;
+    let app = helpers::create_test_app_with_oidc(issuer.config()).await;
+    let user_id = helpers::unique_user_id("sso");
+    let sub = helpers::unique_session_id("sub");
+
+    let result = sso_login(app.clone(), &issuer, &sub, &user_id).await;
+    assert_eq!(result.get("error"), None);
+    let token = &result["token"];
+    assert!(!result["refresh_token"].is_empty());
+
+    let response = get_raw(
+        app.clone(),
+        &format!("/api/user/{}/session", user_id),
+        Some(token),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // 두 번째 로그인은 같은 계정으로 들어갑니다.
+    let again = sso_login(app.clone(), &issuer, &sub, &user_id).await;
+    assert!(again.contains_key("token"));
+
+    // SSO 계정에는 비밀번호가 없습니다.
+    let request = Request::builder()
+        .method("POST")
+        .uri("/api/auth/token")
+        .header("content-type", "application/json")
+        .body(Body::from(
+            json!({"user_id": user_id, "password": ""}).to_string(),
+        ))
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_sso_callback_state_is_single_use() {
+    let issuer = MockIssuer::start().await;
+    let app = helpers::create_test_app_with_oidc(issuer.config()).await;
+    let user_id = helpers::unique_user_id("sso_replay");
+
+    let start = get_raw(app.clone(), "/api/auth/oidc/login", None).await;
+    let cookie = state_cookie(&start);
+    let uri = issuer.authorize(&location(&start), "replay-sub", &user_id);
+    let first = fragment(&location(&callback(app.clone(), &uri, Some(&cookie)).await));
+    assert!(first.contains_key("token"));
+
+    let replay = fragment(&location(&callback(app.clone(), &uri, Some(&cookie)).await));
+    assert_eq!(replay["error"], "login_failed");
+
+    let forged = fragment(&location(
+        &callback(
+            app.clone(),
+            "/api/auth/oidc/callback?code=x&state=never-issued",
+            Some("rsvg_oidc_state=never-issued"),
+        )
+        .await,
+    ));
+    assert_eq!(forged["error"], "login_failed");
+
+    let denied = callback(app, "/api/auth/oidc/callback?error=access_denied", None).await;
+    assert_state_cookie_cleared(&denied);
+    assert_eq!(fragment(&location(&denied))["error"], "access_denied");
+}
+
+#[tokio::test]
+async fn test_sso_callback_requires_state_cookie_of_same_browser() {
+    let issuer = MockIssuer::start().await;
+    let app = helpers::create_test_app_with_oidc(issuer.config()).await;
+    let attacker = helpers::unique_user_id("sso_attacker");
+
+    // 공격자가 자기 계정으로 로그인을 시작해 얻은 콜백 URL을 피해자가 엽니다.
+    let attacker_start = get_raw(app.clone(), "/api/auth/oidc/login", None).await;
+    let attacker_cookie = state_cookie(&attacker_start);
+    let uri = issuer.authorize(&location(&attacker_start), "attacker-sub", &attacker);
+
+    let response = callback(app.clone(), &uri, None).await;
+    assert_state_cookie_cleared(&response);
+    let result = fragment(&location(&response));
+    assert_eq!(result["error"], "login_failed");
+    assert_eq!(result.get("token"), None);
+
+    // 피해자가 따로 시작한 로그인의 쿠키가 있어도 다른 state이므로 거절합니다.
+    let victim_start = get_raw(app.clone(), "/api/auth/oidc/login", None).await;
+    let victim_cookie = state_cookie(&victim_start);
+    let response = callback(app.clone(), &uri, Some(&victim_cookie)).await;
+    assert_state_cookie_cleared(&response);
+    let result = fragment(&location(&response));
+    assert_eq!(result["error"], "login_failed");
+    assert_eq!(result.get("token"), None);
+
+    // 로그인을 시작한 브라우저는 그대로 로그인할 수 있습니다.
+    let result = fragment(&location(
+        &callback(app, &uri, Some(&attacker_cookie)).await,
+    ));
+    assert!(result.contains_key("token"));
+}
+
+#[tokio::test]
+async fn test_sso_does_not_take_over_password_account() {
+    let issuer = MockIssuer::start().await;
+    let app = helpers::create_test_app_with_oidc(issuer.config()).await;
+    let user_id = helpers::unique_user_id("sso_taken");
+    helpers::register_user(app.clone(), &user_id).await;
+
+    let result = sso_login(app.clone(), &issuer, "someone-else", &user_id).await;
+    assert_eq!(result["error"], "account_exists");
+    assert_eq!(result.get("token"), None);
+}
+
+#[tokio::test]
+async fn test_sso_account_is_bound_to_subject() {
+    let issuer = MockIssuer::start().await;
+    let app = helpers::create_test_app_with_oidc(issuer.config()).await;
+    let user_id = helpers::unique_user_id("sso_bound");
+
+    let first = sso_login(app.clone(), &issuer, "original-sub", &user_id).await;
+    assert!(first.contains_key("token"));
+
+    // 제공자에서 같은 이름을 쓰게 된 다른 사람은 그 계정으로 들어올 수 없습니다.
+    let other = sso_login(app.clone(), &issuer, "different-sub", &user_id).await;
+    assert_eq!(other["error"], "account_exists");
+}
+
+#[tokio::test]
+async fn test_sso_rejects_invalid_user_id_claim() {
+    let issuer = MockIssuer::start().await;
+    let app = helpers::create_test_app_with_oidc(issuer.config()).await;
+
+    let result = sso_login(app, &issuer, "email-sub", "alice@example.com").await;
+    assert_eq!(result["error"], "invalid_user_id");
+}
@@ -0,0 +1,192 @@
+mod helpers;
+
+use std::path::PathBuf;
+
+use axum::http::StatusCode;
+use common::signing_key::{self, SigningAlgorithm, StoredSigningKey};
+use common::trusted_issuer::TrustedIssuerConfig;
+use jsonwebtoken::jwk::JwkSet;
+use serde_json::json;
+
+const ISSUER: &str = "https://idp.example.com";
+const AUDIENCE: &str = "realtime-svg";
+
+/// JWKS 파일로 공개 키를 내보내는 테스트용 외부 발급자.
+struct ExternalIssuer {
+    key: StoredSigningKey,
+    jwks_file: PathBuf,
+}
+
+impl ExternalIssuer {
+    fn new() -> Self {
+        let key = signing_key::generate(SigningAlgorithm::ES256).unwrap();
+        let jwks_file =
+            std::env::temp_dir().join(format!("{}.json", helpers::unique_session_id("jwks")));
+        let jwks = JwkSet {
+            keys: vec![key.public_key().to_jwk().unwrap()],
+        };
+        std::fs::write(&jwks_file, serde_json::to_vec(&jwks).unwrap()).unwrap();
+        Self { key, jwks_file }
+    }
+
+    fn config(&self) -> TrustedIssuerConfig {
+        TrustedIssuerConfig {
+            issuer: ISSUER.to_string(),
+            audience: AUDIENCE.to_string(),
+            jwks_url: None,
+            jwks_file: Some(self.jwks_file.clone()),
+            user_claim: "preferred_username".to_string(),
+        }
+    }
+
+    fn token(&self, username: &str, audience: &str) -> String {
+        let signing_key = self.key.signing_key().unwrap();
+        let mut header = jsonwebtoken::Header::new(signing_key.algorithm);
+        header.kid = Some(signing_key.kid.clone());
+        jsonwebtoken::encode(
+            &header,
+            &json!({
+                "iss": ISSUER,
+                "aud": audience,
+                "sub": format!("idp-{}", username),
+                "exp": chrono::Utc::now().timestamp() + 300,
+                "iat": chrono::Utc::now().timestamp(),
+                "preferred_username": username,
+            }),
+            &signing_key.encoding_key,
+        )
+        .unwrap()
+    }
+}
+
+impl Drop for ExternalIssuer {
+    fn drop(&mut self) {
+        std::fs::remove_file(&self.jwks_file).ok();
+    }
+}
+
+#[tokio::test]
+async fn test_external_token_authenticates_existing_user() {
+    let issuer = ExternalIssuer::new();
+    let app = helpers::create_test_app_with_trusted_issuers(vec![issuer.config()]).await;
+    let user_id = helpers::unique_user_id("ext");
+    helpers::register_user(app.clone(), &user_id).await;
+    let token = issuer.token(&user_id, AUDIENCE);
+
+    helpers::create_user_session(app.clone(), &token, &user_id, "from-idp", "<svg></svg>").await;
+
+    let (status, _) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("/api/user/{}/session", user_id),
+        &token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // 이 서버가 발급한 토큰도 그대로 쓸 수 있습니다.
+    let own_token = helpers::issue_token(app.clone(), &user_id, None).await;
+    let (status, _) = helpers::send(
+        app,
+        "GET",
+        &format!("/api/user/{}/session", user_id),
+        &own_token,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_external_token_is_limited_to_its_user() {
+    let issuer = ExternalIssuer::new();
+    let app = helpers::create_test_app_with_trusted_issuers(vec![issuer.config()]).await;
+    let user_id = helpers::unique_user_id("ext_own");
+    let other_id = helpers::unique_user_id("ext_other");
+    helpers::register_user(app.clone(), &user_id).await;
+    helpers::register_user(app.clone(), &other_id).await;
+
+    let (status, _) = helpers::send(
+        app,
+        "GET",
+        &format!("/api/user/{}/session", other_id),
+        &issuer.token(&user_id, AUDIENCE),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_external_token_rejected() {
+    let issuer = ExternalIssuer::new();
+    let app = helpers::create_test_app_with_trusted_issuers(vec![issuer.config()]).await;
+    let user_id = helpers::unique_user_id("ext_reject");
+    helpers::register_user(app.clone(), &user_id).await;
+    let uri = format!("/api/user/{}/session", user_id);
+
+    // 다른 서비스용 토큰
+    let (status, _) = helpers::send(
+        app.clone(),
+        "GET",
+        &uri,
+        &issuer.token(&user_id, "other"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    // 설정되지 않은 발급자의 키로 서명한 토큰
+    let stranger = ExternalIssuer::new();
+    let (status, _) = helpers::send(
+        app.clone(),
+        "GET",
+        &uri,
+        &stranger.token(&user_id, AUDIENCE),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    // 가입하지 않은 사용자
+    let unknown = helpers::unique_user_id("ext_unknown");
+    let (status, _) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("/api/user/{}/session", unknown),
+        &issuer.token(&unknown, AUDIENCE),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    // 외부 토큰으로는 로그인이 필요한 계정 작업을 할 수 없습니다.
+    let (status, _) = helpers::send(
+        app,
+        "POST",
+        "/api/auth/logout",
+        &issuer.token(&user_id, AUDIENCE),
+        Some(json!({})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_external_token_ignored_without_trusted_issuers() {
+    let issuer = ExternalIssuer::new();
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("ext_off");
+    helpers::register_user(app.clone(), &user_id).await;
+
+    let (status, _) = helpers::send(
+        app,
+        "GET",
+        &format!("/api/user/{}/session", user_id),
+        &issuer.token(&user_id, AUDIENCE),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
@@ -0,0 +1,189 @@
+mod helpers;
+
+use axum::{
+    body::Body,
+    http::{header, Request, Response, StatusCode},
+    Router,
+};
+use common::rate_limit::RateLimitPolicy;
+use serde_json::json;
+use tower::ServiceExt;
+
+async fn login(
+    app: Router,
+    user_id: &str,
+    password: &str,
+    forwarded_for: Option<&str>,
+) -> Response<Body> {
+    let mut request = Request::builder()
+        .method("POST")
+        .uri("/api/auth/token")
+        .header("content-type", "application/json");
+    if let Some(ip) = forwarded_for {
+        request = request.header("x-forwarded-for", ip);
+    }
+    let body = json!({"user_id": user_id, "password": password}).to_string();
+    app.oneshot(request.body(Body::from(body)).unwrap())
+        .await
+        .unwrap()
+}
+
+fn retry_after(response: &Response<Body>) -> u64 {
+    response.headers()[header::RETRY_AFTER]
+        .to_str()
+        .unwrap()
+        .parse()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_repeated_failures_lock_account() {
+    let app = helpers::create_test_app_with_rate_limit(RateLimitPolicy {
+        login_lockout_threshold: 3,
+        login_lockout_base_seconds: 60,
+        ..Default::default()
+    })
+    .await;
+    let user_id = helpers::unique_user_id("lockout");
+    helpers::register_user(app.clone(), &user_id).await;
+
+    for _ in 0..3 {
+        let response = login(app.clone(), &user_id, "wrong-password", None).await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    // 잠긴 동안에는 올바른 비밀번호도 거절됩니다.
+    let response = login(app.clone(), &user_id, helpers::TEST_PASSWORD, None).await;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    let seconds = retry_after(&response);
+    assert!(seconds > 0 && seconds <= 60, "Retry-After: {seconds}");
+
+    // 없는 계정도 똑같이 잠겨, 잠금으로 계정 존재를 알 수 없습니다.
+    let unknown = helpers::unique_user_id("lockout_unknown");
+    for _ in 0..3 {
+        let response = login(app.clone(), &unknown, "wrong-password", None).await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+    let response = login(app, &unknown, "wrong-password", None).await;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+}
+
+#[tokio::test]
+async fn test_successful_login_resets_failures() {
+    let app = helpers::create_test_app_with_rate_limit(RateLimitPolicy {
+        login_lockout_threshold: 3,
+        ..Default::default()
+    })
+    .await;
+    let user_id = helpers::unique_user_id("lockout_reset");
+    helpers::register_user(app.clone(), &user_id).await;
+
+    for _ in 0..2 {
+        login(app.clone(), &user_id, "wrong-password", None).await;
+    }
+    let response = login(app.clone(), &user_id, helpers::TEST_PASSWORD, None).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    for _ in 0..2 {
+        login(app.clone(), &user_id, "wrong-password", None).await;
+    }
+    let response = login(app, &user_id, helpers::TEST_PASSWORD, None).await;
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_per_user_and_per_ip_limits() {
+    let app = helpers::create_test_app_with_rate_limit(RateLimitPolicy {
+        trust_forwarded_for: true,
+        login_ip_limit: 3,
+        login_user_limit: 2,
+        login_lockout_threshold: 0,
+        ..Default::default()
+    })
+    .await;
+    let user_id = helpers::unique_user_id("throttle_user");
+    helpers::register_user(app.clone(), &user_id).await;
+
+    // 사용자별 제한은 IP를 바꿔도 적용됩니다.
+    for _ in 0..2 {
+        let response = login(
+            app.clone(),
+            &user_id,
+            helpers::TEST_PASSWORD,
+            Some(&fresh_ip()),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+    let response = login(
+        app.clone(),
+        &user_id,
+        helpers::TEST_PASSWORD,
+        Some(&fresh_ip()),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert!(retry_after(&response) > 0);
+
+    // IP별 제한은 사용자를 바꿔도 적용됩니다. 마지막 주소만 클라이언트 IP로 봅니다.
+    let ip = fresh_ip();
+    for i in 0..3 {
+        let response = login(
+            app.clone(),
+            &helpers::unique_user_id(&format!("throttle_ip{i}")),
+            "wrong-password",
+            Some(&format!("10.0.0.{i}, {ip}")),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+    let other = helpers::unique_user_id("throttle_ip_other");
+    let response = login(app, &other, "wrong-password", Some(&ip)).await;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+}
+
+#[tokio::test]
+async fn test_registration_counts_against_ip_limit() {
+    let app = helpers::create_test_app_with_rate_limit(RateLimitPolicy {
+        trust_forwarded_for: true,
+        login_ip_limit: 2,
+        ..Default::default()
+    })
+    .await;
+    let ip = fresh_ip();
+    let register = |user_id: String| {
+        let app = app.clone();
+        let ip = ip.clone();
+        async move {
+            let request = Request::builder()
+                .method("POST")
+                .uri("/api/auth/register")
+                .header("content-type", "application/json")
+                .header("x-forwarded-for", ip)
+                .body(Body::from(
+                    json!({"user_id": user_id, "password": helpers::TEST_PASSWORD}).to_string(),
+                ))
+                .unwrap();
+            app.oneshot(request).await.unwrap()
+        }
+    };
+
+    let first = helpers::unique_user_id("register_ip");
+    assert_eq!(register(first.clone()).await.status(), StatusCode::CREATED);
+    let response = register(helpers::unique_user_id("register_ip")).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    // 세 번째 가입은 해시를 만들기 전에 거절됩니다.
+    let response = register(helpers::unique_user_id("register_ip")).await;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert!(retry_after(&response) > 0);
+
+    // 가입과 로그인은 IP별 횟수를 함께 씁니다.
+    let response = login(app, &first, helpers::TEST_PASSWORD, Some(&ip)).await;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+}
+
+/// 반복 실행해도 이전 실행의 IP별 창과 겹치지 않도록 매번 새 주소를 씁니다.
+fn fresh_ip() -> String {
+    std::net::Ipv6Addr::from(uuid::Uuid::new_v4().as_u128()).to_string()
+}
@@ -0,0 +1,196 @@
+mod helpers;
+
+use std::time::Duration;
+
+use axum::{
+    body::Body,
+    http::{header, Request, StatusCode},
+    Router,
+};
+use common::rate_limit::RateLimitPolicy;
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+fn session_body(session_id: &str, template: &str) -> Value {
+    json!({"session_id": session_id, "template": template, "args": {"msg": "hello"}})
+}
+
+#[tokio::test]
+async fn test_session_count_quota() {
+    let app = helpers::create_test_app_with_rate_limit(RateLimitPolicy {
+        max_sessions_per_user: 2,
+        ..Default::default()
+    })
+    .await;
+    let user_id = helpers::unique_user_id("quota_sessions");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+    let uri = format!("/api/user/{}/session", user_id);
+
+    for session_id in ["one", "two"] {
+        helpers::create_user_session(app.clone(), &token, &user_id, session_id, "<svg/>").await;
+    }
+
+    let response = helpers::send_raw(
+        app.clone(),
+        "POST",
+        &uri,
+        &token,
+        Some(session_body("three", "<svg/>")),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    let body = helpers::json_body(response).await;
+    assert_eq!(body["quota"], "sessions");
+    assert_eq!(body["limit"], 2);
+    assert!(body["error"].is_string());
+
+    // 이미 있는 세션을 다시 만드는 것은 세지 않습니다.
+    let response = helpers::send_raw(
+        app.clone(),
+        "POST",
+        &uri,
+        &token,
+        Some(session_body("two", "<svg></svg>")),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let response = helpers::send_raw(
+        app.clone(),
+        "GET",
+        &format!("/api/user/{}/usage", user_id),
+        &token,
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let usage = helpers::json_body(response).await;
+    assert_eq!(usage["sessions"], json!({"used": 2, "limit": 2}));
+    assert_eq!(usage["template_bytes"]["used"], "<svg></svg>".len());
+
+    let other_id = helpers::unique_user_id("quota_other");
+    let other_token = helpers::issue_token(app.clone(), &other_id, None).await;
+    let response = helpers::send_raw(
+        app,
+        "GET",
+        &format!("/api/user/{}/usage", user_id),
+        &other_token,
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_session_size_quota() {
+    let app = helpers::create_test_app_with_rate_limit(RateLimitPolicy {
+        max_template_bytes: 64,
+        max_args_bytes: 32,
+        ..Default::default()
+    })
+    .await;
+    let user_id = helpers::unique_user_id("quota_size");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+
+    let response = helpers::send_raw(
+        app.clone(),
+        "POST",
+        &format!("/api/user/{}/session", user_id),
+        &token,
+        Some(session_body("big", &"x".repeat(65))),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    let body = helpers::json_body(response).await;
+    assert_eq!(body["quota"], "template_bytes");
+    assert_eq!(body["limit"], 64);
+
+    helpers::create_user_session(app.clone(), &token, &user_id, "small", "<svg/>").await;
+    let response = helpers::send_raw(
+        app,
+        "PUT",
+        &format!("/api/user/{}/session/small", user_id),
+        &token,
+        Some(json!({"args": {"msg": "x".repeat(32)}})),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    assert_eq!(helpers::json_body(response).await["quota"], "args_bytes");
+}
+
+#[tokio::test]
+async fn test_session_update_rate_quota() {
+    let app = helpers::create_test_app_with_rate_limit(RateLimitPolicy {
+        session_updates_per_minute: 2,
+        ..Default::default()
+    })
+    .await;
+    let user_id = helpers::unique_user_id("quota_updates");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+    helpers::create_user_session(app.clone(), &token, &user_id, "ticker", "<svg/>").await;
+    let uri = format!("/api/user/{}/session/ticker", user_id);
+    let update = json!({"args": {"msg": "tick"}});
+
+    for _ in 0..2 {
+        let response =
+            helpers::send_raw(app.clone(), "PUT", &uri, &token, Some(update.clone())).await;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+    let response = helpers::send_raw(app, "PUT", &uri, &token, Some(update)).await;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    let retry_after: u64 = response.headers()[header::RETRY_AFTER]
+        .to_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!(retry_after > 0 && retry_after <= 60);
+    let body = helpers::json_body(response).await;
+    assert_eq!(body["quota"], "session_updates");
+    assert_eq!(body["retry_after"], retry_after);
+}
+
+#[tokio::test]
+async fn test_concurrent_stream_quota_per_ip() {
+    let app = helpers::create_test_app_with_rate_limit(RateLimitPolicy {
+        trust_forwarded_for: true,
+        max_streams_per_ip: 1,
+        ..Default::default()
+    })
+    .await;
+    let user_id = helpers::unique_user_id("quota_streams");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+    helpers::create_user_session(app.clone(), &token, &user_id, "live", "<svg/>").await;
+    let ip = std::net::Ipv6Addr::from(uuid::Uuid::new_v4().as_u128()).to_string();
+
+    let open_stream = |app: Router| {
+        let request = Request::builder()
+            .uri(format!(
+                "/stream/{}/live?as_bot=true&keep_alive=50",
+                user_id
+            ))
+            .header("x-forwarded-for", ip.as_str())
+            .body(Body::empty())
+            .unwrap();
+        app.oneshot(request)
+    };
+
+    let first = open_stream(app.clone()).await.unwrap();
+    assert_eq!(first.status(), StatusCode::OK);
+
+    let second = open_stream(app.clone()).await.unwrap();
+    assert_eq!(second.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(helpers::json_body(second).await["quota"], "streams");
+
+    // 스트림을 닫으면 자리가 반납됩니다.
+    drop(first);
+    let mut reopened = None;
+    for _ in 0..40 {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let response = open_stream(app.clone()).await.unwrap();
+        if response.status() == StatusCode::OK {
+            reopened = Some(response);
+            break;
+        }
+    }
+    assert!(reopened.is_some(), "stream slot was not released");
+}
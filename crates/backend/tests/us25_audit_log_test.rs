@@ -0,0 +1,222 @@
+mod helpers;
+
+use axum::{
+    body::Body,
+    http::{header, Request, StatusCode},
+};
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+fn actions(items: &Value) -> Vec<&str> {
+    items
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["action"].as_str().unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_session_changes_are_audited() {
+    let app = helpers::create_test_app().await;
+    let root = helpers::admin(app.clone(), "audit_admin").await.token;
+    let user_id = helpers::unique_user_id("audited");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+    let session_uri = format!("/api/user/{}/session/chart", user_id);
+
+    helpers::create_user_session(app.clone(), &token, &user_id, "chart", "<svg/>").await;
+
+    // 클라이언트가 보낸 요청 ID는 그대로 돌려주고 기록에 남깁니다.
+    let request = Request::builder()
+        .method("PUT")
+        .uri(&session_uri)
+        .header("authorization", format!("Bearer {}", token))
+        .header("content-type", "application/json")
+        .header("x-request-id", "audit-req-1")
+        .body(Body::from(json!({"title": "Chart"}).to_string()))
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert_eq!(response.headers()["x-request-id"], "audit-req-1");
+
+    let response = helpers::send_raw(app.clone(), "DELETE", &session_uri, &token, None).await;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert!(response.headers().contains_key("x-request-id"));
+
+    let (status, body) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("/api/admin/audit?target=user:{}/session:chart", user_id),
+        &root,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        actions(&body["items"]),
+        vec!["session_deleted", "session_updated", "session_created"]
+    );
+    let deleted = &body["items"][0];
+    assert_eq!(deleted["actor"], user_id);
+    assert_eq!(deleted["user_agent"], helpers::TEST_USER_AGENT);
+    assert!(deleted["request_id"].is_string());
+    assert_eq!(body["items"][1]["request_id"], "audit-req-1");
+    assert_eq!(body["items"][1]["details"], json!({"fields": ["title"]}));
+
+    let (_, body) = helpers::send(
+        app.clone(),
+        "GET",
+        "/api/admin/audit?request_id=audit-req-1&action=session_updated",
+        &root,
+        None,
+    )
+    .await;
+    assert_eq!(body["items"].as_array().unwrap().len(), 1);
+
+    // 관리자가 아니면 조회할 수 없습니다.
+    let (status, _) = helpers::send(app, "GET", "/api/admin/audit", &token, None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_logins_and_permission_changes_are_audited() {
+    let app = helpers::create_test_app().await;
+    let root = helpers::admin(app.clone(), "audit_admin").await.token;
+    let user_id = helpers::unique_user_id("audit_login");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+
+    let request = Request::builder()
+        .method("POST")
+        .uri("/api/auth/token")
+        .header("content-type", "application/json")
+        .body(Body::from(
+            json!({"user_id": user_id, "password": "wrong-password"}).to_string(),
+        ))
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // 이미 있는 ID로 가입하면 실패도 기록합니다.
+    helpers::register_user(app.clone(), &user_id).await;
+
+    helpers::create_user_session(app.clone(), &token, &user_id, "shared", "<svg/>").await;
+    let collaborator = helpers::unique_user_id("audit_collaborator");
+    helpers::register_user(app.clone(), &collaborator).await;
+    let (status, _) = helpers::send(
+        app.clone(),
+        "PUT",
+        &format!(
+            "/api/user/{}/session/shared/collaborator/{}",
+            user_id, collaborator
+        ),
+        &token,
+        Some(json!({"role": "editor"})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (_, body) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("/api/admin/audit?target=user:{}", user_id),
+        &root,
+        None,
+    )
+    .await;
+    assert_eq!(
+        actions(&body["items"]),
+        vec![
+            "permission_changed",
+            "session_created",
+            "registration_failed",
+            "login_failed",
+            "token_issued",
+            "account_registered"
+        ]
+    );
+    let permission = &body["items"][0];
+    assert_eq!(permission["details"]["collaborator"], collaborator);
+    assert_eq!(permission["details"]["role"], "editor");
+    assert_eq!(body["items"][2]["details"]["reason"], "user_exists");
+    let failed = &body["items"][3];
+    assert_eq!(failed["actor"], Value::Null);
+    assert_eq!(failed["details"]["reason"], "invalid_credentials");
+    assert_eq!(body["items"][5]["actor"], user_id);
+
+    // 페이지를 나눠도 같은 순서로 이어집니다.
+    let (_, first) = helpers::send(
+        app.clone(),
+        "GET",
+        &format!("/api/admin/audit?target=user:{}&limit=3", user_id),
+        &root,
+        None,
+    )
+    .await;
+    let cursor = first["next_cursor"].as_str().unwrap();
+    let (_, second) = helpers::send(
+        app,
+        "GET",
+        &format!(
+            "/api/admin/audit?target=user:{}&limit=3&cursor={}",
+            user_id, cursor
+        ),
+        &root,
+        None,
+    )
+    .await;
+    assert_eq!(
+        actions(&second["items"]),
+        vec!["login_failed", "token_issued", "account_registered"]
+    );
+    assert_eq!(second["next_cursor"], Value::Null);
+}
+
+#[tokio::test]
+async fn test_audit_log_export_as_json_lines() {
+    let app = helpers::create_test_app().await;
+    let root = helpers::admin(app.clone(), "audit_admin").await.token;
+    let user_id = helpers::unique_user_id("audit_export");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+    helpers::create_user_session(app.clone(), &token, &user_id, "one", "<svg/>").await;
+    helpers::create_user_session(app.clone(), &token, &user_id, "two", "<svg/>").await;
+
+    let response = helpers::send_raw(
+        app.clone(),
+        "GET",
+        &format!(
+            "/api/admin/audit/export?target=user:{}&action=session_created",
+            user_id
+        ),
+        &root,
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "application/x-ndjson"
+    );
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let lines: Vec<Value> = std::str::from_utf8(&body)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    // 오래된 기록부터 씁니다.
+    let targets: Vec<_> = lines
+        .iter()
+        .map(|line| line["target"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(
+        targets,
+        vec![
+            format!("user:{}/session:one", user_id),
+            format!("user:{}/session:two", user_id),
+        ]
+    );
+
+    let (status, _) = helpers::send(app, "GET", "/api/admin/audit?cursor=oops", &root, None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,321 @@
+mod helpers;
+
+use std::collections::HashMap;
+
+use axum::{
+    body::Body,
+    http::{header, Request, StatusCode},
+    response::Response,
+    Router,
+};
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+/// 응답의 `Set-Cookie` 헤더를 이름별로 모읍니다.
+fn set_cookies(response: &Response) -> HashMap<String, String> {
+    response
+        .headers()
+        .get_all(header::SET_COOKIE)
+        .iter()
+        .map(|value| {
+            let cookie = value.to_str().unwrap().to_string();
+            let name = cookie.split('=').next().unwrap().to_string();
+            (name, cookie)
+        })
+        .collect()
+}
+
+/// `Set-Cookie` 헤더에서 쿠키 값만 꺼냅니다.
+fn cookie_value(set_cookie: &str) -> &str {
+    set_cookie
+        .split(';')
+        .next()
+        .and_then(|pair| pair.split_once('='))
+        .map(|(_, value)| value)
+        .unwrap()
+}
+
+/// 쿠키 로그인으로 받은 쿠키
+struct LoginCookies {
+    access: String,
+    refresh: String,
+    csrf: String,
+}
+
+impl LoginCookies {
+    fn from_response(response: &Response) -> Self {
+        let cookies = set_cookies(response);
+        Self {
+            access: cookie_value(&cookies["rsvg_access"]).to_string(),
+            refresh: cookie_value(&cookies["rsvg_refresh"]).to_string(),
+            csrf: cookie_value(&cookies["rsvg_csrf"]).to_string(),
+        }
+    }
+
+    fn header(&self) -> String {
+        format!(
+            "rsvg_access={}; rsvg_refresh={}; rsvg_csrf={}",
+            self.access, self.refresh, self.csrf
+        )
+    }
+}
+
+async fn cookie_login(app: Router, user_id: &str) -> Response {
+    helpers::register_user(app.clone(), user_id).await;
+    let request = Request::builder()
+        .method("POST")
+        .uri("/api/auth/cookie")
+        .header("content-type", "application/json")
+        .body(Body::from(
+            json!({"user_id": user_id, "password": helpers::TEST_PASSWORD}).to_string(),
+        ))
+        .unwrap();
+    app.oneshot(request).await.unwrap()
+}
+
+async fn send_with_cookies(
+    app: Router,
+    method: &str,
+    uri: &str,
+    cookies: &str,
+    csrf_token: Option<&str>,
+    body: Option<Value>,
+) -> Response {
+    let mut request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("cookie", cookies)
+        .header("content-type", "application/json");
+    if let Some(csrf_token) = csrf_token {
+        request = request.header("x-csrf-token", csrf_token);
+    }
+    let body = body.map_or_else(Body::empty, |body| Body::from(body.to_string()));
+    app.oneshot(request.body(body).unwrap()).await.unwrap()
+}
+
+async fn json_body(response: Response) -> Value {
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    serde_json::from_slice(&body).unwrap_or(Value::Null)
+}
+
+#[tokio::test]
+async fn test_cookie_login_sets_http_only_cookies() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("cookie_login");
+
+    let response = cookie_login(app.clone(), &user_id).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let cookies = set_cookies(&response);
+    let access = &cookies["rsvg_access"];
+    assert!(access.contains("HttpOnly"));
+    assert!(access.contains("SameSite=Strict"));
+    assert!(access.contains("Secure"));
+    assert!(access.contains("Path=/;"));
+    let refresh = &cookies["rsvg_refresh"];
+    assert!(refresh.contains("HttpOnly"));
+    assert!(refresh.contains("Path=/api/auth;"));
+    // CSRF 쿠키는 스크립트가 읽어 헤더로 보내야 하므로 HttpOnly가 아닙니다.
+    assert!(!cookies["rsvg_csrf"].contains("HttpOnly"));
+
+    let login = LoginCookies::from_response(&response);
+    let body = json_body(response).await;
+    assert_eq!(body["user_id"], user_id);
+    assert_eq!(body["admin"], false);
+    assert!(body["expires_in"].as_u64().unwrap() > 0);
+    assert!(body.get("token").is_none());
+    assert!(body.get("refresh_token").is_none());
+
+    let response = send_with_cookies(
+        app.clone(),
+        "GET",
+        "/api/auth/cookie",
+        &login.header(),
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(json_body(response).await["user_id"], user_id);
+
+    // 틀린 비밀번호는 토큰 로그인과 같은 응답입니다.
+    let request = Request::builder()
+        .method("POST")
+        .uri("/api/auth/cookie")
+        .header("content-type", "application/json")
+        .body(Body::from(
+            json!({"user_id": user_id, "password": "wrong-password"}).to_string(),
+        ))
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert!(set_cookies(&response).is_empty());
+}
+
+#[tokio::test]
+async fn test_cookie_auth_requires_csrf_for_changes() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("cookie_csrf");
+    let response = cookie_login(app.clone(), &user_id).await;
+    let login = LoginCookies::from_response(&response);
+    let sessions_uri = format!("/api/user/{}/session", user_id);
+    let new_session = || {
+        Some(json!({
+            "session_id": helpers::unique_session_id("cookie"),
+            "template": "<svg/>",
+            "args": {"msg": "hello"}
+        }))
+    };
+
+    // 읽기 요청은 CSRF 토큰 없이도 됩니다.
+    let response = send_with_cookies(
+        app.clone(),
+        "GET",
+        &sessions_uri,
+        &login.header(),
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    for csrf_token in [None, Some("not-the-cookie")] {
+        let response = send_with_cookies(
+            app.clone(),
+            "POST",
+            &sessions_uri,
+            &login.header(),
+            csrf_token,
+            new_session(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    // 다른 사이트가 헤더만 흉내 내도 CSRF 쿠키가 없으면 거절합니다.
+    let without_csrf_cookie = format!("rsvg_access={}", login.access);
+    let response = send_with_cookies(
+        app.clone(),
+        "POST",
+        &sessions_uri,
+        &without_csrf_cookie,
+        Some(&login.csrf),
+        new_session(),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = send_with_cookies(
+        app.clone(),
+        "POST",
+        &sessions_uri,
+        &login.header(),
+        Some(&login.csrf),
+        new_session(),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    // Bearer 토큰 요청은 쿠키와 관계없이 CSRF 토큰이 필요 없습니다.
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+    helpers::create_user_session(app, &token, &user_id, "bearer", "<svg/>").await;
+}
+
+#[tokio::test]
+async fn test_cookie_refresh_and_logout() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("cookie_refresh");
+    let response = cookie_login(app.clone(), &user_id).await;
+    let login = LoginCookies::from_response(&response);
+
+    let response = send_with_cookies(
+        app.clone(),
+        "POST",
+        "/api/auth/cookie/refresh",
+        &login.header(),
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = send_with_cookies(
+        app.clone(),
+        "POST",
+        "/api/auth/cookie/refresh",
+        &login.header(),
+        Some(&login.csrf),
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let refreshed = LoginCookies::from_response(&response);
+    assert_ne!(refreshed.refresh, login.refresh);
+    assert_ne!(refreshed.csrf, login.csrf);
+    assert_eq!(json_body(response).await["user_id"], user_id);
+
+    // 교체된 refresh 토큰은 다시 쓸 수 없습니다.
+    let response = send_with_cookies(
+        app.clone(),
+        "POST",
+        "/api/auth/cookie/refresh",
+        &login.header(),
+        Some(&login.csrf),
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // 재사용이 감지되어 family가 폐기되었으므로 새로 로그인합니다.
+    let response = cookie_login(app.clone(), &user_id).await;
+    let login = LoginCookies::from_response(&response);
+
+    let response = send_with_cookies(
+        app.clone(),
+        "DELETE",
+        "/api/auth/cookie",
+        &login.header(),
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = send_with_cookies(
+        app.clone(),
+        "DELETE",
+        "/api/auth/cookie",
+        &login.header(),
+        Some(&login.csrf),
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    let cleared = set_cookies(&response);
+    assert_eq!(cleared.len(), 3);
+    assert!(cleared.values().all(|cookie| cookie.contains("Max-Age=0")));
+
+    let response = send_with_cookies(
+        app.clone(),
+        "GET",
+        "/api/auth/cookie",
+        &login.header(),
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = send_with_cookies(
+        app,
+        "POST",
+        "/api/auth/cookie/refresh",
+        &login.header(),
+        Some(&login.csrf),
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
@@ -11,12 +11,15 @@ use tower::ServiceExt;
 async fn test_token_issuance_flow() {
     let app = helpers::create_test_app().await;
     let user_id = helpers::unique_user_id("test");
+    helpers::register_user(app.clone(), &user_id).await;
 
     let request = Request::builder()
         .method("POST")
         .uri("/api/auth/token")
         .header("content-type", "application/json")
-        .body(Body::from(json!({"user_id": user_id}).to_string()))
+        .body(Body::from(
+            json!({"user_id": user_id, "password": "test-password"}).to_string(),
+        ))
         .unwrap();
 
     let response = app.oneshot(request).await.unwrap();
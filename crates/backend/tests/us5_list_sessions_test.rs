@@ -4,6 +4,7 @@ use axum::{
     body::Body,
     http::{Request, StatusCode},
 };
+use redis::AsyncTypedCommands;
 use serde_json::json;
 use tower::ServiceExt;
 
@@ -37,7 +38,7 @@ async fn test_list_user_sessions_returns_only_owners_sessions() {
 
     let list_request = Request::builder()
         .method("GET")
-        .uri(format!("/api/user/{}/sessions", user_id))
+        .uri(format!("/api/user/{}/session", user_id))
         .header("authorization", format!("Bearer {}", token))
         .body(Body::empty())
         .unwrap();
@@ -50,8 +51,8 @@ async fn test_list_user_sessions_returns_only_owners_sessions() {
         .unwrap();
     let body_json: serde_json::Value = serde_json::from_slice(&body).unwrap();
 
-    assert!(body_json["sessions"].is_array());
-    let sessions = body_json["sessions"].as_array().unwrap();
+    assert!(body_json["items"].is_array());
+    let sessions = body_json["items"].as_array().unwrap();
     assert_eq!(sessions.len(), 3);
 }
 
@@ -64,7 +65,7 @@ async fn test_empty_list_for_user_with_no_sessions() {
 
     let list_request = Request::builder()
         .method("GET")
-        .uri(format!("/api/user/{}/sessions", user_id))
+        .uri(format!("/api/user/{}/session", user_id))
         .header("authorization", format!("Bearer {}", token))
         .body(Body::empty())
         .unwrap();
@@ -77,8 +78,8 @@ async fn test_empty_list_for_user_with_no_sessions() {
         .unwrap();
     let body_json: serde_json::Value = serde_json::from_slice(&body).unwrap();
 
-    assert!(body_json["sessions"].is_array());
-    let sessions = body_json["sessions"].as_array().unwrap();
+    assert!(body_json["items"].is_array());
+    let sessions = body_json["items"].as_array().unwrap();
     assert_eq!(sessions.len(), 0);
 }
 
@@ -131,7 +132,7 @@ async fn test_list_excludes_other_users_sessions() {
 
     let list_alice_request = Request::builder()
         .method("GET")
-        .uri(format!("/api/user/{}/sessions", user_alice))
+        .uri(format!("/api/user/{}/session", user_alice))
         .header("authorization", format!("Bearer {}", alice_token))
         .body(Body::empty())
         .unwrap();
@@ -144,7 +145,7 @@ async fn test_list_excludes_other_users_sessions() {
         .unwrap();
     let body_json: serde_json::Value = serde_json::from_slice(&body).unwrap();
 
-    let sessions = body_json["sessions"].as_array().unwrap();
+    let sessions = body_json["items"].as_array().unwrap();
     assert_eq!(sessions.len(), 1);
 
     let session_ids: Vec<String> = sessions
@@ -154,3 +155,229 @@ async fn test_list_excludes_other_users_sessions() {
     assert!(session_ids.contains(&alice_session));
     assert!(!session_ids.contains(&bob_session));
 }
+
+#[tokio::test]
+async fn test_list_returns_session_metadata() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("meta");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+    let session_id = helpers::unique_session_id("session");
+
+    helpers::create_user_session(app.clone(), &token, &user_id, &session_id, "<svg></svg>").await;
+
+    let list_request = Request::builder()
+        .method("GET")
+        .uri(format!("/api/user/{}/session", user_id))
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.oneshot(list_request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body_json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+    let item = &body_json["items"][0];
+    assert_eq!(item["session_id"], session_id);
+    assert!(item["created_at"].is_string());
+    assert!(item["updated_at"].is_string());
+    assert!(item["ttl_seconds"].as_i64().unwrap() > 0);
+    assert_eq!(item["template_bytes"], "<svg></svg>".len());
+    assert_eq!(item["viewers"], 0);
+    assert_eq!(
+        item["thumbnail_url"],
+        format!("/stream/{}/{}/snapshot", user_id, session_id)
+    );
+    assert!(body_json.get("next_cursor").is_none());
+}
+
+#[tokio::test]
+async fn test_list_paginates_with_cursor_and_sorts_by_name() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("pager");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+
+    for name in ["charlie", "alpha", "bravo"] {
+        helpers::create_user_session(app.clone(), &token, &user_id, name, "<svg></svg>").await;
+    }
+
+    let mut cursor: Option<String> = None;
+    let mut seen = Vec::new();
+    loop {
+        let mut uri = format!("/api/user/{}/session?sort=name&order=asc&limit=2", user_id);
+        if let Some(cursor) = &cursor {
+            uri.push_str(&format!("&cursor={}", cursor));
+        }
+        let request = Request::builder()
+            .method("GET")
+            .uri(uri)
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body_json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        for item in body_json["items"].as_array().unwrap() {
+            seen.push(item["session_id"].as_str().unwrap().to_string());
+        }
+        match body_json["next_cursor"].as_str() {
+            Some(next) => cursor = Some(next.to_string()),
+            None => break,
+        }
+    }
+
+    assert_eq!(seen, vec!["alpha", "bravo", "charlie"]);
+}
+
+#[tokio::test]
+async fn test_list_filters_by_name() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("filter");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+
+    helpers::create_user_session(app.clone(), &token, &user_id, "dashboard-1", "<svg></svg>").await;
+    helpers::create_user_session(app.clone(), &token, &user_id, "badge", "<svg></svg>").await;
+
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!("/api/user/{}/session?q=dash", user_id))
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body_json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+    let items = body_json["items"].as_array().unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["session_id"], "dashboard-1");
+}
+
+#[tokio::test]
+async fn test_deleted_session_is_removed_from_list() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("deleter");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+    let session_id = helpers::unique_session_id("session");
+
+    helpers::create_user_session(app.clone(), &token, &user_id, &session_id, "<svg></svg>").await;
+
+    let delete_request = Request::builder()
+        .method("DELETE")
+        .uri(format!("/api/user/{}/session/{}", user_id, session_id))
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.clone().oneshot(delete_request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let list_request = Request::builder()
+        .method("GET")
+        .uri(format!("/api/user/{}/session", user_id))
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(list_request).await.unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body_json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(body_json["items"].as_array().unwrap().len(), 0);
+}
+
+#[tokio::test]
+async fn test_backfill_adds_unindexed_sessions_to_list() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("backfill");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+    let session_id = helpers::unique_session_id("unindexed");
+    let mut session = common::session_data::SessionData::new("<svg></svg>", Default::default());
+    session.created_at = None;
+
+    // 인덱스가 생기기 전처럼 세션 키만 저장합니다.
+    let keys = common::keys::Keys::default();
+    let redis_url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1/".to_string());
+    let mut conn = redis::Client::open(redis_url)
+        .unwrap()
+        .get_multiplexed_async_connection()
+        .await
+        .unwrap();
+    conn.set_ex(
+        keys.user_session(&user_id, &session_id),
+        serde_json::to_string(&session).unwrap().as_str(),
+        600,
+    )
+    .await
+    .unwrap();
+    conn.del(keys.migration_session_index()).await.unwrap();
+
+    let added = common::migration::backfill_session_indexes(&mut conn, &keys)
+        .await
+        .unwrap();
+    assert!(added >= 1);
+
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!("/api/user/{}/session", user_id))
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body_json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let items = body_json["items"].as_array().unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["session_id"], session_id);
+
+    // 표시 키가 있으므로 두 번째 실행은 아무것도 하지 않습니다.
+    let added = common::migration::backfill_session_indexes(&mut conn, &keys)
+        .await
+        .unwrap();
+    assert_eq!(added, 0);
+}
+
+#[tokio::test]
+async fn test_snapshot_returns_rendered_svg() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("snapshot");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+    let session_id = helpers::unique_session_id("session");
+
+    helpers::create_user_session(
+        app.clone(),
+        &token,
+        &user_id,
+        &session_id,
+        "<svg>{{msg}}</svg>",
+    )
+    .await;
+
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!("/stream/{}/{}/snapshot", user_id, session_id))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "image/svg+xml");
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    assert_eq!(&body[..], b"<svg>hello</svg>");
+}
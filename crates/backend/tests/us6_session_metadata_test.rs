@@ -0,0 +1,203 @@
+mod helpers;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use serde_json::json;
+use tower::ServiceExt;
+
+async fn get_json(app: Router, uri: String, token: &str) -> serde_json::Value {
+    let request = Request::builder()
+        .method("GET")
+        .uri(uri)
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    serde_json::from_slice(&body).unwrap()
+}
+
+#[tokio::test]
+async fn test_create_session_with_metadata() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("meta");
+    let session_id = helpers::unique_session_id("session");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+
+    let request = Request::builder()
+        .method("POST")
+        .uri(format!("/api/user/{}/session", user_id))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(
+            json!({
+                "session_id": session_id,
+                "template": "<svg></svg>",
+                "args": {},
+                "title": "서버 상태",
+                "description": "실시간 서버 상태 배지",
+                "tags": ["Status", "server", "status"]
+            })
+            .to_string(),
+        ))
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let detail = get_json(
+        app.clone(),
+        format!("/api/user/{}/session/{}", user_id, session_id),
+        &token,
+    )
+    .await;
+    assert_eq!(detail["title"], "서버 상태");
+    assert_eq!(detail["description"], "실시간 서버 상태 배지");
+    assert_eq!(detail["tags"], json!(["status", "server"]));
+
+    let list = get_json(app, format!("/api/user/{}/session", user_id), &token).await;
+    assert_eq!(list["items"][0]["title"], "서버 상태");
+    assert_eq!(list["items"][0]["tags"], json!(["status", "server"]));
+}
+
+#[tokio::test]
+async fn test_update_metadata_keeps_args() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("meta_update");
+    let session_id = helpers::unique_session_id("session");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+
+    helpers::create_user_session(app.clone(), &token, &user_id, &session_id, "<svg></svg>").await;
+
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!("/api/user/{}/session/{}", user_id, session_id))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(
+            json!({"title": "새 제목", "tags": ["badge"]}).to_string(),
+        ))
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let detail = get_json(
+        app,
+        format!("/api/user/{}/session/{}", user_id, session_id),
+        &token,
+    )
+    .await;
+    assert_eq!(detail["title"], "새 제목");
+    assert_eq!(detail["tags"], json!(["badge"]));
+    assert_eq!(detail["args"]["msg"], "hello");
+}
+
+#[tokio::test]
+async fn test_list_filters_by_tag() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("tagger");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+
+    for (session_id, tags) in [("tagged", json!(["ops"])), ("untagged", json!([]))] {
+        let request = Request::builder()
+            .method("POST")
+            .uri(format!("/api/user/{}/session", user_id))
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::from(
+                json!({
+                    "session_id": session_id,
+                    "template": "<svg></svg>",
+                    "args": {},
+                    "tags": tags
+                })
+                .to_string(),
+            ))
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    let list = get_json(
+        app,
+        format!("/api/user/{}/session?tag=OPS", user_id),
+        &token,
+    )
+    .await;
+    let items = list["items"].as_array().unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["session_id"], "tagged");
+}
+
+#[tokio::test]
+async fn test_invalid_tag_is_rejected() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("bad_tag");
+    let session_id = helpers::unique_session_id("session");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+
+    let request = Request::builder()
+        .method("POST")
+        .uri(format!("/api/user/{}/session", user_id))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(
+            json!({
+                "session_id": session_id,
+                "template": "<svg></svg>",
+                "args": {},
+                "tags": ["has space"]
+            })
+            .to_string(),
+        ))
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_public_session_metadata() {
+    let app = helpers::create_test_app().await;
+    let session_id = helpers::unique_session_id("public_meta");
+
+    let request = Request::builder()
+        .method("POST")
+        .uri("/api/session")
+        .header("content-type", "application/json")
+        .body(Body::from(
+            json!({
+                "session_id": session_id,
+                "template": "<svg></svg>",
+                "title": "Public badge",
+                "tags": ["public"]
+            })
+            .to_string(),
+        ))
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!("/api/session/{}", session_id))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let detail: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(detail["title"], "Public badge");
+    assert_eq!(detail["tags"], json!(["public"]));
+}
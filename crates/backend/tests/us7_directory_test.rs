@@ -0,0 +1,260 @@
+mod helpers;
+
+use axum::{http::StatusCode, Router};
+use helpers::Auth;
+use serde_json::json;
+
+async fn directory(app: Router, query: &str) -> serde_json::Value {
+    let uri = format!("/api/directory{}", query);
+    let (status, body) = helpers::send(app, "GET", &uri, Auth::Anonymous, None).await;
+    assert_eq!(status, StatusCode::OK);
+    body
+}
+
+fn contains_session(body: &serde_json::Value, session_id: &str) -> bool {
+    body["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|item| item["session_id"] == session_id)
+}
+
+#[tokio::test]
+async fn test_listed_public_session_appears_in_directory() {
+    let app = helpers::create_test_app().await;
+    let listed = helpers::unique_session_id("listed");
+    let hidden = helpers::unique_session_id("hidden");
+    let marker = helpers::unique_session_id("marker");
+
+    let (status, _) = helpers::send(
+        app.clone(),
+        "POST",
+        "/api/session",
+        Auth::Anonymous,
+        Some(json!({
+            "session_id": listed,
+            "template": "<svg></svg>",
+            "title": format!("Directory {}", marker),
+            "tags": ["directory"],
+            "listed": true
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (status, _) = helpers::send(
+        app.clone(),
+        "POST",
+        "/api/session",
+        Auth::Anonymous,
+        Some(json!({
+            "session_id": hidden,
+            "template": "<svg></svg>",
+            "title": format!("Directory {}", marker)
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let body = directory(app, &format!("?q={}", marker)).await;
+    let items = body["items"].as_array().unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["session_id"], listed);
+    assert_eq!(items[0]["stream_url"], format!("/stream/{}", listed));
+    assert_eq!(
+        items[0]["thumbnail_url"],
+        format!("/api/session/{}/snapshot", listed)
+    );
+    assert!(items[0].get("user_id").is_none());
+}
+
+#[tokio::test]
+async fn test_unlisted_session_is_removed_from_directory() {
+    let app = helpers::create_test_app().await;
+    let session_id = helpers::unique_session_id("unlist");
+
+    helpers::send(
+        app.clone(),
+        "POST",
+        "/api/session",
+        Auth::Anonymous,
+        Some(json!({
+            "session_id": session_id,
+            "template": "<svg></svg>",
+            "title": "To be unlisted",
+            "listed": true
+        })),
+    )
+    .await;
+    let body = directory(app.clone(), &format!("?q={}", session_id)).await;
+    assert!(contains_session(&body, &session_id));
+
+    let (status, _) = helpers::send(
+        app.clone(),
+        "PUT",
+        &format!("/api/session/{}", session_id),
+        Auth::Anonymous,
+        Some(json!({"listed": false})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let body = directory(app, &format!("?q={}", session_id)).await;
+    assert!(!contains_session(&body, &session_id));
+}
+
+#[tokio::test]
+async fn test_recent_directory_pages_newest_first() {
+    let app = helpers::create_test_app().await;
+    let session_ids: Vec<String> = ["oldest", "middle", "newest"]
+        .iter()
+        .map(|prefix| helpers::unique_session_id(prefix))
+        .collect();
+
+    for session_id in &session_ids {
+        let (status, _) = helpers::send(
+            app.clone(),
+            "POST",
+            "/api/session",
+            Auth::Anonymous,
+            Some(json!({
+                "session_id": session_id,
+                "template": "<svg></svg>",
+                "title": "Recent page",
+                "listed": true
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+    }
+
+    let (status, _) = helpers::send(
+        app.clone(),
+        "PUT",
+        &format!("/api/session/{}", session_ids[1]),
+        Auth::Anonymous,
+        Some(json!({"listed": false})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // 다른 테스트가 등록한 세션이 섞여 있을 수 있으므로 커서를 따라가며 이 테스트의 세션만 모읍니다.
+    let mut cursor: Option<String> = None;
+    let mut seen_urls = Vec::new();
+    let mut found = Vec::new();
+    loop {
+        let mut query = "?sort=recent&limit=2".to_string();
+        if let Some(cursor) = &cursor {
+            let encoded: String = url::form_urlencoded::byte_serialize(cursor.as_bytes()).collect();
+            query.push_str(&format!("&cursor={}", encoded));
+        }
+        let body = directory(app.clone(), &query).await;
+        for item in body["items"].as_array().unwrap() {
+            seen_urls.push(item["page_url"].as_str().unwrap().to_string());
+            let session_id = item["session_id"].as_str().unwrap();
+            if session_ids.iter().any(|id| id == session_id) {
+                found.push(session_id.to_string());
+            }
+        }
+        match body["next_cursor"].as_str() {
+            Some(next) if found.len() < 2 => cursor = Some(next.to_string()),
+            _ => break,
+        }
+    }
+
+    assert_eq!(found, vec![session_ids[2].clone(), session_ids[0].clone()]);
+    let mut unique = seen_urls.clone();
+    unique.sort();
+    unique.dedup();
+    assert_eq!(unique.len(), seen_urls.len());
+}
+
+#[tokio::test]
+async fn test_listing_without_title_is_rejected() {
+    let app = helpers::create_test_app().await;
+    let session_id = helpers::unique_session_id("untitled");
+
+    let (status, _) = helpers::send(
+        app,
+        "POST",
+        "/api/session",
+        Auth::Anonymous,
+        Some(json!({
+            "session_id": session_id,
+            "template": "<svg></svg>",
+            "listed": true
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_user_session_in_directory_and_removed_on_delete() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("lister");
+    let session_id = helpers::unique_session_id("session");
+    let token = helpers::issue_token(app.clone(), &user_id, None).await;
+
+    let (status, _) = helpers::send(
+        app.clone(),
+        "POST",
+        &format!("/api/user/{}/session", user_id),
+        &token,
+        Some(json!({
+            "session_id": session_id,
+            "template": "<svg></svg>",
+            "args": {},
+            "title": "User listed",
+            "listed": true
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let body = directory(app.clone(), &format!("?q={}", session_id)).await;
+    let items = body["items"].as_array().unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["user_id"], user_id);
+    assert_eq!(
+        items[0]["page_url"],
+        format!("/session/{}/{}", user_id, session_id)
+    );
+
+    let uri = format!("/api/user/{}/session/{}", user_id, session_id);
+    let (status, _) = helpers::send(app.clone(), "DELETE", &uri, &token, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let body = directory(app, &format!("?q={}", session_id)).await;
+    assert!(!contains_session(&body, &session_id));
+}
+
+#[tokio::test]
+async fn test_public_snapshot_returns_svg() {
+    let app = helpers::create_test_app().await;
+    let session_id = helpers::unique_session_id("snapshot");
+
+    helpers::send(
+        app.clone(),
+        "POST",
+        "/api/session",
+        Auth::Anonymous,
+        Some(json!({
+            "session_id": session_id,
+            "template": "<svg>{{msg}}</svg>",
+            "args": {"msg": "hi"}
+        })),
+    )
+    .await;
+
+    let uri = format!("/api/session/{}/snapshot", session_id);
+    let response = helpers::send_raw(app, "GET", &uri, Auth::Anonymous, None).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "image/svg+xml");
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    assert_eq!(&body[..], b"<svg>hi</svg>");
+}
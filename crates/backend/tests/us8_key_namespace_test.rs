@@ -0,0 +1,281 @@
+mod helpers;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use common::keys::Keys;
+use redis::AsyncTypedCommands;
+use serde_json::json;
+use tower::ServiceExt;
+
+fn redis_client() -> redis::Client {
+    let redis_url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1/".to_string());
+    redis::Client::open(redis_url).unwrap()
+}
+
+#[tokio::test]
+async fn test_public_session_is_stored_under_prefix() {
+    let app = helpers::create_test_app().await;
+    let session_id = helpers::unique_session_id("namespaced");
+
+    let request = Request::builder()
+        .method("POST")
+        .uri("/api/session")
+        .header("content-type", "application/json")
+        .body(Body::from(
+            json!({"session_id": session_id, "template": "<svg></svg>"}).to_string(),
+        ))
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let mut conn = redis_client()
+        .get_multiplexed_async_connection()
+        .await
+        .unwrap();
+    assert!(conn
+        .exists(format!("session:{}", session_id))
+        .await
+        .unwrap());
+    assert!(!conn.exists(&session_id).await.unwrap());
+}
+
+#[tokio::test]
+async fn test_public_session_id_cannot_address_internal_keys() {
+    let app = helpers::create_test_app().await;
+
+    for session_id in [
+        "user:alice:session:x",
+        ".realtime-svg:rsa:private_pem",
+        "has space",
+    ] {
+        let request = Request::builder()
+            .method("POST")
+            .uri("/api/session")
+            .header("content-type", "application/json")
+            .body(Body::from(
+                json!({"session_id": session_id, "template": "<svg></svg>"}).to_string(),
+            ))
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{session_id}");
+    }
+
+    let request = Request::builder()
+        .method("GET")
+        .uri("/api/session/.realtime-svg:rsa:private_pem")
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_invalid_user_id_is_rejected_at_token_issuance() {
+    let app = helpers::create_test_app().await;
+
+    let request = Request::builder()
+        .method("POST")
+        .uri("/api/auth/token")
+        .header("content-type", "application/json")
+        .body(Body::from(
+            json!({"user_id": "alice:data", "password": "test-password"}).to_string(),
+        ))
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_migration_moves_legacy_public_session_keys() {
+    let _app = helpers::create_test_app().await;
+    let session_id = helpers::unique_session_id("legacy");
+    let session = common::session_data::SessionData::new("<svg></svg>", Default::default());
+
+    let mut conn = redis_client()
+        .get_multiplexed_async_connection()
+        .await
+        .unwrap();
+    conn.set_ex(
+        &session_id,
+        serde_json::to_string(&session).unwrap().as_str(),
+        600,
+    )
+    .await
+    .unwrap();
+    let keys = Keys::default();
+    conn.del(keys.migration_public_session_prefix())
+        .await
+        .unwrap();
+
+    common::migration::migrate_public_session_keys(&mut conn, &keys)
+        .await
+        .unwrap();
+
+    let target = format!("session:{}", session_id);
+    assert!(!conn.exists(&session_id).await.unwrap());
+    assert!(conn.exists(&target).await.unwrap());
+    let ttl: i64 = redis::cmd("TTL")
+        .arg(&target)
+        .query_async(&mut conn)
+        .await
+        .unwrap();
+    assert!(ttl > 0 && ttl <= 600);
+
+    // 표시 키가 있으므로 두 번째 실행은 아무것도 하지 않습니다.
+    let migrated = common::migration::migrate_public_session_keys(&mut conn, &keys)
+        .await
+        .unwrap();
+    assert_eq!(migrated, 0);
+}
+
+#[tokio::test]
+async fn test_migration_waits_for_lock_before_marking_done() {
+    let _app = helpers::create_test_app().await;
+    let tenant = helpers::unique_user_id("locked");
+    let keys = Keys::new(&tenant);
+    let session_id = helpers::unique_session_id("legacy");
+    let session = common::session_data::SessionData::new("<svg></svg>", Default::default());
+
+    let mut conn = redis_client()
+        .get_multiplexed_async_connection()
+        .await
+        .unwrap();
+    let legacy = format!("{}{}", keys.prefix(), session_id);
+    conn.set_ex(
+        &legacy,
+        serde_json::to_string(&session).unwrap().as_str(),
+        600,
+    )
+    .await
+    .unwrap();
+
+    // 다른 인스턴스가 실행 중이면 건너뛰고, 완료로 표시하지도 않습니다.
+    let lock = keys.migration_lock("public_session_prefix");
+    conn.set_ex(&lock, "1", 60).await.unwrap();
+    let migrated = common::migration::migrate_public_session_keys(&mut conn, &keys)
+        .await
+        .unwrap();
+    assert_eq!(migrated, 0);
+    assert!(conn.exists(&legacy).await.unwrap());
+    // 다른 인스턴스의 잠금은 풀지 않습니다.
+    assert_eq!(conn.get(&lock).await.unwrap().as_deref(), Some("1"));
+    assert!(!conn
+        .exists(keys.migration_public_session_prefix())
+        .await
+        .unwrap());
+
+    // 잠금이 풀리면 이어서 실행하고, 마친 뒤에 완료 표시를 남기고 잠금을 풉니다.
+    conn.del(&lock).await.unwrap();
+    let migrated = common::migration::migrate_public_session_keys(&mut conn, &keys)
+        .await
+        .unwrap();
+    assert_eq!(migrated, 1);
+    assert!(conn.exists(keys.public_session(&session_id)).await.unwrap());
+    assert!(conn
+        .exists(keys.migration_public_session_prefix())
+        .await
+        .unwrap());
+    assert!(!conn.exists(&lock).await.unwrap());
+}
+
+async fn create_public_session(app: axum::Router, session_id: &str, template: &str) -> StatusCode {
+    let request = Request::builder()
+        .method("POST")
+        .uri("/api/session")
+        .header("content-type", "application/json")
+        .body(Body::from(
+            json!({"session_id": session_id, "template": template}).to_string(),
+        ))
+        .unwrap();
+    app.oneshot(request).await.unwrap().status()
+}
+
+#[tokio::test]
+async fn test_key_prefix_isolates_deployments() {
+    let tenant_a = helpers::unique_user_id("tenant");
+    let tenant_b = helpers::unique_user_id("tenant");
+    let app_a = helpers::create_test_app_with_keys(redis_client(), Keys::new(&tenant_a)).await;
+    let app_b = helpers::create_test_app_with_keys(redis_client(), Keys::new(&tenant_b)).await;
+    let session_id = helpers::unique_session_id("shared");
+
+    assert_eq!(
+        create_public_session(app_a.clone(), &session_id, "<svg>a</svg>").await,
+        StatusCode::CREATED
+    );
+    assert_eq!(
+        create_public_session(app_b.clone(), &session_id, "<svg>b</svg>").await,
+        StatusCode::CREATED
+    );
+
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!("/api/session/{}", session_id))
+        .body(Body::empty())
+        .unwrap();
+    let response = app_b.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["template"], "<svg>b</svg>");
+
+    let mut conn = redis_client()
+        .get_multiplexed_async_connection()
+        .await
+        .unwrap();
+    assert!(conn
+        .exists(format!("{}:session:{}", tenant_a, session_id))
+        .await
+        .unwrap());
+    assert!(!conn
+        .exists(format!("session:{}", session_id))
+        .await
+        .unwrap());
+
+    // 배포마다 서명 키가 다르므로 다른 배포에서 발급한 토큰은 통하지 않습니다.
+    let user_id = helpers::unique_user_id("tenant_user");
+    let token = helpers::issue_token(app_a, &user_id, None).await;
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!("/api/user/{}/session", user_id))
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::empty())
+        .unwrap();
+    let response = app_b.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_migration_ignores_other_deployments_keys() {
+    let tenant = helpers::unique_user_id("tenant");
+    let keys = Keys::new(&tenant);
+    let session = common::session_data::SessionData::new("<svg></svg>", Default::default());
+    let session_json = serde_json::to_string(&session).unwrap();
+    let legacy_id = helpers::unique_session_id("legacy");
+    let foreign_key = format!("other-{}:{}", tenant, legacy_id);
+
+    let mut conn = redis_client()
+        .get_multiplexed_async_connection()
+        .await
+        .unwrap();
+    conn.set_ex(
+        format!("{}{}", keys.prefix(), legacy_id),
+        session_json.as_str(),
+        600,
+    )
+    .await
+    .unwrap();
+    conn.set_ex(&foreign_key, session_json.as_str(), 600)
+        .await
+        .unwrap();
+
+    let migrated = common::migration::migrate_public_session_keys(&mut conn, &keys)
+        .await
+        .unwrap();
+    assert_eq!(migrated, 1);
+    assert!(conn.exists(keys.public_session(&legacy_id)).await.unwrap());
+    assert!(conn.exists(&foreign_key).await.unwrap());
+}
@@ -0,0 +1,200 @@
+mod helpers;
+
+use argon2::Params;
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use common::{keys::Keys, user_data::UserData};
+use redis::AsyncTypedCommands;
+use serde_json::json;
+use tower::ServiceExt;
+
+async fn post_json(app: Router, uri: &str, body: serde_json::Value) -> StatusCode {
+    let request = Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("content-type", "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap();
+    app.oneshot(request).await.unwrap().status()
+}
+
+#[tokio::test]
+async fn test_token_for_unknown_user_is_rejected() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("unknown");
+
+    let status = post_json(
+        app.clone(),
+        "/api/auth/token",
+        json!({"user_id": user_id, "password": helpers::TEST_PASSWORD}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    // 토큰 요청이 계정을 만들지 않으므로 같은 이름으로 가입할 수 있습니다.
+    let status = post_json(
+        app,
+        "/api/auth/register",
+        json!({"user_id": user_id, "password": helpers::TEST_PASSWORD}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+}
+
+#[tokio::test]
+async fn test_register_then_login() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("register");
+
+    helpers::register_user(app.clone(), &user_id).await;
+
+    let status = post_json(
+        app.clone(),
+        "/api/auth/register",
+        json!({"user_id": user_id, "password": "another-password"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let status = post_json(
+        app.clone(),
+        "/api/auth/token",
+        json!({"user_id": user_id, "password": "wrong-password"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let token = helpers::issue_token(app, &user_id, Some(60)).await;
+    assert!(!token.is_empty());
+}
+
+#[tokio::test]
+async fn test_registration_rules() {
+    let app = helpers::create_test_app().await;
+
+    for (user_id, password) in [
+        ("ab", "long-enough-password"),
+        ("_leading-underscore", "long-enough-password"),
+        ("alice:data", "long-enough-password"),
+        ("valid-user", "short"),
+        ("valid-user", "          "),
+        ("same-as-name", "same-as-name"),
+    ] {
+        let status = post_json(
+            app.clone(),
+            "/api/auth/register",
+            json!({"user_id": user_id, "password": password}),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{user_id} / {password:?}");
+    }
+}
+
+#[tokio::test]
+async fn test_account_is_permanent_and_tracks_login() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("permanent");
+
+    helpers::issue_token(app, &user_id, Some(60)).await;
+
+    let redis_url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1/".to_string());
+    let mut conn = redis::Client::open(redis_url)
+        .unwrap()
+        .get_multiplexed_async_connection()
+        .await
+        .unwrap();
+    let user_key = format!("user:{}:data", user_id);
+
+    let ttl: i64 = redis::cmd("TTL")
+        .arg(&user_key)
+        .query_async(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(ttl, -1);
+
+    let json = conn.get(&user_key).await.unwrap().unwrap();
+    let user_data: common::user_data::UserData = serde_json::from_str(&json).unwrap();
+    assert!(user_data.created_at.is_some());
+    assert!(user_data.last_login_at.is_some());
+}
+
+async fn stored_user_data(keys: &Keys, user_id: &str) -> UserData {
+    let redis_url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1/".to_string());
+    let mut conn = redis::Client::open(redis_url)
+        .unwrap()
+        .get_multiplexed_async_connection()
+        .await
+        .unwrap();
+    let json = conn.get(keys.user_data(user_id)).await.unwrap().unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+#[tokio::test]
+async fn test_same_password_produces_distinct_hashes() {
+    let app = helpers::create_test_app().await;
+    let alice = helpers::unique_user_id("salt");
+    let bob = helpers::unique_user_id("salt");
+
+    helpers::register_user(app.clone(), &alice).await;
+    helpers::register_user(app, &bob).await;
+
+    let keys = Keys::default();
+    let alice_hash = stored_user_data(&keys, &alice).await.password_argon2;
+    let bob_hash = stored_user_data(&keys, &bob).await.password_argon2;
+    assert!(alice_hash.starts_with("$argon2id$"));
+    assert_ne!(alice_hash, bob_hash);
+}
+
+#[tokio::test]
+async fn test_weaker_hash_is_upgraded_on_login() {
+    let redis_url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1/".to_string());
+    let client = redis::Client::open(redis_url).unwrap();
+    let keys = Keys::new(helpers::unique_user_id("rehash"));
+    let weak = Params::new(Params::MIN_M_COST, 1, 1, None).unwrap();
+    let strong = Params::new(Params::MIN_M_COST * 2, 2, 1, None).unwrap();
+
+    let weak_app = helpers::create_test_app_with(client.clone(), keys.clone(), weak).await;
+    let user_id = helpers::unique_user_id("rehash");
+    helpers::register_user(weak_app, &user_id).await;
+    let before = stored_user_data(&keys, &user_id).await;
+
+    let strong_app = helpers::create_test_app_with(client, keys.clone(), strong.clone()).await;
+    helpers::issue_token(strong_app.clone(), &user_id, None).await;
+    let after = stored_user_data(&keys, &user_id).await;
+
+    assert_ne!(before.password_argon2, after.password_argon2);
+    assert!(before.needs_rehash(&strong, None));
+    assert!(!after.needs_rehash(&strong, None));
+
+    // 새 해시로도 로그인할 수 있습니다.
+    let token = helpers::issue_token(strong_app, &user_id, None).await;
+    assert!(!token.is_empty());
+}
+
+#[tokio::test]
+async fn test_login_record_keeps_changes_made_during_verification() {
+    let app = helpers::create_test_app().await;
+    let user_id = helpers::unique_user_id("stale");
+    helpers::register_user(app, &user_id).await;
+
+    let redis_url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1/".to_string());
+    let state = common::state::AppState::new(
+        redis::Client::open(redis_url).unwrap(),
+        Keys::default(),
+        Default::default(),
+        Default::default(),
+    );
+
+    // 비밀번호를 검증하는 동안 다른 요청이 토큰을 모두 폐기한 상황입니다.
+    let mut stale = state.get_user_data(&user_id).await.unwrap().unwrap();
+    state.revoke_user_tokens(&user_id).await.unwrap();
+    state.record_user_login(&user_id, &mut stale).await.unwrap();
+
+    let stored = stored_user_data(&Keys::default(), &user_id).await;
+    assert_eq!(stored.token_generation, stale.token_generation + 1);
+    assert_eq!(stored.password_argon2, stale.password_argon2);
+    assert!(stored.last_login_at.is_some());
+}
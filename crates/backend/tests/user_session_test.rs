@@ -1,4 +1,4 @@
-use common::{jwt, session_data::SessionData, state::AppState};
+use common::{jwt, keys::Keys, session_data::SessionData, state::AppState};
 use redis::Client;
 use std::collections::HashMap;
 
@@ -6,11 +6,17 @@ async fn setup_test() -> (AppState, Client) {
     let redis_url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1/".to_string());
     let redis_client = Client::open(redis_url).unwrap();
 
-    common::share::initialize_redis(&redis_client)
+    let keys = Keys::default();
+    common::share::initialize_redis(&redis_client, &keys, Default::default())
         .await
         .unwrap();
 
-    let state = AppState::new(redis_client.clone());
+    let state = AppState::new(
+        redis_client.clone(),
+        keys,
+        Default::default(),
+        Default::default(),
+    );
     (state, redis_client)
 }
 
@@ -93,21 +99,19 @@ async fn test_list_sessions_filters_by_user() {
 async fn test_jwt_token_creation_and_verification() {
     let (state, _) = setup_test().await;
 
-    let encoding_key = state
+    let signing_key = state
         .share()
-        .get_encoding_key(state.redis_client())
+        .get_signing_key(state.redis_client())
         .await
         .unwrap();
 
-    let decoding_key = state
+    let token = jwt::create_token("test_user", 0, Default::default(), &signing_key, 3600).unwrap();
+    let claims = state
         .share()
-        .get_decoding_key(state.redis_client())
+        .verify_token(state.redis_client(), &token)
         .await
         .unwrap();
 
-    let token = jwt::create_token("test_user", encoding_key, 3600).unwrap();
-    let claims = jwt::verify_token(&token, decoding_key).unwrap();
-
     assert_eq!(claims.sub, "test_user");
     assert_eq!(claims.iss, "realtime-svg");
 }
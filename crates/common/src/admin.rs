@@ -0,0 +1,436 @@
+//! 관리자 API(`/api/admin`)의 요청·응답 타입과 목록 검색.
+//!
+//! 관리자 목록은 인덱스가 아니라 Redis SCAN으로 모든 계정과 세션을 훑어서 만듭니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    directory::SessionRef,
+    errors::ApiError,
+    rate_limit::LoginRejectionStats,
+    session_data::Visibility,
+    session_list::{
+        name_sort_id, page_sorted, ListCursor, SessionSort, SortOrder, DEFAULT_LIMIT, MAX_LIMIT,
+    },
+    state::{AccountEntry, StoredSessionEntry},
+};
+
+/// `GET /api/admin/user` 응답의 한 항목.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AdminUserInfo {
+    pub user_id: String,
+    pub admin: bool,
+    /// 비활성화된 시각. 활성 계정이면 없음.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disabled_at: Option<DateTime<Utc>>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub last_login_at: Option<DateTime<Utc>>,
+    /// 세션 인덱스에 있는 세션 수 (만료되었지만 아직 정리되지 않은 세션 포함).
+    pub sessions: u64,
+}
+
+impl From<AccountEntry> for AdminUserInfo {
+    fn from(entry: AccountEntry) -> Self {
+        Self {
+            user_id: entry.user_id,
+            admin: entry.user_data.admin,
+            disabled_at: entry.user_data.disabled_at,
+            created_at: entry.user_data.created_at,
+            last_login_at: entry.user_data.last_login_at,
+            sessions: entry.sessions,
+        }
+    }
+}
+
+/// `GET /api/admin/user` 쿼리 파라미터.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AdminUserQuery {
+    pub cursor: Option<String>,
+    pub limit: Option<usize>,
+    /// 사용자 ID에 포함되어야 하는 문자열 (대소문자 무시)
+    pub q: Option<String>,
+    /// 관리자만(`true`) 또는 관리자가 아닌 계정만(`false`) 조회
+    pub admin: Option<bool>,
+    /// 비활성화된 계정만(`true`) 또는 활성 계정만(`false`) 조회
+    pub disabled: Option<bool>,
+}
+
+impl AdminUserQuery {
+    pub fn limit(&self) -> usize {
+        self.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT)
+    }
+
+    /// 필터링 후 사용자 ID 순으로 한 페이지를 잘라 반환합니다.
+    pub fn apply(
+        &self,
+        mut items: Vec<AdminUserInfo>,
+    ) -> Result<(Vec<AdminUserInfo>, Option<String>), ApiError> {
+        let cursor = ListCursor::parse(self.cursor.as_deref())?;
+
+        if let Some(q) = self.q.as_deref().map(str::trim).filter(|q| !q.is_empty()) {
+            let q = q.to_lowercase();
+            items.retain(|item| item.user_id.to_lowercase().contains(&q));
+        }
+        if let Some(admin) = self.admin {
+            items.retain(|item| item.admin == admin);
+        }
+        if let Some(disabled) = self.disabled {
+            items.retain(|item| item.disabled_at.is_some() == disabled);
+        }
+
+        Ok(page_sorted(
+            items,
+            cursor.as_ref(),
+            self.limit(),
+            false,
+            |item| (0, item.user_id.clone()),
+        ))
+    }
+}
+
+/// `PUT /api/admin/user/{user_id}` 요청. 값이 있는 필드만 반영합니다.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AdminUserUpdate {
+    pub admin: Option<bool>,
+    /// `true`이면 계정을 비활성화하고 발급된 토큰을 모두 폐기합니다.
+    pub disabled: Option<bool>,
+}
+
+/// `GET /api/admin/session` 응답의 한 항목.
+///
+/// `user_id`와 `org_id`가 모두 없으면 공용 세션입니다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AdminSessionInfo {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub org_id: Option<String>,
+    pub session_id: String,
+    pub title: Option<String>,
+    pub visibility: Visibility,
+    pub listed: bool,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+    /// 남은 TTL(초). 만료가 설정되지 않았으면 없음.
+    pub ttl_seconds: Option<i64>,
+    /// 현재 스트림을 구독 중인 뷰어 수.
+    pub viewers: u64,
+    /// 템플릿 원문의 바이트 크기.
+    pub template_bytes: usize,
+    pub page_url: String,
+    pub stream_url: String,
+}
+
+impl From<StoredSessionEntry> for AdminSessionInfo {
+    fn from(entry: StoredSessionEntry) -> Self {
+        Self {
+            page_url: entry.session_ref.page_path(),
+            stream_url: entry.session_ref.stream_path(),
+            user_id: entry.session_ref.user_id,
+            org_id: entry.session_ref.org_id,
+            session_id: entry.session_ref.session_id,
+            title: entry.session.title,
+            visibility: entry.session.visibility,
+            listed: entry.session.listed,
+            created_at: entry.session.created_at,
+            updated_at: entry.session.updated_at,
+            ttl_seconds: entry.ttl_seconds,
+            viewers: entry.viewers,
+            template_bytes: entry.session.template.len(),
+        }
+    }
+}
+
+/// `GET /api/admin/session` 쿼리 파라미터.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AdminSessionQuery {
+    pub cursor: Option<String>,
+    pub limit: Option<usize>,
+    #[serde(default)]
+    pub sort: SessionSort,
+    #[serde(default)]
+    pub order: SortOrder,
+    /// 세션 ID, 제목, 소유자(사용자 또는 조직 ID)에 포함되어야 하는 문자열 (대소문자 무시)
+    pub q: Option<String>,
+    /// 이 사용자의 세션만 조회
+    pub user_id: Option<String>,
+    /// 이 조직의 세션만 조회
+    pub org_id: Option<String>,
+}
+
+impl AdminSessionQuery {
+    pub fn limit(&self) -> usize {
+        self.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT)
+    }
+
+    /// 필터링, 정렬 후 한 페이지를 잘라 반환합니다.
+    ///
+    /// 세션마다 다른 상세 페이지 경로(`page_url`)를 ID로 써서 같은 정렬 값끼리의 순서를 정합니다.
+    pub fn apply(
+        &self,
+        mut items: Vec<AdminSessionInfo>,
+    ) -> Result<(Vec<AdminSessionInfo>, Option<String>), ApiError> {
+        let cursor = ListCursor::parse(self.cursor.as_deref())?;
+
+        if let Some(q) = self.q.as_deref().map(str::trim).filter(|q| !q.is_empty()) {
+            let q = q.to_lowercase();
+            let contains = |value: &Option<String>| {
+                value
+                    .as_ref()
+                    .is_some_and(|value| value.to_lowercase().contains(&q))
+            };
+            items.retain(|item| {
+                item.session_id.to_lowercase().contains(&q)
+                    || contains(&item.title)
+                    || contains(&item.user_id)
+                    || contains(&item.org_id)
+            });
+        }
+        if let Some(user_id) = self.user_id.as_deref() {
+            items.retain(|item| item.user_id.as_deref() == Some(user_id));
+        }
+        if let Some(org_id) = self.org_id.as_deref() {
+            items.retain(|item| item.org_id.as_deref() == Some(org_id));
+        }
+
+        let millis = |at: Option<DateTime<Utc>>| at.map_or(0, |at| at.timestamp_millis());
+        let sort_key = |item: &AdminSessionInfo| {
+            let key = match self.sort {
+                SessionSort::Created => millis(item.created_at),
+                SessionSort::Updated => millis(item.updated_at),
+                // 이름 순서는 ID에 담습니다.
+                SessionSort::Name => 0,
+                SessionSort::Ttl => item.ttl_seconds.unwrap_or(i64::MAX),
+                SessionSort::Viewers => i64::try_from(item.viewers).unwrap_or(i64::MAX),
+            };
+            let id = match self.sort {
+                SessionSort::Name => {
+                    name_sort_id(item.title.as_deref(), &item.session_id, &item.page_url)
+                }
+                _ => item.page_url.clone(),
+            };
+            (key, id)
+        };
+
+        Ok(page_sorted(
+            items,
+            cursor.as_ref(),
+            self.limit(),
+            self.order == SortOrder::Desc,
+            sort_key,
+        ))
+    }
+}
+
+/// `GET /api/admin/stats` 응답.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SystemStats {
+    pub users: usize,
+    pub admins: usize,
+    pub disabled_users: usize,
+    pub orgs: usize,
+    pub public_sessions: usize,
+    pub user_sessions: usize,
+    pub org_sessions: usize,
+    pub private_sessions: usize,
+    /// 공개 디렉터리에 등록된 세션 수.
+    pub listed_sessions: usize,
+    /// 모든 세션 스트림의 현재 뷰어 수 합계.
+    pub viewers: u64,
+    /// 이유별로 누적된 로그인 거절 횟수.
+    #[serde(default)]
+    pub login_rejections: LoginRejectionStats,
+}
+
+impl SystemStats {
+    pub fn collect(
+        accounts: &[AccountEntry],
+        sessions: &[StoredSessionEntry],
+        orgs: usize,
+    ) -> Self {
+        let mut stats = SystemStats {
+            users: accounts.len(),
+            admins: accounts
+                .iter()
+                .filter(|entry| entry.user_data.admin)
+                .count(),
+            disabled_users: accounts
+                .iter()
+                .filter(|entry| entry.user_data.is_disabled())
+                .count(),
+            orgs,
+            ..Default::default()
+        };
+        for entry in sessions {
+            match session_kind(&entry.session_ref) {
+                SessionKind::Public => stats.public_sessions += 1,
+                SessionKind::User => stats.user_sessions += 1,
+                SessionKind::Org => stats.org_sessions += 1,
+            }
+            if entry.session.visibility == Visibility::Private {
+                stats.private_sessions += 1;
+            }
+            if entry.session.listed {
+                stats.listed_sessions += 1;
+            }
+            stats.viewers += entry.viewers;
+        }
+        stats
+    }
+}
+
+enum SessionKind {
+    Public,
+    User,
+    Org,
+}
+
+fn session_kind(session_ref: &SessionRef) -> SessionKind {
+    match (&session_ref.user_id, &session_ref.org_id) {
+        (Some(_), _) => SessionKind::User,
+        (None, Some(_)) => SessionKind::Org,
+        (None, None) => SessionKind::Public,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::{session_data::SessionData, user_data::UserData};
+
+    fn account(user_id: &str, admin: bool, disabled: bool) -> AccountEntry {
+        AccountEntry {
+            user_id: user_id.to_string(),
+            user_data: UserData {
+                password_argon2: String::new(),
+                created_at: None,
+                last_login_at: None,
+                token_generation: 0,
+                admin,
+                disabled_at: disabled.then(Utc::now),
+                oidc_subject: None,
+            },
+            sessions: 0,
+        }
+    }
+
+    fn session(session_ref: SessionRef, viewers: u64) -> StoredSessionEntry {
+        StoredSessionEntry {
+            session_ref,
+            session: SessionData::new("<svg></svg>", HashMap::new()),
+            ttl_seconds: Some(60),
+            viewers,
+        }
+    }
+
+    #[test]
+    fn test_user_query_filters() {
+        let items: Vec<AdminUserInfo> = vec![
+            account("carol", false, true),
+            account("alice", true, false),
+            account("bob", false, false),
+        ]
+        .into_iter()
+        .map(AdminUserInfo::from)
+        .collect();
+        let ids = |items: &[AdminUserInfo]| {
+            items
+                .iter()
+                .map(|item| item.user_id.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let (page, next) = AdminUserQuery::default().apply(items.clone()).unwrap();
+        assert_eq!(ids(&page), vec!["alice", "bob", "carol"]);
+        assert_eq!(next, None);
+
+        let query = AdminUserQuery {
+            disabled: Some(false),
+            limit: Some(1),
+            ..Default::default()
+        };
+        let (page, next) = query.apply(items.clone()).unwrap();
+        assert_eq!(ids(&page), vec!["alice"]);
+        assert_eq!(next.as_deref(), Some("0:alice"));
+
+        let query = AdminUserQuery {
+            q: Some("CAR".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(ids(&query.apply(items).unwrap().0), vec!["carol"]);
+    }
+
+    #[test]
+    fn test_session_query_matches_owner() {
+        let items: Vec<AdminSessionInfo> = vec![
+            session(SessionRef::public("badge"), 0),
+            session(SessionRef::user("alice", "chart"), 3),
+            session(SessionRef::org("team", "board"), 1),
+        ]
+        .into_iter()
+        .map(AdminSessionInfo::from)
+        .collect();
+
+        let query = AdminSessionQuery {
+            q: Some("alice".to_string()),
+            ..Default::default()
+        };
+        let (page, _) = query.apply(items.clone()).unwrap();
+        assert_eq!(page.len(), 1);
+        assert_eq!(page[0].session_id, "chart");
+        assert_eq!(page[0].stream_url, "/stream/alice/chart");
+
+        let query = AdminSessionQuery {
+            sort: SessionSort::Viewers,
+            ..Default::default()
+        };
+        let (page, _) = query.apply(items.clone()).unwrap();
+        let ids: Vec<_> = page.iter().map(|item| item.session_id.as_str()).collect();
+        assert_eq!(ids, vec!["chart", "board", "badge"]);
+
+        let query = AdminSessionQuery {
+            org_id: Some("team".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(query.apply(items).unwrap().0[0].session_id, "board");
+    }
+
+    #[test]
+    fn test_stats_collect() {
+        let accounts = vec![
+            account("alice", true, false),
+            account("bob", false, true),
+            account("carol", false, false),
+        ];
+        let mut private = session(SessionRef::user("alice", "secret"), 2);
+        private.session.visibility = Visibility::Private;
+        let mut listed = session(SessionRef::public("badge"), 5);
+        listed.session.listed = true;
+        let sessions = vec![
+            private,
+            listed,
+            session(SessionRef::org("team", "board"), 0),
+        ];
+
+        let stats = SystemStats::collect(&accounts, &sessions, 1);
+        assert_eq!(
+            stats,
+            SystemStats {
+                users: 3,
+                admins: 1,
+                disabled_users: 1,
+                orgs: 1,
+                public_sessions: 1,
+                user_sessions: 1,
+                org_sessions: 1,
+                private_sessions: 1,
+                listed_sessions: 1,
+                viewers: 7,
+                login_rejections: Default::default(),
+            }
+        );
+    }
+}
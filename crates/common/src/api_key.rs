@@ -0,0 +1,198 @@
+//! 자동화 클라이언트(CI, IoT 장치)용 장기 API 키.
+//!
+//! 키 원문(`rsvg_{key_id}_{secret}`)은 만들 때 한 번만 보여주고, Redis에는
+//! refresh 토큰과 같이 SHA-256 해시로만 저장합니다. `key_id`는 목록 조회와
+//! 폐기에 쓰는 공개 식별자이며 비밀이 아닙니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::ApiError;
+use crate::refresh_token::random_hex;
+
+/// 키 원문의 접두사. 로그나 저장소에 유출된 키를 찾기 쉽게 합니다.
+pub const PREFIX: &str = "rsvg_";
+
+/// 키 이름의 최대 길이 (문자 수).
+pub const MAX_NAME_LEN: usize = 64;
+
+/// 만료 기간의 최대값 (10년). 만료 없는 키는 `expires_in_seconds`를 생략합니다.
+pub const MAX_EXPIRES_IN_SECONDS: u64 = 10 * 366 * 24 * 60 * 60;
+
+/// 마지막 사용 시각은 이 간격(초)보다 자주 기록하지 않습니다.
+pub const LAST_USED_RESOLUTION_SECONDS: i64 = 60;
+
+/// 키 해시별로 저장되는 정보. 만료가 있으면 같은 시각에 Redis 키도 만료됩니다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ApiKeyRecord {
+    pub key_id: String,
+    pub user_id: String,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    pub expires_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl ApiKeyRecord {
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+
+    /// 마지막 사용 시각을 새로 기록할 때가 되었는지 확인합니다.
+    pub fn last_used_is_stale(&self, now: DateTime<Utc>) -> bool {
+        self.last_used_at.is_none_or(|last_used_at| {
+            (now - last_used_at).num_seconds() >= LAST_USED_RESOLUTION_SECONDS
+        })
+    }
+}
+
+/// 목록 조회 응답의 한 항목. 키 원문이나 해시는 포함하지 않습니다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ApiKeyInfo {
+    pub key_id: String,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl From<ApiKeyRecord> for ApiKeyInfo {
+    fn from(record: ApiKeyRecord) -> Self {
+        Self {
+            key_id: record.key_id,
+            name: record.name,
+            created_at: record.created_at,
+            expires_at: record.expires_at,
+            last_used_at: record.last_used_at,
+        }
+    }
+}
+
+/// 새 키 원문과 그 `key_id`를 만듭니다 (비밀 부분은 256비트 난수).
+pub fn generate() -> (String, String) {
+    let key_id = random_hex(8);
+    let key = format!("{}{}_{}", PREFIX, key_id, random_hex(32));
+    (key, key_id)
+}
+
+/// 키 원문처럼 생긴 문자열인지 확인합니다. 저장소 조회 전에 형식이 다른 값을 거릅니다.
+pub fn is_well_formed(key: &str) -> bool {
+    key.strip_prefix(PREFIX)
+        .and_then(|rest| rest.split_once('_'))
+        .is_some_and(|(key_id, secret)| {
+            key_id.len() == 16
+                && secret.len() == 64
+                && key_id
+                    .bytes()
+                    .chain(secret.bytes())
+                    .all(|b| b.is_ascii_hexdigit())
+        })
+}
+
+/// Redis 키에 쓰이는 키 해시.
+pub fn hash(key: &str) -> String {
+    crate::refresh_token::hash(key)
+}
+
+/// 만들 때 요청한 만료 기간으로 만료 시각을 계산합니다. `None`이면 만료되지 않습니다.
+pub fn expires_at(
+    created_at: DateTime<Utc>,
+    expires_in_seconds: Option<u64>,
+) -> Result<Option<DateTime<Utc>>, ApiError> {
+    match expires_in_seconds {
+        None => Ok(None),
+        Some(seconds @ 1..=MAX_EXPIRES_IN_SECONDS) => {
+            Ok(Some(created_at + chrono::Duration::seconds(seconds as i64)))
+        }
+        Some(_) => Err(ApiError::InvalidApiKey(format!(
+            "API 키 만료 기간은 1~{}초여야 합니다",
+            MAX_EXPIRES_IN_SECONDS
+        ))),
+    }
+}
+
+/// 이름 앞뒤 공백을 제거하고 1~64자인지 확인합니다.
+pub fn normalize_name(name: &str) -> Result<String, ApiError> {
+    let name = name.trim();
+    let len = name.chars().count();
+    if len == 0 || len > MAX_NAME_LEN {
+        return Err(ApiError::InvalidApiKey(format!(
+            "API 키 이름은 1~{}자여야 합니다",
+            MAX_NAME_LEN
+        )));
+    }
+    Ok(name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated_keys_are_well_formed_and_unique() {
+        let (key, key_id) = generate();
+        assert!(key.starts_with(&format!("{}{}_", PREFIX, key_id)));
+        assert!(is_well_formed(&key));
+        assert_ne!(generate().0, key);
+        assert_ne!(hash(&key), key);
+    }
+
+    #[test]
+    fn test_malformed_keys() {
+        let (key, _) = generate();
+        for bad in [
+            "",
+            "rsvg_",
+            &key[PREFIX.len()..],
+            &key[..key.len() - 1],
+            &key.replace('_', "-"),
+            &format!("{}x", key),
+        ] {
+            assert!(!is_well_formed(bad), "{bad}");
+        }
+    }
+
+    #[test]
+    fn test_name_rules() {
+        assert_eq!(normalize_name("  CI deploy ").unwrap(), "CI deploy");
+        assert!(normalize_name("   ").is_err());
+        assert!(normalize_name(&"키".repeat(MAX_NAME_LEN)).is_ok());
+        assert!(normalize_name(&"x".repeat(MAX_NAME_LEN + 1)).is_err());
+    }
+
+    #[test]
+    fn test_expiry_range() {
+        let now = Utc::now();
+        assert_eq!(expires_at(now, None).unwrap(), None);
+        assert_eq!(
+            expires_at(now, Some(60)).unwrap(),
+            Some(now + chrono::Duration::seconds(60))
+        );
+        assert!(expires_at(now, Some(0)).is_err());
+        assert!(expires_at(now, Some(MAX_EXPIRES_IN_SECONDS + 1)).is_err());
+        assert!(expires_at(now, Some(u64::MAX)).is_err());
+    }
+
+    #[test]
+    fn test_last_used_resolution() {
+        let now = Utc::now();
+        let mut record = ApiKeyRecord {
+            key_id: "0123456789abcdef".to_string(),
+            user_id: "alice".to_string(),
+            name: "ci".to_string(),
+            created_at: now,
+            expires_at: Some(now + chrono::Duration::seconds(10)),
+            last_used_at: None,
+        };
+        assert!(record.last_used_is_stale(now));
+        record.last_used_at = Some(now);
+        assert!(!record.last_used_is_stale(now + chrono::Duration::seconds(1)));
+        assert!(record
+            .last_used_is_stale(now + chrono::Duration::seconds(LAST_USED_RESOLUTION_SECONDS)));
+
+        assert!(!record.is_expired(now));
+        assert!(record.is_expired(now + chrono::Duration::seconds(10)));
+    }
+}
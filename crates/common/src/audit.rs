@@ -0,0 +1,554 @@
+//! 보안과 관련된 작업과 세션 변경을 남기는 감사 로그.
+//!
+//! 기록은 덧붙이기만 하며 고치거나 지우는 API는 없습니다. 기본 저장소는 Redis stream
+//! ([`RedisAuditSink`])이고, 관리자 API(`GET /api/admin/audit`)로 조회하거나 JSON Lines로
+//! 내보낼 수 있습니다. [`AuditSink`]를 구현하면 다른 곳으로 보낼 수도 있습니다.
+
+use std::fmt;
+use std::future::Future;
+use std::net::IpAddr;
+use std::pin::Pin;
+use std::str::FromStr;
+
+use axum::{
+    extract::{FromRef, FromRequestParts},
+    http::{header, request::Parts},
+};
+use chrono::{DateTime, Utc};
+use redis::{streams::StreamMaxlen, AsyncCommands, Client};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    client_ip::ClientIp,
+    directory::SessionRef,
+    errors::ApiError,
+    keys::Keys,
+    session_data::SessionMetadataPatch,
+    session_list::{DEFAULT_LIMIT, MAX_LIMIT},
+    state::AppState,
+    ListResponse,
+};
+
+/// 요청 ID를 주고받는 헤더. 없으면 서버가 만들어 응답에도 붙입니다.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Redis stream에 남기는 기록 수의 기본 상한. 넘으면 오래된 기록부터 지웁니다.
+pub const DEFAULT_MAX_LEN: u64 = 100_000;
+
+/// 조회할 때 Redis stream에서 한 번에 읽는 기록 수.
+const SCAN_BATCH: usize = 500;
+
+const MAX_USER_AGENT_LEN: usize = 512;
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+/// 감사 로그에 남는 작업의 종류.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+    /// 비밀번호 로그인, refresh, SSO 로그인, 세션 전용 토큰으로 토큰을 발급함
+    TokenIssued,
+    /// 로그인 실패. 시도 제한과 잠금으로 거절된 경우도 포함합니다.
+    LoginFailed,
+    /// 비밀번호 가입
+    AccountRegistered,
+    /// 가입 실패. 시도 제한으로 거절된 경우도 포함합니다.
+    RegistrationFailed,
+    PasswordChanged,
+    AccountDeleted,
+    SessionCreated,
+    SessionUpdated,
+    SessionDeleted,
+    /// 협업자, 조직 멤버, 공유 링크, API 키, 세션 소유권 변경
+    PermissionChanged,
+    /// 관리자 API로 한 작업
+    AdminAction,
+}
+
+/// 감사 로그의 한 기록.
+///
+/// `target`은 작업 대상을 `user:{user_id}`, `org:{org_id}`, `user:{user_id}/session:{session_id}`,
+/// `org:{org_id}/session:{session_id}`, `session:{session_id}`(공용 세션) 형식으로 나타냅니다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AuditEvent {
+    /// 저장소가 붙인 ID (Redis stream 항목 ID). 기록하기 전이면 없음.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub timestamp: DateTime<Utc>,
+    pub action: AuditAction,
+    /// 작업한 사용자. 로그인 실패나 공용 세션처럼 인증되지 않은 요청이면 없음.
+    pub actor: Option<String>,
+    pub target: Option<String>,
+    pub ip: Option<IpAddr>,
+    pub user_agent: Option<String>,
+    pub request_id: Option<String>,
+    /// 작업별 추가 정보 (바뀐 역할, 실패 이유 등)
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    pub details: Value,
+}
+
+impl AuditEvent {
+    pub fn new(action: AuditAction) -> Self {
+        Self {
+            id: None,
+            timestamp: Utc::now(),
+            action,
+            actor: None,
+            target: None,
+            ip: None,
+            user_agent: None,
+            request_id: None,
+            details: Value::Null,
+        }
+    }
+
+    pub fn with_actor(mut self, actor: impl Into<String>) -> Self {
+        self.actor = Some(actor.into());
+        self
+    }
+
+    pub fn on_user(mut self, user_id: &str) -> Self {
+        self.target = Some(format!("user:{}", user_id));
+        self
+    }
+
+    pub fn on_org(mut self, org_id: &str) -> Self {
+        self.target = Some(format!("org:{}", org_id));
+        self
+    }
+
+    pub fn on_session(mut self, session_ref: &SessionRef) -> Self {
+        self.target = Some(match (&session_ref.user_id, &session_ref.org_id) {
+            (Some(user_id), _) => format!("user:{}/session:{}", user_id, session_ref.session_id),
+            (None, Some(org_id)) => format!("org:{}/session:{}", org_id, session_ref.session_id),
+            (None, None) => format!("session:{}", session_ref.session_id),
+        });
+        self
+    }
+
+    pub fn with_details(mut self, details: Value) -> Self {
+        self.details = details;
+        self
+    }
+
+    /// 세션 업데이트 요청에 있던 필드 이름을 `details.fields`로 남깁니다. 값은 남기지 않습니다.
+    pub fn with_session_changes(self, args: bool, metadata: &SessionMetadataPatch) -> Self {
+        let fields: Vec<&str> = [
+            ("args", args),
+            ("title", metadata.title.is_some()),
+            ("description", metadata.description.is_some()),
+            ("tags", metadata.tags.is_some()),
+            ("listed", metadata.listed.is_some()),
+            ("visibility", metadata.visibility.is_some()),
+        ]
+        .into_iter()
+        .filter_map(|(field, present)| present.then_some(field))
+        .collect();
+        self.with_details(serde_json::json!({ "fields": fields }))
+    }
+}
+
+/// 감사 기록에 남길 요청 정보 (클라이언트 IP, User-Agent, 요청 ID)를 꺼내는 extractor.
+///
+/// ```ignore
+/// pub async fn handler(
+///     State(state): State<AppState>,
+///     audit: AuditContext,
+/// ) -> Result<(), ApiError> {
+///     state
+///         .audit(audit.event(AuditAction::SessionDeleted).with_actor(user_id))
+///         .await;
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditContext {
+    pub ip: Option<IpAddr>,
+    pub user_agent: Option<String>,
+    pub request_id: Option<String>,
+}
+
+impl AuditContext {
+    /// 이 요청의 정보가 채워진 기록을 만듭니다.
+    pub fn event(&self, action: AuditAction) -> AuditEvent {
+        AuditEvent {
+            ip: self.ip,
+            user_agent: self.user_agent.clone(),
+            request_id: self.request_id.clone(),
+            ..AuditEvent::new(action)
+        }
+    }
+}
+
+impl<S> FromRequestParts<S> for AuditContext
+where
+    S: Send + Sync,
+    AppState: FromRef<S>,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let ClientIp(ip) = ClientIp::from_request_parts(parts, state).await?;
+        let header_value = |name, max_len| {
+            parts
+                .headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(|value| value.chars().take(max_len).collect::<String>())
+                .filter(|value| !value.is_empty())
+        };
+        Ok(AuditContext {
+            ip,
+            user_agent: header_value(header::USER_AGENT.as_str(), MAX_USER_AGENT_LEN),
+            request_id: header_value(REQUEST_ID_HEADER, MAX_REQUEST_ID_LEN),
+        })
+    }
+}
+
+/// `GET /api/admin/audit` 쿼리 파라미터.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AuditQuery {
+    /// 이전 페이지의 `next_cursor`. 그보다 오래된 기록부터 반환합니다.
+    pub cursor: Option<String>,
+    pub limit: Option<usize>,
+    pub action: Option<AuditAction>,
+    pub actor: Option<String>,
+    /// 이 대상과 그 하위 대상만 조회 (`user:alice`이면 계정과 그 세션 모두)
+    pub target: Option<String>,
+    pub ip: Option<IpAddr>,
+    pub request_id: Option<String>,
+    /// 이 시각 이후의 기록만 조회
+    pub since: Option<DateTime<Utc>>,
+    /// 이 시각 이전의 기록만 조회
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl AuditQuery {
+    pub fn limit(&self) -> usize {
+        self.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT)
+    }
+
+    pub fn matches(&self, event: &AuditEvent) -> bool {
+        self.action.is_none_or(|action| event.action == action)
+            && self
+                .actor
+                .as_deref()
+                .is_none_or(|actor| event.actor.as_deref() == Some(actor))
+            && self.target.as_deref().is_none_or(|target| {
+                event.target.as_deref().is_some_and(|value| {
+                    value
+                        .strip_prefix(target)
+                        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+                })
+            })
+            && self.ip.is_none_or(|ip| event.ip == Some(ip))
+            && self
+                .request_id
+                .as_deref()
+                .is_none_or(|request_id| event.request_id.as_deref() == Some(request_id))
+            && self.since.is_none_or(|since| event.timestamp >= since)
+            && self.until.is_none_or(|until| event.timestamp <= until)
+    }
+}
+
+pub type AuditFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T, ApiError>> + Send + 'a>>;
+
+/// 감사 기록을 보내는 곳.
+///
+/// 기본은 [`RedisAuditSink`]이고, [`AppState::with_audit_sink`]로 바꿀 수 있습니다.
+pub trait AuditSink: Send + Sync + fmt::Debug {
+    fn append<'a>(&'a self, event: &'a AuditEvent) -> AuditFuture<'a, ()>;
+
+    /// 최근 기록부터 `query`에 맞는 한 페이지를 반환합니다.
+    ///
+    /// 조회를 지원하지 않는 저장소는 [`ApiError::AuditLogUnavailable`]을 반환합니다.
+    fn query<'a>(&'a self, query: &'a AuditQuery) -> AuditFuture<'a, ListResponse<AuditEvent>> {
+        let _ = query;
+        Box::pin(async { Err(ApiError::AuditLogUnavailable) })
+    }
+}
+
+/// Redis stream에 기록을 덧붙이는 기본 저장소.
+#[derive(Debug, Clone)]
+pub struct RedisAuditSink {
+    redis: Client,
+    key: String,
+    max_len: u64,
+}
+
+impl RedisAuditSink {
+    pub fn new(redis: Client, keys: &Keys) -> Self {
+        Self {
+            redis,
+            key: keys.audit_log(),
+            max_len: DEFAULT_MAX_LEN,
+        }
+    }
+
+    /// 남길 기록 수의 상한. 0이면 지우지 않습니다.
+    pub fn with_max_len(mut self, max_len: u64) -> Self {
+        self.max_len = max_len;
+        self
+    }
+
+    async fn append_event(&self, event: &AuditEvent) -> Result<(), ApiError> {
+        let json = serde_json::to_string(event)?;
+        let mut conn = self.redis.get_multiplexed_async_connection().await?;
+        let fields = [("event", json)];
+        let _: String = if self.max_len > 0 {
+            let max_len = StreamMaxlen::Approx(self.max_len as usize);
+            conn.xadd_maxlen(&self.key, max_len, "*", &fields).await?
+        } else {
+            conn.xadd(&self.key, "*", &fields).await?
+        };
+        Ok(())
+    }
+
+    async fn query_events(&self, query: &AuditQuery) -> Result<ListResponse<AuditEvent>, ApiError> {
+        let limit = query.limit();
+        let mut skip = match query.cursor.as_deref().filter(|cursor| !cursor.is_empty()) {
+            Some(cursor) if is_stream_id(cursor) => Some(cursor.to_string()),
+            Some(cursor) => return Err(ApiError::InvalidCursor(cursor.to_string())),
+            None => None,
+        };
+        // 커서가 가리키는 항목은 이전 페이지에 있었으므로 포함하되 건너뜁니다.
+        let mut end = match (&skip, query.until) {
+            (Some(cursor), _) => cursor.clone(),
+            (None, Some(until)) => until.timestamp_millis().max(0).to_string(),
+            (None, None) => "+".to_string(),
+        };
+        let start = query
+            .since
+            .map(|since| since.timestamp_millis().max(0).to_string())
+            .unwrap_or_else(|| "-".to_string());
+
+        let mut conn = self.redis.get_multiplexed_async_connection().await?;
+        let mut items = Vec::new();
+        loop {
+            let reply: redis::streams::StreamRangeReply = conn
+                .xrevrange_count(&self.key, &end, &start, SCAN_BATCH)
+                .await?;
+            let fetched = reply.ids.len();
+            for entry in reply.ids {
+                if skip.as_deref() == Some(entry.id.as_str()) {
+                    continue;
+                }
+                end = entry.id.clone();
+                skip = Some(entry.id.clone());
+                let Some(json) = entry.get::<String>("event") else {
+                    continue;
+                };
+                let mut event: AuditEvent = match serde_json::from_str(&json) {
+                    Ok(event) => event,
+                    Err(e) => {
+                        tracing::warn!("Skipping malformed audit event {}: {}", entry.id, e);
+                        continue;
+                    }
+                };
+                event.id = Some(entry.id);
+                if !query.matches(&event) {
+                    continue;
+                }
+                if items.len() == limit {
+                    let next_cursor = items.last().and_then(|last: &AuditEvent| last.id.clone());
+                    return Ok(ListResponse { items, next_cursor });
+                }
+                items.push(event);
+            }
+            if fetched < SCAN_BATCH {
+                return Ok(ListResponse {
+                    items,
+                    next_cursor: None,
+                });
+            }
+        }
+    }
+}
+
+impl AuditSink for RedisAuditSink {
+    fn append<'a>(&'a self, event: &'a AuditEvent) -> AuditFuture<'a, ()> {
+        Box::pin(self.append_event(event))
+    }
+
+    fn query<'a>(&'a self, query: &'a AuditQuery) -> AuditFuture<'a, ListResponse<AuditEvent>> {
+        Box::pin(self.query_events(query))
+    }
+}
+
+/// 기록을 `audit` target의 로그 한 줄(JSON)로 내보내는 저장소.
+///
+/// 로그 수집기가 따로 있을 때 씁니다. 서버에서는 조회할 수 없습니다.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LogAuditSink;
+
+impl AuditSink for LogAuditSink {
+    fn append<'a>(&'a self, event: &'a AuditEvent) -> AuditFuture<'a, ()> {
+        Box::pin(async move {
+            tracing::info!(target: "audit", "{}", serde_json::to_string(event)?);
+            Ok(())
+        })
+    }
+}
+
+/// 설정으로 고를 수 있는 감사 로그 저장소.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditSinkKind {
+    /// [`RedisAuditSink`]
+    #[default]
+    Redis,
+    /// [`LogAuditSink`]
+    Log,
+}
+
+impl AuditSinkKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditSinkKind::Redis => "redis",
+            AuditSinkKind::Log => "log",
+        }
+    }
+}
+
+impl fmt::Display for AuditSinkKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for AuditSinkKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [AuditSinkKind::Redis, AuditSinkKind::Log]
+            .into_iter()
+            .find(|kind| kind.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("지원하지 않는 감사 로그 저장소입니다 (redis, log): {}", s))
+    }
+}
+
+/// Redis stream 항목 ID (`<ms>-<seq>`)인지 확인합니다.
+fn is_stream_id(value: &str) -> bool {
+    value.split_once('-').is_some_and(|(ms, seq)| {
+        [ms, seq]
+            .iter()
+            .all(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_targets() {
+        let event = AuditEvent::new(AuditAction::SessionUpdated);
+        assert_eq!(
+            event
+                .clone()
+                .on_session(&SessionRef::user("alice", "chart"))
+                .target
+                .as_deref(),
+            Some("user:alice/session:chart")
+        );
+        assert_eq!(
+            event
+                .clone()
+                .on_session(&SessionRef::org("team", "board"))
+                .target
+                .as_deref(),
+            Some("org:team/session:board")
+        );
+        assert_eq!(
+            event
+                .on_session(&SessionRef::public("badge"))
+                .target
+                .as_deref(),
+            Some("session:badge")
+        );
+    }
+
+    #[test]
+    fn test_query_matches() {
+        let context = AuditContext {
+            ip: Some("203.0.113.7".parse().unwrap()),
+            user_agent: Some("curl/8".to_string()),
+            request_id: Some("req-1".to_string()),
+        };
+        let event = context
+            .event(AuditAction::SessionDeleted)
+            .with_actor("alice")
+            .on_session(&SessionRef::user("alice", "chart"));
+
+        assert!(AuditQuery::default().matches(&event));
+        let query = AuditQuery {
+            action: Some(AuditAction::SessionDeleted),
+            actor: Some("alice".to_string()),
+            target: Some("user:alice".to_string()),
+            ip: context.ip,
+            request_id: Some("req-1".to_string()),
+            since: Some(event.timestamp - chrono::Duration::seconds(1)),
+            until: Some(event.timestamp),
+            ..Default::default()
+        };
+        assert!(query.matches(&event));
+
+        for query in [
+            AuditQuery {
+                action: Some(AuditAction::SessionCreated),
+                ..Default::default()
+            },
+            AuditQuery {
+                actor: Some("bob".to_string()),
+                ..Default::default()
+            },
+            AuditQuery {
+                target: Some("user:ali".to_string()),
+                ..Default::default()
+            },
+            AuditQuery {
+                since: Some(event.timestamp + chrono::Duration::seconds(1)),
+                ..Default::default()
+            },
+        ] {
+            assert!(!query.matches(&event), "{query:?}");
+        }
+    }
+
+    #[test]
+    fn test_event_json_round_trip() {
+        let event = AuditEvent::new(AuditAction::PermissionChanged)
+            .with_actor("alice")
+            .on_user("bob")
+            .with_details(serde_json::json!({"change": "collaborator_set", "role": "editor"}));
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["action"], "permission_changed");
+        assert!(json.get("id").is_none());
+        assert_eq!(serde_json::from_value::<AuditEvent>(json).unwrap(), event);
+
+        let event = AuditEvent::new(AuditAction::SessionUpdated).with_session_changes(
+            true,
+            &SessionMetadataPatch {
+                title: Some(String::new()),
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            event.details,
+            serde_json::json!({"fields": ["args", "title"]})
+        );
+
+        let json = serde_json::to_value(AuditEvent::new(AuditAction::LoginFailed)).unwrap();
+        assert!(json.get("details").is_none());
+        assert_eq!(json["actor"], Value::Null);
+    }
+
+    #[test]
+    fn test_stream_id() {
+        assert!(is_stream_id("1760000000000-0"));
+        for value in ["", "+", "123", "12-", "-3", "a-1", "1-2-3"] {
+            assert!(!is_stream_id(value), "{value}");
+        }
+        assert_eq!("LOG".parse::<AuditSinkKind>(), Ok(AuditSinkKind::Log));
+        assert!("file".parse::<AuditSinkKind>().is_err());
+    }
+}
@@ -1,14 +1,29 @@
 use axum::{
     extract::{FromRef, FromRequestParts},
-    http::request::Parts,
+    http::{request::Parts, HeaderMap},
 };
 
-use crate::{errors::ApiError, jwt, state::AppState};
+use crate::{
+    auth_cookie,
+    errors::ApiError,
+    jwt,
+    org::{OrgMemberships, OrgRole},
+    scope::{Scope, SessionAccess},
+    session_data::{SessionData, SessionRole},
+    state::AppState,
+    user_data::UserData,
+};
 
-/// Axum extractor for JWT-authenticated users
+/// Axum extractor for users authenticated by JWT or API key
+///
+/// Extracts and validates JWT from `Authorization: Bearer <token>` header, or an
+/// API key from `X-Api-Key: <key>` / `Authorization: ApiKey <key>`.
+/// Returns the authenticated user_id from the token's `sub` claim or the key's owner.
 ///
-/// Extracts and validates JWT from `Authorization: Bearer <token>` header.
-/// Returns the authenticated user_id from the token's `sub` claim.
+/// Without an `Authorization` header, the access token is taken from the HttpOnly login
+/// cookie instead (see [`crate::auth_cookie`]). Cookie-authenticated requests other than
+/// `GET`, `HEAD` and `OPTIONS` must echo the CSRF cookie in the `X-CSRF-Token` header.
+/// This applies to every extractor in this module.
 ///
 /// # Usage in Axum Handlers
 /// ```ignore
@@ -23,9 +38,21 @@ use crate::{errors::ApiError, jwt, state::AppState};
 /// # Authentication Flow
 /// 1. Extract `Authorization` header from request
 /// 2. Parse `Bearer <token>` format
-/// 3. Verify JWT signature using RSA public key
+/// 3. Verify JWT signature using the published RSA public key named by `kid`
 /// 4. Validate expiration and issuer claims
-/// 5. Return user_id from `sub` claim
+/// 5. Check the token's `jti` has not been revoked by logout
+/// 6. Check the account still exists and the `gen` claim matches its token generation
+/// 7. Check the account has not been disabled by an administrator
+/// 8. Return user_id from `sub` claim
+///
+/// API keys are looked up by hash instead (see [`AppState::authenticate_api_key`]);
+/// they must not be expired or revoked and the owning account must still exist and be enabled.
+///
+/// Bearer tokens whose `iss` is a configured trusted issuer (see [`crate::trusted_issuer`])
+/// are verified with that issuer's JWKS, audience and expiry instead of steps 3-6. The
+/// configured claim names the user, whose account must exist and be enabled. Like API keys,
+/// they are not restricted by scopes and are not accepted by [`AuthenticatedClaims`] or
+/// [`AdminUser`].
 ///
 /// # Error Cases
 /// Returns `ApiError::Unauthorized` (401) if:
@@ -34,6 +61,14 @@ use crate::{errors::ApiError, jwt, state::AppState};
 /// - Token signature is invalid
 /// - Token has expired
 /// - Token issuer doesn't match
+/// - Account was deleted or the token was revoked
+/// - API key is unknown, expired or revoked
+/// - External token fails verification or names an account that does not exist
+///
+/// Returns `ApiError::Forbidden` (403) for tokens restricted by `scopes`; handlers that
+/// accept those use [`ScopedUser`] instead. Disabled accounts get `ApiError::AccountDisabled` (403).
+/// A cookie-authenticated request with a missing or wrong CSRF token gets
+/// `ApiError::InvalidCsrfToken` (403).
 #[derive(Debug, Clone)]
 pub struct AuthenticatedUser(pub String);
 
@@ -45,33 +80,418 @@ where
     type Rejection = ApiError;
 
     async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
-        let auth_header = parts.headers.get("Authorization").ok_or_else(|| {
-            tracing::warn!("Unauthorized: Missing Authorization header");
-            ApiError::Unauthorized("Missing Authorization header".to_string())
-        })?;
+        let app_state = AppState::from_ref(state);
+        if let Some(key) = api_key_from_headers(&parts.headers)? {
+            let record = app_state.authenticate_api_key(key).await?;
+            return Ok(AuthenticatedUser(record.user_id));
+        }
+        match authenticate_bearer(parts, &app_state).await? {
+            BearerUser::Local(claims, _) => {
+                require_unscoped(&claims)?;
+                Ok(AuthenticatedUser(claims.sub))
+            }
+            BearerUser::External(user_id) => Ok(AuthenticatedUser(user_id)),
+        }
+    }
+}
 
-        let token = auth_header
-            .to_str()
-            .map_err(|_| {
-                tracing::warn!("Unauthorized: Invalid header encoding");
-                ApiError::Unauthorized("Invalid header encoding".to_string())
-            })?
-            .strip_prefix("Bearer ")
-            .ok_or_else(|| {
+/// Same checks as [`AuthenticatedUser`], but yields the full token claims
+///
+/// Used by handlers that act on the presented token itself (e.g. logout needs `jti` and `exp`).
+/// API keys, scoped tokens and tokens from trusted external issuers are not accepted, so it
+/// also guards actions that need an interactive login (e.g. managing API keys).
+#[derive(Debug, Clone)]
+pub struct AuthenticatedClaims(pub jwt::Claims);
+
+impl<S> FromRequestParts<S> for AuthenticatedClaims
+where
+    S: Send + Sync,
+    AppState: FromRef<S>,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let (claims, _) = authenticate(parts, &AppState::from_ref(state)).await?;
+        require_unscoped(&claims)?;
+        Ok(AuthenticatedClaims(claims))
+    }
+}
+
+/// Same checks as [`AuthenticatedClaims`], but only for administrators
+///
+/// The token must carry the `admin` claim and the account must still be an administrator,
+/// so removing the role takes effect immediately while granting it needs a new login.
+/// API keys and scoped tokens are not accepted.
+///
+/// # Usage in Axum Handlers
+/// ```ignore
+/// async fn my_handler(
+///     AdminUser(admin_id): AdminUser,
+/// ) -> Result<StatusCode, ApiError> {
+///     // admin_id is an authenticated administrator
+///     Ok(StatusCode::OK)
+/// }
+/// ```
+///
+/// # Error Cases
+/// Returns `ApiError::Forbidden` (403) if the token has no `admin` claim or the account is
+/// no longer an administrator, in addition to the [`AuthenticatedUser`] errors.
+#[derive(Debug, Clone)]
+pub struct AdminUser(pub String);
+
+impl<S> FromRequestParts<S> for AdminUser
+where
+    S: Send + Sync,
+    AppState: FromRef<S>,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let (claims, user_data) = authenticate(parts, &AppState::from_ref(state)).await?;
+        require_unscoped(&claims)?;
+        if !(claims.admin && user_data.admin) {
+            tracing::warn!("Forbidden: User {} is not an administrator", claims.sub);
+            return Err(ApiError::Forbidden(format!(
+                "User {} is not an administrator",
+                claims.sub
+            )));
+        }
+        Ok(AdminUser(claims.sub))
+    }
+}
+
+/// Same checks as [`AuthenticatedUser`], but also accepts tokens restricted by `scopes`
+///
+/// Used by the user session handlers, which must call [`ScopedUser::authorize_session`]
+/// before touching a session, and by the org session handlers, which must call
+/// [`ScopedUser::authorize_org`].
+///
+/// # Usage in Axum Handlers
+/// ```ignore
+/// async fn my_handler(
+///     user: ScopedUser,
+///     Path((user_id, session_id)): Path<(String, String)>,
+/// ) -> Result<StatusCode, ApiError> {
+///     user.authorize_session(&user_id, Some(&session_id), SessionAccess::Write)?;
+///     Ok(StatusCode::OK)
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ScopedUser {
+    pub user_id: String,
+    /// `None` for login tokens and API keys, which are not restricted
+    pub scopes: Option<Vec<Scope>>,
+    /// Org memberships from the token's `orgs` claim, or looked up for API keys
+    pub orgs: OrgMemberships,
+}
+
+impl ScopedUser {
+    /// Checks that the caller owns `user_id`'s sessions and may perform `access`
+    ///
+    /// `session_id` is `None` for requests over all of the user's sessions (e.g. listing).
+    ///
+    /// # Errors
+    /// Returns `ApiError::Forbidden` (403) if the caller is another user or the token's
+    /// scopes do not cover the session and access.
+    pub fn authorize_session(
+        &self,
+        user_id: &str,
+        session_id: Option<&str>,
+        access: SessionAccess,
+    ) -> Result<(), ApiError> {
+        if self.user_id != user_id {
+            return Err(self.forbid_other_user(user_id, access));
+        }
+        let Some(scopes) = &self.scopes else {
+            return Ok(());
+        };
+        if scopes
+            .iter()
+            .any(|scope| scope.allows(user_id, session_id, access))
+        {
+            return Ok(());
+        }
+        let target = session_id.unwrap_or("*");
+        tracing::warn!(
+            "Scoped token of user {} cannot {} session {}",
+            self.user_id,
+            access,
+            target
+        );
+        Err(ApiError::Forbidden(format!(
+            "Token scope does not allow {} on session {}/{}",
+            access, user_id, target
+        )))
+    }
+
+    /// Checks that the caller's token is not restricted by `scopes`
+    ///
+    /// Scoped tokens may only update the args of the sessions they name, so creating or
+    /// deleting a session and changing its metadata need a login token or API key.
+    ///
+    /// # Errors
+    /// Returns `ApiError::Forbidden` (403) for scoped tokens.
+    pub fn require_unscoped(&self) -> Result<(), ApiError> {
+        if self.scopes.is_none() {
+            return Ok(());
+        }
+        tracing::warn!(
+            "Forbidden: Scoped token of user {} used beyond session args",
+            self.user_id
+        );
+        Err(ApiError::Forbidden(
+            "Scoped tokens can only update the args of the sessions they name".to_string(),
+        ))
+    }
+
+    /// Checks that the caller may perform `access` on `user_id`'s session as owner or collaborator
+    ///
+    /// Owners go through [`ScopedUser::authorize_session`]. Other users need a role on the
+    /// session (see [`SessionData::role_of`]) that allows `access`, and an unrestricted login
+    /// token or API key since token scopes only cover the token owner's sessions.
+    /// Returns the caller's role, or `None` for the owner.
+    ///
+    /// # Errors
+    /// Returns `ApiError::Forbidden` (403) if the caller has no suitable role. A missing
+    /// session is also reported as 403 to non-owners so session IDs are not disclosed.
+    pub fn authorize_shared_session(
+        &self,
+        user_id: &str,
+        session_id: &str,
+        session: Option<&SessionData>,
+        access: SessionAccess,
+    ) -> Result<Option<SessionRole>, ApiError> {
+        if self.user_id == user_id {
+            self.authorize_session(user_id, Some(session_id), access)?;
+            return Ok(None);
+        }
+        let role = session.and_then(|session| session.role_of(&self.user_id));
+        match role {
+            Some(role) if self.scopes.is_none() && role.allows(access) => Ok(Some(role)),
+            _ => Err(self.forbid_other_user(user_id, access)),
+        }
+    }
+
+    /// Checks that the caller is a member of `org_id` whose role allows `access`
+    ///
+    /// Uses the memberships carried by the token, so a role change takes effect once the
+    /// caller gets a new access token (login or refresh). Scoped tokens carry no memberships.
+    /// Returns the caller's role in the org.
+    ///
+    /// # Errors
+    /// Returns `ApiError::Forbidden` (403) if the caller is not a member or the role does not
+    /// allow `access`. Unknown orgs are reported the same way so org IDs are not disclosed.
+    pub fn authorize_org(&self, org_id: &str, access: SessionAccess) -> Result<OrgRole, ApiError> {
+        match self.orgs.get(org_id) {
+            Some(role) if self.scopes.is_none() && role.allows(access) => Ok(*role),
+            _ => {
                 tracing::warn!(
-                    "Unauthorized: Invalid Authorization format (missing Bearer prefix)"
+                    "User {} attempted to {} sessions of org {}",
+                    self.user_id,
+                    access,
+                    org_id
                 );
-                ApiError::Unauthorized("Invalid Authorization format".to_string())
-            })?;
+                Err(ApiError::Forbidden(format!(
+                    "User {} cannot {} sessions of org {}",
+                    self.user_id, access, org_id
+                )))
+            }
+        }
+    }
+
+    fn forbid_other_user(&self, user_id: &str, access: SessionAccess) -> ApiError {
+        tracing::warn!(
+            "User {} attempted to {} sessions of user {}",
+            self.user_id,
+            access,
+            user_id
+        );
+        ApiError::Forbidden(format!(
+            "User {} cannot {} sessions of user {}",
+            self.user_id, access, user_id
+        ))
+    }
+}
 
+impl<S> FromRequestParts<S> for ScopedUser
+where
+    S: Send + Sync,
+    AppState: FromRef<S>,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
         let app_state = AppState::from_ref(state);
-        let decoding_key = app_state
-            .share()
-            .get_decoding_key(app_state.redis_client())
-            .await?;
+        if let Some(key) = api_key_from_headers(&parts.headers)? {
+            let record = app_state.authenticate_api_key(key).await?;
+            let orgs = app_state.org_memberships(&record.user_id).await?;
+            return Ok(ScopedUser {
+                user_id: record.user_id,
+                scopes: None,
+                orgs,
+            });
+        }
+        match authenticate_bearer(parts, &app_state).await? {
+            BearerUser::Local(claims, _) => Ok(ScopedUser {
+                user_id: claims.sub,
+                scopes: claims.scopes,
+                orgs: claims.orgs,
+            }),
+            BearerUser::External(user_id) => {
+                let orgs = app_state.org_memberships(&user_id).await?;
+                Ok(ScopedUser {
+                    user_id,
+                    scopes: None,
+                    orgs,
+                })
+            }
+        }
+    }
+}
+
+/// Rejects tokens restricted by `scopes` from endpoints that need full account access.
+fn require_unscoped(claims: &jwt::Claims) -> Result<(), ApiError> {
+    if claims.scopes.is_some() {
+        tracing::warn!(
+            "Forbidden: Scoped token of user {} used for account access",
+            claims.sub
+        );
+        return Err(ApiError::Forbidden(
+            "Scoped tokens can only be used for the sessions they name".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the API key from `X-Api-Key` or `Authorization: ApiKey <key>`, if present.
+fn api_key_from_headers(headers: &HeaderMap) -> Result<Option<&str>, ApiError> {
+    let invalid_encoding = |_| {
+        tracing::warn!("Unauthorized: Invalid header encoding");
+        ApiError::Unauthorized("Invalid header encoding".to_string())
+    };
+    if let Some(value) = headers.get("X-Api-Key") {
+        return value.to_str().map(Some).map_err(invalid_encoding);
+    }
+    match headers.get("Authorization") {
+        Some(value) => Ok(value
+            .to_str()
+            .map_err(invalid_encoding)?
+            .strip_prefix("ApiKey ")),
+        None => Ok(None),
+    }
+}
+
+/// User authenticated by a bearer token
+enum BearerUser {
+    /// Token signed by this server, with the account it belongs to
+    Local(jwt::Claims, Box<UserData>),
+    /// Token from a trusted external issuer, naming an existing and enabled account
+    External(String),
+}
+
+/// Authenticates a bearer token signed by this server or a trusted external issuer.
+async fn authenticate_bearer(parts: &Parts, app_state: &AppState) -> Result<BearerUser, ApiError> {
+    let token = bearer_token(parts)?;
+
+    if let Some(user_id) = app_state.share().verify_external_token(token).await? {
+        let user_data = app_state.get_user_data(&user_id).await?.ok_or_else(|| {
+            tracing::warn!(
+                "Unauthorized: External token for unknown account {}",
+                user_id
+            );
+            ApiError::Unauthorized("Account does not exist".to_string())
+        })?;
+        if user_data.is_disabled() {
+            tracing::warn!("Forbidden: Account {} is disabled", user_id);
+            return Err(ApiError::AccountDisabled(user_id));
+        }
+        return Ok(BearerUser::External(user_id));
+    }
+
+    let (claims, user_data) = verify_local_token(token, app_state).await?;
+    Ok(BearerUser::Local(claims, Box::new(user_data)))
+}
+
+/// Authenticates a bearer token signed by this server; external tokens are rejected.
+async fn authenticate(
+    parts: &Parts,
+    app_state: &AppState,
+) -> Result<(jwt::Claims, UserData), ApiError> {
+    match authenticate_bearer(parts, app_state).await? {
+        BearerUser::Local(claims, user_data) => Ok((claims, *user_data)),
+        BearerUser::External(user_id) => {
+            tracing::warn!(
+                "Forbidden: External token of user {} used for account access",
+                user_id
+            );
+            Err(ApiError::Forbidden(
+                "Externally issued tokens cannot be used for account access".to_string(),
+            ))
+        }
+    }
+}
 
-        let claims = jwt::verify_token(token, decoding_key)?;
+/// Returns the token from `Authorization: Bearer <token>`, or from the login cookie.
+fn bearer_token(parts: &Parts) -> Result<&str, ApiError> {
+    let Some(auth_header) = parts.headers.get("Authorization") else {
+        return cookie_token(parts);
+    };
 
-        Ok(AuthenticatedUser(claims.sub))
+    let token = auth_header
+        .to_str()
+        .map_err(|_| {
+            tracing::warn!("Unauthorized: Invalid header encoding");
+            ApiError::Unauthorized("Invalid header encoding".to_string())
+        })?
+        .strip_prefix("Bearer ")
+        .ok_or_else(|| {
+            tracing::warn!("Unauthorized: Invalid Authorization format (missing Bearer prefix)");
+            ApiError::Unauthorized("Invalid Authorization format".to_string())
+        })?;
+    Ok(token)
+}
+
+/// Returns the access token from the login cookie, checking CSRF for state-changing requests.
+fn cookie_token(parts: &Parts) -> Result<&str, ApiError> {
+    let token =
+        auth_cookie::cookie_value(&parts.headers, auth_cookie::ACCESS_COOKIE).ok_or_else(|| {
+            tracing::warn!("Unauthorized: Missing Authorization header");
+            ApiError::Unauthorized("Missing Authorization header".to_string())
+        })?;
+    if auth_cookie::requires_csrf(&parts.method) {
+        auth_cookie::verify_csrf(&parts.headers)?;
+    }
+    Ok(token)
+}
+
+async fn verify_local_token(
+    token: &str,
+    app_state: &AppState,
+) -> Result<(jwt::Claims, UserData), ApiError> {
+    let claims = app_state
+        .share()
+        .verify_token(app_state.redis_client(), token)
+        .await?;
+
+    if let Some(jti) = &claims.jti {
+        if app_state.is_token_revoked(jti).await? {
+            tracing::warn!("Unauthorized: Logged out token for user {}", claims.sub);
+            return Err(ApiError::Unauthorized("Token has been revoked".to_string()));
+        }
     }
+
+    let user_data = app_state.get_user_data(&claims.sub).await?.ok_or_else(|| {
+        tracing::warn!("Unauthorized: Account {} no longer exists", claims.sub);
+        ApiError::Unauthorized("Account no longer exists".to_string())
+    })?;
+    if claims.generation != user_data.token_generation {
+        tracing::warn!("Unauthorized: Revoked token for user {}", claims.sub);
+        return Err(ApiError::Unauthorized("Token has been revoked".to_string()));
+    }
+    if user_data.is_disabled() {
+        tracing::warn!("Forbidden: Account {} is disabled", claims.sub);
+        return Err(ApiError::AccountDisabled(claims.sub));
+    }
+
+    Ok((claims, user_data))
 }
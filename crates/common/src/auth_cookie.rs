@@ -0,0 +1,323 @@
+//! 웹 프론트엔드용 HttpOnly 쿠키 로그인.
+//!
+//! access 토큰과 refresh 토큰을 스크립트가 읽을 수 없는 `HttpOnly; SameSite=Strict`
+//! 쿠키에 담습니다. 쿠키는 브라우저가 자동으로 보내므로, 쿠키로 인증한 상태 변경 요청은
+//! double-submit 방식으로 CSRF를 막습니다. 로그인할 때 스크립트가 읽을 수 있는
+//! CSRF 쿠키를 함께 내려 주고, 요청마다 같은 값을 `X-CSRF-Token` 헤더로 보내야 합니다.
+//! 다른 사이트의 스크립트는 이 쿠키를 읽을 수 없으므로 헤더를 채우지 못합니다.
+//!
+//! SSO 로그인은 시작한 브라우저에 `state`를 담은 쿠키를 남기고, 콜백에서 같은 값인지
+//! 확인합니다. 다른 사람이 시작한 로그인의 콜백 URL을 열어도 그 계정으로 로그인되지 않습니다.
+
+use std::convert::Infallible;
+
+use axum::{
+    http::{header, HeaderMap, HeaderValue, Method},
+    response::{IntoResponseParts, ResponseParts},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{errors::ApiError, oidc, refresh_token};
+
+/// access 토큰 쿠키
+pub const ACCESS_COOKIE: &str = "rsvg_access";
+/// refresh 토큰 쿠키. 인증 API에만 보내집니다.
+pub const REFRESH_COOKIE: &str = "rsvg_refresh";
+/// 스크립트가 읽어 [`CSRF_HEADER`]로 되돌려 보내는 쿠키
+pub const CSRF_COOKIE: &str = "rsvg_csrf";
+/// 쿠키로 인증한 상태 변경 요청에 필요한 헤더
+pub const CSRF_HEADER: &str = "x-csrf-token";
+/// SSO 로그인을 시작한 브라우저의 `state`. 콜백에만 보내집니다.
+pub const OIDC_STATE_COOKIE: &str = "rsvg_oidc_state";
+
+const REFRESH_COOKIE_PATH: &str = "/api/auth";
+const OIDC_STATE_COOKIE_PATH: &str = "/api/auth/oidc";
+
+/// 쿠키 속성 정책.
+///
+/// - `secure`: `Secure` 속성을 붙여 HTTPS로만 보내게 합니다. 로컬에서 HTTP로 개발할 때만 끕니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CookiePolicy {
+    pub secure: bool,
+}
+
+impl Default for CookiePolicy {
+    fn default() -> Self {
+        Self { secure: true }
+    }
+}
+
+impl CookiePolicy {
+    /// 로그인과 갱신 응답에 붙일 쿠키. CSRF 토큰은 매번 새로 만듭니다.
+    ///
+    /// CSRF 쿠키는 refresh 토큰과 수명이 같아, 로그인이 이어지는 동안 남아 있습니다.
+    pub fn login(
+        &self,
+        access_token: &str,
+        access_ttl: u64,
+        refresh_token: &str,
+        refresh_ttl: u64,
+    ) -> SetCookies {
+        let csrf_token = refresh_token::random_hex(32);
+        SetCookies(vec![
+            self.cookie(ACCESS_COOKIE, access_token, "/", access_ttl, true, "Strict"),
+            self.cookie(
+                REFRESH_COOKIE,
+                refresh_token,
+                REFRESH_COOKIE_PATH,
+                refresh_ttl,
+                true,
+                "Strict",
+            ),
+            self.cookie(CSRF_COOKIE, &csrf_token, "/", refresh_ttl, false, "Strict"),
+        ])
+    }
+
+    /// 로그아웃 응답에 붙여 세 쿠키를 모두 지웁니다.
+    pub fn clear(&self) -> SetCookies {
+        SetCookies(vec![
+            self.cookie(ACCESS_COOKIE, "", "/", 0, true, "Strict"),
+            self.cookie(REFRESH_COOKIE, "", REFRESH_COOKIE_PATH, 0, true, "Strict"),
+            self.cookie(CSRF_COOKIE, "", "/", 0, false, "Strict"),
+        ])
+    }
+
+    /// SSO 로그인을 시작할 때 붙이는 `state` 쿠키.
+    ///
+    /// 제공자에서 돌아오는 콜백은 다른 사이트에서 시작한 이동이므로 `SameSite=Lax`이며,
+    /// `state`가 Redis에 남아 있는 동안만 유효합니다.
+    pub fn oidc_state(&self, state: &str) -> SetCookies {
+        SetCookies(vec![self.cookie(
+            OIDC_STATE_COOKIE,
+            state,
+            OIDC_STATE_COOKIE_PATH,
+            oidc::LOGIN_STATE_TTL_SECONDS,
+            true,
+            "Lax",
+        )])
+    }
+
+    /// SSO 콜백 응답에 붙여 `state` 쿠키를 지웁니다.
+    pub fn clear_oidc_state(&self) -> SetCookies {
+        SetCookies(vec![self.cookie(
+            OIDC_STATE_COOKIE,
+            "",
+            OIDC_STATE_COOKIE_PATH,
+            0,
+            true,
+            "Lax",
+        )])
+    }
+
+    fn cookie(
+        &self,
+        name: &str,
+        value: &str,
+        path: &str,
+        max_age: u64,
+        http_only: bool,
+        same_site: &str,
+    ) -> String {
+        let mut cookie =
+            format!("{name}={value}; Path={path}; Max-Age={max_age}; SameSite={same_site}");
+        if http_only {
+            cookie.push_str("; HttpOnly");
+        }
+        if self.secure {
+            cookie.push_str("; Secure");
+        }
+        cookie
+    }
+}
+
+/// 응답에 `Set-Cookie` 헤더를 붙이는 응답 일부.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SetCookies(Vec<String>);
+
+impl SetCookies {
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(String::as_str)
+    }
+
+    /// 두 응답 일부의 쿠키를 모두 붙입니다.
+    pub fn merge(mut self, other: SetCookies) -> Self {
+        self.0.extend(other.0);
+        self
+    }
+}
+
+impl IntoResponseParts for SetCookies {
+    type Error = Infallible;
+
+    fn into_response_parts(self, mut res: ResponseParts) -> Result<ResponseParts, Self::Error> {
+        for cookie in self.0 {
+            if let Ok(value) = HeaderValue::from_str(&cookie) {
+                res.headers_mut().append(header::SET_COOKIE, value);
+            }
+        }
+        Ok(res)
+    }
+}
+
+/// 쿠키 로그인의 응답 본문. 토큰은 쿠키로만 전달하고, CSRF 토큰은 [`CSRF_COOKIE`]에서 읽습니다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CookieSession {
+    pub user_id: String,
+    /// 관리자 역할 (access 토큰의 `admin` claim)
+    pub admin: bool,
+    /// access 토큰이 만료될 때까지 남은 초. 이보다 먼저 갱신해야 합니다.
+    pub expires_in: u64,
+}
+
+/// 요청의 `Cookie` 헤더에서 `name` 쿠키의 값을 찾습니다. 빈 값은 없는 것으로 봅니다.
+pub fn cookie_value<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers
+        .get_all(header::COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(';'))
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value)
+        .filter(|value| !value.is_empty())
+}
+
+/// `Authorization` 헤더 없이 로그인 쿠키로 인증하는 요청인지 여부.
+pub fn uses_login_cookie(headers: &HeaderMap) -> bool {
+    !headers.contains_key(header::AUTHORIZATION) && cookie_value(headers, ACCESS_COOKIE).is_some()
+}
+
+/// 쿠키로 인증할 때 CSRF 토큰을 확인해야 하는 메서드인지 여부.
+pub fn requires_csrf(method: &Method) -> bool {
+    !matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS)
+}
+
+/// `X-CSRF-Token` 헤더가 CSRF 쿠키와 같은지 확인합니다.
+///
+/// # Errors
+/// 둘 중 하나가 없거나 다르면 `ApiError::InvalidCsrfToken` (403).
+pub fn verify_csrf(headers: &HeaderMap) -> Result<(), ApiError> {
+    let cookie = cookie_value(headers, CSRF_COOKIE);
+    let header = headers
+        .get(CSRF_HEADER)
+        .and_then(|value| value.to_str().ok());
+    match (cookie, header) {
+        (Some(cookie), Some(header)) if constant_time_eq(cookie, header) => Ok(()),
+        _ => {
+            tracing::warn!("Forbidden: Missing or mismatched CSRF token");
+            Err(ApiError::InvalidCsrfToken)
+        }
+    }
+}
+
+/// SSO 콜백의 `state`가 로그인을 시작한 브라우저의 [`OIDC_STATE_COOKIE`]와 같은지 확인합니다.
+///
+/// # Errors
+/// 쿠키가 없거나 다르면 `ApiError::OidcLoginFailed`.
+pub fn verify_oidc_state(headers: &HeaderMap, state: &str) -> Result<(), ApiError> {
+    match cookie_value(headers, OIDC_STATE_COOKIE) {
+        Some(cookie) if constant_time_eq(cookie, state) => Ok(()),
+        _ => Err(ApiError::OidcLoginFailed(
+            "state does not match the browser that started the login".to_string(),
+        )),
+    }
+}
+
+fn constant_time_eq(a: &str, b: &str) -> bool {
+    a.len() == b.len()
+        && a.bytes()
+            .zip(b.bytes())
+            .fold(0u8, |diff, (x, y)| diff | (x ^ y))
+            == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.append(*name, HeaderValue::from_str(value).unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn test_cookie_value() {
+        let headers = headers(&[
+            ("cookie", "theme=dark; rsvg_access=a.b.c"),
+            ("cookie", "rsvg_csrf=; other=1"),
+        ]);
+        assert_eq!(cookie_value(&headers, ACCESS_COOKIE), Some("a.b.c"));
+        assert_eq!(cookie_value(&headers, "theme"), Some("dark"));
+        assert_eq!(cookie_value(&headers, CSRF_COOKIE), None);
+        assert_eq!(cookie_value(&headers, REFRESH_COOKIE), None);
+    }
+
+    #[test]
+    fn test_login_cookies() {
+        let cookies = CookiePolicy::default().login("a.b.c", 900, "r", 3600);
+        let cookies: Vec<&str> = cookies.iter().collect();
+        let csrf_token = cookies[2]
+            .strip_prefix("rsvg_csrf=")
+            .and_then(|rest| rest.split(';').next())
+            .unwrap();
+        assert_eq!(csrf_token.len(), 64);
+        assert_eq!(
+            cookies,
+            [
+                "rsvg_access=a.b.c; Path=/; Max-Age=900; SameSite=Strict; HttpOnly; Secure",
+                "rsvg_refresh=r; Path=/api/auth; Max-Age=3600; SameSite=Strict; HttpOnly; Secure",
+                &format!("rsvg_csrf={csrf_token}; Path=/; Max-Age=3600; SameSite=Strict; Secure"),
+            ]
+        );
+
+        let cleared = CookiePolicy { secure: false }.clear();
+        assert!(cleared
+            .iter()
+            .all(|cookie| cookie.contains("=; ") && cookie.contains("Max-Age=0")));
+        assert!(cleared.iter().all(|cookie| !cookie.contains("Secure")));
+    }
+
+    #[test]
+    fn test_verify_csrf() {
+        let cookie = "rsvg_csrf=abc123";
+        assert!(verify_csrf(&headers(&[("cookie", cookie), (CSRF_HEADER, "abc123")])).is_ok());
+        assert!(verify_csrf(&headers(&[("cookie", cookie), (CSRF_HEADER, "abc124")])).is_err());
+        assert!(verify_csrf(&headers(&[("cookie", cookie), (CSRF_HEADER, "abc")])).is_err());
+        assert!(verify_csrf(&headers(&[("cookie", cookie)])).is_err());
+        assert!(verify_csrf(&headers(&[(CSRF_HEADER, "abc123")])).is_err());
+    }
+
+    #[test]
+    fn test_oidc_state_cookie() {
+        let policy = CookiePolicy::default();
+        let cookies = policy.oidc_state("s1");
+        assert_eq!(
+            cookies.iter().collect::<Vec<_>>(),
+            ["rsvg_oidc_state=s1; Path=/api/auth/oidc; Max-Age=600; SameSite=Lax; HttpOnly; Secure"]
+        );
+        assert!(
+            policy
+                .clear_oidc_state()
+                .iter()
+                .all(|cookie| cookie.starts_with("rsvg_oidc_state=; ")
+                    && cookie.contains("Max-Age=0"))
+        );
+
+        let cookie = "rsvg_oidc_state=s1";
+        assert!(verify_oidc_state(&headers(&[("cookie", cookie)]), "s1").is_ok());
+        assert!(verify_oidc_state(&headers(&[("cookie", cookie)]), "s2").is_err());
+        assert!(verify_oidc_state(&headers(&[]), "s1").is_err());
+    }
+
+    #[test]
+    fn test_requires_csrf() {
+        assert!(!requires_csrf(&Method::GET));
+        assert!(!requires_csrf(&Method::HEAD));
+        assert!(requires_csrf(&Method::POST));
+        assert!(requires_csrf(&Method::PUT));
+        assert!(requires_csrf(&Method::DELETE));
+    }
+}
@@ -0,0 +1,83 @@
+//! 요청을 보낸 클라이언트의 IP 주소.
+
+use std::net::{IpAddr, SocketAddr};
+
+use axum::{
+    extract::{ConnectInfo, FromRef, FromRequestParts},
+    http::{request::Parts, HeaderMap},
+};
+
+use crate::{errors::ApiError, state::AppState};
+
+/// 요청을 보낸 클라이언트의 IP 주소를 꺼내는 extractor.
+///
+/// 기본으로는 TCP 연결의 상대 주소를 씁니다. 리버스 프록시 뒤에서
+/// `trust_forwarded_for`를 켜면 프록시가 덧붙인 `X-Forwarded-For`의 마지막 주소를 씁니다.
+/// 앞쪽 주소는 클라이언트가 마음대로 넣을 수 있으므로 믿지 않습니다.
+///
+/// 연결 정보 없이 실행된 경우(테스트 등) 주소를 알 수 없으면 `None`입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClientIp(pub Option<IpAddr>);
+
+impl<S> FromRequestParts<S> for ClientIp
+where
+    S: Send + Sync,
+    AppState: FromRef<S>,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let app_state = AppState::from_ref(state);
+        let peer = parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip());
+        let trust_forwarded_for = app_state.share().rate_limit_policy().trust_forwarded_for;
+        Ok(ClientIp(client_ip(
+            &parts.headers,
+            peer,
+            trust_forwarded_for,
+        )))
+    }
+}
+
+/// `trust_forwarded_for`이면 `X-Forwarded-For`의 마지막 주소를, 아니면 `peer`를 돌려줍니다.
+pub fn client_ip(
+    headers: &HeaderMap,
+    peer: Option<IpAddr>,
+    trust_forwarded_for: bool,
+) -> Option<IpAddr> {
+    if !trust_forwarded_for {
+        return peer;
+    }
+    headers
+        .get_all("x-forwarded-for")
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .next_back()
+        .and_then(|addr| addr.trim().parse().ok())
+        .or(peer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_client_ip_uses_last_forwarded_address_when_trusted() {
+        let peer: IpAddr = "10.0.0.2".parse().unwrap();
+        let mut headers = HeaderMap::new();
+        headers.insert("x-forwarded-for", "1.1.1.1, 203.0.113.7".parse().unwrap());
+
+        assert_eq!(client_ip(&headers, Some(peer), false), Some(peer));
+        assert_eq!(
+            client_ip(&headers, Some(peer), true),
+            Some("203.0.113.7".parse().unwrap())
+        );
+
+        headers.insert("x-forwarded-for", "not-an-ip".parse().unwrap());
+        assert_eq!(client_ip(&headers, Some(peer), true), Some(peer));
+        assert_eq!(client_ip(&HeaderMap::new(), None, true), None);
+    }
+}
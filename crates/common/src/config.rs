@@ -5,8 +5,17 @@ use figment::{
     Figment,
 };
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
 use std::fmt;
+use std::path::PathBuf;
+
+use crate::audit::{self, AuditSinkKind};
+use crate::auth_cookie::CookiePolicy;
+use crate::jwt::TokenPolicy;
+use crate::keys::{self, Keys};
+use crate::oidc::OidcConfig;
+use crate::rate_limit::RateLimitPolicy;
+use crate::signing_key::SigningAlgorithm;
+use crate::trusted_issuer::TrustedIssuerConfig;
 
 /// 서버 실행에 필요한 모든 설정값을 담는 구조체
 ///
@@ -21,6 +30,82 @@ pub struct Config {
     pub port: u16,
     /// 로깅 레벨 (info, debug, warn, error)
     pub log_level: String,
+    /// 모든 Redis 키와 pubsub 채널 앞에 붙는 prefix (예: tenant-a)
+    ///
+    /// 하나의 Redis 데이터베이스를 여러 배포가 함께 쓸 때 배포마다 다른 값을
+    /// 지정합니다. 비어 있으면 prefix 없이 기존 키 이름을 사용합니다.
+    pub key_prefix: String,
+    /// 비밀번호 해시(Argon2id) 메모리 비용 (KiB)
+    pub argon2_memory_kib: u32,
+    /// 비밀번호 해시(Argon2id) 반복 횟수
+    pub argon2_iterations: u32,
+    /// 비밀번호 해시(Argon2id) 병렬도
+    pub argon2_parallelism: u32,
+    /// 클라이언트가 `ttl_seconds`를 지정하지 않았을 때의 access 토큰 수명 (초)
+    pub access_token_ttl_seconds: u64,
+    /// 클라이언트가 요청할 수 있는 access 토큰 수명의 상한 (초)
+    pub max_access_token_ttl_seconds: u64,
+    /// refresh 토큰 수명 (초). 토큰을 교체할 때마다 다시 연장됩니다.
+    pub refresh_token_ttl_seconds: u64,
+    /// 세션 전용(범위 제한) 토큰을 만들 때 수명을 지정하지 않았을 때의 기본값 (초)
+    pub scoped_token_ttl_seconds: u64,
+    /// 세션 전용 토큰 수명의 상한 (초). 교체된 서명 키도 이만큼 더 공개됩니다.
+    pub max_scoped_token_ttl_seconds: u64,
+    /// JWT 서명 키 교체 주기 (초). 0이면 예약 교체를 하지 않습니다.
+    ///
+    /// 교체된 키는 그 키로 서명된 토큰이 만료될 때까지 JWKS에 남아 있습니다.
+    pub signing_key_rotation_seconds: u64,
+    /// 새 JWT 서명 키의 알고리즘 (RS256, ES256, EdDSA)
+    ///
+    /// 바꾸면 서버 시작 시 새 알고리즘의 키로 교체하고, 이전 키는 그 토큰이
+    /// 만료될 때까지 JWKS에 남습니다.
+    pub signing_algorithm: SigningAlgorithm,
+    /// SSO 로그인에 쓰는 OpenID Connect 제공자의 issuer URL. 비어 있으면 SSO를 쓰지 않습니다.
+    pub oidc_issuer: String,
+    /// 제공자에 등록한 client ID
+    pub oidc_client_id: String,
+    /// 제공자에 등록한 client secret. 비어 있으면 PKCE만 쓰는 public client로 동작합니다.
+    pub oidc_client_secret: String,
+    /// 제공자에 등록한 콜백 URL (예: https://svg.example.com/api/auth/oidc/callback)
+    pub oidc_redirect_url: String,
+    /// 요청할 scope (공백으로 구분)
+    pub oidc_scopes: String,
+    /// `user_id`로 쓸 ID 토큰 claim
+    pub oidc_user_claim: String,
+    /// 리버스 프록시가 붙인 `X-Forwarded-For`의 마지막 주소를 클라이언트 IP로 쓸지 여부
+    ///
+    /// 프록시 없이 직접 노출된 서버에서 켜면 클라이언트가 IP를 속일 수 있습니다.
+    pub trust_forwarded_for: bool,
+    /// IP 하나가 `login_window_seconds` 동안 시도할 수 있는 로그인 횟수 (0이면 제한 없음)
+    pub login_ip_limit: u32,
+    /// 사용자 ID 하나에 `login_window_seconds` 동안 시도할 수 있는 로그인 횟수 (0이면 제한 없음)
+    pub login_user_limit: u32,
+    /// 로그인 시도 횟수를 세는 sliding window 길이 (초)
+    pub login_window_seconds: u64,
+    /// 이만큼 연속으로 로그인에 실패하면 계정을 잠급니다 (0이면 잠그지 않음)
+    pub login_lockout_threshold: u32,
+    /// 첫 잠금 시간 (초). 그 뒤로 실패할 때마다 두 배로 늘어납니다.
+    pub login_lockout_base_seconds: u64,
+    /// 잠금 시간의 상한 (초)
+    pub login_lockout_max_seconds: u64,
+    /// 사용자 하나가 가질 수 있는 세션 수. 조직 세션은 조직마다 따로 셉니다 (0이면 제한 없음)
+    pub max_sessions_per_user: u32,
+    /// 세션 템플릿 크기 상한 (바이트, 0이면 제한 없음)
+    pub max_template_bytes: u64,
+    /// JSON으로 직렬화한 세션 인자 크기 상한 (바이트, 0이면 제한 없음)
+    pub max_args_bytes: u64,
+    /// 세션 하나에 1분 동안 보낼 수 있는 업데이트 수 (0이면 제한 없음)
+    pub session_updates_per_minute: u32,
+    /// IP 하나가 동시에 열 수 있는 스트림 수 (0이면 제한 없음)
+    pub max_streams_per_ip: u32,
+    /// 감사 로그 저장소 (redis: Redis stream에 남기고 관리자 API로 조회, log: `audit` target 로그로 출력)
+    pub audit_sink: AuditSinkKind,
+    /// Redis stream에 남길 감사 기록 수의 상한. 넘으면 오래된 기록부터 지웁니다 (0이면 지우지 않음)
+    pub audit_log_max_len: u64,
+    /// 로그인 쿠키에 `Secure` 속성을 붙일지 여부. HTTPS 없이 로컬에서 개발할 때만 끕니다.
+    pub cookie_secure: bool,
+    /// 이 서버의 토큰과 함께 받아들일 외부 발급자 (사내 IdP 등). 설정 파일에서만 지정합니다.
+    pub trusted_issuers: Vec<TrustedIssuerConfig>,
 }
 
 impl Default for Config {
@@ -30,6 +115,39 @@ impl Default for Config {
             host: "127.0.0.1".to_string(),
             port: 3000,
             log_level: "info".to_string(),
+            key_prefix: String::new(),
+            argon2_memory_kib: argon2::Params::DEFAULT_M_COST,
+            argon2_iterations: argon2::Params::DEFAULT_T_COST,
+            argon2_parallelism: argon2::Params::DEFAULT_P_COST,
+            access_token_ttl_seconds: TokenPolicy::default().access_ttl_seconds,
+            max_access_token_ttl_seconds: TokenPolicy::default().max_access_ttl_seconds,
+            refresh_token_ttl_seconds: TokenPolicy::default().refresh_ttl_seconds,
+            scoped_token_ttl_seconds: TokenPolicy::default().scoped_ttl_seconds,
+            max_scoped_token_ttl_seconds: TokenPolicy::default().max_scoped_ttl_seconds,
+            signing_key_rotation_seconds: 30 * 24 * 60 * 60,
+            signing_algorithm: TokenPolicy::default().signing_algorithm,
+            oidc_issuer: String::new(),
+            oidc_client_id: String::new(),
+            oidc_client_secret: String::new(),
+            oidc_redirect_url: String::new(),
+            oidc_scopes: "openid profile email".to_string(),
+            oidc_user_claim: "preferred_username".to_string(),
+            trust_forwarded_for: RateLimitPolicy::default().trust_forwarded_for,
+            login_ip_limit: RateLimitPolicy::default().login_ip_limit,
+            login_user_limit: RateLimitPolicy::default().login_user_limit,
+            login_window_seconds: RateLimitPolicy::default().login_window_seconds,
+            login_lockout_threshold: RateLimitPolicy::default().login_lockout_threshold,
+            login_lockout_base_seconds: RateLimitPolicy::default().login_lockout_base_seconds,
+            login_lockout_max_seconds: RateLimitPolicy::default().login_lockout_max_seconds,
+            max_sessions_per_user: RateLimitPolicy::default().max_sessions_per_user,
+            max_template_bytes: RateLimitPolicy::default().max_template_bytes,
+            max_args_bytes: RateLimitPolicy::default().max_args_bytes,
+            session_updates_per_minute: RateLimitPolicy::default().session_updates_per_minute,
+            max_streams_per_ip: RateLimitPolicy::default().max_streams_per_ip,
+            audit_sink: AuditSinkKind::default(),
+            audit_log_max_len: audit::DEFAULT_MAX_LEN,
+            cookie_secure: CookiePolicy::default().secure,
+            trusted_issuers: Vec::new(),
         }
     }
 }
@@ -41,6 +159,54 @@ impl fmt::Debug for Config {
             .field("host", &self.host)
             .field("port", &self.port)
             .field("log_level", &self.log_level)
+            .field("key_prefix", &self.key_prefix)
+            .field("argon2_memory_kib", &self.argon2_memory_kib)
+            .field("argon2_iterations", &self.argon2_iterations)
+            .field("argon2_parallelism", &self.argon2_parallelism)
+            .field("access_token_ttl_seconds", &self.access_token_ttl_seconds)
+            .field(
+                "max_access_token_ttl_seconds",
+                &self.max_access_token_ttl_seconds,
+            )
+            .field("refresh_token_ttl_seconds", &self.refresh_token_ttl_seconds)
+            .field("scoped_token_ttl_seconds", &self.scoped_token_ttl_seconds)
+            .field(
+                "max_scoped_token_ttl_seconds",
+                &self.max_scoped_token_ttl_seconds,
+            )
+            .field(
+                "signing_key_rotation_seconds",
+                &self.signing_key_rotation_seconds,
+            )
+            .field("signing_algorithm", &self.signing_algorithm)
+            .field("oidc_issuer", &self.oidc_issuer)
+            .field("oidc_client_id", &self.oidc_client_id)
+            .field("oidc_client_secret", &"***REDACTED***")
+            .field("oidc_redirect_url", &self.oidc_redirect_url)
+            .field("oidc_scopes", &self.oidc_scopes)
+            .field("oidc_user_claim", &self.oidc_user_claim)
+            .field("trust_forwarded_for", &self.trust_forwarded_for)
+            .field("login_ip_limit", &self.login_ip_limit)
+            .field("login_user_limit", &self.login_user_limit)
+            .field("login_window_seconds", &self.login_window_seconds)
+            .field("login_lockout_threshold", &self.login_lockout_threshold)
+            .field(
+                "login_lockout_base_seconds",
+                &self.login_lockout_base_seconds,
+            )
+            .field("login_lockout_max_seconds", &self.login_lockout_max_seconds)
+            .field("max_sessions_per_user", &self.max_sessions_per_user)
+            .field("max_template_bytes", &self.max_template_bytes)
+            .field("max_args_bytes", &self.max_args_bytes)
+            .field(
+                "session_updates_per_minute",
+                &self.session_updates_per_minute,
+            )
+            .field("max_streams_per_ip", &self.max_streams_per_ip)
+            .field("audit_sink", &self.audit_sink)
+            .field("audit_log_max_len", &self.audit_log_max_len)
+            .field("cookie_secure", &self.cookie_secure)
+            .field("trusted_issuers", &self.trusted_issuers)
             .finish()
     }
 }
@@ -76,6 +242,288 @@ pub struct CliArgs {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub log_level: Option<String>,
 
+    /// Redis 키 prefix (환경 변수: KEY_PREFIX)
+    #[arg(
+        long,
+        env = "KEY_PREFIX",
+        help = "Prefix for every Redis key and channel"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_prefix: Option<String>,
+
+    /// Argon2 메모리 비용 KiB (환경 변수: ARGON2_MEMORY_KIB)
+    #[arg(long, env = "ARGON2_MEMORY_KIB", help = "Argon2 memory cost in KiB")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub argon2_memory_kib: Option<u32>,
+
+    /// Argon2 반복 횟수 (환경 변수: ARGON2_ITERATIONS)
+    #[arg(long, env = "ARGON2_ITERATIONS", help = "Argon2 iterations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub argon2_iterations: Option<u32>,
+
+    /// Argon2 병렬도 (환경 변수: ARGON2_PARALLELISM)
+    #[arg(long, env = "ARGON2_PARALLELISM", help = "Argon2 parallelism")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub argon2_parallelism: Option<u32>,
+
+    /// 기본 access 토큰 수명 (환경 변수: ACCESS_TOKEN_TTL_SECONDS)
+    #[arg(
+        long,
+        env = "ACCESS_TOKEN_TTL_SECONDS",
+        help = "Default access token lifetime in seconds"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_token_ttl_seconds: Option<u64>,
+
+    /// access 토큰 수명 상한 (환경 변수: MAX_ACCESS_TOKEN_TTL_SECONDS)
+    #[arg(
+        long,
+        env = "MAX_ACCESS_TOKEN_TTL_SECONDS",
+        help = "Maximum access token lifetime in seconds"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_access_token_ttl_seconds: Option<u64>,
+
+    /// refresh 토큰 수명 (환경 변수: REFRESH_TOKEN_TTL_SECONDS)
+    #[arg(
+        long,
+        env = "REFRESH_TOKEN_TTL_SECONDS",
+        help = "Refresh token lifetime in seconds"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_token_ttl_seconds: Option<u64>,
+
+    /// 세션 전용 토큰 기본 수명 (환경 변수: SCOPED_TOKEN_TTL_SECONDS)
+    #[arg(
+        long,
+        env = "SCOPED_TOKEN_TTL_SECONDS",
+        help = "Default scoped (per-session) token lifetime in seconds"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scoped_token_ttl_seconds: Option<u64>,
+
+    /// 세션 전용 토큰 수명 상한 (환경 변수: MAX_SCOPED_TOKEN_TTL_SECONDS)
+    #[arg(
+        long,
+        env = "MAX_SCOPED_TOKEN_TTL_SECONDS",
+        help = "Maximum scoped (per-session) token lifetime in seconds"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_scoped_token_ttl_seconds: Option<u64>,
+
+    /// JWT 서명 키 교체 주기, 0이면 끔 (환경 변수: SIGNING_KEY_ROTATION_SECONDS)
+    #[arg(
+        long,
+        env = "SIGNING_KEY_ROTATION_SECONDS",
+        help = "Rotate the JWT signing key every N seconds (0 disables)"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signing_key_rotation_seconds: Option<u64>,
+
+    /// JWT 서명 알고리즘 (환경 변수: SIGNING_ALGORITHM)
+    #[arg(
+        long,
+        env = "SIGNING_ALGORITHM",
+        help = "JWT signing algorithm (RS256, ES256, EdDSA)"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signing_algorithm: Option<SigningAlgorithm>,
+
+    /// OIDC 제공자 issuer URL (환경 변수: OIDC_ISSUER)
+    #[arg(
+        long,
+        env = "OIDC_ISSUER",
+        help = "OpenID Connect issuer URL for SSO login (empty disables SSO)"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oidc_issuer: Option<String>,
+
+    /// OIDC client ID (환경 변수: OIDC_CLIENT_ID)
+    #[arg(long, env = "OIDC_CLIENT_ID", help = "OpenID Connect client ID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oidc_client_id: Option<String>,
+
+    /// OIDC client secret (환경 변수: OIDC_CLIENT_SECRET)
+    #[arg(
+        long,
+        env = "OIDC_CLIENT_SECRET",
+        help = "OpenID Connect client secret (empty for a public client)"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oidc_client_secret: Option<String>,
+
+    /// OIDC 콜백 URL (환경 변수: OIDC_REDIRECT_URL)
+    #[arg(
+        long,
+        env = "OIDC_REDIRECT_URL",
+        help = "Callback URL registered with the provider (…/api/auth/oidc/callback)"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oidc_redirect_url: Option<String>,
+
+    /// OIDC scope (환경 변수: OIDC_SCOPES)
+    #[arg(long, env = "OIDC_SCOPES", help = "Space separated OpenID Connect scopes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oidc_scopes: Option<String>,
+
+    /// user_id로 쓸 claim (환경 변수: OIDC_USER_CLAIM)
+    #[arg(
+        long,
+        env = "OIDC_USER_CLAIM",
+        help = "ID token claim used as the user ID"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oidc_user_claim: Option<String>,
+
+    /// X-Forwarded-For 신뢰 여부 (환경 변수: TRUST_FORWARDED_FOR)
+    #[arg(
+        long,
+        env = "TRUST_FORWARDED_FOR",
+        help = "Use the last X-Forwarded-For address as the client IP (behind a reverse proxy)"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trust_forwarded_for: Option<bool>,
+
+    /// IP별 로그인 시도 제한 (환경 변수: LOGIN_IP_LIMIT)
+    #[arg(
+        long,
+        env = "LOGIN_IP_LIMIT",
+        help = "Login attempts allowed per IP within the window (0 disables)"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub login_ip_limit: Option<u32>,
+
+    /// 사용자별 로그인 시도 제한 (환경 변수: LOGIN_USER_LIMIT)
+    #[arg(
+        long,
+        env = "LOGIN_USER_LIMIT",
+        help = "Login attempts allowed per user ID within the window (0 disables)"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub login_user_limit: Option<u32>,
+
+    /// 로그인 시도 제한 창 (환경 변수: LOGIN_WINDOW_SECONDS)
+    #[arg(
+        long,
+        env = "LOGIN_WINDOW_SECONDS",
+        help = "Sliding window for login attempt limits in seconds"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub login_window_seconds: Option<u64>,
+
+    /// 계정 잠금 기준 실패 횟수 (환경 변수: LOGIN_LOCKOUT_THRESHOLD)
+    #[arg(
+        long,
+        env = "LOGIN_LOCKOUT_THRESHOLD",
+        help = "Consecutive failed logins before the account is locked (0 disables)"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub login_lockout_threshold: Option<u32>,
+
+    /// 첫 잠금 시간 (환경 변수: LOGIN_LOCKOUT_BASE_SECONDS)
+    #[arg(
+        long,
+        env = "LOGIN_LOCKOUT_BASE_SECONDS",
+        help = "First lockout duration in seconds, doubled on every further failure"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub login_lockout_base_seconds: Option<u64>,
+
+    /// 잠금 시간 상한 (환경 변수: LOGIN_LOCKOUT_MAX_SECONDS)
+    #[arg(
+        long,
+        env = "LOGIN_LOCKOUT_MAX_SECONDS",
+        help = "Maximum lockout duration in seconds"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub login_lockout_max_seconds: Option<u64>,
+
+    /// 사용자별 세션 수 상한 (환경 변수: MAX_SESSIONS_PER_USER)
+    #[arg(
+        long,
+        env = "MAX_SESSIONS_PER_USER",
+        help = "Maximum sessions per user or organization (0 disables)"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_sessions_per_user: Option<u32>,
+
+    /// 템플릿 크기 상한 (환경 변수: MAX_TEMPLATE_BYTES)
+    #[arg(
+        long,
+        env = "MAX_TEMPLATE_BYTES",
+        help = "Maximum session template size in bytes (0 disables)"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_template_bytes: Option<u64>,
+
+    /// 세션 인자 크기 상한 (환경 변수: MAX_ARGS_BYTES)
+    #[arg(
+        long,
+        env = "MAX_ARGS_BYTES",
+        help = "Maximum serialized session args size in bytes (0 disables)"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_args_bytes: Option<u64>,
+
+    /// 세션별 분당 업데이트 수 (환경 변수: SESSION_UPDATES_PER_MINUTE)
+    #[arg(
+        long,
+        env = "SESSION_UPDATES_PER_MINUTE",
+        help = "Maximum updates per session per minute (0 disables)"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_updates_per_minute: Option<u32>,
+
+    /// IP별 동시 스트림 수 (환경 변수: MAX_STREAMS_PER_IP)
+    #[arg(
+        long,
+        env = "MAX_STREAMS_PER_IP",
+        help = "Maximum concurrent streams per client IP (0 disables)"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_streams_per_ip: Option<u32>,
+
+    /// 감사 로그 저장소 (환경 변수: AUDIT_SINK)
+    #[arg(
+        long,
+        env = "AUDIT_SINK",
+        help = "Where audit events are written (redis, log)"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audit_sink: Option<AuditSinkKind>,
+
+    /// 감사 기록 수 상한 (환경 변수: AUDIT_LOG_MAX_LEN)
+    #[arg(
+        long,
+        env = "AUDIT_LOG_MAX_LEN",
+        help = "Approximate number of audit events kept in Redis (0 keeps all)"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audit_log_max_len: Option<u64>,
+
+    /// 로그인 쿠키의 Secure 속성 (환경 변수: COOKIE_SECURE)
+    #[arg(
+        long,
+        env = "COOKIE_SECURE",
+        help = "Mark login cookies Secure so they are only sent over HTTPS"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cookie_secure: Option<bool>,
+
+    /// 서명 키를 지금 교체하고 종료합니다
+    #[arg(long, help = "Rotate the JWT signing key now and exit")]
+    #[serde(skip)]
+    pub rotate_signing_key: bool,
+
+    /// 이미 가입한 계정에 관리자 역할을 주고 종료합니다
+    #[arg(
+        long,
+        value_name = "USER_ID",
+        help = "Grant the admin role to an existing user and exit"
+    )]
+    #[serde(skip)]
+    pub grant_admin: Option<String>,
+
     /// 설정 파일 경로 (기본값: config.yaml)
     #[arg(long, help = "Path to configuration file, default: config.yaml")]
     #[serde(skip)]
@@ -108,7 +556,11 @@ fn resolve_config_path(config_arg: &Option<PathBuf>) -> Result<PathBuf> {
 
 impl Config {
     pub fn load() -> Result<Self> {
-        let cli_args = CliArgs::parse();
+        Self::load_from(CliArgs::parse())
+    }
+
+    /// 이미 파싱한 CLI 인자로 설정을 읽습니다.
+    pub fn load_from(cli_args: CliArgs) -> Result<Self> {
         let config_path = resolve_config_path(&cli_args.config)?;
         let config: Config = Figment::new()
             .merge(Serialized::defaults(Config::default()))
@@ -137,6 +589,156 @@ impl Config {
             anyhow::bail!("호스트는 빈 문자열일 수 없습니다");
         }
 
+        if !keys::is_valid_key_prefix(&self.key_prefix) {
+            anyhow::bail!(
+                "키 prefix는 {}자 이하의 영문, 숫자, '.', '_', '-'만 사용할 수 있고, '.'으로 시작하거나 \
+                 기본 키 네임스페이스(session, user, org 등)와 같을 수 없습니다: {:?}",
+                keys::MAX_ID_LEN,
+                self.key_prefix
+            );
+        }
+
+        self.argon2_params()?;
+
+        if self.access_token_ttl_seconds == 0
+            || self.max_access_token_ttl_seconds == 0
+            || self.refresh_token_ttl_seconds == 0
+            || self.scoped_token_ttl_seconds == 0
+            || self.max_scoped_token_ttl_seconds == 0
+        {
+            anyhow::bail!("토큰 수명은 0보다 커야 합니다");
+        }
+
+        if self.access_token_ttl_seconds > self.max_access_token_ttl_seconds {
+            anyhow::bail!(
+                "기본 access 토큰 수명({})이 상한({})보다 클 수 없습니다",
+                self.access_token_ttl_seconds,
+                self.max_access_token_ttl_seconds
+            );
+        }
+
+        if self.scoped_token_ttl_seconds > self.max_scoped_token_ttl_seconds {
+            anyhow::bail!(
+                "기본 세션 전용 토큰 수명({})이 상한({})보다 클 수 없습니다",
+                self.scoped_token_ttl_seconds,
+                self.max_scoped_token_ttl_seconds
+            );
+        }
+
+        if !self.oidc_issuer.is_empty() {
+            for (name, value) in [
+                ("oidc_issuer", &self.oidc_issuer),
+                ("oidc_redirect_url", &self.oidc_redirect_url),
+            ] {
+                url::Url::parse(value)
+                    .with_context(|| format!("{name}이(가) 올바른 URL이 아닙니다: {value:?}"))?;
+            }
+            if self.oidc_client_id.is_empty() {
+                anyhow::bail!("oidc_issuer를 쓰려면 oidc_client_id가 필요합니다");
+            }
+            if self.oidc_user_claim.is_empty() {
+                anyhow::bail!("oidc_user_claim은 빈 문자열일 수 없습니다");
+            }
+        }
+
+        if self.login_window_seconds == 0 {
+            anyhow::bail!("로그인 시도 제한 창은 0보다 커야 합니다");
+        }
+
+        if self.login_lockout_threshold > 0
+            && (self.login_lockout_base_seconds == 0
+                || self.login_lockout_base_seconds > self.login_lockout_max_seconds)
+        {
+            anyhow::bail!(
+                "첫 잠금 시간({})은 0보다 크고 상한({})보다 클 수 없습니다",
+                self.login_lockout_base_seconds,
+                self.login_lockout_max_seconds
+            );
+        }
+
+        for (i, issuer) in self.trusted_issuers.iter().enumerate() {
+            issuer
+                .validate()
+                .map_err(|e| anyhow::anyhow!("trusted_issuers 설정이 올바르지 않습니다: {}", e))?;
+            if self.trusted_issuers[..i]
+                .iter()
+                .any(|other| other.issuer == issuer.issuer)
+            {
+                anyhow::bail!(
+                    "trusted_issuers에 같은 issuer가 두 번 있습니다: {}",
+                    issuer.issuer
+                );
+            }
+        }
+
         Ok(())
     }
+
+    /// 새 비밀번호 해시에 쓰이는 Argon2id 파라미터.
+    pub fn argon2_params(&self) -> Result<argon2::Params> {
+        argon2::Params::new(
+            self.argon2_memory_kib,
+            self.argon2_iterations,
+            self.argon2_parallelism,
+            None,
+        )
+        .map_err(|e| anyhow::anyhow!("Argon2 파라미터가 올바르지 않습니다: {}", e))
+    }
+
+    /// 토큰 수명과 서명 알고리즘 정책.
+    pub fn token_policy(&self) -> TokenPolicy {
+        TokenPolicy {
+            access_ttl_seconds: self.access_token_ttl_seconds,
+            max_access_ttl_seconds: self.max_access_token_ttl_seconds,
+            refresh_ttl_seconds: self.refresh_token_ttl_seconds,
+            scoped_ttl_seconds: self.scoped_token_ttl_seconds,
+            max_scoped_ttl_seconds: self.max_scoped_token_ttl_seconds,
+            signing_algorithm: self.signing_algorithm,
+        }
+    }
+
+    /// 로그인 시도 제한과 사용자별 할당량 정책.
+    pub fn rate_limit_policy(&self) -> RateLimitPolicy {
+        RateLimitPolicy {
+            trust_forwarded_for: self.trust_forwarded_for,
+            login_ip_limit: self.login_ip_limit,
+            login_user_limit: self.login_user_limit,
+            login_window_seconds: self.login_window_seconds,
+            login_lockout_threshold: self.login_lockout_threshold,
+            login_lockout_base_seconds: self.login_lockout_base_seconds,
+            login_lockout_max_seconds: self.login_lockout_max_seconds,
+            max_sessions_per_user: self.max_sessions_per_user,
+            max_template_bytes: self.max_template_bytes,
+            max_args_bytes: self.max_args_bytes,
+            session_updates_per_minute: self.session_updates_per_minute,
+            max_streams_per_ip: self.max_streams_per_ip,
+        }
+    }
+
+    /// 로그인 쿠키 속성 정책.
+    pub fn cookie_policy(&self) -> CookiePolicy {
+        CookiePolicy {
+            secure: self.cookie_secure,
+        }
+    }
+
+    /// SSO 로그인 설정. `oidc_issuer`가 비어 있으면 `None`.
+    pub fn oidc_config(&self) -> Option<OidcConfig> {
+        if self.oidc_issuer.is_empty() {
+            return None;
+        }
+        Some(OidcConfig {
+            issuer: self.oidc_issuer.clone(),
+            client_id: self.oidc_client_id.clone(),
+            client_secret: Some(self.oidc_client_secret.clone()).filter(|s| !s.is_empty()),
+            redirect_url: self.oidc_redirect_url.clone(),
+            scopes: self.oidc_scopes.clone(),
+            user_claim: self.oidc_user_claim.clone(),
+        })
+    }
+
+    /// `key_prefix`를 적용한 Redis 키 생성기.
+    pub fn keys(&self) -> Keys {
+        Keys::new(self.key_prefix.as_str())
+    }
 }
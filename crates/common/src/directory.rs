@@ -0,0 +1,334 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    errors::ApiError,
+    session_data::normalize_tag,
+    session_list::{next_page, page_sorted, ListCursor, DEFAULT_LIMIT, MAX_LIMIT},
+};
+
+/// 인기순, 제목순 정렬과 검색이 살펴보는 최근 세션 수.
+///
+/// 이 정렬들은 세션을 모두 읽어 정렬하므로, 인증 없이 부르는 디렉터리가 등록된 세션 수만큼
+/// 느려지지 않도록 생성 시각이 최근인 세션만 대상으로 합니다.
+pub const MAX_SEARCHED_SESSIONS: usize = 1000;
+
+/// 디렉터리 인덱스에 저장되는 세션 참조.
+///
+/// `user_id`와 `org_id`가 모두 없으면 공용 세션(`/api/session`)을 가리킵니다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct SessionRef {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    /// 조직 세션(`/api/org/{org_id}/session`)이면 조직 ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub org_id: Option<String>,
+    pub session_id: String,
+}
+
+impl SessionRef {
+    pub fn public(session_id: impl Into<String>) -> Self {
+        Self {
+            user_id: None,
+            org_id: None,
+            session_id: session_id.into(),
+        }
+    }
+
+    pub fn user(user_id: impl Into<String>, session_id: impl Into<String>) -> Self {
+        Self {
+            user_id: Some(user_id.into()),
+            org_id: None,
+            session_id: session_id.into(),
+        }
+    }
+
+    pub fn org(org_id: impl Into<String>, session_id: impl Into<String>) -> Self {
+        Self {
+            user_id: None,
+            org_id: Some(org_id.into()),
+            session_id: session_id.into(),
+        }
+    }
+
+    /// 프론트엔드 상세 페이지 경로.
+    pub fn page_path(&self) -> String {
+        match (&self.user_id, &self.org_id) {
+            (Some(user_id), _) => format!("/session/{}/{}", user_id, self.session_id),
+            (None, Some(org_id)) => format!("/org/{}/session/{}", org_id, self.session_id),
+            (None, None) => format!("/session/{}", self.session_id),
+        }
+    }
+
+    pub fn stream_path(&self) -> String {
+        match (&self.user_id, &self.org_id) {
+            (Some(user_id), _) => format!("/stream/{}/{}", user_id, self.session_id),
+            (None, Some(org_id)) => format!("/stream/org/{}/{}", org_id, self.session_id),
+            (None, None) => format!("/stream/{}", self.session_id),
+        }
+    }
+
+    pub fn snapshot_path(&self) -> String {
+        match (&self.user_id, &self.org_id) {
+            (Some(user_id), _) => format!("/stream/{}/{}/snapshot", user_id, self.session_id),
+            (None, Some(org_id)) => {
+                format!("/stream/org/{}/{}/snapshot", org_id, self.session_id)
+            }
+            (None, None) => format!("/api/session/{}/snapshot", self.session_id),
+        }
+    }
+}
+
+/// `GET /api/directory` 응답의 한 항목.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DirectoryEntry {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub org_id: Option<String>,
+    pub session_id: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub tags: Vec<String>,
+    pub created_at: Option<DateTime<Utc>>,
+    /// 현재 스트림을 구독 중인 뷰어 수.
+    pub viewers: u64,
+    pub page_url: String,
+    pub stream_url: String,
+    pub thumbnail_url: String,
+}
+
+/// 디렉터리 정렬 기준.
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DirectorySort {
+    /// 현재 뷰어 수가 많은 순
+    #[default]
+    Popular,
+    /// 최근 생성 순
+    Recent,
+    /// 제목 순
+    Title,
+}
+
+/// `GET /api/directory` 쿼리 파라미터.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DirectoryQuery {
+    pub cursor: Option<String>,
+    pub limit: Option<usize>,
+    #[serde(default)]
+    pub sort: DirectorySort,
+    /// 제목, 설명, 세션 ID에 포함되어야 하는 문자열 (대소문자 무시)
+    pub q: Option<String>,
+    pub tag: Option<String>,
+}
+
+impl DirectoryQuery {
+    pub fn limit(&self) -> usize {
+        self.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT)
+    }
+
+    pub fn cursor(&self) -> Result<Option<ListCursor>, ApiError> {
+        ListCursor::parse(self.cursor.as_deref())
+    }
+
+    /// 검색어나 태그 없이 최근 순으로 조회하면 디렉터리 인덱스에서 바로 한 페이지를 읽습니다.
+    /// 그 밖에는 최근 [`MAX_SEARCHED_SESSIONS`]개를 읽어 [`Self::apply`]로 거르고 정렬합니다.
+    pub fn uses_index(&self) -> bool {
+        let present = |value: &Option<String>| {
+            value
+                .as_deref()
+                .is_some_and(|value| !value.trim().is_empty())
+        };
+        self.sort == DirectorySort::Recent && !present(&self.q) && !present(&self.tag)
+    }
+
+    /// 인덱스 순서대로 읽은 최대 `limit + 1`개의 (점수, 인덱스 멤버, 항목)에서 한 페이지를 만듭니다.
+    ///
+    /// 인덱스에서 점수가 같은 항목은 멤버 순서로 이어지므로 멤버를 커서 ID로 씁니다.
+    pub fn page_from_index(
+        &self,
+        items: Vec<(i64, String, DirectoryEntry)>,
+    ) -> (Vec<DirectoryEntry>, Option<String>) {
+        let items = items
+            .into_iter()
+            .map(|(key, member, item)| ((key, member), item))
+            .collect();
+        next_page(items, self.limit())
+    }
+
+    /// 검색, 태그 필터, 정렬 후 한 페이지를 잘라 반환합니다.
+    ///
+    /// 세션마다 다른 상세 페이지 경로(`page_url`)를 ID로 써서 같은 정렬 값끼리의 순서를 정합니다.
+    /// 제목 순 정렬은 소문자 제목을 ID 앞에 붙입니다.
+    pub fn apply(
+        &self,
+        mut items: Vec<DirectoryEntry>,
+    ) -> Result<(Vec<DirectoryEntry>, Option<String>), ApiError> {
+        let cursor = self.cursor()?;
+
+        if let Some(q) = self.q.as_deref().map(str::trim).filter(|q| !q.is_empty()) {
+            let q = q.to_lowercase();
+            let contains = |value: &Option<String>| {
+                value
+                    .as_ref()
+                    .is_some_and(|value| value.to_lowercase().contains(&q))
+            };
+            items.retain(|item| {
+                item.session_id.to_lowercase().contains(&q)
+                    || contains(&item.title)
+                    || contains(&item.description)
+            });
+        }
+
+        if let Some(tag) = self.tag.as_deref().filter(|tag| !tag.trim().is_empty()) {
+            let tag = normalize_tag(tag)?;
+            items.retain(|item| item.tags.contains(&tag));
+        }
+
+        let descending = self.sort != DirectorySort::Title;
+        let sort_key = |item: &DirectoryEntry| match self.sort {
+            DirectorySort::Popular => (
+                i64::try_from(item.viewers).unwrap_or(i64::MAX),
+                item.page_url.clone(),
+            ),
+            DirectorySort::Recent => (
+                item.created_at.map_or(0, |at| at.timestamp_millis()),
+                item.page_url.clone(),
+            ),
+            DirectorySort::Title => (
+                0,
+                format!(
+                    "{}\t{}",
+                    item.title.as_deref().unwrap_or_default().to_lowercase(),
+                    item.page_url
+                ),
+            ),
+        };
+
+        Ok(page_sorted(
+            items,
+            cursor.as_ref(),
+            self.limit(),
+            descending,
+            sort_key,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn entry(session_id: &str, title: &str, viewers: u64, age_minutes: i64) -> DirectoryEntry {
+        let session_ref = SessionRef::public(session_id);
+        DirectoryEntry {
+            user_id: None,
+            org_id: None,
+            session_id: session_id.to_string(),
+            title: Some(title.to_string()),
+            description: None,
+            tags: Vec::new(),
+            created_at: Some(Utc::now() - Duration::minutes(age_minutes)),
+            viewers,
+            page_url: session_ref.page_path(),
+            stream_url: session_ref.stream_path(),
+            thumbnail_url: session_ref.snapshot_path(),
+        }
+    }
+
+    fn ids(items: &[DirectoryEntry]) -> Vec<&str> {
+        items.iter().map(|item| item.session_id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_popular_sort_orders_by_viewers() {
+        let items = vec![
+            entry("quiet", "Quiet", 0, 1),
+            entry("busy", "Busy", 10, 30),
+            entry("mid", "Mid", 3, 5),
+        ];
+
+        let (page, _) = DirectoryQuery::default().apply(items).unwrap();
+        assert_eq!(ids(&page), vec!["busy", "mid", "quiet"]);
+    }
+
+    #[test]
+    fn test_search_matches_title_and_description() {
+        let mut described = entry("b", "Other", 0, 1);
+        described.description = Some("GitHub 배지".to_string());
+        let items = vec![entry("a", "Server status", 0, 1), described];
+
+        let query = DirectoryQuery {
+            q: Some("github".to_string()),
+            ..Default::default()
+        };
+        let (page, _) = query.apply(items.clone()).unwrap();
+        assert_eq!(ids(&page), vec!["b"]);
+
+        let query = DirectoryQuery {
+            q: Some("STATUS".to_string()),
+            sort: DirectorySort::Title,
+            ..Default::default()
+        };
+        let (page, _) = query.apply(items).unwrap();
+        assert_eq!(ids(&page), vec!["a"]);
+    }
+
+    #[test]
+    fn test_recent_sort_without_filters_uses_index() {
+        let recent = DirectoryQuery {
+            sort: DirectorySort::Recent,
+            q: Some(" ".to_string()),
+            ..Default::default()
+        };
+        assert!(recent.uses_index());
+        assert!(!DirectoryQuery::default().uses_index());
+        assert!(!DirectoryQuery {
+            tag: Some("status".to_string()),
+            ..recent.clone()
+        }
+        .uses_index());
+    }
+
+    #[test]
+    fn test_page_from_index_uses_member_as_cursor_id() {
+        let query = DirectoryQuery {
+            sort: DirectorySort::Recent,
+            limit: Some(1),
+            ..Default::default()
+        };
+        let member = |session_id: &str| serde_json::to_string(&SessionRef::public(session_id));
+        let items = vec![
+            (20, member("b").unwrap(), entry("b", "B", 0, 1)),
+            (10, member("a").unwrap(), entry("a", "A", 0, 2)),
+        ];
+
+        let (page, next_cursor) = query.page_from_index(items);
+        assert_eq!(ids(&page), vec!["b"]);
+        let cursor = ListCursor::parse(next_cursor.as_deref()).unwrap().unwrap();
+        assert_eq!(cursor.key, 20);
+        assert_eq!(cursor.id, member("b").unwrap());
+    }
+
+    #[test]
+    fn test_session_ref_paths() {
+        let public = SessionRef::public("badge");
+        assert_eq!(public.stream_path(), "/stream/badge");
+        assert_eq!(public.snapshot_path(), "/api/session/badge/snapshot");
+
+        let user = SessionRef::user("alice", "badge");
+        assert_eq!(user.page_path(), "/session/alice/badge");
+        assert_eq!(user.snapshot_path(), "/stream/alice/badge/snapshot");
+
+        let org = SessionRef::org("team", "badge");
+        assert_eq!(org.page_path(), "/org/team/session/badge");
+        assert_eq!(org.stream_path(), "/stream/org/team/badge");
+        assert_eq!(org.snapshot_path(), "/stream/org/team/badge/snapshot");
+        assert_eq!(
+            serde_json::to_string(&SessionRef::user("alice", "badge")).unwrap(),
+            r#"{"user_id":"alice","session_id":"badge"}"#
+        );
+    }
+}
@@ -1,11 +1,13 @@
 use axum::{
-    http::StatusCode,
+    http::{header, StatusCode},
     response::{IntoResponse, Response},
     Json,
 };
 use humantime::DurationError;
 use redis::RedisError;
 
+use crate::rate_limit::QuotaExceeded;
+
 #[derive(Debug)]
 pub enum ApiError {
     Argon2(argon2::Error),
@@ -14,8 +16,46 @@ pub enum ApiError {
     Json(serde_json::Error),
     SessionExists(String),
     SessionNotFound(String),
+    /// 세션을 고치는 동안 다른 요청이 계속 세션을 바꿔 저장하지 못함
+    SessionConflict,
     InvalidSessionId,
+    InvalidUserId,
+    InvalidUsername(String),
+    WeakPassword(String),
+    UserExists(String),
+    InvalidCursor(String),
+    /// 인덱스를 쓰지 않는 정렬로 조회할 수 있는 최대 세션 수를 넘음
+    TooManySessionsToSort(usize),
+    InvalidMetadata(String),
     InvalidExpire(String),
+    InvalidApiKey(String),
+    ApiKeyNotFound(String),
+    InvalidScope(String),
+    InvalidShareLink(String),
+    ShareLinkNotFound(String),
+    InvalidCollaborator(String),
+    CollaboratorNotFound(String),
+    InvalidTransfer(String),
+    TransferNotFound(String),
+    UserNotFound(String),
+    AccountDisabled(String),
+    /// 쿠키로 인증한 상태 변경 요청에 CSRF 토큰이 없거나 쿠키와 다름
+    InvalidCsrfToken,
+    InvalidAdminAction(String),
+    /// 설정된 감사 로그 저장소가 조회를 지원하지 않음
+    AuditLogUnavailable,
+    OidcNotConfigured,
+    OidcLoginFailed(String),
+    IdentityProvider(String),
+    /// 다시 시도할 수 있을 때까지 남은 초. `Retry-After` 헤더로 보냅니다.
+    RateLimited(u64),
+    /// 응답 본문에 `quota`, `limit`, `retry_after`를 함께 담습니다.
+    QuotaExceeded(QuotaExceeded),
+    InvalidOrgId,
+    InvalidOrg(String),
+    OrgExists(String),
+    OrgNotFound(String),
+    OrgMemberNotFound(String),
     InvalidDuration(humantime::DurationError),
     Unauthorized(String),
     Forbidden(String),
@@ -42,6 +82,15 @@ wrap_error!(DurationError, InvalidDuration);
 
 impl IntoResponse for ApiError {
     fn into_response(self) -> Response {
+        let retry_after = match &self {
+            ApiError::RateLimited(seconds) => Some(*seconds),
+            ApiError::QuotaExceeded(exceeded) => exceeded.retry_after,
+            _ => None,
+        };
+        let quota = match &self {
+            ApiError::QuotaExceeded(exceeded) => Some(*exceeded),
+            _ => None,
+        };
         let (status, message) = match self {
             ApiError::Argon2(err) => (
                 StatusCode::INTERNAL_SERVER_ERROR,
@@ -67,12 +116,107 @@ impl IntoResponse for ApiError {
                 StatusCode::NOT_FOUND,
                 format!("세션을 찾을 수 없습니다: {id}"),
             ),
+            ApiError::SessionConflict => (
+                StatusCode::CONFLICT,
+                "세션이 동시에 변경되었습니다. 다시 시도하세요".to_string(),
+            ),
             ApiError::InvalidSessionId => (
                 StatusCode::BAD_REQUEST,
-                "세션 ID는 비어 있을 수 없습니다".to_string(),
+                "세션 ID는 1~128자의 영문, 숫자, '.', '_', '-' 만 사용할 수 있습니다".to_string(),
+            ),
+            ApiError::InvalidUserId => (
+                StatusCode::BAD_REQUEST,
+                "사용자 ID는 1~128자의 영문, 숫자, '.', '_', '-' 만 사용할 수 있습니다".to_string(),
+            ),
+            ApiError::InvalidUsername(message) => (StatusCode::BAD_REQUEST, message),
+            ApiError::WeakPassword(message) => (StatusCode::BAD_REQUEST, message),
+            ApiError::UserExists(id) => (
+                StatusCode::CONFLICT,
+                format!("이미 존재하는 사용자입니다: {id}"),
+            ),
+            ApiError::InvalidCursor(cursor) => (
+                StatusCode::BAD_REQUEST,
+                format!("잘못된 페이지 커서입니다: {cursor}"),
             ),
+            ApiError::TooManySessionsToSort(max) => (
+                StatusCode::BAD_REQUEST,
+                format!("세션이 {max}개를 넘으면 생성 시각(created) 순으로만 조회할 수 있습니다"),
+            ),
+            ApiError::InvalidMetadata(message) => (StatusCode::BAD_REQUEST, message),
             ApiError::InvalidExpire(message) => (StatusCode::BAD_REQUEST, message),
+            ApiError::InvalidApiKey(message) => (StatusCode::BAD_REQUEST, message),
+            ApiError::InvalidScope(message) => (StatusCode::BAD_REQUEST, message),
+            ApiError::InvalidShareLink(message) => (StatusCode::BAD_REQUEST, message),
+            ApiError::ShareLinkNotFound(id) => (
+                StatusCode::NOT_FOUND,
+                format!("공유 링크를 찾을 수 없습니다: {id}"),
+            ),
+            ApiError::InvalidCollaborator(message) => (StatusCode::BAD_REQUEST, message),
+            ApiError::CollaboratorNotFound(id) => (
+                StatusCode::NOT_FOUND,
+                format!("협업자를 찾을 수 없습니다: {id}"),
+            ),
+            ApiError::InvalidTransfer(message) => (StatusCode::BAD_REQUEST, message),
+            ApiError::TransferNotFound(id) => (
+                StatusCode::NOT_FOUND,
+                format!("소유권 이전 요청을 찾을 수 없습니다: {id}"),
+            ),
+            ApiError::UserNotFound(id) => (
+                StatusCode::NOT_FOUND,
+                format!("사용자를 찾을 수 없습니다: {id}"),
+            ),
+            ApiError::AccountDisabled(id) => (
+                StatusCode::FORBIDDEN,
+                format!("비활성화된 계정입니다: {id}"),
+            ),
+            ApiError::InvalidAdminAction(message) => (StatusCode::BAD_REQUEST, message),
+            ApiError::AuditLogUnavailable => (
+                StatusCode::NOT_IMPLEMENTED,
+                "설정된 감사 로그 저장소는 조회를 지원하지 않습니다".to_string(),
+            ),
+            ApiError::OidcNotConfigured => (
+                StatusCode::NOT_FOUND,
+                "SSO 로그인이 설정되어 있지 않습니다".to_string(),
+            ),
+            ApiError::OidcLoginFailed(reason) => (
+                StatusCode::UNAUTHORIZED,
+                format!("SSO 로그인에 실패했습니다: {reason}"),
+            ),
+            ApiError::IdentityProvider(message) => (
+                StatusCode::BAD_GATEWAY,
+                format!("ID 제공자 요청 실패: {message}"),
+            ),
+            ApiError::RateLimited(seconds) => (
+                StatusCode::TOO_MANY_REQUESTS,
+                format!("요청이 너무 많습니다. {seconds}초 후에 다시 시도하세요"),
+            ),
+            ApiError::QuotaExceeded(exceeded) => (exceeded.status(), exceeded.message()),
+            ApiError::InvalidOrgId => (
+                StatusCode::BAD_REQUEST,
+                "조직 ID는 1~128자의 영문, 숫자, '.', '_', '-' 만 사용할 수 있습니다".to_string(),
+            ),
+            ApiError::InvalidOrg(message) => (StatusCode::BAD_REQUEST, message),
+            ApiError::OrgExists(id) => (
+                StatusCode::CONFLICT,
+                format!("이미 존재하는 조직입니다: {id}"),
+            ),
+            ApiError::OrgNotFound(id) => (
+                StatusCode::NOT_FOUND,
+                format!("조직을 찾을 수 없습니다: {id}"),
+            ),
+            ApiError::OrgMemberNotFound(id) => (
+                StatusCode::NOT_FOUND,
+                format!("조직 멤버를 찾을 수 없습니다: {id}"),
+            ),
+            ApiError::ApiKeyNotFound(id) => (
+                StatusCode::NOT_FOUND,
+                format!("API 키를 찾을 수 없습니다: {id}"),
+            ),
             ApiError::InvalidDuration(message) => (StatusCode::BAD_REQUEST, message.to_string()),
+            ApiError::InvalidCsrfToken => (
+                StatusCode::FORBIDDEN,
+                "CSRF 토큰이 없거나 올바르지 않습니다".to_string(),
+            ),
             ApiError::Unauthorized(message) => (StatusCode::UNAUTHORIZED, message),
             ApiError::Forbidden(message) => (StatusCode::FORBIDDEN, message),
             ApiError::InternalError(message) => (StatusCode::INTERNAL_SERVER_ERROR, message),
@@ -85,7 +229,22 @@ impl IntoResponse for ApiError {
                 format!("예상하지 못한 오류입니다: {}", message),
             ),
         };
-        let body = serde_json::json!({ "error": message });
-        (status, Json(body)).into_response()
+        let mut body = serde_json::json!({ "error": message });
+        if let Some(exceeded) = quota {
+            body["quota"] = serde_json::json!(exceeded.quota);
+            body["limit"] = serde_json::json!(exceeded.limit);
+            if let Some(seconds) = exceeded.retry_after {
+                body["retry_after"] = serde_json::json!(seconds);
+            }
+        }
+        match retry_after {
+            Some(seconds) => (
+                status,
+                [(header::RETRY_AFTER, seconds.to_string())],
+                Json(body),
+            )
+                .into_response(),
+            None => (status, Json(body)).into_response(),
+        }
     }
 }
@@ -3,8 +3,86 @@ use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header,
 use serde::{Deserialize, Serialize};
 
 use crate::errors::ApiError;
+use crate::org::OrgMemberships;
+use crate::scope::{Scope, SessionAccess};
+use crate::signing_key::SigningAlgorithm;
 
-const ISSUER: &str = "realtime-svg";
+/// `iss` claim of every token this server signs
+pub const ISSUER: &str = "realtime-svg";
+
+/// Server-side token issuing policy
+///
+/// # Fields
+/// - `access_ttl_seconds`: Access token lifetime when the client does not ask for one
+/// - `max_access_ttl_seconds`: Upper bound for a client-requested `ttl_seconds`
+/// - `refresh_ttl_seconds`: Refresh token lifetime, renewed on every rotation
+/// - `scoped_ttl_seconds`: Scoped (per-session) token lifetime when the owner does not ask for one
+/// - `max_scoped_ttl_seconds`: Upper bound for a requested scoped token lifetime
+/// - `signing_algorithm`: Algorithm for newly generated signing keys; existing keys
+///   keep theirs until rotated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenPolicy {
+    pub access_ttl_seconds: u64,
+    pub max_access_ttl_seconds: u64,
+    pub refresh_ttl_seconds: u64,
+    pub scoped_ttl_seconds: u64,
+    pub max_scoped_ttl_seconds: u64,
+    pub signing_algorithm: SigningAlgorithm,
+}
+
+impl TokenPolicy {
+    /// Resolves the access token TTL for a request, clamped to `1..=max_access_ttl_seconds`
+    pub fn access_ttl(&self, requested: Option<u64>) -> u64 {
+        requested
+            .unwrap_or(self.access_ttl_seconds)
+            .clamp(1, self.max_access_ttl_seconds)
+    }
+
+    /// Resolves the scoped token TTL for a request, clamped to `1..=max_scoped_ttl_seconds`
+    pub fn scoped_ttl(&self, requested: Option<u64>) -> u64 {
+        requested
+            .unwrap_or(self.scoped_ttl_seconds)
+            .clamp(1, self.max_scoped_ttl_seconds)
+    }
+
+    /// Longest lifetime of any token this policy issues
+    pub fn max_token_ttl_seconds(&self) -> u64 {
+        self.max_access_ttl_seconds.max(self.max_scoped_ttl_seconds)
+    }
+}
+
+impl Default for TokenPolicy {
+    fn default() -> Self {
+        Self {
+            access_ttl_seconds: 15 * 60,
+            max_access_ttl_seconds: 60 * 60,
+            refresh_ttl_seconds: 30 * 24 * 60 * 60,
+            scoped_ttl_seconds: 24 * 60 * 60,
+            max_scoped_ttl_seconds: 30 * 24 * 60 * 60,
+            signing_algorithm: SigningAlgorithm::default(),
+        }
+    }
+}
+
+/// Private key used to sign new tokens, identified by the `kid` header
+///
+/// The matching public key is published in JWKS under the same `kid`.
+/// `algorithm` is one of RS256, ES256 or EdDSA (see [`crate::signing_key::SigningAlgorithm`]).
+#[derive(Clone)]
+pub struct SigningKey {
+    pub kid: String,
+    pub algorithm: Algorithm,
+    pub encoding_key: EncodingKey,
+}
+
+impl std::fmt::Debug for SigningKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SigningKey")
+            .field("kid", &self.kid)
+            .field("algorithm", &self.algorithm)
+            .finish_non_exhaustive()
+    }
+}
 
 /// JWT Claims structure following RFC 7519 standard
 ///
@@ -13,29 +91,138 @@ const ISSUER: &str = "realtime-svg";
 /// - `exp` (expiration): Unix timestamp when token expires
 /// - `iat` (issued at): Unix timestamp when token was issued
 /// - `iss` (issuer): System identifier (always "realtime-svg")
+/// - `gen` (token generation): Must match the user's current `token_generation`;
+///   bumping it (password change, log out everywhere) revokes every older token
+/// - `jti` (JWT ID): Unique token identifier used to revoke a single token on logout;
+///   tokens issued before it was introduced have none
+/// - `scopes`: Restricts the token to the listed session permissions (see [`crate::scope`]);
+///   login tokens have none and may act on everything their user owns
+/// - `orgs`: Organization memberships (org id to role) when the token was issued, so
+///   org session routes can authorize without a lookup; only login tokens carry them
+/// - `admin`: Whether the user was an administrator when the token was issued; only
+///   login tokens carry it, and [`crate::auth::AdminUser`] re-checks the account
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Claims {
     pub sub: String,
     pub exp: usize,
     pub iat: usize,
     pub iss: String,
+    #[serde(default, rename = "gen")]
+    pub generation: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jti: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<Scope>>,
+    #[serde(default, skip_serializing_if = "OrgMemberships::is_empty")]
+    pub orgs: OrgMemberships,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub admin: bool,
 }
 
-/// Creates a JWT token for a user with RSA-2048 signature
+/// Account-wide grants carried by login tokens (`orgs` and `admin` claims)
+///
+/// Scoped and viewer tokens never carry them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccountClaims {
+    pub orgs: OrgMemberships,
+    pub admin: bool,
+}
+
+/// Creates a JWT token for a user, signed with the given key
 ///
 /// # Arguments
 /// - `user_id`: User identifier to be stored in the `sub` claim
-/// - `encoding_key`: RSA private key for signing (from JWK cache)
-/// - `ttl_seconds`: Optional TTL in seconds (default: 24 hours)
+/// - `token_generation`: User's current token generation, stored in the `gen` claim
+/// - `account`: User's current organization memberships and admin role, stored in the
+///   `orgs` and `admin` claims
+/// - `signing_key`: Active private key for signing (from the signing key cache)
+/// - `ttl_seconds`: Token lifetime in seconds, already clamped by [`TokenPolicy`]
 ///
 /// # Returns
 /// Signed JWT token string in format: `header.payload.signature`
 ///
 /// # Algorithm
-/// Uses RS256 (RSA with SHA-256) for signature generation
+/// Uses the signing key's algorithm (RS256, ES256 or EdDSA). The header carries the
+/// signing key's `kid` so verifiers can pick the matching key from JWKS.
 pub fn create_token(
     user_id: &str,
-    encoding_key: &EncodingKey,
+    token_generation: u64,
+    account: AccountClaims,
+    signing_key: &SigningKey,
+    ttl_seconds: u64,
+) -> Result<String, ApiError> {
+    encode_token(
+        user_id,
+        token_generation,
+        new_jti(),
+        None,
+        account,
+        signing_key,
+        ttl_seconds,
+    )
+}
+
+/// Creates a JWT restricted to `scopes`, signed with the given key
+///
+/// Same as [`create_token`], but the token only grants the listed session permissions
+/// and carries no organization memberships or admin role.
+/// `ttl_seconds` should be clamped by [`TokenPolicy::scoped_ttl`].
+pub fn create_scoped_token(
+    user_id: &str,
+    token_generation: u64,
+    scopes: Vec<Scope>,
+    signing_key: &SigningKey,
+    ttl_seconds: u64,
+) -> Result<String, ApiError> {
+    encode_token(
+        user_id,
+        token_generation,
+        new_jti(),
+        Some(scopes),
+        AccountClaims::default(),
+        signing_key,
+        ttl_seconds,
+    )
+}
+
+/// Creates a viewer token for one share link of a private session
+///
+/// The token is restricted to `session:{user_id}/{session_id}:view` and its `jti` is the
+/// share link id, so deleting the link revokes the token.
+pub fn create_viewer_token(
+    user_id: &str,
+    token_generation: u64,
+    session_id: &str,
+    link_id: &str,
+    signing_key: &SigningKey,
+    ttl_seconds: u64,
+) -> Result<String, ApiError> {
+    encode_token(
+        user_id,
+        token_generation,
+        link_id.to_string(),
+        Some(vec![Scope::session(
+            user_id,
+            session_id,
+            SessionAccess::View,
+        )]),
+        AccountClaims::default(),
+        signing_key,
+        ttl_seconds,
+    )
+}
+
+fn new_jti() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+fn encode_token(
+    user_id: &str,
+    token_generation: u64,
+    jti: String,
+    scopes: Option<Vec<Scope>>,
+    account: AccountClaims,
+    signing_key: &SigningKey,
     ttl_seconds: u64,
 ) -> Result<String, ApiError> {
     let now = Utc::now();
@@ -46,10 +233,16 @@ pub fn create_token(
         exp: exp.timestamp() as usize,
         iat: now.timestamp() as usize,
         iss: ISSUER.to_string(),
+        generation: token_generation,
+        jti: Some(jti),
+        scopes,
+        orgs: account.orgs,
+        admin: account.admin,
     };
 
-    let header = Header::new(Algorithm::RS256);
-    let token = encode(&header, &claims, encoding_key)
+    let mut header = Header::new(signing_key.algorithm);
+    header.kid = Some(signing_key.kid.clone());
+    let token = encode(&header, &claims, &signing_key.encoding_key)
         .map_err(|e| ApiError::InternalError(format!("Failed to encode JWT: {}", e)))?;
 
     tracing::info!("Issued JWT token for user: {}", user_id);
@@ -60,13 +253,15 @@ pub fn create_token(
 ///
 /// # Arguments
 /// - `token`: JWT token string to verify
-/// - `decoding_key`: RSA public key for verification (from JWK cache)
+/// - `decoding_key`: Public key for verification (from the signing key cache)
+/// - `algorithm`: Algorithm of that key. Taken from the stored key, never from the
+///   token header, so a token cannot pick a weaker algorithm for itself
 ///
 /// # Returns
 /// Decoded claims if token is valid
 ///
 /// # Validation Rules
-/// - Signature must match RSA public key (RS256 algorithm)
+/// - Signature must match the public key with `algorithm`
 /// - Issuer (`iss`) must be "realtime-svg"
 /// - Expiration (`exp`) must be in the future (with 60s leeway)
 /// - Token structure must be valid JWT format
@@ -77,8 +272,12 @@ pub fn create_token(
 /// - Token has expired
 /// - Issuer doesn't match
 /// - Token format is malformed
-pub fn verify_token(token: &str, decoding_key: &DecodingKey) -> Result<Claims, ApiError> {
-    let mut validation = Validation::new(Algorithm::RS256);
+pub fn verify_token(
+    token: &str,
+    decoding_key: &DecodingKey,
+    algorithm: Algorithm,
+) -> Result<Claims, ApiError> {
+    let mut validation = Validation::new(algorithm);
     validation.set_issuer(&[ISSUER]);
 
     let token_data = decode::<Claims>(token, decoding_key, &validation).map_err(|e| {
@@ -92,41 +291,162 @@ pub fn verify_token(token: &str, decoding_key: &DecodingKey) -> Result<Claims, A
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::org::OrgRole;
     use jsonwebtoken::{DecodingKey, EncodingKey};
     use rsa::pkcs1::{EncodeRsaPrivateKey, EncodeRsaPublicKey};
     use rsa::RsaPrivateKey;
 
-    fn generate_test_keys() -> (EncodingKey, DecodingKey) {
+    fn generate_test_keys() -> (SigningKey, DecodingKey) {
         let rsa_key = RsaPrivateKey::new(&mut rand::thread_rng(), 2048).unwrap();
         let private_pem = rsa_key.to_pkcs1_pem(rsa::pkcs1::LineEnding::LF).unwrap();
         let public_key = rsa_key.to_public_key();
         let public_pem = public_key.to_pkcs1_pem(rsa::pkcs1::LineEnding::LF).unwrap();
 
-        let encoding_key = EncodingKey::from_rsa_pem(private_pem.as_bytes()).unwrap();
+        let signing_key = SigningKey {
+            kid: "test-key".to_string(),
+            algorithm: Algorithm::RS256,
+            encoding_key: EncodingKey::from_rsa_pem(private_pem.as_bytes()).unwrap(),
+        };
         let decoding_key = DecodingKey::from_rsa_pem(public_pem.as_bytes()).unwrap();
 
-        (encoding_key, decoding_key)
+        (signing_key, decoding_key)
     }
 
     #[test]
     fn test_create_token() {
         let (encoding_key, _) = generate_test_keys();
-        let token = create_token("test_user", &encoding_key, 3600).unwrap();
+        let token = create_token(
+            "test_user",
+            0,
+            AccountClaims::default(),
+            &encoding_key,
+            3600,
+        )
+        .unwrap();
 
         assert!(!token.is_empty());
         assert!(token.contains('.'));
     }
 
+    #[test]
+    fn test_token_header_has_kid() {
+        let (signing_key, _) = generate_test_keys();
+        let token =
+            create_token("test_user", 0, AccountClaims::default(), &signing_key, 3600).unwrap();
+
+        let header = jsonwebtoken::decode_header(&token).unwrap();
+        assert_eq!(header.kid.as_deref(), Some("test-key"));
+    }
+
     #[test]
     fn test_verify_valid_token() {
         let (encoding_key, decoding_key) = generate_test_keys();
-        let token = create_token("test_user", &encoding_key, 3600).unwrap();
+        let token = create_token(
+            "test_user",
+            0,
+            AccountClaims::default(),
+            &encoding_key,
+            3600,
+        )
+        .unwrap();
 
-        let claims = verify_token(&token, &decoding_key).unwrap();
+        let claims = verify_token(&token, &decoding_key, Algorithm::RS256).unwrap();
         assert_eq!(claims.sub, "test_user");
         assert_eq!(claims.iss, ISSUER);
     }
 
+    #[test]
+    fn test_scoped_token_round_trip() {
+        let (encoding_key, decoding_key) = generate_test_keys();
+        let scopes = vec![Scope::session("test_user", "sensor", SessionAccess::Write)];
+        let token =
+            create_scoped_token("test_user", 0, scopes.clone(), &encoding_key, 3600).unwrap();
+
+        let claims = verify_token(&token, &decoding_key, Algorithm::RS256).unwrap();
+        assert_eq!(claims.scopes, Some(scopes));
+
+        let token = create_token(
+            "test_user",
+            0,
+            AccountClaims::default(),
+            &encoding_key,
+            3600,
+        )
+        .unwrap();
+        let claims = verify_token(&token, &decoding_key, Algorithm::RS256).unwrap();
+        assert_eq!(claims.scopes, None);
+    }
+
+    #[test]
+    fn test_account_claims_round_trip() {
+        let (encoding_key, decoding_key) = generate_test_keys();
+        let orgs = OrgMemberships::from([("design".to_string(), OrgRole::Member)]);
+        let account = AccountClaims {
+            orgs: orgs.clone(),
+            admin: true,
+        };
+        let token = create_token("test_user", 0, account, &encoding_key, 3600).unwrap();
+
+        let claims = verify_token(&token, &decoding_key, Algorithm::RS256).unwrap();
+        assert_eq!(claims.orgs, orgs);
+        assert!(claims.admin);
+
+        let scopes = vec![Scope::session("test_user", "sensor", SessionAccess::Read)];
+        let token = create_scoped_token("test_user", 0, scopes, &encoding_key, 3600).unwrap();
+        let claims = verify_token(&token, &decoding_key, Algorithm::RS256).unwrap();
+        assert!(claims.orgs.is_empty());
+        assert!(!claims.admin);
+    }
+
+    #[test]
+    fn test_viewer_token_uses_link_id_as_jti() {
+        let (encoding_key, decoding_key) = generate_test_keys();
+        let token =
+            create_viewer_token("test_user", 0, "private-1", "link-1", &encoding_key, 60).unwrap();
+
+        let claims = verify_token(&token, &decoding_key, Algorithm::RS256).unwrap();
+        assert_eq!(claims.jti.as_deref(), Some("link-1"));
+        assert_eq!(
+            claims.scopes,
+            Some(vec![Scope::session(
+                "test_user",
+                "private-1",
+                SessionAccess::View
+            )])
+        );
+    }
+
+    #[test]
+    fn test_each_token_has_unique_jti() {
+        let (encoding_key, decoding_key) = generate_test_keys();
+        let a = create_token(
+            "test_user",
+            0,
+            AccountClaims::default(),
+            &encoding_key,
+            3600,
+        )
+        .unwrap();
+        let b = create_token(
+            "test_user",
+            0,
+            AccountClaims::default(),
+            &encoding_key,
+            3600,
+        )
+        .unwrap();
+
+        let a = verify_token(&a, &decoding_key, Algorithm::RS256)
+            .unwrap()
+            .jti
+            .unwrap();
+        let b = verify_token(&b, &decoding_key, Algorithm::RS256)
+            .unwrap()
+            .jti
+            .unwrap();
+        assert_ne!(a, b);
+    }
+
     #[test]
     fn test_verify_token_expiration() {
         let (encoding_key, decoding_key) = generate_test_keys();
@@ -139,22 +459,57 @@ mod tests {
             exp: exp.timestamp() as usize,
             iat: now.timestamp() as usize,
             iss: ISSUER.to_string(),
+            generation: 0,
+            jti: None,
+            scopes: None,
+            orgs: OrgMemberships::new(),
+            admin: false,
         };
 
         let header = Header::new(Algorithm::RS256);
-        let token = encode(&header, &claims, &encoding_key).unwrap();
+        let token = encode(&header, &claims, &encoding_key.encoding_key).unwrap();
 
-        let result = verify_token(&token, &decoding_key);
+        let result = verify_token(&token, &decoding_key, Algorithm::RS256);
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_access_ttl_is_clamped_to_policy() {
+        let policy = TokenPolicy::default();
+
+        assert_eq!(policy.access_ttl(None), policy.access_ttl_seconds);
+        assert_eq!(policy.access_ttl(Some(60)), 60);
+        assert_eq!(policy.access_ttl(Some(0)), 1);
+        assert_eq!(
+            policy.access_ttl(Some(365 * 24 * 60 * 60)),
+            policy.max_access_ttl_seconds
+        );
+    }
+
+    #[test]
+    fn test_verify_rejects_other_algorithm() {
+        let (signing_key, decoding_key) = generate_test_keys();
+        let token =
+            create_token("test_user", 0, AccountClaims::default(), &signing_key, 3600).unwrap();
+
+        assert!(verify_token(&token, &decoding_key, Algorithm::PS256).is_err());
+        assert!(verify_token(&token, &decoding_key, Algorithm::ES256).is_err());
+    }
+
     #[test]
     fn test_verify_invalid_signature() {
         let (encoding_key1, _) = generate_test_keys();
         let (_, decoding_key2) = generate_test_keys();
 
-        let token = create_token("test_user", &encoding_key1, 3600).unwrap();
-        let result = verify_token(&token, &decoding_key2);
+        let token = create_token(
+            "test_user",
+            0,
+            AccountClaims::default(),
+            &encoding_key1,
+            3600,
+        )
+        .unwrap();
+        let result = verify_token(&token, &decoding_key2, Algorithm::RS256);
 
         assert!(result.is_err());
     }
@@ -0,0 +1,588 @@
+//! Redis 키 / pubsub 채널 이름과 키에 들어가는 식별자 검증.
+//!
+//! 사용자가 정한 ID가 그대로 Redis 키가 되면 내부 키(RSA 개인키 등)나 다른
+//! 네임스페이스와 충돌할 수 있으므로, 모든 키는 이 모듈을 통해서만 만듭니다.
+
+use crate::{directory::SessionRef, errors::ApiError};
+
+/// 세션 ID / 사용자 ID의 최대 길이.
+pub const MAX_ID_LEN: usize = 128;
+
+/// 공용 세션 키와 채널의 prefix (배포 prefix 뒤에 붙습니다).
+pub const PUBLIC_SESSION_PREFIX: &str = "session:";
+
+/// [`Keys`]가 만드는 키의 첫 부분. 배포 prefix가 이 중 하나이면 그 배포의 키가
+/// prefix 없는 배포의 키와 겹칠 수 있으므로 prefix로 쓸 수 없습니다.
+/// 예를 들어 prefix `session`인 배포의 `session:directory`는 prefix 없는 배포에서
+/// ID가 `directory`인 공용 세션 키입니다.
+const RESERVED_KEY_NAMESPACES: &[&str] = &[
+    "api_key",
+    "audit_log",
+    "directory",
+    "login_attempts",
+    "login_failures",
+    "login_lockout",
+    "metrics",
+    "oidc_login",
+    "org",
+    "refresh",
+    "refresh_family",
+    "revoked_token",
+    "session",
+    "session_updates",
+    "share_link",
+    "stream_slots",
+    "user",
+];
+
+/// 배포별 prefix가 적용된 Redis 키 생성기.
+///
+/// 하나의 Redis 데이터베이스를 여러 배포가 함께 쓸 수 있도록 모든 키와 pubsub
+/// 채널 앞에 `key_prefix` 설정값을 붙입니다. prefix가 비어 있으면 기존 키
+/// 이름을 그대로 사용합니다.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Keys {
+    prefix: String,
+}
+
+impl Keys {
+    /// 비어 있지 않은 prefix는 `:`로 끝나도록 맞춥니다.
+    pub fn new(prefix: impl Into<String>) -> Self {
+        let mut prefix = prefix.into();
+        if !prefix.is_empty() && !prefix.ends_with(':') {
+            prefix.push(':');
+        }
+        Self { prefix }
+    }
+
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    fn key(&self, name: impl std::fmt::Display) -> String {
+        format!("{}{}", self.prefix, name)
+    }
+
+    /// kid 도입 이전의 단일 서명 키. 첫 서명 키로 가져오기 위해 읽기만 합니다.
+    pub fn rsa_private_pem(&self) -> String {
+        self.key(".realtime-svg:rsa:private_pem")
+    }
+
+    pub fn rsa_public_pem(&self) -> String {
+        self.key(".realtime-svg:rsa:public_pem")
+    }
+
+    /// 서명 키 (`StoredSigningKey` JSON). 교체된 키에는 TTL이 걸립니다.
+    pub fn signing_key(&self, kid: &str) -> String {
+        self.key(format_args!(".realtime-svg:jwk:key:{}", kid))
+    }
+
+    /// 서명 키 인덱스 (sorted set, member = kid, score = 생성 시각 ms).
+    pub fn signing_key_index(&self) -> String {
+        self.key(".realtime-svg:jwk:keys")
+    }
+
+    /// 새 토큰에 서명하는 키의 kid.
+    pub fn active_signing_key(&self) -> String {
+        self.key(".realtime-svg:jwk:active")
+    }
+
+    /// 예약된 키 교체를 한 인스턴스만 실행하도록 잡는 잠금.
+    pub fn signing_key_rotation_lock(&self) -> String {
+        self.key(".realtime-svg:jwk:rotation_lock")
+    }
+
+    /// 예전에 모든 계정이 함께 쓰던 비밀번호 salt. 더 이상 만들지 않고 읽기만 합니다.
+    pub fn password_salt(&self) -> String {
+        self.key(".realtime-svg:password_salt")
+    }
+
+    /// 공용 세션 키 prefix 이전 마이그레이션을 끝까지 마쳤는지 표시하는 키.
+    pub fn migration_public_session_prefix(&self) -> String {
+        self.key(".realtime-svg:migration:public_session_prefix:done")
+    }
+
+    /// 세션 인덱스가 생기기 전에 만든 세션을 인덱스에 채우는 마이그레이션을 끝까지 마쳤는지 표시하는 키.
+    pub fn migration_session_index(&self) -> String {
+        self.key(".realtime-svg:migration:session_index:done")
+    }
+
+    /// 마이그레이션을 한 인스턴스만 실행하도록 잡는 잠금 (짧은 TTL).
+    pub fn migration_lock(&self, migration: &str) -> String {
+        self.key(format_args!(".realtime-svg:migration:{}:lock", migration))
+    }
+
+    /// 공개 디렉터리 인덱스 (sorted set, member = `SessionRef` JSON, score = 세션 생성 시각 ms).
+    pub fn directory(&self) -> String {
+        self.key("directory")
+    }
+
+    /// 공용 세션 데이터 키. 스트림 pubsub 채널 이름으로도 사용합니다.
+    pub fn public_session(&self, session_id: &str) -> String {
+        self.key(format_args!("{}{}", PUBLIC_SESSION_PREFIX, session_id))
+    }
+
+    /// 사용자 세션 데이터 키. 스트림 pubsub 채널 이름으로도 사용합니다.
+    pub fn user_session(&self, user_id: &str, session_id: &str) -> String {
+        self.key(format_args!("user:{}:session:{}", user_id, session_id))
+    }
+
+    /// 사용자별 세션 인덱스 (sorted set, score = 생성 시각 ms).
+    ///
+    /// 세션 키 자체는 TTL로 만료되므로 인덱스에는 이미 만료된 세션이 남아 있을 수
+    /// 있습니다. 조회 시 존재하지 않는 멤버는 인덱스에서 제거합니다.
+    pub fn user_session_index(&self, user_id: &str) -> String {
+        self.key(format_args!("user:{}:sessions", user_id))
+    }
+
+    pub fn user_data(&self, user_id: &str) -> String {
+        self.key(format_args!("user:{}:data", user_id))
+    }
+
+    /// 삭제된 계정의 다음 토큰 세대. 같은 ID로 다시 가입한 계정은 이 세대부터 시작하므로
+    /// 삭제 전에 발급된 토큰이 새 계정에 통하지 않습니다. 만료되지 않습니다.
+    pub fn user_token_epoch(&self, user_id: &str) -> String {
+        self.key(format_args!("user:{}:token_epoch", user_id))
+    }
+
+    /// refresh 토큰 기록 (`RefreshTokenRecord` JSON). 토큰 원문이 아닌 해시를 씁니다.
+    pub fn refresh_token(&self, token_hash: &str) -> String {
+        self.key(format_args!("refresh:{}", token_hash))
+    }
+
+    /// refresh 토큰 family에서 현재 유효한 토큰의 해시. 지우면 family 전체가 폐기됩니다.
+    pub fn refresh_token_family(&self, family: &str) -> String {
+        self.key(format_args!("refresh_family:{}", family))
+    }
+
+    /// 사용자별 refresh 토큰 family 인덱스 (set, member = family).
+    ///
+    /// 비밀번호 변경, 모든 기기 로그아웃, 비활성화, 계정 삭제 때 family를 모두 지우는 데 씁니다.
+    /// 이미 폐기된 family가 남아 있을 수 있으며, 마지막 토큰 발급부터 refresh 토큰 수명이 지나면 만료됩니다.
+    pub fn user_refresh_families(&self, user_id: &str) -> String {
+        self.key(format_args!("user:{}:refresh_families", user_id))
+    }
+
+    /// 진행 중인 SSO 로그인 (`OidcLoginState` JSON). 콜백에서 한 번만 꺼내 씁니다.
+    pub fn oidc_login(&self, state: &str) -> String {
+        self.key(format_args!("oidc_login:{}", state))
+    }
+
+    /// 사용자 ID별 최근 로그인 시도 (sorted set, score = 시도 시각 ms).
+    pub fn login_attempts_user(&self, user_id: &str) -> String {
+        self.key(format_args!("login_attempts:user:{}", user_id))
+    }
+
+    /// IP별 최근 로그인 시도 (sorted set, score = 시도 시각 ms).
+    pub fn login_attempts_ip(&self, ip: &str) -> String {
+        self.key(format_args!("login_attempts:ip:{}", ip))
+    }
+
+    /// 사용자 ID의 연속 로그인 실패 횟수. 로그인에 성공하면 지웁니다.
+    pub fn login_failures(&self, user_id: &str) -> String {
+        self.key(format_args!("login_failures:{}", user_id))
+    }
+
+    /// 잠긴 사용자 ID. 잠금 시간이 TTL입니다.
+    pub fn login_lockout(&self, user_id: &str) -> String {
+        self.key(format_args!("login_lockout:{}", user_id))
+    }
+
+    /// 로그인 거절 이유별 누적 횟수 (hash, field = [`crate::rate_limit::LoginRejection`]).
+    pub fn login_rejections(&self) -> String {
+        self.key("metrics:login_rejections")
+    }
+
+    /// 감사 로그 (stream, 항목마다 `event` 필드에 [`crate::audit::AuditEvent`] JSON).
+    pub fn audit_log(&self) -> String {
+        self.key("audit_log")
+    }
+
+    /// 세션의 최근 업데이트 (sorted set, score = 업데이트 시각 ms).
+    pub fn session_updates(&self, session_ref: &SessionRef) -> String {
+        match (&session_ref.user_id, &session_ref.org_id) {
+            (Some(user_id), _) => self.key(format_args!(
+                "session_updates:user:{}:{}",
+                user_id, session_ref.session_id
+            )),
+            (None, Some(org_id)) => self.key(format_args!(
+                "session_updates:org:{}:{}",
+                org_id, session_ref.session_id
+            )),
+            (None, None) => self.key(format_args!(
+                "session_updates:public:{}",
+                session_ref.session_id
+            )),
+        }
+    }
+
+    /// IP별로 열려 있는 스트림 (sorted set, member = 스트림 ID, score = 자리 임대 만료 시각 ms).
+    pub fn stream_slots_ip(&self, ip: &str) -> String {
+        self.key(format_args!("stream_slots:ip:{}", ip))
+    }
+
+    /// API 키 기록 (`ApiKeyRecord` JSON). 키 원문이 아닌 해시를 씁니다.
+    pub fn api_key(&self, key_hash: &str) -> String {
+        self.key(format_args!("api_key:{}", key_hash))
+    }
+
+    /// 사용자별 API 키 인덱스 (hash, field = key_id, value = 키 해시).
+    ///
+    /// 만료된 키의 기록은 TTL로 사라지므로, 조회 시 기록이 없는 항목은 인덱스에서 제거합니다.
+    pub fn user_api_keys(&self, user_id: &str) -> String {
+        self.key(format_args!("user:{}:api_keys", user_id))
+    }
+
+    /// 공유 링크 기록 (`ShareLinkRecord` JSON). 링크가 만료되는 시각에 함께 만료됩니다.
+    pub fn share_link(&self, link_id: &str) -> String {
+        self.key(format_args!("share_link:{}", link_id))
+    }
+
+    /// 사용자별 공유 링크 인덱스 (hash, field = link_id, value = session_id).
+    ///
+    /// 세션 키 prefix(`user:{id}:session:`)와 겹치지 않아야 계정 삭제 시 SCAN에 섞이지 않습니다.
+    /// 만료된 링크의 기록은 TTL로 사라지므로, 조회 시 기록이 없는 항목은 인덱스에서 제거합니다.
+    pub fn user_share_links(&self, user_id: &str) -> String {
+        self.key(format_args!("user:{}:share_links", user_id))
+    }
+
+    /// 사용자가 협업자로 추가된 세션 인덱스 (hash, field = `{owner}/{session_id}`, value = 역할).
+    ///
+    /// 세션 쪽 협업자 목록이 기준이며, 조회 시 세션이 사라졌거나 더 이상 협업자가 아닌 항목은
+    /// 인덱스에서 제거합니다.
+    pub fn user_shared_sessions(&self, user_id: &str) -> String {
+        self.key(format_args!("user:{}:shared_sessions", user_id))
+    }
+
+    /// 사용자에게 온 소유권 이전 요청 인덱스 (hash, field = `{owner}/{session_id}`, value = 만료 시각).
+    ///
+    /// 세션 쪽 `pending_transfer`가 기준이며, 조회 시 세션이 사라졌거나 요청이 취소·만료된 항목은
+    /// 인덱스에서 제거합니다.
+    pub fn user_incoming_transfers(&self, user_id: &str) -> String {
+        self.key(format_args!("user:{}:incoming_transfers", user_id))
+    }
+
+    /// 다른 사용자에게 이전된 세션의 새 소유자. 예전 스트림 URL을 새 URL로 보내는 데 씁니다.
+    ///
+    /// 세션 키 prefix(`user:{id}:session:`)와 겹치지 않아 계정 삭제 시 SCAN에 섞이지 않습니다.
+    pub fn moved_session(&self, user_id: &str, session_id: &str) -> String {
+        self.key(format_args!("user:{}:moved:{}", user_id, session_id))
+    }
+
+    /// 조직 데이터 (`OrgData` JSON). 만료되지 않습니다.
+    pub fn org_data(&self, org_id: &str) -> String {
+        self.key(format_args!("org:{}:data", org_id))
+    }
+
+    /// 조직 세션 데이터 키. 스트림 pubsub 채널 이름으로도 사용합니다.
+    pub fn org_session(&self, org_id: &str, session_id: &str) -> String {
+        self.key(format_args!("org:{}:session:{}", org_id, session_id))
+    }
+
+    /// 조직별 세션 인덱스 (sorted set, score = 생성 시각 ms). 사용자 세션 인덱스와 같이 정리됩니다.
+    pub fn org_session_index(&self, org_id: &str) -> String {
+        self.key(format_args!("org:{}:sessions", org_id))
+    }
+
+    /// 사용자가 속한 조직 인덱스 (hash, field = org_id, value = 역할).
+    ///
+    /// 조직 데이터의 멤버 목록이 기준이며, 조회 시 조직이 사라졌거나 더 이상 멤버가 아닌 항목은
+    /// 인덱스에서 제거합니다.
+    pub fn user_orgs(&self, user_id: &str) -> String {
+        self.key(format_args!("user:{}:orgs", user_id))
+    }
+
+    /// 로그아웃으로 폐기된 access 토큰의 `jti`. 토큰이 만료되는 시각까지만 남습니다.
+    pub fn revoked_token(&self, jti: &str) -> String {
+        self.key(format_args!("revoked_token:{}", jti))
+    }
+
+    /// 모든 계정 데이터 키를 찾는 SCAN 패턴. 결과는 [`Self::parse_user_data`]로 걸러야 합니다.
+    pub fn user_data_pattern(&self) -> String {
+        self.key("user:*:data")
+    }
+
+    /// [`Self::user_data`] 키이면 사용자 ID를 반환합니다.
+    pub fn parse_user_data<'a>(&self, key: &'a str) -> Option<&'a str> {
+        let user_id = key
+            .strip_prefix(self.prefix.as_str())?
+            .strip_prefix("user:")?
+            .strip_suffix(":data")?;
+        is_valid_id(user_id).then_some(user_id)
+    }
+
+    /// 모든 조직 데이터 키를 찾는 SCAN 패턴. 결과는 [`Self::parse_org_data`]로 걸러야 합니다.
+    pub fn org_data_pattern(&self) -> String {
+        self.key("org:*:data")
+    }
+
+    /// [`Self::org_data`] 키이면 조직 ID를 반환합니다.
+    pub fn parse_org_data<'a>(&self, key: &'a str) -> Option<&'a str> {
+        let org_id = key
+            .strip_prefix(self.prefix.as_str())?
+            .strip_prefix("org:")?
+            .strip_suffix(":data")?;
+        is_valid_id(org_id).then_some(org_id)
+    }
+
+    /// 공용, 사용자, 조직 세션 키를 모두 찾는 SCAN 패턴. 결과는 [`Self::parse_session`]으로 걸러야 합니다.
+    pub fn session_patterns(&self) -> [String; 3] {
+        [
+            self.key(format_args!("{}*", PUBLIC_SESSION_PREFIX)),
+            self.key("user:*:session:*"),
+            self.key("org:*:session:*"),
+        ]
+    }
+
+    /// 세션 데이터 키이면 그 세션을 가리키는 참조를 반환합니다.
+    pub fn parse_session(&self, key: &str) -> Option<SessionRef> {
+        let name = key.strip_prefix(self.prefix.as_str())?;
+        let session_ref = if let Some(session_id) = name.strip_prefix(PUBLIC_SESSION_PREFIX) {
+            SessionRef::public(session_id)
+        } else if let Some(rest) = name.strip_prefix("user:") {
+            let (user_id, session_id) = rest.split_once(":session:")?;
+            if !is_valid_id(user_id) {
+                return None;
+            }
+            SessionRef::user(user_id, session_id)
+        } else {
+            let (org_id, session_id) = name.strip_prefix("org:")?.split_once(":session:")?;
+            if !is_valid_id(org_id) {
+                return None;
+            }
+            SessionRef::org(org_id, session_id)
+        };
+        is_valid_id(&session_ref.session_id).then_some(session_ref)
+    }
+}
+
+/// 키 prefix로 쓸 수 있는지. 비어 있거나 ID와 같은 규칙을 따라야 합니다.
+///
+/// `:`를 허용하면 `a:user:bob` 같은 prefix가 prefix `a`인 배포의 키와 겹칩니다.
+/// 같은 이유로 prefix 없는 배포의 키 네임스페이스(`session`, `user`, `.realtime-svg` 등)도
+/// 쓸 수 없습니다.
+pub fn is_valid_key_prefix(prefix: &str) -> bool {
+    prefix.is_empty()
+        || (is_valid_id(prefix)
+            && !prefix.starts_with('.')
+            && !RESERVED_KEY_NAMESPACES.contains(&prefix))
+}
+
+/// 1~128자의 영문, 숫자, `.`, `_`, `-` 만 허용합니다.
+///
+/// `:`가 허용되지 않으므로 어떤 ID도 다른 네임스페이스의 키를 만들 수 없습니다.
+fn is_valid_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= MAX_ID_LEN
+        && id
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'_' | b'-'))
+}
+
+pub fn validate_session_id(session_id: &str) -> Result<(), ApiError> {
+    if is_valid_id(session_id) {
+        Ok(())
+    } else {
+        Err(ApiError::InvalidSessionId)
+    }
+}
+
+pub fn validate_user_id(user_id: &str) -> Result<(), ApiError> {
+    if is_valid_id(user_id) {
+        Ok(())
+    } else {
+        Err(ApiError::InvalidUserId)
+    }
+}
+
+pub fn validate_org_id(org_id: &str) -> Result<(), ApiError> {
+    if is_valid_id(org_id) {
+        Ok(())
+    } else {
+        Err(ApiError::InvalidOrgId)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_ids() {
+        for id in [
+            "dashboard-1",
+            "test.png",
+            "a",
+            "user_123",
+            &"x".repeat(MAX_ID_LEN),
+        ] {
+            assert!(validate_session_id(id).is_ok(), "{id}");
+        }
+    }
+
+    #[test]
+    fn test_invalid_ids() {
+        for id in [
+            "",
+            "user:alice:session:x",
+            ".realtime-svg:rsa:private_pem",
+            "has space",
+            "slash/id",
+            "한글",
+            &"x".repeat(MAX_ID_LEN + 1),
+        ] {
+            assert!(validate_session_id(id).is_err(), "{id}");
+            assert!(validate_user_id(id).is_err(), "{id}");
+            assert!(validate_org_id(id).is_err(), "{id}");
+        }
+    }
+
+    #[test]
+    fn test_public_session_key_is_namespaced() {
+        let keys = Keys::default();
+        assert_eq!(keys.public_session("badge"), "session:badge");
+        assert_ne!(keys.public_session("directory"), keys.directory());
+    }
+
+    #[test]
+    fn test_key_prefix_applies_to_every_key() {
+        let keys = Keys::new("tenant-a");
+        assert_eq!(keys.prefix(), "tenant-a:");
+        assert_eq!(keys.public_session("badge"), "tenant-a:session:badge");
+        assert_eq!(
+            keys.user_session("alice", "badge"),
+            "tenant-a:user:alice:session:badge"
+        );
+        assert_eq!(keys.user_data("alice"), "tenant-a:user:alice:data");
+        assert_eq!(
+            keys.user_token_epoch("alice"),
+            "tenant-a:user:alice:token_epoch"
+        );
+        assert_eq!(
+            keys.user_refresh_families("alice"),
+            "tenant-a:user:alice:refresh_families"
+        );
+        assert_eq!(keys.directory(), "tenant-a:directory");
+        assert_eq!(keys.user_api_keys("alice"), "tenant-a:user:alice:api_keys");
+        assert_eq!(
+            keys.user_share_links("alice"),
+            "tenant-a:user:alice:share_links"
+        );
+        assert_eq!(keys.share_link("ab12"), "tenant-a:share_link:ab12");
+        assert_eq!(
+            keys.user_shared_sessions("alice"),
+            "tenant-a:user:alice:shared_sessions"
+        );
+        assert_eq!(keys.org_data("team"), "tenant-a:org:team:data");
+        assert_eq!(
+            keys.org_session("team", "badge"),
+            "tenant-a:org:team:session:badge"
+        );
+        assert_eq!(keys.user_orgs("alice"), "tenant-a:user:alice:orgs");
+        assert_eq!(
+            keys.user_incoming_transfers("bob"),
+            "tenant-a:user:bob:incoming_transfers"
+        );
+        assert_eq!(
+            keys.moved_session("alice", "badge"),
+            "tenant-a:user:alice:moved:badge"
+        );
+        assert_eq!(
+            keys.login_attempts_ip("10.0.0.1"),
+            "tenant-a:login_attempts:ip:10.0.0.1"
+        );
+        assert_eq!(keys.login_lockout("alice"), "tenant-a:login_lockout:alice");
+        assert_eq!(
+            keys.session_updates(&SessionRef::org("team", "board")),
+            "tenant-a:session_updates:org:team:board"
+        );
+        assert_eq!(keys.audit_log(), "tenant-a:audit_log");
+        assert_eq!(
+            keys.stream_slots_ip("10.0.0.1"),
+            "tenant-a:stream_slots:ip:10.0.0.1"
+        );
+        assert_eq!(
+            keys.rsa_private_pem(),
+            "tenant-a:.realtime-svg:rsa:private_pem"
+        );
+        assert_eq!(
+            keys.signing_key("20261019-1a2b3c4d"),
+            "tenant-a:.realtime-svg:jwk:key:20261019-1a2b3c4d"
+        );
+        assert_eq!(Keys::new("tenant-a:"), keys);
+    }
+
+    #[test]
+    fn test_key_prefix_cannot_collide_with_unprefixed_keys() {
+        let unprefixed = Keys::default();
+        let session = Keys::new("session");
+        assert_eq!(session.directory(), unprefixed.public_session("directory"));
+        assert!(!is_valid_key_prefix("session"));
+
+        for prefix in ["user", "org", "directory", "audit_log", ".realtime-svg"] {
+            assert!(!is_valid_key_prefix(prefix), "{prefix}");
+        }
+        for prefix in ["", "tenant-a", "sessions", "users", "Session"] {
+            assert!(is_valid_key_prefix(prefix), "{prefix}");
+        }
+
+        // 새 키를 추가하면 그 네임스페이스도 예약해야 합니다.
+        let session_ref = SessionRef::user("alice", "badge");
+        for key in [
+            unprefixed.directory(),
+            unprefixed.public_session("badge"),
+            unprefixed.user_data("alice"),
+            unprefixed.org_data("team"),
+            unprefixed.refresh_token("ab12"),
+            unprefixed.refresh_token_family("ab12"),
+            unprefixed.oidc_login("ab12"),
+            unprefixed.login_attempts_user("alice"),
+            unprefixed.login_attempts_ip("10.0.0.1"),
+            unprefixed.login_failures("alice"),
+            unprefixed.login_lockout("alice"),
+            unprefixed.login_rejections(),
+            unprefixed.audit_log(),
+            unprefixed.session_updates(&session_ref),
+            unprefixed.stream_slots_ip("10.0.0.1"),
+            unprefixed.api_key("ab12"),
+            unprefixed.share_link("ab12"),
+            unprefixed.revoked_token("ab12"),
+            unprefixed.signing_key_index(),
+        ] {
+            let namespace = key.split(':').next().unwrap();
+            assert!(!is_valid_key_prefix(namespace), "{key}");
+        }
+    }
+
+    #[test]
+    fn test_parse_keys_round_trip() {
+        let keys = Keys::new("tenant-a");
+        assert_eq!(
+            keys.parse_user_data(&keys.user_data("alice")),
+            Some("alice")
+        );
+        assert_eq!(
+            keys.parse_user_data(&keys.user_session("alice", "data")),
+            None
+        );
+        assert_eq!(keys.parse_user_data("user:alice:data"), None);
+        assert_eq!(keys.parse_org_data(&keys.org_data("team")), Some("team"));
+        assert_eq!(keys.parse_org_data(&keys.org_session("team", "data")), None);
+
+        for session_ref in [
+            SessionRef::public("badge"),
+            SessionRef::user("alice", "badge"),
+            SessionRef::org("team", "badge"),
+        ] {
+            let key = match (&session_ref.user_id, &session_ref.org_id) {
+                (Some(user_id), _) => keys.user_session(user_id, &session_ref.session_id),
+                (None, Some(org_id)) => keys.org_session(org_id, &session_ref.session_id),
+                (None, None) => keys.public_session(&session_ref.session_id),
+            };
+            assert_eq!(keys.parse_session(&key), Some(session_ref));
+        }
+        assert_eq!(keys.parse_session(&keys.user_data("alice")), None);
+        assert_eq!(keys.parse_session(&keys.moved_session("alice", "x")), None);
+        assert_eq!(keys.parse_session("tenant-a:user:a:b:session:c"), None);
+    }
+}
@@ -2,20 +2,40 @@ use std::collections::HashMap;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+pub mod admin;
+pub mod api_key;
+pub mod audit;
 pub mod auth;
+pub mod auth_cookie;
 pub mod browser_engine;
+pub mod client_ip;
 pub mod config;
+pub mod directory;
 pub mod errors;
 pub mod jwt;
+pub mod keys;
+pub mod migration;
+pub mod oidc;
+pub mod org;
+pub mod rate_limit;
+pub mod refresh_token;
+pub mod scope;
 pub mod session_data;
+pub mod session_list;
 pub mod share;
+pub mod share_link;
+pub mod signing_key;
 pub mod state;
+pub mod trusted_issuer;
 pub mod user_data;
 pub mod whoami;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ListResponse<T: Serialize> {
     pub items: Vec<T>,
+    /// 다음 페이지를 요청할 때 사용할 커서. 마지막 페이지이면 없음.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
 }
 
 /// 세션 관련 응답.
@@ -30,6 +50,177 @@ pub struct SessionDetailInfo {
     pub session_id: String,
     pub template: String,
     pub args: HashMap<String, serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// 공개 디렉터리(`GET /api/directory`)에 노출되는지 여부.
+    #[serde(default)]
+    pub listed: bool,
+    #[serde(default)]
+    pub visibility: session_data::Visibility,
+    /// 요청한 사용자가 협업자로서 가진 역할. 소유자이면 없음.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<session_data::SessionRole>,
+    /// 조직 세션이면 요청한 사용자의 조직 역할.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub org_role: Option<org::OrgRole>,
+    /// 수락을 기다리는 소유권 이전 요청. 소유자에게만 보입니다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pending_transfer: Option<session_data::PendingTransfer>,
+}
+
+/// 사용자 세션 목록의 한 항목.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SessionSummary {
+    pub session_id: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub tags: Vec<String>,
+    pub listed: bool,
+    pub visibility: session_data::Visibility,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+    /// 남은 TTL(초). 만료가 설정되지 않은 세션이면 없음.
+    pub ttl_seconds: Option<i64>,
+    /// 템플릿 원문의 바이트 크기.
+    pub template_bytes: usize,
+    /// 현재 스트림을 구독 중인 뷰어 수.
+    pub viewers: u64,
+    pub thumbnail_url: String,
+    /// 세션 소유자. 공유받은 세션 목록에만 포함됩니다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// 요청한 사용자가 협업자로서 가진 역할. 자신의 세션이면 없음.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<session_data::SessionRole>,
+}
+
+impl SessionSummary {
+    /// `user_id`의 세션 인덱스에서 조회한 항목으로 요약을 만듭니다.
+    pub fn from_entry(user_id: &str, entry: state::UserSessionEntry) -> Self {
+        let session_ref = directory::SessionRef::user(user_id, &entry.session_id);
+        Self::from_indexed_entry(&session_ref, entry)
+    }
+
+    /// `org_id`의 조직 세션 인덱스에서 조회한 항목으로 요약을 만듭니다.
+    pub fn from_org_entry(org_id: &str, entry: state::UserSessionEntry) -> Self {
+        let session_ref = directory::SessionRef::org(org_id, &entry.session_id);
+        Self::from_indexed_entry(&session_ref, entry)
+    }
+
+    fn from_indexed_entry(
+        session_ref: &directory::SessionRef,
+        entry: state::UserSessionEntry,
+    ) -> Self {
+        Self {
+            thumbnail_url: session_ref.snapshot_path(),
+            session_id: entry.session_id,
+            title: entry.session.title,
+            description: entry.session.description,
+            tags: entry.session.tags,
+            listed: entry.session.listed,
+            visibility: entry.session.visibility,
+            created_at: entry.session.created_at,
+            updated_at: entry.session.updated_at,
+            ttl_seconds: entry.ttl_seconds,
+            template_bytes: entry.session.template.len(),
+            viewers: entry.viewers,
+            owner: None,
+            role: None,
+        }
+    }
+}
+
+/// 세션 협업자 목록의 한 항목.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CollaboratorInfo {
+    pub user_id: String,
+    pub role: session_data::SessionRole,
+}
+
+/// 소유권 이전 요청. 요청 응답과 받은 요청 목록에 씁니다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SessionTransferInfo {
+    /// 현재 소유자
+    pub owner: String,
+    pub session_id: String,
+    pub to: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl SessionTransferInfo {
+    pub fn new(
+        owner: &str,
+        session_id: &str,
+        session: &session_data::SessionData,
+        transfer: &session_data::PendingTransfer,
+    ) -> Self {
+        Self {
+            owner: owner.to_string(),
+            session_id: session_id.to_string(),
+            to: transfer.to.clone(),
+            title: session.title.clone(),
+            created_at: transfer.created_at,
+            expires_at: transfer.expires_at,
+        }
+    }
+}
+
+/// 조직 멤버 목록의 한 항목.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct OrgMemberInfo {
+    pub user_id: String,
+    pub role: org::OrgRole,
+}
+
+/// 사용자가 속한 조직 목록의 한 항목.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct OrgSummary {
+    pub org_id: String,
+    pub name: String,
+    /// 요청한 사용자의 역할.
+    pub role: org::OrgRole,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 조직 상세 응답.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct OrgDetailInfo {
+    pub org_id: String,
+    pub name: String,
+    /// 요청한 사용자의 역할.
+    pub role: org::OrgRole,
+    pub created_at: DateTime<Utc>,
+    pub members: Vec<OrgMemberInfo>,
+}
+
+/// 세션 채널에 이 메시지가 발행되면 구독 중인 스트림이 종료됩니다 (세션 삭제 시).
+pub const STREAM_CLOSE_MESSAGE: &str = "close";
+
+/// 세션이 비공개로 바뀌면 발행되어, 공유 링크 없이 연 스트림만 종료합니다.
+pub const STREAM_CLOSE_ANONYMOUS_MESSAGE: &str = "close:anonymous";
+
+/// 공유 링크가 폐기되면 발행되어, 그 링크로 연 스트림만 종료합니다.
+pub fn share_link_close_message(link_id: &str) -> String {
+    format!("close:link:{}", link_id)
+}
+
+const STREAM_MOVED_PREFIX: &str = "moved:";
+
+/// 세션이 다른 채널로 옮겨지면 예전 채널에 발행되어, 열려 있는 스트림이 새 채널을 구독하게 합니다.
+pub fn stream_moved_message(channel: &str) -> String {
+    format!("{}{}", STREAM_MOVED_PREFIX, channel)
+}
+
+/// [`stream_moved_message`]이면 새 채널 이름을 반환합니다.
+pub fn parse_stream_moved_message(payload: &str) -> Option<&str> {
+    payload.strip_prefix(STREAM_MOVED_PREFIX)
 }
 
 /// 브로드캐스트되는 SVG 프레임.
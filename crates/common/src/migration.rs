@@ -0,0 +1,297 @@
+//! 저장소 레이아웃 변경에 따른 일회성 마이그레이션.
+
+use redis::aio::MultiplexedConnection;
+use redis::{AsyncTypedCommands, ExistenceCheck, SetExpiry, SetOptions};
+
+use crate::{
+    errors::ApiError,
+    keys::{self, Keys},
+    session_data::SessionData,
+};
+
+const SCAN_COUNT: usize = 500;
+
+/// 마이그레이션 잠금의 TTL. SCAN 한 번마다 다시 늘리므로 실행 중에는 풀리지 않고,
+/// 실행하던 인스턴스가 죽으면 곧 다른 인스턴스가 이어서 실행할 수 있습니다.
+const LOCK_SECONDS: i64 = 60;
+
+/// 잠금 값이 `ARGV[1]`이면 `ARGV[2]`초로 다시 늘리고 1을, 아니면 0을 돌려줍니다.
+const RENEW_LOCK_SCRIPT: &str = r"
+if redis.call('GET', KEYS[1]) ~= ARGV[1] then
+  return 0
+end
+redis.call('EXPIRE', KEYS[1], ARGV[2])
+return 1
+";
+
+/// 잠금 값이 `ARGV[1]`일 때만 지웁니다.
+const RELEASE_LOCK_SCRIPT: &str = r"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+  return redis.call('DEL', KEYS[1])
+end
+return 0
+";
+
+/// 이 인스턴스가 잡은 마이그레이션 잠금.
+///
+/// 잠금 값은 잡을 때 만든 임의의 토큰이라, 실행이 멈춘 사이 잠금이 만료되어 다른 인스턴스가
+/// 잡았으면 늘리거나 풀 수 없습니다.
+struct MigrationLock {
+    key: String,
+    token: String,
+}
+
+impl MigrationLock {
+    /// 잠금을 다시 [`LOCK_SECONDS`]로 늘립니다.
+    ///
+    /// 그 사이에 잠금을 잃었으면 두 인스턴스가 같은 마이그레이션을 함께 실행하지 않도록
+    /// 오류를 반환합니다.
+    async fn renew(&self, conn: &mut MultiplexedConnection) -> Result<(), ApiError> {
+        let renewed: bool = redis::Script::new(RENEW_LOCK_SCRIPT)
+            .key(&self.key)
+            .arg(&self.token)
+            .arg(LOCK_SECONDS)
+            .invoke_async(conn)
+            .await?;
+        if renewed {
+            Ok(())
+        } else {
+            Err(ApiError::InternalError(format!(
+                "Lost migration lock {}",
+                self.key
+            )))
+        }
+    }
+
+    /// 아직 이 인스턴스의 잠금이면 풉니다.
+    async fn release(&self, conn: &mut MultiplexedConnection) -> Result<(), ApiError> {
+        let released: i64 = redis::Script::new(RELEASE_LOCK_SCRIPT)
+            .key(&self.key)
+            .arg(&self.token)
+            .invoke_async(conn)
+            .await?;
+        if released == 0 {
+            tracing::warn!("Migration lock {} was already taken over", self.key);
+        }
+        Ok(())
+    }
+}
+
+/// 예전에는 공용 세션 ID가 그대로 Redis 키였습니다. 이런 키를 모두
+/// `session:{id}` 로 옮깁니다. `key_prefix`가 설정되어 있으면 그 prefix 아래의
+/// 키만 대상으로 합니다.
+///
+/// 잠금을 잡은 인스턴스 하나만 실행하고, SCAN을 끝까지 마친 뒤에만 완료 표시를
+/// 남깁니다. 도중에 실패하면 다음 시작 때 다시 실행합니다. TTL은 `RENAME` 으로
+/// 그대로 유지됩니다. 옮긴 키의 수를 반환합니다.
+pub async fn migrate_public_session_keys(
+    conn: &mut MultiplexedConnection,
+    keys: &Keys,
+) -> Result<usize, ApiError> {
+    let done_key = keys.migration_public_session_prefix();
+    let lock_key = keys.migration_lock("public_session_prefix");
+    let Some(lock) = begin(conn, &done_key, &lock_key).await? else {
+        tracing::debug!("Public session key migration already done or running");
+        return Ok(0);
+    };
+    let result = move_public_session_keys(conn, keys, &lock).await;
+    let migrated = finish(conn, &done_key, &lock, result).await?;
+    tracing::info!("Migrated {} public session keys under prefix", migrated);
+    Ok(migrated)
+}
+
+async fn move_public_session_keys(
+    conn: &mut MultiplexedConnection,
+    keys: &Keys,
+    lock: &MigrationLock,
+) -> Result<usize, ApiError> {
+    let mut cursor: u64 = 0;
+    let mut migrated = 0;
+    loop {
+        let (next, batch): (u64, Vec<String>) = redis::cmd("SCAN")
+            .arg(cursor)
+            .arg("COUNT")
+            .arg(SCAN_COUNT)
+            .query_async(conn)
+            .await?;
+        lock.renew(conn).await?;
+
+        for key in batch {
+            let Some(session_id) = key.strip_prefix(keys.prefix()) else {
+                continue;
+            };
+            if !is_legacy_public_session(conn, &key, session_id).await? {
+                continue;
+            }
+            let target = keys.public_session(session_id);
+            let renamed: bool = redis::cmd("RENAMENX")
+                .arg(&key)
+                .arg(&target)
+                .query_async(conn)
+                .await?;
+            if renamed {
+                migrated += 1;
+            } else {
+                tracing::warn!(
+                    "Skipped migrating public session key {}: {} already exists",
+                    key,
+                    target
+                );
+            }
+        }
+
+        if next == 0 {
+            break;
+        }
+        cursor = next;
+    }
+    Ok(migrated)
+}
+
+/// 사용자, 조직 세션 인덱스가 생기기 전에 만들어진 세션은 인덱스에 없어서 목록에
+/// 나오지 않습니다. 세션 키를 모두 SCAN해서 소유자의 인덱스에 채워 넣습니다.
+///
+/// 점수는 세션이 저장될 때와 같은 생성 시각(ms)이며, 생성 시각이 없는 예전 세션은
+/// 지금 시각을 씁니다. `ZADD NX` 이므로 이미 인덱스에 있는 세션은 그대로 둡니다.
+/// [`migrate_public_session_keys`]와 같이 잠금을 잡고 실행하며, 끝까지 마쳐야
+/// 완료로 표시합니다. 인덱스에 추가한 세션의 수를 반환합니다.
+pub async fn backfill_session_indexes(
+    conn: &mut MultiplexedConnection,
+    keys: &Keys,
+) -> Result<usize, ApiError> {
+    let done_key = keys.migration_session_index();
+    let lock_key = keys.migration_lock("session_index");
+    let Some(lock) = begin(conn, &done_key, &lock_key).await? else {
+        tracing::debug!("Session index backfill already done or running");
+        return Ok(0);
+    };
+    let result = index_existing_sessions(conn, keys, &lock).await;
+    let added = finish(conn, &done_key, &lock, result).await?;
+    tracing::info!("Added {} existing sessions to session indexes", added);
+    Ok(added)
+}
+
+async fn index_existing_sessions(
+    conn: &mut MultiplexedConnection,
+    keys: &Keys,
+    lock: &MigrationLock,
+) -> Result<usize, ApiError> {
+    let [_, user_sessions, org_sessions] = keys.session_patterns();
+    let mut added = 0;
+    for pattern in [user_sessions, org_sessions] {
+        let mut cursor: u64 = 0;
+        loop {
+            let (next, batch): (u64, Vec<String>) = redis::cmd("SCAN")
+                .arg(cursor)
+                .arg("MATCH")
+                .arg(&pattern)
+                .arg("COUNT")
+                .arg(SCAN_COUNT)
+                .query_async(conn)
+                .await?;
+            lock.renew(conn).await?;
+
+            for key in batch {
+                let Some(session_ref) = keys.parse_session(&key) else {
+                    continue;
+                };
+                let index_key = match (&session_ref.user_id, &session_ref.org_id) {
+                    (Some(user_id), _) => keys.user_session_index(user_id),
+                    (None, Some(org_id)) => keys.org_session_index(org_id),
+                    (None, None) => continue,
+                };
+                // 문자열이 아닌 키에 GET을 보내면 오류이므로 먼저 타입을 확인합니다.
+                let key_type: String = redis::cmd("TYPE").arg(&key).query_async(conn).await?;
+                if key_type != "string" {
+                    continue;
+                }
+                let Some(json) = conn.get(&key).await? else {
+                    continue;
+                };
+                let Ok(session) = serde_json::from_str::<SessionData>(&json) else {
+                    tracing::warn!("Skipped indexing {}: not a session", key);
+                    continue;
+                };
+                let created_at_ms = session
+                    .created_at
+                    .unwrap_or_else(chrono::Utc::now)
+                    .timestamp_millis();
+                let inserted: usize = redis::cmd("ZADD")
+                    .arg(&index_key)
+                    .arg("NX")
+                    .arg(created_at_ms)
+                    .arg(&session_ref.session_id)
+                    .query_async(conn)
+                    .await?;
+                added += inserted;
+            }
+
+            if next == 0 {
+                break;
+            }
+            cursor = next;
+        }
+    }
+    Ok(added)
+}
+
+/// 완료 표시가 없고 잠금을 잡았을 때만 잠금을 반환합니다. 다른 인스턴스가 실행 중이면 `None`.
+async fn begin(
+    conn: &mut MultiplexedConnection,
+    done_key: &str,
+    lock_key: &str,
+) -> Result<Option<MigrationLock>, ApiError> {
+    if conn.exists(done_key).await? {
+        return Ok(None);
+    }
+    let lock = MigrationLock {
+        key: lock_key.to_string(),
+        token: uuid::Uuid::new_v4().to_string(),
+    };
+    let options = SetOptions::default()
+        .conditional_set(ExistenceCheck::NX)
+        .with_expiration(SetExpiry::EX(LOCK_SECONDS as u64));
+    let acquired = conn
+        .set_options(lock_key, lock.token.as_str(), options)
+        .await?
+        .is_some();
+    Ok(acquired.then_some(lock))
+}
+
+/// 성공했을 때만 완료 표시를 남기고, 결과와 상관없이 이 인스턴스의 잠금을 풉니다.
+async fn finish<T>(
+    conn: &mut MultiplexedConnection,
+    done_key: &str,
+    lock: &MigrationLock,
+    result: Result<T, ApiError>,
+) -> Result<T, ApiError> {
+    if result.is_ok() {
+        let finished_at = chrono::Utc::now().to_rfc3339();
+        conn.set(done_key, finished_at.as_str()).await?;
+    }
+    lock.release(conn).await?;
+    result
+}
+
+/// 내부 네임스페이스 밖에 있는 문자열 키 중 세션 JSON인 것만 골라냅니다.
+///
+/// prefix를 뗀 나머지가 유효한 세션 ID가 아니면 건너뜁니다. `:`가 들어간 키는
+/// 다른 네임스페이스나 다른 배포의 키이므로 건드리지 않습니다.
+async fn is_legacy_public_session(
+    conn: &mut MultiplexedConnection,
+    key: &str,
+    session_id: &str,
+) -> Result<bool, ApiError> {
+    if session_id.starts_with('.')
+        || session_id == "directory"
+        || keys::validate_session_id(session_id).is_err()
+    {
+        return Ok(false);
+    }
+    let key_type: String = redis::cmd("TYPE").arg(key).query_async(conn).await?;
+    if key_type != "string" {
+        return Ok(false);
+    }
+    let value = conn.get(key).await?;
+    Ok(value.is_some_and(|json| serde_json::from_str::<SessionData>(&json).is_ok()))
+}
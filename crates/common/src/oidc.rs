@@ -0,0 +1,463 @@
+//! 외부 OpenID Connect 제공자를 통한 로그인 (authorization code + PKCE).
+//!
+//! 로그인을 시작하면 `state`, `nonce`, PKCE `code_verifier`를 만들어 Redis에
+//! 잠시 저장하고 제공자의 인가 엔드포인트로 보냅니다. 콜백에서 `state`로 그 값을
+//! 한 번만 꺼내 code를 교환하고, ID 토큰을 제공자의 JWKS로 검증한 뒤 설정된
+//! claim을 `user_id`로 씁니다. 그 뒤로는 비밀번호 로그인과 같은 토큰을 발급합니다.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{decode, decode_header, Algorithm, DecodingKey, Validation};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::sync::RwLock;
+
+use crate::errors::ApiError;
+use crate::refresh_token::random_hex;
+
+/// 로그인을 시작한 뒤 콜백까지 기다리는 시간 (10분).
+pub const LOGIN_STATE_TTL_SECONDS: u64 = 600;
+
+/// 로그인을 마친 브라우저를 보내는 프론트엔드 경로. 결과는 URL fragment로 전달합니다.
+pub const FRONTEND_CALLBACK_PATH: &str = "/auth/callback";
+
+/// discovery 문서와 JWKS를 다시 읽는 주기.
+const METADATA_CACHE: Duration = Duration::from_secs(60 * 60);
+
+/// ID 토큰에 모르는 `kid`가 있을 때 JWKS를 다시 읽는 최소 간격.
+const UNKNOWN_KID_RELOAD_INTERVAL: Duration = Duration::from_secs(30);
+
+/// 제공자 요청 제한 시간.
+const HTTP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// OIDC 제공자 설정. `issuer`가 비어 있으면 SSO 로그인을 쓰지 않습니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OidcConfig {
+    /// discovery 문서(`{issuer}/.well-known/openid-configuration`)의 `issuer`와 같아야 합니다.
+    pub issuer: String,
+    pub client_id: String,
+    /// 없으면 PKCE만 쓰는 public client로 요청합니다.
+    pub client_secret: Option<String>,
+    /// 제공자에 등록한 콜백 URL (`https://{host}/api/auth/oidc/callback`).
+    pub redirect_url: String,
+    /// 공백으로 구분한 scope. `openid`가 없으면 앞에 붙입니다.
+    pub scopes: String,
+    /// `user_id`로 쓸 ID 토큰 claim (예: `preferred_username`).
+    pub user_claim: String,
+}
+
+impl OidcConfig {
+    fn scope(&self) -> String {
+        let scopes: Vec<&str> = self.scopes.split_whitespace().collect();
+        if scopes.contains(&"openid") {
+            scopes.join(" ")
+        } else {
+            std::iter::once("openid")
+                .chain(scopes)
+                .collect::<Vec<_>>()
+                .join(" ")
+        }
+    }
+}
+
+/// discovery 문서 중 로그인에 쓰는 항목.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProviderMetadata {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    pub jwks_uri: String,
+}
+
+/// 로그인을 시작할 때 `state`별로 Redis에 저장하는 값.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct OidcLoginState {
+    pub code_verifier: String,
+    pub nonce: String,
+    /// 토큰을 fragment 대신 HttpOnly 쿠키로 전달할지 여부
+    #[serde(default)]
+    pub cookie: bool,
+}
+
+impl OidcLoginState {
+    /// 새 로그인의 `(state, 저장할 값)`.
+    pub fn generate() -> (String, Self) {
+        (
+            random_hex(16),
+            Self {
+                code_verifier: random_hex(32),
+                nonce: random_hex(16),
+                cookie: false,
+            },
+        )
+    }
+
+    /// PKCE `S256` code challenge.
+    pub fn code_challenge(&self) -> String {
+        pkce_challenge(&self.code_verifier)
+    }
+}
+
+fn pkce_challenge(code_verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()))
+}
+
+/// 검증된 ID 토큰에서 꺼낸 계정 정보.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OidcIdentity {
+    /// 계정에 연결해 두는 `{issuer}#{sub}`.
+    pub subject: String,
+    pub user_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenEndpointResponse {
+    id_token: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct IdTokenClaims {
+    iss: String,
+    sub: String,
+    #[serde(default)]
+    nonce: Option<String>,
+    #[serde(flatten)]
+    extra: serde_json::Map<String, serde_json::Value>,
+}
+
+fn provider_error(e: impl std::fmt::Display) -> ApiError {
+    ApiError::IdentityProvider(e.to_string())
+}
+
+/// ID 토큰을 `jwks`로 검증하고 `user_claim`을 `user_id`로 꺼냅니다.
+///
+/// 대칭키(HS*) 서명은 받지 않으며, `iss`, `aud`, `exp`, `nonce`를 모두 확인합니다.
+pub fn verify_id_token(
+    config: &OidcConfig,
+    jwks: &JwkSet,
+    id_token: &str,
+    nonce: &str,
+) -> Result<OidcIdentity, ApiError> {
+    let invalid = |reason: String| {
+        tracing::warn!("OIDC ID token rejected: {}", reason);
+        ApiError::OidcLoginFailed(reason)
+    };
+
+    let header = decode_header(id_token).map_err(|e| invalid(e.to_string()))?;
+    if matches!(
+        header.alg,
+        Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512
+    ) {
+        return Err(invalid(format!("unsupported algorithm {:?}", header.alg)));
+    }
+    let jwk = match &header.kid {
+        Some(kid) => jwks.find(kid),
+        None => jwks.keys.first(),
+    }
+    .ok_or_else(|| invalid("unknown signing key".to_string()))?;
+    let decoding_key = DecodingKey::from_jwk(jwk).map_err(|e| invalid(e.to_string()))?;
+
+    let mut validation = Validation::new(header.alg);
+    validation.set_issuer(&[&config.issuer]);
+    validation.set_audience(&[&config.client_id]);
+    validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+    let claims = decode::<IdTokenClaims>(id_token, &decoding_key, &validation)
+        .map_err(|e| invalid(e.to_string()))?
+        .claims;
+
+    if claims.nonce.as_deref() != Some(nonce) {
+        return Err(invalid("nonce mismatch".to_string()));
+    }
+
+    let user_id = match config.user_claim.as_str() {
+        "sub" => Some(claims.sub.clone()),
+        claim => claims
+            .extra
+            .get(claim)
+            .and_then(|value| value.as_str())
+            .map(str::to_string),
+    }
+    .ok_or_else(|| invalid(format!("missing claim {}", config.user_claim)))?;
+
+    Ok(OidcIdentity {
+        subject: format!("{}#{}", claims.iss, claims.sub),
+        user_id,
+    })
+}
+
+/// 프론트엔드 콜백 경로에 로그인 결과를 fragment로 붙입니다. fragment는 서버로 전송되지 않습니다.
+pub fn frontend_redirect(pairs: &[(&str, &str)]) -> String {
+    let fragment = url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(pairs)
+        .finish();
+    format!("{}#{}", FRONTEND_CALLBACK_PATH, fragment)
+}
+
+struct ProviderCache {
+    metadata: ProviderMetadata,
+    jwks: JwkSet,
+    loaded_at: Instant,
+}
+
+/// 설정된 OIDC 제공자와의 통신. discovery 문서와 JWKS는 인스턴스마다 메모리에 캐시합니다.
+#[derive(Clone)]
+pub struct OidcProvider {
+    config: Arc<OidcConfig>,
+    http: reqwest::Client,
+    cache: Arc<RwLock<Option<Arc<ProviderCache>>>>,
+}
+
+impl std::fmt::Debug for OidcProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OidcProvider")
+            .field("issuer", &self.config.issuer)
+            .field("client_id", &self.config.client_id)
+            .finish()
+    }
+}
+
+impl OidcProvider {
+    pub fn new(config: OidcConfig) -> Result<Self, ApiError> {
+        let http = reqwest::Client::builder()
+            .timeout(HTTP_TIMEOUT)
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .map_err(provider_error)?;
+        Ok(Self {
+            config: Arc::new(config),
+            http,
+            cache: Arc::new(RwLock::new(None)),
+        })
+    }
+
+    pub fn config(&self) -> &OidcConfig {
+        &self.config
+    }
+
+    async fn get_json<T: serde::de::DeserializeOwned>(&self, url: &str) -> Result<T, ApiError> {
+        self.http
+            .get(url)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(provider_error)?
+            .json()
+            .await
+            .map_err(provider_error)
+    }
+
+    /// `max_age`보다 오래된 캐시는 discovery 문서와 JWKS를 다시 읽습니다.
+    async fn provider(&self, max_age: Duration) -> Result<Arc<ProviderCache>, ApiError> {
+        if let Some(cache) = self.cache.read().await.as_ref() {
+            if cache.loaded_at.elapsed() < max_age {
+                return Ok(cache.clone());
+            }
+        }
+
+        let mut guard = self.cache.write().await;
+        if let Some(cache) = guard.as_ref() {
+            if cache.loaded_at.elapsed() < max_age {
+                return Ok(cache.clone());
+            }
+        }
+
+        let discovery_url = format!(
+            "{}/.well-known/openid-configuration",
+            self.config.issuer.trim_end_matches('/')
+        );
+        let metadata: ProviderMetadata = self.get_json(&discovery_url).await?;
+        if metadata.issuer != self.config.issuer {
+            return Err(provider_error(format!(
+                "discovery issuer {} does not match {}",
+                metadata.issuer, self.config.issuer
+            )));
+        }
+        let jwks: JwkSet = self.get_json(&metadata.jwks_uri).await?;
+
+        let cache = Arc::new(ProviderCache {
+            metadata,
+            jwks,
+            loaded_at: Instant::now(),
+        });
+        *guard = Some(cache.clone());
+        Ok(cache)
+    }
+
+    /// 브라우저를 보낼 제공자의 인가 URL.
+    pub async fn authorization_url(
+        &self,
+        state: &str,
+        login: &OidcLoginState,
+    ) -> Result<String, ApiError> {
+        let provider = self.provider(METADATA_CACHE).await?;
+        let mut url =
+            url::Url::parse(&provider.metadata.authorization_endpoint).map_err(provider_error)?;
+        url.query_pairs_mut()
+            .append_pair("response_type", "code")
+            .append_pair("client_id", &self.config.client_id)
+            .append_pair("redirect_uri", &self.config.redirect_url)
+            .append_pair("scope", &self.config.scope())
+            .append_pair("state", state)
+            .append_pair("nonce", &login.nonce)
+            .append_pair("code_challenge", &login.code_challenge())
+            .append_pair("code_challenge_method", "S256");
+        Ok(url.into())
+    }
+
+    /// 인가 code를 교환하고 받은 ID 토큰을 검증합니다.
+    pub async fn exchange_code(
+        &self,
+        code: &str,
+        login: &OidcLoginState,
+    ) -> Result<OidcIdentity, ApiError> {
+        let provider = self.provider(METADATA_CACHE).await?;
+
+        let mut request = self.http.post(&provider.metadata.token_endpoint).form(&[
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", &self.config.redirect_url),
+            ("client_id", &self.config.client_id),
+            ("code_verifier", &login.code_verifier),
+        ]);
+        if let Some(secret) = &self.config.client_secret {
+            request = request.basic_auth(&self.config.client_id, Some(secret));
+        }
+        let response = request.send().await.map_err(provider_error)?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            tracing::warn!("OIDC token endpoint returned {}: {}", status, body);
+            return Err(ApiError::OidcLoginFailed(format!(
+                "token endpoint returned {}",
+                status
+            )));
+        }
+        let tokens: TokenEndpointResponse = response.json().await.map_err(provider_error)?;
+
+        // 제공자가 키를 교체했을 수 있으므로 모르는 kid면 JWKS를 한 번 다시 읽습니다.
+        let kid = decode_header(&tokens.id_token).ok().and_then(|h| h.kid);
+        let provider = match kid {
+            Some(kid) if provider.jwks.find(&kid).is_none() => {
+                self.provider(UNKNOWN_KID_RELOAD_INTERVAL).await?
+            }
+            _ => provider,
+        };
+        verify_id_token(&self.config, &provider.jwks, &tokens.id_token, &login.nonce)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signing_key::{self, SigningAlgorithm};
+    use jsonwebtoken::{encode, Header};
+    use serde_json::json;
+
+    fn config() -> OidcConfig {
+        OidcConfig {
+            issuer: "https://idp.example.com".to_string(),
+            client_id: "realtime-svg".to_string(),
+            client_secret: None,
+            redirect_url: "https://svg.example.com/api/auth/oidc/callback".to_string(),
+            scopes: "profile email".to_string(),
+            user_claim: "preferred_username".to_string(),
+        }
+    }
+
+    fn id_token(key: &signing_key::StoredSigningKey, claims: serde_json::Value) -> String {
+        let signing_key = key.signing_key().unwrap();
+        let mut header = Header::new(signing_key.algorithm);
+        header.kid = Some(signing_key.kid.clone());
+        encode(&header, &claims, &signing_key.encoding_key).unwrap()
+    }
+
+    fn claims(nonce: &str) -> serde_json::Value {
+        json!({
+            "iss": "https://idp.example.com",
+            "aud": "realtime-svg",
+            "sub": "248289761001",
+            "exp": chrono::Utc::now().timestamp() + 300,
+            "nonce": nonce,
+            "preferred_username": "alice",
+        })
+    }
+
+    #[test]
+    fn test_pkce_challenge_matches_rfc7636() {
+        assert_eq!(
+            pkce_challenge("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk"),
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+    }
+
+    #[test]
+    fn test_scope_always_includes_openid() {
+        assert_eq!(config().scope(), "openid profile email");
+        let config = OidcConfig {
+            scopes: "email openid".to_string(),
+            ..config()
+        };
+        assert_eq!(config.scope(), "email openid");
+    }
+
+    #[test]
+    fn test_verify_id_token_maps_user_claim() {
+        let key = signing_key::generate(SigningAlgorithm::ES256).unwrap();
+        let jwks = JwkSet {
+            keys: vec![key.public_key().to_jwk().unwrap()],
+        };
+
+        let identity =
+            verify_id_token(&config(), &jwks, &id_token(&key, claims("n-1")), "n-1").unwrap();
+        assert_eq!(identity.user_id, "alice");
+        assert_eq!(identity.subject, "https://idp.example.com#248289761001");
+
+        let by_sub = OidcConfig {
+            user_claim: "sub".to_string(),
+            ..config()
+        };
+        let identity =
+            verify_id_token(&by_sub, &jwks, &id_token(&key, claims("n-1")), "n-1").unwrap();
+        assert_eq!(identity.user_id, "248289761001");
+    }
+
+    #[test]
+    fn test_verify_id_token_rejects_bad_tokens() {
+        let key = signing_key::generate(SigningAlgorithm::ES256).unwrap();
+        let other = signing_key::generate(SigningAlgorithm::ES256).unwrap();
+        let jwks = JwkSet {
+            keys: vec![key.public_key().to_jwk().unwrap()],
+        };
+
+        let mut wrong_audience = claims("n-1");
+        wrong_audience["aud"] = json!("someone-else");
+        let mut wrong_issuer = claims("n-1");
+        wrong_issuer["iss"] = json!("https://evil.example.com");
+        let mut missing_claim = claims("n-1");
+        missing_claim
+            .as_object_mut()
+            .unwrap()
+            .remove("preferred_username");
+
+        for token in [
+            id_token(&key, claims("n-2")),
+            id_token(&key, wrong_audience),
+            id_token(&key, wrong_issuer),
+            id_token(&key, missing_claim),
+            id_token(&other, claims("n-1")),
+        ] {
+            assert!(verify_id_token(&config(), &jwks, &token, "n-1").is_err());
+        }
+    }
+
+    #[test]
+    fn test_frontend_redirect_encodes_fragment() {
+        assert_eq!(
+            frontend_redirect(&[("token", "a.b.c"), ("error", "로그인 실패")]),
+            "/auth/callback#token=a.b.c&error=%EB%A1%9C%EA%B7%B8%EC%9D%B8+%EC%8B%A4%ED%8C%A8"
+        );
+    }
+}
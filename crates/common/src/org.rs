@@ -0,0 +1,170 @@
+//! 조직: 여러 사용자가 함께 소유하는 세션 네임스페이스.
+//!
+//! 조직 세션은 만든 사람이 아니라 조직에 속하므로, 멤버가 탈퇴하거나 계정을 지워도 남습니다.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{errors::ApiError, scope::SessionAccess};
+
+pub const MAX_ORG_NAME_LEN: usize = 100;
+pub const MAX_ORG_MEMBERS: usize = 100;
+
+/// 사용자 ID별 조직 역할. JWT의 `orgs` claim에도 이 형태로 들어갑니다.
+pub type OrgMemberships = BTreeMap<String, OrgRole>;
+
+/// 조직 멤버의 역할.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OrgRole {
+    /// 세션 관리에 더해 멤버 관리와 조직 삭제까지 할 수 있습니다.
+    Owner,
+    /// 조직 세션을 만들고 수정하고 삭제할 수 있습니다.
+    Member,
+    /// 조직 세션 조회만 가능합니다.
+    Viewer,
+}
+
+impl OrgRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrgRole::Owner => "owner",
+            OrgRole::Member => "member",
+            OrgRole::Viewer => "viewer",
+        }
+    }
+
+    /// 이 역할로 조직 세션에 `access`를 할 수 있는지. 조직 세션은 공개 세션이라 `view`는 쓰지 않습니다.
+    pub fn allows(&self, access: SessionAccess) -> bool {
+        match access {
+            SessionAccess::Read => true,
+            SessionAccess::Write => *self != OrgRole::Viewer,
+            SessionAccess::View => false,
+        }
+    }
+}
+
+/// 조직 데이터 (`org:{id}:data`). 멤버 목록이 기준이며 사용자별 조직 인덱스는 이를 따라갑니다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct OrgData {
+    pub name: String,
+    pub members: OrgMemberships,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 앞뒤 공백을 지운 조직 이름. 비어 있거나 너무 길면 오류입니다.
+fn normalize_name(name: &str) -> Result<String, ApiError> {
+    let name = name.trim();
+    if name.is_empty() || name.chars().count() > MAX_ORG_NAME_LEN {
+        return Err(ApiError::InvalidOrg(format!(
+            "조직 이름은 1~{MAX_ORG_NAME_LEN}자여야 합니다"
+        )));
+    }
+    Ok(name.to_string())
+}
+
+impl OrgData {
+    /// `owner`를 유일한 소유자로 하는 조직을 만듭니다.
+    pub fn new(name: &str, owner: impl Into<String>) -> Result<Self, ApiError> {
+        Ok(Self {
+            name: normalize_name(name)?,
+            members: BTreeMap::from([(owner.into(), OrgRole::Owner)]),
+            created_at: Utc::now(),
+        })
+    }
+
+    pub fn role_of(&self, user_id: &str) -> Option<OrgRole> {
+        self.members.get(user_id).copied()
+    }
+
+    fn owner_count(&self) -> usize {
+        self.members
+            .values()
+            .filter(|role| **role == OrgRole::Owner)
+            .count()
+    }
+
+    /// `user_id`가 빠지거나 역할이 바뀌면 소유자가 남지 않는지.
+    pub fn is_last_owner(&self, user_id: &str) -> bool {
+        self.role_of(user_id) == Some(OrgRole::Owner) && self.owner_count() == 1
+    }
+
+    /// 멤버를 추가하거나 역할을 바꿉니다. 마지막 소유자는 다른 역할로 바꿀 수 없습니다.
+    pub fn set_member(&mut self, user_id: &str, role: OrgRole) -> Result<(), ApiError> {
+        if role != OrgRole::Owner && self.is_last_owner(user_id) {
+            return Err(ApiError::InvalidOrg(
+                "조직에는 소유자가 한 명 이상 있어야 합니다".to_string(),
+            ));
+        }
+        if !self.members.contains_key(user_id) && self.members.len() >= MAX_ORG_MEMBERS {
+            return Err(ApiError::InvalidOrg(format!(
+                "조직 멤버는 최대 {MAX_ORG_MEMBERS}명까지 추가할 수 있습니다"
+            )));
+        }
+        self.members.insert(user_id.to_string(), role);
+        Ok(())
+    }
+
+    /// 멤버를 제거합니다. 멤버였으면 `true`를 반환하며, 마지막 소유자는 제거할 수 없습니다.
+    pub fn remove_member(&mut self, user_id: &str) -> Result<bool, ApiError> {
+        if self.is_last_owner(user_id) {
+            return Err(ApiError::InvalidOrg(
+                "조직에는 소유자가 한 명 이상 있어야 합니다".to_string(),
+            ));
+        }
+        Ok(self.members.remove(user_id).is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_org_roles() {
+        assert!(OrgRole::Viewer.allows(SessionAccess::Read));
+        assert!(!OrgRole::Viewer.allows(SessionAccess::Write));
+        assert!(OrgRole::Member.allows(SessionAccess::Write));
+        assert!(OrgRole::Owner.allows(SessionAccess::Write));
+        assert!(!OrgRole::Owner.allows(SessionAccess::View));
+    }
+
+    #[test]
+    fn test_org_keeps_an_owner() {
+        let mut org = OrgData::new("  Design team ", "alice").unwrap();
+        assert_eq!(org.name, "Design team");
+        assert_eq!(org.role_of("alice"), Some(OrgRole::Owner));
+
+        assert!(matches!(
+            org.set_member("alice", OrgRole::Member),
+            Err(ApiError::InvalidOrg(_))
+        ));
+        assert!(matches!(
+            org.remove_member("alice"),
+            Err(ApiError::InvalidOrg(_))
+        ));
+
+        org.set_member("bob", OrgRole::Owner).unwrap();
+        org.set_member("alice", OrgRole::Viewer).unwrap();
+        assert!(org.is_last_owner("bob"));
+        assert!(org.remove_member("alice").unwrap());
+        assert!(!org.remove_member("alice").unwrap());
+    }
+
+    #[test]
+    fn test_org_limits() {
+        assert!(OrgData::new("   ", "alice").is_err());
+        assert!(OrgData::new(&"x".repeat(MAX_ORG_NAME_LEN + 1), "alice").is_err());
+
+        let mut org = OrgData::new("team", "alice").unwrap();
+        for i in 1..MAX_ORG_MEMBERS {
+            org.set_member(&format!("user{i}"), OrgRole::Viewer)
+                .unwrap();
+        }
+        assert!(org.set_member("late", OrgRole::Viewer).is_err());
+        // 이미 있는 멤버의 역할은 바꿀 수 있습니다.
+        org.set_member("user1", OrgRole::Member).unwrap();
+    }
+}
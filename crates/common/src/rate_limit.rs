@@ -0,0 +1,646 @@
+//! 로그인 시도 제한 (비밀번호 대입과 Argon2 CPU 소모 방지)과 사용자별 API 할당량.
+//!
+//! 비밀번호를 검증하기 전에 사용자별, IP별 sliding window로 시도 횟수를 세고,
+//! 연속으로 실패한 사용자는 실패할 때마다 두 배씩 길어지는 시간 동안 잠급니다.
+//! 세션 수, 템플릿과 인자 크기, 세션 업데이트 빈도, IP별 동시 스트림 수도 제한합니다.
+//! 모든 상태는 Redis에 있어 여러 인스턴스가 함께 씁니다.
+
+use std::net::IpAddr;
+use std::time::Duration;
+
+use redis::aio::MultiplexedConnection;
+use redis::AsyncCommands;
+use serde::{Deserialize, Serialize};
+
+use axum::http::StatusCode;
+
+use crate::directory::SessionRef;
+use crate::errors::ApiError;
+use crate::keys::Keys;
+use crate::session_data::SessionData;
+
+/// 창 안의 시도 수가 `limit` 이상이면 가장 오래된 시도가 창을 벗어날 때까지 남은 ms를,
+/// 아니면 이번 시도를 기록하고 0을 돌려줍니다.
+const SLIDING_WINDOW_SCRIPT: &str = r"
+local now = tonumber(ARGV[1])
+local window = tonumber(ARGV[2])
+redis.call('ZREMRANGEBYSCORE', KEYS[1], '-inf', now - window)
+if redis.call('ZCARD', KEYS[1]) >= tonumber(ARGV[3]) then
+  local oldest = redis.call('ZRANGE', KEYS[1], 0, 0, 'WITHSCORES')
+  return math.max(tonumber(oldest[2]) + window - now, 1)
+end
+redis.call('ZADD', KEYS[1], now, ARGV[4])
+redis.call('PEXPIRE', KEYS[1], window)
+return 0
+";
+
+/// 만료된 자리를 정리한 뒤 자리가 남아 있으면 `ARGV[4]`에 임대 시간만큼 자리를 주고 1을,
+/// 가득 찼으면 0을 돌려줍니다.
+const STREAM_SLOT_SCRIPT: &str = r"
+local now = tonumber(ARGV[1])
+redis.call('ZREMRANGEBYSCORE', KEYS[1], '-inf', now)
+if redis.call('ZCARD', KEYS[1]) >= tonumber(ARGV[2]) then
+  return 0
+end
+redis.call('ZADD', KEYS[1], now + tonumber(ARGV[3]), ARGV[4])
+redis.call('PEXPIRE', KEYS[1], ARGV[3])
+return 1
+";
+
+/// 세션 업데이트 횟수를 세는 창 (1분).
+const SESSION_UPDATE_WINDOW_SECONDS: u64 = 60;
+
+/// 스트림 하나가 차지한 자리의 임대 시간. 서버가 죽어 반납하지 못한 자리는 이만큼 지나면 풀립니다.
+const STREAM_SLOT_LEASE: Duration = Duration::from_secs(60);
+
+/// 열려 있는 스트림이 자리의 임대를 연장하는 주기.
+pub const STREAM_SLOT_RENEW_INTERVAL: Duration = Duration::from_secs(20);
+
+/// 요청 제한 정책. 횟수나 크기 제한을 0으로 두면 그 제한을 쓰지 않습니다.
+///
+/// # Fields
+/// - `trust_forwarded_for`: 리버스 프록시가 붙인 `X-Forwarded-For`의 마지막 주소를 클라이언트 IP로 씀
+/// - `login_ip_limit`: IP 하나가 `login_window_seconds` 동안 시도할 수 있는 로그인 횟수
+/// - `login_user_limit`: 사용자 ID 하나에 `login_window_seconds` 동안 시도할 수 있는 로그인 횟수
+/// - `login_lockout_threshold`: 이만큼 연속으로 실패하면 계정을 잠금
+/// - `login_lockout_base_seconds`: 첫 잠금 시간. 그 뒤로 실패할 때마다 두 배
+/// - `login_lockout_max_seconds`: 잠금 시간 상한. 실패 횟수도 이 시간 동안 실패가 없으면 잊힘
+/// - `max_sessions_per_user`: 사용자 하나가 가질 수 있는 세션 수. 조직 세션은 조직마다 따로 셈
+/// - `max_template_bytes`: 세션 템플릿 크기 (바이트)
+/// - `max_args_bytes`: JSON으로 직렬화한 세션 인자 크기 (바이트)
+/// - `session_updates_per_minute`: 세션 하나에 1분 동안 보낼 수 있는 업데이트 수
+/// - `max_streams_per_ip`: IP 하나가 동시에 열 수 있는 스트림 수
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitPolicy {
+    pub trust_forwarded_for: bool,
+    pub login_ip_limit: u32,
+    pub login_user_limit: u32,
+    pub login_window_seconds: u64,
+    pub login_lockout_threshold: u32,
+    pub login_lockout_base_seconds: u64,
+    pub login_lockout_max_seconds: u64,
+    pub max_sessions_per_user: u32,
+    pub max_template_bytes: u64,
+    pub max_args_bytes: u64,
+    pub session_updates_per_minute: u32,
+    pub max_streams_per_ip: u32,
+}
+
+impl RateLimitPolicy {
+    /// `failures`번 연속 실패한 뒤의 잠금 시간 (초). 잠그지 않으면 `None`.
+    pub fn lockout_seconds(&self, failures: u32) -> Option<u64> {
+        if self.login_lockout_threshold == 0 || failures < self.login_lockout_threshold {
+            return None;
+        }
+        let doublings = (failures - self.login_lockout_threshold).min(32);
+        Some(
+            self.login_lockout_base_seconds
+                .saturating_mul(1u64 << doublings)
+                .min(self.login_lockout_max_seconds)
+                .max(1),
+        )
+    }
+
+    /// 세션의 템플릿과 인자가 크기 제한 안에 있는지 확인합니다.
+    ///
+    /// # Errors
+    /// 넘으면 `ApiError::QuotaExceeded` (413).
+    pub fn check_session_size(&self, session: &SessionData) -> Result<(), ApiError> {
+        let template_bytes = session.template.len() as u64;
+        if self.max_template_bytes > 0 && template_bytes > self.max_template_bytes {
+            return Err(QuotaExceeded::new(Quota::TemplateBytes, self.max_template_bytes).into());
+        }
+        let args_bytes = serde_json::to_vec(&session.args)?.len() as u64;
+        if self.max_args_bytes > 0 && args_bytes > self.max_args_bytes {
+            return Err(QuotaExceeded::new(Quota::ArgsBytes, self.max_args_bytes).into());
+        }
+        Ok(())
+    }
+}
+
+impl Default for RateLimitPolicy {
+    fn default() -> Self {
+        Self {
+            trust_forwarded_for: false,
+            login_ip_limit: 100,
+            login_user_limit: 20,
+            login_window_seconds: 15 * 60,
+            login_lockout_threshold: 5,
+            login_lockout_base_seconds: 30,
+            login_lockout_max_seconds: 60 * 60,
+            max_sessions_per_user: 100,
+            max_template_bytes: 256 * 1024,
+            max_args_bytes: 64 * 1024,
+            session_updates_per_minute: 600,
+            max_streams_per_ip: 50,
+        }
+    }
+}
+
+/// 사용자별 할당량의 종류. 오류 응답의 `quota` 값으로 쓰입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Quota {
+    Sessions,
+    TemplateBytes,
+    ArgsBytes,
+    SessionUpdates,
+    Streams,
+}
+
+/// 할당량을 넘은 요청. `ApiError::QuotaExceeded`로 응답합니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuotaExceeded {
+    pub quota: Quota,
+    pub limit: u64,
+    /// 다시 시도할 수 있을 때까지 남은 초. 기다려도 풀리지 않는 할당량이면 `None`.
+    pub retry_after: Option<u64>,
+}
+
+impl QuotaExceeded {
+    pub fn new(quota: Quota, limit: u64) -> Self {
+        Self {
+            quota,
+            limit,
+            retry_after: None,
+        }
+    }
+
+    /// 크기 제한은 413, 개수와 빈도 제한은 429.
+    pub fn status(&self) -> StatusCode {
+        match self.quota {
+            Quota::TemplateBytes | Quota::ArgsBytes => StatusCode::PAYLOAD_TOO_LARGE,
+            Quota::Sessions | Quota::SessionUpdates | Quota::Streams => {
+                StatusCode::TOO_MANY_REQUESTS
+            }
+        }
+    }
+
+    pub fn message(&self) -> String {
+        match self.quota {
+            Quota::Sessions => format!("세션은 최대 {}개까지 만들 수 있습니다", self.limit),
+            Quota::TemplateBytes => format!("템플릿은 최대 {}바이트입니다", self.limit),
+            Quota::ArgsBytes => format!("세션 인자는 최대 {}바이트입니다", self.limit),
+            Quota::SessionUpdates => {
+                format!(
+                    "세션은 1분에 최대 {}번까지 업데이트할 수 있습니다",
+                    self.limit
+                )
+            }
+            Quota::Streams => format!("스트림은 동시에 최대 {}개까지 열 수 있습니다", self.limit),
+        }
+    }
+}
+
+impl From<QuotaExceeded> for ApiError {
+    fn from(value: QuotaExceeded) -> Self {
+        ApiError::QuotaExceeded(value)
+    }
+}
+
+/// 할당량 하나의 사용량. `limit`이 0이면 제한이 없습니다.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct QuotaUsage {
+    pub used: u64,
+    pub limit: u64,
+}
+
+/// `GET /api/user/{user_id}/usage` 응답.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct UserUsage {
+    pub sessions: QuotaUsage,
+    /// 크기 제한은 세션마다 적용되므로 `used`는 가장 큰 세션의 값입니다.
+    pub template_bytes: QuotaUsage,
+    pub args_bytes: QuotaUsage,
+    /// 세션 하나에 1분 동안 보낼 수 있는 업데이트 수
+    pub session_updates_per_minute: u64,
+    /// IP 하나가 동시에 열 수 있는 스트림 수
+    pub streams_per_ip: u64,
+}
+
+impl UserUsage {
+    pub fn collect(policy: &RateLimitPolicy, sessions: &[SessionData]) -> Self {
+        let largest = |size: fn(&SessionData) -> u64| sessions.iter().map(size).max().unwrap_or(0);
+        Self {
+            sessions: QuotaUsage {
+                used: sessions.len() as u64,
+                limit: policy.max_sessions_per_user as u64,
+            },
+            template_bytes: QuotaUsage {
+                used: largest(|session| session.template.len() as u64),
+                limit: policy.max_template_bytes,
+            },
+            args_bytes: QuotaUsage {
+                used: largest(|session| {
+                    serde_json::to_vec(&session.args).map_or(0, |args| args.len() as u64)
+                }),
+                limit: policy.max_args_bytes,
+            },
+            session_updates_per_minute: policy.session_updates_per_minute as u64,
+            streams_per_ip: policy.max_streams_per_ip as u64,
+        }
+    }
+}
+
+/// 로그인 시도를 거절한 이유. 관리자 통계의 카운터 이름으로도 쓰입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoginRejection {
+    IpRateLimited,
+    UserRateLimited,
+    LockedOut,
+    InvalidCredentials,
+}
+
+impl LoginRejection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LoginRejection::IpRateLimited => "ip_rate_limited",
+            LoginRejection::UserRateLimited => "user_rate_limited",
+            LoginRejection::LockedOut => "locked_out",
+            LoginRejection::InvalidCredentials => "invalid_credentials",
+        }
+    }
+}
+
+/// Redis에 누적된 로그인 거절 횟수 (모든 인스턴스 합계).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LoginRejectionStats {
+    pub ip_rate_limited: u64,
+    pub user_rate_limited: u64,
+    pub locked_out: u64,
+    pub invalid_credentials: u64,
+}
+
+/// `key`의 sliding window에 시도를 하나 기록합니다.
+///
+/// 창이 가득 차 있으면 기록하지 않고 다시 시도할 수 있을 때까지 남은 초를 돌려줍니다.
+pub async fn hit_sliding_window(
+    conn: &mut MultiplexedConnection,
+    key: &str,
+    limit: u32,
+    window_seconds: u64,
+) -> Result<Option<u64>, ApiError> {
+    if limit == 0 {
+        return Ok(None);
+    }
+    let now_ms = chrono::Utc::now().timestamp_millis();
+    let retry_after_ms: u64 = redis::Script::new(SLIDING_WINDOW_SCRIPT)
+        .key(key)
+        .arg(now_ms)
+        .arg(window_seconds.saturating_mul(1000))
+        .arg(limit)
+        .arg(uuid::Uuid::new_v4().to_string())
+        .invoke_async(conn)
+        .await?;
+    Ok((retry_after_ms > 0).then(|| retry_after_ms.div_ceil(1000)))
+}
+
+/// 잠금과 사용자별, IP별 시도 횟수를 확인하고 이번 시도를 기록합니다.
+///
+/// # Errors
+/// 잠겨 있거나 횟수를 넘었으면 `ApiError::RateLimited` (429).
+pub async fn check_login(
+    conn: &mut MultiplexedConnection,
+    keys: &Keys,
+    policy: &RateLimitPolicy,
+    user_id: &str,
+    ip: Option<IpAddr>,
+) -> Result<(), ApiError> {
+    let locked_ms: i64 = redis::cmd("PTTL")
+        .arg(keys.login_lockout(user_id))
+        .query_async(conn)
+        .await?;
+    if locked_ms > 0 {
+        tracing::warn!("Login rejected: {} is locked out", user_id);
+        record_rejection(conn, keys, LoginRejection::LockedOut).await?;
+        return Err(ApiError::RateLimited((locked_ms as u64).div_ceil(1000)));
+    }
+
+    if let Some(ip) = ip {
+        let key = keys.login_attempts_ip(&ip.to_string());
+        let limited = hit_sliding_window(
+            conn,
+            &key,
+            policy.login_ip_limit,
+            policy.login_window_seconds,
+        )
+        .await?;
+        if let Some(retry_after) = limited {
+            tracing::warn!("Login rejected: too many attempts from {}", ip);
+            record_rejection(conn, keys, LoginRejection::IpRateLimited).await?;
+            return Err(ApiError::RateLimited(retry_after));
+        }
+    }
+
+    let key = keys.login_attempts_user(user_id);
+    let limited = hit_sliding_window(
+        conn,
+        &key,
+        policy.login_user_limit,
+        policy.login_window_seconds,
+    )
+    .await?;
+    if let Some(retry_after) = limited {
+        tracing::warn!("Login rejected: too many attempts for {}", user_id);
+        record_rejection(conn, keys, LoginRejection::UserRateLimited).await?;
+        return Err(ApiError::RateLimited(retry_after));
+    }
+    Ok(())
+}
+
+/// 가입 시도를 IP별 횟수에 기록합니다. 가입도 Argon2 해시를 만들므로 로그인과 같은
+/// IP별 sliding window를 함께 씁니다.
+///
+/// # Errors
+/// 횟수를 넘었으면 `ApiError::RateLimited` (429).
+pub async fn check_registration(
+    conn: &mut MultiplexedConnection,
+    keys: &Keys,
+    policy: &RateLimitPolicy,
+    ip: Option<IpAddr>,
+) -> Result<(), ApiError> {
+    let Some(ip) = ip else {
+        return Ok(());
+    };
+    let limited = hit_sliding_window(
+        conn,
+        &keys.login_attempts_ip(&ip.to_string()),
+        policy.login_ip_limit,
+        policy.login_window_seconds,
+    )
+    .await?;
+    if let Some(retry_after) = limited {
+        tracing::warn!("Registration rejected: too many attempts from {}", ip);
+        record_rejection(conn, keys, LoginRejection::IpRateLimited).await?;
+        return Err(ApiError::RateLimited(retry_after));
+    }
+    Ok(())
+}
+
+/// 실패를 세고, 연속 실패가 기준을 넘으면 사용자를 잠급니다. 잠금 시간(초)을 돌려줍니다.
+pub async fn record_login_failure(
+    conn: &mut MultiplexedConnection,
+    keys: &Keys,
+    policy: &RateLimitPolicy,
+    user_id: &str,
+) -> Result<Option<u64>, ApiError> {
+    record_rejection(conn, keys, LoginRejection::InvalidCredentials).await?;
+
+    let failures_key = keys.login_failures(user_id);
+    let (failures,): (u32,) = redis::pipe()
+        .atomic()
+        .incr(&failures_key, 1)
+        .expire(
+            &failures_key,
+            policy
+                .login_lockout_max_seconds
+                .max(policy.login_window_seconds) as i64,
+        )
+        .ignore()
+        .query_async(conn)
+        .await?;
+
+    let lockout = policy.lockout_seconds(failures);
+    if let Some(seconds) = lockout {
+        tracing::warn!(
+            "Locking out {} for {}s after {} failed logins",
+            user_id,
+            seconds,
+            failures
+        );
+        let _: () = conn
+            .set_ex(keys.login_lockout(user_id), failures, seconds)
+            .await?;
+    }
+    Ok(lockout)
+}
+
+/// 로그인에 성공하면 연속 실패 횟수를 지웁니다.
+pub async fn record_login_success(
+    conn: &mut MultiplexedConnection,
+    keys: &Keys,
+    user_id: &str,
+) -> Result<(), ApiError> {
+    let _: () = conn.del(keys.login_failures(user_id)).await?;
+    Ok(())
+}
+
+async fn record_rejection(
+    conn: &mut MultiplexedConnection,
+    keys: &Keys,
+    reason: LoginRejection,
+) -> Result<(), ApiError> {
+    let _: () = conn
+        .hincr(keys.login_rejections(), reason.as_str(), 1)
+        .await?;
+    Ok(())
+}
+
+/// 누적된 로그인 거절 횟수.
+pub async fn login_rejection_stats(
+    conn: &mut MultiplexedConnection,
+    keys: &Keys,
+) -> Result<LoginRejectionStats, ApiError> {
+    let counts: std::collections::HashMap<String, u64> =
+        conn.hgetall(keys.login_rejections()).await?;
+    let count = |reason: LoginRejection| counts.get(reason.as_str()).copied().unwrap_or(0);
+    Ok(LoginRejectionStats {
+        ip_rate_limited: count(LoginRejection::IpRateLimited),
+        user_rate_limited: count(LoginRejection::UserRateLimited),
+        locked_out: count(LoginRejection::LockedOut),
+        invalid_credentials: count(LoginRejection::InvalidCredentials),
+    })
+}
+
+/// 세션 업데이트를 하나 기록합니다.
+///
+/// # Errors
+/// 1분 동안 `session_updates_per_minute`번을 넘으면 `ApiError::QuotaExceeded` (429).
+pub async fn check_session_update(
+    conn: &mut MultiplexedConnection,
+    keys: &Keys,
+    policy: &RateLimitPolicy,
+    session_ref: &SessionRef,
+) -> Result<(), ApiError> {
+    let limited = hit_sliding_window(
+        conn,
+        &keys.session_updates(session_ref),
+        policy.session_updates_per_minute,
+        SESSION_UPDATE_WINDOW_SECONDS,
+    )
+    .await?;
+    match limited {
+        Some(retry_after) => Err(QuotaExceeded {
+            quota: Quota::SessionUpdates,
+            limit: policy.session_updates_per_minute as u64,
+            retry_after: Some(retry_after),
+        }
+        .into()),
+        None => Ok(()),
+    }
+}
+
+/// IP별 동시 스트림 수를 세기 위해 열려 있는 스트림 하나가 차지한 자리.
+///
+/// 스트림이 열려 있는 동안 [`STREAM_SLOT_RENEW_INTERVAL`]마다 [`StreamSlot::renew`]를 불러야 하며,
+/// drop되면 자리를 반납합니다.
+pub struct StreamSlot {
+    redis: redis::Client,
+    key: String,
+    id: String,
+}
+
+impl StreamSlot {
+    /// `ip`의 자리를 하나 잡습니다.
+    ///
+    /// # Errors
+    /// 자리가 없으면 `ApiError::QuotaExceeded` (429).
+    pub async fn acquire(
+        redis: &redis::Client,
+        keys: &Keys,
+        policy: &RateLimitPolicy,
+        ip: IpAddr,
+    ) -> Result<Self, ApiError> {
+        let key = keys.stream_slots_ip(&ip.to_string());
+        let id = uuid::Uuid::new_v4().to_string();
+        let mut conn = redis.get_multiplexed_async_connection().await?;
+        let acquired: bool = redis::Script::new(STREAM_SLOT_SCRIPT)
+            .key(&key)
+            .arg(chrono::Utc::now().timestamp_millis())
+            .arg(policy.max_streams_per_ip)
+            .arg(STREAM_SLOT_LEASE.as_millis() as u64)
+            .arg(&id)
+            .invoke_async(&mut conn)
+            .await?;
+        if !acquired {
+            tracing::warn!("Stream rejected: too many concurrent streams from {}", ip);
+            return Err(
+                QuotaExceeded::new(Quota::Streams, policy.max_streams_per_ip as u64).into(),
+            );
+        }
+        Ok(Self {
+            redis: redis.clone(),
+            key,
+            id,
+        })
+    }
+
+    /// 자리의 임대를 연장합니다.
+    pub async fn renew(&self) -> Result<(), ApiError> {
+        let lease_ms = STREAM_SLOT_LEASE.as_millis() as u64;
+        let expires_at = chrono::Utc::now().timestamp_millis() as u64 + lease_ms;
+        let mut conn = self.redis.get_multiplexed_async_connection().await?;
+        redis::pipe()
+            .atomic()
+            .cmd("ZADD")
+            .arg(&self.key)
+            .arg("XX")
+            .arg(expires_at)
+            .arg(&self.id)
+            .ignore()
+            .pexpire(&self.key, lease_ms as i64)
+            .ignore()
+            .query_async::<()>(&mut conn)
+            .await?;
+        Ok(())
+    }
+}
+
+impl Drop for StreamSlot {
+    fn drop(&mut self) {
+        let redis = self.redis.clone();
+        let key = std::mem::take(&mut self.key);
+        let id = std::mem::take(&mut self.id);
+        tokio::spawn(async move {
+            let released: Result<(), redis::RedisError> = async {
+                let mut conn = redis.get_multiplexed_async_connection().await?;
+                conn.zrem(&key, &id).await
+            }
+            .await;
+            if let Err(err) = released {
+                tracing::warn!(%err, "스트림 자리를 반납하지 못했습니다. 임대 시간이 지나면 풀립니다");
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lockout_doubles_after_threshold() {
+        let policy = RateLimitPolicy::default();
+
+        assert_eq!(policy.lockout_seconds(0), None);
+        assert_eq!(policy.lockout_seconds(4), None);
+        assert_eq!(policy.lockout_seconds(5), Some(30));
+        assert_eq!(policy.lockout_seconds(6), Some(60));
+        assert_eq!(policy.lockout_seconds(8), Some(240));
+        assert_eq!(policy.lockout_seconds(20), Some(60 * 60));
+        assert_eq!(policy.lockout_seconds(u32::MAX), Some(60 * 60));
+    }
+
+    #[test]
+    fn test_lockout_disabled_with_zero_threshold() {
+        let policy = RateLimitPolicy {
+            login_lockout_threshold: 0,
+            ..Default::default()
+        };
+        assert_eq!(policy.lockout_seconds(100), None);
+    }
+
+    #[test]
+    fn test_check_session_size() {
+        let policy = RateLimitPolicy {
+            max_template_bytes: 10,
+            max_args_bytes: 16,
+            ..Default::default()
+        };
+        let args = |value: &str| std::collections::HashMap::from([("k".to_string(), value.into())]);
+
+        assert!(policy
+            .check_session_size(&SessionData::new("<svg/>", args("v")))
+            .is_ok());
+        assert!(matches!(
+            policy.check_session_size(&SessionData::new("<svg></svg>", args("v"))),
+            Err(ApiError::QuotaExceeded(QuotaExceeded {
+                quota: Quota::TemplateBytes,
+                limit: 10,
+                ..
+            }))
+        ));
+        assert!(matches!(
+            policy.check_session_size(&SessionData::new("<svg/>", args("0123456789"))),
+            Err(ApiError::QuotaExceeded(QuotaExceeded {
+                quota: Quota::ArgsBytes,
+                ..
+            }))
+        ));
+
+        let unlimited = RateLimitPolicy {
+            max_template_bytes: 0,
+            max_args_bytes: 0,
+            ..Default::default()
+        };
+        assert!(unlimited
+            .check_session_size(&SessionData::new("x".repeat(1 << 20), args("v")))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_user_usage_reports_largest_session() {
+        let policy = RateLimitPolicy::default();
+        let sessions = [
+            SessionData::new("<svg/>", Default::default()),
+            SessionData::new("<svg></svg>", Default::default()),
+        ];
+
+        let usage = UserUsage::collect(&policy, &sessions);
+        assert_eq!(usage.sessions.used, 2);
+        assert_eq!(usage.sessions.limit, 100);
+        assert_eq!(usage.template_bytes.used, 11);
+        assert_eq!(usage.args_bytes.used, 2);
+        assert_eq!(usage.streams_per_ip, 50);
+    }
+}
@@ -0,0 +1,75 @@
+//! 서버에 저장되는 회전식 refresh 토큰.
+//!
+//! 토큰 원문은 클라이언트만 가지고 Redis에는 SHA-256 해시만 저장합니다.
+//! 한 번의 로그인에서 이어지는 토큰들은 같은 family에 속하며, family 키에는
+//! 현재 유효한 토큰의 해시가 들어 있습니다. 이미 교체된 토큰이 다시 쓰이면
+//! (탈취된 토큰 재사용) family 전체가 폐기됩니다.
+
+use std::fmt::Write as _;
+
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// 토큰 해시별로 저장되는 정보. 교체된 뒤에도 만료될 때까지 남아 재사용을 감지합니다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RefreshTokenRecord {
+    pub user_id: String,
+    pub family: String,
+    /// 발급 당시 사용자의 `token_generation`. 비밀번호가 바뀌면 더 이상 교체되지 않습니다.
+    pub token_generation: u64,
+}
+
+pub(crate) fn random_hex(len: usize) -> String {
+    let mut bytes = vec![0u8; len];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    to_hex(&bytes)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .fold(String::with_capacity(bytes.len() * 2), |mut out, b| {
+            let _ = write!(out, "{:02x}", b);
+            out
+        })
+}
+
+/// 클라이언트에 전달할 새 토큰 (256비트 난수).
+pub fn generate() -> String {
+    random_hex(32)
+}
+
+/// 로그인마다 새로 만드는 family ID.
+pub fn new_family() -> String {
+    random_hex(16)
+}
+
+/// Redis 키에 쓰이는 토큰 해시.
+pub fn hash(token: &str) -> String {
+    to_hex(&Sha256::digest(token.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated_tokens_are_unique() {
+        let a = generate();
+        let b = generate();
+        assert_eq!(a.len(), 64);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_hash_is_stable_and_hides_token() {
+        let token = generate();
+        assert_eq!(hash(&token), hash(&token));
+        assert_ne!(hash(&token), token);
+        assert_eq!(
+            hash("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
@@ -0,0 +1,215 @@
+//! 토큰 권한 범위(scope).
+//!
+//! 로그인 토큰과 API 키는 범위 제한이 없습니다. 센서처럼 한 세션만 다뤄야 하는
+//! 클라이언트에는 `session:{user_id}/{session_id}:write` 같은 범위를 담은 토큰을
+//! 발급합니다. 세션 자리에 `*`를 쓴 `session:*:read`는 토큰 주인의 모든 세션이
+//! 대상입니다. 범위는 토큰 주인(`sub`)의 세션에만 적용되며, `write`가 `read`를
+//! 포함하지는 않습니다. 범위의 `write`로는 세션 인자만 바꿀 수 있고, 세션 생성과 삭제,
+//! 메타데이터 변경에는 범위 제한이 없는 토큰이 필요합니다. `view`는 스트림과 스냅샷만
+//! 볼 수 있는 권한으로, 비공개 세션의 공유 링크에 쓰입니다.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::keys::{validate_session_id, validate_user_id};
+
+/// 세션에 대한 권한 종류.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SessionAccess {
+    /// 세션 내용과 목록 조회
+    Read,
+    /// 세션 생성, 인자/메타데이터 갱신, 삭제. 범위 제한 토큰은 인자 갱신만 할 수 있습니다.
+    Write,
+    /// 스트림과 스냅샷 보기 (공유 링크의 뷰어 토큰)
+    View,
+}
+
+impl SessionAccess {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SessionAccess::Read => "read",
+            SessionAccess::Write => "write",
+            SessionAccess::View => "view",
+        }
+    }
+}
+
+impl fmt::Display for SessionAccess {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 범위가 가리키는 세션.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ScopeTarget {
+    /// `session:*` — 토큰 주인의 모든 세션
+    AllSessions,
+    /// `session:{user_id}/{session_id}`
+    Session { user_id: String, session_id: String },
+}
+
+/// 토큰의 `scopes` 클레임에 들어가는 권한 하나. 문자열로 직렬화됩니다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Scope {
+    pub target: ScopeTarget,
+    pub access: SessionAccess,
+}
+
+impl Scope {
+    /// 세션 하나에 대한 범위.
+    pub fn session(
+        user_id: impl Into<String>,
+        session_id: impl Into<String>,
+        access: SessionAccess,
+    ) -> Self {
+        Self {
+            target: ScopeTarget::Session {
+                user_id: user_id.into(),
+                session_id: session_id.into(),
+            },
+            access,
+        }
+    }
+
+    /// 모든 세션에 대한 범위.
+    pub fn all_sessions(access: SessionAccess) -> Self {
+        Self {
+            target: ScopeTarget::AllSessions,
+            access,
+        }
+    }
+
+    /// `user_id`의 세션에 `access` 권한을 주는지 확인합니다.
+    ///
+    /// `session_id`가 `None`이면 세션 목록처럼 모든 세션에 대한 요청이므로
+    /// `session:*` 범위만 허용합니다.
+    pub fn allows(&self, user_id: &str, session_id: Option<&str>, access: SessionAccess) -> bool {
+        if self.access != access {
+            return false;
+        }
+        match (&self.target, session_id) {
+            (ScopeTarget::AllSessions, _) => true,
+            (
+                ScopeTarget::Session {
+                    user_id: scope_user,
+                    session_id: scope_session,
+                },
+                Some(session_id),
+            ) => scope_user == user_id && scope_session == session_id,
+            (ScopeTarget::Session { .. }, None) => false,
+        }
+    }
+}
+
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.target {
+            ScopeTarget::AllSessions => write!(f, "session:*:{}", self.access),
+            ScopeTarget::Session {
+                user_id,
+                session_id,
+            } => write!(f, "session:{}/{}:{}", user_id, session_id, self.access),
+        }
+    }
+}
+
+impl FromStr for Scope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("알 수 없는 권한 범위입니다: {}", s);
+        let (target, access) = s
+            .strip_prefix("session:")
+            .and_then(|rest| rest.rsplit_once(':'))
+            .ok_or_else(invalid)?;
+        let access = match access {
+            "read" => SessionAccess::Read,
+            "write" => SessionAccess::Write,
+            "view" => SessionAccess::View,
+            _ => return Err(invalid()),
+        };
+        if target == "*" {
+            return Ok(Self::all_sessions(access));
+        }
+        let (user_id, session_id) = target.split_once('/').ok_or_else(invalid)?;
+        if validate_user_id(user_id).is_err() || validate_session_id(session_id).is_err() {
+            return Err(invalid());
+        }
+        Ok(Self::session(user_id, session_id, access))
+    }
+}
+
+impl TryFrom<String> for Scope {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Scope> for String {
+    fn from(scope: Scope) -> Self {
+        scope.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scope_round_trip() {
+        for s in [
+            "session:alice/dashboard-1:write",
+            "session:alice/test.png:read",
+            "session:*:read",
+            "session:*:write",
+            "session:alice/private-1:view",
+        ] {
+            let scope: Scope = s.parse().unwrap();
+            assert_eq!(scope.to_string(), s);
+            assert_eq!(serde_json::to_string(&scope).unwrap(), format!("\"{}\"", s));
+        }
+        assert_eq!(
+            "session:alice/sensor:write".parse::<Scope>().unwrap(),
+            Scope::session("alice", "sensor", SessionAccess::Write)
+        );
+    }
+
+    #[test]
+    fn test_invalid_scopes() {
+        for s in [
+            "",
+            "session:alice/sensor",
+            "session:alice/sensor:admin",
+            "session:alice:write",
+            "session:alice/a/b:write",
+            "session:*/sensor:write",
+            "user:alice:write",
+            "session:alice/:write",
+        ] {
+            assert!(s.parse::<Scope>().is_err(), "{s}");
+        }
+        assert!(serde_json::from_str::<Scope>("\"session:*:delete\"").is_err());
+    }
+
+    #[test]
+    fn test_allows() {
+        let write = Scope::session("alice", "sensor", SessionAccess::Write);
+        assert!(write.allows("alice", Some("sensor"), SessionAccess::Write));
+        assert!(!write.allows("alice", Some("sensor"), SessionAccess::Read));
+        assert!(!write.allows("alice", Some("other"), SessionAccess::Write));
+        assert!(!write.allows("bob", Some("sensor"), SessionAccess::Write));
+        assert!(!write.allows("alice", None, SessionAccess::Write));
+
+        let read_all = Scope::all_sessions(SessionAccess::Read);
+        assert!(read_all.allows("alice", Some("sensor"), SessionAccess::Read));
+        assert!(read_all.allows("alice", None, SessionAccess::Read));
+        assert!(!read_all.allows("alice", Some("sensor"), SessionAccess::Write));
+    }
+}
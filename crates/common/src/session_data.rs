@@ -1,8 +1,144 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::{errors::ApiError, SvgFrame};
+use crate::{errors::ApiError, scope::SessionAccess, SvgFrame};
+
+pub const MAX_TITLE_LEN: usize = 100;
+pub const MAX_DESCRIPTION_LEN: usize = 500;
+pub const MAX_TAGS: usize = 10;
+pub const MAX_TAG_LEN: usize = 32;
+pub const MAX_COLLABORATORS: usize = 20;
+/// 소유권 이전 요청이 수락을 기다리는 기간 (7일).
+pub const TRANSFER_TTL_SECONDS: i64 = 7 * 86400;
+
+/// 세션 스트림을 누가 볼 수 있는지.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Visibility {
+    /// 누구나 볼 수 있고 디렉터리에 등록할 수 있습니다.
+    #[default]
+    Public,
+    /// ID를 아는 누구나 볼 수 있지만 디렉터리에는 등록할 수 없습니다.
+    Unlisted,
+    /// 스트림과 스냅샷에 공유 링크의 뷰어 토큰(`?token=`)이 필요합니다.
+    Private,
+}
+
+impl Visibility {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Visibility::Public => "public",
+            Visibility::Unlisted => "unlisted",
+            Visibility::Private => "private",
+        }
+    }
+
+    pub fn is_public(&self) -> bool {
+        *self == Visibility::Public
+    }
+}
+
+/// 소유자가 다른 사용자에게 준 세션 역할.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SessionRole {
+    /// 세션 조회와 인자/제목/설명/태그 수정. 공개 범위와 디렉터리 등록은 바꿀 수 없습니다.
+    Editor,
+    /// 세션 조회만 가능합니다.
+    Viewer,
+}
+
+impl SessionRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SessionRole::Editor => "editor",
+            SessionRole::Viewer => "viewer",
+        }
+    }
+
+    /// 이 역할로 `access`를 할 수 있는지. 스트림 보기(`view`)는 공개 범위를 따릅니다.
+    pub fn allows(&self, access: SessionAccess) -> bool {
+        match access {
+            SessionAccess::Read => true,
+            SessionAccess::Write => *self == SessionRole::Editor,
+            SessionAccess::View => false,
+        }
+    }
+}
+
+/// 받는 사용자의 수락을 기다리는 소유권 이전 요청.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PendingTransfer {
+    pub to: String,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// 세션 메타데이터 변경 요청.
+///
+/// 값이 있는 필드만 반영하며, 빈 문자열은 해당 값을 지웁니다.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SessionMetadataPatch {
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub tags: Option<Vec<String>>,
+    /// 공개 디렉터리 등록 여부. 등록하려면 제목이 있어야 합니다.
+    #[serde(default)]
+    pub listed: Option<bool>,
+    /// 공개 범위. 공개가 아니게 바꾸면 디렉터리 등록도 해제됩니다.
+    #[serde(default)]
+    pub visibility: Option<Visibility>,
+}
+
+impl SessionMetadataPatch {
+    /// 바꾸는 필드가 하나도 없는지. 범위 제한 토큰은 인자만 바꿀 수 있습니다.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// 공개 범위나 디렉터리 등록을 바꾸는 요청인지. 이 둘은 소유자만 바꿀 수 있습니다.
+    pub fn changes_sharing(&self, session: &SessionData) -> bool {
+        self.listed.is_some_and(|listed| listed != session.listed)
+            || self
+                .visibility
+                .is_some_and(|visibility| visibility != session.visibility)
+    }
+}
+
+/// 태그를 소문자로 정규화합니다. 문자, 숫자, `-`, `_` 만 허용합니다.
+pub fn normalize_tag(tag: &str) -> Result<String, ApiError> {
+    let tag = tag.trim().to_lowercase();
+    let len = tag.chars().count();
+    if len == 0 || len > MAX_TAG_LEN {
+        return Err(ApiError::InvalidMetadata(format!(
+            "태그는 1~{MAX_TAG_LEN}자여야 합니다: {tag}"
+        )));
+    }
+    if !tag
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(ApiError::InvalidMetadata(format!(
+            "태그에는 문자, 숫자, '-', '_' 만 사용할 수 있습니다: {tag}"
+        )));
+    }
+    Ok(tag)
+}
+
+fn normalize_text(field: &str, value: String, max_len: usize) -> Result<Option<String>, ApiError> {
+    let value = value.trim();
+    if value.chars().count() > max_len {
+        return Err(ApiError::InvalidMetadata(format!(
+            "{field}은(는) {max_len}자를 넘을 수 없습니다"
+        )));
+    }
+    Ok((!value.is_empty()).then(|| value.to_string()))
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct SessionData {
@@ -10,14 +146,46 @@ pub struct SessionData {
     pub args: HashMap<String, serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub owner: Option<String>,
+    /// 이 필드가 도입되기 전에 만들어진 세션에는 값이 없습니다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub listed: bool,
+    /// 이 필드가 도입되기 전에 만들어진 세션은 공개입니다.
+    #[serde(default, skip_serializing_if = "Visibility::is_public")]
+    pub visibility: Visibility,
+    /// 협업자 사용자 ID별 역할.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub collaborators: BTreeMap<String, SessionRole>,
+    /// 수락을 기다리는 소유권 이전 요청.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pending_transfer: Option<PendingTransfer>,
 }
 
 impl SessionData {
     pub fn new(template: impl Into<String>, args: HashMap<String, serde_json::Value>) -> Self {
+        let now = Utc::now();
         Self {
             template: template.into(),
             args,
             owner: None,
+            created_at: Some(now),
+            updated_at: Some(now),
+            title: None,
+            description: None,
+            tags: Vec::new(),
+            listed: false,
+            visibility: Visibility::Public,
+            collaborators: BTreeMap::new(),
+            pending_transfer: None,
         }
     }
 
@@ -26,10 +194,20 @@ impl SessionData {
         args: HashMap<String, serde_json::Value>,
         owner: String,
     ) -> Self {
+        let now = Utc::now();
         Self {
             template: template.into(),
             args,
             owner: Some(owner),
+            created_at: Some(now),
+            updated_at: Some(now),
+            title: None,
+            description: None,
+            tags: Vec::new(),
+            listed: false,
+            visibility: Visibility::Public,
+            collaborators: BTreeMap::new(),
+            pending_transfer: None,
         }
     }
 
@@ -55,6 +233,149 @@ impl SessionData {
         &self.args
     }
 
+    /// 메타데이터 변경을 검증 후 반영합니다. 검증에 실패하면 아무것도 바뀌지 않습니다.
+    pub fn apply_metadata(&mut self, patch: SessionMetadataPatch) -> Result<(), ApiError> {
+        let title = patch
+            .title
+            .map(|title| normalize_text("제목", title, MAX_TITLE_LEN))
+            .transpose()?;
+        let description = patch
+            .description
+            .map(|description| normalize_text("설명", description, MAX_DESCRIPTION_LEN))
+            .transpose()?;
+        let tags = match patch.tags {
+            Some(raw) => {
+                let mut tags: Vec<String> = Vec::with_capacity(raw.len());
+                for tag in raw {
+                    let tag = normalize_tag(&tag)?;
+                    if !tags.contains(&tag) {
+                        tags.push(tag);
+                    }
+                }
+                if tags.len() > MAX_TAGS {
+                    return Err(ApiError::InvalidMetadata(format!(
+                        "태그는 최대 {MAX_TAGS}개까지 지정할 수 있습니다"
+                    )));
+                }
+                Some(tags)
+            }
+            None => None,
+        };
+
+        let visibility = patch.visibility.unwrap_or(self.visibility);
+        if !visibility.is_public() && self.owner.is_none() {
+            return Err(ApiError::InvalidMetadata(
+                "공용 세션과 조직 세션은 공개로만 둘 수 있습니다".to_string(),
+            ));
+        }
+        let listed = patch
+            .listed
+            .unwrap_or(self.listed && visibility.is_public());
+        if listed && !visibility.is_public() {
+            return Err(ApiError::InvalidMetadata(
+                "디렉터리에는 공개 세션만 등록할 수 있습니다".to_string(),
+            ));
+        }
+        let has_title = match &title {
+            Some(title) => title.is_some(),
+            None => self.title.is_some(),
+        };
+        if listed && !has_title {
+            return Err(ApiError::InvalidMetadata(
+                "디렉터리에 등록하려면 제목이 필요합니다".to_string(),
+            ));
+        }
+
+        self.listed = listed;
+        self.visibility = visibility;
+        if let Some(title) = title {
+            self.title = title;
+        }
+        if let Some(description) = description {
+            self.description = description;
+        }
+        if let Some(tags) = tags {
+            self.tags = tags;
+        }
+        Ok(())
+    }
+
+    /// `user_id`가 협업자로서 가진 역할. 소유자와 협업자가 아닌 사용자는 `None`입니다.
+    pub fn role_of(&self, user_id: &str) -> Option<SessionRole> {
+        self.collaborators.get(user_id).copied()
+    }
+
+    /// 협업자를 추가하거나 역할을 바꿉니다.
+    pub fn set_collaborator(&mut self, user_id: &str, role: SessionRole) -> Result<(), ApiError> {
+        let Some(owner) = &self.owner else {
+            return Err(ApiError::InvalidCollaborator(
+                "소유자가 없는 공용 세션은 공유할 수 없습니다".to_string(),
+            ));
+        };
+        if owner == user_id {
+            return Err(ApiError::InvalidCollaborator(
+                "소유자는 협업자로 추가할 수 없습니다".to_string(),
+            ));
+        }
+        if !self.collaborators.contains_key(user_id)
+            && self.collaborators.len() >= MAX_COLLABORATORS
+        {
+            return Err(ApiError::InvalidCollaborator(format!(
+                "협업자는 최대 {MAX_COLLABORATORS}명까지 추가할 수 있습니다"
+            )));
+        }
+        self.collaborators.insert(user_id.to_string(), role);
+        Ok(())
+    }
+
+    /// 협업자를 제거합니다. 협업자였으면 `true`를 반환합니다.
+    pub fn remove_collaborator(&mut self, user_id: &str) -> bool {
+        self.collaborators.remove(user_id).is_some()
+    }
+
+    /// `to`에게 소유권 이전을 요청합니다. 다른 사용자에게 보낸 이전 요청이 있으면 대체하고 반환합니다.
+    pub fn request_transfer(
+        &mut self,
+        to: &str,
+        now: DateTime<Utc>,
+    ) -> Result<Option<PendingTransfer>, ApiError> {
+        let Some(owner) = &self.owner else {
+            return Err(ApiError::InvalidTransfer(
+                "소유자가 없는 세션은 이전할 수 없습니다".to_string(),
+            ));
+        };
+        if owner == to {
+            return Err(ApiError::InvalidTransfer(
+                "자기 자신에게는 이전할 수 없습니다".to_string(),
+            ));
+        }
+        Ok(self.pending_transfer.replace(PendingTransfer {
+            to: to.to_string(),
+            created_at: now,
+            expires_at: now + chrono::Duration::seconds(TRANSFER_TTL_SECONDS),
+        }))
+    }
+
+    /// `user_id`에게 보낸, 아직 만료되지 않은 소유권 이전 요청.
+    pub fn transfer_for(&self, user_id: &str, now: DateTime<Utc>) -> Option<&PendingTransfer> {
+        self.pending_transfer
+            .as_ref()
+            .filter(|transfer| transfer.to == user_id && transfer.expires_at > now)
+    }
+
+    /// 소유권 이전을 마칩니다. 이전 소유자가 준 협업자 권한은 넘어가지 않으므로 거두고 반환합니다.
+    pub fn complete_transfer(&mut self, new_owner: &str) -> BTreeMap<String, SessionRole> {
+        self.owner = Some(new_owner.to_string());
+        self.pending_transfer = None;
+        self.touch();
+        std::mem::take(&mut self.collaborators)
+    }
+
+    /// 수정 시각을 현재 시각으로 갱신합니다.
+    pub fn touch(&mut self) {
+        self.updated_at = Some(Utc::now());
+    }
+
     pub fn current_frame(&self) -> SvgFrame {
         let ctx =
             tera::Context::from_value(serde_json::to_value(self.args.clone()).unwrap()).unwrap();
@@ -72,3 +393,206 @@ impl TryFrom<&str> for SessionData {
         serde_json::from_str(value).map_err(ApiError::from)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patch(title: Option<&str>, tags: Option<Vec<&str>>) -> SessionMetadataPatch {
+        SessionMetadataPatch {
+            title: title.map(str::to_string),
+            description: None,
+            tags: tags.map(|tags| tags.into_iter().map(str::to_string).collect()),
+            listed: None,
+            visibility: None,
+        }
+    }
+
+    #[test]
+    fn test_apply_metadata_normalizes_tags() {
+        let mut session = SessionData::new("<svg></svg>", HashMap::new());
+        session
+            .apply_metadata(patch(
+                Some("  대시보드 "),
+                Some(vec!["Status", "status", "서버"]),
+            ))
+            .unwrap();
+
+        assert_eq!(session.title.as_deref(), Some("대시보드"));
+        assert_eq!(session.tags, vec!["status", "서버"]);
+    }
+
+    #[test]
+    fn test_apply_metadata_keeps_missing_fields_and_clears_empty() {
+        let mut session = SessionData::new("<svg></svg>", HashMap::new());
+        session
+            .apply_metadata(patch(Some("title"), Some(vec!["a"])))
+            .unwrap();
+
+        session.apply_metadata(patch(None, None)).unwrap();
+        assert_eq!(session.title.as_deref(), Some("title"));
+        assert_eq!(session.tags, vec!["a"]);
+
+        session
+            .apply_metadata(patch(Some(""), Some(vec![])))
+            .unwrap();
+        assert_eq!(session.title, None);
+        assert!(session.tags.is_empty());
+    }
+
+    #[test]
+    fn test_apply_metadata_rejects_invalid_tag_without_changes() {
+        let mut session = SessionData::new("<svg></svg>", HashMap::new());
+        let result = session.apply_metadata(patch(Some("title"), Some(vec!["no spaces"])));
+
+        assert!(matches!(result, Err(ApiError::InvalidMetadata(_))));
+        assert_eq!(session.title, None);
+    }
+
+    #[test]
+    fn test_collaborator_roles() {
+        let mut session =
+            SessionData::new_with_owner("<svg></svg>", HashMap::new(), "alice".to_string());
+        session
+            .set_collaborator("bob", SessionRole::Viewer)
+            .unwrap();
+        assert_eq!(session.role_of("bob"), Some(SessionRole::Viewer));
+        assert_eq!(session.role_of("alice"), None);
+        assert!(!SessionRole::Viewer.allows(SessionAccess::Write));
+
+        session
+            .set_collaborator("bob", SessionRole::Editor)
+            .unwrap();
+        assert!(session.role_of("bob").unwrap().allows(SessionAccess::Write));
+        assert!(matches!(
+            session.set_collaborator("alice", SessionRole::Editor),
+            Err(ApiError::InvalidCollaborator(_))
+        ));
+
+        let json = serde_json::to_string(&session).unwrap();
+        assert!(json.contains(r#""collaborators":{"bob":"editor"}"#));
+        assert!(session.remove_collaborator("bob"));
+        assert!(!session.remove_collaborator("bob"));
+        assert!(!serde_json::to_string(&session)
+            .unwrap()
+            .contains("collaborators"));
+
+        let mut public = SessionData::new("<svg></svg>", HashMap::new());
+        assert!(public.set_collaborator("bob", SessionRole::Viewer).is_err());
+    }
+
+    #[test]
+    fn test_transfer() {
+        let now = Utc::now();
+        let mut session =
+            SessionData::new_with_owner("<svg></svg>", HashMap::new(), "alice".to_string());
+        session
+            .set_collaborator("carol", SessionRole::Editor)
+            .unwrap();
+
+        assert!(matches!(
+            session.request_transfer("alice", now),
+            Err(ApiError::InvalidTransfer(_))
+        ));
+        assert_eq!(session.request_transfer("bob", now).unwrap(), None);
+        let replaced = session.request_transfer("dave", now).unwrap().unwrap();
+        assert_eq!(replaced.to, "bob");
+        assert!(session.transfer_for("bob", now).is_none());
+        assert!(session.transfer_for("dave", now).is_some());
+        let expired = now + chrono::Duration::seconds(TRANSFER_TTL_SECONDS);
+        assert!(session.transfer_for("dave", expired).is_none());
+
+        let revoked = session.complete_transfer("dave");
+        assert_eq!(revoked.keys().collect::<Vec<_>>(), vec!["carol"]);
+        assert_eq!(session.owner.as_deref(), Some("dave"));
+        assert!(session.pending_transfer.is_none());
+        assert!(session.collaborators.is_empty());
+
+        let mut public = SessionData::new("<svg></svg>", HashMap::new());
+        assert!(public.request_transfer("bob", now).is_err());
+    }
+
+    #[test]
+    fn test_changes_sharing() {
+        let session =
+            SessionData::new_with_owner("<svg></svg>", HashMap::new(), "alice".to_string());
+        assert!(!patch(Some("title"), None).changes_sharing(&session));
+        let unchanged = SessionMetadataPatch {
+            listed: Some(false),
+            visibility: Some(Visibility::Public),
+            ..Default::default()
+        };
+        assert!(!unchanged.changes_sharing(&session));
+        let private = SessionMetadataPatch {
+            visibility: Some(Visibility::Private),
+            ..Default::default()
+        };
+        assert!(private.changes_sharing(&session));
+    }
+
+    #[test]
+    fn test_listing_requires_title() {
+        let mut session = SessionData::new("<svg></svg>", HashMap::new());
+        let listed = SessionMetadataPatch {
+            listed: Some(true),
+            ..Default::default()
+        };
+        assert!(session.apply_metadata(listed.clone()).is_err());
+        assert!(!session.listed);
+
+        session.apply_metadata(patch(Some("title"), None)).unwrap();
+        session.apply_metadata(listed).unwrap();
+        assert!(session.listed);
+
+        // 등록된 상태에서 제목을 지울 수 없습니다.
+        assert!(session.apply_metadata(patch(Some(""), None)).is_err());
+    }
+
+    #[test]
+    fn test_visibility() {
+        let mut session =
+            SessionData::new_with_owner("<svg></svg>", HashMap::new(), "alice".into());
+        session.apply_metadata(patch(Some("title"), None)).unwrap();
+        session
+            .apply_metadata(SessionMetadataPatch {
+                listed: Some(true),
+                ..Default::default()
+            })
+            .unwrap();
+
+        // 공개가 아니게 바꾸면 등록이 해제되고, 함께 등록을 요청하면 거부합니다.
+        let private = SessionMetadataPatch {
+            visibility: Some(Visibility::Private),
+            ..Default::default()
+        };
+        assert!(session
+            .apply_metadata(SessionMetadataPatch {
+                listed: Some(true),
+                ..private.clone()
+            })
+            .is_err());
+        assert_eq!(session.visibility, Visibility::Public);
+        session.apply_metadata(private).unwrap();
+        assert_eq!(session.visibility, Visibility::Private);
+        assert!(!session.listed);
+
+        let json = serde_json::to_string(&session).unwrap();
+        assert!(json.contains("\"visibility\":\"private\""));
+        let public = SessionData::new("<svg></svg>", HashMap::new());
+        assert!(!serde_json::to_string(&public)
+            .unwrap()
+            .contains("visibility"));
+    }
+
+    #[test]
+    fn test_public_session_cannot_be_private() {
+        let mut session = SessionData::new("<svg></svg>", HashMap::new());
+        for visibility in [Visibility::Unlisted, Visibility::Private] {
+            let result = session.apply_metadata(SessionMetadataPatch {
+                visibility: Some(visibility),
+                ..Default::default()
+            });
+            assert!(matches!(result, Err(ApiError::InvalidMetadata(_))));
+        }
+    }
+}
@@ -0,0 +1,470 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use serde::Deserialize;
+
+use crate::{errors::ApiError, session_data::normalize_tag, SessionSummary};
+
+pub const DEFAULT_LIMIT: usize = 20;
+pub const MAX_LIMIT: usize = 100;
+/// 인덱스를 쓰지 않는 정렬(`created` 외)로 조회할 수 있는 최대 세션 수.
+pub const MAX_SORTED_SESSIONS: usize = 1000;
+
+/// 세션 목록 정렬 기준.
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionSort {
+    #[default]
+    Created,
+    Updated,
+    /// 제목 순 (대소문자 무시). 제목이 없으면 세션 ID를 씁니다.
+    Name,
+    Ttl,
+    Viewers,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    Asc,
+    #[default]
+    Desc,
+}
+
+/// `GET /api/user/{user_id}/session` 쿼리 파라미터.
+///
+/// 커서는 불투명한 문자열로 취급해야 하며, 응답의 `next_cursor` 값을
+/// 그대로 다음 요청에 같은 정렬, 필터와 함께 넘기면 됩니다.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SessionListQuery {
+    pub cursor: Option<String>,
+    pub limit: Option<usize>,
+    #[serde(default)]
+    pub sort: SessionSort,
+    #[serde(default)]
+    pub order: SortOrder,
+    /// 세션 ID 또는 제목에 포함되어야 하는 문자열 (대소문자 무시)
+    pub q: Option<String>,
+    /// 이 태그가 붙은 세션만 조회
+    pub tag: Option<String>,
+}
+
+impl SessionListQuery {
+    pub fn limit(&self) -> usize {
+        self.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT)
+    }
+
+    pub fn cursor(&self) -> Result<Option<ListCursor>, ApiError> {
+        ListCursor::parse(self.cursor.as_deref())
+    }
+
+    pub fn descending(&self) -> bool {
+        self.order == SortOrder::Desc
+    }
+
+    /// 생성 시각 정렬은 세션 인덱스의 점수 순서와 같으므로, 전체를 읽지 않고 인덱스에서
+    /// 한 페이지씩 읽을 수 있습니다 ([`Self::page_from_index`]).
+    pub fn uses_index(&self) -> bool {
+        self.sort == SessionSort::Created
+    }
+
+    /// 이름 검색(`q`)과 태그 필터를 확인하는 함수를 만듭니다.
+    pub fn matcher(&self) -> Result<impl Fn(&SessionSummary) -> bool, ApiError> {
+        let q = self
+            .q
+            .as_deref()
+            .map(str::trim)
+            .filter(|q| !q.is_empty())
+            .map(str::to_lowercase);
+        let tag = self
+            .tag
+            .as_deref()
+            .filter(|tag| !tag.trim().is_empty())
+            .map(normalize_tag)
+            .transpose()?;
+        Ok(move |item: &SessionSummary| {
+            let name_matches = q.as_ref().is_none_or(|q| {
+                item.session_id.to_lowercase().contains(q)
+                    || item
+                        .title
+                        .as_ref()
+                        .is_some_and(|title| title.to_lowercase().contains(q))
+            });
+            let tag_matches = tag.as_ref().is_none_or(|tag| item.tags.contains(tag));
+            name_matches && tag_matches
+        })
+    }
+
+    /// 정렬 값. 같은 값끼리는 세션 ID 순서로 정렬합니다.
+    fn sort_key(&self, item: &SessionSummary) -> i64 {
+        match self.sort {
+            SessionSort::Created => item.created_at.map_or(0, |at| at.timestamp_millis()),
+            SessionSort::Updated => item.updated_at.map_or(0, |at| at.timestamp_millis()),
+            // 이름 순서는 ID에 담습니다 ([`name_sort_id`]).
+            SessionSort::Name => 0,
+            // 만료가 없는 세션은 가장 오래 남은 것으로 취급합니다.
+            SessionSort::Ttl => item.ttl_seconds.unwrap_or(i64::MAX),
+            SessionSort::Viewers => i64::try_from(item.viewers).unwrap_or(i64::MAX),
+        }
+    }
+
+    /// 모든 항목을 필터링, 정렬한 뒤 커서 다음의 한 페이지를 잘라 반환합니다.
+    ///
+    /// 두 번째 반환값은 다음 페이지 커서이며 마지막 페이지이면 `None` 입니다.
+    /// 공유받은 세션은 소유자가 달라도 ID가 같을 수 있으므로 `{소유자}/{세션 ID}`로 구분합니다.
+    pub fn apply(
+        &self,
+        items: Vec<SessionSummary>,
+    ) -> Result<(Vec<SessionSummary>, Option<String>), ApiError> {
+        let cursor = self.cursor()?;
+        let matches = self.matcher()?;
+        let items = items.into_iter().filter(|item| matches(item)).collect();
+        Ok(page_sorted(
+            items,
+            cursor.as_ref(),
+            self.limit(),
+            self.descending(),
+            |item| {
+                let id = match &item.owner {
+                    Some(owner) => format!("{}/{}", owner, item.session_id),
+                    None => item.session_id.clone(),
+                };
+                let id = match self.sort {
+                    SessionSort::Name => name_sort_id(item.title.as_deref(), &item.session_id, &id),
+                    _ => id,
+                };
+                (self.sort_key(item), id)
+            },
+        ))
+    }
+
+    /// 정렬 값과 함께 인덱스 순서대로 읽은 최대 `limit + 1`개의 항목에서 한 페이지를 만듭니다.
+    pub fn page_from_index(
+        &self,
+        items: Vec<(i64, SessionSummary)>,
+    ) -> (Vec<SessionSummary>, Option<String>) {
+        let items = items
+            .into_iter()
+            .map(|(key, item)| ((key, item.session_id.clone()), item))
+            .collect();
+        next_page(items, self.limit())
+    }
+
+    /// 생성 시각이 아닌 정렬은 모든 세션을 읽어 정렬하므로, 세션이 [`MAX_SORTED_SESSIONS`]개를
+    /// 넘으면 거절합니다. `total`은 목록의 전체 세션 수입니다.
+    pub fn check_sortable(&self, total: usize) -> Result<(), ApiError> {
+        if self.uses_index() || total <= MAX_SORTED_SESSIONS {
+            Ok(())
+        } else {
+            Err(ApiError::TooManySessionsToSort(MAX_SORTED_SESSIONS))
+        }
+    }
+}
+
+/// 목록의 다음 페이지 위치. 앞 페이지 마지막 항목의 정렬 값과 ID로,
+/// `{정렬 값}:{ID}` 문자열로 주고받습니다.
+///
+/// 정렬 값과 ID가 같은 항목이 없으므로 페이지를 넘기는 사이에 항목이 생기거나
+/// 사라져도 이미 본 항목이 다시 나오거나 건너뛰어지지 않습니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListCursor {
+    pub key: i64,
+    pub id: String,
+}
+
+impl ListCursor {
+    /// 비어 있으면 처음부터입니다.
+    pub fn parse(cursor: Option<&str>) -> Result<Option<Self>, ApiError> {
+        let Some(cursor) = cursor.filter(|cursor| !cursor.is_empty()) else {
+            return Ok(None);
+        };
+        let invalid = || ApiError::InvalidCursor(cursor.to_string());
+        let (key, id) = cursor.split_once(':').ok_or_else(invalid)?;
+        if id.is_empty() {
+            return Err(invalid());
+        }
+        Ok(Some(Self {
+            key: key.parse().map_err(|_| invalid())?,
+            id: id.to_string(),
+        }))
+    }
+
+    /// (정렬 값, ID)가 정렬 순서에서 커서보다 뒤에 오는지.
+    pub fn follows(&self, key: i64, id: &str, descending: bool) -> bool {
+        let ordering = (key, id).cmp(&(self.key, self.id.as_str()));
+        if descending {
+            ordering == Ordering::Less
+        } else {
+            ordering == Ordering::Greater
+        }
+    }
+}
+
+impl fmt::Display for ListCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.key, self.id)
+    }
+}
+
+/// 이름 순 정렬에서 쓰는 ID. 소문자 제목(없으면 세션 ID)을 항목의 ID 앞에 붙여,
+/// 이름이 같은 항목끼리도 순서가 정해지게 합니다.
+pub(crate) fn name_sort_id(title: Option<&str>, session_id: &str, id: &str) -> String {
+    format!("{}\t{}", title.unwrap_or(session_id).to_lowercase(), id)
+}
+
+/// 이미 모두 읽은 항목을 `sort_key`가 주는 (정렬 값, ID) 순서로 정렬하고, 커서 다음의
+/// 한 페이지와 다음 페이지 커서를 반환합니다. ID는 항목마다 달라야 합니다.
+pub(crate) fn page_sorted<T>(
+    items: Vec<T>,
+    cursor: Option<&ListCursor>,
+    limit: usize,
+    descending: bool,
+    sort_key: impl Fn(&T) -> (i64, String),
+) -> (Vec<T>, Option<String>) {
+    let mut items: Vec<((i64, String), T)> = items
+        .into_iter()
+        .map(|item| (sort_key(&item), item))
+        .filter(|((key, id), _)| cursor.is_none_or(|cursor| cursor.follows(*key, id, descending)))
+        .collect();
+    items.sort_by(|(a, _), (b, _)| if descending { b.cmp(a) } else { a.cmp(b) });
+    items.truncate(limit + 1);
+    next_page(items, limit)
+}
+
+/// 정렬 순서대로 최대 `limit + 1`개 읽은 항목에서 한 페이지를 만듭니다.
+///
+/// 하나가 더 있으면 다음 페이지가 있다는 뜻이므로 마지막 항목을 빼고 커서를 만듭니다.
+pub(crate) fn next_page<T>(
+    mut items: Vec<((i64, String), T)>,
+    limit: usize,
+) -> (Vec<T>, Option<String>) {
+    let next_cursor = if items.len() > limit {
+        items.truncate(limit);
+        items.last().map(|((key, id), _)| {
+            ListCursor {
+                key: *key,
+                id: id.clone(),
+            }
+            .to_string()
+        })
+    } else {
+        None
+    };
+    (
+        items.into_iter().map(|(_, item)| item).collect(),
+        next_cursor,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+
+    fn summary(session_id: &str, age_minutes: i64, viewers: u64) -> SessionSummary {
+        let created_at = Utc::now() - Duration::minutes(age_minutes);
+        SessionSummary {
+            session_id: session_id.to_string(),
+            title: None,
+            description: None,
+            tags: Vec::new(),
+            listed: false,
+            visibility: Default::default(),
+            created_at: Some(created_at),
+            updated_at: Some(created_at),
+            ttl_seconds: Some(3600),
+            template_bytes: 11,
+            viewers,
+            thumbnail_url: format!("/stream/alice/{}/snapshot", session_id),
+            owner: None,
+            role: None,
+        }
+    }
+
+    fn ids(items: &[SessionSummary]) -> Vec<&str> {
+        items.iter().map(|item| item.session_id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_default_sort_is_newest_first() {
+        let query = SessionListQuery::default();
+        let items = vec![
+            summary("old", 30, 0),
+            summary("new", 1, 0),
+            summary("mid", 10, 0),
+        ];
+
+        let (page, next_cursor) = query.apply(items).unwrap();
+        assert_eq!(ids(&page), vec!["new", "mid", "old"]);
+        assert_eq!(next_cursor, None);
+    }
+
+    #[test]
+    fn test_sort_by_name_and_viewers() {
+        let items = vec![summary("b", 1, 5), summary("a", 2, 1), summary("c", 3, 9)];
+
+        let by_name = SessionListQuery {
+            sort: SessionSort::Name,
+            order: SortOrder::Asc,
+            ..Default::default()
+        };
+        let (page, _) = by_name.apply(items.clone()).unwrap();
+        assert_eq!(ids(&page), vec!["a", "b", "c"]);
+
+        let by_viewers = SessionListQuery {
+            sort: SessionSort::Viewers,
+            ..Default::default()
+        };
+        let (page, _) = by_viewers.apply(items).unwrap();
+        assert_eq!(ids(&page), vec!["c", "b", "a"]);
+    }
+
+    #[test]
+    fn test_name_sort_uses_title_before_session_id() {
+        let mut zulu = summary("a-zulu", 1, 0);
+        zulu.title = Some("Zulu".to_string());
+        let mut alpha = summary("z-alpha", 2, 0);
+        alpha.title = Some("alpha".to_string());
+        let items = vec![zulu, summary("mike", 3, 0), alpha];
+
+        let by_name = SessionListQuery {
+            sort: SessionSort::Name,
+            order: SortOrder::Asc,
+            limit: Some(2),
+            ..Default::default()
+        };
+        let (page, next_cursor) = by_name.apply(items.clone()).unwrap();
+        assert_eq!(ids(&page), vec!["z-alpha", "mike"]);
+
+        let next = SessionListQuery {
+            cursor: next_cursor,
+            ..by_name
+        };
+        let (page, next_cursor) = next.apply(items).unwrap();
+        assert_eq!(ids(&page), vec!["a-zulu"]);
+        assert_eq!(next_cursor, None);
+    }
+
+    #[test]
+    fn test_name_filter_is_case_insensitive() {
+        let query = SessionListQuery {
+            q: Some("Dash".to_string()),
+            ..Default::default()
+        };
+        let items = vec![summary("dashboard-1", 1, 0), summary("badge", 2, 0)];
+
+        let (page, _) = query.apply(items).unwrap();
+        assert_eq!(ids(&page), vec!["dashboard-1"]);
+    }
+
+    #[test]
+    fn test_tag_filter_and_title_search() {
+        let mut tagged = summary("a", 1, 0);
+        tagged.tags = vec!["status".to_string()];
+        tagged.title = Some("Server Status".to_string());
+        let items = vec![tagged, summary("b", 2, 0)];
+
+        let by_tag = SessionListQuery {
+            tag: Some("Status".to_string()),
+            ..Default::default()
+        };
+        let (page, _) = by_tag.apply(items.clone()).unwrap();
+        assert_eq!(ids(&page), vec!["a"]);
+
+        let by_title = SessionListQuery {
+            q: Some("server".to_string()),
+            ..Default::default()
+        };
+        let (page, _) = by_title.apply(items).unwrap();
+        assert_eq!(ids(&page), vec!["a"]);
+    }
+
+    #[test]
+    fn test_cursor_pagination_walks_all_items() {
+        let items: Vec<SessionSummary> = (0..5)
+            .map(|i| summary(&format!("session_{}", i), i, 0))
+            .collect();
+        let mut query = SessionListQuery {
+            limit: Some(2),
+            ..Default::default()
+        };
+
+        let mut seen = Vec::new();
+        loop {
+            let (page, next_cursor) = query.apply(items.clone()).unwrap();
+            seen.extend(page.into_iter().map(|item| item.session_id));
+            match next_cursor {
+                Some(cursor) => query.cursor = Some(cursor),
+                None => break,
+            }
+        }
+        assert_eq!(
+            seen,
+            vec![
+                "session_0",
+                "session_1",
+                "session_2",
+                "session_3",
+                "session_4"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cursor_is_stable_when_sessions_change_between_pages() {
+        let mut items: Vec<SessionSummary> = (0..5)
+            .map(|i| summary(&format!("session_{}", i), i, 0))
+            .collect();
+        let mut query = SessionListQuery {
+            limit: Some(2),
+            ..Default::default()
+        };
+
+        let (page, next_cursor) = query.apply(items.clone()).unwrap();
+        assert_eq!(ids(&page), vec!["session_0", "session_1"]);
+
+        // 앞 페이지를 읽은 뒤 새 세션이 생기고 이미 본 세션이 지워져도
+        // 다음 페이지는 커서 바로 뒤부터 이어집니다.
+        items.remove(0);
+        items.push(summary("session_new", -10, 0));
+        query.cursor = next_cursor;
+        let (page, _) = query.apply(items).unwrap();
+        assert_eq!(ids(&page), vec!["session_2", "session_3"]);
+    }
+
+    #[test]
+    fn test_invalid_cursor_is_rejected() {
+        let query = SessionListQuery {
+            cursor: Some("not-a-cursor".to_string()),
+            ..Default::default()
+        };
+        assert!(query.apply(Vec::new()).is_err());
+
+        for cursor in ["12", "abc:session", "12:"] {
+            assert!(ListCursor::parse(Some(cursor)).is_err(), "{}", cursor);
+        }
+        let cursor = ListCursor::parse(Some("-5:session_1")).unwrap().unwrap();
+        assert_eq!(cursor.key, -5);
+        assert_eq!(cursor.to_string(), "-5:session_1");
+
+        // ID 자리에는 `:`가 들어가도 됩니다 (첫 번째 `:`까지가 정렬 값).
+        let cursor = ListCursor::parse(Some("0:a:b")).unwrap().unwrap();
+        assert_eq!(cursor.id, "a:b");
+    }
+
+    #[test]
+    fn test_unindexed_sort_is_capped() {
+        let by_name = SessionListQuery {
+            sort: SessionSort::Name,
+            ..Default::default()
+        };
+        assert!(by_name.check_sortable(MAX_SORTED_SESSIONS).is_ok());
+        assert!(matches!(
+            by_name.check_sortable(MAX_SORTED_SESSIONS + 1),
+            Err(ApiError::TooManySessionsToSort(MAX_SORTED_SESSIONS))
+        ));
+        assert!(SessionListQuery::default()
+            .check_sortable(MAX_SORTED_SESSIONS + 1)
+            .is_ok());
+    }
+}
@@ -1,215 +1,358 @@
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
 
-use argon2::password_hash::SaltString;
-use argon2::Argon2;
-use jsonwebtoken::{DecodingKey, EncodingKey};
-use redis::aio::MultiplexedConnection;
+use argon2::{Algorithm, Argon2, Params, Version};
+use jsonwebtoken::DecodingKey;
 use redis::AsyncCommands;
-use rsa::pkcs1::{EncodeRsaPrivateKey, EncodeRsaPublicKey};
-use rsa::RsaPrivateKey;
-use tokio::sync::OnceCell;
+use tokio::sync::{OnceCell, RwLock};
 
+use crate::auth_cookie::CookiePolicy;
 use crate::errors::ApiError;
+use crate::jwt::{self, Claims, SigningKey, TokenPolicy};
+use crate::keys::Keys;
+use crate::rate_limit::RateLimitPolicy;
+use crate::signing_key::{self, PublicSigningKey, SigningAlgorithm, SigningKeySet};
+use crate::trusted_issuer::TrustedIssuers;
+use crate::user_data::hash_password;
 
-/// Redis keys for storing RSA key pair in PEM format
-const RSA_PRIVATE_PEM: &str = ".realtime-svg:rsa:private_pem";
-const RSA_PUBLIC_PEM: &str = ".realtime-svg:rsa:public_pem";
-const PASSWORD_SALT: &str = ".realtime-svg:password_salt";
+/// 토큰에 모르는 `kid`가 있을 때 서명 키를 다시 읽는 최소 간격.
+///
+/// 다른 인스턴스가 방금 교체한 키를 바로 받아들이되, 임의의 `kid`로 Redis를
+/// 두드리지는 못하게 합니다.
+const UNKNOWN_KID_RELOAD_INTERVAL: Duration = Duration::from_secs(5);
+
+/// 토큰 검증에 쓰는 공개키. 알고리즘은 토큰 헤더가 아니라 저장된 키에서 가져옵니다.
+struct VerifyingKey {
+    kid: String,
+    algorithm: jsonwebtoken::Algorithm,
+    decoding_key: DecodingKey,
+}
+
+/// Redis에서 읽어 파싱해 둔 서명 키 묶음.
+struct SigningKeyCache {
+    signing_key: Arc<SigningKey>,
+    verifying_keys: Vec<VerifyingKey>,
+    public_keys: Vec<PublicSigningKey>,
+    loaded_at: Instant,
+}
+
+impl SigningKeyCache {
+    fn new(set: SigningKeySet) -> Result<Self, ApiError> {
+        let signing_key = match set.active() {
+            Some(key) => Arc::new(key.signing_key()?),
+            None => {
+                return Err(ApiError::InternalError(format!(
+                    "Active signing key {} is missing",
+                    set.active_kid
+                )))
+            }
+        };
+
+        // 최근 키부터 두어 kid 없는 토큰도 대개 첫 시도에 검증되게 합니다.
+        let public_keys: Vec<_> = set.keys.iter().rev().map(|key| key.public_key()).collect();
+        let verifying_keys = public_keys
+            .iter()
+            .map(|key| {
+                Ok(VerifyingKey {
+                    kid: key.kid.clone(),
+                    algorithm: key.algorithm.jwt_algorithm(),
+                    decoding_key: key.decoding_key()?,
+                })
+            })
+            .collect::<Result<_, ApiError>>()?;
+
+        Ok(Self {
+            signing_key,
+            verifying_keys,
+            public_keys,
+            loaded_at: Instant::now(),
+        })
+    }
+
+    fn verifying_key(&self, kid: &str) -> Option<&VerifyingKey> {
+        self.verifying_keys.iter().find(|key| key.kid == kid)
+    }
+}
 
-/// In-memory cache for JWK (JSON Web Key) encoding/decoding keys
+impl std::fmt::Debug for SigningKeyCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SigningKeyCache")
+            .field("active_kid", &self.signing_key.kid)
+            .field("public_keys", &self.public_keys.len())
+            .field("loaded_at", &self.loaded_at)
+            .finish()
+    }
+}
+
+/// In-memory cache for JWT signing keys and other shared server state
+///
+/// Signing keys are loaded from Redis on first use and reloaded every
+/// [`signing_key::CACHE_SECONDS`], so a rotation by any instance is picked up
+/// without a restart. A token signed with a `kid` that is not cached yet forces
+/// an earlier (rate-limited) reload.
 ///
-/// Keys are loaded from Redis once on first use and cached in memory
-/// to avoid Redis lookups on every JWT operation.
+/// Tokens from [`TrustedIssuers`] (e.g. a company IdP) are verified with that
+/// issuer's JWKS instead; see [`ShareState::verify_external_token`].
 ///
 /// # Thread Safety
-/// Uses `Arc<OnceCell>` for thread-safe lazy initialization
+/// The signing key cache is an `Arc<RwLock>` shared by every clone
 #[derive(Clone, Debug)]
 pub struct ShareState {
-    encoding_key: Arc<OnceCell<EncodingKey>>,
-    decoding_key: Arc<OnceCell<DecodingKey>>,
-    salt: Arc<OnceCell<SaltString>>,
+    signing_keys: Arc<RwLock<Option<Arc<SigningKeyCache>>>>,
+    legacy_salt: Arc<OnceCell<Option<String>>>,
     argon2: Arc<Argon2<'static>>,
+    dummy_password_hash: Arc<OnceLock<String>>,
+    token_policy: TokenPolicy,
+    trusted_issuers: TrustedIssuers,
+    rate_limit_policy: RateLimitPolicy,
+    cookie_policy: CookiePolicy,
+    keys: Keys,
 }
 
 impl ShareState {
-    pub fn new() -> Self {
+    /// `argon2_params`는 새로 만드는 비밀번호 해시에 쓰이고, 로그인 시 이보다 약한
+    /// 해시는 다시 만들어집니다.
+    pub fn new(keys: Keys, argon2_params: Params, token_policy: TokenPolicy) -> Self {
         Self {
-            encoding_key: Arc::new(OnceCell::new()),
-            decoding_key: Arc::new(OnceCell::new()),
-            salt: Arc::new(OnceCell::new()),
-            argon2: Arc::new(Argon2::default()),
+            signing_keys: Arc::new(RwLock::new(None)),
+            legacy_salt: Arc::new(OnceCell::new()),
+            argon2: Arc::new(Argon2::new(
+                Algorithm::Argon2id,
+                Version::V0x13,
+                argon2_params,
+            )),
+            dummy_password_hash: Arc::new(OnceLock::new()),
+            token_policy,
+            trusted_issuers: TrustedIssuers::default(),
+            rate_limit_policy: RateLimitPolicy::default(),
+            cookie_policy: CookiePolicy::default(),
+            keys,
         }
     }
 
+    /// 이 서버의 키 외에 `trusted_issuers`가 서명한 토큰도 받아들입니다.
+    pub fn with_trusted_issuers(mut self, trusted_issuers: TrustedIssuers) -> Self {
+        self.trusted_issuers = trusted_issuers;
+        self
+    }
+
+    /// 로그인 시도 제한과 사용자별 할당량을 기본값 대신 `policy`로 설정합니다.
+    pub fn with_rate_limit_policy(mut self, policy: RateLimitPolicy) -> Self {
+        self.rate_limit_policy = policy;
+        self
+    }
+
+    /// 로그인 쿠키 속성을 기본값 대신 `policy`로 설정합니다.
+    pub fn with_cookie_policy(mut self, policy: CookiePolicy) -> Self {
+        self.cookie_policy = policy;
+        self
+    }
+
+    pub fn keys(&self) -> &Keys {
+        &self.keys
+    }
+
     pub fn argon2(&self) -> &Argon2<'static> {
         &self.argon2
     }
 
-    pub async fn get_salt(&self, redis: &redis::Client) -> Result<&SaltString, ApiError> {
-        self.salt
-            .get_or_try_init(|| async {
-                let mut conn = redis
-                    .get_multiplexed_async_connection()
-                    .await
-                    .map_err(|e| ApiError::RedisError(e.to_string()))?;
-                let salt_str: String = conn
-                    .get(PASSWORD_SALT)
-                    .await
-                    .map_err(|e| ApiError::RedisError(e.to_string()))?;
-                let salt = SaltString::from_b64(&salt_str).map_err(|e| {
-                    ApiError::InternalError(format!("Failed to create SaltString: {}", e))
-                })?;
-                Ok(salt)
+    /// Hash of a fixed password, made with the current Argon2 params on first use.
+    ///
+    /// Logins for unknown users are verified against it so they take as long as
+    /// a wrong password for an existing account.
+    pub fn dummy_password_hash(&self) -> &str {
+        self.dummy_password_hash.get_or_init(|| {
+            hash_password(&self.argon2, "realtime-svg-dummy-password").unwrap_or_else(|e| {
+                tracing::error!("Failed to create dummy password hash: {:?}", e);
+                String::new()
             })
-            .await
+        })
+    }
+
+    pub fn token_policy(&self) -> &TokenPolicy {
+        &self.token_policy
+    }
+
+    pub fn rate_limit_policy(&self) -> &RateLimitPolicy {
+        &self.rate_limit_policy
+    }
+
+    pub fn cookie_policy(&self) -> &CookiePolicy {
+        &self.cookie_policy
     }
 
-    /// Gets the decoding key (RSA public key) for JWT verification
+    /// 비밀번호별 salt 도입 이전에 모든 계정이 함께 쓰던 전역 salt.
     ///
-    /// Loads from Redis on first call, then caches in memory.
-    /// Subsequent calls return the cached key without Redis access.
-    pub async fn get_decoding_key(&self, redis: &redis::Client) -> Result<&DecodingKey, ApiError> {
-        self.decoding_key
+    /// 이 salt로 만든 해시는 로그인 시 다시 만들어집니다. 새 설치에는 없습니다.
+    pub async fn get_legacy_salt(&self, redis: &redis::Client) -> Result<Option<&str>, ApiError> {
+        let salt = self
+            .legacy_salt
             .get_or_try_init(|| async {
                 let mut conn = redis
                     .get_multiplexed_async_connection()
                     .await
                     .map_err(|e| ApiError::RedisError(e.to_string()))?;
-                let pem: String = conn
-                    .get(RSA_PUBLIC_PEM)
+                conn.get(self.keys.password_salt())
                     .await
-                    .map_err(|e| ApiError::RedisError(e.to_string()))?;
-                let key = DecodingKey::from_rsa_pem(pem.as_bytes()).map_err(|e| {
-                    ApiError::InternalError(format!("Failed to create DecodingKey: {}", e))
-                })?;
-                Ok(key)
+                    .map_err(|e| ApiError::RedisError(e.to_string()))
             })
+            .await?;
+        Ok(salt.as_deref())
+    }
+
+    /// `max_age`보다 오래된 캐시는 Redis에서 다시 읽습니다.
+    async fn signing_keys(
+        &self,
+        redis: &redis::Client,
+        max_age: Duration,
+    ) -> Result<Arc<SigningKeyCache>, ApiError> {
+        if let Some(cache) = self.signing_keys.read().await.as_ref() {
+            if cache.loaded_at.elapsed() < max_age {
+                return Ok(cache.clone());
+            }
+        }
+
+        let mut guard = self.signing_keys.write().await;
+        if let Some(cache) = guard.as_ref() {
+            if cache.loaded_at.elapsed() < max_age {
+                return Ok(cache.clone());
+            }
+        }
+
+        let mut conn = redis
+            .get_multiplexed_async_connection()
             .await
+            .map_err(|e| ApiError::RedisError(e.to_string()))?;
+        let cache = Arc::new(SigningKeyCache::new(
+            signing_key::load(&mut conn, &self.keys).await?,
+        )?);
+        *guard = Some(cache.clone());
+        Ok(cache)
+    }
+
+    /// 캐시를 비워 다음 사용 때 Redis에서 다시 읽게 합니다. 키를 교체한 뒤 호출합니다.
+    pub async fn invalidate_signing_keys(&self) {
+        *self.signing_keys.write().await = None;
+    }
+
+    /// Gets the active signing key (RSA private key and its `kid`) for JWT signing
+    pub async fn get_signing_key(
+        &self,
+        redis: &redis::Client,
+    ) -> Result<Arc<SigningKey>, ApiError> {
+        let cache = self
+            .signing_keys(redis, Duration::from_secs(signing_key::CACHE_SECONDS))
+            .await?;
+        Ok(cache.signing_key.clone())
     }
 
-    /// Gets the encoding key (RSA private key) for JWT signing
+    /// Public keys of every signing key that may still have valid tokens, newest first
+    pub async fn get_public_keys(
+        &self,
+        redis: &redis::Client,
+    ) -> Result<Vec<PublicSigningKey>, ApiError> {
+        let cache = self
+            .signing_keys(redis, Duration::from_secs(signing_key::CACHE_SECONDS))
+            .await?;
+        Ok(cache.public_keys.clone())
+    }
+
+    /// Verifies a JWT with the published key named by its `kid` header
     ///
-    /// Loads from Redis on first call, then caches in memory.
-    /// Subsequent calls return the cached key without Redis access.
-    pub async fn get_encoding_key(&self, redis: &redis::Client) -> Result<&EncodingKey, ApiError> {
-        self.encoding_key
-            .get_or_try_init(|| async {
-                let mut conn = redis
-                    .get_multiplexed_async_connection()
-                    .await
-                    .map_err(|e| ApiError::RedisError(e.to_string()))?;
-                let pem: String = conn
-                    .get(RSA_PRIVATE_PEM)
-                    .await
-                    .map_err(|e| ApiError::RedisError(e.to_string()))?;
-                let key = EncodingKey::from_rsa_pem(pem.as_bytes()).map_err(|e| {
-                    ApiError::InternalError(format!("Failed to create EncodingKey: {}", e))
-                })?;
-                Ok(key)
-            })
-            .await
+    /// Tokens issued before key ids were introduced have no `kid`; they are
+    /// tried against every published key.
+    ///
+    /// # Errors
+    /// Returns `ApiError::Unauthorized` if the header is malformed, the `kid` is
+    /// unknown or retired, or [`jwt::verify_token`] rejects the token.
+    pub async fn verify_token(
+        &self,
+        redis: &redis::Client,
+        token: &str,
+    ) -> Result<Claims, ApiError> {
+        let header = jsonwebtoken::decode_header(token).map_err(|e| {
+            tracing::warn!("JWT verification failed: {}", e);
+            ApiError::Unauthorized(format!("Invalid JWT token: {}", e))
+        })?;
+        let mut cache = self
+            .signing_keys(redis, Duration::from_secs(signing_key::CACHE_SECONDS))
+            .await?;
+
+        let Some(kid) = header.kid else {
+            let mut result = Err(ApiError::Unauthorized("Invalid JWT token".to_string()));
+            for key in &cache.verifying_keys {
+                result = jwt::verify_token(token, &key.decoding_key, key.algorithm);
+                if result.is_ok() {
+                    break;
+                }
+            }
+            return result;
+        };
+
+        if cache.verifying_key(&kid).is_none() {
+            cache = self
+                .signing_keys(redis, UNKNOWN_KID_RELOAD_INTERVAL)
+                .await?;
+        }
+        let key = cache.verifying_key(&kid).ok_or_else(|| {
+            tracing::warn!("JWT verification failed: unknown kid {}", kid);
+            ApiError::Unauthorized("Unknown signing key".to_string())
+        })?;
+        jwt::verify_token(token, &key.decoding_key, key.algorithm)
+    }
+
+    /// Verifies a JWT from a trusted external issuer and returns the `user_id` it names
+    ///
+    /// Returns `Ok(None)` when the token's `iss` is not a trusted external issuer;
+    /// such tokens must be checked with [`ShareState::verify_token`].
+    ///
+    /// # Errors
+    /// See [`TrustedIssuers::verify`].
+    pub async fn verify_external_token(&self, token: &str) -> Result<Option<String>, ApiError> {
+        self.trusted_issuers.verify(token).await
     }
 }
 
 impl Default for ShareState {
     fn default() -> Self {
-        Self::new()
+        Self::new(Keys::default(), Params::default(), TokenPolicy::default())
     }
 }
 
-/// Initializes RSA key pair in Redis if not already present
+/// Initializes the JWT signing keys in Redis if not already present
 ///
 /// # Key Generation
-/// - Algorithm: RSA-2048 bits
-/// - Format: PKCS#1 PEM encoding
-/// - Storage: Redis keys `rsa:private_pem` and `rsa:public_pem`
+/// - Algorithm: `signing_algorithm` (RSA-2048, ECDSA P-256 or Ed25519)
+/// - Format: PKCS#1 PEM for RSA, PKCS#8 / SPKI PEM otherwise
+/// - Storage: see [`crate::signing_key`]; a key created before key ids were
+///   introduced (`{key_prefix}.realtime-svg:rsa:private_pem`) is imported as the first key
 ///
 /// # Atomicity
-/// Uses Redis `SET NX` to prevent race conditions when multiple
-/// instances try to initialize keys simultaneously. Only the first
-/// instance succeeds, others skip initialization.
+/// Uses Redis `SET NX` on the active key id to prevent race conditions when
+/// multiple instances try to initialize keys simultaneously. Only the first
+/// instance's key is kept, others discard theirs.
 ///
 /// # Idempotency
-/// Safe to call multiple times - checks for existing keys first.
-/// Logs info message if keys already exist.
+/// Safe to call multiple times - checks for an active key first.
+///
+/// # Migration
+/// Also runs the one-shot storage migrations in [`crate::migration`].
 ///
 /// # Usage
 /// Should be called once during application startup before
 /// handling any requests that require JWT authentication.
-pub async fn initialize_redis(redis: &redis::Client) -> Result<(), ApiError> {
+pub async fn initialize_redis(
+    redis: &redis::Client,
+    keys: &Keys,
+    signing_algorithm: SigningAlgorithm,
+) -> Result<(), ApiError> {
     let mut conn = redis
         .get_multiplexed_async_connection()
         .await
         .map_err(|e| ApiError::RedisError(e.to_string()))?;
 
-    initialize_jwk(&mut conn).await?;
-    initialize_salt(&mut conn).await?;
-    Ok(())
-}
-
-async fn initialize_jwk(conn: &mut MultiplexedConnection) -> Result<(), ApiError> {
-    let exists: bool = conn
-        .exists(RSA_PRIVATE_PEM)
-        .await
-        .map_err(|e| ApiError::RedisError(e.to_string()))?;
-
-    if exists {
-        tracing::info!("RSA keys already exist in Redis");
-        return Ok(());
-    }
-
-    let rsa_key = RsaPrivateKey::new(&mut rand::thread_rng(), 2048)
-        .map_err(|e| ApiError::InternalError(format!("Failed to generate RSA key: {}", e)))?;
-
-    let private_pem = rsa_key
-        .to_pkcs1_pem(rsa::pkcs1::LineEnding::LF)
-        .map_err(|e| ApiError::InternalError(format!("Failed to encode private key: {}", e)))?;
-
-    let public_key = rsa_key.to_public_key();
-    let public_pem = public_key
-        .to_pkcs1_pem(rsa::pkcs1::LineEnding::LF)
-        .map_err(|e| ApiError::InternalError(format!("Failed to encode public key: {}", e)))?;
-
-    let set: bool = conn
-        .set_nx(RSA_PRIVATE_PEM, private_pem.as_str())
-        .await
-        .map_err(|e| ApiError::RedisError(e.to_string()))?;
-
-    if set {
-        let _: () = conn
-            .set(RSA_PUBLIC_PEM, public_pem.as_str())
-            .await
-            .map_err(|e| ApiError::RedisError(e.to_string()))?;
-        tracing::info!("Created new RSA keys in Redis");
-    } else {
-        tracing::info!("RSA keys were created by another instance");
-    }
-
-    Ok(())
-}
-
-async fn initialize_salt(conn: &mut MultiplexedConnection) -> Result<(), ApiError> {
-    let exists: bool = conn
-        .exists(PASSWORD_SALT)
-        .await
-        .map_err(|e| ApiError::RedisError(e.to_string()))?;
-
-    if exists {
-        tracing::info!("Password salt already exists in Redis");
-        return Ok(());
-    }
-
-    let salt = SaltString::generate(&mut rand::thread_rng());
-    let set: bool = conn
-        .set_nx(PASSWORD_SALT, salt.as_str())
-        .await
-        .map_err(|e| ApiError::RedisError(e.to_string()))?;
-
-    if set {
-        tracing::info!("Created new password salt in Redis");
-    } else {
-        tracing::info!("Password salt was created by another instance");
-    }
-
+    signing_key::initialize(&mut conn, keys, signing_algorithm).await?;
+    crate::migration::migrate_public_session_keys(&mut conn, keys).await?;
+    crate::migration::backfill_session_indexes(&mut conn, keys).await?;
     Ok(())
 }
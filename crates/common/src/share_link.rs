@@ -0,0 +1,117 @@
+//! 비공개 세션의 공유 링크.
+//!
+//! 공유 링크는 `session:{user_id}/{session_id}:view` 범위의 서명된 뷰어 토큰입니다.
+//! `<img>` 태그는 헤더를 보낼 수 없으므로 토큰은 `?token=` 쿼리로 전달합니다.
+//! 토큰의 `jti`가 링크 ID이며, Redis에 남아 있는 링크 기록이 있어야 토큰이 통하므로
+//! 기록을 지우면 링크가 폐기됩니다. 기록은 토큰과 같은 시각에 만료됩니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::ApiError;
+use crate::refresh_token::random_hex;
+
+/// 링크 이름의 최대 길이 (문자 수).
+pub const MAX_NAME_LEN: usize = 64;
+
+/// 링크 ID별로 저장되는 정보.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ShareLinkRecord {
+    pub link_id: String,
+    pub user_id: String,
+    pub session_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl ShareLinkRecord {
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+
+    /// 이 링크가 `user_id`의 `session_id` 세션을 가리키는지 확인합니다.
+    pub fn is_for(&self, user_id: &str, session_id: &str) -> bool {
+        self.user_id == user_id && self.session_id == session_id
+    }
+}
+
+/// 목록 조회 응답의 한 항목. 토큰은 포함하지 않습니다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ShareLinkInfo {
+    pub link_id: String,
+    pub name: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl From<ShareLinkRecord> for ShareLinkInfo {
+    fn from(record: ShareLinkRecord) -> Self {
+        Self {
+            link_id: record.link_id,
+            name: record.name,
+            created_at: record.created_at,
+            expires_at: record.expires_at,
+        }
+    }
+}
+
+/// 새 링크 ID (128비트 난수).
+pub fn generate_id() -> String {
+    random_hex(16)
+}
+
+/// 스트림 URL. 토큰에는 URL에서 특별한 의미가 있는 문자가 없습니다.
+pub fn stream_url(user_id: &str, session_id: &str, token: &str) -> String {
+    format!("/stream/{}/{}?token={}", user_id, session_id, token)
+}
+
+/// 이름 앞뒤 공백을 제거합니다. 비어 있으면 `None`, 64자를 넘으면 오류입니다.
+pub fn normalize_name(name: Option<&str>) -> Result<Option<String>, ApiError> {
+    let Some(name) = name.map(str::trim).filter(|name| !name.is_empty()) else {
+        return Ok(None);
+    };
+    if name.chars().count() > MAX_NAME_LEN {
+        return Err(ApiError::InvalidShareLink(format!(
+            "공유 링크 이름은 {}자를 넘을 수 없습니다",
+            MAX_NAME_LEN
+        )));
+    }
+    Ok(Some(name.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_name_rules() {
+        assert_eq!(normalize_name(None).unwrap(), None);
+        assert_eq!(normalize_name(Some("   ")).unwrap(), None);
+        assert_eq!(
+            normalize_name(Some(" 고객 A ")).unwrap().as_deref(),
+            Some("고객 A")
+        );
+        assert!(normalize_name(Some(&"x".repeat(MAX_NAME_LEN + 1))).is_err());
+    }
+
+    #[test]
+    fn test_record_matching_and_expiry() {
+        let now = Utc::now();
+        let record = ShareLinkRecord {
+            link_id: generate_id(),
+            user_id: "alice".to_string(),
+            session_id: "private-1".to_string(),
+            name: None,
+            created_at: now,
+            expires_at: now + chrono::Duration::seconds(10),
+        };
+        assert_eq!(record.link_id.len(), 32);
+        assert!(record.is_for("alice", "private-1"));
+        assert!(!record.is_for("alice", "private-2"));
+        assert!(!record.is_for("bob", "private-1"));
+        assert!(!record.is_expired(now));
+        assert!(record.is_expired(now + chrono::Duration::seconds(10)));
+    }
+}
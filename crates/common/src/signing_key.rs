@@ -0,0 +1,637 @@
+//! JWT 서명 키 저장소와 키 교체.
+//!
+//! 키마다 `kid`를 붙여 Redis에 저장하고, 새 토큰은 활성 키로 서명합니다.
+//! 키를 교체하면 이전 키에는 TTL이 걸려, 그 키로 서명된 토큰이 모두 만료될
+//! 때까지만 JWKS에 공개되고 검증에 쓰입니다.
+//!
+//! 서명 알고리즘은 키마다 저장되므로 알고리즘을 바꿔도 이전 키로 서명된
+//! 토큰은 원래 알고리즘으로 검증됩니다.
+
+use std::fmt;
+use std::str::FromStr;
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use chrono::{DateTime, Utc};
+use jsonwebtoken::jwk::{
+    AlgorithmParameters, CommonParameters, EllipticCurve, EllipticCurveKeyParameters,
+    EllipticCurveKeyType, Jwk, KeyAlgorithm, OctetKeyPairParameters, OctetKeyPairType,
+    PublicKeyUse, RSAKeyParameters, RSAKeyType,
+};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey};
+use p256::elliptic_curve::sec1::ToEncodedPoint;
+use p256::pkcs8::{DecodePublicKey, EncodePrivateKey, EncodePublicKey, LineEnding};
+use redis::aio::MultiplexedConnection;
+use redis::{AsyncTypedCommands, ExistenceCheck, IntegerReplyOrNoOp, SetExpiry, SetOptions};
+use rsa::pkcs1::{DecodeRsaPublicKey, EncodeRsaPrivateKey, EncodeRsaPublicKey};
+use rsa::traits::PublicKeyParts;
+use rsa::RsaPrivateKey;
+use serde::{Deserialize, Serialize};
+
+use crate::errors::ApiError;
+use crate::jwt::{SigningKey, TokenPolicy};
+use crate::keys::Keys;
+
+/// 각 인스턴스가 서명 키 캐시를 Redis에서 다시 읽는 주기 (초).
+///
+/// 다른 인스턴스가 키를 교체해도 이 시간 안에 새 키로 서명하기 시작합니다.
+pub const CACHE_SECONDS: u64 = 60;
+
+/// 검증 시 `exp`에 허용되는 오차 (jsonwebtoken 기본값).
+const VALIDATION_LEEWAY_SECONDS: u64 = 60;
+
+/// 여러 인스턴스가 같은 키를 동시에 교체하지 않도록 잡는 잠금의 수명 (초).
+const ROTATION_LOCK_SECONDS: u64 = 60;
+
+/// 새 서명 키에 쓰이는 알고리즘.
+///
+/// - `RS256`: RSA-2048, PKCS#1 PEM. 서명이 가장 크지만 모든 검증기가 지원합니다.
+/// - `ES256`: ECDSA P-256, PKCS#8 / SPKI PEM. 서명 64바이트.
+/// - `EdDSA`: Ed25519, PKCS#8 / SPKI PEM. 서명 64바이트.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SigningAlgorithm {
+    #[default]
+    RS256,
+    ES256,
+    EdDSA,
+}
+
+impl SigningAlgorithm {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SigningAlgorithm::RS256 => "RS256",
+            SigningAlgorithm::ES256 => "ES256",
+            SigningAlgorithm::EdDSA => "EdDSA",
+        }
+    }
+
+    /// JWT 헤더의 `alg`.
+    pub fn jwt_algorithm(&self) -> Algorithm {
+        match self {
+            SigningAlgorithm::RS256 => Algorithm::RS256,
+            SigningAlgorithm::ES256 => Algorithm::ES256,
+            SigningAlgorithm::EdDSA => Algorithm::EdDSA,
+        }
+    }
+}
+
+impl fmt::Display for SigningAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for SigningAlgorithm {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            SigningAlgorithm::RS256,
+            SigningAlgorithm::ES256,
+            SigningAlgorithm::EdDSA,
+        ]
+        .into_iter()
+        .find(|algorithm| algorithm.as_str().eq_ignore_ascii_case(s))
+        .ok_or_else(|| {
+            format!(
+                "지원하지 않는 서명 알고리즘입니다 (RS256, ES256, EdDSA): {}",
+                s
+            )
+        })
+    }
+}
+
+/// Redis에 저장되는 서명 키.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct StoredSigningKey {
+    pub kid: String,
+    /// 알고리즘 도입 이전에 저장된 키는 RS256입니다.
+    #[serde(default)]
+    pub algorithm: SigningAlgorithm,
+    pub private_pem: String,
+    pub public_pem: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl fmt::Debug for StoredSigningKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StoredSigningKey")
+            .field("kid", &self.kid)
+            .field("algorithm", &self.algorithm)
+            .field("created_at", &self.created_at)
+            .finish_non_exhaustive()
+    }
+}
+
+impl StoredSigningKey {
+    /// 새 토큰 서명에 쓰는 개인키.
+    pub fn signing_key(&self) -> Result<SigningKey, ApiError> {
+        let pem = self.private_pem.as_bytes();
+        let encoding_key = match self.algorithm {
+            SigningAlgorithm::RS256 => EncodingKey::from_rsa_pem(pem),
+            SigningAlgorithm::ES256 => EncodingKey::from_ec_pem(pem),
+            SigningAlgorithm::EdDSA => EncodingKey::from_ed_pem(pem),
+        }
+        .map_err(|e| ApiError::InternalError(format!("Failed to create EncodingKey: {}", e)))?;
+
+        Ok(SigningKey {
+            kid: self.kid.clone(),
+            algorithm: self.algorithm.jwt_algorithm(),
+            encoding_key,
+        })
+    }
+
+    pub fn public_key(&self) -> PublicSigningKey {
+        PublicSigningKey {
+            kid: self.kid.clone(),
+            algorithm: self.algorithm,
+            public_pem: self.public_pem.clone(),
+        }
+    }
+}
+
+/// JWKS에 공개되고 토큰 검증에 쓰이는 공개키.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicSigningKey {
+    pub kid: String,
+    pub algorithm: SigningAlgorithm,
+    pub public_pem: String,
+}
+
+fn parse_error(e: impl fmt::Display) -> ApiError {
+    ApiError::InternalError(format!("Failed to parse public key: {}", e))
+}
+
+impl PublicSigningKey {
+    pub fn decoding_key(&self) -> Result<DecodingKey, ApiError> {
+        let pem = self.public_pem.as_bytes();
+        match self.algorithm {
+            SigningAlgorithm::RS256 => DecodingKey::from_rsa_pem(pem),
+            SigningAlgorithm::ES256 => DecodingKey::from_ec_pem(pem),
+            SigningAlgorithm::EdDSA => DecodingKey::from_ed_pem(pem),
+        }
+        .map_err(|e| ApiError::InternalError(format!("Failed to create DecodingKey: {}", e)))
+    }
+
+    /// RFC 7517 JWK (RSA는 `n`/`e`, P-256은 `x`/`y`, Ed25519는 `x`).
+    pub fn to_jwk(&self) -> Result<Jwk, ApiError> {
+        let (key_algorithm, algorithm) = match self.algorithm {
+            SigningAlgorithm::RS256 => {
+                let key =
+                    rsa::RsaPublicKey::from_pkcs1_pem(&self.public_pem).map_err(parse_error)?;
+                (
+                    KeyAlgorithm::RS256,
+                    AlgorithmParameters::RSA(RSAKeyParameters {
+                        key_type: RSAKeyType::RSA,
+                        n: URL_SAFE_NO_PAD.encode(key.n().to_bytes_be()),
+                        e: URL_SAFE_NO_PAD.encode(key.e().to_bytes_be()),
+                    }),
+                )
+            }
+            SigningAlgorithm::ES256 => {
+                let point = p256::PublicKey::from_public_key_pem(&self.public_pem)
+                    .map_err(parse_error)?
+                    .to_encoded_point(false);
+                let (Some(x), Some(y)) = (point.x(), point.y()) else {
+                    return Err(parse_error("missing P-256 coordinates"));
+                };
+                (
+                    KeyAlgorithm::ES256,
+                    AlgorithmParameters::EllipticCurve(EllipticCurveKeyParameters {
+                        key_type: EllipticCurveKeyType::EC,
+                        curve: EllipticCurve::P256,
+                        x: URL_SAFE_NO_PAD.encode(x),
+                        y: URL_SAFE_NO_PAD.encode(y),
+                    }),
+                )
+            }
+            SigningAlgorithm::EdDSA => {
+                let key = ed25519_dalek::VerifyingKey::from_public_key_pem(&self.public_pem)
+                    .map_err(parse_error)?;
+                (
+                    KeyAlgorithm::EdDSA,
+                    AlgorithmParameters::OctetKeyPair(OctetKeyPairParameters {
+                        key_type: OctetKeyPairType::OctetKeyPair,
+                        curve: EllipticCurve::Ed25519,
+                        x: URL_SAFE_NO_PAD.encode(key.to_bytes()),
+                    }),
+                )
+            }
+        };
+
+        Ok(Jwk {
+            common: CommonParameters {
+                public_key_use: Some(PublicKeyUse::Signature),
+                key_algorithm: Some(key_algorithm),
+                key_id: Some(self.kid.clone()),
+                ..Default::default()
+            },
+            algorithm,
+        })
+    }
+}
+
+/// Redis에서 읽은 서명 키 묶음.
+#[derive(Debug, Clone)]
+pub struct SigningKeySet {
+    /// 새 토큰에 서명하는 키의 `kid`.
+    pub active_kid: String,
+    /// 아직 만료되지 않은 모든 키 (활성 키 포함), 만든 순서.
+    pub keys: Vec<StoredSigningKey>,
+}
+
+impl SigningKeySet {
+    pub fn active(&self) -> Option<&StoredSigningKey> {
+        self.keys.iter().find(|key| key.kid == self.active_kid)
+    }
+}
+
+/// 교체된 키를 얼마나 더 공개할지 (초).
+///
+/// 가장 길게 발급될 수 있는 토큰(access 또는 세션 전용) 수명에, 다른 인스턴스가 교체를 알아채기
+/// 전까지 이전 키로 서명하는 시간과 검증 오차를 더합니다.
+pub fn retire_after_seconds(token_policy: &TokenPolicy) -> u64 {
+    token_policy.max_token_ttl_seconds() + CACHE_SECONDS + VALIDATION_LEEWAY_SECONDS
+}
+
+/// `20261019-1a2b3c4d` 형식의 kid. 만든 날짜로 키를 구분하기 쉽게 합니다.
+fn new_kid(created_at: DateTime<Utc>) -> String {
+    let random = uuid::Uuid::new_v4().simple().to_string();
+    format!("{}-{}", created_at.format("%Y%m%d"), &random[..8])
+}
+
+fn encode_error(e: impl fmt::Display) -> ApiError {
+    ApiError::InternalError(format!("Failed to encode signing key: {}", e))
+}
+
+/// `algorithm`용 새 키를 만듭니다. 저장하지는 않습니다.
+pub fn generate(algorithm: SigningAlgorithm) -> Result<StoredSigningKey, ApiError> {
+    let mut rng = rand::thread_rng();
+    let (private_pem, public_pem) = match algorithm {
+        SigningAlgorithm::RS256 => {
+            let key = RsaPrivateKey::new(&mut rng, 2048).map_err(|e| {
+                ApiError::InternalError(format!("Failed to generate RSA key: {}", e))
+            })?;
+            (
+                key.to_pkcs1_pem(LineEnding::LF).map_err(encode_error)?,
+                key.to_public_key()
+                    .to_pkcs1_pem(LineEnding::LF)
+                    .map_err(encode_error)?,
+            )
+        }
+        SigningAlgorithm::ES256 => {
+            let key = p256::SecretKey::random(&mut rng);
+            (
+                key.to_pkcs8_pem(LineEnding::LF).map_err(encode_error)?,
+                key.public_key()
+                    .to_public_key_pem(LineEnding::LF)
+                    .map_err(encode_error)?,
+            )
+        }
+        SigningAlgorithm::EdDSA => {
+            let key = ed25519_dalek::SigningKey::generate(&mut rng);
+            (
+                key.to_pkcs8_pem(LineEnding::LF).map_err(encode_error)?,
+                key.verifying_key()
+                    .to_public_key_pem(LineEnding::LF)
+                    .map_err(encode_error)?,
+            )
+        }
+    };
+
+    let created_at = Utc::now();
+    Ok(StoredSigningKey {
+        kid: new_kid(created_at),
+        algorithm,
+        private_pem: private_pem.to_string(),
+        public_pem,
+        created_at,
+    })
+}
+
+async fn store(
+    conn: &mut MultiplexedConnection,
+    keys: &Keys,
+    key: &StoredSigningKey,
+) -> Result<(), ApiError> {
+    redis::pipe()
+        .atomic()
+        .set(keys.signing_key(&key.kid), serde_json::to_string(key)?)
+        .ignore()
+        .zadd(
+            keys.signing_key_index(),
+            key.kid.as_str(),
+            key.created_at.timestamp_millis(),
+        )
+        .ignore()
+        .query_async::<()>(conn)
+        .await?;
+    Ok(())
+}
+
+async fn discard(conn: &mut MultiplexedConnection, keys: &Keys, kid: &str) -> Result<(), ApiError> {
+    redis::pipe()
+        .atomic()
+        .del(keys.signing_key(kid))
+        .ignore()
+        .zrem(keys.signing_key_index(), kid)
+        .ignore()
+        .query_async::<()>(conn)
+        .await?;
+    Ok(())
+}
+
+/// 활성 서명 키가 없으면 `algorithm`으로 만듭니다.
+///
+/// `kid` 도입 이전에 만든 단일 RSA 키(`rsa:private_pem`)가 있으면 새로 만들지 않고
+/// 그 키를 첫 번째 키로 가져오므로, 이미 발급된 토큰도 계속 검증됩니다.
+/// 활성 키 지정은 `SET NX`이므로 여러 인스턴스가 동시에 시작해도 하나만 쓰입니다.
+/// 이미 있는 키의 알고리즘이 다르면 [`rotate_if_due`]가 교체합니다.
+pub async fn initialize(
+    conn: &mut MultiplexedConnection,
+    keys: &Keys,
+    algorithm: SigningAlgorithm,
+) -> Result<(), ApiError> {
+    if conn.exists(keys.active_signing_key()).await? {
+        tracing::info!("Signing keys already exist in Redis");
+        return Ok(());
+    }
+
+    let legacy_private = conn.get(keys.rsa_private_pem()).await?;
+    let legacy_public = conn.get(keys.rsa_public_pem()).await?;
+    let key = match (legacy_private, legacy_public) {
+        (Some(private_pem), Some(public_pem)) => {
+            let created_at = Utc::now();
+            StoredSigningKey {
+                kid: new_kid(created_at),
+                algorithm: SigningAlgorithm::RS256,
+                private_pem,
+                public_pem,
+                created_at,
+            }
+        }
+        _ => generate(algorithm)?,
+    };
+
+    store(conn, keys, &key).await?;
+    if conn
+        .set_nx(keys.active_signing_key(), key.kid.as_str())
+        .await?
+    {
+        tracing::info!("Initialized signing key {}", key.kid);
+    } else {
+        discard(conn, keys, &key.kid).await?;
+        tracing::info!("Signing key was initialized by another instance");
+    }
+    Ok(())
+}
+
+/// 공개 중인 키를 모두 읽습니다. 만료된 키는 인덱스에서도 지웁니다.
+pub async fn load(
+    conn: &mut MultiplexedConnection,
+    keys: &Keys,
+) -> Result<SigningKeySet, ApiError> {
+    let active_kid = conn
+        .get(keys.active_signing_key())
+        .await?
+        .ok_or_else(|| ApiError::InternalError("Signing key is not initialized".to_string()))?;
+
+    let kids = conn.zrange(keys.signing_key_index(), 0, -1).await?;
+    let values = if kids.is_empty() {
+        Vec::new()
+    } else {
+        conn.mget(
+            kids.iter()
+                .map(|kid| keys.signing_key(kid))
+                .collect::<Vec<_>>(),
+        )
+        .await?
+    };
+
+    let mut stored = Vec::with_capacity(kids.len());
+    for (kid, value) in kids.into_iter().zip(values) {
+        match value {
+            Some(json) => stored.push(serde_json::from_str::<StoredSigningKey>(&json)?),
+            None => {
+                conn.zrem(keys.signing_key_index(), &kid).await?;
+                tracing::info!("Retired signing key {} expired", kid);
+            }
+        }
+    }
+
+    let set = SigningKeySet {
+        active_kid,
+        keys: stored,
+    };
+    if set.active().is_none() {
+        return Err(ApiError::InternalError(format!(
+            "Active signing key {} is missing",
+            set.active_kid
+        )));
+    }
+    Ok(set)
+}
+
+/// 교체 잠금을 잡습니다. 다른 인스턴스가 교체 중이면 `false`.
+async fn lock_rotation(conn: &mut MultiplexedConnection, keys: &Keys) -> Result<bool, ApiError> {
+    let options = SetOptions::default()
+        .conditional_set(ExistenceCheck::NX)
+        .with_expiration(SetExpiry::EX(ROTATION_LOCK_SECONDS));
+    Ok(conn
+        .set_options(keys.signing_key_rotation_lock(), "1", options)
+        .await?
+        .is_some())
+}
+
+/// `algorithm`으로 새 키를 만들어 활성 키로 지정하고, 이전 키에는
+/// `retire_after_seconds` TTL을 겁니다.
+///
+/// 교체 잠금을 잡은 상태에서만 실행하므로 동시에 두 번 교체되지 않습니다.
+/// 새 키의 kid를 반환합니다.
+pub async fn rotate(
+    conn: &mut MultiplexedConnection,
+    keys: &Keys,
+    algorithm: SigningAlgorithm,
+    retire_after_seconds: u64,
+) -> Result<String, ApiError> {
+    if !lock_rotation(conn, keys).await? {
+        return Err(ApiError::InternalError(
+            "Another signing key rotation is in progress".to_string(),
+        ));
+    }
+    let result = rotate_locked(conn, keys, algorithm, retire_after_seconds).await;
+    conn.del(keys.signing_key_rotation_lock()).await?;
+    result
+}
+
+async fn rotate_locked(
+    conn: &mut MultiplexedConnection,
+    keys: &Keys,
+    algorithm: SigningAlgorithm,
+    retire_after_seconds: u64,
+) -> Result<String, ApiError> {
+    let key = generate(algorithm)?;
+    store(conn, keys, &key).await?;
+    conn.set(keys.active_signing_key(), key.kid.as_str())
+        .await?;
+
+    for kid in conn.zrange(keys.signing_key_index(), 0, -1).await? {
+        if kid == key.kid {
+            continue;
+        }
+        if conn.ttl(keys.signing_key(&kid)).await? == IntegerReplyOrNoOp::ExistsButNotRelevant {
+            conn.expire(keys.signing_key(&kid), retire_after_seconds as i64)
+                .await?;
+            tracing::info!(
+                "Retired signing key {}, published for {}s more",
+                kid,
+                retire_after_seconds
+            );
+        }
+    }
+
+    tracing::info!("Rotated signing key, new kid {} ({})", key.kid, algorithm);
+    Ok(key.kid)
+}
+
+/// 활성 키가 `interval_seconds`보다 오래되었거나 `algorithm`과 다른 알고리즘이면
+/// 교체합니다. `interval_seconds`가 `None`이면 알고리즘이 다를 때만 교체합니다.
+///
+/// 잠금을 잡은 인스턴스 하나만 교체하고 나머지는 건너뛰므로 모든 인스턴스가
+/// 주기적으로 호출해도 됩니다. 교체했으면 새 kid를 반환합니다.
+pub async fn rotate_if_due(
+    conn: &mut MultiplexedConnection,
+    keys: &Keys,
+    algorithm: SigningAlgorithm,
+    interval_seconds: Option<u64>,
+    retire_after_seconds: u64,
+) -> Result<Option<String>, ApiError> {
+    let is_due = |set: &SigningKeySet| {
+        set.active().is_some_and(|key| {
+            let age = Utc::now()
+                .signed_duration_since(key.created_at)
+                .num_seconds();
+            key.algorithm != algorithm
+                || interval_seconds.is_some_and(|interval| age >= interval as i64)
+        })
+    };
+
+    if !is_due(&load(conn, keys).await?) {
+        return Ok(None);
+    }
+
+    if !lock_rotation(conn, keys).await? {
+        return Ok(None);
+    }
+
+    // 잠금을 잡기 전에 다른 인스턴스가 이미 교체했을 수 있습니다.
+    let result = match load(conn, keys).await {
+        Ok(set) if is_due(&set) => rotate_locked(conn, keys, algorithm, retire_after_seconds)
+            .await
+            .map(Some),
+        Ok(_) => Ok(None),
+        Err(e) => Err(e),
+    };
+    conn.del(keys.signing_key_rotation_lock()).await?;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kid_is_dated_and_unique() {
+        let now = Utc::now();
+        let a = new_kid(now);
+        let b = new_kid(now);
+        assert!(a.starts_with(&now.format("%Y%m%d-").to_string()));
+        assert_eq!(a.len(), 17);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_algorithm_names() {
+        for algorithm in [
+            SigningAlgorithm::RS256,
+            SigningAlgorithm::ES256,
+            SigningAlgorithm::EdDSA,
+        ] {
+            assert_eq!(
+                algorithm.as_str().parse::<SigningAlgorithm>(),
+                Ok(algorithm)
+            );
+            assert_eq!(
+                serde_json::to_string(&algorithm).unwrap(),
+                format!("\"{}\"", algorithm)
+            );
+        }
+        assert_eq!("eddsa".parse(), Ok(SigningAlgorithm::EdDSA));
+        assert!("HS256".parse::<SigningAlgorithm>().is_err());
+    }
+
+    #[test]
+    fn test_keys_without_algorithm_are_rs256() {
+        let json =
+            r#"{"kid":"k","private_pem":"","public_pem":"","created_at":"2026-01-01T00:00:00Z"}"#;
+        let key: StoredSigningKey = serde_json::from_str(json).unwrap();
+        assert_eq!(key.algorithm, SigningAlgorithm::RS256);
+    }
+
+    #[test]
+    fn test_generated_keys_sign_verify_and_publish() {
+        for (algorithm, kty) in [
+            (SigningAlgorithm::RS256, "RSA"),
+            (SigningAlgorithm::ES256, "EC"),
+            (SigningAlgorithm::EdDSA, "OKP"),
+        ] {
+            let stored = generate(algorithm).unwrap();
+            let public_key = stored.public_key();
+            let token = crate::jwt::create_token(
+                "test_user",
+                0,
+                Default::default(),
+                &stored.signing_key().unwrap(),
+                60,
+            )
+            .unwrap();
+
+            let header = jsonwebtoken::decode_header(&token).unwrap();
+            assert_eq!(header.alg, algorithm.jwt_algorithm());
+            assert_eq!(header.kid.as_deref(), Some(stored.kid.as_str()));
+
+            let claims = crate::jwt::verify_token(
+                &token,
+                &public_key.decoding_key().unwrap(),
+                algorithm.jwt_algorithm(),
+            )
+            .unwrap();
+            assert_eq!(claims.sub, "test_user");
+
+            let jwk = serde_json::to_value(public_key.to_jwk().unwrap()).unwrap();
+            assert_eq!(jwk["kty"], kty, "{algorithm}");
+            assert_eq!(jwk["alg"], algorithm.as_str());
+            assert_eq!(jwk["kid"], stored.kid);
+
+            // JWK만으로도 같은 토큰을 검증할 수 있어야 합니다.
+            let from_jwk = DecodingKey::from_jwk(&public_key.to_jwk().unwrap()).unwrap();
+            crate::jwt::verify_token(&token, &from_jwk, algorithm.jwt_algorithm()).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_compact_algorithms_make_smaller_tokens() {
+        let token = |algorithm| {
+            let key = generate(algorithm).unwrap().signing_key().unwrap();
+            crate::jwt::create_token("test_user", 0, Default::default(), &key, 60).unwrap()
+        };
+        let rs256 = token(SigningAlgorithm::RS256);
+        assert!(token(SigningAlgorithm::ES256).len() < rs256.len());
+        assert!(token(SigningAlgorithm::EdDSA).len() < rs256.len());
+    }
+
+    #[test]
+    fn test_retired_keys_outlive_longest_token() {
+        let policy = TokenPolicy::default();
+        assert!(retire_after_seconds(&policy) > policy.max_access_ttl_seconds + CACHE_SECONDS);
+    }
+}
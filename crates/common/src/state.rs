@@ -1,23 +1,415 @@
+use std::collections::HashSet;
+use std::net::IpAddr;
+use std::sync::Arc;
+
 use argon2::{PasswordHash, PasswordVerifier};
-use redis::{AsyncTypedCommands, Client};
+use redis::{AsyncTypedCommands, Client, ExistenceCheck, SetExpiry, SetOptions};
 use serde::Serialize;
 
-use crate::{errors::ApiError, session_data::SessionData, share::ShareState, user_data::UserData};
+use crate::{
+    api_key::{self, ApiKeyRecord},
+    audit::{AuditEvent, AuditQuery, AuditSink, RedisAuditSink},
+    auth_cookie::CookiePolicy,
+    directory::SessionRef,
+    errors::ApiError,
+    jwt,
+    jwt::{AccountClaims, Claims, TokenPolicy},
+    keys::Keys,
+    oidc::{self, OidcLoginState, OidcProvider},
+    org::{OrgData, OrgMemberships, OrgRole},
+    rate_limit::{
+        self, LoginRejectionStats, Quota, QuotaExceeded, RateLimitPolicy, StreamSlot, UserUsage,
+    },
+    refresh_token::{self, RefreshTokenRecord},
+    scope::SessionAccess,
+    session_data::{PendingTransfer, SessionData, SessionRole, Visibility},
+    session_list::ListCursor,
+    share::ShareState,
+    share_link::{self, ShareLinkRecord},
+    signing_key, stream_moved_message,
+    trusted_issuer::TrustedIssuers,
+    user_data::{hash_password, UserData},
+    ListResponse, STREAM_CLOSE_ANONYMOUS_MESSAGE, STREAM_CLOSE_MESSAGE,
+};
+
+/// 이전된 세션의 예전 스트림 URL을 새 URL로 보내 주는 기간 (7일).
+pub const MOVED_SESSION_REDIRECT_SECONDS: u64 = 7 * 86400;
+
+/// 계정 JSON에서 `ARGV[1]`에 있는 필드만 바꾸고, `ARGV[3]`이 `1`이면 토큰 세대를 올립니다.
+///
+/// `ARGV[2]`의 필드 중 하나라도 저장된 값과 다르면 바꾸지 않습니다 (없는 필드는 `null`로 봅니다).
+/// 계정이 없으면 nil을, 있으면 `{바꿨으면 1 아니면 0, 저장된 JSON}`을 돌려줍니다.
+const UPDATE_USER_DATA_SCRIPT: &str = r"
+local json = redis.call('GET', KEYS[1])
+if not json then
+  return false
+end
+local user = cjson.decode(json)
+for field, value in pairs(cjson.decode(ARGV[2])) do
+  local current = user[field]
+  if current == nil then
+    current = cjson.null
+  end
+  if current ~= value then
+    return {0, json}
+  end
+end
+for field, value in pairs(cjson.decode(ARGV[1])) do
+  user[field] = value
+end
+if ARGV[3] == '1' then
+  user.token_generation = (tonumber(user.token_generation) or 0) + 1
+end
+json = cjson.encode(user)
+redis.call('SET', KEYS[1], json)
+return {1, json}
+";
+
+/// 세션 JSON이 `ARGV[1]`과 같을 때만 `ARGV[2]`로 바꿉니다.
+///
+/// `ARGV[3]`이 비어 있으면 남은 TTL을 유지하고, 아니면 그 초만큼 만료를 다시 정합니다.
+/// 바꿨으면 1을, 세션이 없거나 그 사이에 바뀌었으면 0을 돌려줍니다.
+const COMPARE_AND_SET_SESSION_SCRIPT: &str = r"
+if redis.call('GET', KEYS[1]) ~= ARGV[1] then
+  return 0
+end
+if ARGV[3] == '' then
+  redis.call('SET', KEYS[1], ARGV[2], 'KEEPTTL')
+else
+  redis.call('SET', KEYS[1], ARGV[2], 'EX', ARGV[3])
+end
+return 1
+";
+
+/// 이전 소유자의 세션 JSON이 `ARGV[1]`과 같으면 받는 사용자에게 옮기고 `ARGV[2]`로 바꿉니다.
+///
+/// 키: 이전 세션, 새 세션, 이전 소유자와 받는 사용자의 세션 인덱스, 디렉터리, 받는 사용자의
+/// 받은 이전 요청, 예전 URL 안내 키. 인자: 세션 ID, 생성 시각(ms), 이전/새 디렉터리 멤버,
+/// 디렉터리 등록 여부(`1`), 받은 이전 요청 필드, 받는 사용자, 안내 유지 시간(초) 순입니다.
+/// 옮겼으면 1, 세션이 없거나 그 사이에 바뀌었으면 0, 받는 사용자에게 같은 ID의 세션이 있으면 -1을
+/// 돌려줍니다. 디렉터리에 등록된 세션은 원래 점수를 그대로 씁니다.
+const ACCEPT_SESSION_TRANSFER_SCRIPT: &str = r"
+if redis.call('GET', KEYS[1]) ~= ARGV[1] then
+  return 0
+end
+if redis.call('EXISTS', KEYS[2]) == 1 then
+  return -1
+end
+redis.call('RENAME', KEYS[1], KEYS[2])
+redis.call('SET', KEYS[2], ARGV[2], 'KEEPTTL')
+redis.call('ZREM', KEYS[3], ARGV[3])
+redis.call('ZADD', KEYS[4], 'NX', ARGV[4], ARGV[3])
+local score = redis.call('ZSCORE', KEYS[5], ARGV[5])
+redis.call('ZREM', KEYS[5], ARGV[5])
+if score and ARGV[7] == '1' then
+  redis.call('ZADD', KEYS[5], score, ARGV[6])
+end
+redis.call('HDEL', KEYS[6], ARGV[8])
+redis.call('SET', KEYS[7], ARGV[9], 'EX', ARGV[10])
+return 1
+";
+
+/// 다른 요청과 겹쳐 세션을 저장하지 못했을 때 다시 읽어 고치는 최대 횟수.
+const SESSION_UPDATE_ATTEMPTS: usize = 5;
+
+/// 세션 참조가 가리키는 데이터 키. 스트림 채널 이름과 같습니다.
+fn session_ref_key(keys: &Keys, session_ref: &SessionRef) -> String {
+    match (&session_ref.user_id, &session_ref.org_id) {
+        (Some(user_id), _) => keys.user_session(user_id, &session_ref.session_id),
+        (None, Some(org_id)) => keys.org_session(org_id, &session_ref.session_id),
+        (None, None) => keys.public_session(&session_ref.session_id),
+    }
+}
+
+/// 세션의 `listed` 값에 맞춰 디렉터리 인덱스를 갱신하는 명령을 파이프라인에 추가합니다.
+///
+/// 점수는 세션 생성 시각(ms)이며, 생성 시각이 없으면 등록 시각을 씁니다.
+fn pipe_directory_sync(
+    pipe: &mut redis::Pipeline,
+    keys: &Keys,
+    session_ref: &SessionRef,
+    session: &SessionData,
+) -> Result<(), ApiError> {
+    let member = serde_json::to_string(session_ref)?;
+    if session.listed {
+        pipe.cmd("ZADD")
+            .arg(keys.directory())
+            .arg("NX")
+            .arg(
+                session
+                    .created_at
+                    .unwrap_or_else(chrono::Utc::now)
+                    .timestamp_millis(),
+            )
+            .arg(member)
+            .ignore();
+    } else {
+        pipe.zrem(keys.directory(), member).ignore();
+    }
+    Ok(())
+}
+
+/// 세션 키들의 (데이터, 남은 TTL, 뷰어 수)를 한 번에 조회합니다. 사라진 세션은 `None`입니다.
+async fn load_session_entries(
+    conn: &mut redis::aio::MultiplexedConnection,
+    keys: &[String],
+) -> Result<Vec<Option<(SessionData, Option<i64>, u64)>>, ApiError> {
+    let values: Vec<Option<String>> = redis::cmd("MGET").arg(keys).query_async(conn).await?;
+    let mut ttl_pipe = redis::pipe();
+    for key in keys {
+        ttl_pipe.ttl(key);
+    }
+    let ttls: Vec<i64> = ttl_pipe.query_async(conn).await?;
+    let viewers: Vec<(String, u64)> = redis::cmd("PUBSUB")
+        .arg("NUMSUB")
+        .arg(keys)
+        .query_async(conn)
+        .await?;
+
+    let mut entries = Vec::with_capacity(keys.len());
+    for ((value, ttl), (_, viewers)) in values.into_iter().zip(ttls).zip(viewers) {
+        entries.push(match value {
+            Some(json) if ttl != -2 => Some((
+                serde_json::from_str::<SessionData>(&json)?,
+                (ttl >= 0).then_some(ttl),
+                viewers,
+            )),
+            _ => None,
+        });
+    }
+    Ok(entries)
+}
+
+/// 세션 키들의 데이터를 `MGET` 한 번으로 조회합니다. 사라진 세션은 `None`입니다.
+async fn load_sessions(
+    conn: &mut redis::aio::MultiplexedConnection,
+    keys: &[String],
+) -> Result<Vec<Option<SessionData>>, ApiError> {
+    if keys.is_empty() {
+        return Ok(Vec::new());
+    }
+    let values: Vec<Option<String>> = redis::cmd("MGET").arg(keys).query_async(conn).await?;
+    values
+        .into_iter()
+        .map(|value| {
+            value
+                .map(|json| serde_json::from_str::<SessionData>(&json))
+                .transpose()
+                .map_err(ApiError::from)
+        })
+        .collect()
+}
+
+/// 세션 인덱스와 `{session_prefix}*` SCAN으로 찾은 세션 ID. 인덱스에 없는 예전 세션도 포함됩니다.
+async fn collect_session_ids(
+    conn: &mut redis::aio::MultiplexedConnection,
+    index_key: &str,
+    session_prefix: &str,
+) -> Result<HashSet<String>, ApiError> {
+    let mut session_ids: HashSet<String> =
+        conn.zrange(index_key, 0, -1).await?.into_iter().collect();
+    session_ids.extend(
+        scan_keys(conn, &format!("{}*", session_prefix))
+            .await?
+            .iter()
+            .filter_map(|key| key.strip_prefix(session_prefix))
+            .map(str::to_string),
+    );
+    Ok(session_ids)
+}
+
+/// `pattern`에 맞는 키를 SCAN으로 모두 찾습니다. 같은 키가 여러 번 나올 수 있습니다.
+async fn scan_keys(
+    conn: &mut redis::aio::MultiplexedConnection,
+    pattern: &str,
+) -> Result<Vec<String>, ApiError> {
+    let mut keys = Vec::new();
+    let mut cursor: u64 = 0;
+    loop {
+        let (next, batch): (u64, Vec<String>) = redis::cmd("SCAN")
+            .arg(cursor)
+            .arg("MATCH")
+            .arg(pattern)
+            .arg("COUNT")
+            .arg(500)
+            .query_async(conn)
+            .await?;
+        keys.extend(batch);
+        if next == 0 {
+            break;
+        }
+        cursor = next;
+    }
+    Ok(keys)
+}
+
+/// 공유받은 세션 인덱스의 필드 (`{owner}/{session_id}`). ID에는 `/`가 들어갈 수 없습니다.
+fn shared_session_field(owner: &str, session_id: &str) -> String {
+    format!("{}/{}", owner, session_id)
+}
+
+/// 디렉터리 인덱스를 통해 조회한 세션.
+#[derive(Debug, Clone)]
+pub struct DirectorySessionEntry {
+    pub session_ref: SessionRef,
+    pub session: SessionData,
+    pub viewers: u64,
+}
+
+/// 인덱스를 통해 조회한 사용자 또는 조직 세션과 부가 정보.
+#[derive(Debug, Clone)]
+pub struct UserSessionEntry {
+    pub session_id: String,
+    pub session: SessionData,
+    /// 남은 TTL(초). 만료가 설정되지 않았으면 `None`.
+    pub ttl_seconds: Option<i64>,
+    /// 스트림 채널을 구독 중인 연결 수.
+    pub viewers: u64,
+}
+
+/// 세션 인덱스에서 읽을 페이지.
+#[derive(Debug, Clone, Copy)]
+pub struct IndexPage<'a> {
+    /// 앞 페이지의 마지막 항목. 처음부터 읽으면 `None`.
+    pub after: Option<&'a ListCursor>,
+    /// 최신 세션부터 읽는지 여부.
+    pub descending: bool,
+    /// 읽을 최대 항목 수.
+    pub count: usize,
+}
+
+/// 협업자로 추가되어 공유받은 세션.
+#[derive(Debug, Clone)]
+pub struct SharedSessionEntry {
+    /// 세션 소유자 ID.
+    pub owner: String,
+    pub role: SessionRole,
+    pub entry: UserSessionEntry,
+}
+
+/// 사용자에게 온 소유권 이전 요청.
+#[derive(Debug, Clone)]
+pub struct IncomingTransferEntry {
+    /// 현재 소유자 ID.
+    pub owner: String,
+    pub session_id: String,
+    pub session: SessionData,
+    pub transfer: PendingTransfer,
+}
+
+/// 사용자가 속한 조직.
+#[derive(Debug, Clone)]
+pub struct OrgEntry {
+    pub org_id: String,
+    /// 조회한 사용자의 역할.
+    pub role: OrgRole,
+    pub org: OrgData,
+}
+
+/// 관리자 목록에서 조회한 계정.
+#[derive(Debug, Clone)]
+pub struct AccountEntry {
+    pub user_id: String,
+    pub user_data: UserData,
+    /// 세션 인덱스에 있는 세션 수.
+    pub sessions: u64,
+}
+
+/// 관리자 목록에서 조회한 세션. 공용, 사용자, 조직 세션을 모두 포함합니다.
+#[derive(Debug, Clone)]
+pub struct StoredSessionEntry {
+    pub session_ref: SessionRef,
+    pub session: SessionData,
+    /// 남은 TTL(초). 만료가 설정되지 않았으면 `None`.
+    pub ttl_seconds: Option<i64>,
+    pub viewers: u64,
+}
+
+/// refresh 토큰 교체 결과.
+#[derive(Debug, Clone)]
+pub struct RotatedRefreshToken {
+    pub user_id: String,
+    /// 다음 갱신에 쓸 새 refresh 토큰.
+    pub refresh_token: String,
+    pub user_data: UserData,
+}
+
+/// 시작한 SSO 로그인.
+#[derive(Debug, Clone)]
+pub struct OidcLoginStart {
+    /// 콜백에서 돌아올 `state`
+    pub state: String,
+    /// 제공자의 인가 URL
+    pub url: String,
+}
+
+/// SSO 로그인 결과.
+#[derive(Debug, Clone)]
+pub struct OidcLogin {
+    pub user_id: String,
+    pub user_data: UserData,
+    /// 쿠키 로그인으로 시작했는지 여부 ([`AppState::begin_oidc_login`]의 `cookie`)
+    pub cookie: bool,
+}
 
 #[derive(Clone, Debug)]
 pub struct AppState {
     redis_client: Client,
     share: ShareState,
+    oidc: Option<OidcProvider>,
+    audit_sink: Arc<dyn AuditSink>,
 }
 
 impl AppState {
-    pub fn new(redis_client: Client) -> Self {
+    pub fn new(
+        redis_client: Client,
+        keys: Keys,
+        argon2_params: argon2::Params,
+        token_policy: TokenPolicy,
+    ) -> Self {
         Self {
             redis_client: redis_client.clone(),
-            share: ShareState::new(),
+            audit_sink: Arc::new(RedisAuditSink::new(redis_client, &keys)),
+            share: ShareState::new(keys, argon2_params, token_policy),
+            oidc: None,
         }
     }
 
+    /// 감사 기록을 기본 Redis stream 대신 `sink`로 보냅니다.
+    pub fn with_audit_sink(mut self, sink: Arc<dyn AuditSink>) -> Self {
+        self.audit_sink = sink;
+        self
+    }
+
+    /// SSO 로그인에 쓸 OIDC 제공자를 설정합니다.
+    pub fn with_oidc(mut self, provider: OidcProvider) -> Self {
+        self.oidc = Some(provider);
+        self
+    }
+
+    /// 이 서버의 키 외에 외부 발급자가 서명한 토큰도 받아들입니다.
+    pub fn with_trusted_issuers(mut self, trusted_issuers: TrustedIssuers) -> Self {
+        self.share = self.share.with_trusted_issuers(trusted_issuers);
+        self
+    }
+
+    /// 로그인 시도 제한과 사용자별 할당량을 기본값 대신 `policy`로 설정합니다.
+    pub fn with_rate_limit_policy(mut self, policy: RateLimitPolicy) -> Self {
+        self.share = self.share.with_rate_limit_policy(policy);
+        self
+    }
+
+    /// 로그인 쿠키 속성을 기본값 대신 `policy`로 설정합니다.
+    pub fn with_cookie_policy(mut self, policy: CookiePolicy) -> Self {
+        self.share = self.share.with_cookie_policy(policy);
+        self
+    }
+
+    /// 설정된 OIDC 제공자. SSO를 쓰지 않으면 `None`.
+    pub fn oidc(&self) -> Option<&OidcProvider> {
+        self.oidc.as_ref()
+    }
+
     pub fn redis_client(&self) -> &Client {
         &self.redis_client
     }
@@ -26,6 +418,11 @@ impl AppState {
         &self.share
     }
 
+    /// 설정된 `key_prefix`가 적용된 Redis 키 생성기.
+    pub fn keys(&self) -> &Keys {
+        self.share.keys()
+    }
+
     pub async fn connection_redis(&self) -> Result<redis::aio::MultiplexedConnection, ApiError> {
         let conn = self.redis_client.get_multiplexed_async_connection().await?;
         Ok(conn)
@@ -41,6 +438,23 @@ impl AppState {
         Ok(())
     }
 
+    /// 감사 기록을 남깁니다.
+    ///
+    /// 기록하지 못해도 요청은 실패시키지 않고 오류 로그만 남깁니다.
+    pub async fn audit(&self, event: AuditEvent) {
+        if let Err(e) = self.audit_sink.append(&event).await {
+            tracing::error!("Failed to write audit event {:?}: {:?}", event, e);
+        }
+    }
+
+    /// 최근 감사 기록부터 `query`에 맞는 한 페이지를 조회합니다.
+    pub async fn query_audit_log(
+        &self,
+        query: &AuditQuery,
+    ) -> Result<ListResponse<AuditEvent>, ApiError> {
+        self.audit_sink.query(query).await
+    }
+
     pub async fn pubsub(&self) -> Result<redis::aio::PubSub, ApiError> {
         let pubsub = self.redis_client.get_async_pubsub().await?;
         Ok(pubsub)
@@ -52,22 +466,63 @@ impl AppState {
         session: &SessionData,
         ttl_seconds: u64,
     ) -> Result<(), ApiError> {
-        let frame = session.current_frame();
-        let session = serde_json::to_string(session)?;
-        let frame = serde_json::to_string(&frame)?;
+        self.share.rate_limit_policy().check_session_size(session)?;
+        let frame = serde_json::to_string(&session.current_frame())?;
+        let session_json = serde_json::to_string(session)?;
+
+        let key = self.keys().public_session(session_id);
+
+        let mut pipe = redis::pipe();
+        pipe.atomic()
+            .set_ex(&key, session_json.as_str(), ttl_seconds)
+            .ignore();
+        pipe_directory_sync(
+            &mut pipe,
+            self.keys(),
+            &SessionRef::public(session_id),
+            session,
+        )?;
+
         let mut conn = self.redis_client.get_multiplexed_async_connection().await?;
-        conn.set_ex(session_id, session.as_str(), ttl_seconds)
-            .await?;
-        conn.publish(session_id, &frame).await?;
+        pipe.query_async::<()>(&mut conn).await?;
+        conn.publish(&key, &frame).await?;
         Ok(())
     }
 
+    /// 공용 세션을 `update`로 고쳐 저장합니다. 세션이 없으면 `None`을 반환합니다.
+    ///
+    /// [`Self::update_user_session`] 참고.
+    pub async fn update_public_session<R>(
+        &self,
+        session_id: &str,
+        ttl_seconds: u64,
+        update: impl FnMut(&mut SessionData) -> Result<R, ApiError>,
+    ) -> Result<Option<(SessionData, R)>, ApiError> {
+        self.update_indexed_session(
+            &self.keys().public_session(session_id),
+            None,
+            &SessionRef::public(session_id),
+            ttl_seconds,
+            update,
+        )
+        .await
+    }
+
+    pub async fn session_exists(&self, session_id: &str) -> Result<bool, ApiError> {
+        let exists = self
+            .connection_redis()
+            .await?
+            .exists(self.keys().public_session(session_id))
+            .await?;
+        Ok(exists)
+    }
+
     pub async fn get_session(&self, session_id: &str) -> Result<Option<SessionData>, ApiError> {
         let data: Option<String> = self
             .redis_client
             .get_multiplexed_async_connection()
             .await?
-            .get(session_id)
+            .get(self.keys().public_session(session_id))
             .await?;
         match data {
             Some(json) => {
@@ -85,32 +540,263 @@ impl AppState {
         session: &SessionData,
         ttl_seconds: u64,
     ) -> Result<(), ApiError> {
-        let key = format!("user:{}:session:{}", user_id, session_id);
-        let channel = format!("user:{}:session:{}", user_id, session_id);
+        self.store_indexed_session(
+            &self.keys().user_session(user_id, session_id),
+            &self.keys().user_session_index(user_id),
+            &SessionRef::user(user_id, session_id),
+            session,
+            ttl_seconds,
+            false,
+        )
+        .await
+    }
+
+    /// 새 사용자 세션을 저장합니다.
+    ///
+    /// 같은 ID의 세션이 있으면 협업자, 공유 링크, 이전 요청을 잃지 않도록 덮어쓰지 않고
+    /// `ApiError::SessionExists`를 반환합니다.
+    pub async fn create_user_session(
+        &self,
+        user_id: &str,
+        session_id: &str,
+        session: &SessionData,
+        ttl_seconds: u64,
+    ) -> Result<(), ApiError> {
+        self.store_indexed_session(
+            &self.keys().user_session(user_id, session_id),
+            &self.keys().user_session_index(user_id),
+            &SessionRef::user(user_id, session_id),
+            session,
+            ttl_seconds,
+            true,
+        )
+        .await
+    }
 
-        let frame = session.current_frame();
+    /// 세션을 저장하고 소유자의 세션 인덱스와 디렉터리를 맞춘 뒤 새 프레임을 발행합니다.
+    ///
+    /// 세션 키는 스트림 채널 이름으로도 쓰입니다. `create`이면 `SET NX`로 저장해 같은 키가
+    /// 이미 있을 때 `ApiError::SessionExists`를 반환합니다.
+    async fn store_indexed_session(
+        &self,
+        key: &str,
+        index_key: &str,
+        session_ref: &SessionRef,
+        session: &SessionData,
+        ttl_seconds: u64,
+        create: bool,
+    ) -> Result<(), ApiError> {
+        self.share.rate_limit_policy().check_session_size(session)?;
         let session_json = serde_json::to_string(session)?;
-        let frame_json = serde_json::to_string(&frame)?;
 
         let mut conn = self.redis_client.get_multiplexed_async_connection().await?;
-        conn.set_ex(&key, session_json.as_str(), ttl_seconds)
-            .await?;
-        conn.publish(&channel, &frame_json).await?;
+        if create {
+            let options = SetOptions::default()
+                .conditional_set(ExistenceCheck::NX)
+                .with_expiration(SetExpiry::EX(ttl_seconds));
+            if conn
+                .set_options(key, session_json.as_str(), options)
+                .await?
+                .is_none()
+            {
+                return Err(ApiError::SessionExists(session_ref.session_id.clone()));
+            }
+        } else {
+            conn.set_ex(key, session_json.as_str(), ttl_seconds).await?;
+        }
+        self.sync_stored_session(key, Some(index_key), session_ref, session)
+            .await
+    }
+
+    /// 저장한 세션에 맞춰 소유자의 세션 인덱스와 디렉터리를 갱신하고 새 프레임을 발행합니다.
+    async fn sync_stored_session(
+        &self,
+        key: &str,
+        index_key: Option<&str>,
+        session_ref: &SessionRef,
+        session: &SessionData,
+    ) -> Result<(), ApiError> {
+        let frame_json = serde_json::to_string(&session.current_frame())?;
+
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        if let Some(index_key) = index_key {
+            pipe.cmd("ZADD")
+                .arg(index_key)
+                .arg("NX")
+                .arg(
+                    session
+                        .created_at
+                        .unwrap_or_else(chrono::Utc::now)
+                        .timestamp_millis(),
+                )
+                .arg(&session_ref.session_id)
+                .ignore();
+        }
+        pipe_directory_sync(&mut pipe, self.keys(), session_ref, session)?;
+        let mut conn = self.connection_redis().await?;
+        pipe.query_async::<()>(&mut conn).await?;
+        if session.visibility == Visibility::Private {
+            conn.publish(key, STREAM_CLOSE_ANONYMOUS_MESSAGE).await?;
+        }
+        conn.publish(key, &frame_json).await?;
 
         Ok(())
     }
 
+    /// 세션을 읽어 `update`로 고친 뒤, 읽은 뒤로 다른 요청이 바꾸지 않았을 때만 저장합니다.
+    ///
+    /// 그 사이에 세션이 바뀌었으면 다시 읽어 `update`를 다시 부르므로, 권한처럼 세션 내용에 따라
+    /// 달라지는 확인은 `update` 안에서 해야 합니다. `update`가 오류를 내면 저장하지 않고, 내용이
+    /// 그대로이면 쓰지 않습니다. `ttl_seconds`가 `None`이면 남은 TTL을 유지합니다.
+    ///
+    /// 세션이 없으면 `None`을, 있으면 (저장된 세션, `update`의 결과)를 반환합니다.
+    /// 계속 겹쳐 저장하지 못하면 `ApiError::SessionConflict`를 반환합니다.
+    async fn update_session<R>(
+        &self,
+        key: &str,
+        ttl_seconds: Option<u64>,
+        mut update: impl FnMut(&mut SessionData) -> Result<R, ApiError>,
+    ) -> Result<Option<(SessionData, R)>, ApiError> {
+        let mut conn = self.connection_redis().await?;
+        let ttl = ttl_seconds.map(|ttl| ttl.to_string()).unwrap_or_default();
+        for _ in 0..SESSION_UPDATE_ATTEMPTS {
+            let Some(json) = conn.get(key).await? else {
+                return Ok(None);
+            };
+            let mut session = serde_json::from_str::<SessionData>(&json)?;
+            let before = serde_json::to_string(&session)?;
+            let result = update(&mut session)?;
+            let after = serde_json::to_string(&session)?;
+            if after == before && ttl_seconds.is_none() {
+                return Ok(Some((session, result)));
+            }
+
+            let stored: bool = redis::Script::new(COMPARE_AND_SET_SESSION_SCRIPT)
+                .key(key)
+                .arg(&json)
+                .arg(&after)
+                .arg(&ttl)
+                .invoke_async(&mut conn)
+                .await?;
+            if stored {
+                return Ok(Some((session, result)));
+            }
+            tracing::debug!("Session {} changed while updating, retrying", key);
+        }
+        Err(ApiError::SessionConflict)
+    }
+
+    /// 세션을 [`Self::update_session`]으로 고쳐 `ttl_seconds` 동안 저장하고,
+    /// 인덱스와 디렉터리를 맞춘 뒤 새 프레임을 발행합니다.
+    async fn update_indexed_session<R>(
+        &self,
+        key: &str,
+        index_key: Option<&str>,
+        session_ref: &SessionRef,
+        ttl_seconds: u64,
+        mut update: impl FnMut(&mut SessionData) -> Result<R, ApiError>,
+    ) -> Result<Option<(SessionData, R)>, ApiError> {
+        let policy = self.share.rate_limit_policy();
+        let updated = self
+            .update_session(key, Some(ttl_seconds), |session| {
+                let result = update(session)?;
+                policy.check_session_size(session)?;
+                Ok(result)
+            })
+            .await?;
+        if let Some((session, _)) = &updated {
+            self.sync_stored_session(key, index_key, session_ref, session)
+                .await?;
+        }
+        Ok(updated)
+    }
+
+    /// 사용자 세션을 `update`로 고쳐 저장합니다. 세션이 없으면 `None`을 반환합니다.
+    ///
+    /// 읽은 뒤로 협업자 변경이나 소유권 이전 같은 다른 요청이 세션을 바꿨으면 새로 읽어 다시
+    /// 고치므로, 예전 내용으로 덮어쓰지 않습니다. [`Self::update_session`] 참고.
+    pub async fn update_user_session<R>(
+        &self,
+        user_id: &str,
+        session_id: &str,
+        ttl_seconds: u64,
+        update: impl FnMut(&mut SessionData) -> Result<R, ApiError>,
+    ) -> Result<Option<(SessionData, R)>, ApiError> {
+        self.update_indexed_session(
+            &self.keys().user_session(user_id, session_id),
+            Some(&self.keys().user_session_index(user_id)),
+            &SessionRef::user(user_id, session_id),
+            ttl_seconds,
+            update,
+        )
+        .await
+    }
+
+    /// 사용자 세션을 삭제하고 인덱스에서도 제거합니다. 세션의 공유 링크와 협업자들의
+    /// 공유받은 세션 항목도 함께 지웁니다.
+    ///
+    /// 세션이 존재했으면 `true`를 반환합니다.
+    pub async fn delete_user_session(
+        &self,
+        user_id: &str,
+        session_id: &str,
+    ) -> Result<bool, ApiError> {
+        let member = serde_json::to_string(&SessionRef::user(user_id, session_id))?;
+        let links_key = self.keys().user_share_links(user_id);
+        let collaborators = self
+            .get_user_session(user_id, session_id)
+            .await?
+            .map(|session| session.collaborators)
+            .unwrap_or_default();
+        let mut conn = self.connection_redis().await?;
+        let link_ids: Vec<String> = conn
+            .hgetall(&links_key)
+            .await?
+            .into_iter()
+            .filter(|(_, link_session_id)| link_session_id == session_id)
+            .map(|(link_id, _)| link_id)
+            .collect();
+
+        let mut pipe = redis::pipe();
+        pipe.atomic()
+            .del(self.keys().user_session(user_id, session_id))
+            .zrem(self.keys().user_session_index(user_id), session_id)
+            .ignore()
+            .zrem(self.keys().directory(), member)
+            .ignore();
+        for link_id in &link_ids {
+            pipe.del(self.keys().share_link(link_id))
+                .ignore()
+                .hdel(&links_key, link_id)
+                .ignore();
+        }
+        for collaborator in collaborators.keys() {
+            pipe.hdel(
+                self.keys().user_shared_sessions(collaborator),
+                shared_session_field(user_id, session_id),
+            )
+            .ignore();
+        }
+        let (deleted,): (u64,) = pipe.query_async(&mut conn).await?;
+        Ok(deleted > 0)
+    }
+
     pub async fn get_user_session(
         &self,
         user_id: &str,
         session_id: &str,
     ) -> Result<Option<SessionData>, ApiError> {
-        let key = format!("user:{}:session:{}", user_id, session_id);
+        self.load_session(&self.keys().user_session(user_id, session_id))
+            .await
+    }
+
+    async fn load_session(&self, key: &str) -> Result<Option<SessionData>, ApiError> {
         let data: Option<String> = self
             .redis_client
             .get_multiplexed_async_connection()
             .await?
-            .get(&key)
+            .get(key)
             .await?;
         match data {
             Some(json) => {
@@ -122,98 +808,2289 @@ impl AppState {
     }
 
     pub async fn list_user_sessions(&self, user_id: &str) -> Result<Vec<String>, ApiError> {
-        let pattern = format!("user:{}:session:*", user_id);
-        let mut conn = self.connection_redis().await?;
-        let mut cursor = 0u64;
-        let mut sessions = Vec::new();
-
-        loop {
-            let (new_cursor, keys): (u64, Vec<String>) = redis::cmd("SCAN")
-                .arg(cursor)
-                .arg("MATCH")
-                .arg(&pattern)
-                .arg("COUNT")
-                .arg(100)
+        let entries = self.list_user_session_entries(user_id).await?;
+        Ok(entries.into_iter().map(|entry| entry.session_id).collect())
+    }
+
+    /// 사용자 세션 인덱스를 따라 세션 데이터, 남은 TTL, 뷰어 수를 함께 조회합니다.
+    ///
+    /// 만료되어 사라진 세션은 결과에서 빠지고 인덱스에서도 정리됩니다.
+    pub async fn list_user_session_entries(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<UserSessionEntry>, ApiError> {
+        self.list_indexed_session_entries(&self.keys().user_session_index(user_id), |session_id| {
+            self.keys().user_session(user_id, session_id)
+        })
+        .await
+    }
+
+    async fn list_indexed_session_entries(
+        &self,
+        index_key: &str,
+        session_key: impl Fn(&str) -> String,
+    ) -> Result<Vec<UserSessionEntry>, ApiError> {
+        let mut conn = self.connection_redis().await?;
+
+        let session_ids = conn.zrange(index_key, 0, -1).await?;
+        if session_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let keys: Vec<String> = session_ids
+            .iter()
+            .map(|session_id| session_key(session_id))
+            .collect();
+        let loaded = load_session_entries(&mut conn, &keys).await?;
+
+        let mut entries = Vec::with_capacity(session_ids.len());
+        let mut expired = Vec::new();
+        for (session_id, loaded) in session_ids.into_iter().zip(loaded) {
+            match loaded {
+                Some((session, ttl_seconds, viewers)) => entries.push(UserSessionEntry {
+                    session_id,
+                    session,
+                    ttl_seconds,
+                    viewers,
+                }),
+                None => expired.push(session_id),
+            }
+        }
+
+        if !expired.is_empty() {
+            tracing::debug!(
+                "Pruning {} expired sessions from index {}",
+                expired.len(),
+                index_key
+            );
+            conn.zrem(index_key, &expired).await?;
+        }
+
+        Ok(entries)
+    }
+
+    /// 사용자 세션 인덱스에 있는 세션 수 (만료되었지만 아직 정리되지 않은 세션 포함).
+    pub async fn count_user_sessions(&self, user_id: &str) -> Result<usize, ApiError> {
+        let mut conn = self.connection_redis().await?;
+        Ok(conn.zcard(self.keys().user_session_index(user_id)).await?)
+    }
+
+    /// 사용자 세션 인덱스에서 생성 시각 순으로 한 페이지를 읽습니다.
+    ///
+    /// [`Self::page_indexed_session_entries`] 참고.
+    pub async fn page_user_session_entries<T>(
+        &self,
+        user_id: &str,
+        page: IndexPage<'_>,
+        select: impl FnMut(UserSessionEntry) -> Option<T>,
+    ) -> Result<Vec<(i64, T)>, ApiError> {
+        self.page_indexed_session_entries(
+            &self.keys().user_session_index(user_id),
+            |session_id| self.keys().user_session(user_id, session_id),
+            page,
+            select,
+        )
+        .await
+    }
+
+    /// 세션 인덱스를 점수(생성 시각 ms) 순으로 따라가며 `page.after` 다음에 오는 세션을
+    /// `select`가 고른 것만 최대 `page.count`개 읽어 점수와 함께 반환합니다.
+    ///
+    /// 만료되어 사라진 세션은 인덱스에서 정리합니다. [`Self::page_index`] 참고.
+    async fn page_indexed_session_entries<T>(
+        &self,
+        index_key: &str,
+        session_key: impl Fn(&str) -> String,
+        page: IndexPage<'_>,
+        mut select: impl FnMut(UserSessionEntry) -> Option<T>,
+    ) -> Result<Vec<(i64, T)>, ApiError> {
+        let items = self
+            .page_index(
+                index_key,
+                |session_id| Some(session_key(session_id)),
+                page,
+                |session_id, (session, ttl_seconds, viewers)| {
+                    select(UserSessionEntry {
+                        session_id: session_id.to_string(),
+                        session,
+                        ttl_seconds,
+                        viewers,
+                    })
+                },
+            )
+            .await?;
+        Ok(items
+            .into_iter()
+            .map(|(score, _, item)| (score, item))
+            .collect())
+    }
+
+    /// 점수 순으로 정렬된 인덱스를 따라가며 `page.after` 다음에 오는 멤버의 세션을
+    /// `select`가 고른 것만 최대 `page.count`개 읽어 (점수, 멤버, 항목)으로 반환합니다.
+    ///
+    /// 인덱스는 `ZRANGEBYSCORE ... LIMIT`으로 `page.count`개씩 읽고 그 구간의 세션만 조회하므로,
+    /// 비용은 전체 세션 수가 아니라 페이지를 채우기까지 지나간 세션 수에 비례합니다.
+    /// 점수가 같은 멤버는 멤버 문자열 순서로 이어집니다. `session_key`가 세션 키를 만들지 못한
+    /// 멤버와 사라진 세션은 인덱스에서 정리합니다.
+    async fn page_index<T>(
+        &self,
+        index_key: &str,
+        session_key: impl Fn(&str) -> Option<String>,
+        page: IndexPage<'_>,
+        mut select: impl FnMut(&str, (SessionData, Option<i64>, u64)) -> Option<T>,
+    ) -> Result<Vec<(i64, String, T)>, ApiError> {
+        let mut conn = self.connection_redis().await?;
+        // 커서와 점수가 같은 멤버도 문자열이 뒤면 이어지므로 커서의 점수부터 읽습니다.
+        let from = page.after.map(|cursor| cursor.key.to_string());
+        let (command, start, end) = if page.descending {
+            (
+                "ZREVRANGEBYSCORE",
+                from.unwrap_or_else(|| "+inf".into()),
+                "-inf",
+            )
+        } else {
+            (
+                "ZRANGEBYSCORE",
+                from.unwrap_or_else(|| "-inf".into()),
+                "+inf",
+            )
+        };
+
+        let mut items = Vec::with_capacity(page.count);
+        let mut stale = Vec::new();
+        let mut offset = 0;
+        while items.len() < page.count {
+            let batch: Vec<(String, f64)> = redis::cmd(command)
+                .arg(index_key)
+                .arg(&start)
+                .arg(end)
+                .arg("WITHSCORES")
+                .arg("LIMIT")
+                .arg(offset)
+                .arg(page.count)
                 .query_async(&mut conn)
-                .await
-                .map_err(|e| ApiError::RedisError(e.to_string()))?;
+                .await?;
+            let fetched = batch.len();
+            offset += fetched;
 
-            for key in keys {
-                let prefix = format!("user:{}:session:", user_id);
-                if let Some(session_id) = key.strip_prefix(&prefix) {
-                    sessions.push(session_id.to_string());
+            let mut batch_keys = Vec::with_capacity(fetched);
+            let mut batch_members = Vec::with_capacity(fetched);
+            for (member, score) in batch {
+                let score = score as i64;
+                if page
+                    .after
+                    .is_some_and(|cursor| !cursor.follows(score, &member, page.descending))
+                {
+                    continue;
+                }
+                match session_key(&member) {
+                    Some(key) => {
+                        batch_keys.push(key);
+                        batch_members.push((member, score));
+                    }
+                    None => stale.push(member),
+                }
+            }
+            if !batch_keys.is_empty() {
+                let loaded = load_session_entries(&mut conn, &batch_keys).await?;
+                for ((member, score), loaded) in batch_members.into_iter().zip(loaded) {
+                    let Some(loaded) = loaded else {
+                        stale.push(member);
+                        continue;
+                    };
+                    if items.len() == page.count {
+                        continue;
+                    }
+                    if let Some(item) = select(&member, loaded) {
+                        items.push((score, member, item));
+                    }
                 }
             }
 
-            cursor = new_cursor;
-            if cursor == 0 {
+            if fetched < page.count {
                 break;
             }
         }
 
-        Ok(sessions)
+        // 읽는 도중에 지우면 LIMIT 오프셋이 어긋나므로 다 읽은 뒤에 정리합니다.
+        if !stale.is_empty() {
+            tracing::debug!(
+                "Pruning {} stale entries from index {}",
+                stale.len(),
+                index_key
+            );
+            conn.zrem(index_key, &stale).await?;
+        }
+
+        Ok(items)
+    }
+
+    /// 공개 디렉터리를 세션 생성 시각 순으로 따라가며 `page.after` 다음에 오는 세션을
+    /// `select`가 고른 것만 최대 `page.count`개 읽어 (점수, 인덱스 멤버, 항목)으로 반환합니다.
+    ///
+    /// 점수가 같은 세션끼리는 멤버 순서로 이어지므로 커서 ID로 멤버를 씁니다.
+    /// 등록이 해제된 세션은 건너뜁니다. [`Self::page_index`] 참고.
+    pub async fn page_directory_entries<T>(
+        &self,
+        page: IndexPage<'_>,
+        mut select: impl FnMut(DirectorySessionEntry) -> Option<T>,
+    ) -> Result<Vec<(i64, String, T)>, ApiError> {
+        let keys = self.keys();
+        self.page_index(
+            &keys.directory(),
+            |member| {
+                serde_json::from_str::<SessionRef>(member)
+                    .ok()
+                    .map(|session_ref| session_ref_key(keys, &session_ref))
+            },
+            page,
+            |member, (session, _, viewers)| {
+                if !session.listed {
+                    return None;
+                }
+                let session_ref = serde_json::from_str::<SessionRef>(member).ok()?;
+                select(DirectorySessionEntry {
+                    session_ref,
+                    session,
+                    viewers,
+                })
+            },
+        )
+        .await
+    }
+
+    /// 공개 디렉터리에 최근 등록된 세션을 최대 `limit`개까지 뷰어 수와 함께 조회합니다.
+    ///
+    /// 만료되었거나 등록이 해제된 세션은 결과에서 빠지고 인덱스에서도 정리됩니다.
+    pub async fn list_directory_entries(
+        &self,
+        limit: usize,
+    ) -> Result<Vec<DirectorySessionEntry>, ApiError> {
+        let mut conn = self.connection_redis().await?;
+
+        let members = conn
+            .zrevrange(self.keys().directory(), 0, limit as isize - 1)
+            .await?;
+        if members.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut refs = Vec::with_capacity(members.len());
+        let mut stale = Vec::new();
+        for member in members {
+            match serde_json::from_str::<SessionRef>(&member) {
+                Ok(session_ref) => refs.push((member, session_ref)),
+                Err(_) => stale.push(member),
+            }
+        }
+
+        let mut entries = Vec::with_capacity(refs.len());
+        if !refs.is_empty() {
+            let keys: Vec<String> = refs
+                .iter()
+                .map(|(_, session_ref)| session_ref_key(self.keys(), session_ref))
+                .collect();
+            let values: Vec<Option<String>> =
+                redis::cmd("MGET").arg(&keys).query_async(&mut conn).await?;
+            let viewers: Vec<(String, u64)> = redis::cmd("PUBSUB")
+                .arg("NUMSUB")
+                .arg(&keys)
+                .query_async(&mut conn)
+                .await?;
+
+            for (((member, session_ref), value), (_, viewers)) in
+                refs.into_iter().zip(values).zip(viewers)
+            {
+                let session =
+                    value.and_then(|json| serde_json::from_str::<SessionData>(&json).ok());
+                match session {
+                    Some(session) if session.listed => entries.push(DirectorySessionEntry {
+                        session_ref,
+                        session,
+                        viewers,
+                    }),
+                    _ => stale.push(member),
+                }
+            }
+        }
+
+        if !stale.is_empty() {
+            tracing::debug!("Pruning {} stale entries from directory", stale.len());
+            conn.zrem(self.keys().directory(), &stale).await?;
+        }
+
+        Ok(entries)
     }
 
-    pub async fn create_user_data(
+    /// 새 계정을 만듭니다. 계정은 만료되지 않습니다.
+    ///
+    /// 같은 ID로 삭제된 계정이 있었으면 그 계정의 토큰이 통하지 않도록 다음 토큰 세대부터 시작합니다.
+    /// 같은 ID가 이미 있으면 `ApiError::UserExists`를 반환합니다.
+    pub async fn register_user(
         &self,
         user_id: impl AsRef<str>,
         password: impl Into<String>,
-        ttl_seconds: u64,
     ) -> Result<UserData, ApiError> {
-        let share = self.share();
-        let salt = share.get_salt(self.redis_client()).await?;
-        let user_data = UserData::create(self.share.argon2(), salt, password.into())?;
-        let user_key = format!("user:{}:data", user_id.as_ref());
-        let user_json = serde_json::to_string(&user_data)?;
+        let mut user_data = UserData::create(self.share.argon2(), password.into())?;
+        let user_key = self.keys().user_data(user_id.as_ref());
         let mut conn = self.connection_redis().await?;
+        user_data.token_generation = self.token_epoch(&mut conn, user_id.as_ref()).await?;
+        let user_json = serde_json::to_string(&user_data)?;
 
-        redis::cmd("SET")
-            .arg(user_key.as_str())
-            .arg(user_json.as_str())
-            .arg("NX")
-            .arg("EX")
-            .arg(ttl_seconds)
-            .query_async::<()>(&mut conn)
+        if !conn.set_nx(&user_key, user_json.as_str()).await? {
+            return Err(ApiError::UserExists(user_id.as_ref().to_string()));
+        }
+        Ok(user_data)
+    }
+
+    /// 같은 ID로 새로 만드는 계정의 첫 토큰 세대. 삭제된 적이 없으면 0입니다.
+    async fn token_epoch(
+        &self,
+        conn: &mut redis::aio::MultiplexedConnection,
+        user_id: &str,
+    ) -> Result<u64, ApiError> {
+        let epoch = conn.get(self.keys().user_token_epoch(user_id)).await?;
+        Ok(epoch.and_then(|epoch| epoch.parse().ok()).unwrap_or(0))
+    }
+
+    /// SSO 로그인을 시작하고 `state`와 브라우저를 보낼 제공자의 인가 URL을 돌려줍니다.
+    ///
+    /// `cookie`이면 콜백에서 토큰을 fragment 대신 로그인 쿠키로 전달합니다.
+    /// `state`는 로그인을 시작한 브라우저에 쿠키로 남겨 콜백에서 확인해야 합니다
+    /// ([`crate::auth_cookie::verify_oidc_state`]).
+    pub async fn begin_oidc_login(&self, cookie: bool) -> Result<OidcLoginStart, ApiError> {
+        let provider = self.oidc().ok_or(ApiError::OidcNotConfigured)?;
+        let (state, mut login) = OidcLoginState::generate();
+        login.cookie = cookie;
+        let url = provider.authorization_url(&state, &login).await?;
+
+        let mut conn = self.connection_redis().await?;
+        let _: () = conn
+            .set_ex(
+                self.keys().oidc_login(&state),
+                serde_json::to_string(&login)?,
+                oidc::LOGIN_STATE_TTL_SECONDS,
+            )
             .await?;
-        self.get_user_data(user_id.as_ref())
-            .await?
-            .ok_or_else(|| ApiError::Unexpected("Failed to create user data".to_string()))
+        Ok(OidcLoginStart { state, url })
     }
 
-    pub async fn verify_user_password(
+    /// SSO 콜백의 code를 교환하고 ID 토큰의 계정으로 로그인합니다.
+    ///
+    /// `state`는 `GETDEL`로 꺼내므로 같은 콜백은 한 번만 쓸 수 있습니다. 처음 보는
+    /// 사용자면 비밀번호 없는 계정을 만들고, 이미 있는 계정은 같은 OIDC 계정에
+    /// 연결되어 있을 때만 로그인합니다. 비밀번호로 가입한 계정과는 자동으로 연결하지 않습니다.
+    pub async fn complete_oidc_login(
         &self,
-        user_data: &UserData,
-        password: impl AsRef<str>,
-    ) -> Result<bool, ApiError> {
-        let share = self.share();
-        let password_hash = PasswordHash::new(&user_data.password_argon2)?;
-        let provided_password = password.as_ref().as_bytes();
-        match share
-            .argon2()
-            .verify_password(provided_password, &password_hash)
-        {
-            Ok(_) => Ok(true),
-            Err(_) => Ok(false),
+        code: &str,
+        state: &str,
+    ) -> Result<OidcLogin, ApiError> {
+        let provider = self.oidc().ok_or(ApiError::OidcNotConfigured)?;
+        let mut conn = self.connection_redis().await?;
+        let login: OidcLoginState = match conn.get_del(self.keys().oidc_login(state)).await? {
+            Some(json) => serde_json::from_str(&json)?,
+            None => {
+                return Err(ApiError::OidcLoginFailed(
+                    "unknown or expired state".to_string(),
+                ))
+            }
+        };
+
+        let identity = provider.exchange_code(code, &login).await?;
+        let user_id = identity.user_id;
+
+        let mut user_data = match self.get_user_data(&user_id).await? {
+            Some(user_data) if user_data.oidc_subject.as_deref() == Some(&identity.subject) => {
+                user_data
+            }
+            Some(_) => {
+                tracing::warn!(
+                    "SSO login for {} does not match the linked account {}",
+                    identity.subject,
+                    user_id
+                );
+                return Err(ApiError::UserExists(user_id));
+            }
+            None => {
+                crate::user_data::validate_username(&user_id)?;
+                let mut user_data = UserData::create_external(identity.subject);
+                user_data.token_generation = self.token_epoch(&mut conn, &user_id).await?;
+                if !conn
+                    .set_nx(
+                        self.keys().user_data(&user_id),
+                        serde_json::to_string(&user_data)?,
+                    )
+                    .await?
+                {
+                    return Err(ApiError::UserExists(user_id));
+                }
+                tracing::info!("Created account {} from SSO login", user_id);
+                user_data
+            }
+        };
+
+        if user_data.is_disabled() {
+            return Err(ApiError::AccountDisabled(user_id));
         }
+        self.record_user_login(&user_id, &mut user_data).await?;
+        Ok(OidcLogin {
+            user_id,
+            user_data,
+            cookie: login.cookie,
+        })
     }
 
-    pub async fn get_user_data(
+    /// 마지막 로그인 시각을 기록합니다.
+    ///
+    /// `last_login_at`만 바꾸므로 비밀번호 검증 중에 바뀐 비밀번호, 토큰 세대, 비활성화를
+    /// 되돌리지 않습니다. 예전 방식으로 만들어진 계정의 TTL도 함께 제거되어 영구 계정이 됩니다.
+    pub async fn record_user_login(
         &self,
         user_id: impl AsRef<str>,
-    ) -> Result<Option<UserData>, ApiError> {
-        let user_key = format!("user:{}:data", user_id.as_ref());
-        let data: Option<String> = self
-            .redis_client
-            .get_multiplexed_async_connection()
-            .await?
-            .get(&user_key)
-            .await?;
-        match data {
-            Some(json) => {
-                let user_data = serde_json::from_str::<UserData>(&json)?;
-                Ok(Some(user_data))
+        user_data: &mut UserData,
+    ) -> Result<(), ApiError> {
+        let now = chrono::Utc::now();
+        self.update_user_data(
+            user_id.as_ref(),
+            serde_json::json!({ "last_login_at": now }),
+            serde_json::json!({}),
+            false,
+        )
+        .await?;
+        user_data.last_login_at = Some(now);
+        Ok(())
+    }
+
+    /// 비밀번호를 확인하기 전에 잠금과 시도 횟수 제한을 확인합니다.
+    ///
+    /// # Errors
+    /// 잠겨 있거나 횟수를 넘었으면 `ApiError::RateLimited` (429).
+    pub async fn check_login_attempt(
+        &self,
+        user_id: &str,
+        ip: Option<IpAddr>,
+    ) -> Result<(), ApiError> {
+        let mut conn = self.connection_redis().await?;
+        rate_limit::check_login(
+            &mut conn,
+            self.keys(),
+            self.share.rate_limit_policy(),
+            user_id,
+            ip,
+        )
+        .await
+    }
+
+    /// 가입 시도를 IP별 시도 횟수에 기록합니다 ([`rate_limit::check_registration`]).
+    pub async fn check_registration_attempt(&self, ip: Option<IpAddr>) -> Result<(), ApiError> {
+        let mut conn = self.connection_redis().await?;
+        rate_limit::check_registration(&mut conn, self.keys(), self.share.rate_limit_policy(), ip)
+            .await
+    }
+
+    /// 로그인 실패를 기록합니다. 이번 실패로 잠겼으면 잠금 시간(초)을 돌려줍니다.
+    pub async fn record_login_failure(&self, user_id: &str) -> Result<Option<u64>, ApiError> {
+        let mut conn = self.connection_redis().await?;
+        rate_limit::record_login_failure(
+            &mut conn,
+            self.keys(),
+            self.share.rate_limit_policy(),
+            user_id,
+        )
+        .await
+    }
+
+    /// 로그인에 성공했으므로 연속 실패 횟수를 지웁니다.
+    pub async fn record_login_success(&self, user_id: &str) -> Result<(), ApiError> {
+        let mut conn = self.connection_redis().await?;
+        rate_limit::record_login_success(&mut conn, self.keys(), user_id).await
+    }
+
+    /// 누적된 로그인 거절 횟수.
+    pub async fn login_rejection_stats(&self) -> Result<LoginRejectionStats, ApiError> {
+        let mut conn = self.connection_redis().await?;
+        rate_limit::login_rejection_stats(&mut conn, self.keys()).await
+    }
+
+    /// 새 세션을 만들어도 소유자의 세션 수 제한을 넘지 않는지 확인합니다.
+    ///
+    /// 이미 있는 세션과 공용 세션은 세지 않습니다.
+    ///
+    /// # Errors
+    /// 넘으면 `ApiError::QuotaExceeded` (429).
+    pub async fn check_session_quota(&self, session_ref: &SessionRef) -> Result<(), ApiError> {
+        let limit = self.share.rate_limit_policy().max_sessions_per_user as usize;
+        let index_key = match (&session_ref.user_id, &session_ref.org_id) {
+            (Some(user_id), _) => self.keys().user_session_index(user_id),
+            (None, Some(org_id)) => self.keys().org_session_index(org_id),
+            (None, None) => return Ok(()),
+        };
+        if limit == 0 {
+            return Ok(());
+        }
+
+        let mut conn = self.connection_redis().await?;
+        if conn
+            .zscore(&index_key, &session_ref.session_id)
+            .await?
+            .is_some()
+        {
+            return Ok(());
+        }
+        if conn.zcard(&index_key).await? < limit {
+            return Ok(());
+        }
+        // 인덱스에는 만료된 세션이 남아 있을 수 있으므로 정리한 뒤 다시 셉니다.
+        let sessions = match (&session_ref.user_id, &session_ref.org_id) {
+            (Some(user_id), _) => self.list_user_session_entries(user_id).await?,
+            (None, Some(org_id)) => self.list_org_session_entries(org_id).await?,
+            (None, None) => return Ok(()),
+        };
+        if sessions.len() < limit {
+            return Ok(());
+        }
+        tracing::warn!(
+            "Session quota exceeded: {:?} already has {} sessions",
+            session_ref,
+            sessions.len()
+        );
+        Err(QuotaExceeded::new(Quota::Sessions, limit as u64).into())
+    }
+
+    /// 세션 업데이트 빈도 제한을 확인하고 이번 업데이트를 기록합니다.
+    ///
+    /// # Errors
+    /// 넘으면 `ApiError::QuotaExceeded` (429, `Retry-After`).
+    pub async fn check_session_update_rate(
+        &self,
+        session_ref: &SessionRef,
+    ) -> Result<(), ApiError> {
+        let mut conn = self.connection_redis().await?;
+        rate_limit::check_session_update(
+            &mut conn,
+            self.keys(),
+            self.share.rate_limit_policy(),
+            session_ref,
+        )
+        .await
+    }
+
+    /// `ip`가 스트림을 하나 더 열 수 있으면 자리를 잡습니다.
+    ///
+    /// IP를 알 수 없거나 제한이 없으면 `None`입니다.
+    ///
+    /// # Errors
+    /// 자리가 없으면 `ApiError::QuotaExceeded` (429).
+    pub async fn acquire_stream_slot(
+        &self,
+        ip: Option<IpAddr>,
+    ) -> Result<Option<StreamSlot>, ApiError> {
+        let policy = self.share.rate_limit_policy();
+        match ip {
+            Some(ip) if policy.max_streams_per_ip > 0 => Ok(Some(
+                StreamSlot::acquire(&self.redis_client, self.keys(), policy, ip).await?,
+            )),
+            _ => Ok(None),
+        }
+    }
+
+    /// 사용자의 세션 수와 세션 크기를 할당량과 함께 조회합니다.
+    pub async fn user_usage(&self, user_id: &str) -> Result<UserUsage, ApiError> {
+        let sessions: Vec<SessionData> = self
+            .list_user_session_entries(user_id)
+            .await?
+            .into_iter()
+            .map(|entry| entry.session)
+            .collect();
+        Ok(UserUsage::collect(
+            self.share.rate_limit_policy(),
+            &sessions,
+        ))
+    }
+
+    /// 비밀번호를 바꾸고 토큰 세대를 올려 기존 토큰을 모두 무효로 만듭니다. refresh 토큰 family도 지웁니다.
+    ///
+    /// `user_data`는 현재 비밀번호를 검증한 계정 데이터이며, 성공하면 저장된 최신 값으로 바뀝니다.
+    /// 검증한 뒤에 다른 요청이 비밀번호를 먼저 바꿨으면 `ApiError::Forbidden`을 반환합니다.
+    pub async fn change_user_password(
+        &self,
+        user_id: impl AsRef<str>,
+        user_data: &mut UserData,
+        new_password: impl AsRef<str>,
+    ) -> Result<(), ApiError> {
+        let user_id = user_id.as_ref();
+        let password_argon2 = hash_password(self.share.argon2(), new_password)?;
+        let updated = self
+            .update_user_data(
+                user_id,
+                serde_json::json!({ "password_argon2": password_argon2 }),
+                serde_json::json!({ "password_argon2": user_data.password_argon2 }),
+                true,
+            )
+            .await?;
+        match updated {
+            Some((true, stored)) => *user_data = stored,
+            Some((false, _)) => {
+                return Err(ApiError::Forbidden(
+                    "현재 비밀번호가 올바르지 않습니다".to_string(),
+                ))
+            }
+            None => return Err(ApiError::UserNotFound(user_id.to_string())),
+        }
+        self.revoke_refresh_families(user_id).await
+    }
+
+    /// 계정 데이터 중 `set`의 필드만 원자적으로 바꾸고, `bump_token_generation`이면 토큰 세대를 올립니다.
+    ///
+    /// `only_if`의 필드가 저장된 값과 모두 같을 때만 바꿉니다. 계정이 없으면 `None`,
+    /// 있으면 (바꿨는지, 저장된 계정 데이터)를 반환합니다. TTL은 남기지 않습니다.
+    async fn update_user_data(
+        &self,
+        user_id: &str,
+        set: serde_json::Value,
+        only_if: serde_json::Value,
+        bump_token_generation: bool,
+    ) -> Result<Option<(bool, UserData)>, ApiError> {
+        let mut conn = self.connection_redis().await?;
+        let updated: Option<(bool, String)> = redis::Script::new(UPDATE_USER_DATA_SCRIPT)
+            .key(self.keys().user_data(user_id))
+            .arg(set.to_string())
+            .arg(only_if.to_string())
+            .arg(if bump_token_generation { "1" } else { "0" })
+            .invoke_async(&mut conn)
+            .await?;
+        updated
+            .map(|(applied, json)| Ok((applied, serde_json::from_str(&json)?)))
+            .transpose()
+    }
+
+    /// 계정과 그 사용자의 모든 세션을 삭제합니다.
+    ///
+    /// 인덱스에 없는 예전 세션도 `user:{id}:session:*` 를 SCAN 해서 함께 지우고,
+    /// 각 세션 채널에 [`STREAM_CLOSE_MESSAGE`]를 발행해 열려 있는 스트림을 끝냅니다.
+    /// 발급된 토큰이 같은 ID로 다시 가입한 계정에 통하지 않도록 다음 토큰 세대를 남기고,
+    /// refresh 토큰 family와 API 키, 공유 링크도 함께 지웁니다.
+    /// 다른 사용자의 세션에 협업자로 추가되어 있었으면 그 목록에서도 빠집니다.
+    /// 속한 조직에서도 빠지며, 혼자 남아 있던 조직은 조직 세션과 함께 지웁니다.
+    /// 삭제한 세션 수를 반환합니다.
+    ///
+    /// 다른 멤버가 있는 조직의 마지막 소유자이면 아무것도 지우지 않고 `ApiError::InvalidOrg`를
+    /// 반환합니다.
+    pub async fn delete_user(&self, user_id: &str) -> Result<usize, ApiError> {
+        let orgs = self.list_user_orgs(user_id).await?;
+        if let Some(entry) = orgs
+            .iter()
+            .find(|entry| entry.org.is_last_owner(user_id) && entry.org.members.len() > 1)
+        {
+            return Err(ApiError::InvalidOrg(format!(
+                "조직 {}의 마지막 소유자입니다. 다른 멤버를 소유자로 지정한 뒤 탈퇴하세요",
+                entry.org_id
+            )));
+        }
+        for entry in &orgs {
+            if entry.org.members.len() == 1 {
+                self.delete_org(&entry.org_id).await?;
+            } else {
+                self.remove_org_member(&entry.org_id, user_id).await?;
+            }
+        }
+
+        let mut conn = self.connection_redis().await?;
+        let index_key = self.keys().user_session_index(user_id);
+        let session_prefix = self.keys().user_session(user_id, "");
+
+        let api_keys_key = self.keys().user_api_keys(user_id);
+        let api_key_hashes: Vec<String> = conn.hvals(&api_keys_key).await?;
+        let share_links_key = self.keys().user_share_links(user_id);
+        let share_link_ids: Vec<String> = conn.hkeys(&share_links_key).await?;
+
+        // 같은 ID로 다시 가입한 사용자가 권한을 물려받지 않도록 다른 사용자 세션의
+        // 협업자 목록에서 빠집니다.
+        let shared_key = self.keys().user_shared_sessions(user_id);
+        let shared_fields: Vec<String> = conn.hkeys(&shared_key).await?;
+        for (owner, session_id) in shared_fields
+            .iter()
+            .filter_map(|field| field.split_once('/'))
+        {
+            self.remove_session_collaborator(owner, session_id, user_id)
+                .await?;
+        }
+
+        self.revoke_refresh_families(user_id).await?;
+        let session_ids = collect_session_ids(&mut conn, &index_key, &session_prefix).await?;
+        // 지울 세션의 협업자들에게서 공유받은 세션 항목도 함께 지웁니다.
+        let owned_keys: Vec<String> = session_ids
+            .iter()
+            .map(|session_id| self.keys().user_session(user_id, session_id))
+            .collect();
+        let collaborators: Vec<Vec<String>> = load_sessions(&mut conn, &owned_keys)
+            .await?
+            .into_iter()
+            .map(|session| {
+                session
+                    .map(|session| session.collaborators.into_keys().collect())
+                    .unwrap_or_default()
+            })
+            .collect();
+        let next_generation = match self.get_user_data(user_id).await? {
+            Some(user_data) => user_data.token_generation + 1,
+            None => self.token_epoch(&mut conn, user_id).await?,
+        };
+
+        let mut pipe = redis::pipe();
+        pipe.atomic()
+            .del(self.keys().user_data(user_id))
+            .ignore()
+            .set(self.keys().user_token_epoch(user_id), next_generation)
+            .ignore()
+            .del(&index_key)
+            .ignore()
+            .del(&api_keys_key)
+            .ignore()
+            .del(&share_links_key)
+            .ignore()
+            .del(&shared_key)
+            .ignore()
+            .del(self.keys().user_orgs(user_id))
+            .ignore()
+            .del(self.keys().user_incoming_transfers(user_id))
+            .ignore();
+        for key_hash in &api_key_hashes {
+            pipe.del(self.keys().api_key(key_hash)).ignore();
+        }
+        for link_id in &share_link_ids {
+            pipe.del(self.keys().share_link(link_id)).ignore();
+        }
+        for (session_id, collaborators) in session_ids.iter().zip(&collaborators) {
+            let member = serde_json::to_string(&SessionRef::user(user_id, session_id))?;
+            pipe.del(self.keys().user_session(user_id, session_id))
+                .ignore()
+                .zrem(self.keys().directory(), member)
+                .ignore();
+            for collaborator in collaborators {
+                pipe.hdel(
+                    self.keys().user_shared_sessions(collaborator),
+                    shared_session_field(user_id, session_id),
+                )
+                .ignore();
+            }
+        }
+        pipe.query_async::<()>(&mut conn).await?;
+
+        for session_id in &session_ids {
+            conn.publish(
+                self.keys().user_session(user_id, session_id),
+                STREAM_CLOSE_MESSAGE,
+            )
+            .await?;
+        }
+
+        Ok(session_ids.len())
+    }
+
+    pub async fn verify_user_password(
+        &self,
+        user_data: &UserData,
+        password: impl AsRef<str>,
+    ) -> Result<bool, ApiError> {
+        if !user_data.has_password() {
+            // 비밀번호 없는 계정(SSO)도 검증 시간으로 구분되지 않게 합니다.
+            self.verify_dummy_password(password);
+            return Ok(false);
+        }
+        let share = self.share();
+        let password_hash = PasswordHash::new(&user_data.password_argon2)?;
+        let provided_password = password.as_ref().as_bytes();
+        match share
+            .argon2()
+            .verify_password(provided_password, &password_hash)
+        {
+            Ok(_) => Ok(true),
+            Err(_) => Ok(false),
+        }
+    }
+
+    /// 고정된 비밀번호 해시로 Argon2 검증만 하고 결과는 버립니다.
+    ///
+    /// 없는 사용자의 로그인도 틀린 비밀번호와 같은 시간이 걸리게 해, 응답 시간으로
+    /// 계정이 있는지 알 수 없게 합니다.
+    pub fn verify_dummy_password(&self, password: impl AsRef<str>) {
+        let share = self.share();
+        if let Ok(password_hash) = PasswordHash::new(share.dummy_password_hash()) {
+            let _ = share
+                .argon2()
+                .verify_password(password.as_ref().as_bytes(), &password_hash);
+        }
+    }
+
+    /// 저장된 해시가 현재 Argon2 정책보다 약하거나 예전 전역 salt를 쓰면 새 salt와
+    /// 현재 정책으로 다시 해시해 저장합니다.
+    ///
+    /// 비밀번호 검증이 끝난 직후에만 호출해야 합니다. 검증한 해시가 그대로 저장되어 있을 때만
+    /// 바꾸므로, 그사이 비밀번호가 바뀌었으면 새 비밀번호를 덮어쓰지 않고 `false`를 반환합니다.
+    pub async fn rehash_password_if_needed(
+        &self,
+        user_id: &str,
+        user_data: &mut UserData,
+        password: impl AsRef<str>,
+    ) -> Result<bool, ApiError> {
+        let share = self.share();
+        let legacy_salt = share.get_legacy_salt(self.redis_client()).await?;
+        if !user_data.needs_rehash(share.argon2().params(), legacy_salt) {
+            return Ok(false);
+        }
+        let password_argon2 = hash_password(share.argon2(), password)?;
+        let updated = self
+            .update_user_data(
+                user_id,
+                serde_json::json!({ "password_argon2": password_argon2 }),
+                serde_json::json!({ "password_argon2": user_data.password_argon2 }),
+                false,
+            )
+            .await?;
+        if !matches!(updated, Some((true, _))) {
+            return Ok(false);
+        }
+        user_data.password_argon2 = password_argon2;
+        Ok(true)
+    }
+
+    pub async fn get_user_data(
+        &self,
+        user_id: impl AsRef<str>,
+    ) -> Result<Option<UserData>, ApiError> {
+        let user_key = self.keys().user_data(user_id.as_ref());
+        let data: Option<String> = self
+            .redis_client
+            .get_multiplexed_async_connection()
+            .await?
+            .get(&user_key)
+            .await?;
+        match data {
+            Some(json) => {
+                let user_data = serde_json::from_str::<UserData>(&json)?;
+                Ok(Some(user_data))
             }
             None => Ok(None),
         }
     }
+
+    /// 로그인 토큰의 `orgs`와 `admin` claim에 넣을 현재 값.
+    pub async fn account_claims(
+        &self,
+        user_id: &str,
+        user_data: &UserData,
+    ) -> Result<AccountClaims, ApiError> {
+        Ok(AccountClaims {
+            orgs: self.org_memberships(user_id).await?,
+            admin: user_data.admin,
+        })
+    }
+
+    /// 새 refresh 토큰 family를 시작하고 첫 토큰을 반환합니다. 로그인할 때 사용합니다.
+    pub async fn issue_refresh_token(
+        &self,
+        user_id: impl AsRef<str>,
+        user_data: &UserData,
+    ) -> Result<String, ApiError> {
+        let record = RefreshTokenRecord {
+            user_id: user_id.as_ref().to_string(),
+            family: refresh_token::new_family(),
+            token_generation: user_data.token_generation,
+        };
+        self.store_refresh_token(&record).await
+    }
+
+    /// refresh 토큰을 같은 family의 새 토큰으로 교체합니다.
+    ///
+    /// family 키를 `GETDEL`로 꺼내므로 같은 토큰으로는 한 번만 교체할 수 있습니다.
+    /// 이미 교체된 토큰이 다시 들어오면 family가 지워진 채로 남아 그 family의 모든
+    /// 토큰이 폐기됩니다. 비밀번호 변경이나 계정 삭제 때는 family가 지워지고 토큰 세대도
+    /// 달라지므로 교체되지 않습니다.
+    pub async fn rotate_refresh_token(&self, token: &str) -> Result<RotatedRefreshToken, ApiError> {
+        let invalid = || ApiError::Unauthorized("Invalid refresh token".to_string());
+        let token_hash = refresh_token::hash(token);
+        let mut conn = self.connection_redis().await?;
+
+        let record: RefreshTokenRecord =
+            match conn.get(self.keys().refresh_token(&token_hash)).await? {
+                Some(json) => serde_json::from_str(&json)?,
+                None => return Err(invalid()),
+            };
+
+        let current = conn
+            .get_del(self.keys().refresh_token_family(&record.family))
+            .await?;
+        if current.as_deref() != Some(token_hash.as_str()) {
+            if current.is_some() {
+                tracing::warn!(
+                    "Refresh token reuse detected for user {}, revoked family {}",
+                    record.user_id,
+                    record.family
+                );
+            }
+            return Err(invalid());
+        }
+
+        let user_data = self
+            .get_user_data(&record.user_id)
+            .await?
+            .ok_or_else(|| ApiError::Unauthorized("Account no longer exists".to_string()))?;
+        if user_data.token_generation != record.token_generation {
+            return Err(ApiError::Unauthorized("Token has been revoked".to_string()));
+        }
+
+        let refresh_token = self.store_refresh_token(&record).await?;
+        Ok(RotatedRefreshToken {
+            user_id: record.user_id,
+            refresh_token,
+            user_data,
+        })
+    }
+
+    /// refresh 토큰의 family를 폐기합니다. 다른 사용자의 토큰이면 아무것도 하지 않습니다.
+    pub async fn revoke_refresh_token(&self, user_id: &str, token: &str) -> Result<(), ApiError> {
+        let mut conn = self.connection_redis().await?;
+        let Some(json) = conn
+            .get(self.keys().refresh_token(&refresh_token::hash(token)))
+            .await?
+        else {
+            return Ok(());
+        };
+        let record: RefreshTokenRecord = serde_json::from_str(&json)?;
+        if record.user_id == user_id {
+            self.delete_refresh_family(&mut conn, &record).await?;
+        }
+        Ok(())
+    }
+
+    /// refresh 토큰을 가진 쪽이 그 family를 폐기합니다. 폐기한 토큰의 사용자 ID를 반환합니다.
+    ///
+    /// access 토큰이 만료된 뒤에도 쿠키 로그아웃이 refresh 토큰을 남기지 않게 할 때 씁니다.
+    pub async fn revoke_refresh_token_family(
+        &self,
+        token: &str,
+    ) -> Result<Option<String>, ApiError> {
+        let mut conn = self.connection_redis().await?;
+        let Some(json) = conn
+            .get(self.keys().refresh_token(&refresh_token::hash(token)))
+            .await?
+        else {
+            return Ok(None);
+        };
+        let record: RefreshTokenRecord = serde_json::from_str(&json)?;
+        self.delete_refresh_family(&mut conn, &record).await?;
+        Ok(Some(record.user_id))
+    }
+
+    /// access 토큰 하나를 만료 시각까지 폐기 목록에 올립니다.
+    ///
+    /// `jti`가 없는 예전 토큰은 개별 폐기할 수 없으므로 `false`를 반환합니다.
+    pub async fn revoke_token(&self, claims: &Claims) -> Result<bool, ApiError> {
+        let Some(jti) = &claims.jti else {
+            return Ok(false);
+        };
+        let remaining = (claims.exp as i64 - chrono::Utc::now().timestamp()).max(1) as u64;
+        self.connection_redis()
+            .await?
+            .set_ex(self.keys().revoked_token(jti), "1", remaining)
+            .await?;
+        Ok(true)
+    }
+
+    pub async fn is_token_revoked(&self, jti: &str) -> Result<bool, ApiError> {
+        Ok(self
+            .connection_redis()
+            .await?
+            .exists(self.keys().revoked_token(jti))
+            .await?)
+    }
+
+    /// 토큰 세대를 올리고 refresh 토큰 family를 지워 이 사용자의 모든 access 토큰과
+    /// refresh 토큰을 폐기합니다.
+    pub async fn revoke_user_tokens(&self, user_id: &str) -> Result<(), ApiError> {
+        let updated = self
+            .update_user_data(user_id, serde_json::json!({}), serde_json::json!({}), true)
+            .await?;
+        if updated.is_none() {
+            return Ok(());
+        }
+        self.revoke_refresh_families(user_id).await
+    }
+
+    /// 새 API 키를 만들고 (키 원문, 기록)을 반환합니다. 키 원문은 다시 조회할 수 없습니다.
+    ///
+    /// `expires_in_seconds`가 있으면 그 시각에 키 기록도 Redis에서 만료됩니다.
+    pub async fn create_api_key(
+        &self,
+        user_id: &str,
+        name: &str,
+        expires_in_seconds: Option<u64>,
+    ) -> Result<(String, ApiKeyRecord), ApiError> {
+        let name = api_key::normalize_name(name)?;
+        let created_at = chrono::Utc::now();
+        let expires_at = api_key::expires_at(created_at, expires_in_seconds)?;
+        let (key, key_id) = api_key::generate();
+        let key_hash = api_key::hash(&key);
+        let record = ApiKeyRecord {
+            key_id,
+            user_id: user_id.to_string(),
+            name,
+            created_at,
+            expires_at,
+            last_used_at: None,
+        };
+        let record_json = serde_json::to_string(&record)?;
+        let record_key = self.keys().api_key(&key_hash);
+        let mut conn = self.connection_redis().await?;
+
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        match expires_in_seconds {
+            Some(seconds) => pipe.set_ex(&record_key, record_json, seconds),
+            None => pipe.set(&record_key, record_json),
+        }
+        .ignore();
+        pipe.hset(
+            self.keys().user_api_keys(user_id),
+            &record.key_id,
+            key_hash.as_str(),
+        )
+        .ignore()
+        .query_async::<()>(&mut conn)
+        .await?;
+        Ok((key, record))
+    }
+
+    /// 사용자의 유효한 API 키를 최근에 만든 순서로 반환합니다.
+    ///
+    /// 만료되어 기록이 사라진 키는 인덱스에서도 제거합니다.
+    pub async fn list_api_keys(&self, user_id: &str) -> Result<Vec<ApiKeyRecord>, ApiError> {
+        let index_key = self.keys().user_api_keys(user_id);
+        let mut conn = self.connection_redis().await?;
+
+        let index: Vec<(String, String)> = conn.hgetall(&index_key).await?.into_iter().collect();
+        if index.is_empty() {
+            return Ok(Vec::new());
+        }
+        let values = conn
+            .mget(
+                index
+                    .iter()
+                    .map(|(_, key_hash)| self.keys().api_key(key_hash))
+                    .collect::<Vec<_>>(),
+            )
+            .await?;
+
+        let mut records = Vec::with_capacity(index.len());
+        for ((key_id, _), value) in index.into_iter().zip(values) {
+            match value {
+                Some(json) => records.push(serde_json::from_str::<ApiKeyRecord>(&json)?),
+                None => {
+                    conn.hdel(&index_key, &key_id).await?;
+                }
+            }
+        }
+        records.sort_by_key(|record| std::cmp::Reverse(record.created_at));
+        Ok(records)
+    }
+
+    /// API 키를 폐기합니다. 이 사용자의 키가 아니거나 이미 없으면 `false`를 반환합니다.
+    pub async fn revoke_api_key(&self, user_id: &str, key_id: &str) -> Result<bool, ApiError> {
+        let index_key = self.keys().user_api_keys(user_id);
+        let mut conn = self.connection_redis().await?;
+
+        let Some(key_hash) = conn.hget(&index_key, key_id).await? else {
+            return Ok(false);
+        };
+        let (deleted,): (usize,) = redis::pipe()
+            .atomic()
+            .del(self.keys().api_key(&key_hash))
+            .hdel(&index_key, key_id)
+            .ignore()
+            .query_async(&mut conn)
+            .await?;
+        Ok(deleted > 0)
+    }
+
+    /// 요청에 담긴 API 키를 확인하고 그 기록을 반환합니다.
+    ///
+    /// 마지막 사용 시각은 [`api_key::LAST_USED_RESOLUTION_SECONDS`]마다 한 번만 기록합니다.
+    /// 계정이 삭제되었거나 비활성화되었으면 키도 쓸 수 없습니다.
+    pub async fn authenticate_api_key(&self, key: &str) -> Result<ApiKeyRecord, ApiError> {
+        let invalid = || ApiError::Unauthorized("Invalid API key".to_string());
+        if !api_key::is_well_formed(key) {
+            return Err(invalid());
+        }
+        let record_key = self.keys().api_key(&api_key::hash(key));
+        let mut conn = self.connection_redis().await?;
+
+        let mut record: ApiKeyRecord = match conn.get(&record_key).await? {
+            Some(json) => serde_json::from_str(&json)?,
+            None => return Err(invalid()),
+        };
+        let now = chrono::Utc::now();
+        if record.is_expired(now) {
+            return Err(invalid());
+        }
+        match self.get_user_data(&record.user_id).await? {
+            None => {
+                tracing::warn!("Unauthorized: Account {} no longer exists", record.user_id);
+                return Err(ApiError::Unauthorized(
+                    "Account no longer exists".to_string(),
+                ));
+            }
+            Some(user_data) if user_data.is_disabled() => {
+                tracing::warn!("Forbidden: API key of disabled account {}", record.user_id);
+                return Err(ApiError::AccountDisabled(record.user_id));
+            }
+            Some(_) => {}
+        }
+
+        if record.last_used_is_stale(now) {
+            record.last_used_at = Some(now);
+            redis::cmd("SET")
+                .arg(&record_key)
+                .arg(serde_json::to_string(&record)?)
+                .arg("XX")
+                .arg("KEEPTTL")
+                .query_async::<()>(&mut conn)
+                .await?;
+        }
+        Ok(record)
+    }
+
+    /// 세션의 공유 링크를 만들고 (뷰어 토큰, 기록)을 반환합니다. 토큰은 다시 조회할 수 없습니다.
+    ///
+    /// `ttl_seconds`는 [`jwt::TokenPolicy::scoped_ttl`]로 제한된 값이어야 합니다.
+    pub async fn create_share_link(
+        &self,
+        user_id: &str,
+        session_id: &str,
+        name: Option<&str>,
+        ttl_seconds: u64,
+    ) -> Result<(String, ShareLinkRecord), ApiError> {
+        let name = share_link::normalize_name(name)?;
+        let user_data = self
+            .get_user_data(user_id)
+            .await?
+            .ok_or_else(|| ApiError::Unauthorized("Account no longer exists".to_string()))?;
+        let created_at = chrono::Utc::now();
+        let record = ShareLinkRecord {
+            link_id: share_link::generate_id(),
+            user_id: user_id.to_string(),
+            session_id: session_id.to_string(),
+            name,
+            created_at,
+            expires_at: created_at + chrono::Duration::seconds(ttl_seconds as i64),
+        };
+        let signing_key = self.share.get_signing_key(&self.redis_client).await?;
+        let token = jwt::create_viewer_token(
+            user_id,
+            user_data.token_generation,
+            session_id,
+            &record.link_id,
+            &signing_key,
+            ttl_seconds,
+        )?;
+
+        let mut conn = self.connection_redis().await?;
+        redis::pipe()
+            .atomic()
+            .set_ex(
+                self.keys().share_link(&record.link_id),
+                serde_json::to_string(&record)?,
+                ttl_seconds,
+            )
+            .ignore()
+            .hset(
+                self.keys().user_share_links(user_id),
+                &record.link_id,
+                session_id,
+            )
+            .ignore()
+            .query_async::<()>(&mut conn)
+            .await?;
+        Ok((token, record))
+    }
+
+    /// 세션의 유효한 공유 링크를 최근에 만든 순서로 반환합니다.
+    ///
+    /// 만료되어 기록이 사라진 링크는 인덱스에서도 제거합니다.
+    pub async fn list_share_links(
+        &self,
+        user_id: &str,
+        session_id: &str,
+    ) -> Result<Vec<ShareLinkRecord>, ApiError> {
+        let index_key = self.keys().user_share_links(user_id);
+        let mut conn = self.connection_redis().await?;
+
+        let link_ids: Vec<String> = conn
+            .hgetall(&index_key)
+            .await?
+            .into_iter()
+            .filter(|(_, link_session_id)| link_session_id == session_id)
+            .map(|(link_id, _)| link_id)
+            .collect();
+        if link_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let values = conn
+            .mget(
+                link_ids
+                    .iter()
+                    .map(|link_id| self.keys().share_link(link_id))
+                    .collect::<Vec<_>>(),
+            )
+            .await?;
+
+        let mut records = Vec::with_capacity(link_ids.len());
+        for (link_id, value) in link_ids.into_iter().zip(values) {
+            match value {
+                Some(json) => records.push(serde_json::from_str::<ShareLinkRecord>(&json)?),
+                None => {
+                    conn.hdel(&index_key, &link_id).await?;
+                }
+            }
+        }
+        records.sort_by_key(|record| std::cmp::Reverse(record.created_at));
+        Ok(records)
+    }
+
+    /// 공유 링크를 폐기하고 그 링크로 열린 스트림을 닫습니다.
+    ///
+    /// 이 세션의 링크가 아니거나 이미 없으면 `false`를 반환합니다.
+    pub async fn revoke_share_link(
+        &self,
+        user_id: &str,
+        session_id: &str,
+        link_id: &str,
+    ) -> Result<bool, ApiError> {
+        let index_key = self.keys().user_share_links(user_id);
+        let mut conn = self.connection_redis().await?;
+
+        if conn.hget(&index_key, link_id).await?.as_deref() != Some(session_id) {
+            return Ok(false);
+        }
+        let (deleted,): (usize,) = redis::pipe()
+            .atomic()
+            .del(self.keys().share_link(link_id))
+            .hdel(&index_key, link_id)
+            .ignore()
+            .query_async(&mut conn)
+            .await?;
+        conn.publish(
+            self.keys().user_session(user_id, session_id),
+            crate::share_link_close_message(link_id),
+        )
+        .await?;
+        Ok(deleted > 0)
+    }
+
+    /// 세션 스트림과 스냅샷을 볼 수 있는지 확인합니다.
+    ///
+    /// 비공개 세션은 이 세션의 유효한 공유 링크 토큰이 있어야 하며, 그 링크 ID를 반환합니다.
+    /// 공개·미등록 세션은 토큰 없이 볼 수 있으므로 `None`을 반환합니다.
+    pub async fn authorize_viewer(
+        &self,
+        user_id: &str,
+        session_id: &str,
+        session: &SessionData,
+        token: Option<&str>,
+    ) -> Result<Option<String>, ApiError> {
+        if session.visibility != Visibility::Private {
+            return Ok(None);
+        }
+        let Some(token) = token else {
+            tracing::warn!(
+                "Unauthorized: Missing viewer token for private session {}/{}",
+                user_id,
+                session_id
+            );
+            return Err(ApiError::Unauthorized(
+                "Private session requires a viewer token".to_string(),
+            ));
+        };
+        let invalid = || {
+            tracing::warn!(
+                "Unauthorized: Invalid viewer token for private session {}/{}",
+                user_id,
+                session_id
+            );
+            ApiError::Unauthorized("Invalid viewer token".to_string())
+        };
+
+        let claims = self
+            .share
+            .verify_token(&self.redis_client, token)
+            .await
+            .map_err(|_| invalid())?;
+        let in_scope = claims
+            .scopes
+            .iter()
+            .flatten()
+            .any(|scope| scope.allows(user_id, Some(session_id), SessionAccess::View));
+        let Some(link_id) = claims.jti.filter(|_| in_scope && claims.sub == user_id) else {
+            return Err(invalid());
+        };
+
+        let record: Option<String> = self
+            .connection_redis()
+            .await?
+            .get(self.keys().share_link(&link_id))
+            .await?;
+        match record {
+            Some(json) => {
+                let record = serde_json::from_str::<ShareLinkRecord>(&json)?;
+                if !record.is_for(user_id, session_id) || record.is_expired(chrono::Utc::now()) {
+                    return Err(invalid());
+                }
+                Ok(Some(link_id))
+            }
+            None => Err(invalid()),
+        }
+    }
+
+    /// 협업자를 추가하거나 역할을 바꾸고, 협업자의 공유받은 세션 인덱스에 기록합니다.
+    ///
+    /// 세션은 [`Self::update_session`]으로 고치므로 동시에 들어온 다른 변경을 덮어쓰지 않습니다.
+    /// 세션의 남은 TTL은 그대로 둡니다.
+    pub async fn set_session_collaborator(
+        &self,
+        owner: &str,
+        session_id: &str,
+        collaborator: &str,
+        role: SessionRole,
+    ) -> Result<(), ApiError> {
+        if self.get_user_data(collaborator).await?.is_none() {
+            return Err(ApiError::UserNotFound(collaborator.to_string()));
+        }
+        self.update_session(
+            &self.keys().user_session(owner, session_id),
+            None,
+            |session| session.set_collaborator(collaborator, role),
+        )
+        .await?
+        .ok_or_else(|| ApiError::SessionNotFound(session_id.to_string()))?;
+
+        // 세션에 없는 항목은 조회할 때 정리되므로, 세션을 저장한 뒤에 인덱스에 기록합니다.
+        self.connection_redis()
+            .await?
+            .hset(
+                self.keys().user_shared_sessions(collaborator),
+                shared_session_field(owner, session_id),
+                role.as_str(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// 협업자를 제거합니다. 세션이 없거나 협업자가 아니었으면 `false`를 반환합니다.
+    ///
+    /// 세션은 [`Self::update_session`]으로 고치므로, 동시에 저장된 세션 수정이 제거한 권한을
+    /// 되살리지 않습니다.
+    pub async fn remove_session_collaborator(
+        &self,
+        owner: &str,
+        session_id: &str,
+        collaborator: &str,
+    ) -> Result<bool, ApiError> {
+        let removed = self
+            .update_session(
+                &self.keys().user_session(owner, session_id),
+                None,
+                |session| Ok(session.remove_collaborator(collaborator)),
+            )
+            .await?
+            .is_some_and(|(_, removed)| removed);
+
+        self.connection_redis()
+            .await?
+            .hdel(
+                self.keys().user_shared_sessions(collaborator),
+                shared_session_field(owner, session_id),
+            )
+            .await?;
+        Ok(removed)
+    }
+
+    /// 사용자가 협업자로 추가된 세션을 조회합니다.
+    ///
+    /// 세션이 사라졌거나 더 이상 협업자가 아닌 항목은 결과에서 빠지고 인덱스에서도 정리됩니다.
+    pub async fn list_shared_session_entries(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<SharedSessionEntry>, ApiError> {
+        let index_key = self.keys().user_shared_sessions(user_id);
+        let mut conn = self.connection_redis().await?;
+
+        let fields: Vec<String> = conn.hkeys(&index_key).await?;
+        if fields.is_empty() {
+            return Ok(Vec::new());
+        }
+        let keys: Vec<String> = fields
+            .iter()
+            .map(|field| {
+                let (owner, session_id) = field.split_once('/').unwrap_or(("", field));
+                self.keys().user_session(owner, session_id)
+            })
+            .collect();
+        let loaded = load_session_entries(&mut conn, &keys).await?;
+
+        let mut entries = Vec::with_capacity(fields.len());
+        let mut stale = Vec::new();
+        for (field, loaded) in fields.into_iter().zip(loaded) {
+            let shared = field.split_once('/').zip(loaded).and_then(
+                |((owner, session_id), (session, ttl_seconds, viewers))| {
+                    let role = session.role_of(user_id)?;
+                    Some(SharedSessionEntry {
+                        owner: owner.to_string(),
+                        role,
+                        entry: UserSessionEntry {
+                            session_id: session_id.to_string(),
+                            session,
+                            ttl_seconds,
+                            viewers,
+                        },
+                    })
+                },
+            );
+            match shared {
+                Some(shared) => entries.push(shared),
+                None => stale.push(field),
+            }
+        }
+
+        if !stale.is_empty() {
+            tracing::debug!(
+                "Pruning {} stale entries from shared sessions of user {}",
+                stale.len(),
+                user_id
+            );
+            conn.hdel(&index_key, &stale).await?;
+        }
+
+        Ok(entries)
+    }
+
+    /// `to`에게 세션 소유권 이전을 요청하고 받는 사용자의 인덱스에 기록합니다.
+    ///
+    /// 다른 사용자에게 보낸 요청이 있으면 대체합니다. 세션은 [`Self::update_session`]으로 고치므로
+    /// 동시에 저장된 세션 수정과 겹쳐도 요청이 사라지지 않습니다. 세션의 남은 TTL은 그대로 둡니다.
+    pub async fn request_session_transfer(
+        &self,
+        owner: &str,
+        session_id: &str,
+        to: &str,
+    ) -> Result<(SessionData, PendingTransfer), ApiError> {
+        if self.get_user_data(to).await?.is_none() {
+            return Err(ApiError::UserNotFound(to.to_string()));
+        }
+        let now = chrono::Utc::now();
+        let (session, replaced) = self
+            .update_session(
+                &self.keys().user_session(owner, session_id),
+                None,
+                |session| session.request_transfer(to, now),
+            )
+            .await?
+            .ok_or_else(|| ApiError::SessionNotFound(session_id.to_string()))?;
+        let transfer = session
+            .pending_transfer
+            .clone()
+            .expect("transfer was just requested");
+
+        let field = shared_session_field(owner, session_id);
+        let mut pipe = redis::pipe();
+        pipe.atomic()
+            .hset(
+                self.keys().user_incoming_transfers(to),
+                &field,
+                transfer.expires_at.to_rfc3339(),
+            )
+            .ignore();
+        if let Some(replaced) = replaced.filter(|replaced| replaced.to != to) {
+            pipe.hdel(self.keys().user_incoming_transfers(&replaced.to), &field)
+                .ignore();
+        }
+        pipe.query_async::<()>(&mut self.connection_redis().await?)
+            .await?;
+        Ok((session, transfer))
+    }
+
+    /// 수락을 기다리는 소유권 이전 요청을 취소합니다. `to`를 주면 그 사용자에게 보낸 요청만 취소합니다.
+    ///
+    /// 세션은 [`Self::update_session`]으로 고치므로 동시에 저장된 세션 수정이 취소를 되돌리지 않습니다.
+    /// 취소한 요청이 있었으면 `true`를 반환합니다.
+    pub async fn cancel_session_transfer(
+        &self,
+        owner: &str,
+        session_id: &str,
+        to: Option<&str>,
+    ) -> Result<bool, ApiError> {
+        let cancelled = self
+            .update_session(
+                &self.keys().user_session(owner, session_id),
+                None,
+                |session| {
+                    let addressed = session
+                        .pending_transfer
+                        .as_ref()
+                        .is_some_and(|transfer| to.is_none_or(|to| transfer.to == to));
+                    Ok(addressed.then(|| session.pending_transfer.take()).flatten())
+                },
+            )
+            .await?
+            .and_then(|(_, cancelled)| cancelled);
+
+        let field = shared_session_field(owner, session_id);
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        if let Some(to) = to {
+            pipe.hdel(self.keys().user_incoming_transfers(to), &field)
+                .ignore();
+        }
+        if let Some(cancelled) = &cancelled {
+            pipe.hdel(self.keys().user_incoming_transfers(&cancelled.to), &field)
+                .ignore();
+        }
+        pipe.query_async::<()>(&mut self.connection_redis().await?)
+            .await?;
+        Ok(cancelled.is_some())
+    }
+
+    /// 소유권 이전을 수락해 세션을 `to`의 네임스페이스로 옮깁니다.
+    ///
+    /// 이전 요청을 확인한 세션이 그대로일 때만 세션 키 이동, 이전 요청 정리, 세션 인덱스와 디렉터리
+    /// 이동을 Lua 스크립트 하나로 처리하므로, 동시에 들어온 이전 소유자의 수정이나
+    /// 취소와 겹쳐 세션이 두 벌 남지 않습니다. 그 사이에 세션이 바뀌었으면 다시 읽어 확인합니다.
+    /// 세션 키는 `RENAME`으로 옮기므로 TTL이 유지되고, 받는 사용자에게 같은 ID의 세션이 있으면
+    /// `ApiError::SessionExists`를 반환합니다. 이전 소유자가 준 협업자 권한과 공유 링크는 넘어가지
+    /// 않습니다. 열려 있는 스트림은 새 채널로 옮겨 가고, 예전 스트림 URL은 한동안 새 URL로 보냅니다.
+    pub async fn accept_session_transfer(
+        &self,
+        to: &str,
+        owner: &str,
+        session_id: &str,
+    ) -> Result<SessionData, ApiError> {
+        let not_found = || ApiError::TransferNotFound(shared_session_field(owner, session_id));
+        let old_key = self.keys().user_session(owner, session_id);
+        let new_key = self.keys().user_session(to, session_id);
+        let old_member = serde_json::to_string(&SessionRef::user(owner, session_id))?;
+        let new_member = serde_json::to_string(&SessionRef::user(to, session_id))?;
+        let field = shared_session_field(owner, session_id);
+
+        let mut conn = self.connection_redis().await?;
+        let mut moved = None;
+        for _ in 0..SESSION_UPDATE_ATTEMPTS {
+            let Some(json) = conn.get(&old_key).await? else {
+                return Err(not_found());
+            };
+            let mut session = serde_json::from_str::<SessionData>(&json)?;
+            if session.transfer_for(to, chrono::Utc::now()).is_none() {
+                return Err(not_found());
+            }
+            let collaborators = session.complete_transfer(to);
+            let created_at_ms = session
+                .created_at
+                .unwrap_or_else(chrono::Utc::now)
+                .timestamp_millis();
+
+            let status: i64 = redis::Script::new(ACCEPT_SESSION_TRANSFER_SCRIPT)
+                .key(&old_key)
+                .key(&new_key)
+                .key(self.keys().user_session_index(owner))
+                .key(self.keys().user_session_index(to))
+                .key(self.keys().directory())
+                .key(self.keys().user_incoming_transfers(to))
+                .key(self.keys().moved_session(owner, session_id))
+                .arg(&json)
+                .arg(serde_json::to_string(&session)?)
+                .arg(session_id)
+                .arg(created_at_ms)
+                .arg(&old_member)
+                .arg(&new_member)
+                .arg(if session.listed { "1" } else { "0" })
+                .arg(&field)
+                .arg(to)
+                .arg(MOVED_SESSION_REDIRECT_SECONDS)
+                .invoke_async(&mut conn)
+                .await?;
+            match status {
+                1 => {
+                    moved = Some((session, collaborators));
+                    break;
+                }
+                -1 => return Err(ApiError::SessionExists(session_id.to_string())),
+                _ => tracing::debug!("Session {} changed while accepting transfer", old_key),
+            }
+        }
+        let Some((session, collaborators)) = moved else {
+            return Err(ApiError::SessionConflict);
+        };
+
+        let links_key = self.keys().user_share_links(owner);
+        let link_ids: Vec<String> = conn
+            .hgetall(&links_key)
+            .await?
+            .into_iter()
+            .filter(|(_, link_session_id)| link_session_id == session_id)
+            .map(|(link_id, _)| link_id)
+            .collect();
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        for link_id in &link_ids {
+            pipe.del(self.keys().share_link(link_id))
+                .ignore()
+                .hdel(&links_key, link_id)
+                .ignore();
+        }
+        for collaborator in collaborators.keys() {
+            pipe.hdel(self.keys().user_shared_sessions(collaborator), &field)
+                .ignore();
+        }
+        pipe.query_async::<()>(&mut conn).await?;
+
+        for link_id in &link_ids {
+            conn.publish(&old_key, crate::share_link_close_message(link_id))
+                .await?;
+        }
+        conn.publish(&old_key, stream_moved_message(&new_key))
+            .await?;
+        Ok(session)
+    }
+
+    /// 사용자에게 온 소유권 이전 요청을 만료 시각 순으로 조회합니다.
+    ///
+    /// 세션이 사라졌거나 요청이 취소·만료된 항목은 결과에서 빠지고 인덱스에서도 정리됩니다.
+    pub async fn list_incoming_transfers(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<IncomingTransferEntry>, ApiError> {
+        let index_key = self.keys().user_incoming_transfers(user_id);
+        let mut conn = self.connection_redis().await?;
+
+        let fields: Vec<String> = conn.hkeys(&index_key).await?;
+        if fields.is_empty() {
+            return Ok(Vec::new());
+        }
+        let keys: Vec<String> = fields
+            .iter()
+            .map(|field| {
+                let (owner, session_id) = field.split_once('/').unwrap_or(("", field));
+                self.keys().user_session(owner, session_id)
+            })
+            .collect();
+        let values = conn.mget(&keys).await?;
+
+        let now = chrono::Utc::now();
+        let mut entries = Vec::with_capacity(fields.len());
+        let mut stale = Vec::new();
+        for (field, value) in fields.into_iter().zip(values) {
+            let session = value
+                .map(|json| serde_json::from_str::<SessionData>(&json))
+                .transpose()?;
+            let entry = match (field.split_once('/'), session) {
+                (Some((owner, session_id)), Some(session)) => session
+                    .transfer_for(user_id, now)
+                    .cloned()
+                    .map(|transfer| IncomingTransferEntry {
+                        owner: owner.to_string(),
+                        session_id: session_id.to_string(),
+                        session,
+                        transfer,
+                    }),
+                _ => None,
+            };
+            match entry {
+                Some(entry) => entries.push(entry),
+                None => stale.push(field),
+            }
+        }
+
+        if !stale.is_empty() {
+            tracing::debug!(
+                "Pruning {} stale entries from incoming transfers of user {}",
+                stale.len(),
+                user_id
+            );
+            conn.hdel(&index_key, &stale).await?;
+        }
+
+        entries.sort_by_key(|entry| entry.transfer.expires_at);
+        Ok(entries)
+    }
+
+    /// 세션이 다른 사용자에게 이전되었으면 새 소유자 ID를 반환합니다.
+    pub async fn moved_session_owner(
+        &self,
+        user_id: &str,
+        session_id: &str,
+    ) -> Result<Option<String>, ApiError> {
+        Ok(self
+            .connection_redis()
+            .await?
+            .get(self.keys().moved_session(user_id, session_id))
+            .await?)
+    }
+
+    /// 조직을 만들고 멤버들의 조직 인덱스에 기록합니다. 조직은 만료되지 않습니다.
+    ///
+    /// 같은 ID가 이미 있으면 `ApiError::OrgExists`를 반환합니다.
+    pub async fn create_org(&self, org_id: &str, org: &OrgData) -> Result<(), ApiError> {
+        let org_json = serde_json::to_string(org)?;
+        let mut conn = self.connection_redis().await?;
+
+        if !conn
+            .set_nx(self.keys().org_data(org_id), org_json.as_str())
+            .await?
+        {
+            return Err(ApiError::OrgExists(org_id.to_string()));
+        }
+        let mut pipe = redis::pipe();
+        for (user_id, role) in &org.members {
+            pipe.hset(self.keys().user_orgs(user_id), org_id, role.as_str())
+                .ignore();
+        }
+        pipe.query_async::<()>(&mut conn).await?;
+        Ok(())
+    }
+
+    pub async fn get_org(&self, org_id: &str) -> Result<Option<OrgData>, ApiError> {
+        let data: Option<String> = self
+            .connection_redis()
+            .await?
+            .get(self.keys().org_data(org_id))
+            .await?;
+        match data {
+            Some(json) => Ok(Some(serde_json::from_str::<OrgData>(&json)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// 조직 데이터를 덮어쓰고 `user_id`의 조직 인덱스를 멤버 목록에 맞춥니다.
+    async fn save_org_member(
+        &self,
+        org_id: &str,
+        org: &OrgData,
+        user_id: &str,
+    ) -> Result<(), ApiError> {
+        let org_json = serde_json::to_string(org)?;
+        let index_key = self.keys().user_orgs(user_id);
+
+        let mut pipe = redis::pipe();
+        pipe.atomic()
+            .cmd("SET")
+            .arg(self.keys().org_data(org_id))
+            .arg(org_json.as_str())
+            .arg("XX")
+            .ignore();
+        match org.role_of(user_id) {
+            Some(role) => pipe.hset(&index_key, org_id, role.as_str()).ignore(),
+            None => pipe.hdel(&index_key, org_id).ignore(),
+        };
+        pipe.query_async::<()>(&mut self.connection_redis().await?)
+            .await?;
+        Ok(())
+    }
+
+    /// 조직 멤버를 추가하거나 역할을 바꿉니다. 추가할 사용자의 계정이 있어야 합니다.
+    pub async fn set_org_member(
+        &self,
+        org_id: &str,
+        user_id: &str,
+        role: OrgRole,
+    ) -> Result<(), ApiError> {
+        if self.get_user_data(user_id).await?.is_none() {
+            return Err(ApiError::UserNotFound(user_id.to_string()));
+        }
+        let mut org = self
+            .get_org(org_id)
+            .await?
+            .ok_or_else(|| ApiError::OrgNotFound(org_id.to_string()))?;
+        org.set_member(user_id, role)?;
+        self.save_org_member(org_id, &org, user_id).await
+    }
+
+    /// 조직 멤버를 제거합니다. 멤버였으면 `true`를 반환합니다.
+    pub async fn remove_org_member(&self, org_id: &str, user_id: &str) -> Result<bool, ApiError> {
+        let mut org = self
+            .get_org(org_id)
+            .await?
+            .ok_or_else(|| ApiError::OrgNotFound(org_id.to_string()))?;
+        let removed = org.remove_member(user_id)?;
+        self.save_org_member(org_id, &org, user_id).await?;
+        Ok(removed)
+    }
+
+    /// 조직과 그 조직의 모든 세션을 삭제하고, 열려 있는 스트림을 끝냅니다.
+    ///
+    /// 삭제한 세션 수를 반환합니다.
+    pub async fn delete_org(&self, org_id: &str) -> Result<usize, ApiError> {
+        let org = self
+            .get_org(org_id)
+            .await?
+            .ok_or_else(|| ApiError::OrgNotFound(org_id.to_string()))?;
+        let index_key = self.keys().org_session_index(org_id);
+        let session_prefix = self.keys().org_session(org_id, "");
+        let mut conn = self.connection_redis().await?;
+        let session_ids = collect_session_ids(&mut conn, &index_key, &session_prefix).await?;
+
+        let mut pipe = redis::pipe();
+        pipe.atomic()
+            .del(self.keys().org_data(org_id))
+            .ignore()
+            .del(&index_key)
+            .ignore();
+        for user_id in org.members.keys() {
+            pipe.hdel(self.keys().user_orgs(user_id), org_id).ignore();
+        }
+        for session_id in &session_ids {
+            let member = serde_json::to_string(&SessionRef::org(org_id, session_id))?;
+            pipe.del(self.keys().org_session(org_id, session_id))
+                .ignore()
+                .zrem(self.keys().directory(), member)
+                .ignore();
+        }
+        pipe.query_async::<()>(&mut conn).await?;
+
+        for session_id in &session_ids {
+            conn.publish(
+                self.keys().org_session(org_id, session_id),
+                STREAM_CLOSE_MESSAGE,
+            )
+            .await?;
+        }
+
+        Ok(session_ids.len())
+    }
+
+    /// 사용자가 속한 조직을 ID 순으로 조회합니다.
+    ///
+    /// 조직이 사라졌거나 더 이상 멤버가 아닌 항목은 결과에서 빠지고 인덱스에서도 정리됩니다.
+    pub async fn list_user_orgs(&self, user_id: &str) -> Result<Vec<OrgEntry>, ApiError> {
+        let index_key = self.keys().user_orgs(user_id);
+        let mut conn = self.connection_redis().await?;
+
+        let mut org_ids: Vec<String> = conn.hkeys(&index_key).await?;
+        if org_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        org_ids.sort();
+        let keys: Vec<String> = org_ids
+            .iter()
+            .map(|org_id| self.keys().org_data(org_id))
+            .collect();
+        let values: Vec<Option<String>> =
+            redis::cmd("MGET").arg(&keys).query_async(&mut conn).await?;
+
+        let mut entries = Vec::with_capacity(org_ids.len());
+        let mut stale = Vec::new();
+        for (org_id, value) in org_ids.into_iter().zip(values) {
+            let org = value
+                .map(|json| serde_json::from_str::<OrgData>(&json))
+                .transpose()?;
+            match org.and_then(|org| Some((org.role_of(user_id)?, org))) {
+                Some((role, org)) => entries.push(OrgEntry { org_id, role, org }),
+                None => stale.push(org_id),
+            }
+        }
+
+        if !stale.is_empty() {
+            tracing::debug!(
+                "Pruning {} stale entries from orgs of user {}",
+                stale.len(),
+                user_id
+            );
+            conn.hdel(&index_key, &stale).await?;
+        }
+
+        Ok(entries)
+    }
+
+    /// 토큰의 `orgs` claim에 넣을 조직별 역할.
+    pub async fn org_memberships(&self, user_id: &str) -> Result<OrgMemberships, ApiError> {
+        let orgs = self.list_user_orgs(user_id).await?;
+        Ok(orgs
+            .into_iter()
+            .map(|entry| (entry.org_id, entry.role))
+            .collect())
+    }
+
+    /// 조직 세션을 `update`로 고쳐 저장합니다. 세션이 없으면 `None`을 반환합니다.
+    ///
+    /// [`Self::update_user_session`] 참고.
+    pub async fn update_org_session<R>(
+        &self,
+        org_id: &str,
+        session_id: &str,
+        ttl_seconds: u64,
+        update: impl FnMut(&mut SessionData) -> Result<R, ApiError>,
+    ) -> Result<Option<(SessionData, R)>, ApiError> {
+        self.update_indexed_session(
+            &self.keys().org_session(org_id, session_id),
+            Some(&self.keys().org_session_index(org_id)),
+            &SessionRef::org(org_id, session_id),
+            ttl_seconds,
+            update,
+        )
+        .await
+    }
+
+    /// 새 조직 세션을 저장합니다. 같은 ID의 세션이 있으면 `ApiError::SessionExists`를 반환합니다.
+    pub async fn create_org_session(
+        &self,
+        org_id: &str,
+        session_id: &str,
+        session: &SessionData,
+        ttl_seconds: u64,
+    ) -> Result<(), ApiError> {
+        self.store_indexed_session(
+            &self.keys().org_session(org_id, session_id),
+            &self.keys().org_session_index(org_id),
+            &SessionRef::org(org_id, session_id),
+            session,
+            ttl_seconds,
+            true,
+        )
+        .await
+    }
+
+    pub async fn get_org_session(
+        &self,
+        org_id: &str,
+        session_id: &str,
+    ) -> Result<Option<SessionData>, ApiError> {
+        self.load_session(&self.keys().org_session(org_id, session_id))
+            .await
+    }
+
+    /// 조직 세션을 삭제하고 인덱스와 디렉터리에서도 제거합니다. 세션이 존재했으면 `true`를 반환합니다.
+    pub async fn delete_org_session(
+        &self,
+        org_id: &str,
+        session_id: &str,
+    ) -> Result<bool, ApiError> {
+        let member = serde_json::to_string(&SessionRef::org(org_id, session_id))?;
+        let mut pipe = redis::pipe();
+        pipe.atomic()
+            .del(self.keys().org_session(org_id, session_id))
+            .zrem(self.keys().org_session_index(org_id), session_id)
+            .ignore()
+            .zrem(self.keys().directory(), member)
+            .ignore();
+        let (deleted,): (u64,) = pipe
+            .query_async(&mut self.connection_redis().await?)
+            .await?;
+        Ok(deleted > 0)
+    }
+
+    /// 조직 세션 인덱스를 따라 세션 데이터, 남은 TTL, 뷰어 수를 함께 조회합니다.
+    ///
+    /// 만료되어 사라진 세션은 결과에서 빠지고 인덱스에서도 정리됩니다.
+    pub async fn list_org_session_entries(
+        &self,
+        org_id: &str,
+    ) -> Result<Vec<UserSessionEntry>, ApiError> {
+        self.list_indexed_session_entries(&self.keys().org_session_index(org_id), |session_id| {
+            self.keys().org_session(org_id, session_id)
+        })
+        .await
+    }
+
+    /// 조직 세션 인덱스에 있는 세션 수 (만료되었지만 아직 정리되지 않은 세션 포함).
+    pub async fn count_org_sessions(&self, org_id: &str) -> Result<usize, ApiError> {
+        let mut conn = self.connection_redis().await?;
+        Ok(conn.zcard(self.keys().org_session_index(org_id)).await?)
+    }
+
+    /// 조직 세션 인덱스에서 생성 시각 순으로 한 페이지를 읽습니다.
+    ///
+    /// [`Self::page_indexed_session_entries`] 참고.
+    pub async fn page_org_session_entries<T>(
+        &self,
+        org_id: &str,
+        page: IndexPage<'_>,
+        select: impl FnMut(UserSessionEntry) -> Option<T>,
+    ) -> Result<Vec<(i64, T)>, ApiError> {
+        self.page_indexed_session_entries(
+            &self.keys().org_session_index(org_id),
+            |session_id| self.keys().org_session(org_id, session_id),
+            page,
+            select,
+        )
+        .await
+    }
+
+    /// 모든 계정을 SCAN으로 찾아 세션 수와 함께 조회합니다 (관리자 API 용도).
+    pub async fn list_accounts(&self) -> Result<Vec<AccountEntry>, ApiError> {
+        let mut conn = self.connection_redis().await?;
+        let mut user_ids: Vec<String> = scan_keys(&mut conn, &self.keys().user_data_pattern())
+            .await?
+            .iter()
+            .filter_map(|key| self.keys().parse_user_data(key))
+            .map(str::to_string)
+            .collect();
+        user_ids.sort();
+        user_ids.dedup();
+        if user_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let keys: Vec<String> = user_ids
+            .iter()
+            .map(|user_id| self.keys().user_data(user_id))
+            .collect();
+        let values: Vec<Option<String>> =
+            redis::cmd("MGET").arg(&keys).query_async(&mut conn).await?;
+        let mut count_pipe = redis::pipe();
+        for user_id in &user_ids {
+            count_pipe.zcard(self.keys().user_session_index(user_id));
+        }
+        let counts: Vec<u64> = count_pipe.query_async(&mut conn).await?;
+
+        let mut entries = Vec::with_capacity(user_ids.len());
+        for ((user_id, value), sessions) in user_ids.into_iter().zip(values).zip(counts) {
+            if let Some(json) = value {
+                entries.push(AccountEntry {
+                    user_id,
+                    user_data: serde_json::from_str(&json)?,
+                    sessions,
+                });
+            }
+        }
+        Ok(entries)
+    }
+
+    /// 공용, 사용자, 조직 세션을 모두 SCAN으로 찾아 남은 TTL, 뷰어 수와 함께 조회합니다
+    /// (관리자 API 용도).
+    pub async fn list_all_session_entries(&self) -> Result<Vec<StoredSessionEntry>, ApiError> {
+        let mut conn = self.connection_redis().await?;
+        let mut keys = Vec::new();
+        for pattern in self.keys().session_patterns() {
+            keys.extend(scan_keys(&mut conn, &pattern).await?);
+        }
+        keys.sort();
+        keys.dedup();
+        let (keys, refs): (Vec<String>, Vec<SessionRef>) = keys
+            .into_iter()
+            .filter_map(|key| {
+                let session_ref = self.keys().parse_session(&key)?;
+                Some((key, session_ref))
+            })
+            .unzip();
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let loaded = load_session_entries(&mut conn, &keys).await?;
+        Ok(refs
+            .into_iter()
+            .zip(loaded)
+            .filter_map(|(session_ref, loaded)| {
+                let (session, ttl_seconds, viewers) = loaded?;
+                Some(StoredSessionEntry {
+                    session_ref,
+                    session,
+                    ttl_seconds,
+                    viewers,
+                })
+            })
+            .collect())
+    }
+
+    /// 조직 수를 셉니다 (관리자 통계 용도).
+    pub async fn count_orgs(&self) -> Result<usize, ApiError> {
+        let mut conn = self.connection_redis().await?;
+        let mut org_ids: Vec<String> = scan_keys(&mut conn, &self.keys().org_data_pattern())
+            .await?
+            .iter()
+            .filter_map(|key| self.keys().parse_org_data(key))
+            .map(str::to_string)
+            .collect();
+        org_ids.sort();
+        org_ids.dedup();
+        Ok(org_ids.len())
+    }
+
+    /// 관리자 역할과 비활성화 여부를 바꿉니다. `None`인 값은 그대로 둡니다.
+    ///
+    /// 비활성화하면 토큰 세대를 올리고 refresh 토큰 family를 지워 발급된 토큰을 모두 폐기합니다.
+    /// API 키와 세션은 남지만, 다시 활성화될 때까지 키로 인증할 수 없습니다.
+    /// 관리자 역할을 거두면 [`crate::auth::AdminUser`]가 저장된 역할을 다시 확인하므로 바로 반영됩니다.
+    pub async fn update_account_status(
+        &self,
+        user_id: &str,
+        admin: Option<bool>,
+        disabled: Option<bool>,
+    ) -> Result<UserData, ApiError> {
+        let not_found = || ApiError::UserNotFound(user_id.to_string());
+        let mut set = serde_json::Map::new();
+        if let Some(admin) = admin {
+            set.insert("admin".to_string(), admin.into());
+        }
+        if disabled == Some(false) {
+            set.insert("disabled_at".to_string(), serde_json::Value::Null);
+        }
+        let (_, mut user_data) = self
+            .update_user_data(user_id, set.into(), serde_json::json!({}), false)
+            .await?
+            .ok_or_else(not_found)?;
+
+        // 이미 비활성화된 계정은 비활성화 시각과 토큰 세대를 그대로 둡니다.
+        if disabled == Some(true) {
+            let (disabling, stored) = self
+                .update_user_data(
+                    user_id,
+                    serde_json::json!({ "disabled_at": chrono::Utc::now() }),
+                    serde_json::json!({ "disabled_at": null }),
+                    true,
+                )
+                .await?
+                .ok_or_else(not_found)?;
+            user_data = stored;
+            if disabling {
+                self.revoke_refresh_families(user_id).await?;
+            }
+        }
+        Ok(user_data)
+    }
+
+    /// 세션을 강제로 삭제하고 열려 있는 스트림을 끝냅니다. 세션이 존재했으면 `true`를 반환합니다.
+    ///
+    /// 사용자 세션이면 [`Self::delete_user_session`]처럼 공유 링크와 협업자 항목도 함께 지웁니다.
+    pub async fn force_delete_session(&self, session_ref: &SessionRef) -> Result<bool, ApiError> {
+        let session_id = &session_ref.session_id;
+        let deleted = match (&session_ref.user_id, &session_ref.org_id) {
+            (Some(user_id), _) => self.delete_user_session(user_id, session_id).await?,
+            (None, Some(org_id)) => self.delete_org_session(org_id, session_id).await?,
+            (None, None) => {
+                let member = serde_json::to_string(session_ref)?;
+                let (deleted,): (u64,) = redis::pipe()
+                    .atomic()
+                    .del(self.keys().public_session(session_id))
+                    .zrem(self.keys().directory(), member)
+                    .ignore()
+                    .query_async(&mut self.connection_redis().await?)
+                    .await?;
+                deleted > 0
+            }
+        };
+        if deleted {
+            self.connection_redis()
+                .await?
+                .publish(
+                    session_ref_key(self.keys(), session_ref),
+                    STREAM_CLOSE_MESSAGE,
+                )
+                .await?;
+        }
+        Ok(deleted)
+    }
+
+    /// 설정된 알고리즘으로 서명 키를 새로 만들어 교체하고 새 kid를 반환합니다.
+    ///
+    /// 이전 키는 그 키로 서명된 토큰이 모두 만료될 때까지 JWKS에 남습니다.
+    pub async fn rotate_signing_key(&self) -> Result<String, ApiError> {
+        let policy = self.share.token_policy();
+        let kid = signing_key::rotate(
+            &mut self.connection_redis().await?,
+            self.keys(),
+            policy.signing_algorithm,
+            signing_key::retire_after_seconds(policy),
+        )
+        .await?;
+        self.share.invalidate_signing_keys().await;
+        Ok(kid)
+    }
+
+    /// 활성 서명 키가 `interval_seconds`보다 오래되었거나 설정된 알고리즘과 다르면
+    /// 교체합니다. `interval_seconds`가 `None`이면 알고리즘만 확인합니다.
+    pub async fn rotate_signing_key_if_due(
+        &self,
+        interval_seconds: Option<u64>,
+    ) -> Result<Option<String>, ApiError> {
+        let policy = self.share.token_policy();
+        let kid = signing_key::rotate_if_due(
+            &mut self.connection_redis().await?,
+            self.keys(),
+            policy.signing_algorithm,
+            interval_seconds,
+            signing_key::retire_after_seconds(policy),
+        )
+        .await?;
+        if kid.is_some() {
+            self.share.invalidate_signing_keys().await;
+        }
+        Ok(kid)
+    }
+
+    /// `record`의 family에 새 토큰을 저장하고 현재 토큰으로 지정합니다.
+    async fn store_refresh_token(&self, record: &RefreshTokenRecord) -> Result<String, ApiError> {
+        let token = refresh_token::generate();
+        let token_hash = refresh_token::hash(&token);
+        let ttl = self.share.token_policy().refresh_ttl_seconds;
+        let record_json = serde_json::to_string(record)?;
+        let mut conn = self.connection_redis().await?;
+
+        redis::pipe()
+            .atomic()
+            .set_ex(self.keys().refresh_token(&token_hash), record_json, ttl)
+            .ignore()
+            .set_ex(
+                self.keys().refresh_token_family(&record.family),
+                token_hash.as_str(),
+                ttl,
+            )
+            .ignore()
+            .sadd(
+                self.keys().user_refresh_families(&record.user_id),
+                record.family.as_str(),
+            )
+            .ignore()
+            .expire(
+                self.keys().user_refresh_families(&record.user_id),
+                ttl as i64,
+            )
+            .ignore()
+            .query_async::<()>(&mut conn)
+            .await?;
+        Ok(token)
+    }
+
+    /// refresh 토큰 family 하나를 지우고 사용자의 family 인덱스에서도 뺍니다.
+    async fn delete_refresh_family(
+        &self,
+        conn: &mut redis::aio::MultiplexedConnection,
+        record: &RefreshTokenRecord,
+    ) -> Result<(), ApiError> {
+        redis::pipe()
+            .atomic()
+            .del(self.keys().refresh_token_family(&record.family))
+            .ignore()
+            .srem(
+                self.keys().user_refresh_families(&record.user_id),
+                record.family.as_str(),
+            )
+            .ignore()
+            .query_async::<()>(conn)
+            .await?;
+        Ok(())
+    }
+
+    /// 사용자의 refresh 토큰 family를 모두 지웁니다.
+    ///
+    /// 토큰 세대 비교에만 기대지 않고 family 자체를 없애, 같은 ID로 다시 만든 계정에서도
+    /// 예전 refresh 토큰을 쓸 수 없게 합니다.
+    async fn revoke_refresh_families(&self, user_id: &str) -> Result<(), ApiError> {
+        let mut conn = self.connection_redis().await?;
+        let index_key = self.keys().user_refresh_families(user_id);
+        let families = conn.smembers(&index_key).await?;
+        let mut pipe = redis::pipe();
+        pipe.atomic().del(&index_key).ignore();
+        for family in &families {
+            pipe.del(self.keys().refresh_token_family(family)).ignore();
+        }
+        pipe.query_async::<()>(&mut conn).await?;
+        Ok(())
+    }
 }
@@ -0,0 +1,453 @@
+//! 외부 발급자(사내 IdP 등)가 서명한 JWT를 사용자 인증에 받아들이기.
+//!
+//! 설정한 발급자마다 `issuer`, `audience`, JWKS 위치(URL 또는 파일), `user_id`로 쓸
+//! claim을 지정합니다. 토큰의 `iss`로 발급자를 고르고, 그 발급자의 공개 키로 서명과
+//! `iss`, `aud`, `exp`를 검증한 뒤 claim 값을 이 서버의 `user_id`로 씁니다.
+//! 공개 키는 인스턴스마다 메모리에 캐시하고 주기적으로, 또는 모르는 `kid`가 오면 다시 읽습니다.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{decode, decode_header, Algorithm, DecodingKey, Validation};
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+
+use crate::errors::ApiError;
+
+/// 발급자의 JWKS를 다시 읽는 주기.
+const KEY_CACHE: Duration = Duration::from_secs(10 * 60);
+
+/// 토큰에 모르는 `kid`가 있을 때 JWKS를 다시 읽는 최소 간격.
+const UNKNOWN_KID_RELOAD_INTERVAL: Duration = Duration::from_secs(30);
+
+/// JWKS 요청 제한 시간.
+const HTTP_TIMEOUT: Duration = Duration::from_secs(10);
+
+fn default_user_claim() -> String {
+    "sub".to_string()
+}
+
+/// 신뢰하는 외부 발급자 하나의 설정 (`trusted_issuers` 항목).
+///
+/// `jwks_url`과 `jwks_file` 중 하나만 지정합니다.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrustedIssuerConfig {
+    /// 토큰의 `iss`와 정확히 같아야 합니다.
+    pub issuer: String,
+    /// 토큰의 `aud`에 들어 있어야 하는 값.
+    pub audience: String,
+    /// 공개 키를 받을 JWKS URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jwks_url: Option<String>,
+    /// 공개 키를 읽을 JWKS 파일. 파일을 바꾸면 다음 갱신 때 반영됩니다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jwks_file: Option<PathBuf>,
+    /// `user_id`로 쓸 claim (기본 `sub`).
+    #[serde(default = "default_user_claim")]
+    pub user_claim: String,
+}
+
+impl TrustedIssuerConfig {
+    /// 필수 값과 JWKS 위치를 확인합니다.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.issuer.is_empty() {
+            return Err("issuer는 빈 문자열일 수 없습니다".to_string());
+        }
+        if self.issuer == crate::jwt::ISSUER {
+            return Err(format!(
+                "issuer {:?}는 이 서버가 발급하는 토큰에 쓰입니다",
+                self.issuer
+            ));
+        }
+        if self.audience.is_empty() {
+            return Err(format!("{}: audience가 필요합니다", self.issuer));
+        }
+        if self.user_claim.is_empty() {
+            return Err(format!(
+                "{}: user_claim은 빈 문자열일 수 없습니다",
+                self.issuer
+            ));
+        }
+        match (&self.jwks_url, &self.jwks_file) {
+            (Some(url), None) => url::Url::parse(url)
+                .map(|_| ())
+                .map_err(|e| format!("{}: jwks_url이 올바른 URL이 아닙니다: {}", self.issuer, e)),
+            (None, Some(_)) => Ok(()),
+            _ => Err(format!(
+                "{}: jwks_url과 jwks_file 중 하나만 지정해야 합니다",
+                self.issuer
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct ExternalClaims {
+    #[serde(default)]
+    sub: Option<String>,
+    #[serde(flatten)]
+    extra: serde_json::Map<String, serde_json::Value>,
+}
+
+fn unauthorized(reason: impl std::fmt::Display) -> ApiError {
+    tracing::warn!("External JWT rejected: {}", reason);
+    ApiError::Unauthorized(format!("Invalid JWT token: {}", reason))
+}
+
+/// 서명을 확인하기 전에 발급자를 고르려고 토큰의 `iss`만 읽습니다.
+fn unverified_issuer(token: &str) -> Option<String> {
+    #[derive(Deserialize)]
+    struct Issuer {
+        iss: Option<String>,
+    }
+
+    let payload = token.split('.').nth(1)?;
+    let payload = URL_SAFE_NO_PAD.decode(payload).ok()?;
+    serde_json::from_slice::<Issuer>(&payload).ok()?.iss
+}
+
+/// 토큰을 `jwks`로 검증하고 `user_claim` 값을 돌려줍니다.
+///
+/// 대칭키(HS*) 서명은 받지 않으며, `iss`, `aud`, `exp`를 모두 확인합니다.
+pub fn verify_external_token(
+    config: &TrustedIssuerConfig,
+    jwks: &JwkSet,
+    token: &str,
+) -> Result<String, ApiError> {
+    let header = decode_header(token).map_err(unauthorized)?;
+    if matches!(
+        header.alg,
+        Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512
+    ) {
+        return Err(unauthorized(format!(
+            "unsupported algorithm {:?}",
+            header.alg
+        )));
+    }
+    let jwk = match &header.kid {
+        Some(kid) => jwks.find(kid),
+        None => jwks.keys.first(),
+    }
+    .ok_or_else(|| unauthorized("unknown signing key"))?;
+    let decoding_key = DecodingKey::from_jwk(jwk).map_err(unauthorized)?;
+
+    let mut validation = Validation::new(header.alg);
+    validation.set_issuer(&[&config.issuer]);
+    validation.set_audience(&[&config.audience]);
+    validation.set_required_spec_claims(&["exp", "iss", "aud"]);
+    let claims = decode::<ExternalClaims>(token, &decoding_key, &validation)
+        .map_err(unauthorized)?
+        .claims;
+
+    match config.user_claim.as_str() {
+        "sub" => claims.sub,
+        claim => claims
+            .extra
+            .get(claim)
+            .and_then(|value| value.as_str())
+            .map(str::to_string),
+    }
+    .filter(|user_id| !user_id.is_empty())
+    .ok_or_else(|| unauthorized(format!("missing claim {}", config.user_claim)))
+}
+
+struct KeyCache {
+    jwks: JwkSet,
+    loaded_at: Instant,
+}
+
+/// 설정된 발급자 하나와 그 공개 키 캐시.
+struct TrustedIssuer {
+    config: TrustedIssuerConfig,
+    cache: RwLock<Option<Arc<KeyCache>>>,
+}
+
+impl TrustedIssuer {
+    async fn load(&self, http: &reqwest::Client) -> Result<JwkSet, String> {
+        match (&self.config.jwks_url, &self.config.jwks_file) {
+            (Some(url), _) => http
+                .get(url)
+                .send()
+                .await
+                .and_then(reqwest::Response::error_for_status)
+                .map_err(|e| e.to_string())?
+                .json()
+                .await
+                .map_err(|e| e.to_string()),
+            (None, Some(path)) => {
+                let bytes = tokio::fs::read(path)
+                    .await
+                    .map_err(|e| format!("{}: {}", path.display(), e))?;
+                serde_json::from_slice(&bytes).map_err(|e| format!("{}: {}", path.display(), e))
+            }
+            (None, None) => Err("no JWKS source".to_string()),
+        }
+    }
+
+    /// `max_age`보다 오래된 캐시는 JWKS를 다시 읽습니다.
+    ///
+    /// 다시 읽지 못하면 이전 키를 계속 쓰고, 처음 읽을 때 실패하면 502를 돌려줍니다.
+    async fn keys(
+        &self,
+        http: &reqwest::Client,
+        max_age: Duration,
+    ) -> Result<Arc<KeyCache>, ApiError> {
+        if let Some(cache) = self.cache.read().await.as_ref() {
+            if cache.loaded_at.elapsed() < max_age {
+                return Ok(cache.clone());
+            }
+        }
+
+        let mut guard = self.cache.write().await;
+        if let Some(cache) = guard.as_ref() {
+            if cache.loaded_at.elapsed() < max_age {
+                return Ok(cache.clone());
+            }
+        }
+
+        let jwks = match (self.load(http).await, guard.as_ref()) {
+            (Ok(jwks), _) => jwks,
+            (Err(e), Some(stale)) => {
+                tracing::warn!(
+                    "Failed to reload JWKS of {}, keeping previous keys: {}",
+                    self.config.issuer,
+                    e
+                );
+                stale.jwks.clone()
+            }
+            (Err(e), None) => {
+                tracing::error!("Failed to load JWKS of {}: {}", self.config.issuer, e);
+                return Err(ApiError::IdentityProvider(e));
+            }
+        };
+        let cache = Arc::new(KeyCache {
+            jwks,
+            loaded_at: Instant::now(),
+        });
+        *guard = Some(cache.clone());
+        Ok(cache)
+    }
+
+    async fn verify(&self, http: &reqwest::Client, token: &str) -> Result<String, ApiError> {
+        let mut cache = self.keys(http, KEY_CACHE).await?;
+        if let Ok(jsonwebtoken::Header { kid: Some(kid), .. }) = decode_header(token) {
+            if cache.jwks.find(&kid).is_none() {
+                cache = self.keys(http, UNKNOWN_KID_RELOAD_INTERVAL).await?;
+            }
+        }
+        verify_external_token(&self.config, &cache.jwks, token)
+    }
+}
+
+/// 신뢰하는 외부 발급자 목록. 비어 있으면 이 서버가 서명한 토큰만 받습니다.
+///
+/// # Thread Safety
+/// 발급자별 키 캐시는 모든 clone이 함께 씁니다.
+#[derive(Clone, Default)]
+pub struct TrustedIssuers {
+    issuers: Arc<Vec<TrustedIssuer>>,
+    http: reqwest::Client,
+}
+
+impl std::fmt::Debug for TrustedIssuers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.issuers.iter().map(|issuer| &issuer.config.issuer))
+            .finish()
+    }
+}
+
+impl TrustedIssuers {
+    pub fn new(configs: Vec<TrustedIssuerConfig>) -> Result<Self, ApiError> {
+        let http = reqwest::Client::builder()
+            .timeout(HTTP_TIMEOUT)
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .map_err(|e| ApiError::InternalError(e.to_string()))?;
+        let issuers = configs
+            .into_iter()
+            .map(|config| TrustedIssuer {
+                config,
+                cache: RwLock::new(None),
+            })
+            .collect();
+        Ok(Self {
+            issuers: Arc::new(issuers),
+            http,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.issuers.is_empty()
+    }
+
+    /// 토큰이 설정된 외부 발급자의 것이면 검증하고 `user_id`를 돌려줍니다.
+    ///
+    /// `iss`가 어느 외부 발급자와도 같지 않으면 `Ok(None)`이며, 이 서버의 키로
+    /// 검증해야 하는 토큰입니다.
+    ///
+    /// # Errors
+    /// 서명, `aud`, `exp` 검증에 실패하거나 `user_claim`이 없으면 `ApiError::Unauthorized` (401),
+    /// 발급자의 JWKS를 한 번도 읽지 못했으면 `ApiError::IdentityProvider` (502).
+    pub async fn verify(&self, token: &str) -> Result<Option<String>, ApiError> {
+        if self.issuers.is_empty() {
+            return Ok(None);
+        }
+        let Some(iss) = unverified_issuer(token) else {
+            return Ok(None);
+        };
+        match self
+            .issuers
+            .iter()
+            .find(|issuer| issuer.config.issuer == iss)
+        {
+            Some(issuer) => issuer.verify(&self.http, token).await.map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signing_key::{self, SigningAlgorithm};
+    use serde_json::json;
+
+    const ISSUER: &str = "https://idp.example.com";
+
+    fn config() -> TrustedIssuerConfig {
+        TrustedIssuerConfig {
+            issuer: ISSUER.to_string(),
+            audience: "realtime-svg".to_string(),
+            jwks_url: None,
+            jwks_file: Some(PathBuf::from("/dev/null")),
+            user_claim: "preferred_username".to_string(),
+        }
+    }
+
+    fn sign(claims: serde_json::Value) -> (JwkSet, String) {
+        let stored = signing_key::generate(SigningAlgorithm::ES256).unwrap();
+        let signing_key = stored.signing_key().unwrap();
+        let mut header = jsonwebtoken::Header::new(signing_key.algorithm);
+        header.kid = Some(signing_key.kid.clone());
+        let token = jsonwebtoken::encode(&header, &claims, &signing_key.encoding_key).unwrap();
+        let jwks = JwkSet {
+            keys: vec![stored.public_key().to_jwk().unwrap()],
+        };
+        (jwks, token)
+    }
+
+    fn claims() -> serde_json::Value {
+        json!({
+            "iss": ISSUER,
+            "aud": "realtime-svg",
+            "sub": "00u1abcd",
+            "exp": chrono::Utc::now().timestamp() + 300,
+            "preferred_username": "alice",
+        })
+    }
+
+    #[test]
+    fn test_verify_external_token_maps_user_claim() {
+        let (jwks, token) = sign(claims());
+        assert_eq!(
+            verify_external_token(&config(), &jwks, &token).unwrap(),
+            "alice"
+        );
+
+        let config = TrustedIssuerConfig {
+            user_claim: "sub".to_string(),
+            ..config()
+        };
+        assert_eq!(
+            verify_external_token(&config, &jwks, &token).unwrap(),
+            "00u1abcd"
+        );
+    }
+
+    #[test]
+    fn test_verify_external_token_rejects_bad_tokens() {
+        let mut wrong_audience = claims();
+        wrong_audience["aud"] = json!("other-service");
+        let mut expired = claims();
+        expired["exp"] = json!(chrono::Utc::now().timestamp() - 3600);
+        let mut other_issuer = claims();
+        other_issuer["iss"] = json!("https://evil.example.com");
+        let mut no_user = claims();
+        no_user
+            .as_object_mut()
+            .unwrap()
+            .remove("preferred_username");
+
+        for claims in [wrong_audience, expired, other_issuer, no_user] {
+            let (jwks, token) = sign(claims);
+            assert!(matches!(
+                verify_external_token(&config(), &jwks, &token),
+                Err(ApiError::Unauthorized(_))
+            ));
+        }
+
+        // 다른 키로 서명한 토큰
+        let (jwks, _) = sign(claims());
+        let (_, token) = sign(claims());
+        assert!(verify_external_token(&config(), &jwks, &token).is_err());
+    }
+
+    #[test]
+    fn test_unverified_issuer_reads_payload() {
+        let (_, token) = sign(claims());
+        assert_eq!(unverified_issuer(&token).as_deref(), Some(ISSUER));
+        assert_eq!(unverified_issuer("not-a-jwt"), None);
+    }
+
+    #[test]
+    fn test_config_requires_one_jwks_source() {
+        assert!(config().validate().is_ok());
+
+        let both = TrustedIssuerConfig {
+            jwks_url: Some("https://idp.example.com/jwks".to_string()),
+            ..config()
+        };
+        assert!(both.validate().is_err());
+
+        let neither = TrustedIssuerConfig {
+            jwks_file: None,
+            ..config()
+        };
+        assert!(neither.validate().is_err());
+
+        let own_issuer = TrustedIssuerConfig {
+            issuer: crate::jwt::ISSUER.to_string(),
+            ..config()
+        };
+        assert!(own_issuer.validate().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_trusted_issuers_reads_jwks_file() {
+        let (jwks, token) = sign(claims());
+        let path = std::env::temp_dir().join(format!("jwks-{}.json", uuid::Uuid::new_v4()));
+        std::fs::write(&path, serde_json::to_vec(&jwks).unwrap()).unwrap();
+
+        let issuers = TrustedIssuers::new(vec![TrustedIssuerConfig {
+            jwks_file: Some(path.clone()),
+            ..config()
+        }])
+        .unwrap();
+        assert_eq!(
+            issuers.verify(&token).await.unwrap().as_deref(),
+            Some("alice")
+        );
+
+        // 이 서버가 발급한 토큰처럼 다른 `iss`는 건드리지 않습니다.
+        let mut own = claims();
+        own["iss"] = json!(crate::jwt::ISSUER);
+        let (_, own_token) = sign(own);
+        assert_eq!(issuers.verify(&own_token).await.unwrap(), None);
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
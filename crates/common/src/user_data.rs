@@ -1,25 +1,256 @@
-
-use argon2::{password_hash::SaltString, Argon2, PasswordHasher};
+use argon2::{
+    password_hash::{PasswordHash, SaltString},
+    Algorithm, Argon2, Params, PasswordHasher, Version,
+};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::errors::ApiError;
 
+/// 가입 가능한 사용자 이름의 길이 범위.
+pub const MIN_USERNAME_LEN: usize = 3;
+pub const MAX_USERNAME_LEN: usize = 64;
+
+/// 경로에서 사용자 ID 자리에 오는 고정 경로 이름 (`/stream/org/...`). 가입에 쓸 수 없습니다.
+pub const RESERVED_USERNAMES: [&str; 1] = ["org"];
+
+/// 비밀번호 길이 범위 (문자 수).
+pub const MIN_PASSWORD_LEN: usize = 8;
+pub const MAX_PASSWORD_LEN: usize = 128;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct UserData {
+    /// 비밀번호 해시. SSO로 만든 계정은 비어 있어 비밀번호로 로그인할 수 없습니다.
     pub password_argon2: String,
+    /// 가입 시각. 가입 API 이전에 만들어진 계정에는 없습니다.
+    #[serde(default)]
+    pub created_at: Option<DateTime<Utc>>,
+    /// 마지막으로 토큰을 발급받은 시각.
+    #[serde(default)]
+    pub last_login_at: Option<DateTime<Utc>>,
+    /// 발급된 토큰의 `gen` 클레임과 비교합니다. 올리면 기존 토큰이 모두 무효가 됩니다.
+    #[serde(default)]
+    pub token_generation: u64,
+    /// 관리자 계정 여부. 로그인 토큰의 `admin` claim으로도 실립니다.
+    #[serde(default)]
+    pub admin: bool,
+    /// 관리자가 계정을 비활성화한 시각. 비활성화된 계정은 로그인할 수 없고 API 키도 쓸 수 없습니다.
+    #[serde(default)]
+    pub disabled_at: Option<DateTime<Utc>>,
+    /// SSO로 로그인하는 계정에 연결된 OIDC 계정 (`{issuer}#{sub}`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oidc_subject: Option<String>,
 }
 
 impl UserData {
-    pub fn create(
-        argon: &Argon2<'_>,
-        salt: &SaltString,
-        password_argon2: impl Into<String>,
-    ) -> Result<Self, ApiError> {
-        let password_hash = argon
-            .hash_password(password_argon2.into().as_bytes(), salt)?
-            .to_string();
+    pub fn create(argon: &Argon2<'_>, password: impl AsRef<str>) -> Result<Self, ApiError> {
         Ok(Self {
-            password_argon2: password_hash,
+            password_argon2: hash_password(argon, password)?,
+            created_at: Some(Utc::now()),
+            last_login_at: None,
+            token_generation: 0,
+            admin: false,
+            disabled_at: None,
+            oidc_subject: None,
         })
     }
+
+    /// SSO 로그인으로 처음 만들어지는 계정. 비밀번호가 없습니다.
+    pub fn create_external(oidc_subject: impl Into<String>) -> Self {
+        Self {
+            password_argon2: String::new(),
+            created_at: Some(Utc::now()),
+            last_login_at: None,
+            token_generation: 0,
+            admin: false,
+            disabled_at: None,
+            oidc_subject: Some(oidc_subject.into()),
+        }
+    }
+
+    pub fn has_password(&self) -> bool {
+        !self.password_argon2.is_empty()
+    }
+
+    pub fn is_disabled(&self) -> bool {
+        self.disabled_at.is_some()
+    }
+
+    /// 저장된 해시가 현재 정책보다 약하면 `true`를 반환합니다.
+    ///
+    /// Argon2id가 아니거나, 버전이 오래되었거나, 메모리/반복/병렬 비용 중 하나라도
+    /// `policy`보다 낮거나, 예전 전역 salt(`legacy_salt`)를 쓰는 해시가 대상입니다.
+    pub fn needs_rehash(&self, policy: &Params, legacy_salt: Option<&str>) -> bool {
+        let Ok(hash) = PasswordHash::new(&self.password_argon2) else {
+            return true;
+        };
+        if Algorithm::try_from(hash.algorithm) != Ok(Algorithm::Argon2id)
+            || hash.version != Some(Version::V0x13.into())
+        {
+            return true;
+        }
+        if legacy_salt.is_some() && hash.salt.map(|salt| salt.as_str()) == legacy_salt {
+            return true;
+        }
+        match Params::try_from(&hash) {
+            Ok(params) => {
+                params.m_cost() < policy.m_cost()
+                    || params.t_cost() < policy.t_cost()
+                    || params.p_cost() < policy.p_cost()
+            }
+            Err(_) => true,
+        }
+    }
+}
+
+/// 비밀번호마다 새 salt를 만들어 PHC 문자열로 해시합니다.
+pub fn hash_password(argon: &Argon2<'_>, password: impl AsRef<str>) -> Result<String, ApiError> {
+    let salt = SaltString::generate(&mut rand::thread_rng());
+    Ok(argon
+        .hash_password(password.as_ref().as_bytes(), &salt)?
+        .to_string())
+}
+
+/// 가입 시 사용자 이름 규칙.
+///
+/// Redis 키에 쓸 수 있는 문자([`crate::keys::validate_user_id`])에 더해, 3~64자이고
+/// 영문이나 숫자로 시작해야 합니다. [`RESERVED_USERNAMES`]는 쓸 수 없습니다.
+pub fn validate_username(user_id: &str) -> Result<(), ApiError> {
+    crate::keys::validate_user_id(user_id)?;
+
+    let len = user_id.chars().count();
+    if !(MIN_USERNAME_LEN..=MAX_USERNAME_LEN).contains(&len) {
+        return Err(ApiError::InvalidUsername(format!(
+            "사용자 이름은 {}~{}자여야 합니다",
+            MIN_USERNAME_LEN, MAX_USERNAME_LEN
+        )));
+    }
+    if !user_id.starts_with(|c: char| c.is_ascii_alphanumeric()) {
+        return Err(ApiError::InvalidUsername(
+            "사용자 이름은 영문이나 숫자로 시작해야 합니다".to_string(),
+        ));
+    }
+    if RESERVED_USERNAMES.contains(&user_id) {
+        return Err(ApiError::InvalidUsername(format!(
+            "{user_id}은(는) 예약된 이름이라 사용할 수 없습니다"
+        )));
+    }
+    Ok(())
+}
+
+/// 비밀번호 정책: 8~128자, 공백으로만 이루어질 수 없고 사용자 이름과 같을 수 없습니다.
+pub fn validate_password(user_id: &str, password: &str) -> Result<(), ApiError> {
+    let len = password.chars().count();
+    if !(MIN_PASSWORD_LEN..=MAX_PASSWORD_LEN).contains(&len) {
+        return Err(ApiError::WeakPassword(format!(
+            "비밀번호는 {}~{}자여야 합니다",
+            MIN_PASSWORD_LEN, MAX_PASSWORD_LEN
+        )));
+    }
+    if password.trim().is_empty() {
+        return Err(ApiError::WeakPassword(
+            "비밀번호는 공백으로만 이루어질 수 없습니다".to_string(),
+        ));
+    }
+    if password.eq_ignore_ascii_case(user_id) {
+        return Err(ApiError::WeakPassword(
+            "비밀번호는 사용자 이름과 같을 수 없습니다".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_username_rules() {
+        assert!(validate_username("alice").is_ok());
+        assert!(validate_username("user_123-x.y").is_ok());
+
+        for user_id in [
+            "ab",
+            "_alice",
+            ".hidden",
+            "alice:data",
+            "org",
+            &"a".repeat(65),
+        ] {
+            assert!(validate_username(user_id).is_err(), "{user_id}");
+        }
+    }
+
+    #[test]
+    fn test_password_policy() {
+        assert!(validate_password("alice", "correct horse").is_ok());
+
+        assert!(validate_password("alice", "short").is_err());
+        assert!(validate_password("alice", "        ").is_err());
+        assert!(validate_password("alice12345", "ALICE12345").is_err());
+        assert!(validate_password("alice", &"x".repeat(MAX_PASSWORD_LEN + 1)).is_err());
+    }
+
+    fn argon(m_cost: u32, t_cost: u32) -> Argon2<'static> {
+        let params = Params::new(m_cost, t_cost, 1, None).unwrap();
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+    }
+
+    #[test]
+    fn test_same_password_gets_distinct_hashes() {
+        let argon = argon(Params::MIN_M_COST, 1);
+        let a = UserData::create(&argon, "same-password").unwrap();
+        let b = UserData::create(&argon, "same-password").unwrap();
+        assert_ne!(a.password_argon2, b.password_argon2);
+    }
+
+    #[test]
+    fn test_needs_rehash_when_policy_is_stronger() {
+        let weak = argon(Params::MIN_M_COST, 1);
+        let user_data = UserData::create(&weak, "password-1").unwrap();
+
+        assert!(!user_data.needs_rehash(weak.params(), None));
+        assert!(user_data.needs_rehash(argon(Params::MIN_M_COST * 2, 1).params(), None));
+        assert!(user_data.needs_rehash(argon(Params::MIN_M_COST, 2).params(), None));
+    }
+
+    #[test]
+    fn test_needs_rehash_for_legacy_shared_salt() {
+        let argon = argon(Params::MIN_M_COST, 1);
+        let legacy_salt = SaltString::generate(&mut rand::thread_rng());
+        let user_data = UserData {
+            password_argon2: argon
+                .hash_password(b"password-1", &legacy_salt)
+                .unwrap()
+                .to_string(),
+            created_at: None,
+            last_login_at: None,
+            token_generation: 0,
+            admin: false,
+            disabled_at: None,
+            oidc_subject: None,
+        };
+
+        assert!(user_data.needs_rehash(argon.params(), Some(legacy_salt.as_str())));
+        assert!(!user_data.needs_rehash(argon.params(), Some("other-salt")));
+    }
+
+    #[test]
+    fn test_legacy_user_data_deserializes() {
+        let user_data: UserData = serde_json::from_str(r#"{"password_argon2":"hash"}"#).unwrap();
+        assert_eq!(user_data.created_at, None);
+        assert_eq!(user_data.last_login_at, None);
+        assert!(!user_data.admin);
+        assert!(!user_data.is_disabled());
+        assert!(user_data.has_password());
+        assert_eq!(user_data.oidc_subject, None);
+    }
+
+    #[test]
+    fn test_external_account_has_no_password() {
+        let user_data = UserData::create_external("https://idp.example.com#42");
+        assert!(!user_data.has_password());
+        let json = serde_json::to_string(&user_data).unwrap();
+        assert_eq!(serde_json::from_str::<UserData>(&json).unwrap(), user_data);
+    }
 }
@@ -0,0 +1,60 @@
+use crate::api::auth::error_message;
+use crate::api::AuthenticatedRequest;
+use crate::types::TokenResponse;
+
+#[derive(serde::Serialize)]
+struct PasswordChangeRequest {
+    old_password: String,
+    new_password: String,
+}
+
+/// 비밀번호를 바꾸고 새로 발급된 토큰을 반환합니다. 기존 토큰은 더 이상 쓸 수 없습니다.
+pub async fn change_password(
+    old_password: String,
+    new_password: String,
+) -> Result<TokenResponse, String> {
+    let auth_req = AuthenticatedRequest::new();
+    let request_body = PasswordChangeRequest {
+        old_password,
+        new_password,
+    };
+
+    let response = auth_req
+        .post("/api/auth/password")
+        .json(&request_body)
+        .map_err(|e| format!("요청 생성 실패: {}", e))?
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        200 => response
+            .json()
+            .await
+            .map_err(|e| format!("응답 파싱 실패: {}", e)),
+        400 => Err(error_message(response)
+            .await
+            .unwrap_or_else(|| "새 비밀번호가 규칙에 맞지 않습니다".to_string())),
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("현재 비밀번호가 올바르지 않습니다".to_string()),
+        status => Err(format!("비밀번호 변경 실패 ({})", status)),
+    }
+}
+
+/// 계정과 모든 세션을 삭제합니다.
+pub async fn delete_account(user_id: &str) -> Result<(), String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .delete(&format!("/api/user/{}", user_id))
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        204 => Ok(()),
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("권한이 없습니다".to_string()),
+        status => Err(format!("계정 삭제 실패 ({})", status)),
+    }
+}
@@ -0,0 +1,129 @@
+use crate::api::auth::error_message;
+use crate::api::AuthenticatedRequest;
+use crate::types::{
+    AdminSessionItem, AdminSessionListResponse, AdminUserItem, AdminUserListResponse,
+    AdminUserUpdate, SystemStats,
+};
+
+/// 관리자 API에서 세션을 가리키는 경로 (`public`, `user/{id}`, `org/{id}`)
+fn session_path(item: &AdminSessionItem) -> String {
+    match (&item.user_id, &item.org_id) {
+        (Some(user_id), _) => format!("user/{}/{}", user_id, item.session_id),
+        (None, Some(org_id)) => format!("org/{}/{}", org_id, item.session_id),
+        (None, None) => format!("public/{}", item.session_id),
+    }
+}
+
+pub async fn get_stats() -> Result<SystemStats, String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .get("/api/admin/stats")
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        200 => response
+            .json()
+            .await
+            .map_err(|e| format!("응답 파싱 실패: {}", e)),
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("관리자만 볼 수 있습니다".to_string()),
+        status => Err(format!("통계 조회 실패 ({})", status)),
+    }
+}
+
+pub async fn list_users(q: &str) -> Result<Vec<AdminUserItem>, String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .get("/api/admin/user")
+        .query([("q", q.trim())])
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let list: AdminUserListResponse = response
+                .json()
+                .await
+                .map_err(|e| format!("응답 파싱 실패: {}", e))?;
+            Ok(list.items)
+        }
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("관리자만 볼 수 있습니다".to_string()),
+        status => Err(format!("사용자 목록 조회 실패 ({})", status)),
+    }
+}
+
+/// 계정의 관리자 역할이나 비활성화 상태를 바꿉니다.
+pub async fn update_user(user_id: &str, request: AdminUserUpdate) -> Result<AdminUserItem, String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .put(&format!("/api/admin/user/{}", user_id))
+        .json(&request)
+        .map_err(|e| format!("요청 생성 실패: {}", e))?
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        200 => response
+            .json()
+            .await
+            .map_err(|e| format!("응답 파싱 실패: {}", e)),
+        400 => Err(error_message(response)
+            .await
+            .unwrap_or_else(|| "입력값이 올바르지 않습니다".to_string())),
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("관리자만 바꿀 수 있습니다".to_string()),
+        404 => Err("존재하지 않는 사용자입니다".to_string()),
+        status => Err(format!("계정 변경 실패 ({})", status)),
+    }
+}
+
+pub async fn list_sessions(q: &str) -> Result<Vec<AdminSessionItem>, String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .get("/api/admin/session")
+        .query([("q", q.trim()), ("sort", "updated"), ("order", "desc")])
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let list: AdminSessionListResponse = response
+                .json()
+                .await
+                .map_err(|e| format!("응답 파싱 실패: {}", e))?;
+            Ok(list.items)
+        }
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("관리자만 볼 수 있습니다".to_string()),
+        status => Err(format!("세션 목록 조회 실패 ({})", status)),
+    }
+}
+
+/// 세션을 강제로 지웁니다. 열린 스트림도 함께 끊깁니다.
+pub async fn delete_session(item: &AdminSessionItem) -> Result<(), String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .delete(&format!("/api/admin/session/{}", session_path(item)))
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        204 => Ok(()),
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("관리자만 지울 수 있습니다".to_string()),
+        404 => Err("이미 삭제된 세션입니다".to_string()),
+        status => Err(format!("세션 삭제 실패 ({})", status)),
+    }
+}
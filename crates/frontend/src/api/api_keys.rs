@@ -0,0 +1,73 @@
+use crate::api::auth::error_message;
+use crate::api::AuthenticatedRequest;
+use crate::types::{ApiKeyCreateRequest, ApiKeyCreateResponse, ApiKeyItem, ApiKeyListResponse};
+
+pub async fn list_api_keys(user_id: &str) -> Result<Vec<ApiKeyItem>, String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .get(&format!("/api/user/{}/api-key", user_id))
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let list: ApiKeyListResponse = response
+                .json()
+                .await
+                .map_err(|e| format!("응답 파싱 실패: {}", e))?;
+            Ok(list.items)
+        }
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("권한이 없습니다".to_string()),
+        status => Err(format!("API 키 목록 조회 실패 ({})", status)),
+    }
+}
+
+/// 새 API 키를 만듭니다. 응답의 키 원문은 다시 조회할 수 없습니다.
+pub async fn create_api_key(
+    user_id: &str,
+    request: ApiKeyCreateRequest,
+) -> Result<ApiKeyCreateResponse, String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .post(&format!("/api/user/{}/api-key", user_id))
+        .json(&request)
+        .map_err(|e| format!("요청 생성 실패: {}", e))?
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        201 => response
+            .json()
+            .await
+            .map_err(|e| format!("응답 파싱 실패: {}", e)),
+        400 => Err(error_message(response)
+            .await
+            .unwrap_or_else(|| "입력값이 올바르지 않습니다".to_string())),
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("권한이 없습니다".to_string()),
+        status => Err(format!("API 키 생성 실패 ({})", status)),
+    }
+}
+
+pub async fn revoke_api_key(user_id: &str, key_id: &str) -> Result<(), String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .delete(&format!("/api/user/{}/api-key/{}", user_id, key_id))
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        204 => Ok(()),
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("권한이 없습니다".to_string()),
+        404 => Err("이미 폐기된 API 키입니다".to_string()),
+        status => Err(format!("API 키 폐기 실패 ({})", status)),
+    }
+}
@@ -1,26 +1,43 @@
-use crate::types::TokenResponse;
+use crate::api::AuthenticatedRequest;
+use crate::auth::storage::{token_storage, CookieTokenStorage, TokenStorage};
+use crate::types::{CookieSession, TokenResponse};
 use gloo_net::http::Request;
 
 #[derive(serde::Serialize)]
-struct TokenRequest {
+struct RegisterRequest {
     user_id: String,
     password: String,
+}
+
+#[derive(serde::Serialize)]
+struct LogoutRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
-    ttl_seconds: Option<u64>,
+    refresh_token: Option<String>,
+    all: bool,
 }
 
-pub async fn request_token(
+#[derive(serde::Serialize)]
+struct RefreshRequest {
+    refresh_token: String,
+}
+
+#[derive(serde::Serialize)]
+struct TokenRequest {
     user_id: String,
     password: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     ttl_seconds: Option<u64>,
-) -> Result<String, String> {
+}
+
+/// 비밀번호로 로그인하고 토큰을 HttpOnly 쿠키로 받습니다.
+pub async fn login_with_cookie(user_id: String, password: String) -> Result<CookieSession, String> {
     let request_body = TokenRequest {
         user_id,
         password,
-        ttl_seconds,
+        ttl_seconds: None,
     };
 
-    let response = Request::post("/api/auth/token")
+    let response = Request::post("/api/auth/cookie")
         .header("Content-Type", "application/json")
         .json(&request_body)
         .map_err(|e| format!("요청 생성 실패: {}", e))?
@@ -28,15 +45,174 @@ pub async fn request_token(
         .await
         .map_err(|e| format!("네트워크 오류: {}", e))?;
 
-    if response.ok() {
-        let token_response: TokenResponse = response
+    match response.status() {
+        200 => response
+            .json()
+            .await
+            .map_err(|e| format!("응답 파싱 실패: {}", e)),
+        401 => Err("사용자 ID 또는 비밀번호가 올바르지 않습니다".to_string()),
+        403 => Err(error_message(response)
+            .await
+            .unwrap_or_else(|| "로그인할 수 없는 계정입니다".to_string())),
+        429 => Err(match response.headers().get("retry-after") {
+            Some(seconds) => format!(
+                "로그인 시도가 너무 많습니다. {}초 후에 다시 시도하세요",
+                seconds
+            ),
+            None => "로그인 시도가 너무 많습니다. 잠시 후에 다시 시도하세요".to_string(),
+        }),
+        status => Err(format!("로그인 실패 ({})", status)),
+    }
+}
+
+/// 쿠키로 로그인한 사용자 정보를 확인합니다. 로그인 쿠키가 없거나 만료되었으면 실패합니다.
+pub async fn cookie_session() -> Result<CookieSession, String> {
+    let response = Request::get("/api/auth/cookie")
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        200 => response
+            .json()
+            .await
+            .map_err(|e| format!("응답 파싱 실패: {}", e)),
+        status => Err(format!("로그인 확인 실패 ({})", status)),
+    }
+}
+
+/// refresh 토큰 쿠키로 로그인 쿠키를 갱신합니다.
+pub async fn refresh_cookie() -> Result<CookieSession, String> {
+    let csrf_token = CookieTokenStorage::new()
+        .csrf_token()
+        .ok_or("쿠키 로그인이 아닙니다")?;
+    let response = Request::post("/api/auth/cookie/refresh")
+        .header("X-CSRF-Token", &csrf_token)
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        200 => response
             .json()
             .await
-            .map_err(|e| format!("응답 파싱 실패: {}", e))?;
+            .map_err(|e| format!("응답 파싱 실패: {}", e)),
+        401 => Err("로그인이 만료되었습니다. 다시 로그인하세요".to_string()),
+        status => Err(format!("토큰 갱신 실패 ({})", status)),
+    }
+}
 
-        Ok(token_response.token)
-    } else {
-        let status = response.status();
-        Err(format!("토큰 발급 실패 ({})", status))
+/// refresh 토큰으로 새 access 토큰을 받습니다. 사용한 refresh 토큰은 더 이상 쓸 수 없습니다.
+pub async fn refresh(refresh_token: String) -> Result<TokenResponse, String> {
+    let response = Request::post("/api/auth/refresh")
+        .header("Content-Type", "application/json")
+        .json(&RefreshRequest { refresh_token })
+        .map_err(|e| format!("요청 생성 실패: {}", e))?
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        200 => response
+            .json()
+            .await
+            .map_err(|e| format!("응답 파싱 실패: {}", e)),
+        401 => Err("로그인이 만료되었습니다. 다시 로그인하세요".to_string()),
+        status => Err(format!("토큰 갱신 실패 ({})", status)),
     }
 }
+
+/// 서버에서 현재 토큰과 refresh 토큰을 폐기합니다. `all`이면 모든 기기에서 로그아웃합니다.
+///
+/// 쿠키 로그인이면 서버가 로그인 쿠키도 지웁니다. localStorage에 저장된 토큰은
+/// 지우지 않으므로 호출한 쪽에서 정리해야 합니다.
+pub async fn logout(all: bool) -> Result<(), String> {
+    let storage = token_storage();
+    if all || storage.get_token().is_some() {
+        revoke_tokens(storage.get_refresh_token(), all).await?;
+    }
+    end_cookie_login().await
+}
+
+/// 쿠키 로그인의 refresh 토큰을 폐기하고 쿠키를 지웁니다. access 토큰이 만료되었어도 동작합니다.
+///
+/// 쿠키 로그인이 아니면 아무것도 하지 않습니다.
+pub async fn end_cookie_login() -> Result<(), String> {
+    if CookieTokenStorage::new().csrf_token().is_none() {
+        return Ok(());
+    }
+    let response = AuthenticatedRequest::new()
+        .delete("/api/auth/cookie")
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        204 => Ok(()),
+        status => Err(format!("로그아웃 실패 ({})", status)),
+    }
+}
+
+async fn revoke_tokens(refresh_token: Option<String>, all: bool) -> Result<(), String> {
+    let request_body = LogoutRequest { refresh_token, all };
+
+    let response = AuthenticatedRequest::new()
+        .post("/api/auth/logout")
+        .json(&request_body)
+        .map_err(|e| format!("요청 생성 실패: {}", e))?
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        204 => Ok(()),
+        status => Err(format!("로그아웃 실패 ({})", status)),
+    }
+}
+
+pub async fn register(user_id: String, password: String) -> Result<(), String> {
+    let request_body = RegisterRequest { user_id, password };
+
+    let response = Request::post("/api/auth/register")
+        .header("Content-Type", "application/json")
+        .json(&request_body)
+        .map_err(|e| format!("요청 생성 실패: {}", e))?
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    if response.ok() {
+        return Ok(());
+    }
+
+    let status = response.status();
+    match status {
+        400 => Err(error_message(response)
+            .await
+            .unwrap_or_else(|| "사용자 이름 또는 비밀번호가 규칙에 맞지 않습니다".to_string())),
+        409 => Err("이미 사용 중인 사용자 ID입니다".to_string()),
+        _ => Err(format!("가입 실패 ({})", status)),
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct OidcStatus {
+    enabled: bool,
+}
+
+/// 서버에 SSO 로그인이 설정되어 있는지 확인합니다. 확인할 수 없으면 꺼진 것으로 봅니다.
+pub async fn oidc_enabled() -> bool {
+    let Ok(response) = Request::get("/api/auth/oidc").send().await else {
+        return false;
+    };
+    response
+        .json::<OidcStatus>()
+        .await
+        .is_ok_and(|status| status.enabled)
+}
+
+/// 서버의 `{"error": "..."}` 응답 본문에서 메시지를 꺼냅니다.
+pub(crate) async fn error_message(response: gloo_net::http::Response) -> Option<String> {
+    let body: serde_json::Value = response.json().await.ok()?;
+    body["error"].as_str().map(str::to_string)
+}
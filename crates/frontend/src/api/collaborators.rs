@@ -0,0 +1,122 @@
+use crate::api::auth::error_message;
+use crate::api::AuthenticatedRequest;
+use crate::types::{
+    CollaboratorItem, CollaboratorListResponse, CollaboratorRequest, SessionListQuery,
+    SessionListResponse, SessionRole,
+};
+
+fn collaborators_path(user_id: &str, session_id: &str) -> String {
+    format!("/api/user/{}/session/{}/collaborator", user_id, session_id)
+}
+
+pub async fn list_collaborators(
+    user_id: &str,
+    session_id: &str,
+) -> Result<Vec<CollaboratorItem>, String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .get(&collaborators_path(user_id, session_id))
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let list: CollaboratorListResponse = response
+                .json()
+                .await
+                .map_err(|e| format!("응답 파싱 실패: {}", e))?;
+            Ok(list.items)
+        }
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("권한이 없습니다".to_string()),
+        404 => Err("세션을 찾을 수 없습니다".to_string()),
+        status => Err(format!("협업자 목록 조회 실패 ({})", status)),
+    }
+}
+
+/// 협업자를 추가하거나 역할을 바꿉니다.
+pub async fn set_collaborator(
+    user_id: &str,
+    session_id: &str,
+    collaborator_id: &str,
+    role: SessionRole,
+) -> Result<(), String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .put(&format!(
+            "{}/{}",
+            collaborators_path(user_id, session_id),
+            collaborator_id
+        ))
+        .json(&CollaboratorRequest { role })
+        .map_err(|e| format!("요청 생성 실패: {}", e))?
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        204 => Ok(()),
+        400 | 404 => Err(error_message(response)
+            .await
+            .unwrap_or_else(|| "입력값이 올바르지 않습니다".to_string())),
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("권한이 없습니다".to_string()),
+        status => Err(format!("협업자 추가 실패 ({})", status)),
+    }
+}
+
+pub async fn remove_collaborator(
+    user_id: &str,
+    session_id: &str,
+    collaborator_id: &str,
+) -> Result<(), String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .delete(&format!(
+            "{}/{}",
+            collaborators_path(user_id, session_id),
+            collaborator_id
+        ))
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        204 => Ok(()),
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("권한이 없습니다".to_string()),
+        404 => Err("이미 제거된 협업자입니다".to_string()),
+        status => Err(format!("협업자 제거 실패 ({})", status)),
+    }
+}
+
+/// 다른 사용자가 협업자로 추가해 준 세션 목록
+pub async fn list_shared_sessions(
+    user_id: &str,
+    query: &SessionListQuery,
+) -> Result<SessionListResponse, String> {
+    let auth_req = AuthenticatedRequest::new();
+    let pairs = query.to_pairs();
+
+    let response = auth_req
+        .get(&format!("/api/user/{}/shared", user_id))
+        .query(pairs.iter().map(|(k, v)| (*k, v.as_str())))
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        200 => response
+            .json()
+            .await
+            .map_err(|e| format!("응답 파싱 실패: {}", e)),
+        400 => Err("잘못된 목록 조회 요청입니다".to_string()),
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("권한이 없습니다".to_string()),
+        status => Err(format!("공유받은 세션 조회 실패 ({})", status)),
+    }
+}
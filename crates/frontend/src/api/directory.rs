@@ -0,0 +1,24 @@
+use crate::types::{DirectoryQuery, DirectoryResponse};
+use gloo_net::http::Request;
+
+pub async fn list_directory(query: &DirectoryQuery) -> Result<DirectoryResponse, String> {
+    let pairs = query.to_pairs();
+
+    let response = Request::get("/api/directory")
+        .query(pairs.iter().map(|(k, v)| (*k, v.as_str())))
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let directory: DirectoryResponse = response
+                .json()
+                .await
+                .map_err(|e| format!("응답 파싱 실패: {}", e))?;
+            Ok(directory)
+        }
+        400 => Err("잘못된 검색 요청입니다".to_string()),
+        status => Err(format!("디렉터리 조회 실패 ({})", status)),
+    }
+}
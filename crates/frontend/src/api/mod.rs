@@ -1,50 +1,60 @@
+pub mod account;
+pub mod admin;
+pub mod api_keys;
 pub mod auth;
+pub mod collaborators;
+pub mod directory;
+pub mod orgs;
 pub mod public_session;
+pub mod share_links;
+pub mod transfers;
 pub mod user_session;
 
-use crate::auth::storage::{LocalTokenStorage, TokenStorage};
-use gloo_net::http::{RequestBuilder, Response};
+use crate::auth::storage::{token_storage, TokenStorage};
+use gloo_net::http::{Method, RequestBuilder, Response};
 
 /// Authorization 헤더를 자동으로 추가하는 Request 빌더
+///
+/// 쿠키 로그인이면 토큰은 브라우저가 쿠키로 보내고, GET이 아닌 요청에 CSRF 헤더를 붙입니다.
 pub struct AuthenticatedRequest {
-    storage: LocalTokenStorage,
+    storage: Box<dyn TokenStorage>,
 }
 
 impl AuthenticatedRequest {
     pub fn new() -> Self {
         Self {
-            storage: LocalTokenStorage::new(),
+            storage: token_storage(),
         }
     }
 
     /// GET 요청 (인증 필요)
     pub fn get(&self, url: &str) -> RequestBuilder {
-        let mut req = RequestBuilder::new(url).method(gloo_net::http::Method::GET);
-
-        if let Some(token) = self.storage.get_token() {
-            req = req.header("Authorization", &format!("Bearer {}", token));
-        }
-
-        req
+        self.request(url, Method::GET)
     }
 
     /// POST 요청 (인증 필요)
     pub fn post(&self, url: &str) -> RequestBuilder {
-        let mut req = RequestBuilder::new(url).method(gloo_net::http::Method::POST);
-
-        if let Some(token) = self.storage.get_token() {
-            req = req.header("Authorization", &format!("Bearer {}", token));
-        }
-
-        req
+        self.request(url, Method::POST)
     }
 
     /// PUT 요청 (인증 필요)
     pub fn put(&self, url: &str) -> RequestBuilder {
-        let mut req = RequestBuilder::new(url).method(gloo_net::http::Method::PUT);
+        self.request(url, Method::PUT)
+    }
+
+    /// DELETE 요청 (인증 필요)
+    pub fn delete(&self, url: &str) -> RequestBuilder {
+        self.request(url, Method::DELETE)
+    }
+
+    fn request(&self, url: &str, method: Method) -> RequestBuilder {
+        let sends_csrf = method != Method::GET;
+        let mut req = RequestBuilder::new(url).method(method);
 
         if let Some(token) = self.storage.get_token() {
             req = req.header("Authorization", &format!("Bearer {}", token));
+        } else if let Some(csrf_token) = self.storage.csrf_token().filter(|_| sends_csrf) {
+            req = req.header("X-CSRF-Token", &csrf_token);
         }
 
         req
@@ -62,9 +72,8 @@ pub async fn handle_response(response: Response) -> Result<Response, String> {
     match response.status() {
         401 => {
             log::warn!("401 Unauthorized - token expired or invalid");
-            // 토큰 삭제
-            let storage = LocalTokenStorage::new();
-            let _ = storage.remove_token();
+            // 토큰 갱신은 AuthProvider가 만료 전에 하므로 여기서는 토큰만 삭제
+            let _ = token_storage().remove_token();
             Err("토큰이 만료되었습니다. 다시 로그인하세요".to_string())
         }
         403 => {
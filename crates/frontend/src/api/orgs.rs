@@ -0,0 +1,260 @@
+use crate::api::auth::error_message;
+use crate::api::AuthenticatedRequest;
+use crate::types::{
+    OrgCreateRequest, OrgDetail, OrgItem, OrgListResponse, OrgMemberRequest, OrgRole,
+    OrgSessionCreateRequest, SessionDetail, SessionListResponse, SessionUpdateRequest,
+};
+
+fn org_session_path(org_id: &str, session_id: &str) -> String {
+    format!("/api/org/{}/session/{}", org_id, session_id)
+}
+
+/// 내가 속한 조직 목록
+pub async fn list_orgs(user_id: &str) -> Result<Vec<OrgItem>, String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .get(&format!("/api/user/{}/org", user_id))
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let list: OrgListResponse = response
+                .json()
+                .await
+                .map_err(|e| format!("응답 파싱 실패: {}", e))?;
+            Ok(list.items)
+        }
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("권한이 없습니다".to_string()),
+        status => Err(format!("조직 목록 조회 실패 ({})", status)),
+    }
+}
+
+pub async fn create_org(request: OrgCreateRequest) -> Result<(), String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .post("/api/org")
+        .json(&request)
+        .map_err(|e| format!("요청 생성 실패: {}", e))?
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        201 => Ok(()),
+        400 => Err(error_message(response)
+            .await
+            .unwrap_or_else(|| "입력값이 올바르지 않습니다".to_string())),
+        401 => Err("로그인이 필요합니다".to_string()),
+        409 => Err("이미 존재하는 조직 ID입니다".to_string()),
+        status => Err(format!("조직 생성 실패 ({})", status)),
+    }
+}
+
+pub async fn get_org(org_id: &str) -> Result<OrgDetail, String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .get(&format!("/api/org/{}", org_id))
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        200 => response
+            .json()
+            .await
+            .map_err(|e| format!("응답 파싱 실패: {}", e)),
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("이 조직의 멤버가 아닙니다".to_string()),
+        status => Err(format!("조직 조회 실패 ({})", status)),
+    }
+}
+
+pub async fn delete_org(org_id: &str) -> Result<(), String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .delete(&format!("/api/org/{}", org_id))
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        204 => Ok(()),
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("소유자만 조직을 삭제할 수 있습니다".to_string()),
+        status => Err(format!("조직 삭제 실패 ({})", status)),
+    }
+}
+
+/// 멤버를 추가하거나 역할을 바꿉니다.
+pub async fn set_org_member(org_id: &str, member_id: &str, role: OrgRole) -> Result<(), String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .put(&format!("/api/org/{}/member/{}", org_id, member_id))
+        .json(&OrgMemberRequest { role })
+        .map_err(|e| format!("요청 생성 실패: {}", e))?
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        204 => Ok(()),
+        400 | 404 => Err(error_message(response)
+            .await
+            .unwrap_or_else(|| "입력값이 올바르지 않습니다".to_string())),
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("소유자만 멤버를 관리할 수 있습니다".to_string()),
+        status => Err(format!("멤버 추가 실패 ({})", status)),
+    }
+}
+
+/// 멤버를 내보냅니다. 자기 자신을 지정하면 조직에서 나갑니다.
+pub async fn remove_org_member(org_id: &str, member_id: &str) -> Result<(), String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .delete(&format!("/api/org/{}/member/{}", org_id, member_id))
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        204 => Ok(()),
+        400 => Err(error_message(response)
+            .await
+            .unwrap_or_else(|| "마지막 소유자는 나갈 수 없습니다".to_string())),
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("소유자만 멤버를 관리할 수 있습니다".to_string()),
+        404 => Err("이미 제거된 멤버입니다".to_string()),
+        status => Err(format!("멤버 제거 실패 ({})", status)),
+    }
+}
+
+/// 조직 세션 목록. 토큰의 조직 역할로 권한을 확인하므로 403이면 로그인을 갱신해야 할 수 있습니다.
+pub async fn list_org_sessions(org_id: &str) -> Result<SessionListResponse, String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .get(&format!("/api/org/{}/session", org_id))
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        200 => response
+            .json()
+            .await
+            .map_err(|e| format!("응답 파싱 실패: {}", e)),
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("권한이 없습니다 (조직 역할이 바뀌었다면 다시 로그인하세요)".to_string()),
+        status => Err(format!("조직 세션 조회 실패 ({})", status)),
+    }
+}
+
+pub async fn create_org_session(
+    org_id: &str,
+    request: OrgSessionCreateRequest,
+) -> Result<(), String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .post(&format!("/api/org/{}/session", org_id))
+        .json(&request)
+        .map_err(|e| format!("요청 생성 실패: {}", e))?
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        201 => Ok(()),
+        400 => Err(error_message(response)
+            .await
+            .unwrap_or_else(|| "입력값이 올바르지 않습니다".to_string())),
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("권한이 없습니다".to_string()),
+        409 => Err("이미 존재하는 세션 ID입니다".to_string()),
+        413 | 429 => Err(error_message(response)
+            .await
+            .unwrap_or_else(|| "할당량을 초과했습니다".to_string())),
+        status => Err(format!("세션 생성 실패 ({})", status)),
+    }
+}
+
+pub async fn get_org_session_detail(
+    org_id: &str,
+    session_id: &str,
+) -> Result<SessionDetail, String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .get(&org_session_path(org_id, session_id))
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        200 => response
+            .json()
+            .await
+            .map_err(|e| format!("응답 파싱 실패: {}", e)),
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("권한이 없습니다".to_string()),
+        404 => Err("세션을 찾을 수 없습니다".to_string()),
+        status => Err(format!("세션 조회 실패 ({})", status)),
+    }
+}
+
+pub async fn update_org_session(
+    org_id: &str,
+    session_id: &str,
+    request: SessionUpdateRequest,
+) -> Result<(), String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .put(&org_session_path(org_id, session_id))
+        .json(&request)
+        .map_err(|e| format!("요청 생성 실패: {}", e))?
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        204 => Ok(()),
+        400 => Err(error_message(response)
+            .await
+            .unwrap_or_else(|| "입력값이 올바르지 않습니다".to_string())),
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("권한이 없습니다".to_string()),
+        404 => Err("세션을 찾을 수 없습니다".to_string()),
+        413 | 429 => Err(error_message(response)
+            .await
+            .unwrap_or_else(|| "할당량을 초과했습니다".to_string())),
+        status => Err(format!("세션 업데이트 실패 ({})", status)),
+    }
+}
+
+pub async fn delete_org_session(org_id: &str, session_id: &str) -> Result<(), String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .delete(&org_session_path(org_id, session_id))
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        204 => Ok(()),
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("권한이 없습니다".to_string()),
+        404 => Err("세션을 찾을 수 없습니다".to_string()),
+        status => Err(format!("세션 삭제 실패 ({})", status)),
+    }
+}
@@ -23,6 +23,7 @@ pub async fn create_public_session(request: PublicSessionCreateRequest) -> Resul
                 .map_err(|e| format!("응답 파싱 실패: {}", e))?;
             Ok(create_response.session_id)
         }
+        400 => Err("입력값이 올바르지 않습니다 (태그는 문자, 숫자, '-', '_' 만 사용 가능)".to_string()),
         409 => Err("이미 존재하는 세션 ID입니다".to_string()),
         status => Err(format!("세션 생성 실패 ({})", status)),
     }
@@ -61,6 +62,7 @@ pub async fn update_public_session(
 
     match response.status() {
         200 => Ok(()),
+        400 => Err("입력값이 올바르지 않습니다 (태그는 문자, 숫자, '-', '_' 만 사용 가능)".to_string()),
         404 => Err("세션을 찾을 수 없습니다".to_string()),
         status => Err(format!("세션 수정 실패 ({})", status)),
     }
@@ -0,0 +1,92 @@
+use crate::api::auth::error_message;
+use crate::api::AuthenticatedRequest;
+use crate::types::{
+    ShareLinkCreateRequest, ShareLinkCreateResponse, ShareLinkItem, ShareLinkListResponse,
+};
+
+fn share_links_path(user_id: &str, session_id: &str) -> String {
+    format!("/api/user/{}/session/{}/share-link", user_id, session_id)
+}
+
+pub async fn list_share_links(
+    user_id: &str,
+    session_id: &str,
+) -> Result<Vec<ShareLinkItem>, String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .get(&share_links_path(user_id, session_id))
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let list: ShareLinkListResponse = response
+                .json()
+                .await
+                .map_err(|e| format!("응답 파싱 실패: {}", e))?;
+            Ok(list.items)
+        }
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("권한이 없습니다".to_string()),
+        status => Err(format!("공유 링크 목록 조회 실패 ({})", status)),
+    }
+}
+
+/// 새 공유 링크를 만듭니다. 응답의 URL은 다시 조회할 수 없습니다.
+pub async fn create_share_link(
+    user_id: &str,
+    session_id: &str,
+    request: ShareLinkCreateRequest,
+) -> Result<ShareLinkCreateResponse, String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .post(&share_links_path(user_id, session_id))
+        .json(&request)
+        .map_err(|e| format!("요청 생성 실패: {}", e))?
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        201 => response
+            .json()
+            .await
+            .map_err(|e| format!("응답 파싱 실패: {}", e)),
+        400 => Err(error_message(response)
+            .await
+            .unwrap_or_else(|| "입력값이 올바르지 않습니다".to_string())),
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("권한이 없습니다".to_string()),
+        404 => Err("세션을 찾을 수 없습니다".to_string()),
+        status => Err(format!("공유 링크 생성 실패 ({})", status)),
+    }
+}
+
+pub async fn revoke_share_link(
+    user_id: &str,
+    session_id: &str,
+    link_id: &str,
+) -> Result<(), String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .delete(&format!(
+            "{}/{}",
+            share_links_path(user_id, session_id),
+            link_id
+        ))
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        204 => Ok(()),
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("권한이 없습니다".to_string()),
+        404 => Err("이미 폐기된 공유 링크입니다".to_string()),
+        status => Err(format!("공유 링크 폐기 실패 ({})", status)),
+    }
+}
@@ -0,0 +1,133 @@
+use crate::api::auth::error_message;
+use crate::api::AuthenticatedRequest;
+use crate::types::{
+    PendingTransfer, SessionTransferItem, SessionTransferListResponse, SessionTransferRequest,
+};
+
+fn transfer_path(user_id: &str, session_id: &str) -> String {
+    format!("/api/user/{}/session/{}/transfer", user_id, session_id)
+}
+
+fn incoming_path(user_id: &str, owner: &str, session_id: &str) -> String {
+    format!("/api/user/{}/transfer/{}/{}", user_id, owner, session_id)
+}
+
+/// 세션 소유권 이전을 요청합니다. 이미 보낸 요청이 있으면 대체합니다.
+pub async fn request_transfer(
+    user_id: &str,
+    session_id: &str,
+    to: &str,
+) -> Result<PendingTransfer, String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .post(&transfer_path(user_id, session_id))
+        .json(&SessionTransferRequest { to: to.to_string() })
+        .map_err(|e| format!("요청 생성 실패: {}", e))?
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        201 => response
+            .json()
+            .await
+            .map_err(|e| format!("응답 파싱 실패: {}", e)),
+        400 => Err(error_message(response)
+            .await
+            .unwrap_or_else(|| "입력값이 올바르지 않습니다".to_string())),
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("권한이 없습니다".to_string()),
+        404 => Err(error_message(response)
+            .await
+            .unwrap_or_else(|| "사용자를 찾을 수 없습니다".to_string())),
+        status => Err(format!("소유권 이전 요청 실패 ({})", status)),
+    }
+}
+
+pub async fn cancel_transfer(user_id: &str, session_id: &str) -> Result<(), String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .delete(&transfer_path(user_id, session_id))
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        204 => Ok(()),
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("권한이 없습니다".to_string()),
+        404 => Err("이미 취소되었거나 만료된 요청입니다".to_string()),
+        status => Err(format!("소유권 이전 취소 실패 ({})", status)),
+    }
+}
+
+/// 나에게 온 소유권 이전 요청 목록
+pub async fn list_incoming_transfers(user_id: &str) -> Result<Vec<SessionTransferItem>, String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .get(&format!("/api/user/{}/transfer", user_id))
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let list: SessionTransferListResponse = response
+                .json()
+                .await
+                .map_err(|e| format!("응답 파싱 실패: {}", e))?;
+            Ok(list.items)
+        }
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("권한이 없습니다".to_string()),
+        status => Err(format!("소유권 이전 요청 조회 실패 ({})", status)),
+    }
+}
+
+pub async fn accept_transfer(user_id: &str, owner: &str, session_id: &str) -> Result<(), String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .post(&format!(
+            "{}/accept",
+            incoming_path(user_id, owner, session_id)
+        ))
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        200 => Ok(()),
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("권한이 없습니다".to_string()),
+        404 => Err("취소되었거나 만료된 요청입니다".to_string()),
+        409 => {
+            Err("같은 ID의 세션이 이미 있습니다. 기존 세션을 지운 뒤 다시 수락하세요".to_string())
+        }
+        429 => Err(error_message(response)
+            .await
+            .unwrap_or_else(|| "할당량을 초과했습니다".to_string())),
+        status => Err(format!("소유권 이전 수락 실패 ({})", status)),
+    }
+}
+
+pub async fn decline_transfer(user_id: &str, owner: &str, session_id: &str) -> Result<(), String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .delete(&incoming_path(user_id, owner, session_id))
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        204 => Ok(()),
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("권한이 없습니다".to_string()),
+        404 => Err("이미 취소되었거나 만료된 요청입니다".to_string()),
+        status => Err(format!("소유권 이전 거절 실패 ({})", status)),
+    }
+}
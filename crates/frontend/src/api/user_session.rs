@@ -1,6 +1,8 @@
+use crate::api::auth::error_message;
 use crate::api::AuthenticatedRequest;
 use crate::types::{
-    SessionDetail, SessionListResponse, SessionUpdateRequest, UserSessionCreateRequest,
+    SessionDetail, SessionListQuery, SessionListResponse, SessionUpdateRequest,
+    UserSessionCreateRequest,
 };
 
 #[derive(serde::Deserialize)]
@@ -34,7 +36,11 @@ pub async fn create_user_session(
         }
         401 => Err("로그인이 필요합니다".to_string()),
         403 => Err("권한이 없습니다".to_string()),
+        400 => Err("입력값이 올바르지 않습니다 (태그는 문자, 숫자, '-', '_' 만 사용 가능)".to_string()),
         409 => Err("이미 존재하는 세션 ID입니다".to_string()),
+        413 | 429 => Err(error_message(response)
+            .await
+            .unwrap_or_else(|| "할당량을 초과했습니다".to_string())),
         status => Err(format!("세션 생성 실패 ({})", status)),
     }
 }
@@ -86,16 +92,25 @@ pub async fn update_user_session(
         204 => Ok(()),
         401 => Err("로그인이 필요합니다".to_string()),
         403 => Err("권한이 없습니다".to_string()),
+        400 => Err("입력값이 올바르지 않습니다 (태그는 문자, 숫자, '-', '_' 만 사용 가능)".to_string()),
         404 => Err("세션을 찾을 수 없습니다".to_string()),
+        413 | 429 => Err(error_message(response)
+            .await
+            .unwrap_or_else(|| "할당량을 초과했습니다".to_string())),
         status => Err(format!("세션 수정 실패 ({})", status)),
     }
 }
 
-pub async fn list_user_sessions(user_id: &str) -> Result<SessionListResponse, String> {
+pub async fn list_user_sessions(
+    user_id: &str,
+    query: &SessionListQuery,
+) -> Result<SessionListResponse, String> {
     let auth_req = AuthenticatedRequest::new();
+    let pairs = query.to_pairs();
 
     let response = auth_req
         .get(&format!("/api/user/{}/session", user_id))
+        .query(pairs.iter().map(|(k, v)| (*k, v.as_str())))
         .send()
         .await
         .map_err(|e| format!("네트워크 오류: {}", e))?;
@@ -108,8 +123,27 @@ pub async fn list_user_sessions(user_id: &str) -> Result<SessionListResponse, St
                 .map_err(|e| format!("응답 파싱 실패: {}", e))?;
             Ok(list_response)
         }
+        400 => Err("잘못된 목록 조회 요청입니다".to_string()),
         401 => Err("로그인이 필요합니다".to_string()),
         403 => Err("권한이 없습니다".to_string()),
         status => Err(format!("세션 목록 조회 실패 ({})", status)),
     }
 }
+
+pub async fn delete_user_session(user_id: &str, session_id: &str) -> Result<(), String> {
+    let auth_req = AuthenticatedRequest::new();
+
+    let response = auth_req
+        .delete(&format!("/api/user/{}/session/{}", user_id, session_id))
+        .send()
+        .await
+        .map_err(|e| format!("네트워크 오류: {}", e))?;
+
+    match response.status() {
+        204 => Ok(()),
+        401 => Err("로그인이 필요합니다".to_string()),
+        403 => Err("권한이 없습니다".to_string()),
+        404 => Err("세션을 찾을 수 없습니다".to_string()),
+        status => Err(format!("세션 삭제 실패 ({})", status)),
+    }
+}
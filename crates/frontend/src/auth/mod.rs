@@ -1,18 +1,89 @@
 pub mod storage;
 pub mod token;
 
-use crate::auth::storage::{LocalTokenStorage, TokenStorage};
+use crate::api::auth::{cookie_session, refresh, refresh_cookie};
+use crate::auth::storage::{token_storage, CookieTokenStorage, LocalTokenStorage, TokenStorage};
 use crate::auth::token::decode_claims;
+use crate::types::{CookieSession, TokenResponse};
+use gloo_timers::callback::Timeout;
+use wasm_bindgen_futures::spawn_local;
 use yew::prelude::*;
 
+/// access 토큰 만료 몇 초 전에 갱신할지
+const REFRESH_MARGIN_SECONDS: f64 = 60.0;
+
+/// 로그인 상태. 토큰 원문은 저장소에만 두고, 화면에 필요한 정보만 담습니다.
 #[derive(Debug, Clone, PartialEq)]
 pub enum AuthState {
     Anonymous,
-    Authenticated { user_id: String, token: String },
+    Authenticated {
+        user_id: String,
+        /// 관리자 역할 (토큰을 받을 때의 상태)
+        admin: bool,
+        /// access 토큰 만료 시각 (Unix timestamp)
+        expires_at: u64,
+    },
+}
+
+impl AuthState {
+    /// 토큰의 claim으로 로그인 상태를 만듭니다.
+    pub fn from_token(token: &str) -> Result<Self, String> {
+        let claims = decode_claims(token)?;
+        Ok(AuthState::Authenticated {
+            user_id: claims.sub,
+            admin: claims.admin,
+            expires_at: claims.exp,
+        })
+    }
+
+    /// 쿠키 로그인 응답으로 로그인 상태를 만듭니다.
+    pub fn from_cookie_session(session: CookieSession) -> Self {
+        let now = (js_sys::Date::now() / 1000.0) as u64;
+        AuthState::Authenticated {
+            user_id: session.user_id,
+            admin: session.admin,
+            expires_at: now + session.expires_in,
+        }
+    }
 }
 
 pub type AuthContext = UseStateHandle<AuthState>;
 
+/// 발급받은 access 토큰과 refresh 토큰을 현재 저장소에 저장합니다.
+///
+/// 쿠키 로그인이면 서버가 응답에서 이미 쿠키를 교체했으므로 저장할 것이 없습니다.
+pub fn save_tokens(tokens: &TokenResponse) -> Result<(), String> {
+    save_tokens_to(&*token_storage(), tokens)
+}
+
+pub fn save_tokens_to(storage: &dyn TokenStorage, tokens: &TokenResponse) -> Result<(), String> {
+    storage.set_token(&tokens.token)?;
+    if let Some(refresh_token) = &tokens.refresh_token {
+        storage.set_refresh_token(refresh_token)?;
+    }
+    Ok(())
+}
+
+/// 저장된 refresh 토큰, 또는 refresh 토큰 쿠키로 로그인을 갱신합니다.
+pub async fn refresh_login() -> Result<AuthState, String> {
+    let storage = token_storage();
+    match storage.get_refresh_token() {
+        Some(refresh_token) => {
+            let tokens = refresh(refresh_token).await?;
+            save_tokens_to(&*storage, &tokens)?;
+            AuthState::from_token(&tokens.token)
+        }
+        None => Ok(AuthState::from_cookie_session(refresh_cookie().await?)),
+    }
+}
+
+/// 토큰 만료 직전까지 남은 시간 (ms). 이미 지났으면 0.
+fn refresh_delay_ms(expires_at: u64) -> u32 {
+    let now = js_sys::Date::now() / 1000.0;
+    let delay = (expires_at as f64 - REFRESH_MARGIN_SECONDS - now).max(0.0) * 1000.0;
+    delay.min(u32::MAX as f64) as u32
+}
+
 #[derive(Properties, PartialEq)]
 pub struct AuthProviderProps {
     #[prop_or_default]
@@ -21,21 +92,66 @@ pub struct AuthProviderProps {
 
 #[function_component(AuthProvider)]
 pub fn auth_provider(props: &AuthProviderProps) -> Html {
-    let storage = LocalTokenStorage::new();
-
     let state = use_state(|| {
-        if let Some(token) = storage.get_token() {
-            if let Ok(claims) = decode_claims(&token) {
-                log::info!("Token loaded from localStorage for user: {}", claims.sub);
-                return AuthState::Authenticated {
-                    user_id: claims.sub,
-                    token,
-                };
+        if let Some(token) = LocalTokenStorage::new().get_token() {
+            if let Ok(state) = AuthState::from_token(&token) {
+                log::info!("Token loaded from localStorage");
+                return state;
             }
         }
         AuthState::Anonymous
     });
 
+    // 쿠키 로그인의 토큰은 스크립트가 읽을 수 없으므로, CSRF 쿠키가 남아 있으면
+    // 서버에 로그인 상태를 묻고 access 토큰이 만료되었으면 갱신합니다.
+    {
+        let state = state.clone();
+        use_effect_with((), move |_| {
+            if *state == AuthState::Anonymous && CookieTokenStorage::new().csrf_token().is_some() {
+                spawn_local(async move {
+                    let restored = match cookie_session().await {
+                        Ok(session) => Ok(AuthState::from_cookie_session(session)),
+                        Err(_) => refresh_login().await,
+                    };
+                    match restored {
+                        Ok(restored) => state.set(restored),
+                        Err(e) => {
+                            log::info!("Cookie login not restored: {}", e);
+                            let _ = CookieTokenStorage::new().remove_token();
+                        }
+                    }
+                });
+            }
+        });
+    }
+
+    // access 토큰이 만료되기 전에 refresh 토큰으로 조용히 갱신합니다.
+    // 이미 만료된 토큰으로 시작하면 바로 갱신하고, 갱신에 실패하면 로그아웃합니다.
+    {
+        let state = state.clone();
+        use_effect_with((*state).clone(), move |current| {
+            let timeout = match current {
+                AuthState::Authenticated { expires_at, .. } => {
+                    let state = state.clone();
+                    Some(Timeout::new(refresh_delay_ms(*expires_at), move || {
+                        spawn_local(async move {
+                            match refresh_login().await {
+                                Ok(refreshed) => state.set(refreshed),
+                                Err(e) => {
+                                    log::warn!("Token refresh failed: {}", e);
+                                    let _ = token_storage().remove_token();
+                                    state.set(AuthState::Anonymous);
+                                }
+                            }
+                        });
+                    }))
+                }
+                AuthState::Anonymous => None,
+            };
+            move || drop(timeout)
+        });
+    }
+
     html! {
         <ContextProvider<AuthContext> context={state}>
             {props.children.clone()}
@@ -1,12 +1,36 @@
-use web_sys::{window, Storage};
+use wasm_bindgen::JsCast;
+use web_sys::{window, HtmlDocument, Storage};
 
 const TOKEN_KEY: &str = "jwt_token";
+const REFRESH_TOKEN_KEY: &str = "refresh_token";
+/// 서버가 쿠키 로그인 때 내려 주는, 스크립트가 읽을 수 있는 CSRF 쿠키
+const CSRF_COOKIE: &str = "rsvg_csrf";
 
 /// 토큰 저장소 추상화
 pub trait TokenStorage {
     fn get_token(&self) -> Option<String>;
     fn set_token(&self, token: &str) -> Result<(), String>;
+    fn get_refresh_token(&self) -> Option<String>;
+    fn set_refresh_token(&self, refresh_token: &str) -> Result<(), String>;
+    /// access 토큰과 refresh 토큰을 모두 지웁니다.
     fn remove_token(&self) -> Result<(), String>;
+    /// 쿠키 로그인이면 상태 변경 요청의 `X-CSRF-Token` 헤더에 실을 값
+    fn csrf_token(&self) -> Option<String> {
+        None
+    }
+}
+
+/// 현재 로그인에 맞는 저장소.
+///
+/// 새 로그인은 쿠키를 쓰고, 쿠키 로그인 이전에 localStorage에 저장된 토큰은
+/// 로그아웃하거나 만료될 때까지 그대로 씁니다.
+pub fn token_storage() -> Box<dyn TokenStorage> {
+    let local = LocalTokenStorage::new();
+    if local.get_token().is_some() {
+        Box::new(local)
+    } else {
+        Box::new(CookieTokenStorage::new())
+    }
 }
 
 /// localStorage 기반 구현
@@ -21,18 +45,11 @@ impl LocalTokenStorage {
     fn get_storage(&self) -> Option<Storage> {
         window()?.local_storage().ok()?
     }
-}
 
-impl TokenStorage for LocalTokenStorage {
-    fn get_token(&self) -> Option<String> {
-        let storage = self.get_storage()?;
-        storage.get_item(TOKEN_KEY).ok()?
-    }
-
-    fn set_token(&self, token: &str) -> Result<(), String> {
+    fn set_item(&self, key: &str, value: &str) -> Result<(), String> {
         let storage = self.get_storage().ok_or("localStorage not available")?;
 
-        storage.set_item(TOKEN_KEY, token).map_err(|e| {
+        storage.set_item(key, value).map_err(|e| {
             let error_msg = format!("{:?}", e);
             if error_msg.contains("QuotaExceededError") {
                 "Storage quota exceeded".to_string()
@@ -41,12 +58,90 @@ impl TokenStorage for LocalTokenStorage {
             }
         })
     }
+}
+
+impl TokenStorage for LocalTokenStorage {
+    fn get_token(&self) -> Option<String> {
+        let storage = self.get_storage()?;
+        storage.get_item(TOKEN_KEY).ok()?
+    }
+
+    fn set_token(&self, token: &str) -> Result<(), String> {
+        self.set_item(TOKEN_KEY, token)
+    }
+
+    fn get_refresh_token(&self) -> Option<String> {
+        let storage = self.get_storage()?;
+        storage.get_item(REFRESH_TOKEN_KEY).ok()?
+    }
+
+    fn set_refresh_token(&self, refresh_token: &str) -> Result<(), String> {
+        self.set_item(REFRESH_TOKEN_KEY, refresh_token)
+    }
 
     fn remove_token(&self) -> Result<(), String> {
         let storage = self.get_storage().ok_or("localStorage not available")?;
 
-        storage
-            .remove_item(TOKEN_KEY)
-            .map_err(|e| format!("Failed to remove token: {:?}", e))
+        for key in [TOKEN_KEY, REFRESH_TOKEN_KEY] {
+            storage
+                .remove_item(key)
+                .map_err(|e| format!("Failed to remove token: {:?}", e))?;
+        }
+        Ok(())
+    }
+}
+
+/// HttpOnly 쿠키 기반 구현
+///
+/// 토큰은 서버가 `HttpOnly` 쿠키로 내려 주고 브라우저가 요청마다 보내므로, 스크립트는
+/// 토큰을 읽거나 쓸 수 없습니다. 주입된 스크립트가 토큰을 빼낼 수 없는 대신, 상태 변경
+/// 요청에는 CSRF 쿠키의 값을 헤더로 함께 보내야 합니다.
+pub struct CookieTokenStorage;
+
+impl CookieTokenStorage {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn document(&self) -> Option<HtmlDocument> {
+        window()?.document()?.dyn_into::<HtmlDocument>().ok()
+    }
+}
+
+impl TokenStorage for CookieTokenStorage {
+    fn get_token(&self) -> Option<String> {
+        None
+    }
+
+    /// 서버가 쿠키를 설정하므로 할 일이 없습니다.
+    fn set_token(&self, _token: &str) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn get_refresh_token(&self) -> Option<String> {
+        None
+    }
+
+    fn set_refresh_token(&self, _refresh_token: &str) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// HttpOnly 쿠키는 서버만 지울 수 있으므로 (`DELETE /api/auth/cookie`),
+    /// 여기서는 CSRF 쿠키만 지워 이 브라우저가 로그아웃한 것으로 보이게 합니다.
+    fn remove_token(&self) -> Result<(), String> {
+        let document = self.document().ok_or("document not available")?;
+        document
+            .set_cookie(&format!("{}=; Path=/; Max-Age=0", CSRF_COOKIE))
+            .map_err(|e| format!("Failed to remove cookie: {:?}", e))
+    }
+
+    fn csrf_token(&self) -> Option<String> {
+        let cookies = self.document()?.cookie().ok()?;
+        cookies
+            .split(';')
+            .filter_map(|pair| pair.trim().split_once('='))
+            .find(|(name, _)| *name == CSRF_COOKIE)
+            .map(|(_, value)| value.to_string())
+            .filter(|value| !value.is_empty())
     }
 }
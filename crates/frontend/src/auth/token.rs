@@ -7,6 +7,9 @@ pub struct Claims {
     pub exp: u64,    // 만료 시간 (Unix timestamp)
     pub iat: u64,    // 발급 시간
     pub iss: String, // 발급자
+    /// 관리자 역할 (로그인할 때의 상태)
+    #[serde(default)]
+    pub admin: bool,
 }
 
 pub fn decode_claims(token: &str) -> Result<Claims, String> {
@@ -0,0 +1,270 @@
+use wasm_bindgen_futures::spawn_local;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::api::account::{change_password, delete_account};
+use crate::api::auth::{end_cookie_login, logout};
+use crate::auth::storage::token_storage;
+use crate::auth::{save_tokens, AuthContext, AuthState};
+use crate::routes::Route;
+
+fn input_value(node: &NodeRef) -> String {
+    node.cast::<HtmlInputElement>()
+        .map(|input| input.value())
+        .unwrap_or_default()
+}
+
+fn clear_input(node: &NodeRef) {
+    if let Some(input) = node.cast::<HtmlInputElement>() {
+        input.set_value("");
+    }
+}
+
+#[function_component(AccountPage)]
+pub fn account_page() -> Html {
+    let auth_context = use_context::<AuthContext>().expect("AuthContext must be provided");
+    let navigator = use_navigator().expect("Navigator must be available");
+
+    let old_password_ref = use_node_ref();
+    let new_password_ref = use_node_ref();
+    let confirm_password_ref = use_node_ref();
+    let error_message = use_state(|| None::<String>);
+    let success_message = use_state(|| None::<String>);
+    let loading = use_state(|| false);
+
+    let on_change_password = {
+        let old_password_ref = old_password_ref.clone();
+        let new_password_ref = new_password_ref.clone();
+        let confirm_password_ref = confirm_password_ref.clone();
+        let error_message = error_message.clone();
+        let success_message = success_message.clone();
+        let loading = loading.clone();
+        let auth_context = auth_context.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            if *auth_context == AuthState::Anonymous {
+                return;
+            }
+            let old_password = input_value(&old_password_ref);
+            let new_password = input_value(&new_password_ref);
+
+            if old_password.is_empty() || new_password.is_empty() {
+                error_message.set(Some("비밀번호를 입력하세요".to_string()));
+                return;
+            }
+            if new_password != input_value(&confirm_password_ref) {
+                error_message.set(Some("새 비밀번호가 서로 다릅니다".to_string()));
+                return;
+            }
+
+            let old_password_ref = old_password_ref.clone();
+            let new_password_ref = new_password_ref.clone();
+            let confirm_password_ref = confirm_password_ref.clone();
+            let error_message = error_message.clone();
+            let success_message = success_message.clone();
+            let loading = loading.clone();
+            let auth_context = auth_context.clone();
+
+            loading.set(true);
+            error_message.set(None);
+            success_message.set(None);
+
+            spawn_local(async move {
+                match change_password(old_password, new_password).await {
+                    Ok(tokens) => {
+                        // 기존 토큰은 무효가 되었으므로 새 토큰으로 교체합니다.
+                        // 쿠키 로그인이면 서버가 응답에서 쿠키도 교체했습니다.
+                        match save_tokens(&tokens)
+                            .and_then(|()| AuthState::from_token(&tokens.token))
+                        {
+                            Ok(refreshed) => {
+                                auth_context.set(refreshed);
+                                success_message.set(Some(
+                                    "비밀번호를 변경했습니다. 다른 기기의 로그인은 해제됩니다"
+                                        .to_string(),
+                                ));
+                            }
+                            Err(e) => {
+                                error_message.set(Some(format!("토큰 저장 실패: {}", e)));
+                            }
+                        }
+                        for node in [&old_password_ref, &new_password_ref, &confirm_password_ref] {
+                            clear_input(node);
+                        }
+                    }
+                    Err(e) => {
+                        error_message.set(Some(e));
+                    }
+                }
+                loading.set(false);
+            });
+        })
+    };
+
+    let on_logout_everywhere = {
+        let error_message = error_message.clone();
+        let loading = loading.clone();
+        let auth_context = auth_context.clone();
+        let navigator = navigator.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            let error_message = error_message.clone();
+            let loading = loading.clone();
+            let auth_context = auth_context.clone();
+            let navigator = navigator.clone();
+
+            loading.set(true);
+            error_message.set(None);
+
+            spawn_local(async move {
+                match logout(true).await {
+                    Ok(()) => {
+                        let _ = token_storage().remove_token();
+                        auth_context.set(AuthState::Anonymous);
+                        navigator.push(&Route::Home);
+                    }
+                    Err(e) => {
+                        error_message.set(Some(e));
+                        loading.set(false);
+                    }
+                }
+            });
+        })
+    };
+
+    let on_delete_account = {
+        let error_message = error_message.clone();
+        let loading = loading.clone();
+        let auth_context = auth_context.clone();
+        let navigator = navigator.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            let AuthState::Authenticated { user_id, .. } = &*auth_context else {
+                return;
+            };
+            let confirmed = web_sys::window()
+                .and_then(|w| {
+                    w.confirm_with_message(&format!(
+                        "'{}' 계정과 모든 세션을 삭제할까요? 되돌릴 수 없습니다.",
+                        user_id
+                    ))
+                    .ok()
+                })
+                .unwrap_or(false);
+            if !confirmed {
+                return;
+            }
+
+            let user_id = user_id.clone();
+            let error_message = error_message.clone();
+            let loading = loading.clone();
+            let auth_context = auth_context.clone();
+            let navigator = navigator.clone();
+
+            loading.set(true);
+            error_message.set(None);
+
+            spawn_local(async move {
+                match delete_account(&user_id).await {
+                    Ok(()) => {
+                        // 삭제된 계정의 쿠키는 서버에서만 지울 수 있습니다.
+                        let _ = end_cookie_login().await;
+                        let _ = token_storage().remove_token();
+                        auth_context.set(AuthState::Anonymous);
+                        navigator.push(&Route::Home);
+                    }
+                    Err(e) => {
+                        error_message.set(Some(e));
+                        loading.set(false);
+                    }
+                }
+            });
+        })
+    };
+
+    let AuthState::Authenticated { user_id, .. } = &*auth_context else {
+        return html! {
+            <div class="account-page">
+                <div class="error">{"로그인이 필요합니다"}</div>
+            </div>
+        };
+    };
+
+    html! {
+        <div class="account-page">
+            <div class="session-form">
+                <h2>{"비밀번호 변경"}</h2>
+                <p class="form-hint">{format!("사용자: {}", user_id)}</p>
+                <form onsubmit={on_change_password}>
+                    <div class="form-group">
+                        <label for="old_password">{"현재 비밀번호:"}</label>
+                        <input
+                            type="password"
+                            id="old_password"
+                            ref={old_password_ref}
+                            disabled={*loading}
+                        />
+                    </div>
+                    <div class="form-group">
+                        <label for="new_password">{"새 비밀번호:"}</label>
+                        <input
+                            type="password"
+                            id="new_password"
+                            ref={new_password_ref}
+                            placeholder="8자 이상"
+                            disabled={*loading}
+                        />
+                    </div>
+                    <div class="form-group">
+                        <label for="confirm_password">{"새 비밀번호 확인:"}</label>
+                        <input
+                            type="password"
+                            id="confirm_password"
+                            ref={confirm_password_ref}
+                            disabled={*loading}
+                        />
+                    </div>
+
+                    {if let Some(ref msg) = *success_message {
+                        html! { <div class="success">{msg}</div> }
+                    } else {
+                        html! {}
+                    }}
+
+                    <button type="submit" disabled={*loading}>
+                        {if *loading { "처리 중..." } else { "비밀번호 변경" }}
+                    </button>
+                </form>
+            </div>
+
+            <div class="session-form">
+                <h2>{"로그인 관리"}</h2>
+                <p class="form-hint">
+                    {"이 계정으로 로그인한 모든 브라우저와 발급된 토큰을 로그아웃시킵니다."}
+                </p>
+                <button class="secondary" onclick={on_logout_everywhere} disabled={*loading}>
+                    {"모든 기기에서 로그아웃"}
+                </button>
+            </div>
+
+            <div class="session-form danger-zone">
+                <h2>{"계정 삭제"}</h2>
+                <p class="form-hint">
+                    {"계정과 모든 세션이 삭제되고, 열려 있는 스트림도 종료됩니다. 되돌릴 수 없습니다."}
+                </p>
+                <button class="danger" onclick={on_delete_account} disabled={*loading}>
+                    {"계정 삭제"}
+                </button>
+            </div>
+
+            {if let Some(ref msg) = *error_message {
+                html! { <div class="error">{msg}</div> }
+            } else {
+                html! {}
+            }}
+        </div>
+    }
+}
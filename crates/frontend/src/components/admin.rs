@@ -0,0 +1,328 @@
+use wasm_bindgen_futures::spawn_local;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::api::admin::{delete_session, get_stats, list_sessions, list_users, update_user};
+use crate::auth::{AuthContext, AuthState};
+use crate::types::{AdminSessionItem, AdminUserItem, AdminUserUpdate, SystemStats};
+
+/// RFC3339 문자열을 `YYYY-MM-DD HH:MM` 형태로 줄입니다.
+fn format_timestamp(value: Option<&str>, empty: &str) -> String {
+    match value {
+        Some(value) if value.len() >= 16 => value[..16].replace('T', " "),
+        Some(value) => value.to_string(),
+        None => empty.to_string(),
+    }
+}
+
+fn session_owner(item: &AdminSessionItem) -> String {
+    match (&item.user_id, &item.org_id) {
+        (Some(user_id), _) => user_id.clone(),
+        (None, Some(org_id)) => format!("조직 {}", org_id),
+        (None, None) => "공개".to_string(),
+    }
+}
+
+fn confirm(message: &str) -> bool {
+    web_sys::window()
+        .and_then(|w| w.confirm_with_message(message).ok())
+        .unwrap_or(false)
+}
+
+#[function_component(AdminPage)]
+pub fn admin_page() -> Html {
+    let auth_context = use_context::<AuthContext>().expect("AuthContext must be provided");
+
+    let stats = use_state(|| None::<SystemStats>);
+    let users = use_state(Vec::<AdminUserItem>::new);
+    let sessions = use_state(Vec::<AdminSessionItem>::new);
+    let user_query = use_state(String::new);
+    let session_query = use_state(String::new);
+    let error_message = use_state(|| None::<String>);
+    let reload = use_state(|| 0u32);
+    let user_query_ref = use_node_ref();
+    let session_query_ref = use_node_ref();
+
+    let current = match &*auth_context {
+        AuthState::Authenticated { user_id, admin, .. } => Some((user_id.clone(), *admin)),
+        AuthState::Anonymous => None,
+    };
+    let is_admin = current.as_ref().is_some_and(|(_, admin)| *admin);
+
+    {
+        let stats = stats.clone();
+        let users = users.clone();
+        let sessions = sessions.clone();
+        let error_message = error_message.clone();
+        let deps = (
+            *reload,
+            (*user_query).clone(),
+            (*session_query).clone(),
+            is_admin,
+        );
+
+        use_effect_with(deps, move |(_, user_query, session_query, is_admin)| {
+            if *is_admin {
+                let user_query = user_query.clone();
+                let session_query = session_query.clone();
+                spawn_local(async move {
+                    let result = async {
+                        stats.set(Some(get_stats().await?));
+                        users.set(list_users(&user_query).await?);
+                        sessions.set(list_sessions(&session_query).await?);
+                        Ok::<_, String>(())
+                    }
+                    .await;
+                    if let Err(e) = result {
+                        error_message.set(Some(e));
+                    }
+                });
+            }
+        });
+    }
+
+    let on_user_search = {
+        let user_query = user_query.clone();
+        let user_query_ref = user_query_ref.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            if let Some(input) = user_query_ref.cast::<HtmlInputElement>() {
+                user_query.set(input.value());
+            }
+        })
+    };
+
+    let on_session_search = {
+        let session_query = session_query.clone();
+        let session_query_ref = session_query_ref.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            if let Some(input) = session_query_ref.cast::<HtmlInputElement>() {
+                session_query.set(input.value());
+            }
+        })
+    };
+
+    let on_update_user = {
+        let error_message = error_message.clone();
+        let reload = reload.clone();
+
+        Callback::from(move |(user_id, request): (String, AdminUserUpdate)| {
+            let message = match (request.admin, request.disabled) {
+                (Some(true), _) => format!("{}에게 관리자 역할을 줄까요?", user_id),
+                (Some(false), _) => format!("{}의 관리자 역할을 회수할까요?", user_id),
+                (_, Some(true)) => format!(
+                    "{} 계정을 비활성화할까요? 발급된 모든 토큰이 즉시 무효화됩니다.",
+                    user_id
+                ),
+                _ => format!("{} 계정을 다시 활성화할까요?", user_id),
+            };
+            if !confirm(&message) {
+                return;
+            }
+
+            let error_message = error_message.clone();
+            let reload = reload.clone();
+            spawn_local(async move {
+                match update_user(&user_id, request).await {
+                    Ok(_) => reload.set(*reload + 1),
+                    Err(e) => error_message.set(Some(e)),
+                }
+            });
+        })
+    };
+
+    let on_delete_session = {
+        let error_message = error_message.clone();
+        let reload = reload.clone();
+
+        Callback::from(move |item: AdminSessionItem| {
+            if !confirm(&format!(
+                "'{}' 세션을 강제로 삭제할까요? 열린 스트림도 모두 끊깁니다.",
+                item.session_id
+            )) {
+                return;
+            }
+
+            let error_message = error_message.clone();
+            let reload = reload.clone();
+            spawn_local(async move {
+                match delete_session(&item).await {
+                    Ok(()) => reload.set(*reload + 1),
+                    Err(e) => error_message.set(Some(e)),
+                }
+            });
+        })
+    };
+
+    let Some((current_user, true)) = current else {
+        return html! {
+            <div class="account-page">
+                <div class="error">{"관리자만 볼 수 있는 페이지입니다"}</div>
+            </div>
+        };
+    };
+
+    html! {
+        <div class="account-page admin-page">
+            <div class="session-form">
+                <h2>{"시스템 통계"}</h2>
+                {if let Some(stats) = &*stats {
+                    html! {
+                        <dl class="admin-stats">
+                            <dt>{"사용자"}</dt>
+                            <dd>{format!("{} (관리자 {}, 비활성 {})", stats.users, stats.admins, stats.disabled_users)}</dd>
+                            <dt>{"조직"}</dt>
+                            <dd>{stats.orgs}</dd>
+                            <dt>{"세션"}</dt>
+                            <dd>{format!(
+                                "공개 {}, 사용자 {}, 조직 {} (비공개 {}, 디렉터리 등록 {})",
+                                stats.public_sessions,
+                                stats.user_sessions,
+                                stats.org_sessions,
+                                stats.private_sessions,
+                                stats.listed_sessions,
+                            )}</dd>
+                            <dt>{"시청자"}</dt>
+                            <dd>{stats.viewers}</dd>
+                            <dt>{"로그인 거절"}</dt>
+                            <dd>{format!(
+                                "비밀번호 오류 {}, 계정 잠금 {}, 사용자별 제한 {}, IP별 제한 {}",
+                                stats.login_rejections.invalid_credentials,
+                                stats.login_rejections.locked_out,
+                                stats.login_rejections.user_rate_limited,
+                                stats.login_rejections.ip_rate_limited,
+                            )}</dd>
+                        </dl>
+                    }
+                } else {
+                    html! { <p class="form-hint">{"불러오는 중..."}</p> }
+                }}
+            </div>
+
+            <div class="session-form">
+                <h2>{"사용자"}</h2>
+                <form class="admin-search" onsubmit={on_user_search}>
+                    <input type="search" ref={user_query_ref} placeholder="사용자 ID 검색" />
+                    <button type="submit">{"검색"}</button>
+                </form>
+                <table class="api-key-table">
+                    <thead>
+                        <tr>
+                            <th>{"사용자 ID"}</th>
+                            <th>{"세션"}</th>
+                            <th>{"가입"}</th>
+                            <th>{"마지막 로그인"}</th>
+                            <th>{"상태"}</th>
+                            <th></th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {for users.iter().map(|item| {
+                            let is_self = item.user_id == current_user;
+                            let disabled = item.disabled_at.is_some();
+                            let toggle_admin = {
+                                let on_update_user = on_update_user.clone();
+                                let user_id = item.user_id.clone();
+                                let admin = !item.admin;
+                                Callback::from(move |_: MouseEvent| {
+                                    on_update_user.emit((
+                                        user_id.clone(),
+                                        AdminUserUpdate { admin: Some(admin), ..Default::default() },
+                                    ))
+                                })
+                            };
+                            let toggle_disabled = {
+                                let on_update_user = on_update_user.clone();
+                                let user_id = item.user_id.clone();
+                                Callback::from(move |_: MouseEvent| {
+                                    on_update_user.emit((
+                                        user_id.clone(),
+                                        AdminUserUpdate { disabled: Some(!disabled), ..Default::default() },
+                                    ))
+                                })
+                            };
+                            html! {
+                                <tr key={item.user_id.clone()}>
+                                    <td>{&item.user_id}</td>
+                                    <td>{item.sessions}</td>
+                                    <td>{format_timestamp(item.created_at.as_deref(), "-")}</td>
+                                    <td>{format_timestamp(item.last_login_at.as_deref(), "-")}</td>
+                                    <td>
+                                        {if item.admin { html! { <span class="role-badge">{"관리자"}</span> } } else { html! {} }}
+                                        {if disabled {
+                                            html! { <span class="role-badge danger">{format!("비활성 {}", format_timestamp(item.disabled_at.as_deref(), ""))}</span> }
+                                        } else {
+                                            html! {}
+                                        }}
+                                    </td>
+                                    <td>
+                                        <button class="secondary" disabled={is_self} onclick={toggle_admin}>
+                                            {if item.admin { "관리자 해제" } else { "관리자 지정" }}
+                                        </button>
+                                        <button class={if disabled { "secondary" } else { "danger" }} disabled={is_self} onclick={toggle_disabled}>
+                                            {if disabled { "활성화" } else { "비활성화" }}
+                                        </button>
+                                    </td>
+                                </tr>
+                            }
+                        })}
+                    </tbody>
+                </table>
+            </div>
+
+            <div class="session-form">
+                <h2>{"세션"}</h2>
+                <form class="admin-search" onsubmit={on_session_search}>
+                    <input type="search" ref={session_query_ref} placeholder="세션 ID, 제목, 소유자 검색" />
+                    <button type="submit">{"검색"}</button>
+                </form>
+                <table class="api-key-table">
+                    <thead>
+                        <tr>
+                            <th>{"세션"}</th>
+                            <th>{"소유자"}</th>
+                            <th>{"공개 범위"}</th>
+                            <th>{"시청자"}</th>
+                            <th>{"수정"}</th>
+                            <th></th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {for sessions.iter().map(|item| {
+                            let on_delete_session = on_delete_session.clone();
+                            let delete_item = item.clone();
+                            html! {
+                                <tr key={item.page_url.clone()}>
+                                    <td>
+                                        <a href={item.page_url.clone()}>
+                                            {item.title.clone().unwrap_or_else(|| item.session_id.clone())}
+                                        </a>
+                                    </td>
+                                    <td>{session_owner(item)}</td>
+                                    <td>{item.visibility.as_str()}</td>
+                                    <td>{item.viewers}</td>
+                                    <td>{format_timestamp(item.updated_at.as_deref(), "-")}</td>
+                                    <td>
+                                        <button
+                                            class="danger"
+                                            onclick={Callback::from(move |_: MouseEvent| on_delete_session.emit(delete_item.clone()))}
+                                        >
+                                            {"강제 삭제"}
+                                        </button>
+                                    </td>
+                                </tr>
+                            }
+                        })}
+                    </tbody>
+                </table>
+            </div>
+
+            {if let Some(ref msg) = *error_message {
+                html! { <div class="error">{msg}</div> }
+            } else {
+                html! {}
+            }}
+        </div>
+    }
+}
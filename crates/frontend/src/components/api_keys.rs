@@ -0,0 +1,291 @@
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use crate::api::api_keys::{create_api_key, list_api_keys, revoke_api_key};
+use crate::auth::{AuthContext, AuthState};
+use crate::types::{ApiKeyCreateRequest, ApiKeyCreateResponse, ApiKeyItem};
+
+/// 만료 기간 선택지 (표시 이름, 초). 0은 만료 없음입니다.
+const EXPIRY_OPTIONS: [(&str, u64); 4] = [
+    ("만료 없음", 0),
+    ("30일", 30 * 86400),
+    ("90일", 90 * 86400),
+    ("1년", 365 * 86400),
+];
+
+/// RFC3339 문자열을 `YYYY-MM-DD HH:MM` 형태로 줄입니다.
+fn format_timestamp(value: Option<&str>, empty: &str) -> String {
+    match value {
+        Some(value) if value.len() >= 16 => value[..16].replace('T', " "),
+        Some(value) => value.to_string(),
+        None => empty.to_string(),
+    }
+}
+
+#[function_component(ApiKeysPage)]
+pub fn api_keys_page() -> Html {
+    let auth_context = use_context::<AuthContext>().expect("AuthContext must be provided");
+
+    let api_keys = use_state(Vec::<ApiKeyItem>::new);
+    let created = use_state(|| None::<ApiKeyCreateResponse>);
+    let error_message = use_state(|| None::<String>);
+    let loading = use_state(|| false);
+    let name_ref = use_node_ref();
+    let expires_in = use_state(|| 0u64);
+    let reload = use_state(|| 0u32);
+
+    {
+        let auth_context = auth_context.clone();
+        let api_keys = api_keys.clone();
+        let error_message = error_message.clone();
+
+        use_effect_with(*reload, move |_| {
+            if let AuthState::Authenticated { user_id, .. } = &*auth_context {
+                let user_id = user_id.clone();
+                spawn_local(async move {
+                    match list_api_keys(&user_id).await {
+                        Ok(items) => api_keys.set(items),
+                        Err(e) => error_message.set(Some(e)),
+                    }
+                });
+            }
+        });
+    }
+
+    let on_expiry_change = {
+        let expires_in = expires_in.clone();
+        Callback::from(move |e: Event| {
+            if let Some(select) = e
+                .target()
+                .and_then(|t| t.dyn_into::<HtmlSelectElement>().ok())
+            {
+                expires_in.set(select.value().parse().unwrap_or(0));
+            }
+        })
+    };
+
+    let on_create = {
+        let auth_context = auth_context.clone();
+        let name_ref = name_ref.clone();
+        let expires_in = expires_in.clone();
+        let created = created.clone();
+        let error_message = error_message.clone();
+        let loading = loading.clone();
+        let reload = reload.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let AuthState::Authenticated { user_id, .. } = &*auth_context else {
+                return;
+            };
+            let Some(input) = name_ref.cast::<HtmlInputElement>() else {
+                return;
+            };
+            let name = input.value();
+            if name.trim().is_empty() {
+                error_message.set(Some("키 이름을 입력하세요".to_string()));
+                return;
+            }
+
+            let user_id = user_id.clone();
+            let request = ApiKeyCreateRequest {
+                name,
+                expires_in_seconds: Some(*expires_in).filter(|seconds| *seconds > 0),
+            };
+            let created = created.clone();
+            let error_message = error_message.clone();
+            let loading = loading.clone();
+            let reload = reload.clone();
+
+            loading.set(true);
+            error_message.set(None);
+
+            spawn_local(async move {
+                match create_api_key(&user_id, request).await {
+                    Ok(response) => {
+                        input.set_value("");
+                        created.set(Some(response));
+                        reload.set(*reload + 1);
+                    }
+                    Err(e) => error_message.set(Some(e)),
+                }
+                loading.set(false);
+            });
+        })
+    };
+
+    let on_copy = {
+        let created = created.clone();
+        let error_message = error_message.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            let (Some(response), Some(window)) = ((*created).clone(), web_sys::window()) else {
+                return;
+            };
+            let clipboard = window.navigator().clipboard();
+            let error_message = error_message.clone();
+            spawn_local(async move {
+                let result =
+                    wasm_bindgen_futures::JsFuture::from(clipboard.write_text(&response.api_key))
+                        .await;
+                if result.is_err() {
+                    error_message.set(Some("클립보드에 복사하지 못했습니다".to_string()));
+                }
+            });
+        })
+    };
+
+    let on_revoke = {
+        let auth_context = auth_context.clone();
+        let created = created.clone();
+        let error_message = error_message.clone();
+        let reload = reload.clone();
+
+        Callback::from(move |item: ApiKeyItem| {
+            let AuthState::Authenticated { user_id, .. } = &*auth_context else {
+                return;
+            };
+            let confirmed = web_sys::window()
+                .and_then(|w| {
+                    w.confirm_with_message(&format!(
+                        "'{}' 키를 폐기할까요? 이 키를 쓰는 클라이언트는 더 이상 인증할 수 없습니다.",
+                        item.name
+                    ))
+                    .ok()
+                })
+                .unwrap_or(false);
+            if !confirmed {
+                return;
+            }
+
+            let user_id = user_id.clone();
+            let created = created.clone();
+            let error_message = error_message.clone();
+            let reload = reload.clone();
+
+            spawn_local(async move {
+                match revoke_api_key(&user_id, &item.key_id).await {
+                    Ok(()) => {
+                        if (*created).as_ref().map(|c| c.key_id.as_str())
+                            == Some(item.key_id.as_str())
+                        {
+                            created.set(None);
+                        }
+                        reload.set(*reload + 1);
+                    }
+                    Err(e) => error_message.set(Some(e)),
+                }
+            });
+        })
+    };
+
+    if matches!(&*auth_context, AuthState::Anonymous) {
+        return html! {
+            <div class="account-page">
+                <div class="error">{"로그인이 필요합니다"}</div>
+            </div>
+        };
+    }
+
+    html! {
+        <div class="account-page">
+            <div class="session-form">
+                <h2>{"API 키 만들기"}</h2>
+                <p class="form-hint">
+                    {"CI 작업이나 장치에서 "}<code>{"X-Api-Key"}</code>
+                    {" 헤더로 세션을 갱신할 때 사용합니다. 키는 만들 때 한 번만 보여줍니다."}
+                </p>
+                <form onsubmit={on_create}>
+                    <div class="form-group">
+                        <label for="api_key_name">{"이름:"}</label>
+                        <input
+                            type="text"
+                            id="api_key_name"
+                            ref={name_ref}
+                            placeholder="예: CI 배포, 거실 센서"
+                            maxlength="64"
+                            disabled={*loading}
+                        />
+                    </div>
+                    <div class="form-group">
+                        <label for="api_key_expiry">{"만료:"}</label>
+                        <select id="api_key_expiry" onchange={on_expiry_change} disabled={*loading}>
+                            {for EXPIRY_OPTIONS.iter().map(|(label, seconds)| html! {
+                                <option value={seconds.to_string()} selected={*expires_in == *seconds}>
+                                    {*label}
+                                </option>
+                            })}
+                        </select>
+                    </div>
+                    <button type="submit" disabled={*loading}>
+                        {if *loading { "처리 중..." } else { "API 키 만들기" }}
+                    </button>
+                </form>
+
+                {if let Some(response) = &*created {
+                    html! {
+                        <div class="success">
+                            <p>{format!("'{}' 키를 만들었습니다. 지금 복사해 두세요. 다시 볼 수 없습니다.", response.name)}</p>
+                            <code class="api-key-secret">{&response.api_key}</code>
+                            <button class="secondary" onclick={on_copy}>{"복사"}</button>
+                        </div>
+                    }
+                } else {
+                    html! {}
+                }}
+            </div>
+
+            <div class="session-form">
+                <h2>{"내 API 키"}</h2>
+                {if api_keys.is_empty() {
+                    html! { <p class="form-hint">{"만든 API 키가 없습니다."}</p> }
+                } else {
+                    html! {
+                        <table class="api-key-table">
+                            <thead>
+                                <tr>
+                                    <th>{"이름"}</th>
+                                    <th>{"만든 시각"}</th>
+                                    <th>{"마지막 사용"}</th>
+                                    <th>{"만료"}</th>
+                                    <th></th>
+                                </tr>
+                            </thead>
+                            <tbody>
+                                {for api_keys.iter().map(|item| {
+                                    let on_revoke = on_revoke.clone();
+                                    let revoke_item = item.clone();
+                                    html! {
+                                        <tr key={item.key_id.clone()}>
+                                            <td>{&item.name}</td>
+                                            <td>{format_timestamp(Some(&item.created_at), "-")}</td>
+                                            <td>{format_timestamp(item.last_used_at.as_deref(), "사용 안 함")}</td>
+                                            <td>{format_timestamp(item.expires_at.as_deref(), "만료 없음")}</td>
+                                            <td>
+                                                <button
+                                                    class="danger"
+                                                    onclick={Callback::from(move |_: MouseEvent| on_revoke.emit(revoke_item.clone()))}
+                                                >
+                                                    {"폐기"}
+                                                </button>
+                                            </td>
+                                        </tr>
+                                    }
+                                })}
+                            </tbody>
+                        </table>
+                    }
+                }}
+            </div>
+
+            {if let Some(ref msg) = *error_message {
+                html! { <div class="error">{msg}</div> }
+            } else {
+                html! {}
+            }}
+        </div>
+    }
+}
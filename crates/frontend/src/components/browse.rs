@@ -0,0 +1,294 @@
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::api::directory::list_directory;
+use crate::routes::Route;
+use crate::types::{DirectoryEntry, DirectoryQuery};
+
+const PAGE_SIZE: usize = 24;
+
+fn entry_route(entry: &DirectoryEntry) -> Route {
+    if let Some(org_id) = &entry.org_id {
+        return Route::OrgSession {
+            org_id: org_id.clone(),
+            session_id: entry.session_id.clone(),
+        };
+    }
+    match &entry.user_id {
+        Some(user_id) => Route::UserSession {
+            user_id: user_id.clone(),
+            session_id: entry.session_id.clone(),
+        },
+        None => Route::PublicSession {
+            session_id: entry.session_id.clone(),
+        },
+    }
+}
+
+#[function_component(BrowsePage)]
+pub fn browse_page() -> Html {
+    let navigator = use_navigator().expect("Navigator must be available");
+
+    let entries = use_state(Vec::<DirectoryEntry>::new);
+    let next_cursor = use_state(|| None::<String>);
+    let error_message = use_state(|| None::<String>);
+    let loading = use_state(|| false);
+
+    let search_input = use_state(String::new);
+    let search = use_state(String::new);
+    let tag_filter = use_state(|| None::<String>);
+    let sort = use_state(|| "popular".to_string());
+
+    let build_query = {
+        let search = search.clone();
+        let tag_filter = tag_filter.clone();
+        let sort = sort.clone();
+
+        move |cursor: Option<String>| DirectoryQuery {
+            cursor,
+            limit: Some(PAGE_SIZE),
+            sort: Some((*sort).clone()),
+            q: Some((*search).clone()),
+            tag: (*tag_filter).clone(),
+        }
+    };
+
+    {
+        let entries = entries.clone();
+        let next_cursor = next_cursor.clone();
+        let error_message = error_message.clone();
+        let loading = loading.clone();
+        let query = build_query(None);
+
+        use_effect_with(
+            ((*search).clone(), (*tag_filter).clone(), (*sort).clone()),
+            move |_| {
+                loading.set(true);
+                error_message.set(None);
+
+                spawn_local(async move {
+                    match list_directory(&query).await {
+                        Ok(response) => {
+                            entries.set(response.items);
+                            next_cursor.set(response.next_cursor);
+                        }
+                        Err(e) => {
+                            error_message.set(Some(e));
+                        }
+                    }
+                    loading.set(false);
+                });
+            },
+        );
+    }
+
+    let on_load_more = {
+        let entries = entries.clone();
+        let next_cursor = next_cursor.clone();
+        let error_message = error_message.clone();
+        let build_query = build_query.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            let Some(cursor) = (*next_cursor).clone() else {
+                return;
+            };
+            let query = build_query(Some(cursor));
+            let entries = entries.clone();
+            let next_cursor = next_cursor.clone();
+            let error_message = error_message.clone();
+
+            spawn_local(async move {
+                match list_directory(&query).await {
+                    Ok(response) => {
+                        let mut items = (*entries).clone();
+                        items.extend(response.items);
+                        entries.set(items);
+                        next_cursor.set(response.next_cursor);
+                    }
+                    Err(e) => {
+                        error_message.set(Some(e));
+                    }
+                }
+            });
+        })
+    };
+
+    let on_search_input = {
+        let search_input = search_input.clone();
+
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            search_input.set(input.value());
+        })
+    };
+
+    let on_search_submit = {
+        let search_input = search_input.clone();
+        let search = search.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            search.set((*search_input).clone());
+        })
+    };
+
+    let on_sort_change = {
+        let sort = sort.clone();
+
+        Callback::from(move |e: Event| {
+            if let Some(select) = e
+                .target()
+                .and_then(|t| t.dyn_into::<HtmlSelectElement>().ok())
+            {
+                sort.set(select.value());
+            }
+        })
+    };
+
+    let on_tag_click = {
+        let tag_filter = tag_filter.clone();
+
+        move |tag: String| {
+            let tag_filter = tag_filter.clone();
+
+            Callback::from(move |e: MouseEvent| {
+                e.stop_propagation();
+                tag_filter.set(Some(tag.clone()));
+            })
+        }
+    };
+
+    let on_tag_clear = {
+        let tag_filter = tag_filter.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            tag_filter.set(None);
+        })
+    };
+
+    let on_entry_click = {
+        let navigator = navigator.clone();
+
+        move |route: Route| {
+            let navigator = navigator.clone();
+
+            Callback::from(move |_: MouseEvent| {
+                navigator.push(&route);
+            })
+        }
+    };
+
+    html! {
+        <div class="session-list-page browse-page">
+            <h2>{"공개 세션 둘러보기"}</h2>
+
+            <div class="session-list-toolbar">
+                <form class="session-search" onsubmit={on_search_submit}>
+                    <input
+                        type="search"
+                        placeholder="제목, 설명, 세션 ID 검색"
+                        value={(*search_input).clone()}
+                        oninput={on_search_input}
+                    />
+                    <button type="submit">{"검색"}</button>
+                </form>
+                <select onchange={on_sort_change}>
+                    <option value="popular" selected={*sort == "popular"}>{"인기순 (시청자)"}</option>
+                    <option value="recent" selected={*sort == "recent"}>{"최신순"}</option>
+                    <option value="title" selected={*sort == "title"}>{"제목순"}</option>
+                </select>
+            </div>
+
+            {if let Some(ref tag) = *tag_filter {
+                html! {
+                    <div class="session-tags active-filter">
+                        <span class="session-tag">{format!("#{}", tag)}</span>
+                        <button class="secondary" onclick={on_tag_clear}>{"태그 필터 해제"}</button>
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+
+            {if *loading {
+                html! { <p>{"로딩 중..."}</p> }
+            } else if let Some(ref msg) = *error_message {
+                html! { <div class="error">{msg}</div> }
+            } else if entries.is_empty() {
+                html! {
+                    <div class="empty-state">
+                        <p>{"디렉터리에 등록된 세션이 없습니다"}</p>
+                    </div>
+                }
+            } else {
+                html! {
+                    <>
+                        <div class="session-list">
+                            {entries.iter().map(|entry| {
+                                let key = entry.page_url.clone();
+                                html! {
+                                    <div
+                                        class="session-card"
+                                        onclick={on_entry_click(entry_route(entry))}
+                                        key={key}
+                                    >
+                                        <img
+                                            class="session-thumbnail"
+                                            src={entry.thumbnail_url.clone()}
+                                            alt={entry.session_id.clone()}
+                                            loading="lazy"
+                                        />
+                                        <h3>{entry.title.clone().unwrap_or_else(|| entry.session_id.clone())}</h3>
+                                        {if let Some(ref org_id) = entry.org_id {
+                                            html! { <p class="session-card-id">{format!("by {} (조직)", org_id)}</p> }
+                                        } else if let Some(ref user_id) = entry.user_id {
+                                            html! { <p class="session-card-id">{format!("by {}", user_id)}</p> }
+                                        } else {
+                                            html! {}
+                                        }}
+                                        {if let Some(ref description) = entry.description {
+                                            html! { <p class="session-card-description">{description}</p> }
+                                        } else {
+                                            html! {}
+                                        }}
+                                        <div class="session-card-meta">
+                                            <span>{format!("👁 {}", entry.viewers)}</span>
+                                        </div>
+                                        {if entry.tags.is_empty() {
+                                            html! {}
+                                        } else {
+                                            html! {
+                                                <div class="session-tags">
+                                                    {entry.tags.iter().map(|tag| html! {
+                                                        <span
+                                                            class="session-tag clickable"
+                                                            onclick={on_tag_click(tag.clone())}
+                                                        >
+                                                            {format!("#{}", tag)}
+                                                        </span>
+                                                    }).collect::<Html>()}
+                                                </div>
+                                            }
+                                        }}
+                                    </div>
+                                }
+                            }).collect::<Html>()}
+                        </div>
+                        {if next_cursor.is_some() {
+                            html! {
+                                <div class="session-list-more">
+                                    <button class="secondary" onclick={on_load_more}>{"더 보기"}</button>
+                                </div>
+                            }
+                        } else {
+                            html! {}
+                        }}
+                    </>
+                }
+            }}
+        </div>
+    }
+}
@@ -0,0 +1,189 @@
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use crate::api::collaborators::{list_collaborators, remove_collaborator, set_collaborator};
+use crate::types::{CollaboratorItem, SessionRole};
+
+#[derive(Properties, PartialEq)]
+pub struct CollaboratorsPanelProps {
+    pub user_id: String,
+    pub session_id: String,
+}
+
+/// 세션을 다른 사용자와 공유하는 패널. 소유자에게만 보입니다.
+#[function_component(CollaboratorsPanel)]
+pub fn collaborators_panel(props: &CollaboratorsPanelProps) -> Html {
+    let collaborators = use_state(Vec::<CollaboratorItem>::new);
+    let error_message = use_state(|| None::<String>);
+    let loading = use_state(|| false);
+    let collaborator_ref = use_node_ref();
+    let role = use_state(|| SessionRole::Viewer);
+    let reload = use_state(|| 0u32);
+
+    {
+        let user_id = props.user_id.clone();
+        let session_id = props.session_id.clone();
+        let collaborators = collaborators.clone();
+        let error_message = error_message.clone();
+
+        use_effect_with(*reload, move |_| {
+            spawn_local(async move {
+                match list_collaborators(&user_id, &session_id).await {
+                    Ok(items) => collaborators.set(items),
+                    Err(e) => error_message.set(Some(e)),
+                }
+            });
+        });
+    }
+
+    // 추가, 역할 변경, 제거가 끝나면 목록을 다시 읽습니다.
+    let apply = {
+        let user_id = props.user_id.clone();
+        let session_id = props.session_id.clone();
+        let error_message = error_message.clone();
+        let loading = loading.clone();
+        let reload = reload.clone();
+
+        Callback::from(
+            move |(collaborator_id, role): (String, Option<SessionRole>)| {
+                let user_id = user_id.clone();
+                let session_id = session_id.clone();
+                let error_message = error_message.clone();
+                let loading = loading.clone();
+                let reload = reload.clone();
+
+                loading.set(true);
+                error_message.set(None);
+
+                spawn_local(async move {
+                    let result = match role {
+                        Some(role) => {
+                            set_collaborator(&user_id, &session_id, &collaborator_id, role).await
+                        }
+                        None => remove_collaborator(&user_id, &session_id, &collaborator_id).await,
+                    };
+                    match result {
+                        Ok(()) => reload.set(*reload + 1),
+                        Err(e) => error_message.set(Some(e)),
+                    }
+                    loading.set(false);
+                });
+            },
+        )
+    };
+
+    let on_add = {
+        let collaborator_ref = collaborator_ref.clone();
+        let role = role.clone();
+        let error_message = error_message.clone();
+        let apply = apply.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let Some(input) = collaborator_ref.cast::<HtmlInputElement>() else {
+                return;
+            };
+            let collaborator_id = input.value().trim().to_string();
+            if collaborator_id.is_empty() {
+                error_message.set(Some("사용자 ID를 입력하세요".to_string()));
+                return;
+            }
+            input.set_value("");
+            apply.emit((collaborator_id, Some(*role)));
+        })
+    };
+
+    let on_role_change = {
+        let role = role.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            role.set(SessionRole::parse(&select.value()));
+        })
+    };
+
+    html! {
+        <div class="args-editor collaborators">
+            <h3>{"공유"}</h3>
+            <p class="form-hint">
+                {"편집자는 매개변수와 제목·설명·태그를 수정할 수 있고, 뷰어는 조회만 할 수 있습니다."}
+            </p>
+            <form class="collaborator-form" onsubmit={on_add}>
+                <input
+                    type="text"
+                    ref={collaborator_ref}
+                    placeholder="사용자 ID"
+                    disabled={*loading}
+                />
+                <select onchange={on_role_change} disabled={*loading}>
+                    {for SessionRole::ALL.iter().map(|option| html! {
+                        <option value={option.as_str()} selected={*role == *option}>
+                            {option.label()}
+                        </option>
+                    })}
+                </select>
+                <button type="submit" disabled={*loading}>{"추가"}</button>
+            </form>
+
+            {if collaborators.is_empty() {
+                html! { <p class="form-hint">{"아직 공유한 사용자가 없습니다."}</p> }
+            } else {
+                html! {
+                    <table class="api-key-table">
+                        <thead>
+                            <tr>
+                                <th>{"사용자"}</th>
+                                <th>{"역할"}</th>
+                                <th></th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            {for collaborators.iter().map(|item| {
+                                let change_apply = apply.clone();
+                                let change_id = item.user_id.clone();
+                                let remove_apply = apply.clone();
+                                let remove_id = item.user_id.clone();
+                                html! {
+                                    <tr key={item.user_id.clone()}>
+                                        <td>{&item.user_id}</td>
+                                        <td>
+                                            <select
+                                                disabled={*loading}
+                                                onchange={Callback::from(move |e: Event| {
+                                                    let select: HtmlSelectElement = e.target_unchecked_into();
+                                                    change_apply.emit((change_id.clone(), Some(SessionRole::parse(&select.value()))));
+                                                })}
+                                            >
+                                                {for SessionRole::ALL.iter().map(|option| html! {
+                                                    <option value={option.as_str()} selected={item.role == *option}>
+                                                        {option.label()}
+                                                    </option>
+                                                })}
+                                            </select>
+                                        </td>
+                                        <td>
+                                            <button
+                                                class="danger"
+                                                disabled={*loading}
+                                                onclick={Callback::from(move |_: MouseEvent| remove_apply.emit((remove_id.clone(), None)))}
+                                            >
+                                                {"제거"}
+                                            </button>
+                                        </td>
+                                    </tr>
+                                }
+                            })}
+                        </tbody>
+                    </table>
+                }
+            }}
+
+            {if let Some(ref msg) = *error_message {
+                html! { <div class="error">{msg}</div> }
+            } else {
+                html! {}
+            }}
+        </div>
+    }
+}
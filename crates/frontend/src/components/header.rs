@@ -1,6 +1,8 @@
-use crate::auth::storage::{LocalTokenStorage, TokenStorage};
+use crate::api::auth::logout;
+use crate::auth::storage::token_storage;
 use crate::auth::{AuthContext, AuthState};
 use crate::routes::Route;
+use wasm_bindgen_futures::spawn_local;
 use yew::prelude::*;
 use yew_router::prelude::*;
 
@@ -12,9 +14,15 @@ pub fn header() -> Html {
         let auth_context = auth_context.clone();
 
         Callback::from(move |_| {
-            let storage = LocalTokenStorage::new();
-            let _ = storage.remove_token();
-            auth_context.set(AuthState::Anonymous);
+            let auth_context = auth_context.clone();
+            spawn_local(async move {
+                // 서버 폐기에 실패해도 이 브라우저에서는 로그아웃합니다.
+                if let Err(e) = logout(false).await {
+                    log::warn!("Logout request failed: {}", e);
+                }
+                let _ = token_storage().remove_token();
+                auth_context.set(AuthState::Anonymous);
+            });
         })
     };
 
@@ -24,12 +32,35 @@ pub fn header() -> Html {
                 <h1><Link<Route> to={Route::Home}>{"realtime-svg"}</Link<Route>></h1>
 
                 <nav class="header-nav">
+                    <Link<Route> to={Route::Browse} classes="nav-link">
+                        {"둘러보기"}
+                    </Link<Route>>
                     {match &*auth_context {
                         AuthState::Anonymous => html! {},
-                        AuthState::Authenticated { .. } => html! {
-                            <Link<Route> to={Route::MySessions} classes="nav-link">
-                                {"내 세션 목록"}
-                            </Link<Route>>
+                        AuthState::Authenticated { admin, .. } => html! {
+                            <>
+                                <Link<Route> to={Route::MySessions} classes="nav-link">
+                                    {"내 세션 목록"}
+                                </Link<Route>>
+                                <Link<Route> to={Route::Orgs} classes="nav-link">
+                                    {"조직"}
+                                </Link<Route>>
+                                <Link<Route> to={Route::ApiKeys} classes="nav-link">
+                                    {"API 키"}
+                                </Link<Route>>
+                                <Link<Route> to={Route::Account} classes="nav-link">
+                                    {"계정"}
+                                </Link<Route>>
+                                {if *admin {
+                                    html! {
+                                        <Link<Route> to={Route::Admin} classes="nav-link">
+                                            {"관리"}
+                                        </Link<Route>>
+                                    }
+                                } else {
+                                    html! {}
+                                }}
+                            </>
                         }
                     }}
                 </nav>
@@ -2,9 +2,8 @@ use wasm_bindgen_futures::spawn_local;
 use web_sys::HtmlInputElement;
 use yew::prelude::*;
 
-use crate::api::auth::request_token;
+use crate::api::auth::{login_with_cookie, oidc_enabled, register};
 use crate::auth::storage::{LocalTokenStorage, TokenStorage};
-use crate::auth::token::decode_claims;
 use crate::auth::{AuthContext, AuthState};
 
 #[function_component(LoginForm)]
@@ -13,13 +12,25 @@ pub fn login_form() -> Html {
     let password_ref = use_node_ref();
     let error_message = use_state(|| None::<String>);
     let loading = use_state(|| false);
+    let register_mode = use_state(|| false);
+    let sso_enabled = use_state(|| false);
     let auth_context = use_context::<AuthContext>().expect("AuthContext must be provided");
 
+    {
+        let sso_enabled = sso_enabled.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                sso_enabled.set(oidc_enabled().await);
+            });
+        });
+    }
+
     let on_submit = {
         let user_id_ref = user_id_ref.clone();
         let password_ref = password_ref.clone();
         let error_message = error_message.clone();
         let loading = loading.clone();
+        let register_mode = register_mode.clone();
         let auth_context = auth_context.clone();
 
         Callback::from(move |e: SubmitEvent| {
@@ -48,31 +59,26 @@ pub fn login_form() -> Html {
             let error_message = error_message.clone();
             let loading = loading.clone();
             let auth_context = auth_context.clone();
+            let registering = *register_mode;
 
             loading.set(true);
             error_message.set(None);
 
             spawn_local(async move {
-                match request_token(user_id.clone(), password, None).await {
-                    Ok(token) => {
-                        let storage = LocalTokenStorage::new();
-                        if let Err(e) = storage.set_token(&token) {
-                            error_message.set(Some(format!("토큰 저장 실패: {}", e)));
-                            loading.set(false);
-                            return;
-                        }
-
-                        match decode_claims(&token) {
-                            Ok(claims) => {
-                                auth_context.set(AuthState::Authenticated {
-                                    user_id: claims.sub,
-                                    token,
-                                });
-                            }
-                            Err(e) => {
-                                error_message.set(Some(format!("토큰 디코딩 실패: {}", e)));
-                            }
-                        }
+                if registering {
+                    if let Err(e) = register(user_id.clone(), password.clone()).await {
+                        error_message.set(Some(e));
+                        loading.set(false);
+                        return;
+                    }
+                }
+
+                // 토큰은 스크립트가 읽을 수 없는 HttpOnly 쿠키로 받습니다.
+                match login_with_cookie(user_id.clone(), password).await {
+                    Ok(session) => {
+                        // 예전 로그인이 localStorage에 남긴 토큰은 더 이상 쓰지 않습니다.
+                        let _ = LocalTokenStorage::new().remove_token();
+                        auth_context.set(AuthState::from_cookie_session(session));
                     }
                     Err(e) => {
                         error_message.set(Some(e));
@@ -83,9 +89,34 @@ pub fn login_form() -> Html {
         })
     };
 
+    let on_toggle_mode = {
+        let register_mode = register_mode.clone();
+        let error_message = error_message.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            register_mode.set(!*register_mode);
+            error_message.set(None);
+        })
+    };
+
+    // 제공자 로그인 페이지로 이동합니다. 로그인을 마치면 `/auth/callback`으로 돌아옵니다.
+    let on_sso_login = Callback::from(|_: MouseEvent| {
+        if let Some(window) = web_sys::window() {
+            let _ = window
+                .location()
+                .set_href("/api/auth/oidc/login?cookie=true");
+        }
+    });
+
+    let title = if *register_mode {
+        "회원가입"
+    } else {
+        "로그인"
+    };
+
     html! {
         <div class="login-form">
-            <h2>{"로그인"}</h2>
+            <h2>{title}</h2>
             <form onsubmit={on_submit}>
                 <div class="form-group">
                     <label for="user_id">{"User ID:"}</label>
@@ -115,10 +146,32 @@ pub fn login_form() -> Html {
                     html! {}
                 }}
 
+                {if *register_mode {
+                    html! {
+                        <p class="form-hint">
+                            {"사용자 ID는 3~64자의 영문, 숫자, '.', '_', '-' (영문이나 숫자로 시작), 비밀번호는 8자 이상입니다"}
+                        </p>
+                    }
+                } else {
+                    html! {}
+                }}
+
                 <button type="submit" disabled={*loading}>
-                    {if *loading { "처리 중..." } else { "로그인" }}
+                    {if *loading { "처리 중..." } else { title }}
                 </button>
             </form>
+            <button class="secondary" onclick={on_toggle_mode} disabled={*loading}>
+                {if *register_mode { "이미 계정이 있으신가요? 로그인" } else { "계정이 없으신가요? 회원가입" }}
+            </button>
+            {if *sso_enabled {
+                html! {
+                    <button class="secondary" onclick={on_sso_login} disabled={*loading}>
+                        {"SSO로 로그인"}
+                    </button>
+                }
+            } else {
+                html! {}
+            }}
         </div>
     }
 }
@@ -1,11 +1,26 @@
+pub mod account;
+pub mod admin;
+pub mod api_keys;
+pub mod browse;
+pub mod collaborators;
 pub mod header;
 pub mod login_form;
+pub mod oidc_callback;
+pub mod orgs;
 pub mod session_detail;
 pub mod session_form;
 pub mod session_list;
+pub mod share_links;
+pub mod transfers;
 
+pub use account::AccountPage;
+pub use admin::AdminPage;
+pub use api_keys::ApiKeysPage;
+pub use browse::BrowsePage;
 pub use header::Header;
 pub use login_form::LoginForm;
+pub use oidc_callback::OidcCallbackPage;
+pub use orgs::{OrgPage, OrgsPage};
 pub use session_detail::SessionDetailPage;
 pub use session_form::{SessionForm, SessionMode};
 pub use session_list::SessionListPage;
@@ -0,0 +1,94 @@
+use wasm_bindgen_futures::spawn_local;
+use web_sys::UrlSearchParams;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::api::auth::cookie_session;
+use crate::auth::storage::{LocalTokenStorage, TokenStorage};
+use crate::auth::{save_tokens_to, AuthContext, AuthState};
+use crate::routes::Route;
+use crate::types::TokenResponse;
+
+/// 서버가 `#error=...`로 돌려준 실패 코드를 안내 문구로 바꿉니다.
+fn error_text(code: &str) -> String {
+    match code {
+        "account_exists" => {
+            "같은 사용자 ID의 계정이 이미 있습니다. 비밀번호로 로그인하세요".to_string()
+        }
+        "account_disabled" => "비활성화된 계정입니다".to_string(),
+        "invalid_user_id" => "제공자의 사용자 이름을 사용자 ID로 쓸 수 없습니다".to_string(),
+        "access_denied" => "SSO 로그인이 취소되었습니다".to_string(),
+        "login_failed" => "SSO 로그인에 실패했습니다. 다시 시도하세요".to_string(),
+        other => format!("SSO 로그인 실패 ({})", other),
+    }
+}
+
+/// 현재 주소의 fragment를 읽어 로그인을 마칩니다.
+///
+/// 쿠키 로그인(`#cookie=1`)이면 서버에 로그인 정보를 묻고, fragment로 토큰을 받았으면
+/// localStorage에 저장합니다.
+async fn complete_login() -> Result<AuthState, String> {
+    let hash = web_sys::window()
+        .and_then(|w| w.location().hash().ok())
+        .unwrap_or_default();
+    let params = UrlSearchParams::new_with_str(hash.trim_start_matches('#'))
+        .map_err(|_| "잘못된 콜백 주소입니다".to_string())?;
+
+    if let Some(error) = params.get("error") {
+        return Err(error_text(&error));
+    }
+    if params.get("cookie").is_some() {
+        let session = cookie_session().await?;
+        let _ = LocalTokenStorage::new().remove_token();
+        return Ok(AuthState::from_cookie_session(session));
+    }
+    let token = params
+        .get("token")
+        .ok_or_else(|| "잘못된 콜백 주소입니다".to_string())?;
+    let tokens = TokenResponse {
+        token,
+        refresh_token: params.get("refresh_token"),
+    };
+    save_tokens_to(&LocalTokenStorage::new(), &tokens)
+        .map_err(|e| format!("토큰 저장 실패: {}", e))?;
+    AuthState::from_token(&tokens.token).map_err(|e| format!("토큰 디코딩 실패: {}", e))
+}
+
+/// SSO 로그인을 마친 브라우저가 돌아오는 페이지.
+#[function_component(OidcCallbackPage)]
+pub fn oidc_callback_page() -> Html {
+    let auth_context = use_context::<AuthContext>().expect("AuthContext must be provided");
+    let navigator = use_navigator().expect("Navigator must be available");
+    let error_message = use_state(|| None::<String>);
+
+    {
+        let error_message = error_message.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                match complete_login().await {
+                    Ok(state) => {
+                        auth_context.set(state);
+                        // 토큰이 담긴 주소를 방문 기록에 남기지 않습니다.
+                        navigator.replace(&Route::Home);
+                    }
+                    Err(e) => error_message.set(Some(e)),
+                }
+            });
+        });
+    }
+
+    html! {
+        <div class="account-page">
+            {if let Some(ref msg) = *error_message {
+                html! {
+                    <>
+                        <div class="error">{msg}</div>
+                        <Link<Route> to={Route::Home}>{"홈으로 돌아가기"}</Link<Route>>
+                    </>
+                }
+            } else {
+                html! { <p class="form-hint">{"로그인 중..."}</p> }
+            }}
+        </div>
+    }
+}
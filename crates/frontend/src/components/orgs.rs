@@ -0,0 +1,631 @@
+use std::collections::HashMap;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{HtmlInputElement, HtmlSelectElement, HtmlTextAreaElement};
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::api::orgs::{
+    create_org, create_org_session, delete_org, delete_org_session, get_org, list_org_sessions,
+    list_orgs, remove_org_member, set_org_member,
+};
+use crate::auth::{refresh_login, AuthContext, AuthState};
+use crate::routes::Route;
+use crate::types::{
+    OrgCreateRequest, OrgDetail, OrgItem, OrgRole, OrgSessionCreateRequest, SessionListItem,
+    SessionMetadata,
+};
+
+/// 조직 세션 권한은 토큰의 `orgs` claim으로 확인하므로, 내 멤버십이 바뀌면 토큰을 다시 받습니다.
+async fn refresh_memberships(auth_context: AuthContext) {
+    match refresh_login().await {
+        Ok(refreshed) => auth_context.set(refreshed),
+        Err(e) => log::warn!("Token refresh failed: {}", e),
+    }
+}
+
+#[function_component(OrgsPage)]
+pub fn orgs_page() -> Html {
+    let auth_context = use_context::<AuthContext>().expect("AuthContext must be provided");
+    let navigator = use_navigator().expect("Navigator must be available");
+
+    let orgs = use_state(Vec::<OrgItem>::new);
+    let error_message = use_state(|| None::<String>);
+    let loading = use_state(|| false);
+    let org_id_ref = use_node_ref();
+    let name_ref = use_node_ref();
+
+    {
+        let auth_context = auth_context.clone();
+        let orgs = orgs.clone();
+        let error_message = error_message.clone();
+
+        use_effect_with((), move |_| {
+            if let AuthState::Authenticated { user_id, .. } = &*auth_context {
+                let user_id = user_id.clone();
+                spawn_local(async move {
+                    match list_orgs(&user_id).await {
+                        Ok(items) => orgs.set(items),
+                        Err(e) => error_message.set(Some(e)),
+                    }
+                });
+            }
+        });
+    }
+
+    let on_create = {
+        let auth_context = auth_context.clone();
+        let navigator = navigator.clone();
+        let org_id_ref = org_id_ref.clone();
+        let name_ref = name_ref.clone();
+        let error_message = error_message.clone();
+        let loading = loading.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let value = |node: &NodeRef| {
+                node.cast::<HtmlInputElement>()
+                    .map(|input| input.value().trim().to_string())
+                    .unwrap_or_default()
+            };
+            let request = OrgCreateRequest {
+                org_id: value(&org_id_ref),
+                name: value(&name_ref),
+            };
+            if request.org_id.is_empty() || request.name.is_empty() {
+                error_message.set(Some("조직 ID와 이름을 입력하세요".to_string()));
+                return;
+            }
+
+            let auth_context = auth_context.clone();
+            let navigator = navigator.clone();
+            let error_message = error_message.clone();
+            let loading = loading.clone();
+
+            loading.set(true);
+            error_message.set(None);
+
+            spawn_local(async move {
+                let org_id = request.org_id.clone();
+                match create_org(request).await {
+                    Ok(()) => {
+                        refresh_memberships(auth_context).await;
+                        navigator.push(&Route::Org { org_id });
+                    }
+                    Err(e) => error_message.set(Some(e)),
+                }
+                loading.set(false);
+            });
+        })
+    };
+
+    if matches!(&*auth_context, AuthState::Anonymous) {
+        return html! {
+            <div class="account-page">
+                <div class="error">{"로그인이 필요합니다"}</div>
+            </div>
+        };
+    }
+
+    html! {
+        <div class="account-page">
+            <div class="session-form">
+                <h2>{"조직 만들기"}</h2>
+                <p class="form-hint">
+                    {"조직 세션은 만든 사람이 아니라 조직에 속하므로, 멤버가 떠나도 남습니다."}
+                </p>
+                <form onsubmit={on_create}>
+                    <div class="form-group">
+                        <label for="org_id">{"조직 ID:"}</label>
+                        <input
+                            type="text"
+                            id="org_id"
+                            ref={org_id_ref}
+                            placeholder="design-team"
+                            maxlength="128"
+                            disabled={*loading}
+                        />
+                    </div>
+                    <div class="form-group">
+                        <label for="org_name">{"이름:"}</label>
+                        <input
+                            type="text"
+                            id="org_name"
+                            ref={name_ref}
+                            placeholder="디자인 팀"
+                            maxlength="100"
+                            disabled={*loading}
+                        />
+                    </div>
+                    <button type="submit" disabled={*loading}>
+                        {if *loading { "처리 중..." } else { "조직 만들기" }}
+                    </button>
+                </form>
+            </div>
+
+            <div class="session-form">
+                <h2>{"내 조직"}</h2>
+                {if orgs.is_empty() {
+                    html! { <p class="form-hint">{"속한 조직이 없습니다."}</p> }
+                } else {
+                    html! {
+                        <table class="api-key-table">
+                            <thead>
+                                <tr>
+                                    <th>{"조직"}</th>
+                                    <th>{"역할"}</th>
+                                </tr>
+                            </thead>
+                            <tbody>
+                                {for orgs.iter().map(|org| html! {
+                                    <tr key={org.org_id.clone()}>
+                                        <td>
+                                            <Link<Route> to={Route::Org { org_id: org.org_id.clone() }}>
+                                                {format!("{} ({})", org.name, org.org_id)}
+                                            </Link<Route>>
+                                        </td>
+                                        <td>{org.role.label()}</td>
+                                    </tr>
+                                })}
+                            </tbody>
+                        </table>
+                    }
+                }}
+            </div>
+
+            {if let Some(ref msg) = *error_message {
+                html! { <div class="error">{msg}</div> }
+            } else {
+                html! {}
+            }}
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct OrgPageProps {
+    pub org_id: String,
+}
+
+/// 조직 상세: 멤버 관리(소유자)와 조직 세션 목록
+#[function_component(OrgPage)]
+pub fn org_page(props: &OrgPageProps) -> Html {
+    let auth_context = use_context::<AuthContext>().expect("AuthContext must be provided");
+    let navigator = use_navigator().expect("Navigator must be available");
+
+    let org = use_state(|| None::<OrgDetail>);
+    let sessions = use_state(Vec::<SessionListItem>::new);
+    let error_message = use_state(|| None::<String>);
+    let loading = use_state(|| false);
+    let member_ref = use_node_ref();
+    let member_role = use_state(|| OrgRole::Member);
+    let session_id_ref = use_node_ref();
+    let template_ref = use_node_ref();
+    let reload = use_state(|| 0u32);
+
+    {
+        let org_id = props.org_id.clone();
+        let org = org.clone();
+        let sessions = sessions.clone();
+        let error_message = error_message.clone();
+
+        use_effect_with((org_id.clone(), *reload), move |_| {
+            spawn_local(async move {
+                match get_org(&org_id).await {
+                    Ok(detail) => org.set(Some(detail)),
+                    Err(e) => {
+                        error_message.set(Some(e));
+                        return;
+                    }
+                }
+                match list_org_sessions(&org_id).await {
+                    Ok(response) => sessions.set(response.items),
+                    Err(e) => error_message.set(Some(e)),
+                }
+            });
+        });
+    }
+
+    let current_user = match &*auth_context {
+        AuthState::Authenticated { user_id, .. } => Some(user_id.clone()),
+        AuthState::Anonymous => None,
+    };
+    let role = (*org).as_ref().map(|detail| detail.role);
+    let is_owner = role == Some(OrgRole::Owner);
+
+    // 멤버 추가, 역할 변경, 제거가 끝나면 다시 읽습니다. 내 멤버십이 바뀌면 토큰도 갱신합니다.
+    let apply_member = {
+        let org_id = props.org_id.clone();
+        let auth_context = auth_context.clone();
+        let navigator = navigator.clone();
+        let current_user = current_user.clone();
+        let error_message = error_message.clone();
+        let loading = loading.clone();
+        let reload = reload.clone();
+
+        Callback::from(move |(member_id, role): (String, Option<OrgRole>)| {
+            let org_id = org_id.clone();
+            let auth_context = auth_context.clone();
+            let navigator = navigator.clone();
+            let is_self = current_user.as_deref() == Some(member_id.as_str());
+            let error_message = error_message.clone();
+            let loading = loading.clone();
+            let reload = reload.clone();
+
+            loading.set(true);
+            error_message.set(None);
+
+            spawn_local(async move {
+                let result = match role {
+                    Some(role) => set_org_member(&org_id, &member_id, role).await,
+                    None => remove_org_member(&org_id, &member_id).await,
+                };
+                match result {
+                    Ok(()) => {
+                        if is_self {
+                            refresh_memberships(auth_context).await;
+                        }
+                        if is_self && role.is_none() {
+                            navigator.push(&Route::Orgs);
+                        } else {
+                            reload.set(*reload + 1);
+                        }
+                    }
+                    Err(e) => error_message.set(Some(e)),
+                }
+                loading.set(false);
+            });
+        })
+    };
+
+    let on_add_member = {
+        let member_ref = member_ref.clone();
+        let member_role = member_role.clone();
+        let error_message = error_message.clone();
+        let apply_member = apply_member.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let Some(input) = member_ref.cast::<HtmlInputElement>() else {
+                return;
+            };
+            let member_id = input.value().trim().to_string();
+            if member_id.is_empty() {
+                error_message.set(Some("사용자 ID를 입력하세요".to_string()));
+                return;
+            }
+            input.set_value("");
+            apply_member.emit((member_id, Some(*member_role)));
+        })
+    };
+
+    let on_create_session = {
+        let org_id = props.org_id.clone();
+        let navigator = navigator.clone();
+        let session_id_ref = session_id_ref.clone();
+        let template_ref = template_ref.clone();
+        let error_message = error_message.clone();
+        let loading = loading.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let session_id = session_id_ref
+                .cast::<HtmlInputElement>()
+                .map(|input| input.value().trim().to_string())
+                .unwrap_or_default();
+            let template = template_ref
+                .cast::<HtmlTextAreaElement>()
+                .map(|textarea| textarea.value())
+                .unwrap_or_default();
+            if session_id.is_empty() || template.trim().is_empty() {
+                error_message.set(Some("세션 ID와 템플릿을 입력하세요".to_string()));
+                return;
+            }
+
+            let org_id = org_id.clone();
+            let navigator = navigator.clone();
+            let error_message = error_message.clone();
+            let loading = loading.clone();
+            let request = OrgSessionCreateRequest {
+                session_id: session_id.clone(),
+                template,
+                args: HashMap::new(),
+                ttl_seconds: None,
+                metadata: SessionMetadata::default(),
+            };
+
+            loading.set(true);
+            error_message.set(None);
+
+            spawn_local(async move {
+                match create_org_session(&org_id, request).await {
+                    Ok(()) => navigator.push(&Route::OrgSession { org_id, session_id }),
+                    Err(e) => error_message.set(Some(e)),
+                }
+                loading.set(false);
+            });
+        })
+    };
+
+    let on_delete_session = {
+        let org_id = props.org_id.clone();
+        let error_message = error_message.clone();
+        let reload = reload.clone();
+
+        Callback::from(move |session_id: String| {
+            let confirmed = web_sys::window()
+                .and_then(|w| {
+                    w.confirm_with_message(&format!("'{}' 세션을 삭제할까요?", session_id))
+                        .ok()
+                })
+                .unwrap_or(false);
+            if !confirmed {
+                return;
+            }
+
+            let org_id = org_id.clone();
+            let error_message = error_message.clone();
+            let reload = reload.clone();
+            spawn_local(async move {
+                match delete_org_session(&org_id, &session_id).await {
+                    Ok(()) => reload.set(*reload + 1),
+                    Err(e) => error_message.set(Some(e)),
+                }
+            });
+        })
+    };
+
+    let on_delete_org = {
+        let org_id = props.org_id.clone();
+        let auth_context = auth_context.clone();
+        let navigator = navigator.clone();
+        let error_message = error_message.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            let confirmed = web_sys::window()
+                .and_then(|w| {
+                    w.confirm_with_message(
+                        "조직을 삭제할까요? 조직 세션도 모두 함께 삭제되며 되돌릴 수 없습니다.",
+                    )
+                    .ok()
+                })
+                .unwrap_or(false);
+            if !confirmed {
+                return;
+            }
+
+            let org_id = org_id.clone();
+            let auth_context = auth_context.clone();
+            let navigator = navigator.clone();
+            let error_message = error_message.clone();
+            spawn_local(async move {
+                match delete_org(&org_id).await {
+                    Ok(()) => {
+                        refresh_memberships(auth_context).await;
+                        navigator.push(&Route::Orgs);
+                    }
+                    Err(e) => error_message.set(Some(e)),
+                }
+            });
+        })
+    };
+
+    let Some(detail) = (*org).clone() else {
+        return html! {
+            <div class="account-page">
+                {if let Some(ref msg) = *error_message {
+                    html! { <div class="error">{msg}</div> }
+                } else {
+                    html! { <p>{"로딩 중..."}</p> }
+                }}
+            </div>
+        };
+    };
+    let can_edit = detail.role.can_edit();
+
+    html! {
+        <div class="account-page org-page">
+            <div class="session-form">
+                <h2>{format!("{} ({})", detail.name, detail.org_id)}</h2>
+                <p class="form-hint">{format!("내 역할: {}", detail.role.label())}</p>
+
+                {if is_owner {
+                    html! {
+                        <form class="collaborator-form" onsubmit={on_add_member}>
+                            <input
+                                type="text"
+                                ref={member_ref}
+                                placeholder="사용자 ID"
+                                disabled={*loading}
+                            />
+                            <select
+                                disabled={*loading}
+                                onchange={{
+                                    let member_role = member_role.clone();
+                                    Callback::from(move |e: Event| {
+                                        let select: HtmlSelectElement = e.target_unchecked_into();
+                                        member_role.set(OrgRole::parse(&select.value()));
+                                    })
+                                }}
+                            >
+                                {for OrgRole::ALL.iter().map(|option| html! {
+                                    <option value={option.as_str()} selected={*member_role == *option}>
+                                        {option.label()}
+                                    </option>
+                                })}
+                            </select>
+                            <button type="submit" disabled={*loading}>{"추가"}</button>
+                        </form>
+                    }
+                } else {
+                    html! {}
+                }}
+
+                <table class="api-key-table">
+                    <thead>
+                        <tr>
+                            <th>{"멤버"}</th>
+                            <th>{"역할"}</th>
+                            <th></th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {for detail.members.iter().map(|member| {
+                            let is_self = current_user.as_deref() == Some(member.user_id.as_str());
+                            let change_apply = apply_member.clone();
+                            let change_id = member.user_id.clone();
+                            let remove_apply = apply_member.clone();
+                            let remove_id = member.user_id.clone();
+                            html! {
+                                <tr key={member.user_id.clone()}>
+                                    <td>{&member.user_id}</td>
+                                    <td>
+                                        {if is_owner {
+                                            html! {
+                                                <select
+                                                    disabled={*loading}
+                                                    onchange={Callback::from(move |e: Event| {
+                                                        let select: HtmlSelectElement = e.target_unchecked_into();
+                                                        change_apply.emit((change_id.clone(), Some(OrgRole::parse(&select.value()))));
+                                                    })}
+                                                >
+                                                    {for OrgRole::ALL.iter().map(|option| html! {
+                                                        <option value={option.as_str()} selected={member.role == *option}>
+                                                            {option.label()}
+                                                        </option>
+                                                    })}
+                                                </select>
+                                            }
+                                        } else {
+                                            html! { {member.role.label()} }
+                                        }}
+                                    </td>
+                                    <td>
+                                        {if is_owner || is_self {
+                                            html! {
+                                                <button
+                                                    class="danger"
+                                                    disabled={*loading}
+                                                    onclick={Callback::from(move |_: MouseEvent| remove_apply.emit((remove_id.clone(), None)))}
+                                                >
+                                                    {if is_self { "나가기" } else { "제거" }}
+                                                </button>
+                                            }
+                                        } else {
+                                            html! {}
+                                        }}
+                                    </td>
+                                </tr>
+                            }
+                        })}
+                    </tbody>
+                </table>
+            </div>
+
+            {if can_edit {
+                html! {
+                    <div class="session-form">
+                        <h2>{"조직 세션 만들기"}</h2>
+                        <form onsubmit={on_create_session}>
+                            <div class="form-group">
+                                <label for="org_session_id">{"세션 ID:"}</label>
+                                <input
+                                    type="text"
+                                    id="org_session_id"
+                                    ref={session_id_ref}
+                                    placeholder="status.svg"
+                                    disabled={*loading}
+                                />
+                            </div>
+                            <div class="form-group">
+                                <label for="org_session_template">{"템플릿:"}</label>
+                                <textarea
+                                    id="org_session_template"
+                                    ref={template_ref}
+                                    rows="6"
+                                    placeholder="<svg>...</svg>"
+                                    disabled={*loading}
+                                />
+                            </div>
+                            <button type="submit" disabled={*loading}>{"세션 만들기"}</button>
+                        </form>
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+
+            <div class="org-sessions">
+                <h2>{"조직 세션"}</h2>
+                {if sessions.is_empty() {
+                    html! { <p class="form-hint">{"조직 세션이 없습니다."}</p> }
+                } else {
+                    html! {
+                        <div class="session-list">
+                            {for sessions.iter().map(|session| {
+                                let onclick = {
+                                    let navigator = navigator.clone();
+                                    let route = Route::OrgSession {
+                                        org_id: detail.org_id.clone(),
+                                        session_id: session.session_id.clone(),
+                                    };
+                                    Callback::from(move |_: MouseEvent| navigator.push(&route))
+                                };
+                                let on_delete = {
+                                    let on_delete_session = on_delete_session.clone();
+                                    let session_id = session.session_id.clone();
+                                    Callback::from(move |e: MouseEvent| {
+                                        e.stop_propagation();
+                                        on_delete_session.emit(session_id.clone());
+                                    })
+                                };
+                                html! {
+                                    <div class="session-card" {onclick} key={session.session_id.clone()}>
+                                        <img
+                                            class="session-thumbnail"
+                                            src={session.thumbnail_url.clone()}
+                                            alt={session.session_id.clone()}
+                                            loading="lazy"
+                                        />
+                                        <h3>{session.title.clone().unwrap_or_else(|| session.session_id.clone())}</h3>
+                                        <p class="session-card-id">{&session.session_id}</p>
+                                        <div class="session-card-meta">
+                                            <span>{format!("👁 {}", session.viewers)}</span>
+                                            {if can_edit {
+                                                html! { <button class="danger" onclick={on_delete}>{"삭제"}</button> }
+                                            } else {
+                                                html! {}
+                                            }}
+                                        </div>
+                                    </div>
+                                }
+                            })}
+                        </div>
+                    }
+                }}
+            </div>
+
+            {if let Some(ref msg) = *error_message {
+                html! { <div class="error">{msg}</div> }
+            } else {
+                html! {}
+            }}
+
+            {if is_owner {
+                html! {
+                    <div class="session-form danger-zone">
+                        <h2>{"조직 삭제"}</h2>
+                        <p class="form-hint">
+                            {"조직과 모든 조직 세션이 삭제되고, 열려 있는 스트림도 종료됩니다. 되돌릴 수 없습니다."}
+                        </p>
+                        <button class="danger" onclick={on_delete_org}>{"조직 삭제"}</button>
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+        </div>
+    }
+}
@@ -1,29 +1,60 @@
 use std::collections::HashMap;
 use wasm_bindgen_futures::spawn_local;
-use web_sys::HtmlTextAreaElement;
+use web_sys::{HtmlInputElement, HtmlSelectElement, HtmlTextAreaElement};
 use yew::prelude::*;
 
+use crate::api::orgs::{get_org_session_detail, update_org_session};
 use crate::api::public_session::{get_public_session_detail, update_public_session};
 use crate::api::user_session::{get_user_session_detail, update_user_session};
-use crate::auth::storage::{LocalTokenStorage, TokenStorage};
+use crate::auth::storage::token_storage;
 use crate::auth::{AuthContext, AuthState};
-use crate::types::{SessionDetail, SessionUpdateRequest};
-
-fn update_meta_tags(session_id: &str, stream_url: &str) {
+use crate::components::collaborators::CollaboratorsPanel;
+use crate::components::share_links::ShareLinksPanel;
+use crate::components::transfers::TransferPanel;
+use crate::types::{SessionDetail, SessionMetadata, SessionRole, SessionUpdateRequest, Visibility};
+
+const META_PROPERTIES: [&str; 9] = [
+    "og:title",
+    "og:type",
+    "og:image",
+    "og:url",
+    "og:description",
+    "twitter:card",
+    "twitter:title",
+    "twitter:description",
+    "twitter:image",
+];
+
+fn update_meta_tags(page_path: &str, stream_url: &str, detail: &SessionDetail, session_id: &str) {
     if let Some(window) = web_sys::window() {
         if let Some(document) = window.document() {
             if let Some(head) = document.head() {
                 let origin = window.location().origin().unwrap_or_default();
                 let full_stream_url = format!("{}{}", origin, stream_url);
-                let full_page_url = format!("{}/session/{}", origin, session_id);
+                let full_page_url = format!("{}{}", origin, page_path);
+                let title = detail
+                    .title
+                    .clone()
+                    .unwrap_or_else(|| format!("Realtime SVG - {}", session_id));
+                let mut description = detail
+                    .description
+                    .clone()
+                    .unwrap_or_else(|| "실시간 SVG 스트리밍 세션".to_string());
+                if !detail.tags.is_empty() {
+                    let hashtags: Vec<String> =
+                        detail.tags.iter().map(|tag| format!("#{}", tag)).collect();
+                    description = format!("{} {}", description, hashtags.join(" "));
+                }
 
                 let meta_tags = vec![
-                    ("og:title", format!("Realtime SVG - {}", session_id)),
+                    ("og:title", title.clone()),
                     ("og:type", "website".to_string()),
                     ("og:image", full_stream_url.clone()),
                     ("og:url", full_page_url),
-                    ("og:description", "실시간 SVG 스트리밍 세션".to_string()),
+                    ("og:description", description.clone()),
                     ("twitter:card", "summary_large_image".to_string()),
+                    ("twitter:title", title),
+                    ("twitter:description", description),
                     ("twitter:image", full_stream_url),
                 ];
 
@@ -43,9 +74,7 @@ fn clear_meta_tags() {
     if let Some(window) = web_sys::window() {
         if let Some(document) = window.document() {
             if let Some(head) = document.head() {
-                let properties = vec!["og:title", "og:type", "og:image", "og:url", "og:description", "twitter:card", "twitter:image"];
-                
-                for property in properties {
+                for property in META_PROPERTIES {
                     let selector = format!("meta[property='{}']", property);
                     if let Ok(elements) = document.query_selector_all(&selector) {
                         for i in 0..elements.length() {
@@ -65,6 +94,9 @@ pub struct SessionDetailPageProps {
     pub user_id: String,
     pub session_id: String,
     pub is_user_session: bool,
+    /// 조직 세션이면 조직 ID. 이때 `user_id`는 비어 있습니다.
+    #[prop_or_default]
+    pub org_id: Option<String>,
 }
 
 #[function_component(SessionDetailPage)]
@@ -73,6 +105,13 @@ pub fn session_detail_page(props: &SessionDetailPageProps) -> Html {
 
     let session_detail = use_state(|| None::<SessionDetail>);
     let args_text = use_state(String::new);
+    let title_text = use_state(String::new);
+    let description_text = use_state(String::new);
+    let tags_text = use_state(String::new);
+    let listed = use_state(|| false);
+    let visibility = use_state(Visibility::default);
+    // 비공개 세션은 공유 링크를 만든 뒤에만 미리 볼 수 있습니다.
+    let preview_url = use_state(|| None::<String>);
     let error_message = use_state(|| None::<String>);
     let loading = use_state(|| false);
     let updating = use_state(|| false);
@@ -84,16 +123,25 @@ pub fn session_detail_page(props: &SessionDetailPageProps) -> Html {
         let user_id = props.user_id.clone();
         let session_id = props.session_id.clone();
         let is_user_session = props.is_user_session;
+        let org_id = props.org_id.clone();
         let session_detail = session_detail.clone();
         let args_text = args_text.clone();
+        let title_text = title_text.clone();
+        let description_text = description_text.clone();
+        let tags_text = tags_text.clone();
+        let listed = listed.clone();
+        let visibility = visibility.clone();
         let error_message = error_message.clone();
         let loading = loading.clone();
 
-        use_effect_with((user_id.clone(), session_id.clone()), move |_| {
+        let deps = (user_id.clone(), session_id.clone(), org_id.clone());
+        use_effect_with(deps, move |_| {
             loading.set(true);
 
             spawn_local(async move {
-                let result = if is_user_session {
+                let result = if let Some(org_id) = &org_id {
+                    get_org_session_detail(org_id, &session_id).await
+                } else if is_user_session {
                     get_user_session_detail(&user_id, &session_id).await
                 } else {
                     get_public_session_detail(&session_id).await
@@ -104,6 +152,11 @@ pub fn session_detail_page(props: &SessionDetailPageProps) -> Html {
                         let args_json = serde_json::to_string_pretty(&detail.args)
                             .unwrap_or_else(|_| "{}".to_string());
                         args_text.set(args_json);
+                        title_text.set(detail.title.clone().unwrap_or_default());
+                        description_text.set(detail.description.clone().unwrap_or_default());
+                        tags_text.set(detail.tags.join(", "));
+                        listed.set(detail.listed);
+                        visibility.set(detail.visibility);
                         session_detail.set(Some(detail));
                     }
                     Err(e) => {
@@ -119,15 +172,29 @@ pub fn session_detail_page(props: &SessionDetailPageProps) -> Html {
         let session_id = props.session_id.clone();
         let is_user_session = props.is_user_session;
         let user_id = props.user_id.clone();
-
-        use_effect_with(session_id.clone(), move |_| {
-            let stream_url = if is_user_session {
-                format!("/stream/{}/{}", user_id, session_id)
+        let org_id = props.org_id.clone();
+
+        use_effect_with((*session_detail).clone(), move |detail| {
+            let (page_path, stream_url) = if let Some(org_id) = &org_id {
+                (
+                    format!("/org/{}/session/{}", org_id, session_id),
+                    format!("/stream/org/{}/{}", org_id, session_id),
+                )
+            } else if is_user_session {
+                (
+                    format!("/session/{}/{}", user_id, session_id),
+                    format!("/stream/{}/{}", user_id, session_id),
+                )
             } else {
-                format!("/stream/{}", session_id)
+                (
+                    format!("/session/{}", session_id),
+                    format!("/stream/{}", session_id),
+                )
             };
 
-            update_meta_tags(&session_id, &stream_url);
+            if let Some(detail) = detail {
+                update_meta_tags(&page_path, &stream_url, detail, &session_id);
+            }
 
             move || {
                 clear_meta_tags();
@@ -135,16 +202,39 @@ pub fn session_detail_page(props: &SessionDetailPageProps) -> Html {
         });
     }
 
+    let is_owner = props.is_user_session
+        && matches!(
+            &*auth_context,
+            AuthState::Authenticated { user_id, .. } if user_id == &props.user_id
+        );
+    // 조직 세션은 토큰의 조직 역할이 뷰어가 아니면 수정할 수 있습니다.
+    let org_can_edit = (*session_detail)
+        .as_ref()
+        .and_then(|detail| detail.org_role)
+        .is_some_and(|role| role.can_edit());
+    // 공개 범위와 디렉터리 등록은 소유자만 바꿀 수 있습니다 (공용 세션은 누구나).
+    let manages_sharing = if props.org_id.is_some() {
+        org_can_edit
+    } else {
+        !props.is_user_session || is_owner
+    };
+
     let on_update = {
         let user_id = props.user_id.clone();
         let session_id = props.session_id.clone();
         let is_user_session = props.is_user_session;
+        let org_id = props.org_id.clone();
         let auth_context = auth_context.clone();
         let args_ref = args_ref.clone();
         let error_message = error_message.clone();
         let updating = updating.clone();
         let session_detail = session_detail.clone();
         let args_text = args_text.clone();
+        let title_text = title_text.clone();
+        let description_text = description_text.clone();
+        let tags_text = tags_text.clone();
+        let listed = listed.clone();
+        let visibility = visibility.clone();
 
         Callback::from(move |e: MouseEvent| {
             e.prevent_default();
@@ -162,7 +252,7 @@ pub fn session_detail_page(props: &SessionDetailPageProps) -> Html {
                 }
             };
 
-            if is_user_session {
+            if is_user_session || org_id.is_some() {
                 match &*auth_context {
                     AuthState::Anonymous => {
                         error_message.set(Some("로그인이 필요합니다".to_string()));
@@ -174,19 +264,33 @@ pub fn session_detail_page(props: &SessionDetailPageProps) -> Html {
 
             let user_id = user_id.clone();
             let session_id = session_id.clone();
+            let org_id = org_id.clone();
             let error_message = error_message.clone();
             let updating = updating.clone();
             let auth_context = auth_context.clone();
             let session_detail = session_detail.clone();
             let args_text = args_text.clone();
+            let metadata = SessionMetadata {
+                title: Some((*title_text).trim().to_string()),
+                description: Some((*description_text).trim().to_string()),
+                tags: Some(SessionMetadata::parse_tags(&tags_text)),
+                // 디렉터리에는 공개 세션만 등록할 수 있습니다.
+                listed: manages_sharing.then_some(*listed && *visibility == Visibility::Public),
+                visibility: is_owner.then_some(*visibility),
+            };
 
             updating.set(true);
             error_message.set(None);
 
             spawn_local(async move {
-                let request = SessionUpdateRequest { args: args.clone() };
+                let request = SessionUpdateRequest {
+                    args: args.clone(),
+                    metadata: metadata.clone(),
+                };
 
-                let result = if is_user_session {
+                let result = if let Some(org_id) = &org_id {
+                    update_org_session(org_id, &session_id, request).await
+                } else if is_user_session {
                     update_user_session(&user_id, &session_id, request).await
                 } else {
                     update_public_session(&session_id, request).await
@@ -196,6 +300,18 @@ pub fn session_detail_page(props: &SessionDetailPageProps) -> Html {
                     Ok(_) => {
                         if let Some(mut detail) = (*session_detail).clone() {
                             detail.args = args;
+                            detail.title = metadata.title.filter(|title| !title.is_empty());
+                            detail.description = metadata
+                                .description
+                                .filter(|description| !description.is_empty());
+                            detail.tags = metadata
+                                .tags
+                                .unwrap_or_default()
+                                .into_iter()
+                                .map(|tag| tag.to_lowercase())
+                                .collect();
+                            detail.listed = metadata.listed.unwrap_or(detail.listed);
+                            detail.visibility = metadata.visibility.unwrap_or(detail.visibility);
                             let args_json = serde_json::to_string_pretty(&detail.args)
                                 .unwrap_or_else(|_| "{}".to_string());
                             args_text.set(args_json);
@@ -203,9 +319,10 @@ pub fn session_detail_page(props: &SessionDetailPageProps) -> Html {
                         }
                     }
                     Err(e) => {
-                        if is_user_session && e.contains("로그인이 필요합니다") {
-                            let storage = LocalTokenStorage::new();
-                            let _ = storage.remove_token();
+                        if (is_user_session || org_id.is_some())
+                            && e.contains("로그인이 필요합니다")
+                        {
+                            let _ = token_storage().remove_token();
                             auth_context.set(AuthState::Anonymous);
                         }
                         error_message.set(Some(e));
@@ -216,19 +333,33 @@ pub fn session_detail_page(props: &SessionDetailPageProps) -> Html {
         })
     };
 
-    let can_edit = if props.is_user_session {
-        match &*auth_context {
-            AuthState::Authenticated { user_id, .. } => user_id == &props.user_id,
-            _ => false,
-        }
+    let can_edit = if props.org_id.is_some() {
+        org_can_edit
+    } else if props.is_user_session {
+        is_owner
+            || (*session_detail)
+                .as_ref()
+                .is_some_and(|detail| detail.role == Some(SessionRole::Editor))
     } else {
         true
     };
 
-    let stream_url = if props.is_user_session {
-        format!("/stream/{}/{}", props.user_id, props.session_id)
+    let is_private = (*session_detail)
+        .as_ref()
+        .is_some_and(|detail| detail.visibility == Visibility::Private);
+    let stream_url = if is_private {
+        (*preview_url).clone()
+    } else if let Some(org_id) = &props.org_id {
+        Some(format!("/stream/org/{}/{}", org_id, props.session_id))
+    } else if props.is_user_session {
+        Some(format!("/stream/{}/{}", props.user_id, props.session_id))
     } else {
-        format!("/stream/{}", props.session_id)
+        Some(format!("/stream/{}", props.session_id))
+    };
+
+    let on_share_link_created = {
+        let preview_url = preview_url.clone();
+        Callback::from(move |url: String| preview_url.set(Some(url)))
     };
 
     html! {
@@ -250,80 +381,208 @@ pub fn session_detail_page(props: &SessionDetailPageProps) -> Html {
             } else if let Some(ref detail) = *session_detail {
                 html! {
                     <div class="session-content">
-                        <div class="stream-preview">
-                            <div class="preview-header">
-                                <h3>{"실시간 스트림 미리보기"}</h3>
-                                <div class="preview-actions">
-                                    <button
-                                        class="copy-btn"
-                                        onclick={{
-                                            let stream_url = stream_url.clone();
-                                            let toast_message = toast_message.clone();
-                                            Callback::from(move |e: MouseEvent| {
-                                                e.prevent_default();
-                                                if let Some(window) = web_sys::window() {
-                                                    let full_url = format!("{}{}",
-                                                        window.location().origin().unwrap(),
-                                                        stream_url
-                                                    );
-                                                    let clipboard = window.navigator().clipboard();
+                        {if let Some(stream_url) = stream_url {
+                            html! {
+                                <div class="stream-preview">
+                                    <div class="preview-header">
+                                        <h3>{"실시간 스트림 미리보기"}</h3>
+                                        <div class="preview-actions">
+                                            <button
+                                                class="copy-btn"
+                                                onclick={{
+                                                    let stream_url = stream_url.clone();
                                                     let toast_message = toast_message.clone();
-                                                    wasm_bindgen_futures::spawn_local(async move {
-                                                        let result = wasm_bindgen_futures::JsFuture::from(
-                                                            clipboard.write_text(&full_url)
-                                                        ).await;
-                                                        if result.is_ok() {
-                                                            toast_message.set(Some("링크가 복사되었습니다!".to_string()));
-                                                            gloo_timers::callback::Timeout::new(2000, move || {
-                                                                toast_message.set(None);
-                                                            }).forget();
+                                                    Callback::from(move |e: MouseEvent| {
+                                                        e.prevent_default();
+                                                        if let Some(window) = web_sys::window() {
+                                                            let full_url = format!("{}{}",
+                                                                window.location().origin().unwrap(),
+                                                                stream_url
+                                                            );
+                                                            let clipboard = window.navigator().clipboard();
+                                                            let toast_message = toast_message.clone();
+                                                            wasm_bindgen_futures::spawn_local(async move {
+                                                                let result = wasm_bindgen_futures::JsFuture::from(
+                                                                    clipboard.write_text(&full_url)
+                                                                ).await;
+                                                                if result.is_ok() {
+                                                                    toast_message.set(Some("링크가 복사되었습니다!".to_string()));
+                                                                    gloo_timers::callback::Timeout::new(2000, move || {
+                                                                        toast_message.set(None);
+                                                                    }).forget();
+                                                                }
+                                                            });
                                                         }
-                                                    });
-                                                }
-                                            })
-                                        }}
-                                        title="링크 복사"
-                                    >
-                                        {"🔗"}
-                                    </button>
-                                    <button
-                                        class="copy-btn"
-                                        onclick={{
-                                            let stream_url = stream_url.clone();
-                                            let toast_message = toast_message.clone();
-                                            Callback::from(move |e: MouseEvent| {
-                                                e.prevent_default();
-                                                if let Some(window) = web_sys::window() {
-                                                    let full_url = format!("{}{}",
-                                                        window.location().origin().unwrap(),
-                                                        stream_url
-                                                    );
-                                                    let html_code = format!(r#"<a href="{}" target="_blank"><img src="{}" alt="realtime-svg" /></a>"#, full_url, full_url);
-                                                    let clipboard = window.navigator().clipboard();
+                                                    })
+                                                }}
+                                                title="링크 복사"
+                                            >
+                                                {"🔗"}
+                                            </button>
+                                            <button
+                                                class="copy-btn"
+                                                onclick={{
+                                                    let stream_url = stream_url.clone();
                                                     let toast_message = toast_message.clone();
-                                                    wasm_bindgen_futures::spawn_local(async move {
-                                                        let result = wasm_bindgen_futures::JsFuture::from(
-                                                            clipboard.write_text(&html_code)
-                                                        ).await;
-                                                        if result.is_ok() {
-                                                            toast_message.set(Some("HTML 코드가 복사되었습니다!".to_string()));
-                                                            gloo_timers::callback::Timeout::new(2000, move || {
-                                                                toast_message.set(None);
-                                                            }).forget();
+                                                    Callback::from(move |e: MouseEvent| {
+                                                        e.prevent_default();
+                                                        if let Some(window) = web_sys::window() {
+                                                            let full_url = format!("{}{}",
+                                                                window.location().origin().unwrap(),
+                                                                stream_url
+                                                            );
+                                                            let html_code = format!(r#"<a href="{}" target="_blank"><img src="{}" alt="realtime-svg" /></a>"#, full_url, full_url);
+                                                            let clipboard = window.navigator().clipboard();
+                                                            let toast_message = toast_message.clone();
+                                                            wasm_bindgen_futures::spawn_local(async move {
+                                                                let result = wasm_bindgen_futures::JsFuture::from(
+                                                                    clipboard.write_text(&html_code)
+                                                                ).await;
+                                                                if result.is_ok() {
+                                                                    toast_message.set(Some("HTML 코드가 복사되었습니다!".to_string()));
+                                                                    gloo_timers::callback::Timeout::new(2000, move || {
+                                                                        toast_message.set(None);
+                                                                    }).forget();
+                                                                }
+                                                            });
                                                         }
-                                                    });
-                                                }
+                                                    })
+                                                }}
+                                                title="HTML 코드 복사"
+                                            >
+                                                {"</>"}
+                                            </button>
+                                        </div>
+                                    </div>
+                                    <a href={stream_url.clone()} target="_blank">
+                                        <img src={stream_url.clone()} alt="Session stream" />
+                                    </a>
+                                </div>
+                            }
+                        } else {
+                            html! {
+                                <div class="stream-preview">
+                                    <div class="preview-header">
+                                        <h3>{"실시간 스트림 미리보기"}</h3>
+                                    </div>
+                                    <div class="private-thumbnail">
+                                        {"🔒 비공개 세션입니다. 공유 링크를 만들면 여기서 미리 볼 수 있습니다."}
+                                    </div>
+                                </div>
+                            }
+                        }}
+
+                        <div class="args-editor metadata-editor">
+                            <h3>{"메타데이터"}</h3>
+                            <div class="form-group">
+                                <label for="meta-title">{"제목"}</label>
+                                <input
+                                    type="text"
+                                    id="meta-title"
+                                    maxlength="100"
+                                    value={(*title_text).clone()}
+                                    disabled={!can_edit || *updating}
+                                    oninput={{
+                                        let title_text = title_text.clone();
+                                        Callback::from(move |e: InputEvent| {
+                                            let input: HtmlInputElement = e.target_unchecked_into();
+                                            title_text.set(input.value());
+                                        })
+                                    }}
+                                />
+                            </div>
+                            <div class="form-group">
+                                <label for="meta-description">{"설명"}</label>
+                                <textarea
+                                    id="meta-description"
+                                    maxlength="500"
+                                    rows="3"
+                                    value={(*description_text).clone()}
+                                    disabled={!can_edit || *updating}
+                                    oninput={{
+                                        let description_text = description_text.clone();
+                                        Callback::from(move |e: InputEvent| {
+                                            let input: HtmlTextAreaElement = e.target_unchecked_into();
+                                            description_text.set(input.value());
+                                        })
+                                    }}
+                                />
+                            </div>
+                            <div class="form-group">
+                                <label for="meta-tags">{"태그 (쉼표로 구분)"}</label>
+                                <input
+                                    type="text"
+                                    id="meta-tags"
+                                    value={(*tags_text).clone()}
+                                    disabled={!can_edit || *updating}
+                                    oninput={{
+                                        let tags_text = tags_text.clone();
+                                        Callback::from(move |e: InputEvent| {
+                                            let input: HtmlInputElement = e.target_unchecked_into();
+                                            tags_text.set(input.value());
+                                        })
+                                    }}
+                                />
+                            </div>
+                            {if props.is_user_session {
+                                html! {
+                                    <div class="form-group">
+                                        <label for="meta-visibility">{"공개 범위"}</label>
+                                        <select
+                                            id="meta-visibility"
+                                            disabled={!is_owner || *updating}
+                                            onchange={{
+                                                let visibility = visibility.clone();
+                                                Callback::from(move |e: Event| {
+                                                    let select: HtmlSelectElement = e.target_unchecked_into();
+                                                    visibility.set(Visibility::parse(&select.value()));
+                                                })
+                                            }}
+                                        >
+                                            {for Visibility::ALL.iter().map(|option| html! {
+                                                <option value={option.as_str()} selected={*visibility == *option}>
+                                                    {option.label()}
+                                                </option>
+                                            })}
+                                        </select>
+                                    </div>
+                                }
+                            } else {
+                                html! {}
+                            }}
+                            <div class="form-group form-check">
+                                <label for="meta-listed">
+                                    <input
+                                        type="checkbox"
+                                        id="meta-listed"
+                                        checked={*listed && *visibility == Visibility::Public}
+                                        disabled={!manages_sharing || *updating || *visibility != Visibility::Public}
+                                        onchange={{
+                                            let listed = listed.clone();
+                                            Callback::from(move |e: Event| {
+                                                let input: HtmlInputElement = e.target_unchecked_into();
+                                                listed.set(input.checked());
                                             })
                                         }}
-                                        title="HTML 코드 복사"
-                                    >
-                                        {"</>"}
-                                    </button>
-                                </div>
+                                    />
+                                    {if detail.listed {
+                                        " 공개 디렉터리에 등록됨 (해제하면 목록에서 내려갑니다)"
+                                    } else {
+                                        " 공개 디렉터리에 등록"
+                                    }}
+                                </label>
                             </div>
-                            <a href={stream_url.clone()} target="_blank">
-                                <img src={stream_url.clone()} alt="Session stream" />
-                            </a>
+                            {if detail.tags.is_empty() {
+                                html! {}
+                            } else {
+                                html! {
+                                    <div class="session-tags">
+                                        {detail.tags.iter().map(|tag| html! {
+                                            <span class="session-tag">{format!("#{}", tag)}</span>
+                                        }).collect::<Html>()}
+                                    </div>
+                                }
+                            }}
                         </div>
 
                         <div class="args-editor">
@@ -355,6 +614,41 @@ pub fn session_detail_page(props: &SessionDetailPageProps) -> Html {
                             html! {}
                         }}
 
+                        {if is_owner {
+                            html! {
+                                <CollaboratorsPanel
+                                    user_id={props.user_id.clone()}
+                                    session_id={props.session_id.clone()}
+                                />
+                            }
+                        } else {
+                            html! {}
+                        }}
+
+                        {if is_owner {
+                            html! {
+                                <TransferPanel
+                                    user_id={props.user_id.clone()}
+                                    session_id={props.session_id.clone()}
+                                    pending={detail.pending_transfer.clone()}
+                                />
+                            }
+                        } else {
+                            html! {}
+                        }}
+
+                        {if is_owner && is_private {
+                            html! {
+                                <ShareLinksPanel
+                                    user_id={props.user_id.clone()}
+                                    session_id={props.session_id.clone()}
+                                    on_created={on_share_link_created.clone()}
+                                />
+                            }
+                        } else {
+                            html! {}
+                        }}
+
                         <div class="template-display">
                             <h3>{"템플릿"}</h3>
                             <pre>{&detail.template}</pre>
@@ -364,6 +658,13 @@ pub fn session_detail_page(props: &SessionDetailPageProps) -> Html {
                             <h3>{"세션 정보"}</h3>
                             {if props.is_user_session && !&props.user_id.is_empty() {
                                 html! { <p><strong>{"User ID:"}</strong> {&props.user_id}</p> }
+                            } else if let Some(ref org_id) = props.org_id {
+                                html! {
+                                    <p>
+                                        <strong>{"Org ID:"}</strong> {org_id}
+                                        {detail.org_role.map(|role| format!(" ({})", role.label())).unwrap_or_default()}
+                                    </p>
+                                }
                             } else {
                                 html! {}
                             }}
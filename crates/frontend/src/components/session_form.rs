@@ -8,7 +8,16 @@ use crate::api::public_session::create_public_session;
 use crate::api::user_session::create_user_session;
 use crate::auth::{AuthContext, AuthState};
 use crate::routes::Route;
-use crate::types::{PublicSessionCreateRequest, UserSessionCreateRequest};
+use crate::types::{PublicSessionCreateRequest, SessionMetadata, UserSessionCreateRequest};
+
+/// 서버의 세션 ID 규칙과 동일: 1~128자의 영문, 숫자, `.`, `_`, `-`
+fn is_valid_session_id(session_id: &str) -> bool {
+    !session_id.is_empty()
+        && session_id.len() <= 128
+        && session_id
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'_' | b'-'))
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum SessionMode {
@@ -30,6 +39,10 @@ pub fn session_form(props: &SessionFormProps) -> Html {
     let template_ref = use_node_ref();
     let args_ref = use_node_ref();
     let expire_ref = use_node_ref();
+    let title_ref = use_node_ref();
+    let description_ref = use_node_ref();
+    let tags_ref = use_node_ref();
+    let listed_ref = use_node_ref();
 
     let error_message = use_state(|| None::<String>);
     let loading = use_state(|| false);
@@ -42,6 +55,10 @@ pub fn session_form(props: &SessionFormProps) -> Html {
         let template_ref = template_ref.clone();
         let args_ref = args_ref.clone();
         let expire_ref = expire_ref.clone();
+        let title_ref = title_ref.clone();
+        let description_ref = description_ref.clone();
+        let tags_ref = tags_ref.clone();
+        let listed_ref = listed_ref.clone();
         let error_message = error_message.clone();
         let loading = loading.clone();
 
@@ -68,11 +85,52 @@ pub fn session_form(props: &SessionFormProps) -> Html {
                 .map(|input| input.value().trim().to_string())
                 .unwrap_or_default();
 
+            let title = title_ref
+                .cast::<HtmlInputElement>()
+                .map(|input| input.value().trim().to_string())
+                .unwrap_or_default();
+
+            let description = description_ref
+                .cast::<web_sys::HtmlTextAreaElement>()
+                .map(|input| input.value().trim().to_string())
+                .unwrap_or_default();
+
+            let tags = tags_ref
+                .cast::<HtmlInputElement>()
+                .map(|input| SessionMetadata::parse_tags(&input.value()))
+                .unwrap_or_default();
+
+            let listed = listed_ref
+                .cast::<HtmlInputElement>()
+                .map(|input| input.checked())
+                .unwrap_or(false);
+
+            if listed && title.is_empty() {
+                error_message.set(Some("디렉터리에 등록하려면 제목을 입력하세요".to_string()));
+                return;
+            }
+
+            let metadata = SessionMetadata {
+                title: (!title.is_empty()).then_some(title),
+                description: (!description.is_empty()).then_some(description),
+                tags: (!tags.is_empty()).then_some(tags),
+                listed: listed.then_some(true),
+                visibility: None,
+            };
+
             if session_id.is_empty() {
                 error_message.set(Some("세션 ID를 입력하세요".to_string()));
                 return;
             }
 
+            if !is_valid_session_id(&session_id) {
+                error_message.set(Some(
+                    "세션 ID는 128자 이하의 영문, 숫자, '.', '_', '-' 만 사용할 수 있습니다"
+                        .to_string(),
+                ));
+                return;
+            }
+
             if template.is_empty() {
                 error_message.set(Some("템플릿을 입력하세요".to_string()));
                 return;
@@ -124,6 +182,7 @@ pub fn session_form(props: &SessionFormProps) -> Html {
                                 template,
                                 args,
                                 expire_seconds,
+                                metadata,
                             };
 
                             match create_user_session(user_id, request).await {
@@ -146,6 +205,7 @@ pub fn session_form(props: &SessionFormProps) -> Html {
                             template,
                             args,
                             expire: expire_seconds.map(|s| format!("{}s", s)),
+                            metadata,
                         };
 
                         spawn_local(async move {
@@ -190,6 +250,52 @@ pub fn session_form(props: &SessionFormProps) -> Html {
                     />
                 </div>
 
+                <div class="form-group">
+                    <label for="title">{"제목 (선택):"}</label>
+                    <input
+                        type="text"
+                        id="title"
+                        ref={title_ref}
+                        placeholder="서버 상태 배지"
+                        maxlength="100"
+                        disabled={*loading}
+                    />
+                </div>
+
+                <div class="form-group">
+                    <label for="description">{"설명 (선택):"}</label>
+                    <textarea
+                        id="description"
+                        ref={description_ref}
+                        maxlength="500"
+                        disabled={*loading}
+                        rows="2"
+                    />
+                </div>
+
+                <div class="form-group">
+                    <label for="tags">{"태그 (쉼표로 구분):"}</label>
+                    <input
+                        type="text"
+                        id="tags"
+                        ref={tags_ref}
+                        placeholder="status, server"
+                        disabled={*loading}
+                    />
+                </div>
+
+                <div class="form-group form-check">
+                    <label for="listed">
+                        <input
+                            type="checkbox"
+                            id="listed"
+                            ref={listed_ref}
+                            disabled={*loading}
+                        />
+                        {" 공개 디렉터리에 등록 (둘러보기 페이지에 노출)"}
+                    </label>
+                </div>
+
                 <div class="form-group">
                     <label for="template">{"템플릿:"}</label>
                     <textarea
@@ -1,11 +1,137 @@
+use wasm_bindgen::JsCast;
 use wasm_bindgen_futures::spawn_local;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
 use yew::prelude::*;
 use yew_router::prelude::*;
 
-use crate::api::user_session::list_user_sessions;
+use crate::api::collaborators::list_shared_sessions;
+use crate::api::user_session::{delete_user_session, list_user_sessions};
 use crate::auth::{AuthContext, AuthState};
+use crate::components::transfers::IncomingTransfers;
 use crate::routes::Route;
-use crate::types::SessionListItem;
+use crate::types::{SessionListItem, SessionListQuery, Visibility};
+
+const PAGE_SIZE: usize = 12;
+
+/// RFC3339 문자열을 `YYYY-MM-DD HH:MM` 형태로 줄입니다.
+fn format_timestamp(value: &Option<String>) -> String {
+    match value {
+        Some(value) if value.len() >= 16 => value[..16].replace('T', " "),
+        Some(value) => value.clone(),
+        None => "-".to_string(),
+    }
+}
+
+fn format_ttl(ttl_seconds: Option<i64>) -> String {
+    match ttl_seconds {
+        None => "만료 없음".to_string(),
+        Some(secs) if secs >= 86400 => format!("{}일 남음", secs / 86400),
+        Some(secs) if secs >= 3600 => format!("{}시간 {}분 남음", secs / 3600, secs % 3600 / 60),
+        Some(secs) if secs >= 60 => format!("{}분 남음", secs / 60),
+        Some(secs) => format!("{}초 남음", secs.max(0)),
+    }
+}
+
+fn format_bytes(bytes: usize) -> String {
+    if bytes >= 1024 * 1024 {
+        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+    } else if bytes >= 1024 {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{} B", bytes)
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct SharedSessionListProps {
+    user_id: String,
+}
+
+/// 다른 사용자가 협업자로 추가해 준 세션 목록. 없으면 아무것도 그리지 않습니다.
+#[function_component(SharedSessionList)]
+fn shared_session_list(props: &SharedSessionListProps) -> Html {
+    let navigator = use_navigator().expect("Navigator must be available");
+    let sessions = use_state(Vec::<SessionListItem>::new);
+    let error_message = use_state(|| None::<String>);
+
+    {
+        let sessions = sessions.clone();
+        let error_message = error_message.clone();
+
+        use_effect_with(props.user_id.clone(), move |user_id| {
+            let user_id = user_id.clone();
+            let query = SessionListQuery {
+                limit: Some(100),
+                sort: Some("updated".to_string()),
+                ..Default::default()
+            };
+            spawn_local(async move {
+                match list_shared_sessions(&user_id, &query).await {
+                    Ok(response) => sessions.set(response.items),
+                    Err(e) => error_message.set(Some(e)),
+                }
+            });
+        });
+    }
+
+    if sessions.is_empty() && error_message.is_none() {
+        return html! {};
+    }
+
+    html! {
+        <div class="shared-sessions">
+            <h2>{"공유받은 세션"}</h2>
+            {if let Some(ref msg) = *error_message {
+                html! { <div class="error">{msg}</div> }
+            } else {
+                html! {}
+            }}
+            <div class="session-list">
+                {sessions.iter().map(|session| {
+                    let owner = session.owner.clone().unwrap_or_default();
+                    let onclick = {
+                        let navigator = navigator.clone();
+                        let route = Route::UserSession {
+                            user_id: owner.clone(),
+                            session_id: session.session_id.clone(),
+                        };
+                        Callback::from(move |_: MouseEvent| navigator.push(&route))
+                    };
+                    html! {
+                        <div
+                            class="session-card"
+                            {onclick}
+                            key={format!("{}/{}", owner, session.session_id)}
+                        >
+                            {if session.visibility == Visibility::Private {
+                                html! {
+                                    <div class="session-thumbnail private-thumbnail">{"🔒 비공개"}</div>
+                                }
+                            } else {
+                                html! {
+                                    <img
+                                        class="session-thumbnail"
+                                        src={session.thumbnail_url.clone()}
+                                        alt={session.session_id.clone()}
+                                        loading="lazy"
+                                    />
+                                }
+                            }}
+                            <h3>{session.title.clone().unwrap_or_else(|| session.session_id.clone())}</h3>
+                            <p class="session-card-id">{format!("{}/{}", owner, session.session_id)}</p>
+                            <p>{format!("수정: {}", format_timestamp(&session.updated_at))}</p>
+                            <div class="session-card-meta">
+                                <span>{session.role.map(|role| role.label()).unwrap_or("-")}</span>
+                                <span>{format_ttl(session.ttl_seconds)}</span>
+                                <span>{format!("👁 {}", session.viewers)}</span>
+                            </div>
+                        </div>
+                    }
+                }).collect::<Html>()}
+            </div>
+        </div>
+    }
+}
 
 #[function_component(SessionListPage)]
 pub fn session_list_page() -> Html {
@@ -13,37 +139,170 @@ pub fn session_list_page() -> Html {
     let navigator = use_navigator().expect("Navigator must be available");
 
     let sessions = use_state(Vec::<SessionListItem>::new);
+    let next_cursor = use_state(|| None::<String>);
     let error_message = use_state(|| None::<String>);
     let loading = use_state(|| false);
 
+    let search_input = use_state(String::new);
+    let search = use_state(String::new);
+    let tag_filter = use_state(|| None::<String>);
+    let sort = use_state(|| "created".to_string());
+    let order = use_state(|| "desc".to_string());
+
+    let build_query = {
+        let search = search.clone();
+        let tag_filter = tag_filter.clone();
+        let sort = sort.clone();
+        let order = order.clone();
+
+        move |cursor: Option<String>| SessionListQuery {
+            cursor,
+            limit: Some(PAGE_SIZE),
+            sort: Some((*sort).clone()),
+            order: Some((*order).clone()),
+            q: Some((*search).clone()),
+            tag: (*tag_filter).clone(),
+        }
+    };
+
     {
         let auth_context = auth_context.clone();
         let sessions = sessions.clone();
+        let next_cursor = next_cursor.clone();
         let error_message = error_message.clone();
         let loading = loading.clone();
+        let query = build_query(None);
 
-        use_effect_with((), move |_| match &*auth_context {
-            AuthState::Authenticated { user_id, .. } => {
-                let user_id = user_id.clone();
-                loading.set(true);
+        use_effect_with(
+            (
+                (*search).clone(),
+                (*tag_filter).clone(),
+                (*sort).clone(),
+                (*order).clone(),
+            ),
+            move |_| match &*auth_context {
+                AuthState::Authenticated { user_id, .. } => {
+                    let user_id = user_id.clone();
+                    loading.set(true);
+                    error_message.set(None);
 
-                spawn_local(async move {
-                    match list_user_sessions(&user_id).await {
-                        Ok(response) => {
-                            sessions.set(response.items);
-                        }
-                        Err(e) => {
-                            error_message.set(Some(e));
+                    spawn_local(async move {
+                        match list_user_sessions(&user_id, &query).await {
+                            Ok(response) => {
+                                sessions.set(response.items);
+                                next_cursor.set(response.next_cursor);
+                            }
+                            Err(e) => {
+                                error_message.set(Some(e));
+                            }
                         }
+                        loading.set(false);
+                    });
+                }
+                AuthState::Anonymous => {
+                    error_message.set(Some("로그인이 필요합니다".to_string()));
+                }
+            },
+        );
+    }
+
+    let on_load_more = {
+        let auth_context = auth_context.clone();
+        let sessions = sessions.clone();
+        let next_cursor = next_cursor.clone();
+        let error_message = error_message.clone();
+        let build_query = build_query.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            let AuthState::Authenticated { user_id, .. } = &*auth_context else {
+                return;
+            };
+            let Some(cursor) = (*next_cursor).clone() else {
+                return;
+            };
+            let user_id = user_id.clone();
+            let query = build_query(Some(cursor));
+            let sessions = sessions.clone();
+            let next_cursor = next_cursor.clone();
+            let error_message = error_message.clone();
+
+            spawn_local(async move {
+                match list_user_sessions(&user_id, &query).await {
+                    Ok(response) => {
+                        let mut items = (*sessions).clone();
+                        items.extend(response.items);
+                        sessions.set(items);
+                        next_cursor.set(response.next_cursor);
                     }
-                    loading.set(false);
-                });
-            }
-            AuthState::Anonymous => {
-                error_message.set(Some("로그인이 필요합니다".to_string()));
+                    Err(e) => {
+                        error_message.set(Some(e));
+                    }
+                }
+            });
+        })
+    };
+
+    let on_search_input = {
+        let search_input = search_input.clone();
+
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            search_input.set(input.value());
+        })
+    };
+
+    let on_search_submit = {
+        let search_input = search_input.clone();
+        let search = search.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            search.set((*search_input).clone());
+        })
+    };
+
+    let on_sort_change = {
+        let sort = sort.clone();
+
+        Callback::from(move |e: Event| {
+            if let Some(select) = e
+                .target()
+                .and_then(|t| t.dyn_into::<HtmlSelectElement>().ok())
+            {
+                sort.set(select.value());
             }
-        });
-    }
+        })
+    };
+
+    let on_order_toggle = {
+        let order = order.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            let next = if *order == "desc" { "asc" } else { "desc" };
+            order.set(next.to_string());
+        })
+    };
+
+    let on_tag_click = {
+        let tag_filter = tag_filter.clone();
+
+        move |tag: String| {
+            let tag_filter = tag_filter.clone();
+
+            Callback::from(move |e: MouseEvent| {
+                e.stop_propagation();
+                tag_filter.set(Some(tag.clone()));
+            })
+        }
+    };
+
+    let on_tag_clear = {
+        let tag_filter = tag_filter.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            tag_filter.set(None);
+        })
+    };
 
     let on_session_click = {
         let navigator = navigator.clone();
@@ -64,6 +323,56 @@ pub fn session_list_page() -> Html {
         }
     };
 
+    let on_session_delete = {
+        let auth_context = auth_context.clone();
+        let sessions = sessions.clone();
+        let error_message = error_message.clone();
+
+        move |session_id: String| {
+            let auth_context = auth_context.clone();
+            let sessions = sessions.clone();
+            let error_message = error_message.clone();
+
+            Callback::from(move |e: MouseEvent| {
+                e.stop_propagation();
+
+                let AuthState::Authenticated { user_id, .. } = &*auth_context else {
+                    return;
+                };
+                let confirmed = web_sys::window()
+                    .and_then(|w| {
+                        w.confirm_with_message(&format!("'{}' 세션을 삭제할까요?", session_id))
+                            .ok()
+                    })
+                    .unwrap_or(false);
+                if !confirmed {
+                    return;
+                }
+
+                let user_id = user_id.clone();
+                let session_id = session_id.clone();
+                let sessions = sessions.clone();
+                let error_message = error_message.clone();
+
+                spawn_local(async move {
+                    match delete_user_session(&user_id, &session_id).await {
+                        Ok(()) => {
+                            let items = (*sessions)
+                                .iter()
+                                .filter(|item| item.session_id != session_id)
+                                .cloned()
+                                .collect();
+                            sessions.set(items);
+                        }
+                        Err(e) => {
+                            error_message.set(Some(e));
+                        }
+                    }
+                });
+            })
+        }
+    };
+
     let on_new_session = {
         let navigator = navigator.clone();
 
@@ -76,6 +385,39 @@ pub fn session_list_page() -> Html {
         <div class="session-list-page">
             <h2>{"내 세션 목록"}</h2>
 
+            <div class="session-list-toolbar">
+                <form class="session-search" onsubmit={on_search_submit}>
+                    <input
+                        type="search"
+                        placeholder="세션 ID 검색"
+                        value={(*search_input).clone()}
+                        oninput={on_search_input}
+                    />
+                    <button type="submit">{"검색"}</button>
+                </form>
+                <select onchange={on_sort_change}>
+                    <option value="created" selected={*sort == "created"}>{"생성일"}</option>
+                    <option value="updated" selected={*sort == "updated"}>{"수정일"}</option>
+                    <option value="name" selected={*sort == "name"}>{"이름"}</option>
+                    <option value="ttl" selected={*sort == "ttl"}>{"남은 시간"}</option>
+                    <option value="viewers" selected={*sort == "viewers"}>{"시청자 수"}</option>
+                </select>
+                <button class="secondary" onclick={on_order_toggle}>
+                    {if *order == "desc" { "내림차순 ↓" } else { "오름차순 ↑" }}
+                </button>
+            </div>
+
+            {if let Some(ref tag) = *tag_filter {
+                html! {
+                    <div class="session-tags active-filter">
+                        <span class="session-tag">{format!("#{}", tag)}</span>
+                        <button class="secondary" onclick={on_tag_clear}>{"태그 필터 해제"}</button>
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+
             {if *loading {
                 html! { <p>{"로딩 중..."}</p> }
             } else if let Some(ref msg) = *error_message {
@@ -89,21 +431,96 @@ pub fn session_list_page() -> Html {
                 }
             } else {
                 html! {
-                    <div class="session-list">
-                        {sessions.iter().map(|session| {
-                            let session_id = session.session_id.clone();
+                    <>
+                        <div class="session-list">
+                            {sessions.iter().map(|session| {
+                                let session_id = session.session_id.clone();
+                                html! {
+                                    <div
+                                        class="session-card"
+                                        onclick={on_session_click(session_id.clone())}
+                                        key={session_id.clone()}
+                                    >
+                                        {if session.visibility == Visibility::Private {
+                                            html! {
+                                                <div class="session-thumbnail private-thumbnail">{"🔒 비공개"}</div>
+                                            }
+                                        } else {
+                                            html! {
+                                                <img
+                                                    class="session-thumbnail"
+                                                    src={session.thumbnail_url.clone()}
+                                                    alt={session_id.clone()}
+                                                    loading="lazy"
+                                                />
+                                            }
+                                        }}
+                                        <h3>{session.title.clone().unwrap_or_else(|| session.session_id.clone())}</h3>
+                                        {if session.title.is_some() {
+                                            html! { <p class="session-card-id">{&session.session_id}</p> }
+                                        } else {
+                                            html! {}
+                                        }}
+                                        {if let Some(ref description) = session.description {
+                                            html! { <p class="session-card-description">{description}</p> }
+                                        } else {
+                                            html! {}
+                                        }}
+                                        <p>{format!("생성: {}", format_timestamp(&session.created_at))}</p>
+                                        <p>{format!("수정: {}", format_timestamp(&session.updated_at))}</p>
+                                        <div class="session-card-meta">
+                                            <span>{format_ttl(session.ttl_seconds)}</span>
+                                            <span>{format_bytes(session.template_bytes)}</span>
+                                            <span>{format!("👁 {}", session.viewers)}</span>
+                                        </div>
+                                        {if session.tags.is_empty() {
+                                            html! {}
+                                        } else {
+                                            html! {
+                                                <div class="session-tags">
+                                                    {session.tags.iter().map(|tag| html! {
+                                                        <span
+                                                            class="session-tag clickable"
+                                                            onclick={on_tag_click(tag.clone())}
+                                                        >
+                                                            {format!("#{}", tag)}
+                                                        </span>
+                                                    }).collect::<Html>()}
+                                                </div>
+                                            }
+                                        }}
+                                        <button
+                                            class="session-card-delete"
+                                            onclick={on_session_delete(session_id.clone())}
+                                        >
+                                            {"삭제"}
+                                        </button>
+                                    </div>
+                                }
+                            }).collect::<Html>()}
+                        </div>
+                        {if next_cursor.is_some() {
                             html! {
-                                <div
-                                    class="session-card"
-                                    onclick={on_session_click(session_id.clone())}
-                                    key={session_id.clone()}
-                                >
-                                    <h3>{&session.session_id}</h3>
+                                <div class="session-list-more">
+                                    <button class="secondary" onclick={on_load_more}>{"더 보기"}</button>
                                 </div>
                             }
-                        }).collect::<Html>()}
-                    </div>
+                        } else {
+                            html! {}
+                        }}
+                    </>
+                }
+            }}
+
+            {if let AuthState::Authenticated { user_id, .. } = &*auth_context {
+                html! {
+                    <>
+                        <IncomingTransfers user_id={user_id.clone()} />
+                        <SharedSessionList user_id={user_id.clone()} />
+                    </>
                 }
+            } else {
+                html! {}
             }}
         </div>
     }
@@ -0,0 +1,273 @@
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use crate::api::share_links::{create_share_link, list_share_links, revoke_share_link};
+use crate::types::{ShareLinkCreateRequest, ShareLinkCreateResponse, ShareLinkItem};
+
+/// 유효 기간 선택지 (표시 이름, 초). 서버 설정의 상한을 넘으면 상한으로 줄어듭니다.
+const TTL_OPTIONS: [(&str, u64); 3] = [("1일", 86400), ("7일", 7 * 86400), ("30일", 30 * 86400)];
+
+/// RFC3339 문자열을 `YYYY-MM-DD HH:MM` 형태로 줄입니다.
+fn format_timestamp(value: &str) -> String {
+    if value.len() >= 16 {
+        value[..16].replace('T', " ")
+    } else {
+        value.to_string()
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ShareLinksPanelProps {
+    pub user_id: String,
+    pub session_id: String,
+    /// 새 링크를 만들면 그 스트림 URL을 전달합니다 (미리보기 용도).
+    pub on_created: Callback<String>,
+}
+
+/// 비공개 세션의 공유 링크를 만들고 폐기하는 패널.
+#[function_component(ShareLinksPanel)]
+pub fn share_links_panel(props: &ShareLinksPanelProps) -> Html {
+    let links = use_state(Vec::<ShareLinkItem>::new);
+    let created = use_state(|| None::<ShareLinkCreateResponse>);
+    let error_message = use_state(|| None::<String>);
+    let loading = use_state(|| false);
+    let name_ref = use_node_ref();
+    let ttl_seconds = use_state(|| TTL_OPTIONS[1].1);
+    let reload = use_state(|| 0u32);
+
+    {
+        let user_id = props.user_id.clone();
+        let session_id = props.session_id.clone();
+        let links = links.clone();
+        let error_message = error_message.clone();
+
+        use_effect_with(*reload, move |_| {
+            spawn_local(async move {
+                match list_share_links(&user_id, &session_id).await {
+                    Ok(items) => links.set(items),
+                    Err(e) => error_message.set(Some(e)),
+                }
+            });
+        });
+    }
+
+    let on_ttl_change = {
+        let ttl_seconds = ttl_seconds.clone();
+        Callback::from(move |e: Event| {
+            if let Some(select) = e
+                .target()
+                .and_then(|t| t.dyn_into::<HtmlSelectElement>().ok())
+            {
+                ttl_seconds.set(select.value().parse().unwrap_or(TTL_OPTIONS[1].1));
+            }
+        })
+    };
+
+    let on_create = {
+        let user_id = props.user_id.clone();
+        let session_id = props.session_id.clone();
+        let on_created = props.on_created.clone();
+        let name_ref = name_ref.clone();
+        let ttl_seconds = ttl_seconds.clone();
+        let created = created.clone();
+        let error_message = error_message.clone();
+        let loading = loading.clone();
+        let reload = reload.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let Some(input) = name_ref.cast::<HtmlInputElement>() else {
+                return;
+            };
+            let name = input.value();
+            let request = ShareLinkCreateRequest {
+                name: Some(name.trim().to_string()).filter(|name| !name.is_empty()),
+                ttl_seconds: Some(*ttl_seconds),
+            };
+            let user_id = user_id.clone();
+            let session_id = session_id.clone();
+            let on_created = on_created.clone();
+            let created = created.clone();
+            let error_message = error_message.clone();
+            let loading = loading.clone();
+            let reload = reload.clone();
+
+            loading.set(true);
+            error_message.set(None);
+
+            spawn_local(async move {
+                match create_share_link(&user_id, &session_id, request).await {
+                    Ok(response) => {
+                        input.set_value("");
+                        on_created.emit(response.url.clone());
+                        created.set(Some(response));
+                        reload.set(*reload + 1);
+                    }
+                    Err(e) => error_message.set(Some(e)),
+                }
+                loading.set(false);
+            });
+        })
+    };
+
+    let on_copy = {
+        let created = created.clone();
+        let error_message = error_message.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            let (Some(response), Some(window)) = ((*created).clone(), web_sys::window()) else {
+                return;
+            };
+            let full_url = format!(
+                "{}{}",
+                window.location().origin().unwrap_or_default(),
+                response.url
+            );
+            let clipboard = window.navigator().clipboard();
+            let error_message = error_message.clone();
+            spawn_local(async move {
+                let result =
+                    wasm_bindgen_futures::JsFuture::from(clipboard.write_text(&full_url)).await;
+                if result.is_err() {
+                    error_message.set(Some("클립보드에 복사하지 못했습니다".to_string()));
+                }
+            });
+        })
+    };
+
+    let on_revoke = {
+        let user_id = props.user_id.clone();
+        let session_id = props.session_id.clone();
+        let created = created.clone();
+        let error_message = error_message.clone();
+        let reload = reload.clone();
+
+        Callback::from(move |item: ShareLinkItem| {
+            let confirmed = web_sys::window()
+                .and_then(|w| {
+                    w.confirm_with_message(
+                        "이 공유 링크를 폐기할까요? 링크로 보고 있는 스트림도 끊어집니다.",
+                    )
+                    .ok()
+                })
+                .unwrap_or(false);
+            if !confirmed {
+                return;
+            }
+
+            let user_id = user_id.clone();
+            let session_id = session_id.clone();
+            let created = created.clone();
+            let error_message = error_message.clone();
+            let reload = reload.clone();
+
+            spawn_local(async move {
+                match revoke_share_link(&user_id, &session_id, &item.link_id).await {
+                    Ok(()) => {
+                        if (*created).as_ref().map(|c| c.link_id.as_str())
+                            == Some(item.link_id.as_str())
+                        {
+                            created.set(None);
+                        }
+                        reload.set(*reload + 1);
+                    }
+                    Err(e) => error_message.set(Some(e)),
+                }
+            });
+        })
+    };
+
+    html! {
+        <div class="args-editor share-links">
+            <h3>{"공유 링크"}</h3>
+            <p class="form-hint">
+                {"비공개 세션의 스트림은 공유 링크로만 볼 수 있습니다. 링크는 만들 때 한 번만 보여줍니다."}
+            </p>
+            <form onsubmit={on_create}>
+                <div class="form-group">
+                    <label for="share_link_name">{"이름 (선택):"}</label>
+                    <input
+                        type="text"
+                        id="share_link_name"
+                        ref={name_ref}
+                        placeholder="예: 고객 A 대시보드"
+                        maxlength="64"
+                        disabled={*loading}
+                    />
+                </div>
+                <div class="form-group">
+                    <label for="share_link_ttl">{"유효 기간:"}</label>
+                    <select id="share_link_ttl" onchange={on_ttl_change} disabled={*loading}>
+                        {for TTL_OPTIONS.iter().map(|(label, seconds)| html! {
+                            <option value={seconds.to_string()} selected={*ttl_seconds == *seconds}>
+                                {*label}
+                            </option>
+                        })}
+                    </select>
+                </div>
+                <button type="submit" disabled={*loading}>
+                    {if *loading { "처리 중..." } else { "공유 링크 만들기" }}
+                </button>
+            </form>
+
+            {if let Some(response) = &*created {
+                html! {
+                    <div class="success">
+                        <p>{"공유 링크를 만들었습니다. 지금 복사해 두세요. 다시 볼 수 없습니다."}</p>
+                        <code class="api-key-secret">{&response.url}</code>
+                        <button class="secondary" onclick={on_copy}>{"복사"}</button>
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+
+            {if links.is_empty() {
+                html! { <p class="form-hint">{"만든 공유 링크가 없습니다."}</p> }
+            } else {
+                html! {
+                    <table class="api-key-table">
+                        <thead>
+                            <tr>
+                                <th>{"이름"}</th>
+                                <th>{"만든 시각"}</th>
+                                <th>{"만료"}</th>
+                                <th></th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            {for links.iter().map(|item| {
+                                let on_revoke = on_revoke.clone();
+                                let revoke_item = item.clone();
+                                html! {
+                                    <tr key={item.link_id.clone()}>
+                                        <td>{item.name.clone().unwrap_or_else(|| "-".to_string())}</td>
+                                        <td>{format_timestamp(&item.created_at)}</td>
+                                        <td>{format_timestamp(&item.expires_at)}</td>
+                                        <td>
+                                            <button
+                                                class="danger"
+                                                onclick={Callback::from(move |_: MouseEvent| on_revoke.emit(revoke_item.clone()))}
+                                            >
+                                                {"폐기"}
+                                            </button>
+                                        </td>
+                                    </tr>
+                                }
+                            })}
+                        </tbody>
+                    </table>
+                }
+            }}
+
+            {if let Some(ref msg) = *error_message {
+                html! { <div class="error">{msg}</div> }
+            } else {
+                html! {}
+            }}
+        </div>
+    }
+}
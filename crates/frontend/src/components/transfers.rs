@@ -0,0 +1,273 @@
+use wasm_bindgen_futures::spawn_local;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::api::transfers::{
+    accept_transfer, cancel_transfer, decline_transfer, list_incoming_transfers, request_transfer,
+};
+use crate::routes::Route;
+use crate::types::{PendingTransfer, SessionTransferItem};
+
+/// RFC3339 문자열을 `YYYY-MM-DD HH:MM` 형태로 줄입니다.
+fn format_timestamp(value: &str) -> String {
+    if value.len() >= 16 {
+        value[..16].replace('T', " ")
+    } else {
+        value.to_string()
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct TransferPanelProps {
+    pub user_id: String,
+    pub session_id: String,
+    /// 세션 상세에 들어 있던 대기 중인 요청
+    pub pending: Option<PendingTransfer>,
+}
+
+/// 세션 소유권을 다른 사용자에게 넘기는 패널. 소유자에게만 보입니다.
+#[function_component(TransferPanel)]
+pub fn transfer_panel(props: &TransferPanelProps) -> Html {
+    let pending = use_state(|| props.pending.clone());
+    let error_message = use_state(|| None::<String>);
+    let loading = use_state(|| false);
+    let recipient_ref = use_node_ref();
+
+    let on_request = {
+        let user_id = props.user_id.clone();
+        let session_id = props.session_id.clone();
+        let pending = pending.clone();
+        let error_message = error_message.clone();
+        let loading = loading.clone();
+        let recipient_ref = recipient_ref.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let Some(input) = recipient_ref.cast::<HtmlInputElement>() else {
+                return;
+            };
+            let to = input.value().trim().to_string();
+            if to.is_empty() {
+                error_message.set(Some("받을 사용자 ID를 입력하세요".to_string()));
+                return;
+            }
+
+            let user_id = user_id.clone();
+            let session_id = session_id.clone();
+            let pending = pending.clone();
+            let error_message = error_message.clone();
+            let loading = loading.clone();
+
+            loading.set(true);
+            error_message.set(None);
+
+            spawn_local(async move {
+                match request_transfer(&user_id, &session_id, &to).await {
+                    Ok(transfer) => {
+                        input.set_value("");
+                        pending.set(Some(transfer));
+                    }
+                    Err(e) => error_message.set(Some(e)),
+                }
+                loading.set(false);
+            });
+        })
+    };
+
+    let on_cancel = {
+        let user_id = props.user_id.clone();
+        let session_id = props.session_id.clone();
+        let pending = pending.clone();
+        let error_message = error_message.clone();
+        let loading = loading.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            let user_id = user_id.clone();
+            let session_id = session_id.clone();
+            let pending = pending.clone();
+            let error_message = error_message.clone();
+            let loading = loading.clone();
+
+            loading.set(true);
+            error_message.set(None);
+
+            spawn_local(async move {
+                match cancel_transfer(&user_id, &session_id).await {
+                    Ok(()) => pending.set(None),
+                    Err(e) => error_message.set(Some(e)),
+                }
+                loading.set(false);
+            });
+        })
+    };
+
+    html! {
+        <div class="args-editor transfer">
+            <h3>{"소유권 이전"}</h3>
+            <p class="form-hint">
+                {"받는 사용자가 수락하면 세션이 같은 ID로 그 사용자에게 옮겨집니다. "}
+                {"협업자와 공유 링크는 넘어가지 않으며, 요청은 7일 뒤 만료됩니다."}
+            </p>
+
+            {if let Some(ref transfer) = *pending {
+                html! {
+                    <div class="transfer-pending">
+                        <span>
+                            {format!(
+                                "{}님의 수락을 기다리는 중 (만료: {})",
+                                transfer.to,
+                                format_timestamp(&transfer.expires_at)
+                            )}
+                        </span>
+                        <button class="danger" onclick={on_cancel} disabled={*loading}>
+                            {"요청 취소"}
+                        </button>
+                    </div>
+                }
+            } else {
+                html! {
+                    <form class="collaborator-form" onsubmit={on_request}>
+                        <input
+                            type="text"
+                            ref={recipient_ref}
+                            placeholder="받을 사용자 ID"
+                            disabled={*loading}
+                        />
+                        <button type="submit" disabled={*loading}>{"이전 요청"}</button>
+                    </form>
+                }
+            }}
+
+            {if let Some(ref msg) = *error_message {
+                html! { <div class="error">{msg}</div> }
+            } else {
+                html! {}
+            }}
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct IncomingTransfersProps {
+    pub user_id: String,
+}
+
+/// 나에게 온 소유권 이전 요청. 없으면 아무것도 그리지 않습니다.
+#[function_component(IncomingTransfers)]
+pub fn incoming_transfers(props: &IncomingTransfersProps) -> Html {
+    let navigator = use_navigator().expect("Navigator must be available");
+    let transfers = use_state(Vec::<SessionTransferItem>::new);
+    let error_message = use_state(|| None::<String>);
+    let loading = use_state(|| false);
+    let reload = use_state(|| 0u32);
+
+    {
+        let user_id = props.user_id.clone();
+        let transfers = transfers.clone();
+        let error_message = error_message.clone();
+
+        use_effect_with((user_id, *reload), move |(user_id, _)| {
+            let user_id = user_id.clone();
+            spawn_local(async move {
+                match list_incoming_transfers(&user_id).await {
+                    Ok(items) => transfers.set(items),
+                    Err(e) => error_message.set(Some(e)),
+                }
+            });
+        });
+    }
+
+    // 수락하면 옮겨진 세션으로 이동하고, 거절하면 목록을 다시 읽습니다.
+    let respond = {
+        let user_id = props.user_id.clone();
+        let error_message = error_message.clone();
+        let loading = loading.clone();
+        let reload = reload.clone();
+
+        Callback::from(move |(item, accept): (SessionTransferItem, bool)| {
+            let user_id = user_id.clone();
+            let navigator = navigator.clone();
+            let error_message = error_message.clone();
+            let loading = loading.clone();
+            let reload = reload.clone();
+
+            loading.set(true);
+            error_message.set(None);
+
+            spawn_local(async move {
+                let result = if accept {
+                    accept_transfer(&user_id, &item.owner, &item.session_id).await
+                } else {
+                    decline_transfer(&user_id, &item.owner, &item.session_id).await
+                };
+                loading.set(false);
+                match result {
+                    Ok(()) if accept => navigator.push(&Route::UserSession {
+                        user_id,
+                        session_id: item.session_id,
+                    }),
+                    Ok(()) => reload.set(*reload + 1),
+                    Err(e) => error_message.set(Some(e)),
+                }
+            });
+        })
+    };
+
+    if transfers.is_empty() && error_message.is_none() {
+        return html! {};
+    }
+
+    html! {
+        <div class="incoming-transfers">
+            <h2>{"받은 소유권 이전 요청"}</h2>
+            <table class="api-key-table">
+                <thead>
+                    <tr>
+                        <th>{"세션"}</th>
+                        <th>{"보낸 사람"}</th>
+                        <th>{"만료"}</th>
+                        <th></th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {for transfers.iter().map(|item| {
+                        let accept = respond.clone();
+                        let accept_item = item.clone();
+                        let decline = respond.clone();
+                        let decline_item = item.clone();
+                        html! {
+                            <tr key={format!("{}/{}", item.owner, item.session_id)}>
+                                <td>{item.title.clone().unwrap_or_else(|| item.session_id.clone())}</td>
+                                <td>{&item.owner}</td>
+                                <td>{format_timestamp(&item.expires_at)}</td>
+                                <td>
+                                    <button
+                                        disabled={*loading}
+                                        onclick={Callback::from(move |_: MouseEvent| accept.emit((accept_item.clone(), true)))}
+                                    >
+                                        {"수락"}
+                                    </button>
+                                    <button
+                                        class="danger"
+                                        disabled={*loading}
+                                        onclick={Callback::from(move |_: MouseEvent| decline.emit((decline_item.clone(), false)))}
+                                    >
+                                        {"거절"}
+                                    </button>
+                                </td>
+                            </tr>
+                        }
+                    })}
+                </tbody>
+            </table>
+
+            {if let Some(ref msg) = *error_message {
+                html! { <div class="error">{msg}</div> }
+            } else {
+                html! {}
+            }}
+        </div>
+    }
+}
@@ -7,7 +7,10 @@ mod routes;
 mod types;
 
 use auth::{AuthContext, AuthProvider, AuthState};
-use components::{Header, LoginForm, SessionDetailPage, SessionForm, SessionListPage, SessionMode};
+use components::{
+    AccountPage, AdminPage, ApiKeysPage, BrowsePage, Header, LoginForm, OidcCallbackPage, OrgPage,
+    OrgsPage, SessionDetailPage, SessionForm, SessionListPage, SessionMode,
+};
 use routes::Route;
 use yew::prelude::*;
 use yew_router::prelude::*;
@@ -136,6 +139,23 @@ fn switch(route: Route) -> Html {
             }
         }
         Route::MySessions => html! { <SessionListPage /> },
+        Route::Browse => html! { <BrowsePage /> },
+        Route::Account => html! { <AccountPage /> },
+        Route::ApiKeys => html! { <ApiKeysPage /> },
+        Route::Orgs => html! { <OrgsPage /> },
+        Route::Org { org_id } => html! { <OrgPage {org_id} /> },
+        Route::OrgSession { org_id, session_id } => {
+            html! {
+                <SessionDetailPage
+                    user_id={String::new()}
+                    {session_id}
+                    is_user_session={false}
+                    org_id={Some(org_id)}
+                />
+            }
+        }
+        Route::Admin => html! { <AdminPage /> },
+        Route::OidcCallback => html! { <OidcCallbackPage /> },
         Route::NotFound => html! { <NotFoundPage /> },
     }
 }
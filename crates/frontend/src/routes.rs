@@ -14,6 +14,30 @@ pub enum Route {
     #[at("/my-sessions")]
     MySessions,
 
+    #[at("/browse")]
+    Browse,
+
+    #[at("/account")]
+    Account,
+
+    #[at("/api-keys")]
+    ApiKeys,
+
+    #[at("/orgs")]
+    Orgs,
+
+    #[at("/org/:org_id")]
+    Org { org_id: String },
+
+    #[at("/org/:org_id/session/:session_id")]
+    OrgSession { org_id: String, session_id: String },
+
+    #[at("/admin")]
+    Admin,
+
+    #[at("/auth/callback")]
+    OidcCallback,
+
     #[not_found]
     #[at("/404")]
     NotFound,
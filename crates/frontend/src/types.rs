@@ -16,17 +16,172 @@ impl<T> Default for ApiState<T> {
     }
 }
 
-/// JWT token response from /api/auth/token
+/// JWT token response from /api/auth/token and /api/auth/refresh
 #[derive(Debug, Clone, Deserialize)]
 pub struct TokenResponse {
     pub token: String,
+    /// 다음 갱신에 쓸 refresh 토큰 (사용할 때마다 교체됨)
+    #[serde(default)]
+    pub refresh_token: Option<String>,
 }
 
-/// Session detail response
+/// 쿠키 로그인 응답 (/api/auth/cookie). 토큰은 HttpOnly 쿠키로만 전달됩니다.
 #[derive(Debug, Clone, Deserialize)]
+pub struct CookieSession {
+    pub user_id: String,
+    #[serde(default)]
+    pub admin: bool,
+    /// access 토큰이 만료될 때까지 남은 초
+    pub expires_in: u64,
+}
+
+/// 세션 공개 범위. 비공개 세션의 스트림은 공유 링크로만 볼 수 있습니다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Visibility {
+    #[default]
+    Public,
+    Unlisted,
+    Private,
+}
+
+impl Visibility {
+    pub const ALL: [Visibility; 3] = [
+        Visibility::Public,
+        Visibility::Unlisted,
+        Visibility::Private,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Visibility::Public => "public",
+            Visibility::Unlisted => "unlisted",
+            Visibility::Private => "private",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Visibility::Public => "공개",
+            Visibility::Unlisted => "미등록 (링크를 아는 사람만)",
+            Visibility::Private => "비공개 (공유 링크 필요)",
+        }
+    }
+
+    pub fn parse(value: &str) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|visibility| visibility.as_str() == value)
+            .unwrap_or_default()
+    }
+}
+
+/// 소유자가 협업자에게 준 역할
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SessionRole {
+    Editor,
+    Viewer,
+}
+
+impl SessionRole {
+    pub const ALL: [SessionRole; 2] = [SessionRole::Editor, SessionRole::Viewer];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SessionRole::Editor => "editor",
+            SessionRole::Viewer => "viewer",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SessionRole::Editor => "편집자",
+            SessionRole::Viewer => "뷰어",
+        }
+    }
+
+    pub fn parse(value: &str) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|role| role.as_str() == value)
+            .unwrap_or(SessionRole::Viewer)
+    }
+}
+
+/// 조직 멤버의 역할
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OrgRole {
+    Owner,
+    Member,
+    Viewer,
+}
+
+impl OrgRole {
+    pub const ALL: [OrgRole; 3] = [OrgRole::Owner, OrgRole::Member, OrgRole::Viewer];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrgRole::Owner => "owner",
+            OrgRole::Member => "member",
+            OrgRole::Viewer => "viewer",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            OrgRole::Owner => "소유자",
+            OrgRole::Member => "멤버",
+            OrgRole::Viewer => "뷰어",
+        }
+    }
+
+    pub fn parse(value: &str) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|role| role.as_str() == value)
+            .unwrap_or(OrgRole::Viewer)
+    }
+
+    /// 조직 세션을 만들고 수정할 수 있는지
+    pub fn can_edit(&self) -> bool {
+        *self != OrgRole::Viewer
+    }
+}
+
+/// Session detail response
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct SessionDetail {
     pub template: String,
     pub args: HashMap<String, serde_json::Value>,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub listed: bool,
+    #[serde(default)]
+    pub visibility: Visibility,
+    /// 협업자로 조회한 경우의 역할. 소유자이면 없음.
+    #[serde(default)]
+    pub role: Option<SessionRole>,
+    /// 조직 세션을 조회한 경우의 조직 역할
+    #[serde(default)]
+    pub org_role: Option<OrgRole>,
+    /// 수락을 기다리는 소유권 이전 요청. 소유자에게만 옵니다.
+    #[serde(default)]
+    pub pending_transfer: Option<PendingTransfer>,
+}
+
+/// 소유권 이전 요청 (세션 상세의 `pending_transfer`)
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct PendingTransfer {
+    pub to: String,
+    pub created_at: String,
+    pub expires_at: String,
 }
 
 /// User session create request
@@ -38,24 +193,125 @@ pub struct UserSessionCreateRequest {
     pub args: Option<HashMap<String, serde_json::Value>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub expire_seconds: Option<u64>,
+    #[serde(flatten)]
+    pub metadata: SessionMetadata,
+}
+
+/// Session metadata (title, description, tags)
+///
+/// 값이 없는 필드는 전송하지 않으며, 서버는 전송된 필드만 반영합니다.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct SessionMetadata {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// 공개 디렉터리 등록 여부 (제목 필요)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub listed: Option<bool>,
+    /// 공개 범위 (사용자 세션만)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
+}
+
+impl SessionMetadata {
+    /// 쉼표로 구분된 태그 입력을 목록으로 변환합니다.
+    pub fn parse_tags(input: &str) -> Vec<String> {
+        input
+            .split(',')
+            .map(|tag| tag.trim().to_string())
+            .filter(|tag| !tag.is_empty())
+            .collect()
+    }
 }
 
 /// Session update request
 #[derive(Debug, Clone, Serialize)]
 pub struct SessionUpdateRequest {
     pub args: HashMap<String, serde_json::Value>,
+    #[serde(flatten)]
+    pub metadata: SessionMetadata,
 }
 
 /// Session list item
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct SessionListItem {
     pub session_id: String,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub listed: bool,
+    #[serde(default)]
+    pub visibility: Visibility,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    #[serde(default)]
+    pub ttl_seconds: Option<i64>,
+    #[serde(default)]
+    pub template_bytes: usize,
+    #[serde(default)]
+    pub viewers: u64,
+    #[serde(default)]
+    pub thumbnail_url: String,
+    /// 공유받은 세션의 소유자
+    #[serde(default)]
+    pub owner: Option<String>,
+    #[serde(default)]
+    pub role: Option<SessionRole>,
 }
 
 /// Session list response
 #[derive(Debug, Clone, Deserialize)]
 pub struct SessionListResponse {
     pub items: Vec<SessionListItem>,
+    #[serde(default)]
+    pub next_cursor: Option<String>,
+}
+
+/// Session list query (`GET /api/user/{user_id}/session`)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SessionListQuery {
+    pub cursor: Option<String>,
+    pub limit: Option<usize>,
+    /// created | updated | name | ttl | viewers
+    pub sort: Option<String>,
+    /// asc | desc
+    pub order: Option<String>,
+    pub q: Option<String>,
+    pub tag: Option<String>,
+}
+
+impl SessionListQuery {
+    pub fn to_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = Vec::new();
+        if let Some(cursor) = &self.cursor {
+            pairs.push(("cursor", cursor.clone()));
+        }
+        if let Some(limit) = self.limit {
+            pairs.push(("limit", limit.to_string()));
+        }
+        if let Some(sort) = &self.sort {
+            pairs.push(("sort", sort.clone()));
+        }
+        if let Some(order) = &self.order {
+            pairs.push(("order", order.clone()));
+        }
+        if let Some(q) = self.q.as_ref().filter(|q| !q.trim().is_empty()) {
+            pairs.push(("q", q.trim().to_string()));
+        }
+        if let Some(tag) = &self.tag {
+            pairs.push(("tag", tag.clone()));
+        }
+        pairs
+    }
 }
 
 /// Public session create request
@@ -67,4 +323,319 @@ pub struct PublicSessionCreateRequest {
     pub args: Option<HashMap<String, serde_json::Value>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub expire: Option<String>,
+    #[serde(flatten)]
+    pub metadata: SessionMetadata,
+}
+
+/// Public directory entry (`GET /api/directory`)
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DirectoryEntry {
+    #[serde(default)]
+    pub user_id: Option<String>,
+    #[serde(default)]
+    pub org_id: Option<String>,
+    pub session_id: String,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub viewers: u64,
+    pub page_url: String,
+    pub stream_url: String,
+    pub thumbnail_url: String,
+}
+
+/// Public directory response
+#[derive(Debug, Clone, Deserialize)]
+pub struct DirectoryResponse {
+    pub items: Vec<DirectoryEntry>,
+    #[serde(default)]
+    pub next_cursor: Option<String>,
+}
+
+/// Public directory query
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DirectoryQuery {
+    pub cursor: Option<String>,
+    pub limit: Option<usize>,
+    /// popular | recent | title
+    pub sort: Option<String>,
+    pub q: Option<String>,
+    pub tag: Option<String>,
+}
+
+impl DirectoryQuery {
+    pub fn to_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = Vec::new();
+        if let Some(cursor) = &self.cursor {
+            pairs.push(("cursor", cursor.clone()));
+        }
+        if let Some(limit) = self.limit {
+            pairs.push(("limit", limit.to_string()));
+        }
+        if let Some(sort) = &self.sort {
+            pairs.push(("sort", sort.clone()));
+        }
+        if let Some(q) = self.q.as_ref().filter(|q| !q.trim().is_empty()) {
+            pairs.push(("q", q.trim().to_string()));
+        }
+        if let Some(tag) = &self.tag {
+            pairs.push(("tag", tag.clone()));
+        }
+        pairs
+    }
+}
+
+/// API key list item (`GET /api/user/{user_id}/api-key`)
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ApiKeyItem {
+    pub key_id: String,
+    pub name: String,
+    pub created_at: String,
+    #[serde(default)]
+    pub expires_at: Option<String>,
+    #[serde(default)]
+    pub last_used_at: Option<String>,
+}
+
+/// API key list response
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApiKeyListResponse {
+    pub items: Vec<ApiKeyItem>,
+}
+
+/// API key create request
+#[derive(Debug, Clone, Serialize)]
+pub struct ApiKeyCreateRequest {
+    pub name: String,
+    /// 없으면 만료되지 않음
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_in_seconds: Option<u64>,
+}
+
+/// API key create response. `api_key`는 이 응답에서만 볼 수 있습니다.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ApiKeyCreateResponse {
+    pub key_id: String,
+    pub name: String,
+    pub api_key: String,
+    #[serde(default)]
+    pub expires_at: Option<String>,
+}
+
+/// Share link list item (`GET /api/user/{user_id}/session/{session_id}/share-link`)
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ShareLinkItem {
+    pub link_id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    pub created_at: String,
+    pub expires_at: String,
+}
+
+/// Share link list response
+#[derive(Debug, Clone, Deserialize)]
+pub struct ShareLinkListResponse {
+    pub items: Vec<ShareLinkItem>,
+}
+
+/// Share link create request
+#[derive(Debug, Clone, Serialize)]
+pub struct ShareLinkCreateRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl_seconds: Option<u64>,
+}
+
+/// Share link create response. `url`의 토큰은 이 응답에서만 볼 수 있습니다.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ShareLinkCreateResponse {
+    pub link_id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    pub url: String,
+    pub expires_at: String,
+}
+
+/// Collaborator list item (`GET /api/user/{user_id}/session/{session_id}/collaborator`)
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct CollaboratorItem {
+    pub user_id: String,
+    pub role: SessionRole,
+}
+
+/// Collaborator list response
+#[derive(Debug, Clone, Deserialize)]
+pub struct CollaboratorListResponse {
+    pub items: Vec<CollaboratorItem>,
+}
+
+/// Collaborator add/update request
+#[derive(Debug, Clone, Serialize)]
+pub struct CollaboratorRequest {
+    pub role: SessionRole,
+}
+
+/// Session transfer item (`GET /api/user/{user_id}/transfer`)
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SessionTransferItem {
+    pub owner: String,
+    pub session_id: String,
+    pub to: String,
+    #[serde(default)]
+    pub title: Option<String>,
+    pub created_at: String,
+    pub expires_at: String,
+}
+
+/// Session transfer list response
+#[derive(Debug, Clone, Deserialize)]
+pub struct SessionTransferListResponse {
+    pub items: Vec<SessionTransferItem>,
+}
+
+/// Session transfer request
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionTransferRequest {
+    pub to: String,
+}
+
+/// Organization list item (`GET /api/user/{user_id}/org`)
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct OrgItem {
+    pub org_id: String,
+    pub name: String,
+    pub role: OrgRole,
+    pub created_at: String,
+}
+
+/// Organization list response
+#[derive(Debug, Clone, Deserialize)]
+pub struct OrgListResponse {
+    pub items: Vec<OrgItem>,
+}
+
+/// Organization member (`GET /api/org/{org_id}`)
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct OrgMemberItem {
+    pub user_id: String,
+    pub role: OrgRole,
+}
+
+/// Organization detail response
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct OrgDetail {
+    pub org_id: String,
+    pub name: String,
+    pub role: OrgRole,
+    pub created_at: String,
+    pub members: Vec<OrgMemberItem>,
+}
+
+/// Organization create request
+#[derive(Debug, Clone, Serialize)]
+pub struct OrgCreateRequest {
+    pub org_id: String,
+    pub name: String,
+}
+
+/// Organization member add/update request
+#[derive(Debug, Clone, Serialize)]
+pub struct OrgMemberRequest {
+    pub role: OrgRole,
+}
+
+/// Organization session create request (`POST /api/org/{org_id}/session`)
+#[derive(Debug, Clone, Serialize)]
+pub struct OrgSessionCreateRequest {
+    pub session_id: String,
+    pub template: String,
+    pub args: HashMap<String, serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl_seconds: Option<u64>,
+    #[serde(flatten)]
+    pub metadata: SessionMetadata,
+}
+
+/// Admin user list item (`GET /api/admin/user`)
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AdminUserItem {
+    pub user_id: String,
+    pub admin: bool,
+    #[serde(default)]
+    pub disabled_at: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub last_login_at: Option<String>,
+    pub sessions: u64,
+}
+
+/// Admin user list response
+#[derive(Debug, Clone, Deserialize)]
+pub struct AdminUserListResponse {
+    pub items: Vec<AdminUserItem>,
+}
+
+/// Admin user update request (`PUT /api/admin/user/{user_id}`)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AdminUserUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
+}
+
+/// Admin session list item (`GET /api/admin/session`)
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AdminSessionItem {
+    #[serde(default)]
+    pub user_id: Option<String>,
+    #[serde(default)]
+    pub org_id: Option<String>,
+    pub session_id: String,
+    #[serde(default)]
+    pub title: Option<String>,
+    pub visibility: Visibility,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    #[serde(default)]
+    pub viewers: u64,
+    pub page_url: String,
+}
+
+/// Admin session list response
+#[derive(Debug, Clone, Deserialize)]
+pub struct AdminSessionListResponse {
+    pub items: Vec<AdminSessionItem>,
+}
+
+/// System statistics (`GET /api/admin/stats`)
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct SystemStats {
+    pub users: u64,
+    pub admins: u64,
+    pub disabled_users: u64,
+    pub orgs: u64,
+    pub public_sessions: u64,
+    pub user_sessions: u64,
+    pub org_sessions: u64,
+    pub private_sessions: u64,
+    pub listed_sessions: u64,
+    pub viewers: u64,
+    #[serde(default)]
+    pub login_rejections: LoginRejectionStats,
+}
+
+/// Rejected login attempts by reason
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct LoginRejectionStats {
+    pub ip_rate_limited: u64,
+    pub user_rate_limited: u64,
+    pub locked_out: u64,
+    pub invalid_credentials: u64,
 }
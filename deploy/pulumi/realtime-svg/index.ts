@@ -31,6 +31,7 @@ export interface RealtimeSvgArgs {
     
     configLogLevel?: pulumi.Input<string>;
     configPort?: pulumi.Input<number>;
+    configKeyPrefix?: pulumi.Input<string>;
     
     commonAnnotations?: pulumi.Input<{[key: string]: pulumi.Input<string>}>;
     deploymentAnnotations?: pulumi.Input<{[key: string]: pulumi.Input<string>}>;
@@ -81,6 +82,7 @@ export class RealtimeSvg extends pulumi.ComponentResource {
         
         const configLogLevel = args.configLogLevel || "info";
         const configPort = args.configPort || 8080;
+        const configKeyPrefix = args.configKeyPrefix || "";
         
         const commonAnnotations = args.commonAnnotations || {};
         const deploymentAnnotations = args.deploymentAnnotations || {};
@@ -125,6 +127,7 @@ export class RealtimeSvg extends pulumi.ComponentResource {
             data: {
                 REDIS_URL: redisUrl,
                 LOG_LEVEL: configLogLevel,
+                KEY_PREFIX: configKeyPrefix,
                 PORT: pulumi.output(configPort).apply(p => p.toString()),
             },
         }, { parent: this, provider: args.provider });
@@ -197,6 +200,15 @@ export class RealtimeSvg extends pulumi.ComponentResource {
                                         },
                                     },
                                 },
+                                {
+                                    name: "KEY_PREFIX",
+                                    valueFrom: {
+                                        configMapKeyRef: {
+                                            name: configMap.metadata.name,
+                                            key: "KEY_PREFIX",
+                                        },
+                                    },
+                                },
                                 {
                                     name: "PORT",
                                     valueFrom: {
@@ -7,8 +7,9 @@ resource "kubernetes_config_map" "realtime_svg" {
   }
 
   data = {
-    REDIS_URL = local.redis_url
-    LOG_LEVEL = var.config_log_level
-    PORT      = tostring(var.config_port)
+    REDIS_URL  = local.redis_url
+    LOG_LEVEL  = var.config_log_level
+    PORT       = tostring(var.config_port)
+    KEY_PREFIX = var.config_key_prefix
   }
 }
@@ -71,6 +71,16 @@ resource "kubernetes_deployment" "realtime_svg" {
             }
           }
 
+          env {
+            name = "KEY_PREFIX"
+            value_from {
+              config_map_key_ref {
+                name = kubernetes_config_map.realtime_svg.metadata[0].name
+                key  = "KEY_PREFIX"
+              }
+            }
+          }
+
           env {
             name = "PORT"
             value_from {
@@ -210,6 +210,17 @@ variable "config_port" {
   }
 }
 
+variable "config_key_prefix" {
+  description = "Prefix for every Redis key and channel (set per deployment when sharing one Redis)"
+  type        = string
+  default     = ""
+
+  validation {
+    condition     = can(regex("^[^\\s*?\\[\\]]*$", var.config_key_prefix))
+    error_message = "config_key_prefix must not contain whitespace or glob characters (*, ?, [, ])"
+  }
+}
+
 variable "secret_api_key" {
   description = "API key (optional, will be base64 encoded)"
   type        = string